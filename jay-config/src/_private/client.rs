@@ -11,8 +11,9 @@ use {
         },
         exec::Command,
         input::{
-            acceleration::AccelProfile, capability::Capability, FocusFollowsMouseMode, InputDevice,
-            Seat, SwitchEvent,
+            acceleration::AccelProfile, capability::Capability, DesktopClick,
+            FocusFollowsMouseMode, HotCornerEvent, InputDevice, OutputEdge, PointerEdgeBehavior,
+            PointerPosition, PointerTrigger, Seat, SwitchEvent,
         },
         keyboard::{
             mods::{Modifiers, RELEASE},
@@ -25,8 +26,10 @@ use {
         timer::Timer,
         video::{
             connector_type::{ConnectorType, CON_UNKNOWN},
-            Connector, DrmDevice, Format, GfxApi, Mode, TearingMode, Transform, VrrMode,
+            ColorMatrix, Connector, DrmDevice, Format, GfxApi, Mode, OutputId, TearingMode,
+            TitleBarPosition, Transform, VrrMode,
         },
+        window::DecorationMode,
         xwayland::XScalingMode,
         Axis, Direction, ModifiedKeySym, PciId, Workspace,
     },
@@ -99,6 +102,12 @@ pub(crate) struct Client {
     on_del_drm_device: RefCell<Option<Callback<DrmDevice>>>,
     on_idle: RefCell<Option<Callback>>,
     on_switch_event: RefCell<HashMap<InputDevice, Callback<SwitchEvent>>>,
+    on_desktop_click: RefCell<HashMap<Seat, Callback<DesktopClick>>>,
+    on_keyboard_layout_changed: RefCell<HashMap<Seat, Callback<String>>>,
+    key_sequences: RefCell<HashMap<(Seat, Vec<ModifiedKeySym>), Callback>>,
+    on_idle_time: RefCell<HashMap<Seat, Callback<Duration>>>,
+    on_hot_corner: RefCell<HashMap<Seat, Callback<HotCornerEvent>>>,
+    pointer_shortcuts: RefCell<HashMap<(Seat, Modifiers, PointerTrigger), Callback>>,
     bufs: RefCell<Vec<Vec<u8>>>,
     reload: Cell<bool>,
     read_interests: RefCell<HashMap<PollableId, Interest>>,
@@ -231,6 +240,12 @@ pub unsafe extern "C" fn init(
         on_del_drm_device: Default::default(),
         on_idle: Default::default(),
         on_switch_event: Default::default(),
+        on_desktop_click: Default::default(),
+        on_keyboard_layout_changed: Default::default(),
+        key_sequences: Default::default(),
+        on_idle_time: Default::default(),
+        on_hot_corner: Default::default(),
+        pointer_shortcuts: Default::default(),
         bufs: Default::default(),
         reload: Cell::new(false),
         read_interests: Default::default(),
@@ -454,6 +469,38 @@ impl Client {
         self.send(&ClientMessage::DisablePointerConstraint { seat });
     }
 
+    pub fn locate_pointer(&self, seat: Seat) {
+        self.send(&ClientMessage::LocatePointer { seat });
+    }
+
+    pub fn set_pointer_locator_duration(&self, duration: Duration) {
+        self.send(&ClientMessage::SetPointerLocatorDuration { duration });
+    }
+
+    pub fn set_pointer_edge_behavior(&self, edge: OutputEdge, behavior: PointerEdgeBehavior) {
+        self.send(&ClientMessage::SetPointerEdgeBehavior { edge, behavior });
+    }
+
+    pub fn seat_zoom_in(&self, seat: Seat) {
+        self.send(&ClientMessage::SeatZoomIn { seat });
+    }
+
+    pub fn seat_zoom_out(&self, seat: Seat) {
+        self.send(&ClientMessage::SeatZoomOut { seat });
+    }
+
+    pub fn seat_reset_zoom(&self, seat: Seat) {
+        self.send(&ClientMessage::SeatResetZoom { seat });
+    }
+
+    pub fn set_max_zoom(&self, factor: f64) {
+        self.send(&ClientMessage::SetMaxZoom { factor });
+    }
+
+    pub fn seat_toggle_invert_colors(&self, seat: Seat) {
+        self.send(&ClientMessage::SeatToggleInvertColors { seat });
+    }
+
     pub fn move_to_output(&self, workspace: WorkspaceSource, connector: Connector) {
         self.send(&ClientMessage::MoveToOutput {
             workspace,
@@ -499,6 +546,20 @@ impl Client {
         self.set_floating(seat, !self.get_floating(seat));
     }
 
+    pub fn get_sticky(&self, seat: Seat) -> bool {
+        let res = self.send_with_response(&ClientMessage::GetSticky { seat });
+        get_response!(res, false, GetSticky { sticky });
+        sticky
+    }
+
+    pub fn set_sticky(&self, seat: Seat, sticky: bool) {
+        self.send(&ClientMessage::SetSticky { seat, sticky });
+    }
+
+    pub fn toggle_sticky(&self, seat: Seat) {
+        self.set_sticky(seat, !self.get_sticky(seat));
+    }
+
     pub fn reset_colors(&self) {
         self.send(&ClientMessage::ResetColors);
     }
@@ -527,6 +588,10 @@ impl Client {
         self.send(&ClientMessage::SetCursorSize { seat, size })
     }
 
+    pub fn set_cursor_theme(&self, seat: Seat, theme: Option<&str>) {
+        self.send(&ClientMessage::SetCursorTheme { seat, theme })
+    }
+
     pub fn set_use_hardware_cursor(&self, seat: Seat, use_hardware_cursor: bool) {
         self.send(&ClientMessage::SetUseHardwareCursor {
             seat,
@@ -630,6 +695,168 @@ impl Client {
             .insert(input_device, cb(f));
     }
 
+    pub fn on_desktop_click<F: FnMut(DesktopClick) + 'static>(&self, seat: Seat, f: F) {
+        self.on_desktop_click.borrow_mut().insert(seat, cb(f));
+    }
+
+    pub fn on_keyboard_layout_changed<F: FnMut(String) + 'static>(&self, seat: Seat, f: F) {
+        self.on_keyboard_layout_changed
+            .borrow_mut()
+            .insert(seat, cb(f));
+    }
+
+    pub fn seat_get_keyboard_layout(&self, seat: Seat) -> String {
+        let res = self.send_with_response(&ClientMessage::SeatGetKeyboardLayout { seat });
+        get_response!(res, String::new(), GetKeyboardLayout { name });
+        name
+    }
+
+    pub fn seat_get_idle_time(&self, seat: Seat) -> Duration {
+        let res = self.send_with_response(&ClientMessage::SeatGetIdleTime { seat });
+        get_response!(res, Duration::ZERO, GetIdleTime { idle });
+        idle
+    }
+
+    pub fn seat_get_pointer_position(&self, seat: Seat) -> Option<PointerPosition> {
+        let res = self.send_with_response(&ClientMessage::SeatGetPointerPosition { seat });
+        get_response!(res, None, GetPointerPosition { position });
+        Some(position)
+    }
+
+    pub fn on_idle_time<F: FnMut(Duration) + 'static>(&self, seat: Seat, interval: Duration, f: F) {
+        if interval.is_zero() {
+            self.on_idle_time.borrow_mut().remove(&seat);
+        } else {
+            self.on_idle_time.borrow_mut().insert(seat, cb(f));
+        }
+        self.send(&ClientMessage::SeatSetIdleTimeInterval { seat, interval });
+    }
+
+    pub fn bind_sequence<F: FnMut() + 'static>(
+        &self,
+        seat: Seat,
+        keys: &[ModifiedKeySym],
+        mut f: F,
+    ) {
+        let keys = keys.to_vec();
+        self.key_sequences
+            .borrow_mut()
+            .insert((seat, keys.clone()), cb(move |_| f()));
+        self.send(&ClientMessage::AddKeySequence { seat, keys });
+    }
+
+    pub fn unbind_sequence(&self, seat: Seat, keys: &[ModifiedKeySym]) {
+        let keys = keys.to_vec();
+        if self
+            .key_sequences
+            .borrow_mut()
+            .remove(&(seat, keys.clone()))
+            .is_some()
+        {
+            self.send(&ClientMessage::RemoveKeySequence { seat, keys });
+        }
+    }
+
+    pub fn bind_pointer<F: FnMut() + 'static>(
+        &self,
+        seat: Seat,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+        mut f: F,
+    ) {
+        self.pointer_shortcuts
+            .borrow_mut()
+            .insert((seat, mods, trigger), cb(move |_| f()));
+        self.send(&ClientMessage::AddPointerShortcut {
+            seat,
+            mods,
+            trigger,
+        });
+    }
+
+    pub fn unbind_pointer(&self, seat: Seat, mods: Modifiers, trigger: PointerTrigger) {
+        if self
+            .pointer_shortcuts
+            .borrow_mut()
+            .remove(&(seat, mods, trigger))
+            .is_some()
+        {
+            self.send(&ClientMessage::RemovePointerShortcut {
+                seat,
+                mods,
+                trigger,
+            });
+        }
+    }
+
+    pub fn set_key_sequence_timeout(&self, seat: Seat, timeout: Duration) {
+        self.send(&ClientMessage::SetKeySequenceTimeout { seat, timeout });
+    }
+
+    pub fn set_forward_aborted_key_sequences(&self, seat: Seat, forward: bool) {
+        self.send(&ClientMessage::SetForwardAbortedKeySequences { seat, forward });
+    }
+
+    pub fn set_show_key_sequence_hint(&self, seat: Seat, show: bool) {
+        self.send(&ClientMessage::SetShowKeySequenceHint { seat, show });
+    }
+
+    pub fn set_hot_corner_size(&self, seat: Seat, size: i32) {
+        self.send(&ClientMessage::SetHotCornerSize { seat, size });
+    }
+
+    pub fn set_hot_corner_dwell(&self, seat: Seat, dwell: Duration) {
+        self.send(&ClientMessage::SetHotCornerDwell { seat, dwell });
+    }
+
+    pub fn set_focus_follows_mouse_dwell(&self, seat: Seat, dwell: Duration) {
+        self.send(&ClientMessage::SetFocusFollowsMouseDwell { seat, dwell });
+    }
+
+    pub fn set_warp_pointer_on_focus(&self, seat: Seat, warp: bool) {
+        self.send(&ClientMessage::SetWarpPointerOnFocus { seat, warp });
+    }
+
+    pub fn set_hide_cursor_while_typing(&self, seat: Seat, hide: bool) {
+        self.send(&ClientMessage::SetHideCursorWhileTyping { seat, hide });
+    }
+
+    pub fn set_hide_cursor_timeout(&self, seat: Seat, timeout: Duration) {
+        self.send(&ClientMessage::SetHideCursorTimeout { seat, timeout });
+    }
+
+    pub fn set_shortcuts_inhibit_escape(&self, seat: Seat, escape: Option<ModifiedKeySym>) {
+        self.send(&ClientMessage::SetShortcutsInhibitEscape { seat, escape });
+    }
+
+    pub fn set_zoom_scroll_modifiers(&self, seat: Seat, mods: Modifiers) {
+        self.send(&ClientMessage::SetZoomScrollModifiers { seat, mods });
+    }
+
+    pub fn set_workspace_swipe(&self, seat: Seat, fingers: u32, inverted: bool) {
+        self.send(&ClientMessage::SetWorkspaceSwipe {
+            seat,
+            fingers,
+            inverted,
+        });
+    }
+
+    pub fn set_dnd_threshold(&self, seat: Seat, threshold: i32) {
+        self.send(&ClientMessage::SetDndThreshold { seat, threshold });
+    }
+
+    pub fn set_middle_click_paste_enabled(&self, seat: Seat, enabled: bool) {
+        self.send(&ClientMessage::SetMiddleClickPasteEnabled { seat, enabled });
+    }
+
+    pub fn set_which_key_delay(&self, seat: Seat, delay: Option<Duration>) {
+        self.send(&ClientMessage::SetWhichKeyDelay { seat, delay });
+    }
+
+    pub fn on_hot_corner<F: FnMut(HotCornerEvent) + 'static>(&self, seat: Seat, f: F) {
+        self.on_hot_corner.borrow_mut().insert(seat, cb(f));
+    }
+
     pub fn set_double_click_interval(&self, usec: u64) {
         self.send(&ClientMessage::SetDoubleClickIntervalUsec { usec });
     }
@@ -755,6 +982,14 @@ impl Client {
         self.send(&ClientMessage::SetFlipMargin { device, margin });
     }
 
+    pub fn set_show_focused_output(&self, show: bool) {
+        self.send(&ClientMessage::SetShowFocusedOutput { show });
+    }
+
+    pub fn set_shared_clipboard(&self, shared: bool) {
+        self.send(&ClientMessage::SetSharedClipboard { shared });
+    }
+
     pub fn set_ui_drag_enabled(&self, enabled: bool) {
         self.send(&ClientMessage::SetUiDragEnabled { enabled });
     }
@@ -763,6 +998,81 @@ impl Client {
         self.send(&ClientMessage::SetUiDragThreshold { threshold });
     }
 
+    pub fn set_workspace_scroll_inverted(&self, inverted: bool) {
+        self.send(&ClientMessage::SetWorkspaceScrollInverted { inverted });
+    }
+
+    pub fn set_workspace_scroll_threshold(&self, threshold: i32) {
+        self.send(&ClientMessage::SetWorkspaceScrollThreshold { threshold });
+    }
+
+    pub fn set_workspace_scroll_wrap(&self, wrap: bool) {
+        self.send(&ClientMessage::SetWorkspaceScrollWrap { wrap });
+    }
+
+    pub fn set_workspace_output_affinity(
+        &self,
+        workspace: Workspace,
+        preferred: Option<OutputId>,
+        fallback: Option<OutputId>,
+    ) {
+        self.send(&ClientMessage::SetWorkspaceOutputAffinity {
+            workspace,
+            preferred,
+            fallback,
+        });
+    }
+
+    pub fn set_workspace_scroll_skip_empty(&self, skip_empty: bool) {
+        self.send(&ClientMessage::SetWorkspaceScrollSkipEmpty { skip_empty });
+    }
+
+    pub fn connector_set_workspace_scroll_wrap(&self, connector: Connector, wrap: Option<bool>) {
+        self.send(&ClientMessage::ConnectorSetWorkspaceScrollWrap { connector, wrap });
+    }
+
+    pub fn connector_set_workspace_scroll_skip_empty(
+        &self,
+        connector: Connector,
+        skip_empty: Option<bool>,
+    ) {
+        self.send(&ClientMessage::ConnectorSetWorkspaceScrollSkipEmpty {
+            connector,
+            skip_empty,
+        });
+    }
+
+    pub fn connector_set_span(&self, connector: Connector, secondary: Option<Connector>) {
+        self.send(&ClientMessage::ConnectorSetSpan {
+            connector,
+            secondary,
+        });
+    }
+
+    pub fn connector_set_game_mode(&self, connector: Connector, enabled: bool) {
+        self.send(&ClientMessage::ConnectorSetGameMode { connector, enabled });
+    }
+
+    pub fn connector_set_vrr_min_refresh(&self, connector: Connector, hz: Option<u32>) {
+        self.send(&ClientMessage::ConnectorSetVrrMinRefresh { connector, hz });
+    }
+
+    pub fn connector_set_max_scale(&self, connector: Connector, scale: Option<f64>) {
+        self.send(&ClientMessage::ConnectorSetMaxScale { connector, scale });
+    }
+
+    pub fn connector_set_mirror_source(&self, connector: Connector, source: Option<Connector>) {
+        self.send(&ClientMessage::ConnectorSetMirrorSource { connector, source });
+    }
+
+    pub fn set_output_throttle(&self, hz: f64) {
+        self.send(&ClientMessage::SetOutputThrottle { hz });
+    }
+
+    pub fn set_output_throttle_delay(&self, delay: Duration) {
+        self.send(&ClientMessage::SetOutputThrottleDelay { delay });
+    }
+
     pub fn connector_connected(&self, connector: Connector) -> bool {
         let res = self.send_with_response(&ClientMessage::ConnectorConnected { connector });
         get_response!(res, false, ConnectorConnected { connected });
@@ -777,6 +1087,32 @@ impl Client {
         self.send(&ClientMessage::ConnectorSetFormat { connector, format });
     }
 
+    pub fn connector_set_render_scale(&self, connector: Connector, scale: f64) {
+        self.send(&ClientMessage::ConnectorSetRenderScale { connector, scale });
+    }
+
+    pub fn connector_set_vblank_cursor_updates(&self, connector: Connector, enabled: bool) {
+        self.send(&ClientMessage::ConnectorSetVblankCursorUpdates { connector, enabled });
+    }
+
+    pub fn connector_set_use_hardware_cursor(
+        &self,
+        connector: Connector,
+        use_hardware_cursor: bool,
+    ) {
+        self.send(&ClientMessage::ConnectorSetUseHardwareCursor {
+            connector,
+            use_hardware_cursor,
+        });
+    }
+
+    pub fn connector_hardware_cursor_active(&self, connector: Connector) -> bool {
+        let res =
+            self.send_with_response(&ClientMessage::ConnectorHardwareCursorActive { connector });
+        get_response!(res, false, ConnectorHardwareCursorActive { active });
+        active
+    }
+
     pub fn connector_get_scale(&self, connector: Connector) -> f64 {
         let res = self.send_with_response(&ClientMessage::ConnectorGetScale { connector });
         get_response!(res, 1.0, ConnectorGetScale { scale });
@@ -827,6 +1163,39 @@ impl Client {
         self.send(&ClientMessage::SetXScalingMode { mode })
     }
 
+    pub fn set_scale_override(&self, app_id: &str, scale: Option<f64>) {
+        self.send(&ClientMessage::SetScaleOverride { app_id, scale })
+    }
+
+    pub fn set_decoration_mode(&self, app_id: &str, mode: Option<DecorationMode>) {
+        self.send(&ClientMessage::SetDecorationMode { app_id, mode })
+    }
+
+    pub fn set_block_capture(&self, app_id: &str, block: bool) {
+        self.send(&ClientMessage::SetBlockCapture { app_id, block })
+    }
+
+    pub fn reset_window_rules(&self) {
+        self.send(&ClientMessage::ResetWindowRules)
+    }
+
+    pub fn set_window_border_width(&self, app_id: &str, width: Option<i32>) {
+        self.send(&ClientMessage::SetWindowBorderWidth { app_id, width })
+    }
+
+    pub fn set_window_border_color(
+        &self,
+        app_id: &str,
+        colorable: Colorable,
+        color: Option<Color>,
+    ) {
+        self.send(&ClientMessage::SetWindowBorderColor {
+            app_id,
+            colorable,
+            color,
+        })
+    }
+
     pub fn set_vrr_mode(&self, connector: Option<Connector>, mode: VrrMode) {
         self.send(&ClientMessage::SetVrrMode { connector, mode })
     }
@@ -839,6 +1208,29 @@ impl Client {
         self.send(&ClientMessage::SetTearingMode { connector, mode })
     }
 
+    pub fn set_color_filter(&self, connector: Option<Connector>, filter: Option<ColorMatrix>) {
+        self.send(&ClientMessage::SetColorFilter { connector, filter })
+    }
+
+    pub fn connector_set_invert_colors(&self, connector: Connector, invert: bool) {
+        self.send(&ClientMessage::ConnectorSetInvertColors { connector, invert })
+    }
+
+    pub fn connector_set_default_workspace_capture(&self, connector: Connector, capture: bool) {
+        self.send(&ClientMessage::ConnectorSetDefaultWorkspaceCapture { connector, capture })
+    }
+
+    pub fn connector_set_title_bar_position(
+        &self,
+        connector: Connector,
+        position: TitleBarPosition,
+    ) {
+        self.send(&ClientMessage::ConnectorSetTitleBarPosition {
+            connector,
+            position,
+        })
+    }
+
     pub fn drm_devices(&self) -> Vec<DrmDevice> {
         let res = self.send_with_response(&ClientMessage::GetDrmDevices);
         get_response!(res, vec![], GetDrmDevices { devices });
@@ -893,6 +1285,14 @@ impl Client {
         self.send(&ClientMessage::SetIdle { timeout })
     }
 
+    pub fn set_idle_grace_period(&self, period: Duration) {
+        self.send(&ClientMessage::SetIdleGracePeriod { period })
+    }
+
+    pub fn seat_set_idle_grace_period(&self, seat: Seat, period: Option<Duration>) {
+        self.send(&ClientMessage::SeatSetIdleGracePeriod { seat, period })
+    }
+
     pub fn set_explicit_sync_enabled(&self, enabled: bool) {
         self.send(&ClientMessage::SetExplicitSyncEnabled { enabled })
     }
@@ -905,6 +1305,10 @@ impl Client {
         self.send(&ClientMessage::DeviceSetKeymap { device, keymap })
     }
 
+    pub fn set_device_repeat_rate(&self, device: InputDevice, rate: Option<(i32, i32)>) {
+        self.send(&ClientMessage::DeviceSetRepeatRate { device, rate })
+    }
+
     pub fn set_left_handed(&self, device: InputDevice, left_handed: bool) {
         self.send(&ClientMessage::SetLeftHanded {
             device,
@@ -980,6 +1384,17 @@ impl Client {
         self.send(&ClientMessage::SeatSetKeymap { seat, keymap })
     }
 
+    pub fn seat_set_layouts(&self, seat: Seat, layouts: &[Keymap]) {
+        self.send(&ClientMessage::SeatSetLayouts {
+            seat,
+            layouts: layouts.to_vec(),
+        })
+    }
+
+    pub fn seat_cycle_layout(&self, seat: Seat) {
+        self.send(&ClientMessage::SeatCycleLayout { seat })
+    }
+
     pub fn seat_set_repeat_rate(&self, seat: Seat, rate: i32, delay: i32) {
         self.send(&ClientMessage::SeatSetRepeatRate { seat, rate, delay })
     }
@@ -1440,6 +1855,50 @@ impl Client {
                     run_cb("switch event", &cb, event);
                 }
             }
+            ServerMessage::DesktopClick { seat, click } => {
+                let cb = self.on_desktop_click.borrow().get(&seat).cloned();
+                if let Some(cb) = cb {
+                    run_cb("desktop click", &cb, click);
+                }
+            }
+            ServerMessage::KeyboardLayoutChanged { seat, name } => {
+                let cb = self.on_keyboard_layout_changed.borrow().get(&seat).cloned();
+                if let Some(cb) = cb {
+                    run_cb("keyboard layout changed", &cb, name);
+                }
+            }
+            ServerMessage::IdleTime { seat, idle } => {
+                let cb = self.on_idle_time.borrow().get(&seat).cloned();
+                if let Some(cb) = cb {
+                    run_cb("idle time", &cb, idle);
+                }
+            }
+            ServerMessage::InvokeKeySequence { seat, keys } => {
+                let cb = self.key_sequences.borrow().get(&(seat, keys)).cloned();
+                if let Some(cb) = cb {
+                    run_cb("key sequence", &cb, ());
+                }
+            }
+            ServerMessage::HotCorner { seat, event } => {
+                let cb = self.on_hot_corner.borrow().get(&seat).cloned();
+                if let Some(cb) = cb {
+                    run_cb("hot corner", &cb, event);
+                }
+            }
+            ServerMessage::InvokePointerShortcut {
+                seat,
+                mods,
+                trigger,
+            } => {
+                let cb = self
+                    .pointer_shortcuts
+                    .borrow()
+                    .get(&(seat, mods, trigger))
+                    .cloned();
+                if let Some(cb) = cb {
+                    run_cb("pointer shortcut", &cb, ());
+                }
+            }
         }
     }
 
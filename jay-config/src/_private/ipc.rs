@@ -1,20 +1,22 @@
 use {
     crate::{
+        _private::{PollableId, WireMode},
         input::{
-            acceleration::AccelProfile, capability::Capability, FocusFollowsMouseMode, InputDevice,
-            Seat, SwitchEvent,
+            acceleration::AccelProfile, capability::Capability, DesktopClick,
+            FocusFollowsMouseMode, HotCornerEvent, InputDevice, OutputEdge, PointerEdgeBehavior,
+            PointerPosition, PointerTrigger, Seat, SwitchEvent,
         },
-        keyboard::{mods::Modifiers, syms::KeySym, Keymap},
+        keyboard::{mods::Modifiers, syms::KeySym, Keymap, ModifiedKeySym},
         logging::LogLevel,
         theme::{colors::Colorable, sized::Resizable, Color},
         timer::Timer,
         video::{
-            connector_type::ConnectorType, Connector, DrmDevice, Format, GfxApi, TearingMode,
-            Transform, VrrMode,
+            connector_type::ConnectorType, ColorMatrix, Connector, DrmDevice, Format, GfxApi,
+            OutputId, TearingMode, TitleBarPosition, Transform, VrrMode,
         },
-        Axis, Direction, PciId, Workspace,
-        _private::{PollableId, WireMode},
+        window::DecorationMode,
         xwayland::XScalingMode,
+        Axis, Direction, PciId, Workspace,
     },
     serde::{Deserialize, Serialize},
     std::time::Duration,
@@ -92,6 +94,31 @@ pub enum ServerMessage {
         input_device: InputDevice,
         event: SwitchEvent,
     },
+    DesktopClick {
+        seat: Seat,
+        click: DesktopClick,
+    },
+    KeyboardLayoutChanged {
+        seat: Seat,
+        name: String,
+    },
+    InvokeKeySequence {
+        seat: Seat,
+        keys: Vec<ModifiedKeySym>,
+    },
+    IdleTime {
+        seat: Seat,
+        idle: Duration,
+    },
+    HotCorner {
+        seat: Seat,
+        event: HotCornerEvent,
+    },
+    InvokePointerShortcut {
+        seat: Seat,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -527,6 +554,262 @@ pub enum ClientMessage<'a> {
     SetXScalingMode {
         mode: XScalingMode,
     },
+    ConnectorSetVblankCursorUpdates {
+        connector: Connector,
+        enabled: bool,
+    },
+    ConnectorSetUseHardwareCursor {
+        connector: Connector,
+        use_hardware_cursor: bool,
+    },
+    LocatePointer {
+        seat: Seat,
+    },
+    SetPointerLocatorDuration {
+        duration: Duration,
+    },
+    SeatZoomIn {
+        seat: Seat,
+    },
+    SeatZoomOut {
+        seat: Seat,
+    },
+    SeatResetZoom {
+        seat: Seat,
+    },
+    SetMaxZoom {
+        factor: f64,
+    },
+    SetColorFilter {
+        connector: Option<Connector>,
+        filter: Option<ColorMatrix>,
+    },
+    ConnectorSetInvertColors {
+        connector: Connector,
+        invert: bool,
+    },
+    SeatToggleInvertColors {
+        seat: Seat,
+    },
+    SetScaleOverride {
+        app_id: &'a str,
+        scale: Option<f64>,
+    },
+    SetDecorationMode {
+        app_id: &'a str,
+        mode: Option<DecorationMode>,
+    },
+    SetWindowBorderWidth {
+        app_id: &'a str,
+        width: Option<i32>,
+    },
+    SetWindowBorderColor {
+        app_id: &'a str,
+        colorable: Colorable,
+        color: Option<Color>,
+    },
+    SetShowFocusedOutput {
+        show: bool,
+    },
+    SeatGetKeyboardLayout {
+        seat: Seat,
+    },
+    AddKeySequence {
+        seat: Seat,
+        keys: Vec<ModifiedKeySym>,
+    },
+    RemoveKeySequence {
+        seat: Seat,
+        keys: Vec<ModifiedKeySym>,
+    },
+    SetKeySequenceTimeout {
+        seat: Seat,
+        timeout: Duration,
+    },
+    SetForwardAbortedKeySequences {
+        seat: Seat,
+        forward: bool,
+    },
+    SetShowKeySequenceHint {
+        seat: Seat,
+        show: bool,
+    },
+    SetSharedClipboard {
+        shared: bool,
+    },
+    SeatGetIdleTime {
+        seat: Seat,
+    },
+    SeatSetIdleTimeInterval {
+        seat: Seat,
+        interval: Duration,
+    },
+    ConnectorSetDefaultWorkspaceCapture {
+        connector: Connector,
+        capture: bool,
+    },
+    ConnectorSetTitleBarPosition {
+        connector: Connector,
+        position: TitleBarPosition,
+    },
+    SetBlockCapture {
+        app_id: &'a str,
+        block: bool,
+    },
+    SeatGetPointerPosition {
+        seat: Seat,
+    },
+    SetHotCornerSize {
+        seat: Seat,
+        size: i32,
+    },
+    SetHotCornerDwell {
+        seat: Seat,
+        dwell: Duration,
+    },
+    SetPointerEdgeBehavior {
+        edge: OutputEdge,
+        behavior: PointerEdgeBehavior,
+    },
+    ConnectorSetRenderScale {
+        connector: Connector,
+        scale: f64,
+    },
+    SetWorkspaceScrollInverted {
+        inverted: bool,
+    },
+    SetWorkspaceScrollThreshold {
+        threshold: i32,
+    },
+    SetWorkspaceScrollWrap {
+        wrap: bool,
+    },
+    SetOutputThrottle {
+        hz: f64,
+    },
+    SetOutputThrottleDelay {
+        delay: Duration,
+    },
+    SetWorkspaceScrollSkipEmpty {
+        skip_empty: bool,
+    },
+    ConnectorSetWorkspaceScrollWrap {
+        connector: Connector,
+        wrap: Option<bool>,
+    },
+    ConnectorSetWorkspaceScrollSkipEmpty {
+        connector: Connector,
+        skip_empty: Option<bool>,
+    },
+    SetWorkspaceOutputAffinity {
+        workspace: Workspace,
+        preferred: Option<OutputId>,
+        fallback: Option<OutputId>,
+    },
+    ConnectorSetSpan {
+        connector: Connector,
+        secondary: Option<Connector>,
+    },
+    ConnectorSetGameMode {
+        connector: Connector,
+        enabled: bool,
+    },
+    ConnectorSetVrrMinRefresh {
+        connector: Connector,
+        hz: Option<u32>,
+    },
+    ConnectorSetMaxScale {
+        connector: Connector,
+        scale: Option<f64>,
+    },
+    ConnectorSetMirrorSource {
+        connector: Connector,
+        source: Option<Connector>,
+    },
+    SetIdleGracePeriod {
+        period: Duration,
+    },
+    SeatSetIdleGracePeriod {
+        seat: Seat,
+        period: Option<Duration>,
+    },
+    SeatSetLayouts {
+        seat: Seat,
+        layouts: Vec<Keymap>,
+    },
+    SeatCycleLayout {
+        seat: Seat,
+    },
+    DeviceSetRepeatRate {
+        device: InputDevice,
+        rate: Option<(i32, i32)>,
+    },
+    SetFocusFollowsMouseDwell {
+        seat: Seat,
+        dwell: Duration,
+    },
+    SetWarpPointerOnFocus {
+        seat: Seat,
+        warp: bool,
+    },
+    SetShortcutsInhibitEscape {
+        seat: Seat,
+        escape: Option<ModifiedKeySym>,
+    },
+    SetZoomScrollModifiers {
+        seat: Seat,
+        mods: Modifiers,
+    },
+    SetWorkspaceSwipe {
+        seat: Seat,
+        fingers: u32,
+        inverted: bool,
+    },
+    GetSticky {
+        seat: Seat,
+    },
+    SetSticky {
+        seat: Seat,
+        sticky: bool,
+    },
+    SetDndThreshold {
+        seat: Seat,
+        threshold: i32,
+    },
+    SetMiddleClickPasteEnabled {
+        seat: Seat,
+        enabled: bool,
+    },
+    SetWhichKeyDelay {
+        seat: Seat,
+        delay: Option<Duration>,
+    },
+    AddPointerShortcut {
+        seat: Seat,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+    },
+    RemovePointerShortcut {
+        seat: Seat,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+    },
+    ConnectorHardwareCursorActive {
+        connector: Connector,
+    },
+    SetCursorTheme {
+        seat: Seat,
+        theme: Option<&'a str>,
+    },
+    SetHideCursorWhileTyping {
+        seat: Seat,
+        hide: bool,
+    },
+    SetHideCursorTimeout {
+        seat: Seat,
+        timeout: Duration,
+    },
+    ResetWindowRules,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -675,6 +958,21 @@ pub enum Response {
     GetSocketPath {
         path: String,
     },
+    GetKeyboardLayout {
+        name: String,
+    },
+    GetIdleTime {
+        idle: Duration,
+    },
+    GetPointerPosition {
+        position: PointerPosition,
+    },
+    GetSticky {
+        sticky: bool,
+    },
+    ConnectorHardwareCursorActive {
+        active: bool,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1,15 +1,16 @@
 //! Tools for configuring input devices.
 
 pub mod acceleration;
+pub mod button;
 pub mod capability;
 
 use {
     crate::{
+        _private::{ipc::WorkspaceSource, DEFAULT_SEAT_NAME},
         input::{acceleration::AccelProfile, capability::Capability},
         keyboard::{mods::Modifiers, Keymap},
-        Axis, Direction, ModifiedKeySym, Workspace,
-        _private::{ipc::WorkspaceSource, DEFAULT_SEAT_NAME},
         video::Connector,
+        Axis, Direction, ModifiedKeySym, Workspace,
     },
     serde::{Deserialize, Serialize},
     std::time::Duration,
@@ -35,6 +36,22 @@ impl InputDevice {
         get!().set_device_keymap(self, keymap)
     }
 
+    /// Sets the keyboard repeat rate of this device.
+    ///
+    /// While this device is the most recently used keyboard of its seat, this rate is used
+    /// instead of the repeat rate of the seat.
+    ///
+    /// The rate is in characters per second and the delay is in milliseconds. Negative
+    /// values are rejected. A rate of 0 disables key repeat.
+    pub fn set_repeat_rate(self, rate: i32, delay: i32) {
+        get!().set_device_repeat_rate(self, Some((rate, delay)))
+    }
+
+    /// Reverts to the repeat rate of the seat.
+    pub fn unset_repeat_rate(self) {
+        get!().set_device_repeat_rate(self, None)
+    }
+
     /// Returns whether the device has the specified capability.
     pub fn has_capability(self, cap: Capability) -> bool {
         get!(false).has_capability(self, cap)
@@ -57,7 +74,8 @@ impl InputDevice {
 
     /// Sets the acceleration speed of the device.
     ///
-    /// This corresponds to the libinput setting of the same name.
+    /// This corresponds to the libinput setting of the same name. The speed is clamped
+    /// to the range `[-1, 1]`.
     pub fn set_accel_speed(self, speed: f64) {
         get!().set_accel_speed(self, speed);
     }
@@ -205,6 +223,15 @@ impl Seat {
         get!().set_cursor_size(self, size)
     }
 
+    /// Sets the cursor theme used for cursors drawn by the compositor.
+    ///
+    /// If the theme is not installed, the default cursors are used instead.
+    ///
+    /// Default: `None` which uses the theme from the `XCURSOR_THEME` environment variable.
+    pub fn set_cursor_theme(self, theme: Option<&str>) {
+        get!().set_cursor_theme(self, theme)
+    }
+
     /// Creates a compositor-wide hotkey.
     ///
     /// The closure is invoked when the user presses the last key of the modified keysym.
@@ -257,6 +284,27 @@ impl Seat {
         get!().unbind(self, mod_sym.into())
     }
 
+    /// Creates a compositor-wide binding for a mouse button or scroll direction.
+    ///
+    /// The closure is invoked when the trigger occurs while exactly the modifiers `mods`
+    /// are pressed. The event is not forwarded to clients. For buttons, the matching
+    /// release is not forwarded either.
+    ///
+    /// CapsLock and NumLock are ignored during modifier evaluation.
+    pub fn bind_pointer<F: FnMut() + 'static>(
+        self,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+        f: F,
+    ) {
+        get!().bind_pointer(self, mods, trigger, f)
+    }
+
+    /// Removes a binding created with [`Seat::bind_pointer`].
+    pub fn unbind_pointer(self, mods: Modifiers, trigger: PointerTrigger) {
+        get!().unbind_pointer(self, mods, trigger)
+    }
+
     /// Moves the keyboard focus of the seat in the specified direction.
     pub fn focus(self, direction: Direction) {
         get!().focus(self, direction)
@@ -272,6 +320,18 @@ impl Seat {
         get!().seat_set_keymap(self, keymap)
     }
 
+    /// Sets the keymaps that [`Seat::cycle_layout`] switches between.
+    ///
+    /// The first keymap becomes the keymap of the seat.
+    pub fn set_layouts(self, layouts: &[Keymap]) {
+        get!().seat_set_layouts(self, layouts)
+    }
+
+    /// Switches the seat to the next keymap set with [`Seat::set_layouts`].
+    pub fn cycle_layout(self) {
+        get!().seat_cycle_layout(self)
+    }
+
     /// Returns the repeat rate of the seat.
     ///
     /// The returned tuple is `(rate, delay)` where `rate` is the number of times keys repeat per second
@@ -351,6 +411,24 @@ impl Seat {
         get!().toggle_floating(self);
     }
 
+    /// Returns whether the currently focused window is sticky.
+    pub fn get_sticky(self) -> bool {
+        get!().get_sticky(self)
+    }
+
+    /// Sets whether the currently focused window is sticky.
+    ///
+    /// Sticky windows are shown on every workspace of their output. They follow the
+    /// workspace changes of the output only while they are floating.
+    pub fn set_sticky(self, sticky: bool) {
+        get!().set_sticky(self, sticky);
+    }
+
+    /// Toggles whether the currently focused window is sticky.
+    pub fn toggle_sticky(self) {
+        get!().toggle_sticky(self);
+    }
+
     /// Returns the workspace that is currently active on the output that contains the seat's
     /// cursor.
     ///
@@ -392,6 +470,100 @@ impl Seat {
         get!().disable_pointer_constraint(self)
     }
 
+    /// Briefly highlights the position of the pointer of this seat.
+    ///
+    /// The color and size of the highlight can be configured with
+    /// [`POINTER_LOCATOR_COLOR`](crate::theme::colors::POINTER_LOCATOR_COLOR) and
+    /// [`POINTER_LOCATOR_SIZE`](crate::theme::sized::POINTER_LOCATOR_SIZE). The duration
+    /// can be configured with [`set_pointer_locator_duration`].
+    pub fn locate_pointer(self) {
+        get!().locate_pointer(self)
+    }
+
+    /// Magnifies the output containing the pointer of this seat.
+    ///
+    /// While an output is magnified, the area around the pointer is scaled to fill the
+    /// output. The magnification can be limited with [`set_max_zoom`].
+    pub fn zoom_in(self) {
+        get!().seat_zoom_in(self)
+    }
+
+    /// Reduces the magnification of the output containing the pointer of this seat.
+    pub fn zoom_out(self) {
+        get!().seat_zoom_out(self)
+    }
+
+    /// Removes the magnification of the output containing the pointer of this seat.
+    pub fn reset_zoom(self) {
+        get!().seat_reset_zoom(self)
+    }
+
+    /// Sets the modifiers that change the magnification when scrolling.
+    ///
+    /// While exactly these modifiers are pressed, vertical scrolling magnifies or reduces
+    /// the magnification of the output containing the pointer instead of being sent to
+    /// the window under the pointer.
+    ///
+    /// If the modifiers are empty, scrolling never changes the magnification. This is
+    /// the default.
+    pub fn set_zoom_scroll_modifiers(self, mods: Modifiers) {
+        get!().set_zoom_scroll_modifiers(self, mods)
+    }
+
+    /// Sets the number of fingers of touchpad swipes that switch workspaces.
+    ///
+    /// Horizontal swipes with exactly this number of fingers switch between the
+    /// workspaces of the output containing the pointer instead of being sent to the
+    /// window under the pointer. Moving the fingers to the left shows the next
+    /// workspace. If `inverted` is true, the direction is inverted.
+    ///
+    /// The workspaces switch while the fingers move. Short swipes switch a single
+    /// workspace when the fingers are lifted.
+    ///
+    /// If `fingers` is 0, swipes never switch workspaces. This is the default.
+    pub fn set_workspace_swipe(self, fingers: u32, inverted: bool) {
+        get!().set_workspace_swipe(self, fingers, inverted)
+    }
+
+    /// Sets the distance in pixels the pointer has to move before a drag-and-drop
+    /// operation starts.
+    ///
+    /// Until the distance is reached, the button press is treated as a normal click.
+    ///
+    /// The default is 0.
+    pub fn set_dnd_threshold(self, threshold: i32) {
+        get!().set_dnd_threshold(self, threshold)
+    }
+
+    /// Sets whether pressing the middle mouse button pastes the primary selection.
+    ///
+    /// If this is disabled, the primary selection is hidden from the client under the
+    /// pointer while it handles the middle button. The button itself is still sent to
+    /// the client.
+    ///
+    /// The default is `true`.
+    pub fn set_middle_click_paste_enabled(self, enabled: bool) {
+        get!().set_middle_click_paste_enabled(self, enabled)
+    }
+
+    /// Sets how long modifiers must be held before the shortcuts available under them
+    /// are announced.
+    ///
+    /// The shortcuts are sent to `jay_which_key` objects of privileged clients which can
+    /// use them to display an overlay. `None` disables this.
+    ///
+    /// The default is `None`.
+    pub fn set_which_key_delay(self, delay: Option<Duration>) {
+        get!().set_which_key_delay(self, delay)
+    }
+
+    /// Toggles the color inversion of the output containing the pointer of this seat.
+    ///
+    /// See [Connector::set_invert_colors](crate::video::Connector::set_invert_colors).
+    pub fn toggle_invert_colors(self) {
+        get!().seat_toggle_invert_colors(self)
+    }
+
     /// Moves the currently focused workspace to another output.
     pub fn move_to_output(self, connector: Connector) {
         get!().move_to_output(WorkspaceSource::Seat(self), connector);
@@ -422,6 +594,63 @@ impl Seat {
         get!().set_focus_follows_mouse_mode(self, mode);
     }
 
+    /// Sets the time the pointer has to rest in a window before focus-follows-mouse moves
+    /// the keyboard focus to it.
+    ///
+    /// The default is 0.
+    pub fn set_focus_follows_mouse_dwell(self, dwell: Duration) {
+        get!().set_focus_follows_mouse_dwell(self, dwell);
+    }
+
+    /// Sets whether the pointer is moved to the center of a window when the window
+    /// receives the keyboard focus.
+    ///
+    /// The pointer is not moved if it is already inside the window or if it is grabbed
+    /// or constrained.
+    ///
+    /// The default is `false`.
+    pub fn set_warp_pointer_on_focus(self, warp: bool) {
+        get!().set_warp_pointer_on_focus(self, warp);
+    }
+
+    /// Sets whether the cursor is hidden while the user is typing.
+    ///
+    /// The cursor is hidden when a key is pressed and shown again when the pointer
+    /// moves. The cursor is not hidden during drag-and-drop or other pointer grabs.
+    ///
+    /// The default is `false`.
+    pub fn set_hide_cursor_while_typing(self, hide: bool) {
+        get!().set_hide_cursor_while_typing(self, hide);
+    }
+
+    /// Sets the time after which the cursor is hidden if the pointer is not used.
+    ///
+    /// The cursor is shown again on the next input event. If
+    /// [`Seat::set_hide_cursor_while_typing`] is enabled, key presses do not show the
+    /// cursor. The cursor is not hidden during drag-and-drop or other pointer grabs.
+    ///
+    /// Setting the timeout to 0 disables this. The default is 0.
+    pub fn set_hide_cursor_timeout(self, timeout: Duration) {
+        get!().set_hide_cursor_timeout(self, timeout);
+    }
+
+    /// Sets the key that toggles keyboard-shortcut inhibition of the focused window.
+    ///
+    /// Applications such as remote desktop clients and virtual machines can request
+    /// that the compositor does not handle any shortcuts while they have the keyboard
+    /// focus. Pressing this key disables or re-enables such a request. The key is
+    /// never forwarded to the application.
+    ///
+    /// Such requests are ignored while no key is set. By default, no such key is set.
+    pub fn set_shortcuts_inhibit_escape<T: Into<ModifiedKeySym>>(self, mod_sym: T) {
+        get!().set_shortcuts_inhibit_escape(self, Some(mod_sym.into()));
+    }
+
+    /// Unsets the key set by [`Seat::set_shortcuts_inhibit_escape`].
+    pub fn unset_shortcuts_inhibit_escape(self) {
+        get!().set_shortcuts_inhibit_escape(self, None);
+    }
+
     /// Enables or disable window management mode.
     ///
     /// In window management mode, floating windows can be moved by pressing the left
@@ -452,6 +681,134 @@ impl Seat {
             });
         });
     }
+
+    /// Sets a callback to run when a mouse button is pressed on the desktop.
+    ///
+    /// The callback is only invoked if the pointer is over the background of an output,
+    /// i.e. not over a window, a layer surface, or the bar.
+    pub fn on_desktop_click<F: FnMut(DesktopClick) + 'static>(self, f: F) {
+        get!().on_desktop_click(self, f)
+    }
+
+    /// Returns the name of the active keyboard layout of the seat, e.g. `English (US)`.
+    ///
+    /// If the layout does not have a name, the index of the layout in the keymap is
+    /// returned instead.
+    pub fn keyboard_layout(self) -> String {
+        get!(String::new()).seat_get_keyboard_layout(self)
+    }
+
+    /// Sets a callback to run when the active keyboard layout of the seat changes.
+    ///
+    /// The callback receives the new layout name as returned by
+    /// [`Seat::keyboard_layout`].
+    pub fn on_keyboard_layout_changed<F: FnMut(String) + 'static>(self, f: F) {
+        get!().on_keyboard_layout_changed(self, f)
+    }
+
+    /// Returns the time since the last input event of the seat.
+    ///
+    /// This uses the same activity tracking as the idle timeout.
+    pub fn idle_time(self) -> Duration {
+        get!(Duration::ZERO).seat_get_idle_time(self)
+    }
+
+    /// Returns the position of the pointer of the seat.
+    ///
+    /// Returns `None` if the seat does not exist.
+    pub fn pointer_position(self) -> Option<PointerPosition> {
+        get!(None).seat_get_pointer_position(self)
+    }
+
+    /// Sets a callback that periodically receives the time since the last input event of
+    /// the seat.
+    ///
+    /// The callback is invoked every `interval`. If an input event occurs after the
+    /// callback has been invoked, the callback is invoked again immediately with a
+    /// duration of zero.
+    ///
+    /// If `interval` is zero, the callback is removed.
+    pub fn on_idle_time<F: FnMut(Duration) + 'static>(self, interval: Duration, f: F) {
+        get!().on_idle_time(self, interval, f)
+    }
+
+    /// Overrides the idle grace period of this seat.
+    ///
+    /// The compositor only goes idle once every seat has been idle for the idle timeout
+    /// plus its grace period.
+    ///
+    /// `None` uses the value set with
+    /// [`set_idle_grace_period`](crate::set_idle_grace_period).
+    pub fn set_idle_grace_period(self, period: Option<Duration>) {
+        get!().seat_set_idle_grace_period(self, period)
+    }
+
+    /// Creates a key sequence.
+    ///
+    /// The closure is invoked when the user presses the keys of the sequence one after
+    /// another, for example `CTRL | SYM_a` followed by `SYM_c`. The sequence must
+    /// contain at least two keys. Like in [`Seat::bind`], the keysyms are calculated
+    /// without modifiers applied.
+    ///
+    /// While a sequence is in progress, key presses are not forwarded to clients. If the
+    /// next key does not continue any sequence or if no key is pressed within the
+    /// timeout, the sequence is aborted.
+    ///
+    /// Key sequences take precedence over hotkeys created with [`Seat::bind`].
+    pub fn bind_sequence<F: FnMut() + 'static>(self, keys: &[ModifiedKeySym], f: F) {
+        get!().bind_sequence(self, keys, f)
+    }
+
+    /// Removes a key sequence.
+    pub fn unbind_sequence(self, keys: &[ModifiedKeySym]) {
+        get!().unbind_sequence(self, keys)
+    }
+
+    /// Sets the time after which an incomplete key sequence is aborted.
+    ///
+    /// The default is 1 second.
+    pub fn set_key_sequence_timeout(self, timeout: Duration) {
+        get!().set_key_sequence_timeout(self, timeout)
+    }
+
+    /// Sets whether a key that aborts a key sequence is forwarded to clients.
+    ///
+    /// The default is `false`.
+    pub fn set_forward_aborted_key_sequences(self, forward: bool) {
+        get!().set_forward_aborted_key_sequences(self, forward)
+    }
+
+    /// Sets whether the keys of an incomplete key sequence are shown in the status
+    /// text of the bar.
+    ///
+    /// The default is `true`.
+    pub fn set_show_key_sequence_hint(self, show: bool) {
+        get!().set_show_key_sequence_hint(self, show)
+    }
+
+    /// Sets the size of the hot corners of outputs in logical pixels.
+    ///
+    /// A hot corner is a square in a corner of an output. If the pointer rests in a hot
+    /// corner for the dwell time, the callback set via [`Seat::on_hot_corner`] is invoked.
+    ///
+    /// Setting the size to 0 disables hot corners. The default is 2.
+    pub fn set_hot_corner_size(self, size: i32) {
+        get!().set_hot_corner_size(self, size)
+    }
+
+    /// Sets the time the pointer has to rest in a hot corner before the corner is triggered.
+    ///
+    /// The default is 250 milliseconds.
+    pub fn set_hot_corner_dwell(self, dwell: Duration) {
+        get!().set_hot_corner_dwell(self, dwell)
+    }
+
+    /// Sets a callback to run when a hot corner is triggered.
+    ///
+    /// The callback is invoked at most once each time the pointer enters a hot corner.
+    pub fn on_hot_corner<F: FnMut(HotCornerEvent) + 'static>(self, f: F) {
+        get!().on_hot_corner(self, f)
+    }
 }
 
 /// A focus-follows-mouse mode.
@@ -531,6 +888,40 @@ pub fn set_double_click_distance(distance: i32) {
     get!().set_double_click_distance(distance)
 }
 
+/// Sets whether all seats share a single clipboard.
+///
+/// By default, each seat has its own clipboard and primary selection and clients only
+/// see the selections of the seats that have focused them. If this is enabled, setting
+/// the clipboard or primary selection of one seat sets it for all seats.
+///
+/// The default is `false`.
+pub fn set_shared_clipboard(shared: bool) {
+    get!().set_shared_clipboard(shared)
+}
+
+/// Sets how long the pointer locator is shown after [`Seat::locate_pointer`] has been
+/// called.
+///
+/// The default is 1 second.
+pub fn set_pointer_locator_duration(duration: Duration) {
+    get!().set_pointer_locator_duration(duration)
+}
+
+/// Sets what happens when the pointer moves past an edge of an output and no other output
+/// is directly adjacent to that edge.
+///
+/// The default is [`PointerEdgeBehavior::Clamp`] for all edges.
+pub fn set_pointer_edge_behavior(edge: OutputEdge, behavior: PointerEdgeBehavior) {
+    get!().set_pointer_edge_behavior(edge, behavior)
+}
+
+/// Sets the maximum magnification factor that can be reached with [`Seat::zoom_in`].
+///
+/// The default is `8.0`.
+pub fn set_max_zoom(factor: f64) {
+    get!().set_max_zoom(factor)
+}
+
 /// Disables the creation of a default seat.
 ///
 /// Unless this function is called at startup of the compositor, a seat called `default`
@@ -565,6 +956,110 @@ pub enum SwitchEvent {
     ConvertedToTablet,
 }
 
+/// A mouse button press on the desktop.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct DesktopClick {
+    /// The output on which the button was pressed.
+    pub connector: Connector,
+    /// The button that was pressed. See the [`button`] module.
+    pub button: u32,
+    /// The x coordinate of the pointer relative to the output.
+    pub x: i32,
+    /// The y coordinate of the pointer relative to the output.
+    pub y: i32,
+}
+
+/// An edge of an output.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum OutputEdge {
+    /// The left edge.
+    Left,
+    /// The right edge.
+    Right,
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+}
+
+/// The behavior of the pointer when it moves past an edge of an output that has no
+/// adjacent output.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+pub enum PointerEdgeBehavior {
+    /// The pointer stops at the edge of the closest output.
+    #[default]
+    Clamp,
+    /// If no output lies beyond the edge, the pointer appears on the opposite side of the
+    /// layout. For example, leaving the right edge of the rightmost output moves the
+    /// pointer to the left edge of the leftmost output.
+    Wrap,
+    /// The pointer moves to the nearest output in the direction of the motion, even if
+    /// that output is not directly adjacent.
+    PushThrough,
+}
+
+/// A corner of an output.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum HotCorner {
+    /// The top-left corner.
+    TopLeft,
+    /// The top-right corner.
+    TopRight,
+    /// The bottom-left corner.
+    BottomLeft,
+    /// The bottom-right corner.
+    BottomRight,
+}
+
+/// A pointer event that can be bound with [`Seat::bind_pointer`].
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum PointerTrigger {
+    /// A button press.
+    ///
+    /// See the constants in the [`button`] module.
+    Button(u32),
+    /// Scrolling up.
+    ScrollUp,
+    /// Scrolling down.
+    ScrollDown,
+    /// Scrolling left.
+    ScrollLeft,
+    /// Scrolling right.
+    ScrollRight,
+}
+
+/// A hot corner that was triggered.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct HotCornerEvent {
+    /// The output whose corner was triggered.
+    pub connector: Connector,
+    /// The corner that was triggered.
+    pub corner: HotCorner,
+}
+
+/// The position of the pointer of a seat.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct PointerPosition {
+    /// The x coordinate of the pointer in the global compositor space.
+    pub x: f64,
+    /// The y coordinate of the pointer in the global compositor space.
+    pub y: f64,
+    /// The output that contains the pointer.
+    pub connector: Connector,
+    /// The x coordinate of the pointer relative to the top-left corner of the output.
+    pub output_x: f64,
+    /// The y coordinate of the pointer relative to the top-left corner of the output.
+    pub output_y: f64,
+    /// The logical width of the output.
+    ///
+    /// This takes the scale and transform of the output into account.
+    pub output_width: i32,
+    /// The logical height of the output.
+    ///
+    /// This takes the scale and transform of the output into account.
+    pub output_height: i32,
+}
+
 /// Enables or disables the unauthenticated libei socket.
 ///
 /// Even if the socket is disabled, application can still request access via the portal.
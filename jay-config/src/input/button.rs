@@ -0,0 +1,9 @@
+//! Constants specifying mouse buttons.
+//!
+//! These are the button codes used by the linux kernel.
+
+pub const BTN_LEFT: u32 = 0x110;
+pub const BTN_RIGHT: u32 = 0x111;
+pub const BTN_MIDDLE: u32 = 0x112;
+pub const BTN_SIDE: u32 = 0x113;
+pub const BTN_EXTRA: u32 = 0x114;
@@ -44,7 +44,11 @@
 #![warn(unsafe_op_in_unsafe_fn)]
 
 use {
-    crate::{_private::ipc::WorkspaceSource, keyboard::ModifiedKeySym, video::Connector},
+    crate::{
+        _private::ipc::WorkspaceSource,
+        keyboard::ModifiedKeySym,
+        video::{Connector, OutputId},
+    },
     serde::{Deserialize, Serialize},
     std::{
         fmt::{Debug, Display, Formatter},
@@ -67,6 +71,7 @@ pub mod tasks;
 pub mod theme;
 pub mod timer;
 pub mod video;
+pub mod window;
 pub mod xwayland;
 
 /// A planar direction.
@@ -171,6 +176,22 @@ impl Workspace {
     pub fn move_to_output(self, output: Connector) {
         get!().move_to_output(WorkspaceSource::Explicit(self), output);
     }
+
+    /// Sets the output on which this workspace is shown.
+    ///
+    /// The workspace is placed on the preferred output if it is connected, otherwise on the
+    /// fallback output if it is connected, otherwise on the focused output. Placement is
+    /// re-evaluated whenever outputs are connected or disconnected.
+    ///
+    /// This also applies to workspaces that are created after this function is called.
+    pub fn set_output_affinity(self, preferred: OutputId, fallback: Option<OutputId>) {
+        get!().set_workspace_output_affinity(self, Some(preferred), fallback);
+    }
+
+    /// Removes the output affinity set with [`Workspace::set_output_affinity`].
+    pub fn clear_output_affinity(self) {
+        get!().set_workspace_output_affinity(self, None, None);
+    }
 }
 
 /// Returns the workspace with the given name.
@@ -228,6 +249,17 @@ pub fn set_idle(timeout: Option<Duration>) {
     get!().set_idle(timeout.unwrap_or_default())
 }
 
+/// Configures the idle grace period.
+///
+/// After the idle timeout expires, the compositor waits for the grace period before
+/// going idle. Any input during the grace period cancels going idle. A grace period of
+/// zero disables this.
+///
+/// The default is zero.
+pub fn set_idle_grace_period(period: Duration) {
+    get!().set_idle_grace_period(period)
+}
+
 /// Enables or disables explicit sync.
 ///
 /// Calling this after the compositor has started has no effect.
@@ -237,6 +269,17 @@ pub fn set_explicit_sync_enabled(enabled: bool) {
     get!().set_explicit_sync_enabled(enabled);
 }
 
+/// Enables or disables the focused output indicator.
+///
+/// If this is enabled, the line below the bar of the output that contains the pointer of
+/// a seat is drawn in the
+/// [FOCUSED_OUTPUT_INDICATOR_COLOR](theme::colors::FOCUSED_OUTPUT_INDICATOR_COLOR).
+///
+/// The default is `false`.
+pub fn set_show_focused_output(show: bool) {
+    get!().set_show_focused_output(show);
+}
+
 /// Enables or disables dragging of tiles and workspaces.
 ///
 /// The default is `true`.
@@ -250,3 +293,68 @@ pub fn set_ui_drag_enabled(enabled: bool) {
 pub fn set_ui_drag_threshold(threshold: i32) {
     get!().set_ui_drag_threshold(threshold);
 }
+
+/// Sets whether scrolling over the bar switches workspaces in the opposite direction.
+///
+/// By default, scrolling down switches to the next workspace.
+///
+/// The default is `false`.
+pub fn set_workspace_scroll_inverted(inverted: bool) {
+    get!().set_workspace_scroll_inverted(inverted);
+}
+
+/// Sets the number of scroll steps over the bar that switch to the next workspace.
+///
+/// Higher values make workspace switching less sensitive. Values less than 1 are
+/// treated as 1.
+///
+/// The default is `1`.
+pub fn set_workspace_scroll_threshold(threshold: i32) {
+    get!().set_workspace_scroll_threshold(threshold);
+}
+
+/// Sets whether scrolling over the bar wraps around from the last workspace to the first
+/// and vice versa.
+///
+/// This applies to all outputs. Use [`Connector::set_workspace_scroll_wrap`] to override
+/// it for a single output.
+///
+/// The default is `false`.
+///
+/// [`Connector::set_workspace_scroll_wrap`]: crate::video::Connector::set_workspace_scroll_wrap
+pub fn set_workspace_scroll_wrap(wrap: bool) {
+    get!().set_workspace_scroll_wrap(wrap);
+}
+
+/// Sets whether scrolling over the bar skips workspaces that contain no windows.
+///
+/// This applies to all outputs. Use [`Connector::set_workspace_scroll_skip_empty`] to
+/// override it for a single output.
+///
+/// The default is `false`.
+///
+/// [`Connector::set_workspace_scroll_skip_empty`]: crate::video::Connector::set_workspace_scroll_skip_empty
+pub fn set_workspace_scroll_skip_empty(skip_empty: bool) {
+    get!().set_workspace_scroll_skip_empty(skip_empty);
+}
+
+/// Configures throttling of outputs that are neither focused nor captured.
+///
+/// Once an output has neither been focused nor had its contents change for the time
+/// configured with [`set_output_throttle_delay`], it is presented at most `hz` times per
+/// second. The output returns to its full refresh rate once its contents change, a seat
+/// moves to it, or a client starts capturing it.
+///
+/// `None` disables throttling. This is the default.
+pub fn set_output_throttle(hz: Option<f64>) {
+    get!().set_output_throttle(hz.unwrap_or_default())
+}
+
+/// Sets the time after which an unfocused output is throttled.
+///
+/// See [`set_output_throttle`].
+///
+/// The default is 5 seconds.
+pub fn set_output_throttle_delay(delay: Duration) {
+    get!().set_output_throttle_delay(delay)
+}
@@ -261,6 +261,29 @@ pub mod colors {
         ///
         /// Default: `#9d28c67f`.
         const 15 => HIGHLIGHT_COLOR,
+        /// The color of the pointer locator.
+        ///
+        /// Default: `#ff8c00`.
+        const 16 => POINTER_LOCATOR_COLOR,
+        /// The color of the border around a focused window.
+        ///
+        /// Default: `#4c7899`.
+        const 17 => FOCUSED_WINDOW_BORDER_COLOR,
+        /// The color of the border around an unfocused window.
+        ///
+        /// Default: `#333333`.
+        const 18 => UNFOCUSED_WINDOW_BORDER_COLOR,
+        /// The color of the border around a window that has requested attention.
+        ///
+        /// Default: `#900000`.
+        const 19 => ATTENTION_REQUESTED_WINDOW_BORDER_COLOR,
+        /// The color of the line below the bar of the focused output.
+        ///
+        /// This is only used if the indicator has been enabled with
+        /// [`set_show_focused_output`](crate::set_show_focused_output).
+        ///
+        /// Default: `#4c7899`.
+        const 20 => FOCUSED_OUTPUT_INDICATOR_COLOR,
     }
 
     /// Sets the color of GUI element.
@@ -306,11 +329,24 @@ pub mod sized {
     sizes! {
         /// The height of window titles.
         ///
+        /// If this is 0, the bar is hidden completely.
+        ///
         /// Default: 17
         const 01 => TITLE_HEIGHT,
         /// The width of borders between windows.
         ///
         /// Default: 4
         const 02 => BORDER_WIDTH,
+        /// The radius of the pointer locator.
+        ///
+        /// Default: 100
+        const 03 => POINTER_LOCATOR_SIZE,
+        /// The width of the border drawn around each window.
+        ///
+        /// The border is drawn inside the area allocated to the window and reduces the
+        /// size of the window contents. A width of 0 disables the border.
+        ///
+        /// Default: 0
+        const 04 => WINDOW_BORDER_WIDTH,
     }
 }
@@ -2,6 +2,7 @@
 
 use {
     crate::{
+        _private::WireMode,
         video::connector_type::{
             ConnectorType, CON_9PIN_DIN, CON_COMPONENT, CON_COMPOSITE, CON_DISPLAY_PORT, CON_DPI,
             CON_DSI, CON_DVIA, CON_DVID, CON_DVII, CON_EDP, CON_EMBEDDED_WINDOW, CON_HDMIA,
@@ -9,7 +10,6 @@ use {
             CON_VIRTUAL, CON_WRITEBACK,
         },
         PciId,
-        _private::WireMode,
     },
     serde::{Deserialize, Serialize},
     std::{str::FromStr, time::Duration},
@@ -97,6 +97,17 @@ impl Connector {
         get!().connector_set_scale(self, scale);
     }
 
+    /// Sets the maximum scale of the currently connected monitor.
+    ///
+    /// Scales set via [`Connector::set_scale`] that are larger than this value are
+    /// clamped. `None` removes the limit.
+    pub fn set_max_scale(self, scale: Option<f64>) {
+        if !self.exists() {
+            return;
+        }
+        get!().connector_set_max_scale(self, scale);
+    }
+
     /// Returns the connector type.
     pub fn ty(self) -> ConnectorType {
         if !self.exists() {
@@ -263,6 +274,18 @@ impl Connector {
         get!().set_vrr_cursor_hz(Some(self), hz)
     }
 
+    /// Sets the minimum refresh rate while VRR is active.
+    ///
+    /// If no frame has been presented for the corresponding duration, the compositor
+    /// presents a frame even if nothing has changed. This can reduce flicker on panels
+    /// whose brightness depends on the refresh rate.
+    ///
+    /// The value is clamped to the refresh rate of the current mode. `None` removes the
+    /// floor.
+    pub fn set_vrr_min_refresh(self, hz: Option<u32>) {
+        get!().connector_set_vrr_min_refresh(self, hz);
+    }
+
     /// Sets the tearing mode.
     pub fn set_tearing_mode(self, mode: TearingMode) {
         get!().set_tearing_mode(Some(self), mode)
@@ -272,6 +295,171 @@ impl Connector {
     pub fn set_format(self, format: Format) {
         get!().connector_set_format(self, format);
     }
+
+    /// Sets the factor by which the internal render resolution is reduced.
+    ///
+    /// The output is rendered at the mode size multiplied by this factor and upscaled to the
+    /// mode during scanout. This trades sharpness for lower GPU load. The factor is distinct
+    /// from the scale set with [`Connector::set_scale`] and does not affect the logical size
+    /// of the output. Scaling is performed by the display hardware, which must support it.
+    ///
+    /// The factor must be in the range `0.1..=1.0`. The default is `1.0`.
+    pub fn set_render_scale(self, scale: f64) {
+        get!().connector_set_render_scale(self, scale);
+    }
+
+    /// Sets whether scrolling over the bar of this output wraps around from the last
+    /// workspace to the first and vice versa.
+    ///
+    /// `None` uses the value set with
+    /// [`set_workspace_scroll_wrap`](crate::set_workspace_scroll_wrap). This is the
+    /// default.
+    pub fn set_workspace_scroll_wrap(self, wrap: Option<bool>) {
+        get!().connector_set_workspace_scroll_wrap(self, wrap);
+    }
+
+    /// Sets whether scrolling over the bar of this output skips workspaces that contain no
+    /// windows.
+    ///
+    /// `None` uses the value set with
+    /// [`set_workspace_scroll_skip_empty`](crate::set_workspace_scroll_skip_empty). This
+    /// is the default.
+    pub fn set_workspace_scroll_skip_empty(self, skip_empty: Option<bool>) {
+        get!().connector_set_workspace_scroll_skip_empty(self, skip_empty);
+    }
+
+    /// Combines this output and `secondary` into a single logical output.
+    ///
+    /// The combined output has the position of this output and `secondary` shows its
+    /// right half. `secondary` is no longer available as a separate output and its
+    /// workspaces are moved to this output.
+    ///
+    /// Both outputs must use the same mode, scale, and transform. Changing any of them
+    /// or disconnecting one of the outputs dissolves the span.
+    pub fn span_with(self, secondary: Connector) {
+        get!().connector_set_span(self, Some(secondary));
+    }
+
+    /// Dissolves the span that this output is part of.
+    ///
+    /// See [`Connector::span_with`].
+    pub fn unspan(self) {
+        get!().connector_set_span(self, None);
+    }
+
+    /// Makes this output show the contents of `source`.
+    ///
+    /// The contents are scaled to fit this output while preserving their aspect ratio.
+    /// While mirroring, this output is no longer available as a separate output and its
+    /// workspaces are moved to `source`. Disconnecting one of the outputs stops
+    /// mirroring.
+    ///
+    /// `None` stops mirroring.
+    pub fn set_mirror_source(self, source: Option<Connector>) {
+        get!().connector_set_mirror_source(self, source);
+    }
+
+    /// Enables or disables game mode.
+    ///
+    /// While game mode is enabled and a single fullscreen application is displayed that
+    /// is eligible for direct scanout, the compositor stops rendering layer-shell
+    /// surfaces and updating the bar on this output. Tearing and VRR are unaffected.
+    ///
+    /// The default is `false`.
+    pub fn set_game_mode(self, enabled: bool) {
+        get!().connector_set_game_mode(self, enabled);
+    }
+
+    /// Sets whether hardware cursor updates are coalesced to once per vblank.
+    ///
+    /// By default, the cursor plane is updated immediately when the cursor moves. On some
+    /// drivers this causes stutter. Enabling this option trades up to one frame of cursor
+    /// latency for smoothness. It has no effect on software cursors.
+    ///
+    /// The default is `false`.
+    pub fn set_vblank_cursor_updates(self, enabled: bool) {
+        get!().connector_set_vblank_cursor_updates(self, enabled);
+    }
+
+    /// Sets whether the hardware cursor is used on this connector.
+    ///
+    /// If this is `false`, the cursor is always composited into the frame. This can be used
+    /// to work around drivers that render hardware cursors incorrectly.
+    ///
+    /// The default is `true`.
+    pub fn use_hardware_cursor(self, use_hardware_cursor: bool) {
+        get!().connector_set_use_hardware_cursor(self, use_hardware_cursor);
+    }
+
+    /// Returns whether the cursor is currently displayed on a hardware cursor plane.
+    ///
+    /// This is `false` if the hardware cursor has been disabled, if the connector does
+    /// not support hardware cursors, or if the output has to render the cursor in
+    /// software, for example while it is zoomed.
+    pub fn hardware_cursor_active(self) -> bool {
+        if !self.exists() {
+            return false;
+        }
+        get!(false).connector_hardware_cursor_active(self)
+    }
+
+    /// Sets the color filter.
+    ///
+    /// See [set_color_filter] for details.
+    pub fn set_color_filter(self, filter: Option<ColorMatrix>) {
+        get!().set_color_filter(Some(self), filter)
+    }
+
+    /// Sets whether the colors of this connector are inverted.
+    ///
+    /// The inversion is applied after the color filter. Like the color filter, it is
+    /// contained in screencasts and screencopies of the whole output but not in
+    /// screenshots and window captures. The hardware cursor is disabled while the
+    /// colors are inverted.
+    ///
+    /// The default is `false`.
+    pub fn set_invert_colors(self, invert: bool) {
+        get!().connector_set_invert_colors(self, invert);
+    }
+
+    /// Sets whether workspaces on this connector can be captured by default.
+    ///
+    /// This overrides [set_default_workspace_capture](crate::set_default_workspace_capture)
+    /// for workspaces created on this connector. The setting is also applied to the
+    /// workspaces that are currently on this connector.
+    pub fn set_default_workspace_capture(self, capture: bool) {
+        get!().connector_set_default_workspace_capture(self, capture);
+    }
+
+    /// Sets the edge of the connector along which the bar is shown.
+    ///
+    /// The default is [`TitleBarPosition::Top`].
+    pub fn set_title_bar_position(self, position: TitleBarPosition) {
+        get!().connector_set_title_bar_position(self, position);
+    }
+
+    /// Returns the stable identifier of the output connected to this connector.
+    pub fn output_id(self) -> OutputId {
+        OutputId {
+            connector: self.name(),
+            manufacturer: self.manufacturer(),
+            model: self.model(),
+            serial_number: self.serial_number(),
+        }
+    }
+}
+
+/// A stable identifier of an output.
+///
+/// Unlike [`Connector`], this identifier stays the same when the output is disconnected and
+/// reconnected, even if it is reconnected to a different connector. The connector name is
+/// only used to distinguish outputs if the serial number is empty.
+#[derive(Serialize, Deserialize, Clone, Debug, Hash, Eq, PartialEq, Default)]
+pub struct OutputId {
+    pub connector: String,
+    pub manufacturer: String,
+    pub model: String,
+    pub serial_number: String,
 }
 
 /// Returns all available DRM devices.
@@ -566,6 +754,16 @@ pub enum Transform {
     FlipRotate270,
 }
 
+/// The edge of an output along which the bar is shown.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub enum TitleBarPosition {
+    /// The bar is shown at the top of the output.
+    #[default]
+    Top,
+    /// The bar is shown at the bottom of the output.
+    Bottom,
+}
+
 /// The VRR mode of a connector.
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
 pub struct VrrMode(pub u32);
@@ -581,6 +779,50 @@ impl VrrMode {
     pub const VARIANT_2: Self = Self(3);
     /// VRR is enabled when a single game or video is displayed fullscreen.
     pub const VARIANT_3: Self = Self(4);
+
+    const CONTENT_TYPE: u32 = 1 << 8;
+    const CONTENT_TYPE_PHOTO: u32 = 1 << 0;
+    const CONTENT_TYPE_VIDEO: u32 = 1 << 1;
+    const CONTENT_TYPE_GAME: u32 = 1 << 2;
+
+    /// VRR is enabled when a single application is displayed fullscreen and the
+    /// application has set one of the selected content types.
+    ///
+    /// `VrrMode::content_type(false, true, true)` returns [`VrrMode::VARIANT_3`].
+    pub const fn content_type(photo: bool, video: bool, game: bool) -> Self {
+        if !photo && video && game {
+            return Self::VARIANT_3;
+        }
+        let mut mode = Self::CONTENT_TYPE;
+        if photo {
+            mode |= Self::CONTENT_TYPE_PHOTO;
+        }
+        if video {
+            mode |= Self::CONTENT_TYPE_VIDEO;
+        }
+        if game {
+            mode |= Self::CONTENT_TYPE_GAME;
+        }
+        Self(mode)
+    }
+
+    /// Returns the content types selected by a mode created with
+    /// [`VrrMode::content_type`] or by [`VrrMode::VARIANT_3`].
+    ///
+    /// The order is `(photo, video, game)`.
+    pub const fn content_types(self) -> Option<(bool, bool, bool)> {
+        if self.0 == Self::VARIANT_3.0 {
+            return Some((false, true, true));
+        }
+        if self.0 & !0b111 != Self::CONTENT_TYPE {
+            return None;
+        }
+        Some((
+            self.0 & Self::CONTENT_TYPE_PHOTO != 0,
+            self.0 & Self::CONTENT_TYPE_VIDEO != 0,
+            self.0 & Self::CONTENT_TYPE_GAME != 0,
+        ))
+    }
 }
 
 /// Sets the default VRR mode.
@@ -619,6 +861,60 @@ impl TearingMode {
     ///
     /// This is the default.
     pub const VARIANT_3: Self = Self(4);
+    /// Tearing is enabled when a single application is displayed fullscreen and the
+    /// application has set the `game` content type.
+    pub const VARIANT_4: Self = Self::content_type(false, false, false, true);
+    /// Tearing is enabled when a single application is displayed fullscreen, the
+    /// application has requested tearing, and it has set the `game` content type.
+    pub const VARIANT_5: Self = Self::content_type(true, false, false, true);
+
+    const CONTENT_TYPE: u32 = 1 << 8;
+    const CONTENT_TYPE_PHOTO: u32 = 1 << 0;
+    const CONTENT_TYPE_VIDEO: u32 = 1 << 1;
+    const CONTENT_TYPE_GAME: u32 = 1 << 2;
+    const TEARING_REQUESTED: u32 = 1 << 3;
+
+    /// Tearing is enabled when a single application is displayed fullscreen and the
+    /// application has set one of the selected content types.
+    ///
+    /// If `tearing_requested` is set, the application must also have requested
+    /// tearing.
+    pub const fn content_type(
+        tearing_requested: bool,
+        photo: bool,
+        video: bool,
+        game: bool,
+    ) -> Self {
+        let mut mode = Self::CONTENT_TYPE;
+        if tearing_requested {
+            mode |= Self::TEARING_REQUESTED;
+        }
+        if photo {
+            mode |= Self::CONTENT_TYPE_PHOTO;
+        }
+        if video {
+            mode |= Self::CONTENT_TYPE_VIDEO;
+        }
+        if game {
+            mode |= Self::CONTENT_TYPE_GAME;
+        }
+        Self(mode)
+    }
+
+    /// Returns the requirements of a mode created with [`TearingMode::content_type`].
+    ///
+    /// The order is `(tearing_requested, photo, video, game)`.
+    pub const fn content_types(self) -> Option<(bool, bool, bool, bool)> {
+        if self.0 & !0b1111 != Self::CONTENT_TYPE {
+            return None;
+        }
+        Some((
+            self.0 & Self::TEARING_REQUESTED != 0,
+            self.0 & Self::CONTENT_TYPE_PHOTO != 0,
+            self.0 & Self::CONTENT_TYPE_VIDEO != 0,
+            self.0 & Self::CONTENT_TYPE_GAME != 0,
+        ))
+    }
 }
 
 /// Sets the default tearing mode.
@@ -628,6 +924,62 @@ pub fn set_tearing_mode(mode: TearingMode) {
     get!().set_tearing_mode(None, mode)
 }
 
+/// A color matrix used to filter the contents of outputs.
+///
+/// Each row computes one of the red, green, and blue channels. The first three columns
+/// are the coefficients of the red, green, and blue input channels and the last column
+/// is a constant offset. Colors are in the range `[0, 1]` and results are clamped to
+/// this range.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct ColorMatrix(pub [[f32; 4]; 3]);
+
+impl ColorMatrix {
+    /// The matrix that does not change colors.
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+    ]);
+    /// Converts colors to their luminance.
+    pub const GRAYSCALE: Self = Self([
+        [0.2126, 0.7152, 0.0722, 0.0],
+        [0.2126, 0.7152, 0.0722, 0.0],
+        [0.2126, 0.7152, 0.0722, 0.0],
+    ]);
+    /// Shifts colors to be more distinguishable with protanopia.
+    pub const PROTANOPIA: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.5089, 0.4911, 0.0, 0.0],
+        [0.6173, -0.6173, 1.0, 0.0],
+    ]);
+    /// Shifts colors to be more distinguishable with deuteranopia.
+    pub const DEUTERANOPIA: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.2023, 0.7977, 0.0, 0.0],
+        [0.5174, -0.5174, 1.0, 0.0],
+    ]);
+    /// Shifts colors to be more distinguishable with tritanopia.
+    pub const TRITANOPIA: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [-0.1385, 1.1385, 0.0, 0.0],
+        [3.3656, -3.3656, 1.0, 0.0],
+    ]);
+}
+
+/// Sets the color filter of all outputs.
+///
+/// This setting is also used for outputs that are connected later. It can be
+/// overwritten on a per-connector basis with [Connector::set_color_filter].
+///
+/// The filter is applied while compositing. Screencasts and screencopies of whole
+/// outputs contain the filtered image but screenshots and window captures do not. The
+/// hardware cursor is disabled while a filter is active.
+///
+/// The default is `None`.
+pub fn set_color_filter(filter: Option<ColorMatrix>) {
+    get!().set_color_filter(None, filter)
+}
+
 /// A graphics format.
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Format(pub u32);
@@ -0,0 +1,99 @@
+//! Tools for configuring windows.
+
+use {
+    crate::theme::{colors::Colorable, Color},
+    serde::{Deserialize, Serialize},
+};
+
+/// Forces the scale of windows with the given app id.
+///
+/// Windows with this app id are told to render at this scale regardless of the scale of
+/// the output they are on. This also applies when the window is moved to an output
+/// with a different scale. The compositor scales the contents of such windows to the
+/// scale of the output.
+///
+/// Clients that only support integer scales are sent the scale rounded up.
+///
+/// This can be used to make applications that render blurry under fractional scales
+/// render at an integer scale.
+///
+/// X windows are not affected by this setting.
+///
+/// Setting the scale to `None` removes the override.
+pub fn set_scale_override(app_id: &str, scale: Option<f64>) {
+    get!().set_scale_override(app_id, scale)
+}
+
+/// How a window is decorated.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct DecorationMode(pub u32);
+
+impl DecorationMode {
+    /// The client is asked not to draw decorations. If the window is floating, the
+    /// compositor draws a title bar above it.
+    ///
+    /// This is the default.
+    pub const SERVER: Self = Self(0);
+    /// The client is asked to draw its own decorations. If the window is floating, the
+    /// compositor does not draw a title bar above it.
+    pub const CLIENT: Self = Self(1);
+    /// The client is asked not to draw decorations. If the window is floating, the
+    /// compositor does not draw a title bar above it.
+    pub const NONE: Self = Self(2);
+}
+
+/// Forces the decoration mode of windows with the given app id.
+///
+/// This overrides the preference of the client. The mode determines whether the
+/// client draws its own decorations and whether the compositor draws a title bar
+/// above the window while it is floating. Borders are always drawn around floating
+/// windows. Tiled windows are not affected since their title bars are part of the
+/// layout.
+///
+/// The mode is communicated via the xdg-decoration protocol. X windows are not
+/// affected by this setting.
+///
+/// Setting the mode to `None` restores the default, [DecorationMode::SERVER].
+pub fn set_decoration_mode(app_id: &str, mode: Option<DecorationMode>) {
+    get!().set_decoration_mode(app_id, mode)
+}
+
+/// Forces the width of the border drawn around windows with the given app id.
+///
+/// Setting the width to `None` restores the default, the
+/// [WINDOW_BORDER_WIDTH](crate::theme::sized::WINDOW_BORDER_WIDTH) of the theme.
+pub fn set_border_width(app_id: &str, width: Option<i32>) {
+    get!().set_window_border_width(app_id, width)
+}
+
+/// Forces a color of the border drawn around windows with the given app id.
+///
+/// The colorable must be one of
+///
+/// - [FOCUSED_WINDOW_BORDER_COLOR](crate::theme::colors::FOCUSED_WINDOW_BORDER_COLOR),
+/// - [UNFOCUSED_WINDOW_BORDER_COLOR](crate::theme::colors::UNFOCUSED_WINDOW_BORDER_COLOR),
+/// - [ATTENTION_REQUESTED_WINDOW_BORDER_COLOR](crate::theme::colors::ATTENTION_REQUESTED_WINDOW_BORDER_COLOR).
+///
+/// Setting the color to `None` restores the color of the theme.
+pub fn set_border_color(app_id: &str, colorable: Colorable, color: Option<Color>) {
+    get!().set_window_border_color(app_id, colorable, color)
+}
+
+/// Sets whether windows with the given app id are hidden from screen captures.
+///
+/// Such windows are still shown normally on the outputs but are replaced by black
+/// rectangles in screenshots, screencasts, and screencopies. This also applies to
+/// their popups.
+///
+/// The default is `false`.
+pub fn set_block_capture(app_id: &str, block: bool) {
+    get!().set_block_capture(app_id, block)
+}
+
+/// Removes all per-app-id overrides.
+///
+/// This resets all overrides set with [`set_scale_override`], [`set_decoration_mode`],
+/// [`set_border_width`], [`set_border_color`], and [`set_block_capture`].
+pub fn reset_window_rules() {
+    get!().reset_window_rules()
+}
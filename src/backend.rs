@@ -110,6 +110,15 @@ pub trait Connector {
     fn set_enabled(&self, enabled: bool) {
         let _ = enabled;
     }
+    fn set_power(&self, on: bool) {
+        let _ = on;
+    }
+    fn gamma_size(&self) -> Option<usize> {
+        None
+    }
+    fn set_gamma_ramp(&self, ramp: Option<Rc<GammaRamp>>) {
+        let _ = ramp;
+    }
     fn drm_feedback(&self) -> Option<Rc<DrmFeedback>> {
         None
     }
@@ -129,6 +138,19 @@ pub trait Connector {
     fn set_fb_format(&self, format: &'static Format) {
         let _ = format;
     }
+    fn set_render_scale(&self, scale: f64) {
+        let _ = scale;
+    }
+    fn set_flip_margin(&self, margin: Option<u64>) {
+        let _ = margin;
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GammaRamp {
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
 }
 
 #[derive(Debug)]
@@ -148,6 +170,7 @@ pub trait HardwareCursorUpdate {
     fn set_enabled(&mut self, enabled: bool);
     fn get_buffer(&self) -> Rc<dyn GfxFramebuffer>;
     fn set_position(&mut self, x: i32, y: i32);
+    fn set_hotspot(&mut self, x: i32, y: i32);
     fn swap_buffer(&mut self);
     fn set_sync_file(&mut self, sync_file: Option<SyncFile>);
     fn size(&self) -> (i32, i32);
@@ -244,7 +267,7 @@ impl InputDeviceCapability {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum InputDeviceAccelProfile {
     Flat,
     Adaptive,
@@ -285,6 +308,7 @@ pub enum InputEvent {
         key: u32,
         state: KeyState,
     },
+    /// An absolute position in the physical pixels of the connector.
     ConnectorPosition {
         time_usec: u64,
         connector: ConnectorId,
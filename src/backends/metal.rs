@@ -250,6 +250,10 @@ impl Backend for MetalBackend {
         for device in devices.values() {
             let mut change = device.dev.master.change();
             for connector in device.connectors.lock().values() {
+                if !idle && !connector.powered.get() {
+                    // The user has turned this output off. Leave it off.
+                    continue;
+                }
                 if let Some(crtc) = connector.crtc.get() {
                     if idle == crtc.active.value.get() {
                         crtc.active.value.set(!idle);
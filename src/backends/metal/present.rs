@@ -11,6 +11,7 @@ use {
             create_render_pass, AcquireSync, BufferResv, GfxApiOpt, GfxRenderPass, GfxTexture,
             ReleaseSync, SyncFile,
         },
+        scale::Scale,
         theme::Color,
         time::Time,
         tracy::FrameName,
@@ -101,9 +102,12 @@ impl MetalConnector {
             if !self.can_present.get() {
                 continue;
             }
-            let Some(node) = self.state.root.outputs.get(&self.connector_id) else {
+            let Some(node) = self.output_node() else {
                 continue;
             };
+            if let Some(throttle) = node.throttled_present_nsec() {
+                self.state.ring.timeout(throttle).await.unwrap();
+            }
             let mut expected_sequence = self.sequence.get() + 1;
             let mut start = Time::now_unchecked();
             let use_frame_scheduling = !self.try_async_flip();
@@ -186,7 +190,8 @@ impl MetalConnector {
         let mut present_fb = None;
         let mut direct_scanout_id = None;
         if let Some(latched) = &latched {
-            let fb = self.prepare_present_fb(buffer, &plane, &latched.pass, true)?;
+            let try_direct_scanout = !node.is_spanned() && node.mirror_source.is_none();
+            let fb = self.prepare_present_fb(buffer, &plane, &latched.pass, try_direct_scanout)?;
             direct_scanout_id = fb.direct_scanout_data.as_ref().map(|d| d.dma_buf_id);
             present_fb = Some(fb);
         }
@@ -321,11 +326,14 @@ impl MetalConnector {
         if let Some(fb) = new_fb {
             let (crtc_x, crtc_y, crtc_w, crtc_h, src_width, src_height) =
                 match &fb.direct_scanout_data {
-                    None => {
-                        let plane_w = plane.mode_w.get();
-                        let plane_h = plane.mode_h.get();
-                        (0, 0, plane_w, plane_h, plane_w, plane_h)
-                    }
+                    None => (
+                        0,
+                        0,
+                        plane.mode_w.get(),
+                        plane.mode_h.get(),
+                        plane.render_w.get(),
+                        plane.render_h.get(),
+                    ),
                     Some(dsd) => {
                         let p = &dsd.position;
                         (
@@ -432,6 +440,7 @@ impl MetalConnector {
             cursor_swap_buffer: false,
             cursor_x: self.cursor_x.get(),
             cursor_y: self.cursor_y.get(),
+            cursor_hotspot: self.cursor_hotspot.get(),
             cursor_buffer: &buffers[(self.cursor_front_buffer.get() + 1) % buffers.len()],
             sync_file: None,
             cursor_size: (self.dev.cursor_width as _, self.dev.cursor_height as _),
@@ -449,6 +458,7 @@ impl MetalConnector {
         cursor_changed |= c.cursor_swap_buffer;
         cursor_changed |= self.cursor_x.replace(c.cursor_x) != c.cursor_x;
         cursor_changed |= self.cursor_y.replace(c.cursor_y) != c.cursor_y;
+        cursor_changed |= self.cursor_hotspot.replace(c.cursor_hotspot) != c.cursor_hotspot;
         if cursor_changed {
             self.cursor_changed.set(true);
         }
@@ -469,11 +479,12 @@ impl MetalConnector {
             let buffers = self.cursor_buffers.get().unwrap();
             let buffer = &buffers[front_buffer % buffers.len()];
             let (width, height) = buffer.dev_fb.physical_size();
+            let (hot_x, hot_y) = self.cursor_hotspot.get();
             CursorProgramming::Enable {
                 plane,
                 fb: buffer.drm.clone(),
-                x: self.cursor_x.get(),
-                y: self.cursor_y.get(),
+                x: self.cursor_x.get() - hot_x,
+                y: self.cursor_y.get() - hot_y,
                 width,
                 height,
                 swap,
@@ -491,19 +502,40 @@ impl MetalConnector {
         }
         node.global.connector.damaged.set(false);
         let render_hw_cursor = !self.cursor_enabled.get();
-        let mode = node.global.mode.get();
-        let pass = create_render_pass(
-            (mode.width, mode.height),
-            &**node,
+        let render_scale = self.buffer_render_scale.get();
+        let (width, height, scale) = match self.primary_plane.get() {
+            Some(plane) if render_scale != 1.0 => (
+                plane.render_w.get(),
+                plane.render_h.get(),
+                Scale::from_f64(node.global.persistent.scale.get().to_f64() * render_scale),
+            ),
+            _ => {
+                let mode = node.global.mode.get();
+                (mode.width, mode.height, node.global.persistent.scale.get())
+            }
+        };
+        let (content, viewport) = match node
+            .span_viewport()
+            .or_else(|| node.mirror_viewport((width, height), scale))
+        {
+            Some((primary, viewport)) => (primary, Some(viewport)),
+            None => (node.clone(), node.zoom_viewport()),
+        };
+        let mut pass = create_render_pass(
+            (width, height),
+            &*content,
             &self.state,
-            Some(node.global.pos.get()),
-            node.global.persistent.scale.get(),
+            Some(content.global.pos.get()),
+            scale,
             true,
             render_hw_cursor,
-            node.has_fullscreen(),
+            content.has_fullscreen(),
             node.global.persistent.transform.get(),
+            viewport,
             Some(&self.state.damage_visualizer),
+            false,
         );
+        node.apply_color_matrix(&mut pass, (width, height));
         Some(Latched { pass, damage })
     }
 
@@ -537,6 +569,10 @@ impl MetalConnector {
                 // Direct scanout with alpha factor is not supported.
                 return None;
             }
+            if ct.color_matrix.is_some() {
+                // Direct scanout with color transformations is not supported.
+                return None;
+            }
             if !ct.tex.format().has_alpha && ct.target.is_covering() {
                 // Texture covers the entire screen and is opaque.
                 break 'ct ct;
@@ -694,6 +730,8 @@ impl MetalConnector {
         self.trim_scanout_cache();
         let try_direct_scanout = try_direct_scanout
             && self.direct_scanout_enabled()
+            // direct scanout buffers are positioned in mode coordinates
+            && self.buffer_render_scale.get() == 1.0
             // at least on AMD, using a FB on a different device for rendering will fail
             // and destroy the render context. it's possible to work around this by waiting
             // until the FB is no longer being scanned out, but if a notification pops up
@@ -759,6 +797,11 @@ impl MetalConnector {
         let render_hardware_cursor = self.cursor_enabled.get();
         match &fb.direct_scanout_data {
             None => {
+                let mut size = None;
+                if self.buffer_render_scale.get() != 1.0 {
+                    let mode = output.global.mode.get();
+                    size = Some((mode.width, mode.height));
+                }
                 output.perform_screencopies(
                     &fb.tex,
                     None,
@@ -767,7 +810,7 @@ impl MetalConnector {
                     render_hardware_cursor,
                     0,
                     0,
-                    None,
+                    size,
                 );
             }
             Some(dsd) => {
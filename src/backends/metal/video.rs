@@ -4,7 +4,7 @@ use {
         async_engine::{Phase, SpawnedFuture},
         backend::{
             BackendDrmDevice, BackendDrmLease, BackendDrmLessee, BackendEvent, Connector,
-            ConnectorEvent, ConnectorId, ConnectorKernelId, DrmDeviceId, HardwareCursor,
+            ConnectorEvent, ConnectorId, ConnectorKernelId, DrmDeviceId, GammaRamp, HardwareCursor,
             HardwareCursorUpdate, Mode, MonitorInfo,
         },
         backends::metal::{
@@ -36,9 +36,9 @@ use {
         video::{
             dmabuf::DmaBufId,
             drm::{
-                drm_mode_modeinfo, Change, ConnectorStatus, ConnectorType, DrmBlob, DrmConnector,
-                DrmCrtc, DrmEncoder, DrmError, DrmEvent, DrmFramebuffer, DrmLease, DrmMaster,
-                DrmModeInfo, DrmObject, DrmPlane, DrmProperty, DrmPropertyDefinition,
+                drm_color_lut, drm_mode_modeinfo, Change, ConnectorStatus, ConnectorType, DrmBlob,
+                DrmConnector, DrmCrtc, DrmEncoder, DrmError, DrmEvent, DrmFramebuffer, DrmLease,
+                DrmMaster, DrmModeInfo, DrmObject, DrmPlane, DrmProperty, DrmPropertyDefinition,
                 DrmPropertyType, DrmVersion, PropBlob, DRM_CLIENT_CAP_ATOMIC,
                 DRM_MODE_ATOMIC_ALLOW_MODESET,
             },
@@ -285,9 +285,12 @@ impl BackendDrmDevice for MetalDrmDevice {
         self.min_post_commit_margin.set(margin);
         if let Some(dd) = self.backend.device_holder.drm_devices.get(&self.devnum) {
             for c in dd.connectors.lock().values() {
+                if c.fixed_post_commit_margin.get().is_some() {
+                    continue;
+                }
                 c.post_commit_margin.set(margin);
                 c.post_commit_margin_decay.reset(margin);
-                if let Some(output) = self.backend.state.root.outputs.get(&c.connector_id) {
+                if let Some(output) = c.output_node() {
                     output.flip_margin_ns.set(Some(margin));
                 }
             }
@@ -318,6 +321,7 @@ pub struct PersistentDisplayData {
     pub mode: RefCell<Option<DrmModeInfo>>,
     pub vrr_requested: Cell<bool>,
     pub format: Cell<&'static Format>,
+    pub render_scale: Cell<f64>,
 }
 
 #[derive(Debug)]
@@ -438,6 +442,8 @@ pub struct MetalConnector {
     pub next_buffer: NumCell<usize>,
 
     pub enabled: Cell<bool>,
+    pub powered: Cell<bool>,
+    pub gamma_ramp: CloneCell<Option<Rc<GammaRamp>>>,
     pub non_desktop_override: Cell<Option<bool>>,
 
     pub lease: Cell<Option<MetalLeaseId>>,
@@ -463,6 +469,7 @@ pub struct MetalConnector {
 
     pub cursor_x: Cell<i32>,
     pub cursor_y: Cell<i32>,
+    pub cursor_hotspot: Cell<(i32, i32)>,
     pub cursor_enabled: Cell<bool>,
     pub cursor_buffers: CloneCell<Option<Rc<[RenderBuffer; 2]>>>,
     pub cursor_front_buffer: NumCell<usize>,
@@ -477,6 +484,7 @@ pub struct MetalConnector {
 
     pub tearing_requested: Cell<bool>,
     pub try_switch_format: Cell<bool>,
+    pub buffer_render_scale: Cell<f64>,
 
     pub version: NumCell<u64>,
     pub sequence: Cell<u64>,
@@ -485,6 +493,7 @@ pub struct MetalConnector {
     pub pre_commit_margin_decay: GeometricDecay,
     pub post_commit_margin: Cell<u64>,
     pub post_commit_margin_decay: GeometricDecay,
+    pub fixed_post_commit_margin: Cell<Option<u64>>,
     pub vblank_miss_sec: Cell<u32>,
     pub vblank_miss_this_sec: NumCell<u32>,
     pub presentation_is_sync: Cell<bool>,
@@ -506,6 +515,7 @@ pub struct MetalHardwareCursorChange<'a> {
     pub cursor_enabled: bool,
     pub cursor_x: i32,
     pub cursor_y: i32,
+    pub cursor_hotspot: (i32, i32),
     pub cursor_buffer: &'a RenderBuffer,
     pub sync_file: Option<SyncFile>,
     pub cursor_size: (i32, i32),
@@ -541,6 +551,10 @@ impl HardwareCursorUpdate for MetalHardwareCursorChange<'_> {
         self.cursor_y = y;
     }
 
+    fn set_hotspot(&mut self, x: i32, y: i32) {
+        self.cursor_hotspot = (x, y);
+    }
+
     fn swap_buffer(&mut self) {
         self.cursor_swap_buffer = true;
     }
@@ -565,6 +579,34 @@ impl Debug for ConnectorFutures {
 }
 
 impl MetalConnector {
+    pub fn output_node(&self) -> Option<Rc<OutputNode>> {
+        // Secondary outputs of a span are not part of the tree but still have to be
+        // presented.
+        self.state.outputs.get(&self.connector_id)?.node.clone()
+    }
+
+    fn create_gamma_lut_blob(&self, crtc: &MetalCrtc) -> Result<Option<PropBlob>, DrmError> {
+        let Some(ramp) = self.gamma_ramp.get() else {
+            return Ok(None);
+        };
+        if crtc.gamma_lut.is_none() || ramp.red.len() != crtc.gamma_lut_size {
+            return Ok(None);
+        }
+        let lut: Vec<_> = ramp
+            .red
+            .iter()
+            .zip(ramp.green.iter())
+            .zip(ramp.blue.iter())
+            .map(|((&red, &green), &blue)| drm_color_lut {
+                red,
+                green,
+                blue,
+                reserved: 0,
+            })
+            .collect();
+        self.master.create_blob(&lut[..]).map(Some)
+    }
+
     fn send_vrr_enabled(&self) {
         match self.frontend_state.get() {
             FrontState::Removed
@@ -747,7 +789,7 @@ impl MetalConnector {
             if let Err(e) = self.master.queue_sequence(crtc.id) {
                 log::error!("Could not queue a CRTC sequence: {}", ErrorFmt(&e));
                 if let DrmError::QueueSequence(OsError(c::EOPNOTSUPP)) = e {
-                    if let Some(node) = self.state.root.outputs.get(&self.connector_id) {
+                    if let Some(node) = self.output_node() {
                         log::warn!("{}: Switching to vblank emulation", self.kernel_id());
                         crtc.needs_vblank_emulation.set(true);
                         node.global.connector.needs_vblank_emulation.set(true);
@@ -806,6 +848,68 @@ impl Connector for MetalConnector {
         }
     }
 
+    fn set_power(&self, on: bool) {
+        if self.powered.replace(on) == on {
+            return;
+        }
+        let Some(crtc) = self.crtc.get() else {
+            return;
+        };
+        if crtc.active.value.get() == on {
+            return;
+        }
+        let mut change = self.master.change();
+        change.change_object(crtc.id, |c| {
+            c.change(crtc.active.id, on as _);
+        });
+        if let Err(e) = change.commit(DRM_MODE_ATOMIC_ALLOW_MODESET, 0) {
+            log::error!("Could not change the power state: {}", ErrorFmt(e));
+            return;
+        }
+        crtc.active.value.set(on);
+        if on {
+            self.has_damage.fetch_add(1);
+            self.schedule_present();
+        }
+    }
+
+    fn gamma_size(&self) -> Option<usize> {
+        let crtc = self.crtc.get()?;
+        crtc.gamma_lut.as_ref()?;
+        match crtc.gamma_lut_size {
+            0 => None,
+            n => Some(n),
+        }
+    }
+
+    fn set_gamma_ramp(&self, ramp: Option<Rc<GammaRamp>>) {
+        self.gamma_ramp.set(ramp);
+        let Some(crtc) = self.crtc.get() else {
+            return;
+        };
+        let Some(gamma_lut) = &crtc.gamma_lut else {
+            return;
+        };
+        let blob = match self.create_gamma_lut_blob(&crtc) {
+            Ok(b) => b,
+            Err(e) => {
+                log::error!("Could not create the gamma lut: {}", ErrorFmt(e));
+                return;
+            }
+        };
+        let blob_id = blob.as_ref().map(|b| b.id()).unwrap_or(DrmBlob::NONE);
+        let mut change = self.master.change();
+        change.change_object(crtc.id, |c| {
+            c.change(gamma_lut.id, blob_id.0 as _);
+        });
+        if let Err(e) = change.commit(0, 0) {
+            log::error!("Could not change the gamma lut: {}", ErrorFmt(e));
+            return;
+        }
+        gamma_lut.value.set(blob_id);
+        crtc.gamma_lut_blob.set(blob.map(Rc::new));
+    }
+
     fn drm_feedback(&self) -> Option<Rc<DrmFeedback>> {
         self.drm_feedback.get()
     }
@@ -846,6 +950,10 @@ impl Connector for MetalConnector {
         };
         log::warn!("Could not change mode: {}", ErrorFmt(&e));
         *persistent.mode.borrow_mut() = prev.clone();
+        if let Some(prev) = &prev {
+            // The frontend might already have applied the new mode.
+            self.send_event(ConnectorEvent::ModeChanged(prev.to_backend()));
+        }
         self.display.borrow_mut().mode = prev;
         if let MetalError::Modeset(DrmError::Atomic(OsError(c::EACCES))) = e {
             log::warn!("Failed due to access denied. Resetting in memory only.");
@@ -919,6 +1027,32 @@ impl Connector for MetalConnector {
         }
     }
 
+    fn set_render_scale(&self, scale: f64) {
+        {
+            let dd = self.display.borrow().persistent.clone();
+            dd.render_scale.set(scale);
+            if scale == self.buffer_render_scale.get() {
+                return;
+            }
+        }
+        if let Some(dev) = self.backend.device_holder.drm_devices.get(&self.dev.devnum) {
+            if let Err(e) = self.backend.handle_drm_change_(&dev, true) {
+                dev.unprocessed_change.set(true);
+                log::error!("Could not change render scale: {}", ErrorFmt(e));
+            }
+        }
+    }
+
+    fn set_flip_margin(&self, margin: Option<u64>) {
+        self.fixed_post_commit_margin.set(margin);
+        let margin = margin.unwrap_or(self.dev.min_post_commit_margin.get());
+        self.post_commit_margin.set(margin);
+        self.post_commit_margin_decay.reset(margin);
+        if let Some(node) = self.output_node() {
+            node.flip_margin_ns.set(Some(margin));
+        }
+    }
+
     fn set_fb_format(&self, format: &'static Format) {
         {
             let dd = self.display.borrow().persistent.clone();
@@ -955,6 +1089,9 @@ pub struct MetalCrtc {
     pub vrr_enabled: MutableProperty<bool>,
 
     pub mode_blob: CloneCell<Option<Rc<PropBlob>>>,
+    pub gamma_lut: Option<MutableProperty<DrmBlob>>,
+    pub gamma_lut_size: usize,
+    pub gamma_lut_blob: CloneCell<Option<Rc<PropBlob>>>,
     pub have_queued_sequence: Cell<bool>,
     pub needs_vblank_emulation: Cell<bool>,
 }
@@ -998,6 +1135,8 @@ pub struct MetalPlane {
 
     pub mode_w: Cell<i32>,
     pub mode_h: Cell<i32>,
+    pub render_w: Cell<i32>,
+    pub render_h: Cell<i32>,
 
     pub crtc_id: MutableProperty<DrmCrtc>,
     pub crtc_x: MutableProperty<i32>,
@@ -1061,6 +1200,8 @@ fn create_connector(
         buffers: Default::default(),
         next_buffer: Default::default(),
         enabled: Cell::new(true),
+        powered: Cell::new(true),
+        gamma_ramp: Default::default(),
         non_desktop_override: Default::default(),
         lease: Cell::new(None),
         can_present: Cell::new(true),
@@ -1072,6 +1213,7 @@ fn create_connector(
         present_trigger: Default::default(),
         cursor_x: Cell::new(0),
         cursor_y: Cell::new(0),
+        cursor_hotspot: Cell::new((0, 0)),
         cursor_enabled: Cell::new(false),
         cursor_buffers: Default::default(),
         display: RefCell::new(display),
@@ -1089,6 +1231,7 @@ fn create_connector(
         next_vblank_nsec: Cell::new(0),
         tearing_requested: Cell::new(false),
         try_switch_format: Cell::new(false),
+        buffer_render_scale: Cell::new(1.0),
         version: Default::default(),
         sequence: Default::default(),
         expected_sequence: Default::default(),
@@ -1096,6 +1239,7 @@ fn create_connector(
         pre_commit_margin: Cell::new(DEFAULT_PRE_COMMIT_MARGIN),
         post_commit_margin_decay: GeometricDecay::new(0.1, dev.min_post_commit_margin.get()),
         post_commit_margin: Cell::new(dev.min_post_commit_margin.get()),
+        fixed_post_commit_margin: Default::default(),
         vblank_miss_sec: Cell::new(0),
         vblank_miss_this_sec: Default::default(),
         presentation_is_sync: Cell::new(false),
@@ -1235,6 +1379,7 @@ fn create_connector_display_data(
                 mode: RefCell::new(info.modes.first().cloned()),
                 vrr_requested: Default::default(),
                 format: Cell::new(XRGB8888),
+                render_scale: Cell::new(1.0),
             });
             dev.backend
                 .persistent_display_data
@@ -1327,6 +1472,15 @@ fn create_crtc(
         out_fence_ptr: props.get("OUT_FENCE_PTR")?.id,
         vrr_enabled: props.get("VRR_ENABLED")?.map(|v| v == 1),
         mode_blob: Default::default(),
+        gamma_lut: props
+            .get("GAMMA_LUT")
+            .ok()
+            .map(|p| p.map(|v| DrmBlob(v as u32))),
+        gamma_lut_size: props
+            .get("GAMMA_LUT_SIZE")
+            .map(|p| p.value.get() as usize)
+            .unwrap_or(0),
+        gamma_lut_blob: Default::default(),
         have_queued_sequence: Cell::new(false),
         needs_vblank_emulation: Cell::new(false),
     })
@@ -1407,6 +1561,8 @@ fn create_plane(plane: DrmPlane, master: &Rc<DrmMaster>) -> Result<MetalPlane, D
         assigned: Cell::new(false),
         mode_w: Cell::new(0),
         mode_h: Cell::new(0),
+        render_w: Cell::new(0),
+        render_h: Cell::new(0),
         lease: Cell::new(None),
     })
 }
@@ -1634,6 +1790,9 @@ impl MetalBackend {
             if c.try_switch_format.get() && old.persistent.format.get() != c.buffer_format.get() {
                 preserve_connector = false;
             }
+            if old.persistent.render_scale.get() != c.buffer_render_scale.get() {
+                preserve_connector = false;
+            }
             if preserve_connector {
                 preserve.connectors.insert(c.id);
             }
@@ -2002,7 +2161,7 @@ impl MetalBackend {
             *connector.active_framebuffer.borrow_mut() = Some(fb);
         }
         let dd = connector.display.borrow();
-        let global = self.state.root.outputs.get(&connector.connector_id);
+        let global = connector.output_node();
         if let Some(expected) = connector.expected_sequence.take() {
             if connector.vblank_miss_sec.replace(tv_sec) != tv_sec {
                 self.update_post_commit_margin(dev, &connector, &dd, global.as_deref());
@@ -2050,6 +2209,9 @@ impl MetalBackend {
         global: Option<&OutputNode>,
     ) {
         let n_missed = connector.vblank_miss_this_sec.replace(0);
+        if connector.fixed_post_commit_margin.get().is_some() {
+            return;
+        }
         let old_margin = connector.post_commit_margin.get();
         let new_margin = if n_missed > 0 {
             log::debug!("{}: Missed {n_missed} page flips", connector.kernel_id());
@@ -2370,8 +2532,8 @@ impl MetalBackend {
             connector.crtc.set(Some(crtc.clone()));
             connector.version.fetch_add(1);
             crtc.connector.set(Some(connector.clone()));
-            if !crtc.active.value.get() {
-                log::debug!("Crtc is not active");
+            if crtc.active.value.get() != connector.powered.get() {
+                log::debug!("Crtc power state differs from desired power state");
                 return false;
             }
             let mode = match &dd.mode {
@@ -2620,22 +2782,35 @@ impl MetalBackend {
             _ => return Err(MetalError::NoModeForConnector),
         };
         let mode_blob = mode.create_blob(&connector.master)?;
+        let gamma_lut_blob = connector.create_gamma_lut_blob(&crtc)?;
+        let gamma_lut_blob_id = gamma_lut_blob
+            .as_ref()
+            .map(|b| b.id())
+            .unwrap_or(DrmBlob::NONE);
         changes.change_object(connector.id, |c| {
             c.change(dd.crtc_id.id, crtc.id.0 as _);
         });
+        let active = connector.powered.get();
         changes.change_object(crtc.id, |c| {
-            c.change(crtc.active.id, 1);
+            c.change(crtc.active.id, active as _);
             c.change(crtc.mode_id.id, mode_blob.id().0 as _);
             c.change(crtc.vrr_enabled.id, dd.should_enable_vrr() as _);
+            if let Some(gamma_lut) = &crtc.gamma_lut {
+                c.change(gamma_lut.id, gamma_lut_blob_id.0 as _);
+            }
         });
         connector.crtc.set(Some(crtc.clone()));
         connector.version.fetch_add(1);
         dd.crtc_id.value.set(crtc.id);
         crtc.connector.set(Some(connector.clone()));
-        crtc.active.value.set(true);
+        crtc.active.value.set(active);
         crtc.mode_id.value.set(mode_blob.id());
         crtc.mode_blob.set(Some(Rc::new(mode_blob)));
         crtc.vrr_enabled.value.set(dd.should_enable_vrr() as _);
+        if let Some(gamma_lut) = &crtc.gamma_lut {
+            gamma_lut.value.set(gamma_lut_blob_id);
+        }
+        crtc.gamma_lut_blob.set(gamma_lut_blob.map(Rc::new));
         Ok(())
     }
 
@@ -2658,6 +2833,9 @@ impl MetalBackend {
                 return Ok(());
             }
         };
+        let render_scale = dd.persistent.render_scale.get();
+        let render_w = ((mode.hdisplay as f64 * render_scale).round() as i32).max(1);
+        let render_h = ((mode.vdisplay as f64 * render_scale).round() as i32).max(1);
         let allocate_primary_plane = |format: &'static Format| {
             let (primary_plane, primary_modifiers) = 'primary_plane: {
                 for plane in crtc.possible_planes.values() {
@@ -2676,8 +2854,8 @@ impl MetalBackend {
                 &connector.dev,
                 format,
                 primary_modifiers,
-                mode.hdisplay as _,
-                mode.vdisplay as _,
+                render_w,
+                render_h,
                 ctx,
                 false,
             )?);
@@ -2753,12 +2931,14 @@ impl MetalBackend {
             c.change(primary_plane.crtc_h.id, mode.vdisplay as _);
             c.change(primary_plane.src_x.id, 0);
             c.change(primary_plane.src_y.id, 0);
-            c.change(primary_plane.src_w.id, (mode.hdisplay as u64) << 16);
-            c.change(primary_plane.src_h.id, (mode.vdisplay as u64) << 16);
+            c.change(primary_plane.src_w.id, (render_w as u64) << 16);
+            c.change(primary_plane.src_h.id, (render_h as u64) << 16);
         });
         primary_plane.assigned.set(true);
         primary_plane.mode_w.set(mode.hdisplay as _);
         primary_plane.mode_h.set(mode.vdisplay as _);
+        primary_plane.render_w.set(render_w);
+        primary_plane.render_h.set(render_h);
         primary_plane.crtc_id.value.set(crtc.id);
         primary_plane.crtc_x.value.set(0);
         primary_plane.crtc_y.value.set(0);
@@ -2766,8 +2946,8 @@ impl MetalBackend {
         primary_plane.crtc_h.value.set(mode.vdisplay as _);
         primary_plane.src_x.value.set(0);
         primary_plane.src_y.value.set(0);
-        primary_plane.src_w.value.set((mode.hdisplay as u32) << 16);
-        primary_plane.src_h.value.set((mode.vdisplay as u32) << 16);
+        primary_plane.src_w.value.set((render_w as u32) << 16);
+        primary_plane.src_h.value.set((render_h as u32) << 16);
         if let Some(old) = connector.buffers.set(Some(buffers)) {
             old_buffers.push(old);
         }
@@ -2782,6 +2962,7 @@ impl MetalBackend {
         connector.cursor_plane.set(cursor_plane);
         connector.cursor_enabled.set(false);
         connector.buffer_format.set(buffer_format);
+        connector.buffer_render_scale.set(render_scale);
         connector.try_switch_format.set(false);
         connector.version.fetch_add(1);
         Ok(())
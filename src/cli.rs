@@ -1,4 +1,5 @@
 mod color;
+mod config;
 mod damage_tracking;
 mod duration;
 mod generate;
@@ -17,8 +18,8 @@ mod xwayland;
 use {
     crate::{
         cli::{
-            damage_tracking::DamageTrackingArgs, input::InputArgs, randr::RandrArgs,
-            xwayland::XwaylandArgs,
+            config::ConfigArgs, damage_tracking::DamageTrackingArgs, input::InputArgs,
+            randr::RandrArgs, xwayland::XwaylandArgs,
         },
         compositor::start_compositor,
         format::{ref_formats, Format},
@@ -78,6 +79,8 @@ pub enum Cmd {
     DamageTracking(DamageTrackingArgs),
     /// Inspect/modify xwayland settings.
     Xwayland(XwaylandArgs),
+    /// Reload the config or switch to the default config.
+    Config(ConfigArgs),
     #[cfg(feature = "it")]
     RunTests,
 }
@@ -107,6 +110,9 @@ pub enum IdleCmd {
     Status,
     /// Set the idle interval.
     Set(IdleSetArgs),
+    /// Set the grace period after the idle interval during which input cancels going
+    /// idle.
+    SetGracePeriod(IdleSetGracePeriodArgs),
 }
 
 impl Default for IdleCmd {
@@ -133,6 +139,18 @@ pub struct IdleSetArgs {
     pub interval: Vec<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct IdleSetGracePeriodArgs {
+    /// The grace period.
+    ///
+    /// This can be either a number in minutes and seconds or the keyword `disabled` to
+    /// disable the grace period.
+    ///
+    /// The format is the same as for `jay idle set`.
+    #[clap(required = true)]
+    pub period: Vec<String>,
+}
+
 #[derive(ValueEnum, Debug, Copy, Clone, Hash, Default, PartialEq)]
 pub enum ScreenshotFormat {
     /// The PNG image format.
@@ -154,6 +172,11 @@ pub struct ScreenshotArgs {
     ///
     /// The filename can contain the usual strftime parameters.
     pub filename: Option<String>,
+    /// Capture the screenshot after every output has presented its next frame.
+    ///
+    /// This ensures that the screenshot shows a frame that was actually presented.
+    #[clap(long)]
+    pub after_presentation: bool,
 }
 
 #[derive(Args, Debug, Default)]
@@ -167,6 +190,11 @@ pub struct RunArgs {
     /// which they will be tried. Multiple backends can be supplied as a comma-separated list.
     #[clap(value_enum, use_value_delimiter = true, long)]
     pub backends: Vec<CliBackend>,
+    /// What to do if config.so exists but cannot be loaded.
+    ///
+    /// If config.so does not exist, the default config is used in all cases.
+    #[clap(value_enum, long, default_value_t)]
+    pub on_config_error: CliConfigErrorAction,
 }
 
 #[derive(Args, Debug)]
@@ -180,6 +208,13 @@ pub struct LogArgs {
     /// Immediately jump to the end in the pager.
     #[clap(long, short = 'e')]
     pager_end: bool,
+    /// Move the current log file aside and start a new one before opening it.
+    #[clap(long)]
+    rotate: bool,
+    /// Print messages at or above this level as they are logged instead of opening
+    /// the log file.
+    #[clap(long, value_enum)]
+    stream: Option<CliLogLevel>,
 }
 
 #[derive(Args, Debug)]
@@ -187,6 +222,12 @@ pub struct SetLogArgs {
     /// The new log level.
     #[clap(value_enum)]
     level: CliLogLevel,
+    /// Only change the log level of this module and its submodules, e.g. `jay::tree`.
+    #[clap(long)]
+    module: Option<String>,
+    /// Remove the log levels of all modules before changing the log level.
+    #[clap(long)]
+    reset_modules: bool,
 }
 
 #[derive(Args, Debug)]
@@ -204,6 +245,17 @@ pub enum CliBackend {
     Metal,
 }
 
+#[derive(ValueEnum, Debug, Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub enum CliConfigErrorAction {
+    /// Log the error and use the default config.
+    #[default]
+    UseDefault,
+    /// Use the default config and show a warning in the bar.
+    SafeMode,
+    /// Log the error and exit.
+    Exit,
+}
+
 #[derive(ValueEnum, Debug, Copy, Clone, Hash)]
 pub enum CliLogLevel {
     Trace,
@@ -266,6 +318,7 @@ pub fn main() {
         Cmd::Input(a) => input::main(cli.global, a),
         Cmd::DamageTracking(a) => damage_tracking::main(cli.global, a),
         Cmd::Xwayland(a) => xwayland::main(cli.global, a),
+        Cmd::Config(a) => config::main(cli.global, a),
         #[cfg(feature = "it")]
         Cmd::RunTests => crate::it::run_tests(),
     }
@@ -0,0 +1,52 @@
+use {
+    crate::{
+        cli::GlobalArgs,
+        tools::tool_client::{with_tool_client, Handle, ToolClient},
+        wire::jay_compositor::{ConfigError, ReloadConfig, UseDefaultConfig},
+    },
+    clap::{Args, Subcommand},
+    std::rc::Rc,
+};
+
+#[derive(Args, Debug)]
+pub struct ConfigArgs {
+    #[clap(subcommand)]
+    pub command: ConfigCmd,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCmd {
+    /// Reload config.so from the config directory.
+    Reload,
+    /// Replace the current config by the built-in default config.
+    ///
+    /// This can be used to recover from a misbehaving config. Use `jay config reload` to
+    /// switch back to config.so.
+    UseDefault,
+}
+
+pub fn main(global: GlobalArgs, args: ConfigArgs) {
+    with_tool_client(global.log_level.into(), |tc| async move {
+        let config = Rc::new(Config { tc: tc.clone() });
+        config.run(args).await;
+    });
+}
+
+struct Config {
+    tc: Rc<ToolClient>,
+}
+
+impl Config {
+    async fn run(&self, args: ConfigArgs) {
+        let tc = &self.tc;
+        let comp = tc.jay_compositor().await;
+        ConfigError::handle(tc, comp, (), |_, msg| {
+            fatal!("Could not reload the config: {}", msg.msg);
+        });
+        match args.command {
+            ConfigCmd::Reload => tc.send(ReloadConfig { self_id: comp }),
+            ConfigCmd::UseDefault => tc.send(UseDefaultConfig { self_id: comp }),
+        }
+        tc.round_trip().await;
+    }
+}
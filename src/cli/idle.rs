@@ -1,6 +1,10 @@
 use {
     crate::{
-        cli::{duration::parse_duration, GlobalArgs, IdleArgs, IdleCmd, IdleSetArgs},
+        cli::{
+            duration::parse_duration, GlobalArgs, IdleArgs, IdleCmd, IdleSetArgs,
+            IdleSetGracePeriodArgs,
+        },
+        time::Time,
         tools::tool_client::{with_tool_client, Handle, ToolClient},
         utils::stack::Stack,
         wire::{jay_compositor, jay_idle, JayIdleId, WlSurfaceId},
@@ -31,6 +35,7 @@ impl Idle {
         match args.command.unwrap_or_default() {
             IdleCmd::Status => self.status(idle).await,
             IdleCmd::Set(args) => self.set(idle, args).await,
+            IdleCmd::SetGracePeriod(args) => self.set_grace_period(idle, args).await,
         }
     }
 
@@ -41,11 +46,16 @@ impl Idle {
         jay_idle::Interval::handle(tc, idle, interval.clone(), |iv, msg| {
             iv.set(msg.interval);
         });
+        let grace_period = Rc::new(Cell::new(0u64));
+        jay_idle::GracePeriod::handle(tc, idle, grace_period.clone(), |gp, msg| {
+            gp.set(msg.period);
+        });
         struct Inhibitor {
             surface: WlSurfaceId,
             _client_id: u64,
             pid: u64,
             comm: String,
+            since_usec: Option<u64>,
         }
         let inhibitors = Rc::new(Stack::default());
         jay_idle::Inhibitor::handle(tc, idle, inhibitors.clone(), |iv, msg| {
@@ -54,39 +64,36 @@ impl Idle {
                 _client_id: msg.client_id,
                 pid: msg.pid,
                 comm: msg.comm.to_string(),
+                since_usec: None,
+            });
+        });
+        jay_idle::Inhibitor2::handle(tc, idle, inhibitors.clone(), |iv, msg| {
+            iv.push(Inhibitor {
+                surface: msg.surface,
+                _client_id: msg.client_id,
+                pid: msg.pid,
+                comm: msg.comm.to_string(),
+                since_usec: Some(msg.since_usec),
             });
         });
         tc.round_trip().await;
-        let minutes = interval.get() / 60;
-        let seconds = interval.get() % 60;
-        print!("Interval:");
-        if minutes == 0 && seconds == 0 {
-            print!(" disabled");
-        } else {
-            if minutes > 0 {
-                print!(" {} minute", minutes);
-                if minutes > 1 {
-                    print!("s");
-                }
-            }
-            if seconds > 0 {
-                print!(" {} second", seconds);
-                if seconds > 1 {
-                    print!("s");
-                }
-            }
-        }
-        println!();
+        print_duration("Interval", interval.get());
+        print_duration("Grace period", grace_period.get());
         let mut inhibitors = inhibitors.take();
         inhibitors.sort_by_key(|i| i.pid);
         inhibitors.sort_by_key(|i| i.surface);
         if inhibitors.len() > 0 {
             println!("Inhibitors:");
+            let now = Time::now_unchecked().usec();
             for inhibitor in inhibitors {
-                println!(
+                print!(
                     "  {}, surface {}, pid {}",
                     inhibitor.comm, inhibitor.surface, inhibitor.pid
                 );
+                if let Some(since) = inhibitor.since_usec {
+                    print!(", active for {}s", now.saturating_sub(since) / 1_000_000);
+                }
+                println!();
             }
         }
     }
@@ -104,4 +111,41 @@ impl Idle {
         });
         tc.round_trip().await;
     }
+
+    async fn set_grace_period(self, idle: JayIdleId, args: IdleSetGracePeriodArgs) {
+        let tc = &self.tc;
+        let period = if args.period.len() == 1 && args.period[0] == "disabled" {
+            0
+        } else {
+            parse_duration(&args.period).as_secs() as u64
+        };
+        tc.send(jay_idle::SetGracePeriod {
+            self_id: idle,
+            period,
+        });
+        tc.round_trip().await;
+    }
+}
+
+fn print_duration(name: &str, secs: u64) {
+    let minutes = secs / 60;
+    let seconds = secs % 60;
+    print!("{}:", name);
+    if minutes == 0 && seconds == 0 {
+        print!(" disabled");
+    } else {
+        if minutes > 0 {
+            print!(" {} minute", minutes);
+            if minutes > 1 {
+                print!("s");
+            }
+        }
+        if seconds > 0 {
+            print!(" {} second", seconds);
+            if seconds > 1 {
+                print!("s");
+            }
+        }
+    }
+    println!();
 }
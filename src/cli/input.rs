@@ -264,6 +264,7 @@ struct Seat {
     pub repeat_rate: i32,
     pub repeat_delay: i32,
     pub hardware_cursor: bool,
+    pub keyboard_layout: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -683,6 +684,9 @@ impl Input {
         println!("Seat {}:", seat.name);
         println!("  repeat rate: {}", seat.repeat_rate);
         println!("  repeat delay: {}", seat.repeat_delay);
+        if let Some(layout) = &seat.keyboard_layout {
+            println!("  keyboard layout: {}", layout);
+        }
         if !seat.hardware_cursor {
             println!("  hardware cursor disabled");
         }
@@ -772,8 +776,15 @@ impl Input {
                 repeat_rate: msg.repeat_rate,
                 repeat_delay: msg.repeat_delay,
                 hardware_cursor: msg.hardware_cursor != 0,
+                keyboard_layout: None,
             });
         });
+        jay_input::KeyboardLayout::handle(tc, input, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            if let Some(last) = data.seats.last_mut() {
+                last.keyboard_layout = Some(msg.name.to_string());
+            }
+        });
         jay_input::InputDevice::handle(tc, input, data.clone(), |data, msg| {
             use crate::{backend::InputDeviceCapability::*, libinput::consts::*};
             let mut capabilities = vec![];
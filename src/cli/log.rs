@@ -3,13 +3,15 @@ use {
         cli::{GlobalArgs, LogArgs},
         tools::tool_client::{with_tool_client, Handle, ToolClient},
         utils::errorfmt::ErrorFmt,
-        wire::{jay_compositor, jay_log_file},
+        wire::{jay_compositor, jay_log_file, jay_log_stream},
     },
     bstr::{BString, ByteSlice},
-    jay_compositor::GetLogFile,
-    jay_log_file::Path,
+    jay_compositor::{GetLogFile, GetLogStream},
+    jay_log_file::{Path, Rotate, RotateFailed, Rotated},
+    jay_log_stream::{Line, Lost},
     std::{
         cell::RefCell,
+        future::pending,
         ops::Deref,
         os::unix::process::CommandExt,
         process::{self, Command},
@@ -37,11 +39,35 @@ struct Log {
 async fn run(log: Rc<Log>) {
     let tc = &log.tc;
     let comp = tc.jay_compositor().await;
+    if let Some(level) = log.args.stream {
+        let stream = tc.id();
+        tc.send(GetLogStream {
+            self_id: comp,
+            id: stream,
+            level: level as u32,
+        });
+        Line::handle(tc, stream, (), |_, line| {
+            println!("{}", line.line);
+        });
+        Lost::handle(tc, stream, (), |_, lost| {
+            eprintln!("{} messages lost", lost.count);
+        });
+        pending::<()>().await;
+    }
     let log_file = tc.id();
     tc.send(GetLogFile {
         self_id: comp,
         id: log_file,
     });
+    if log.args.rotate {
+        tc.send(Rotate { self_id: log_file });
+        Rotated::handle(tc, log_file, (), |_, ev| {
+            eprintln!("Moved the previous log file to {}", ev.path);
+        });
+        RotateFailed::handle(tc, log_file, (), |_, ev| {
+            fatal!("Could not rotate the log file: {}", ev.msg);
+        });
+    }
     Path::handle(tc, log_file, log.clone(), |log, path| {
         *log.path.borrow_mut() = Some(path.path.to_vec().into());
     });
@@ -1,8 +1,9 @@
 use {
     crate::{
-        cli::GlobalArgs,
+        cli::{color::parse_color, GlobalArgs},
         format::{Format, XRGB8888},
         scale::Scale,
+        theme::Color,
         tools::tool_client::{with_tool_client, Handle, ToolClient},
         utils::{errorfmt::ErrorFmt, transform_ext::TransformExt},
         wire::{jay_compositor, jay_randr, JayRandrId},
@@ -154,6 +155,59 @@ pub enum OutputCommand {
     Tearing(TearingArgs),
     /// Change format settings.
     Format(FormatSettings),
+    /// Show presentation statistics of the output.
+    Statistics,
+    /// Change the background color of the output.
+    Background(BackgroundArgs),
+    /// Modify timing settings of the output.
+    Timing(OutputTimingArgs),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct OutputTimingArgs {
+    #[clap(subcommand)]
+    pub cmd: OutputTimingCmd,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum OutputTimingCmd {
+    /// Shows the flip margin of the output.
+    ShowFlipMargin,
+    /// Sets a fixed margin to use for page flips on this output.
+    ///
+    /// This overrides the margin of the card and disables the automatic adjustment of
+    /// the margin after missed page flips. The setting persists across mode changes.
+    ///
+    /// The margin is not used while tearing is active since frames are then presented
+    /// as soon as they have been rendered. While VRR is active, the margin is applied
+    /// before the predicted vblank just as with a fixed refresh rate.
+    SetFlipMargin(SetFlipMarginArgs),
+    /// Restores the automatic adjustment of the flip margin.
+    ResetFlipMargin,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct BackgroundArgs {
+    #[clap(subcommand)]
+    pub command: BackgroundCommand,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum BackgroundCommand {
+    /// Sets the background color of the output.
+    Set {
+        /// The color to use.
+        ///
+        /// Should be specified in one of the following formats:
+        ///
+        /// * `#rgb`
+        /// * `#rgba`
+        /// * `#rrggbb`
+        /// * `#rrggbbaa`
+        color: String,
+    },
+    /// Uses the background color of the theme.
+    Reset,
 }
 
 #[derive(ValueEnum, Debug, Clone)]
@@ -257,6 +311,12 @@ pub enum TearingModeArg {
     ///
     /// This is the default.
     Variant3,
+    /// Tearing is enabled when a single application is displayed fullscreen and the
+    /// application has set the `game` content type.
+    Variant4,
+    /// Tearing is enabled when a single application is displayed fullscreen, the
+    /// application has requested tearing, and it has set the `game` content type.
+    Variant5,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -368,6 +428,8 @@ struct Output {
     pub formats: Vec<String>,
     pub format: Option<String>,
     pub flip_margin_ns: Option<u64>,
+    pub background: Option<Color>,
+    pub hardware_cursor: Option<bool>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -582,11 +644,13 @@ impl Randr {
                 match a.command {
                     TearingCommand::SetMode(a) => {
                         let mode = match a.mode {
-                            TearingModeArg::Never => VrrMode::NEVER,
-                            TearingModeArg::Always => VrrMode::ALWAYS,
-                            TearingModeArg::Variant1 => VrrMode::VARIANT_1,
-                            TearingModeArg::Variant2 => VrrMode::VARIANT_2,
-                            TearingModeArg::Variant3 => VrrMode::VARIANT_3,
+                            TearingModeArg::Never => TearingMode::NEVER,
+                            TearingModeArg::Always => TearingMode::ALWAYS,
+                            TearingModeArg::Variant1 => TearingMode::VARIANT_1,
+                            TearingModeArg::Variant2 => TearingMode::VARIANT_2,
+                            TearingModeArg::Variant3 => TearingMode::VARIANT_3,
+                            TearingModeArg::Variant4 => TearingMode::VARIANT_4,
+                            TearingModeArg::Variant5 => TearingMode::VARIANT_5,
                         };
                         tc.send(jay_randr::SetTearingMode {
                             self_id: randr,
@@ -610,6 +674,89 @@ impl Randr {
                     }
                 }
             }
+            OutputCommand::Statistics => {
+                self.handle_error(randr, move |msg| {
+                    eprintln!("Could not retrieve the statistics: {}", msg);
+                });
+                jay_randr::Statistics::handle(tc, randr, (), |_, msg| {
+                    println!("vblanks: {}", msg.vblanks);
+                    println!("latches: {}", msg.latches);
+                    println!("presentations: {}", msg.presentations);
+                    println!("missed frames: {}", msg.missed_frames);
+                    println!(
+                        "average flip margin: {:?}",
+                        Duration::from_nanos(msg.avg_flip_margin_ns)
+                    );
+                    if msg.last_refresh_ns != 0 {
+                        println!(
+                            "refresh rate: {:.3} Hz",
+                            1_000_000_000.0 / msg.last_refresh_ns as f64
+                        );
+                    }
+                    println!("presentation flags: 0x{:x}", msg.last_flags);
+                });
+                tc.send(jay_randr::GetStatistics {
+                    self_id: randr,
+                    output: &args.output,
+                });
+            }
+            OutputCommand::Background(a) => {
+                self.handle_error(randr, move |msg| {
+                    eprintln!("Could not change the background color: {}", msg);
+                });
+                match a.command {
+                    BackgroundCommand::Set { color } => {
+                        let color = parse_color(&color);
+                        tc.send(jay_randr::SetBackground {
+                            self_id: randr,
+                            output: &args.output,
+                            r: color.r,
+                            g: color.g,
+                            b: color.b,
+                            a: color.a,
+                        });
+                    }
+                    BackgroundCommand::Reset => {
+                        tc.send(jay_randr::ResetBackground {
+                            self_id: randr,
+                            output: &args.output,
+                        });
+                    }
+                }
+            }
+            OutputCommand::Timing(a) => {
+                self.handle_error(randr, move |msg| {
+                    eprintln!("Could not modify the flip margin: {}", msg);
+                });
+                match a.cmd {
+                    OutputTimingCmd::ShowFlipMargin => {
+                        jay_randr::OutputFlipMargin::handle(tc, randr, (), |_, msg| {
+                            let margin = Duration::from_nanos(msg.margin_ns);
+                            match msg.fixed != 0 {
+                                true => println!("flip margin: {:?} (fixed)", margin),
+                                false => println!("flip margin: {:?}", margin),
+                            }
+                        });
+                        tc.send(jay_randr::GetOutputFlipMargin {
+                            self_id: randr,
+                            output: &args.output,
+                        });
+                    }
+                    OutputTimingCmd::SetFlipMargin(sfm) => {
+                        tc.send(jay_randr::SetOutputFlipMargin {
+                            self_id: randr,
+                            output: &args.output,
+                            margin_ns: (sfm.margin_ms * 1_000_000.0) as u64,
+                        });
+                    }
+                    OutputTimingCmd::ResetFlipMargin => {
+                        tc.send(jay_randr::ResetOutputFlipMargin {
+                            self_id: randr,
+                            output: &args.output,
+                        });
+                    }
+                }
+            }
         }
         tc.round_trip().await;
     }
@@ -747,7 +894,20 @@ impl Randr {
                 VrrMode::VARIANT_2 => "variant2",
                 VrrMode::VARIANT_3 => "variant3",
                 _ => {
-                    mode_str = format!("unknown ({})", o.vrr_mode.0);
+                    mode_str = match o.vrr_mode.content_types() {
+                        Some((photo, video, game)) => {
+                            let mut types = vec![];
+                            for (enabled, name) in
+                                [(photo, "photo"), (video, "video"), (game, "game")]
+                            {
+                                if enabled {
+                                    types.push(name);
+                                }
+                            }
+                            format!("content-type ({})", types.join(", "))
+                        }
+                        _ => format!("unknown ({})", o.vrr_mode.0),
+                    };
                     &mode_str
                 }
             };
@@ -764,8 +924,27 @@ impl Randr {
                 TearingMode::VARIANT_1 => "variant1",
                 TearingMode::VARIANT_2 => "variant2",
                 TearingMode::VARIANT_3 => "variant3",
+                TearingMode::VARIANT_4 => "variant4",
+                TearingMode::VARIANT_5 => "variant5",
                 _ => {
-                    mode_str = format!("unknown ({})", o.vrr_mode.0);
+                    mode_str = match o.tearing_mode.content_types() {
+                        Some((requested, photo, video, game)) => {
+                            let mut types = vec![];
+                            for (enabled, name) in
+                                [(photo, "photo"), (video, "video"), (game, "game")]
+                            {
+                                if enabled {
+                                    types.push(name);
+                                }
+                            }
+                            let requested = match requested {
+                                true => ", tearing requested",
+                                false => "",
+                            };
+                            format!("content-type ({}){}", types.join(", "), requested)
+                        }
+                        _ => format!("unknown ({})", o.tearing_mode.0),
+                    };
                     &mode_str
                 }
             };
@@ -806,6 +985,13 @@ impl Randr {
                 );
             }
         }
+        if let Some(color) = o.background {
+            let [r, g, b, a] = color.to_rgba_straight();
+            println!("        background: #{r:02x}{g:02x}{b:02x}{a:02x}");
+        }
+        if o.hardware_cursor == Some(false) {
+            println!("        cursor: software");
+        }
         if o.modes.is_not_empty() && modes {
             println!("        modes:");
             for mode in &o.modes {
@@ -886,6 +1072,8 @@ impl Randr {
                 formats: vec![],
                 format: None,
                 flip_margin_ns: None,
+                background: None,
+                hardware_cursor: None,
             });
         });
         jay_randr::NonDesktopOutput::handle(tc, randr, data.clone(), |data, msg| {
@@ -914,6 +1102,8 @@ impl Randr {
                 formats: vec![],
                 format: None,
                 flip_margin_ns: None,
+                background: None,
+                hardware_cursor: None,
             });
         });
         jay_randr::VrrState::handle(tc, randr, data.clone(), |data, msg| {
@@ -951,6 +1141,23 @@ impl Randr {
             let output = c.output.as_mut().unwrap();
             output.flip_margin_ns = Some(msg.margin_ns);
         });
+        jay_randr::Background::handle(tc, randr, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            let c = data.connectors.last_mut().unwrap();
+            let output = c.output.as_mut().unwrap();
+            output.background = Some(Color {
+                r: msg.r,
+                g: msg.g,
+                b: msg.b,
+                a: msg.a,
+            });
+        });
+        jay_randr::HardwareCursor::handle(tc, randr, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            let c = data.connectors.last_mut().unwrap();
+            let output = c.output.as_mut().unwrap();
+            output.hardware_cursor = Some(msg.active != 0);
+        });
         jay_randr::Mode::handle(tc, randr, data.clone(), |data, msg| {
             let mut data = data.borrow_mut();
             let c = data.connectors.last_mut().unwrap();
@@ -13,7 +13,7 @@ use {
             gbm::{GbmDevice, GbmError},
         },
         wire::{
-            jay_compositor::TakeScreenshot,
+            jay_compositor::{TakeScreenshot, TakeScreenshot3},
             jay_screenshot::{Dmabuf, Dmabuf2, DrmDev, Error, Plane},
         },
     },
@@ -47,10 +47,19 @@ async fn run(screenshot: Rc<Screenshot>) {
     let tc = &screenshot.tc;
     let comp = tc.jay_compositor().await;
     let sid = tc.id();
-    tc.send(TakeScreenshot {
-        self_id: comp,
-        id: sid,
-    });
+    if screenshot.args.after_presentation {
+        tc.send(TakeScreenshot3 {
+            self_id: comp,
+            id: sid,
+            include_cursor: 0,
+            after_presentation: 1,
+        });
+    } else {
+        tc.send(TakeScreenshot {
+            self_id: comp,
+            id: sid,
+        });
+    }
     let result = Rc::new(AsyncQueue::new());
     Error::handle(tc, sid, result.clone(), |res, err| {
         res.push(Err(err.msg.to_owned()));
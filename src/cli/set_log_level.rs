@@ -2,7 +2,7 @@ use {
     crate::{
         cli::{GlobalArgs, SetLogArgs},
         tools::tool_client::{with_tool_client, ToolClient},
-        wire::jay_compositor::SetLogLevel,
+        wire::jay_compositor::{ResetModuleLogLevels, SetLogLevel, SetModuleLogLevel},
     },
     std::rc::Rc,
 };
@@ -25,9 +25,19 @@ struct Log {
 async fn run(log: Rc<Log>) {
     let tc = &log.tc;
     let comp = tc.jay_compositor().await;
-    tc.send(SetLogLevel {
-        self_id: comp,
-        level: log.args.level as u32,
-    });
+    if log.args.reset_modules {
+        tc.send(ResetModuleLogLevels { self_id: comp });
+    }
+    match &log.args.module {
+        Some(module) => tc.send(SetModuleLogLevel {
+            self_id: comp,
+            module,
+            level: log.args.level as u32,
+        }),
+        None => tc.send(SetLogLevel {
+            self_id: comp,
+            level: log.args.level as u32,
+        }),
+    }
     tc.round_trip().await;
 }
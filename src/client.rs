@@ -36,10 +36,12 @@ use {
 };
 pub use {
     error::{ClientError, ParserError},
+    error_log::{ClientErrorEntry, ClientErrorLog},
     objects::MIN_SERVER_ID,
 };
 
 mod error;
+mod error_log;
 mod objects;
 mod tasks;
 
@@ -156,6 +158,7 @@ impl Clients {
             objects: Objects::new(),
             swapchain: Default::default(),
             flush_request: Default::default(),
+            flushed: Default::default(),
             shutdown: Default::default(),
             tracker: Default::default(),
             is_xwayland,
@@ -176,6 +179,7 @@ impl Clients {
             )),
             wire_scale: Default::default(),
             focus_stealing_serial: Default::default(),
+            screencopies_without_buffer: Default::default(),
         });
         track!(data, data);
         let display = Rc::new(WlDisplay::new(&data));
@@ -273,6 +277,7 @@ pub struct Client {
     pub objects: Objects,
     swapchain: Rc<RefCell<OutBufferSwapchain>>,
     flush_request: AsyncEvent,
+    flushed: AsyncEvent,
     shutdown: AsyncEvent,
     pub tracker: Tracker<Client>,
     pub is_xwayland: bool,
@@ -288,6 +293,7 @@ pub struct Client {
     pub commit_timelines: Rc<CommitTimelines>,
     pub wire_scale: Cell<Option<i32>>,
     pub focus_stealing_serial: Cell<Option<u64>>,
+    pub screencopies_without_buffer: NumCell<u32>,
 }
 
 pub const NUM_CACHED_SERIAL_RANGES: usize = 64;
@@ -306,6 +312,12 @@ impl Client {
             obj.id(),
             obj.interface().name(),
         );
+        self.state.client_errors.add(
+            self,
+            obj.interface().name(),
+            &request.to_string(),
+            "Invalid request",
+        );
         match self.display() {
             Ok(d) => {
                 d.send_invalid_request(obj, request);
@@ -382,6 +394,7 @@ impl Client {
     pub fn error(&self, message: impl Error) {
         let msg = ErrorFmt(message).to_string();
         log::error!("Client {}: A fatal error occurred: {}", self.id.0, msg,);
+        self.state.client_errors.add(self, "", "", &msg);
         match self.display() {
             Ok(d) => {
                 d.send_implementation_error(msg);
@@ -441,6 +454,13 @@ impl Client {
         self.checking_queue_size.set(false);
     }
 
+    /// Waits until the client has fetched most of the events that were sent to it.
+    pub async fn wait_for_event_backlog(&self) {
+        while self.swapchain.borrow().is_backlogged() {
+            self.flushed.triggered().await;
+        }
+    }
+
     pub fn lock_registries(&self) -> Locked<WlRegistryId, Rc<WlRegistry>> {
         self.objects.registries()
     }
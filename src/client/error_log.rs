@@ -0,0 +1,56 @@
+use {
+    crate::{
+        client::{Client, ClientId},
+        ifs::jay_client_errors::JayClientErrors,
+        utils::copyhashmap::CopyHashMap,
+        wire::JayClientErrorsId,
+    },
+    std::{cell::RefCell, collections::VecDeque, rc::Rc},
+};
+
+const MAX_ERRORS: usize = 32;
+
+pub struct ClientErrorEntry {
+    pub client_id: ClientId,
+    pub comm: String,
+    pub interface: String,
+    pub request: String,
+    pub msg: String,
+}
+
+#[derive(Default)]
+pub struct ClientErrorLog {
+    errors: RefCell<VecDeque<Rc<ClientErrorEntry>>>,
+    pub watchers: CopyHashMap<(ClientId, JayClientErrorsId), Rc<JayClientErrors>>,
+}
+
+impl ClientErrorLog {
+    pub fn add(&self, client: &Client, interface: &str, request: &str, msg: &str) {
+        let entry = Rc::new(ClientErrorEntry {
+            client_id: client.id,
+            comm: client.pid_info.comm.clone(),
+            interface: interface.to_string(),
+            request: request.to_string(),
+            msg: msg.to_string(),
+        });
+        {
+            let errors = &mut *self.errors.borrow_mut();
+            if errors.len() >= MAX_ERRORS {
+                errors.pop_front();
+            }
+            errors.push_back(entry.clone());
+        }
+        for watcher in self.watchers.lock().values() {
+            watcher.send_error(&entry);
+        }
+    }
+
+    pub fn errors(&self) -> Vec<Rc<ClientErrorEntry>> {
+        self.errors.borrow().iter().cloned().collect()
+    }
+
+    pub fn clear(&self) {
+        self.errors.borrow_mut().clear();
+        self.watchers.clear();
+    }
+}
@@ -95,13 +95,28 @@ async fn receive(data: Rc<Client>) {
             log::info!("Client {} terminated the connection", data.id.0);
             data.state.clients.kill(data.id);
         } else {
+            let (interface, method) = match &e {
+                ClientError::RequestError(e) => match &**e {
+                    ClientError::MethodError {
+                        interface, method, ..
+                    } => (interface.name(), *method),
+                    _ => ("", ""),
+                },
+                _ => ("", ""),
+            };
+            let invalid_method = matches!(e, ClientError::InvalidMethod);
             let e = ErrorFmt(e);
             log::error!(
                 "An error occurred while trying to handle a message from client {}: {}",
                 data.id.0,
                 e
             );
-            display.send_implementation_error(e.to_string());
+            let msg = e.to_string();
+            if !invalid_method {
+                // invalid requests have already been recorded by invalid_request
+                data.state.client_errors.add(&data, interface, method, &msg);
+            }
+            display.send_implementation_error(msg);
             data.state.clients.shutdown(data.id);
         }
     }
@@ -117,12 +132,16 @@ async fn send(data: Rc<Client>) {
                 let mut swapchain = data.swapchain.borrow_mut();
                 swapchain.commit();
                 mem::swap(&mut swapchain.pending, &mut buffers);
+                swapchain.in_flight = buffers.len();
             }
             let timeout = data.state.now() + Duration::from_millis(5000);
             while let Some(mut cur) = buffers.pop_front() {
                 out.flush(&mut cur, timeout).await?;
-                data.swapchain.borrow_mut().free.push(cur);
+                let mut swapchain = data.swapchain.borrow_mut();
+                swapchain.free.push(cur);
+                swapchain.in_flight -= 1;
             }
+            data.flushed.trigger();
         }
     };
     let res: Result<(), ClientError> = send.await;
@@ -9,7 +9,7 @@ use {
             dummy::{DummyBackend, DummyOutput},
             metal, x,
         },
-        cli::{CliBackend, GlobalArgs, RunArgs},
+        cli::{CliBackend, CliConfigErrorAction, GlobalArgs, RunArgs},
         client::{ClientId, Clients},
         clientmem::{self, ClientMemError},
         config::ConfigProxy,
@@ -20,6 +20,7 @@ use {
         forker,
         globals::Globals,
         ifs::{
+            jay_clipboard::send_clipboard_changes,
             jay_screencast::{perform_screencast_realloc, perform_toplevel_screencasts},
             wl_output::{OutputId, PersistentOutputState, WlOutputGlobal},
             wl_surface::{zwp_input_popup_surface_v2::input_popup_positioning, NoneSurfaceExt},
@@ -41,9 +42,9 @@ use {
         },
         user_session::import_environment,
         utils::{
-            clonecell::CloneCell, errorfmt::ErrorFmt, fdcloser::FdCloser, numcell::NumCell,
-            oserror::OsError, queue::AsyncQueue, refcounted::RefCounted, run_toplevel::RunToplevel,
-            tri::Try,
+            asyncevent::AsyncEvent, clonecell::CloneCell, errorfmt::ErrorFmt,
+            event_listener::EventListener, fdcloser::FdCloser, numcell::NumCell, oserror::OsError,
+            queue::AsyncQueue, refcounted::RefCounted, run_toplevel::RunToplevel, tri::Try,
         },
         version::VERSION,
         video::drm::wait_for_sync_obj::WaitForSyncObj,
@@ -53,7 +54,15 @@ use {
     ahash::AHashSet,
     forker::ForkerProxy,
     jay_config::{_private::DEFAULT_SEAT_NAME, video::GfxApi},
-    std::{cell::Cell, env, future::Future, ops::Deref, rc::Rc, sync::Arc, time::Duration},
+    std::{
+        cell::Cell,
+        env,
+        future::Future,
+        ops::Deref,
+        rc::{Rc, Weak},
+        sync::Arc,
+        time::Duration,
+    },
     thiserror::Error,
     uapi::c,
 };
@@ -142,7 +151,14 @@ fn start_compositor2(
     let xkb_keymap = xkb_ctx.keymap_from_str(include_str!("keymap.xkb")).unwrap();
     let engine = AsyncEngine::new();
     let ring = IoUring::new(&engine, 32)?;
-    let _signal_future = sighand::install(&engine, &ring)?;
+    let config_reload_requested = Rc::new(AsyncEvent::default());
+    let state_dump_requested = Rc::new(AsyncEvent::default());
+    let _signal_future = sighand::install(
+        &engine,
+        &ring,
+        &config_reload_requested,
+        &state_dump_requested,
+    )?;
     let wheel = Wheel::new(&engine, &ring)?;
     let (_run_toplevel_future, run_toplevel) = RunToplevel::install(&engine);
     let node_ids = NodeIds::default();
@@ -167,6 +183,7 @@ fn start_compositor2(
         connector_ids: Default::default(),
         root: Rc::new(DisplayNode::new(node_ids.next())),
         workspaces: Default::default(),
+        workspace_output_affinities: Default::default(),
         dummy_output: Default::default(),
         node_ids,
         backend_events: AsyncQueue::new(),
@@ -188,6 +205,7 @@ fn start_compositor2(
         pending_input_popup_positioning: Default::default(),
         pending_toplevel_screencasts: Default::default(),
         pending_screencast_reallocs_or_reconfigures: Default::default(),
+        pending_clipboard_changes: Default::default(),
         pending_placeholder_render_textures: Default::default(),
         dbus: Dbus::new(&engine, &ring, &run_toplevel),
         fdcloser: FdCloser::new(),
@@ -196,6 +214,9 @@ fn start_compositor2(
         outputs: Default::default(),
         drm_devs: Default::default(),
         status: Default::default(),
+        config_status: Default::default(),
+        status_hint: Default::default(),
+        config_safe_mode: Cell::new(false),
         idle: IdleState {
             input: Default::default(),
             change: Default::default(),
@@ -204,6 +225,7 @@ fn start_compositor2(
             inhibitors: Default::default(),
             inhibitors_changed: Default::default(),
             backend_idle: Cell::new(true),
+            grace_period: Default::default(),
         },
         run_args,
         xwayland: XWaylandState {
@@ -218,7 +240,13 @@ fn start_compositor2(
         serial: Default::default(),
         idle_inhibitor_ids: Default::default(),
         run_toplevel,
-        config_dir: config_dir(),
+        config_reload_requested,
+        state_dump_requested,
+        // Tests must not load the config of the user running them.
+        config_dir: match test_future.is_some() {
+            true => None,
+            false => config_dir(),
+        },
         config_file_id: NumCell::new(1),
         tracker: Default::default(),
         data_offer_ids: Default::default(),
@@ -231,10 +259,16 @@ fn start_compositor2(
         },
         scales,
         cursor_sizes: Default::default(),
+        cursor_themes: Default::default(),
+        themed_cursors: Default::default(),
         hardware_tick_cursor: Default::default(),
         testers: Default::default(),
         render_ctx_watchers: Default::default(),
         workspace_watchers: Default::default(),
+        output_watchers: Default::default(),
+        output_extents_batched: Default::default(),
+        output_extents_change_pending: Default::default(),
+        client_errors: Default::default(),
         default_workspace_capture: Cell::new(true),
         default_gfx_api: Cell::new(GfxApi::Vulkan),
         activation_tokens: Default::default(),
@@ -263,6 +297,7 @@ fn start_compositor2(
         default_vrr_mode: Cell::new(VrrMode::NEVER),
         default_vrr_cursor_hz: Cell::new(None),
         default_tearing_mode: Cell::new(TearingMode::VARIANT_3),
+        default_color_filter: Default::default(),
         ei_acceptor: Default::default(),
         ei_acceptor_future: Default::default(),
         enable_ei_acceptor: Default::default(),
@@ -271,10 +306,25 @@ fn start_compositor2(
         cpu_worker,
         ui_drag_enabled: Cell::new(true),
         ui_drag_threshold_squared: Cell::new(10),
+        pointer_locator_duration_usec: Cell::new(1_000_000),
+        pointer_edge_behaviors: Default::default(),
+        workspace_scroll_inverted: Cell::new(false),
+        workspace_scroll_threshold: Cell::new(1),
+        workspace_scroll_wrap: Cell::new(false),
+        workspace_scroll_skip_empty: Cell::new(false),
+        output_throttle_frame_nsec: Default::default(),
+        output_throttle_delay_nsec: Cell::new(5_000_000_000),
+        max_zoom: Cell::new(8.0),
+        scale_overrides: Default::default(),
+        decoration_modes: Default::default(),
+        window_borders: Default::default(),
+        capture_blocked_app_ids: Default::default(),
+        show_focused_output: Cell::new(false),
         toplevels: Default::default(),
         const_40hz_latch: Default::default(),
         tray_item_ids: Default::default(),
         data_control_device_ids: Default::default(),
+        shared_clipboard: Cell::new(false),
     });
     state.tracker.register(ClientId::from_raw(0));
     create_dummy_output(&state);
@@ -325,7 +375,10 @@ async fn start_compositor3(state: Rc<State>, test_future: Option<TestFuture>) {
         }
     }
 
-    let config = load_config(&state, is_test);
+    let Some(config) = load_config(&state, is_test) else {
+        state.ring.stop();
+        return;
+    };
     config.configure(false);
     state.config.set(Some(Rc::new(config)));
 
@@ -347,19 +400,35 @@ async fn start_compositor3(state: Rc<State>, test_future: Option<TestFuture>) {
 fn load_config(
     state: &Rc<State>,
     #[allow(clippy::allow_attributes, unused_variables)] for_test: bool,
-) -> ConfigProxy {
+) -> Option<ConfigProxy> {
     #[cfg(feature = "it")]
     if for_test {
-        return ConfigProxy::for_test(state);
+        return Some(ConfigProxy::for_test(state));
     }
-    match ConfigProxy::from_config_dir(state) {
-        Ok(c) => c,
-        Err(e) => {
-            log::warn!("Could not load config.so: {}", ErrorFmt(e));
-            log::warn!("Using default config");
-            ConfigProxy::default(state)
+    let e = match ConfigProxy::from_config_dir(state) {
+        Ok(c) => return Some(c),
+        Err(e) => e,
+    };
+    if e.is_missing() {
+        log::info!("Could not load config.so: {}", ErrorFmt(e));
+        log::info!("Using default config");
+        return Some(ConfigProxy::default(state));
+    }
+    log::error!("Could not load config.so: {}", ErrorFmt(e));
+    match state.run_args.on_config_error {
+        CliConfigErrorAction::UseDefault => {
+            log::error!("Using default config");
+        }
+        CliConfigErrorAction::SafeMode => {
+            log::error!("Using default config in safe mode");
+            state.enter_config_safe_mode();
+        }
+        CliConfigErrorAction::Exit => {
+            log::error!("Refusing to start without the config");
+            return None;
         }
     }
+    Some(ConfigProxy::default(state))
 }
 
 fn start_global_event_handlers(
@@ -374,6 +443,14 @@ fn start_global_event_handlers(
             tasks::handle_backend_events(state.clone()),
         ),
         eng.spawn("slow client", tasks::handle_slow_clients(state.clone())),
+        eng.spawn(
+            "config reload requests",
+            tasks::handle_config_reload_requests(state.clone()),
+        ),
+        eng.spawn(
+            "state dump requests",
+            tasks::handle_state_dump_requests(state.clone()),
+        ),
         eng.spawn(
             "handware cursor tick",
             tasks::handle_hardware_cursor_tick(state.clone()),
@@ -429,6 +506,11 @@ fn start_global_event_handlers(
             Phase::PostLayout,
             perform_screencast_realloc(state.clone()),
         ),
+        eng.spawn2(
+            "clipboard changes",
+            Phase::PostLayout,
+            send_clipboard_changes(state.clone()),
+        ),
         eng.spawn2(
             "visualize damage",
             Phase::PostLayout,
@@ -517,9 +599,22 @@ fn create_dummy_output(state: &Rc<State>) {
         transform: Default::default(),
         scale: Default::default(),
         pos: Default::default(),
+        mode: Default::default(),
         vrr_mode: Cell::new(VrrMode::NEVER),
         vrr_cursor_hz: Default::default(),
         tearing_mode: Cell::new(&TearingMode::Never),
+        vblank_cursor_updates: Default::default(),
+        use_hardware_cursor: Cell::new(true),
+        color_filter: Default::default(),
+        invert_colors: Default::default(),
+        workspace_capture: Default::default(),
+        workspace_scroll_wrap: Default::default(),
+        workspace_scroll_skip_empty: Default::default(),
+        flip_margin_ns: Default::default(),
+        game_mode: Default::default(),
+        vrr_min_refresh_hz: Default::default(),
+        max_scale: Default::default(),
+        background: Default::default(),
     });
     let connector = Rc::new(DummyOutput {
         id: state.connector_ids.next(),
@@ -540,7 +635,7 @@ fn create_dummy_output(state: &Rc<State>) {
         &connector_data,
         &persistent_state,
     ));
-    let dummy_output = Rc::new(OutputNode {
+    let dummy_output = Rc::new_cyclic(|slf: &Weak<OutputNode>| OutputNode {
         id: state.node_ids.next(),
         global: Rc::new(WlOutputGlobal::new(
             state.globals.name(),
@@ -558,6 +653,8 @@ fn create_dummy_output(state: &Rc<State>) {
             &persistent_state,
         )),
         jay_outputs: Default::default(),
+        presentation_feeds: Default::default(),
+        frame_timings: Default::default(),
         workspaces: Default::default(),
         workspace: Default::default(),
         seat_state: Default::default(),
@@ -570,18 +667,28 @@ fn create_dummy_output(state: &Rc<State>) {
         state: state.clone(),
         is_dummy: true,
         status: Default::default(),
+        status_max_width: Default::default(),
         scroll: Default::default(),
+        workspace_scroll_steps: Default::default(),
+        workspace_scroll_wrap: Default::default(),
+        workspace_scroll_skip_empty: Default::default(),
         pointer_positions: Default::default(),
         pointer_down: Default::default(),
         lock_surface: Default::default(),
         hardware_cursor: Default::default(),
+        backend_hardware_cursor: Default::default(),
+        hardware_cursor_unavailable_logged: Default::default(),
         update_render_data_scheduled: Cell::new(false),
         screencasts: Default::default(),
         hardware_cursor_needs_render: Cell::new(false),
+        hardware_cursor_vblank_pending: Cell::new(false),
+        cursor_vblank_listener: EventListener::new(slf.clone()),
         screencopies: Default::default(),
         title_visible: Cell::new(false),
+        focus_indicator: Cell::new(false),
         schedule,
         vblank_event: Default::default(),
+        passive_vblank_event: Default::default(),
         latch_event: Default::default(),
         presentation_event: Default::default(),
         render_margin_ns: Default::default(),
@@ -590,6 +697,26 @@ fn create_dummy_output(state: &Rc<State>) {
         before_latch_event: Default::default(),
         tray_start_rel: Default::default(),
         tray_items: Default::default(),
+        stats: Default::default(),
+        zoom: Cell::new(1.0),
+        zoom_seat: Default::default(),
+        title_bar_position: Default::default(),
+        capture_buffer: Default::default(),
+        color_filter_buffer: Default::default(),
+        power: Cell::new(true),
+        gamma_ramp: Default::default(),
+        span_secondary: Default::default(),
+        span_primary: Default::default(),
+        game_mode_active: Default::default(),
+        title_double_click_state: Default::default(),
+        title_last_click: Default::default(),
+        requested_scale: Default::default(),
+        max_scale: Default::default(),
+        mirror_source: Default::default(),
+        mirrors: Default::default(),
+        screencopy_damage: Default::default(),
+        screencopy_damage_seq: Default::default(),
+        screencopy_damage_latched: Default::default(),
     });
     let dummy_workspace = Rc::new(WorkspaceNode {
         id: state.node_ids.next(),
@@ -600,12 +727,13 @@ fn create_dummy_output(state: &Rc<State>) {
         container: Default::default(),
         stacked: Default::default(),
         seat_state: Default::default(),
-        name: "dummy".to_string(),
+        name: CloneCell::new(Rc::new("dummy".to_string())),
         output_link: Default::default(),
         visible: Default::default(),
         fullscreen: Default::default(),
         visible_on_desired_output: Default::default(),
         desired_output: CloneCell::new(dummy_output.global.output_id.clone()),
+        fallback_output: Default::default(),
         jay_workspaces: Default::default(),
         may_capture: Cell::new(false),
         has_capture: Cell::new(false),
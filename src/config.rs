@@ -6,7 +6,7 @@ use {
     crate::{
         backend::{ConnectorId, DrmDeviceId, InputDeviceId},
         config::handler::ConfigProxyHandler,
-        ifs::wl_seat::SeatId,
+        ifs::wl_seat::{SeatId, SequenceKey},
         state::State,
         utils::{
             clonecell::CloneCell, numcell::NumCell, ptr_ext::PtrExt, unlink_on_drop::UnlinkOnDrop,
@@ -20,21 +20,23 @@ use {
             ipc::{InitMessage, ServerFeature, ServerMessage, V1InitMessage},
             ConfigEntry, VERSION,
         },
-        input::{InputDevice, Seat, SwitchEvent},
+        input::{
+            DesktopClick, HotCorner, HotCornerEvent, InputDevice, PointerTrigger, Seat, SwitchEvent,
+        },
         keyboard::{mods::Modifiers, syms::KeySym},
         video::{Connector, DrmDevice},
     },
     libloading::Library,
-    std::{cell::Cell, io, mem, ptr, rc::Rc},
+    std::{cell::Cell, io, mem, ptr, rc::Rc, time::Duration},
     thiserror::Error,
 };
 
 #[derive(Debug, Error)]
 pub enum ConfigError {
-    #[error("Could not load the config library")]
-    CouldNotLoadLibrary(#[source] libloading::Error),
-    #[error("Config library does not contain the entry symbol")]
-    LibraryDoesNotContainEntry(#[source] libloading::Error),
+    #[error("Could not load the config library {0}")]
+    CouldNotLoadLibrary(String, #[source] libloading::Error),
+    #[error("Config library {0} does not contain the entry symbol")]
+    LibraryDoesNotContainEntry(String, #[source] libloading::Error),
     #[error("Could not determine the config directory")]
     ConfigDirNotSet,
     #[error("Could not copy the config file")]
@@ -43,6 +45,17 @@ pub enum ConfigError {
     XrdNotSet,
 }
 
+impl ConfigError {
+    /// Returns whether the error was caused by the user not having a config library.
+    pub fn is_missing(&self) -> bool {
+        match self {
+            ConfigError::ConfigDirNotSet => true,
+            ConfigError::CopyConfigFile(e) => e.kind() == io::ErrorKind::NotFound,
+            _ => false,
+        }
+    }
+}
+
 pub struct ConfigProxy {
     handler: CloneCell<Option<Rc<ConfigProxyHandler>>>,
 }
@@ -81,6 +94,13 @@ impl ConfigProxy {
         self.send(&msg);
     }
 
+    pub fn invoke_key_sequence(&self, seat: SeatId, keys: &[SequenceKey]) {
+        self.send(&ServerMessage::InvokeKeySequence {
+            seat: Seat(seat.raw() as _),
+            keys: keys.iter().copied().map(Into::into).collect(),
+        });
+    }
+
     pub fn new_drm_dev(&self, dev: DrmDeviceId) {
         self.send(&ServerMessage::NewDrmDev {
             device: DrmDevice(dev.raw() as _),
@@ -145,6 +165,31 @@ impl ConfigProxy {
         self.send(&ServerMessage::Idle);
     }
 
+    pub fn idle_time(&self, seat: SeatId, idle: Duration) {
+        self.send(&ServerMessage::IdleTime {
+            seat: Seat(seat.raw() as _),
+            idle,
+        });
+    }
+
+    pub fn hot_corner(&self, seat: SeatId, connector: ConnectorId, corner: HotCorner) {
+        self.send(&ServerMessage::HotCorner {
+            seat: Seat(seat.raw() as _),
+            event: HotCornerEvent {
+                connector: Connector(connector.raw() as _),
+                corner,
+            },
+        });
+    }
+
+    pub fn invoke_pointer_shortcut(&self, seat: SeatId, mods: Modifiers, trigger: PointerTrigger) {
+        self.send(&ServerMessage::InvokePointerShortcut {
+            seat: Seat(seat.raw() as _),
+            mods,
+            trigger,
+        });
+    }
+
     pub fn switch_event(&self, seat: SeatId, input_device: InputDeviceId, event: SwitchEvent) {
         self.send(&ServerMessage::SwitchEvent {
             seat: Seat(seat.raw() as _),
@@ -152,6 +197,25 @@ impl ConfigProxy {
             event,
         });
     }
+
+    pub fn desktop_click(&self, seat: SeatId, connector: ConnectorId, button: u32, x: i32, y: i32) {
+        self.send(&ServerMessage::DesktopClick {
+            seat: Seat(seat.raw() as _),
+            click: DesktopClick {
+                connector: Connector(connector.raw() as _),
+                button,
+                x,
+                y,
+            },
+        });
+    }
+
+    pub fn keyboard_layout_changed(&self, seat: SeatId, name: &str) {
+        self.send(&ServerMessage::KeyboardLayoutChanged {
+            seat: Seat(seat.raw() as _),
+            name: name.to_string(),
+        });
+    }
 }
 
 impl Drop for ConfigProxy {
@@ -283,12 +347,12 @@ impl ConfigProxy {
         let unlink = UnlinkOnDrop(&copy);
         let lib = match unsafe { Library::new(&copy) } {
             Ok(l) => l,
-            Err(e) => return Err(ConfigError::CouldNotLoadLibrary(e)),
+            Err(e) => return Err(ConfigError::CouldNotLoadLibrary(path.to_string(), e)),
         };
         let entry = unsafe { lib.get::<&'static ConfigEntry>(b"JAY_CONFIG_ENTRY_V1\0") };
         let entry = match entry {
             Ok(e) => *e,
-            Err(e) => return Err(ConfigError::LibraryDoesNotContainEntry(e)),
+            Err(e) => return Err(ConfigError::LibraryDoesNotContainEntry(path.to_string(), e)),
         };
         mem::forget(unlink);
         Ok(Self::new(Some(lib), entry, state, Some(copy)))
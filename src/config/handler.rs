@@ -6,17 +6,21 @@ use {
             InputDeviceId,
         },
         compositor::MAX_EXTENTS,
-        config::ConfigProxy,
         format::config_formats,
-        ifs::wl_seat::{SeatId, WlSeatGlobal},
+        gfx_api::ColorMatrix,
+        ifs::{
+            wl_output::OutputId,
+            wl_seat::{SeatId, SequenceKey, WlSeatGlobal},
+        },
         io_uring::TaskResultExt,
         output_schedule::map_cursor_hz,
         scale::Scale,
         state::{ConnectorData, DeviceHandlerData, DrmDevData, OutputData, State},
         theme::{Color, ThemeSized},
         tree::{
-            move_ws_to_output, ContainerNode, ContainerSplit, FloatNode, Node, NodeVisitorBase,
-            OutputNode, TearingMode, VrrMode, WsMoveConfig,
+            ContainerNode, ContainerSplit, DecorationMode, FloatNode, Node, NodeVisitorBase,
+            OutputMirrorError, OutputNode, OutputSpanError, TearingMode, TitleBarPosition, VrrMode,
+            WindowBorder, WorkspaceOutputAffinity,
         },
         utils::{
             asyncevent::AsyncEvent,
@@ -43,16 +47,19 @@ use {
                 Capability, CAP_GESTURE, CAP_KEYBOARD, CAP_POINTER, CAP_SWITCH, CAP_TABLET_PAD,
                 CAP_TABLET_TOOL, CAP_TOUCH,
             },
-            FocusFollowsMouseMode, InputDevice, Seat,
+            FocusFollowsMouseMode, InputDevice, OutputEdge, PointerEdgeBehavior, PointerPosition,
+            PointerTrigger, Seat,
         },
-        keyboard::{mods::Modifiers, syms::KeySym, Keymap},
+        keyboard::{mods::Modifiers, syms::KeySym, Keymap, ModifiedKeySym},
         logging::LogLevel,
         theme::{colors::Colorable, sized::Resizable},
         timer::Timer as JayTimer,
         video::{
-            Connector, DrmDevice, Format as ConfigFormat, GfxApi, TearingMode as ConfigTearingMode,
-            Transform, VrrMode as ConfigVrrMode,
+            ColorMatrix as ConfigColorMatrix, Connector, DrmDevice, Format as ConfigFormat, GfxApi,
+            OutputId as ConfigOutputId, TearingMode as ConfigTearingMode,
+            TitleBarPosition as ConfigTitleBarPosition, Transform, VrrMode as ConfigVrrMode,
         },
+        window::DecorationMode as ConfigDecorationMode,
         xwayland::XScalingMode,
         Axis, Direction, Workspace,
     },
@@ -266,22 +273,9 @@ impl ConfigProxyHandler {
     }
 
     fn handle_reload(&self) {
-        log::info!("Reloading config");
-        let config = match ConfigProxy::from_config_dir(&self.state) {
-            Ok(c) => c,
-            Err(e) => {
-                log::error!("Cannot reload config: {}", ErrorFmt(e));
-                return;
-            }
-        };
-        if let Some(config) = self.state.config.take() {
-            config.destroy();
-            for seat in self.state.globals.seats.lock().values() {
-                seat.clear_shortcuts();
-            }
+        if let Err(e) = self.state.reload_config() {
+            log::error!("Cannot reload config: {}", ErrorFmt(e));
         }
-        config.configure(true);
-        self.state.config.set(Some(Rc::new(config)));
     }
 
     fn handle_get_fullscreen(&self, seat: Seat) -> Result<(), CphError> {
@@ -309,6 +303,21 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_layouts(&self, seat: Seat, layouts: Vec<Keymap>) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let mut keymaps = vec![];
+        for keymap in layouts {
+            keymaps.push(self.get_keymap(keymap)?);
+        }
+        seat.set_layouts(keymaps);
+        Ok(())
+    }
+
+    fn handle_cycle_layout(&self, seat: Seat) -> Result<(), CphError> {
+        self.get_seat(seat)?.cycle_layout();
+        Ok(())
+    }
+
     fn handle_set_device_keymap(
         &self,
         device: InputDevice,
@@ -324,6 +333,24 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_device_repeat_rate(
+        &self,
+        device: InputDevice,
+        rate: Option<(i32, i32)>,
+    ) -> Result<(), CphError> {
+        let dev = self.get_device_handler_data(device)?;
+        if let Some((rate, delay)) = rate {
+            if rate < 0 {
+                return Err(CphError::NegativeRepeatRate);
+            }
+            if delay < 0 {
+                return Err(CphError::NegativeRepeatDelay);
+            }
+        }
+        dev.set_repeat_rate(rate);
+        Ok(())
+    }
+
     fn handle_set_forward(&self, seat: Seat, forward: bool) -> Result<(), CphError> {
         let seat = self.get_seat(seat)?;
         seat.set_forward(forward);
@@ -409,10 +436,10 @@ impl ConfigProxyHandler {
     fn handle_get_workspaces(&self) {
         let mut workspaces = vec![];
         for ws in self.state.workspaces.lock().values() {
-            let id = match self.workspaces_by_name.get(&ws.name) {
+            let name = ws.name.get();
+            let id = match self.workspaces_by_name.get(&name) {
                 None => {
                     let id = self.workspace_ids.fetch_add(1);
-                    let name = Rc::new(ws.name.clone());
                     self.workspaces_by_name.set(name.clone(), id);
                     self.workspaces_by_id.set(id, name);
                     id
@@ -504,6 +531,157 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_get_keyboard_layout(&self, seat: Seat) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        self.respond(Response::GetKeyboardLayout {
+            name: seat.keyboard_layout().to_string(),
+        });
+        Ok(())
+    }
+
+    fn handle_get_idle_time(&self, seat: Seat) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        self.respond(Response::GetIdleTime {
+            idle: seat.idle_time(),
+        });
+        Ok(())
+    }
+
+    fn handle_get_pointer_position(&self, seat: Seat) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let (x, y) = seat.pointer_cursor().position();
+        let (x, y) = (x.to_f64(), y.to_f64());
+        let output = seat.get_output();
+        let pos = output.global.pos.get();
+        self.respond(Response::GetPointerPosition {
+            position: PointerPosition {
+                x,
+                y,
+                connector: Connector(output.global.connector.connector.id().raw() as _),
+                output_x: x - pos.x1() as f64,
+                output_y: y - pos.y1() as f64,
+                output_width: pos.width(),
+                output_height: pos.height(),
+            },
+        });
+        Ok(())
+    }
+
+    fn handle_set_idle_time_interval(
+        &self,
+        seat: Seat,
+        interval: Duration,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_idle_time_interval(interval);
+        Ok(())
+    }
+
+    fn handle_add_key_sequence(
+        &self,
+        seat: Seat,
+        keys: Vec<ModifiedKeySym>,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.add_key_sequence(keys.into_iter().map(SequenceKey::from).collect());
+        Ok(())
+    }
+
+    fn handle_remove_key_sequence(
+        &self,
+        seat: Seat,
+        keys: Vec<ModifiedKeySym>,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let keys: Vec<_> = keys.into_iter().map(SequenceKey::from).collect();
+        seat.remove_key_sequence(&keys);
+        Ok(())
+    }
+
+    fn handle_set_key_sequence_timeout(
+        &self,
+        seat: Seat,
+        timeout: Duration,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let ms = timeout.as_millis().min(u64::MAX as u128) as u64;
+        seat.set_key_sequence_timeout(ms);
+        Ok(())
+    }
+
+    fn handle_set_forward_aborted_key_sequences(
+        &self,
+        seat: Seat,
+        forward: bool,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_forward_aborted_key_sequences(forward);
+        Ok(())
+    }
+
+    fn handle_set_show_key_sequence_hint(&self, seat: Seat, show: bool) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_show_key_sequence_hint(show);
+        Ok(())
+    }
+
+    fn handle_set_hot_corner_size(&self, seat: Seat, size: i32) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_hot_corner_size(size);
+        Ok(())
+    }
+
+    fn handle_set_hot_corner_dwell(&self, seat: Seat, dwell: Duration) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let ms = dwell.as_millis().min(u64::MAX as u128) as u64;
+        seat.set_hot_corner_dwell(ms);
+        Ok(())
+    }
+
+    fn handle_set_focus_follows_mouse_dwell(
+        &self,
+        seat: Seat,
+        dwell: Duration,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let ms = dwell.as_millis().min(u64::MAX as u128) as u64;
+        seat.set_focus_follows_mouse_dwell(ms);
+        Ok(())
+    }
+
+    fn handle_set_warp_pointer_on_focus(&self, seat: Seat, warp: bool) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_warp_pointer_on_focus(warp);
+        Ok(())
+    }
+
+    fn handle_set_hide_cursor_while_typing(&self, seat: Seat, hide: bool) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_hide_cursor_while_typing(hide);
+        Ok(())
+    }
+
+    fn handle_set_hide_cursor_timeout(
+        &self,
+        seat: Seat,
+        timeout: Duration,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let ms = timeout.as_millis().min(u64::MAX as u128) as u64;
+        seat.set_hide_cursor_timeout(ms);
+        Ok(())
+    }
+
+    fn handle_set_shortcuts_inhibit_escape(
+        &self,
+        seat: Seat,
+        escape: Option<ModifiedKeySym>,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_shortcuts_inhibit_escape(escape.map(|e| (e.mods, e.sym)));
+        Ok(())
+    }
+
     fn handle_set_repeat_rate(&self, seat: Seat, rate: i32, delay: i32) -> Result<(), CphError> {
         let seat = self.get_seat(seat)?;
         if rate < 0 {
@@ -639,7 +817,7 @@ impl ConfigProxyHandler {
 
     fn handle_set_accel_speed(&self, device: InputDevice, speed: f64) -> Result<(), CphError> {
         let dev = self.get_device_handler_data(device)?;
-        dev.device.set_accel_speed(speed);
+        dev.device.set_accel_speed(speed.clamp(-1.0, 1.0));
         Ok(())
     }
 
@@ -771,10 +949,286 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_scale_override(&self, app_id: &str, scale: Option<f64>) -> Result<(), CphError> {
+        match scale {
+            Some(scale) => {
+                if scale < 0.1 {
+                    return Err(CphError::ScaleTooSmall(scale));
+                }
+                if scale > 1000.0 {
+                    return Err(CphError::ScaleTooLarge(scale));
+                }
+                let scale = Scale::from_f64(scale);
+                self.state.scale_overrides.set(app_id.to_string(), scale);
+            }
+            None => {
+                self.state.scale_overrides.remove(app_id);
+            }
+        }
+        for tl in self.state.toplevels.lock().values() {
+            if let Some(tl) = tl.upgrade() {
+                tl.tl_data().update_scale_override();
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_set_decoration_mode(
+        &self,
+        app_id: &str,
+        mode: Option<ConfigDecorationMode>,
+    ) -> Result<(), CphError> {
+        match mode {
+            Some(mode) => {
+                let mode = match mode {
+                    ConfigDecorationMode::SERVER => DecorationMode::Server,
+                    ConfigDecorationMode::CLIENT => DecorationMode::Client,
+                    ConfigDecorationMode::NONE => DecorationMode::None,
+                    _ => return Err(CphError::UnknownDecorationMode(mode)),
+                };
+                self.state.decoration_modes.set(app_id.to_string(), mode);
+            }
+            None => {
+                self.state.decoration_modes.remove(app_id);
+            }
+        }
+        for tl in self.state.toplevels.lock().values() {
+            if let Some(tl) = tl.upgrade() {
+                tl.tl_data().update_decoration_mode();
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_set_block_capture(&self, app_id: &str, block: bool) {
+        if block {
+            self.state
+                .capture_blocked_app_ids
+                .set(app_id.to_string(), ());
+        } else {
+            self.state.capture_blocked_app_ids.remove(app_id);
+        }
+        for tl in self.state.toplevels.lock().values() {
+            if let Some(tl) = tl.upgrade() {
+                tl.tl_data().update_block_capture_rule();
+            }
+        }
+    }
+
+    fn handle_reset_window_rules(&self) {
+        self.state.scale_overrides.clear();
+        self.state.decoration_modes.clear();
+        self.state.capture_blocked_app_ids.clear();
+        self.state.window_borders.clear();
+        for tl in self.state.toplevels.lock().values() {
+            if let Some(tl) = tl.upgrade() {
+                let data = tl.tl_data();
+                data.update_scale_override();
+                data.update_decoration_mode();
+                data.update_block_capture_rule();
+                data.update_window_border();
+            }
+        }
+    }
+
+    fn update_window_border(
+        &self,
+        app_id: &str,
+        f: impl FnOnce(&mut WindowBorder),
+    ) -> Result<(), CphError> {
+        let mut border = self.state.window_borders.get(app_id).unwrap_or_default();
+        f(&mut border);
+        if border == WindowBorder::default() {
+            self.state.window_borders.remove(app_id);
+        } else {
+            self.state.window_borders.set(app_id.to_string(), border);
+        }
+        for tl in self.state.toplevels.lock().values() {
+            if let Some(tl) = tl.upgrade() {
+                tl.tl_data().update_window_border();
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_set_window_border_width(
+        &self,
+        app_id: &str,
+        width: Option<i32>,
+    ) -> Result<(), CphError> {
+        if let Some(width) = width {
+            let sized = ThemeSized::window_border_width;
+            if width < sized.min() || width > sized.max() {
+                return Err(CphError::InvalidSize(width, sized));
+            }
+        }
+        self.update_window_border(app_id, |b| b.width = width)
+    }
+
+    fn handle_set_window_border_color(
+        &self,
+        app_id: &str,
+        colorable: Colorable,
+        color: Option<jay_config::theme::Color>,
+    ) -> Result<(), CphError> {
+        use jay_config::theme::colors::*;
+        let color = color.map(Color::from);
+        match colorable {
+            FOCUSED_WINDOW_BORDER_COLOR => {
+                self.update_window_border(app_id, |b| b.focused_color = color)
+            }
+            UNFOCUSED_WINDOW_BORDER_COLOR => {
+                self.update_window_border(app_id, |b| b.unfocused_color = color)
+            }
+            ATTENTION_REQUESTED_WINDOW_BORDER_COLOR => {
+                self.update_window_border(app_id, |b| b.attention_requested_color = color)
+            }
+            _ => Err(CphError::NotAWindowBorderColor(colorable.0)),
+        }
+    }
+
+    fn handle_set_show_focused_output(&self, show: bool) {
+        self.state.show_focused_output.set(show);
+        for output in self.state.root.outputs.lock().values() {
+            output.update_focus_indicator();
+        }
+    }
+
+    fn handle_set_shared_clipboard(&self, shared: bool) {
+        self.state.shared_clipboard.set(shared);
+    }
+
     fn handle_set_ui_drag_enabled(&self, enabled: bool) {
         self.state.ui_drag_enabled.set(enabled);
     }
 
+    fn handle_set_workspace_scroll_inverted(&self, inverted: bool) {
+        self.state.workspace_scroll_inverted.set(inverted);
+    }
+
+    fn handle_set_workspace_scroll_threshold(&self, threshold: i32) {
+        self.state.workspace_scroll_threshold.set(threshold.max(1));
+    }
+
+    fn handle_set_workspace_scroll_wrap(&self, wrap: bool) {
+        self.state.workspace_scroll_wrap.set(wrap);
+        for output in self.state.root.outputs.lock().values() {
+            output.update_workspace_scroll_behavior();
+        }
+    }
+
+    fn handle_set_workspace_scroll_skip_empty(&self, skip_empty: bool) {
+        self.state.workspace_scroll_skip_empty.set(skip_empty);
+        for output in self.state.root.outputs.lock().values() {
+            output.update_workspace_scroll_behavior();
+        }
+    }
+
+    fn handle_connector_set_workspace_scroll_wrap(
+        &self,
+        connector: Connector,
+        wrap: Option<bool>,
+    ) -> Result<(), CphError> {
+        let output = self.get_output_node(connector)?;
+        output.global.persistent.workspace_scroll_wrap.set(wrap);
+        output.update_workspace_scroll_behavior();
+        Ok(())
+    }
+
+    fn handle_connector_set_workspace_scroll_skip_empty(
+        &self,
+        connector: Connector,
+        skip_empty: Option<bool>,
+    ) -> Result<(), CphError> {
+        let output = self.get_output_node(connector)?;
+        output
+            .global
+            .persistent
+            .workspace_scroll_skip_empty
+            .set(skip_empty);
+        output.update_workspace_scroll_behavior();
+        Ok(())
+    }
+
+    fn handle_connector_set_span(
+        &self,
+        connector: Connector,
+        secondary: Option<Connector>,
+    ) -> Result<(), CphError> {
+        let output = self.get_output_node(connector)?;
+        match secondary {
+            Some(secondary) => {
+                let secondary = self.get_output_node(secondary)?;
+                output.span(&secondary)?;
+            }
+            None => output.leave_span(),
+        }
+        Ok(())
+    }
+
+    fn handle_connector_set_game_mode(
+        &self,
+        connector: Connector,
+        enabled: bool,
+    ) -> Result<(), CphError> {
+        self.get_output_node(connector)?.set_game_mode(enabled);
+        Ok(())
+    }
+
+    fn handle_connector_set_vrr_min_refresh(
+        &self,
+        connector: Connector,
+        hz: Option<u32>,
+    ) -> Result<(), CphError> {
+        self.get_output_node(connector)?.set_vrr_min_refresh(hz);
+        Ok(())
+    }
+
+    fn handle_connector_set_max_scale(
+        &self,
+        connector: Connector,
+        scale: Option<f64>,
+    ) -> Result<(), CphError> {
+        if let Some(scale) = scale {
+            if scale < 0.1 {
+                return Err(CphError::ScaleTooSmall(scale));
+            }
+            if scale > 1000.0 {
+                return Err(CphError::ScaleTooLarge(scale));
+            }
+        }
+        self.get_output_node(connector)?
+            .set_max_scale(scale.map(Scale::from_f64));
+        Ok(())
+    }
+
+    fn handle_connector_set_mirror_source(
+        &self,
+        connector: Connector,
+        source: Option<Connector>,
+    ) -> Result<(), CphError> {
+        let output = self.get_output_node(connector)?;
+        let source = match source {
+            Some(source) => Some(self.get_output_node(source)?),
+            None => None,
+        };
+        output.set_mirror_source(source)?;
+        Ok(())
+    }
+
+    fn handle_set_output_throttle(&self, hz: f64) {
+        let frame_nsec = match hz > 0.0 && hz.is_finite() {
+            true => Some((1_000_000_000.0 / hz) as u64),
+            false => None,
+        };
+        self.state.output_throttle_frame_nsec.set(frame_nsec);
+    }
+
+    fn handle_set_output_throttle_delay(&self, delay: Duration) {
+        let nsec = delay.as_nanos().try_into().unwrap_or(u64::MAX);
+        self.state.output_throttle_delay_nsec.set(nsec);
+    }
+
     fn handle_set_ui_drag_threshold(&self, threshold: i32) {
         let threshold = threshold.max(1);
         let squared = threshold.saturating_mul(threshold);
@@ -820,7 +1274,7 @@ impl ConfigProxyHandler {
         let mut workspace = 0;
         if !output.is_dummy {
             if let Some(ws) = output.workspace.get() {
-                if let Some(ws) = self.workspaces_by_name.get(&ws.name) {
+                if let Some(ws) = self.workspaces_by_name.get(&ws.name.get()) {
                     workspace = ws;
                 }
             }
@@ -843,7 +1297,10 @@ impl ConfigProxyHandler {
         let name = self.get_workspace(ws)?;
         let workspace = match self.state.workspaces.get(name.deref()) {
             Some(ws) => ws,
-            _ => seat.get_output().create_workspace(name.deref()),
+            _ => self
+                .state
+                .new_workspace_output(&seat, &name)
+                .create_workspace(name.deref()),
         };
         seat.set_workspace(&workspace);
         Ok(())
@@ -893,25 +1350,49 @@ impl ConfigProxyHandler {
                 _ => return Ok(()),
             },
         };
-        if ws.is_dummy || output.is_dummy {
-            return Ok(());
-        }
-        if ws.output.get().id == output.id {
-            return Ok(());
-        }
-        let link = match &*ws.output_link.borrow() {
-            None => return Ok(()),
-            Some(l) => l.to_ref(),
+        output.move_workspace_here(&ws);
+        Ok(())
+    }
+
+    fn handle_set_workspace_output_affinity(
+        &self,
+        workspace: Workspace,
+        preferred: Option<ConfigOutputId>,
+        fallback: Option<ConfigOutputId>,
+    ) -> Result<(), CphError> {
+        let name = self.get_workspace(workspace)?;
+        let map = |id: ConfigOutputId| {
+            Rc::new(OutputId::new(
+                id.connector,
+                id.manufacturer,
+                id.model,
+                id.serial_number,
+            ))
         };
-        let config = WsMoveConfig {
-            make_visible_always: false,
-            make_visible_if_empty: true,
-            source_is_destroyed: false,
-            before: None,
+        let ws = self.state.workspaces.get(name.as_str());
+        let Some(preferred) = preferred else {
+            self.state.workspace_output_affinities.remove(name.as_str());
+            if let Some(ws) = ws {
+                ws.set_output_affinity(None);
+            }
+            return Ok(());
         };
-        move_ws_to_output(&link, &output, config);
-        ws.desired_output.set(output.global.output_id.clone());
-        self.state.tree_changed();
+        let affinity = Rc::new(WorkspaceOutputAffinity {
+            preferred: map(preferred),
+            fallback: fallback.map(map),
+        });
+        self.state
+            .workspace_output_affinities
+            .set(name.to_string(), affinity.clone());
+        if let Some(ws) = ws {
+            ws.set_output_affinity(Some(&affinity));
+            if let Some(output) = ws.affinity_output() {
+                if output.id != ws.output.get().id {
+                    output.move_workspace_here(&ws);
+                    ws.desired_output.set(affinity.preferred.clone());
+                }
+            }
+        }
         Ok(())
     }
 
@@ -919,6 +1400,19 @@ impl ConfigProxyHandler {
         self.state.idle.set_timeout(timeout);
     }
 
+    fn handle_set_idle_grace_period(&self, period: Duration) {
+        self.state.idle.set_grace_period(period);
+    }
+
+    fn handle_seat_set_idle_grace_period(
+        &self,
+        seat: Seat,
+        period: Option<Duration>,
+    ) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_idle_grace_period(period);
+        Ok(())
+    }
+
     fn handle_set_explicit_sync_enabled(&self, enabled: bool) {
         self.state.explicit_sync_enabled.set(enabled);
     }
@@ -968,12 +1462,16 @@ impl ConfigProxyHandler {
         connector: Connector,
         mode: WireMode,
     ) -> Result<(), CphError> {
-        let connector = self.get_output(connector)?;
-        connector.connector.connector.set_mode(backend::Mode {
+        let output = self.get_output(connector)?;
+        let be_mode = backend::Mode {
             width: mode.width,
             height: mode.height,
             refresh_rate_millihz: mode.refresh_millihz,
-        });
+        };
+        if !output.monitor_info.modes.contains(&be_mode) {
+            return Err(CphError::UnsupportedMode(connector, mode));
+        }
+        output.connector.connector.set_mode(be_mode);
         Ok(())
     }
 
@@ -1035,12 +1533,113 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_cursor_theme(&self, seat: Seat, theme: Option<&str>) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.cursor_group()
+            .set_cursor_theme(theme.map(|t| t.to_string()));
+        Ok(())
+    }
+
     fn handle_disable_pointer_constraint(&self, seat: Seat) -> Result<(), CphError> {
         let seat = self.get_seat(seat)?;
         seat.disable_pointer_constraint();
         Ok(())
     }
 
+    fn handle_locate_pointer(&self, seat: Seat) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.locate_pointer();
+        Ok(())
+    }
+
+    fn handle_set_pointer_locator_duration(&self, duration: Duration) {
+        let usec = duration.as_micros().min(u64::MAX as u128) as u64;
+        self.state.pointer_locator_duration_usec.set(usec);
+    }
+
+    fn handle_set_pointer_edge_behavior(&self, edge: OutputEdge, behavior: PointerEdgeBehavior) {
+        self.state.pointer_edge_behaviors[edge as usize].set(behavior);
+    }
+
+    fn handle_seat_zoom_in(&self, seat: Seat) -> Result<(), CphError> {
+        self.get_seat(seat)?.zoom_in();
+        Ok(())
+    }
+
+    fn handle_seat_zoom_out(&self, seat: Seat) -> Result<(), CphError> {
+        self.get_seat(seat)?.zoom_out();
+        Ok(())
+    }
+
+    fn handle_seat_reset_zoom(&self, seat: Seat) -> Result<(), CphError> {
+        self.get_seat(seat)?.reset_zoom();
+        Ok(())
+    }
+
+    fn handle_set_zoom_scroll_modifiers(
+        &self,
+        seat: Seat,
+        mods: Modifiers,
+    ) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_zoom_scroll_modifiers(mods);
+        Ok(())
+    }
+
+    fn handle_get_sticky(&self, seat: Seat) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        self.respond(Response::GetSticky {
+            sticky: seat.get_sticky().unwrap_or(false),
+        });
+        Ok(())
+    }
+
+    fn handle_set_sticky(&self, seat: Seat, sticky: bool) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.set_sticky(sticky);
+        Ok(())
+    }
+
+    fn handle_set_workspace_swipe(
+        &self,
+        seat: Seat,
+        fingers: u32,
+        inverted: bool,
+    ) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_workspace_swipe(fingers, inverted);
+        Ok(())
+    }
+
+    fn handle_set_dnd_threshold(&self, seat: Seat, threshold: i32) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_dnd_threshold(threshold);
+        Ok(())
+    }
+
+    fn handle_set_middle_click_paste_enabled(
+        &self,
+        seat: Seat,
+        enabled: bool,
+    ) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_middle_click_paste_enabled(enabled);
+        Ok(())
+    }
+
+    fn handle_set_which_key_delay(
+        &self,
+        seat: Seat,
+        delay: Option<Duration>,
+    ) -> Result<(), CphError> {
+        let ms = delay.map(|d| d.as_millis().min(u64::MAX as u128) as u64);
+        self.get_seat(seat)?.set_which_key_delay(ms);
+        Ok(())
+    }
+
+    fn handle_set_max_zoom(&self, factor: f64) {
+        self.state.max_zoom.set(factor.max(1.0));
+        for output in self.state.root.outputs.lock().values() {
+            output.clamp_zoom();
+        }
+    }
+
     fn handle_set_use_hardware_cursor(
         &self,
         seat: Seat,
@@ -1096,6 +1695,58 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_connector_set_render_scale(
+        &self,
+        connector: Connector,
+        scale: f64,
+    ) -> Result<(), CphError> {
+        if !(0.1..=1.0).contains(&scale) {
+            return Err(CphError::InvalidRenderScale(scale));
+        }
+        let connector = self.get_connector(connector)?;
+        connector.connector.set_render_scale(scale);
+        Ok(())
+    }
+
+    fn handle_connector_set_vblank_cursor_updates(
+        &self,
+        connector: Connector,
+        enabled: bool,
+    ) -> Result<(), CphError> {
+        let connector = self.get_output_node(connector)?;
+        connector
+            .global
+            .persistent
+            .vblank_cursor_updates
+            .set(enabled);
+        if !enabled && connector.hardware_cursor_vblank_pending.take() {
+            connector.cursor_vblank_listener.detach();
+            connector.hardware_cursor_changed();
+        }
+        Ok(())
+    }
+
+    fn handle_connector_set_use_hardware_cursor(
+        &self,
+        connector: Connector,
+        use_hardware_cursor: bool,
+    ) -> Result<(), CphError> {
+        let connector = self.get_output_node(connector)?;
+        connector.set_use_hardware_cursor(use_hardware_cursor);
+        Ok(())
+    }
+
+    fn handle_connector_hardware_cursor_active(
+        &self,
+        connector: Connector,
+    ) -> Result<(), CphError> {
+        let connector = self.get_output_node(connector)?;
+        self.respond(Response::ConnectorHardwareCursorActive {
+            active: connector.hardware_cursor.is_some(),
+        });
+        Ok(())
+    }
+
     fn handle_set_vrr_mode(
         &self,
         connector: Option<Connector>,
@@ -1154,6 +1805,62 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_color_filter(
+        &self,
+        connector: Option<Connector>,
+        filter: Option<ConfigColorMatrix>,
+    ) -> Result<(), CphError> {
+        let filter = filter.map(|f| ColorMatrix::from_affine(&f.0));
+        match connector {
+            Some(c) => self.get_output_node(c)?.set_color_filter(filter),
+            _ => {
+                self.state.default_color_filter.set(filter);
+                for output in self.state.root.outputs.lock().values() {
+                    output.set_color_filter(filter);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_connector_set_invert_colors(
+        &self,
+        connector: Connector,
+        invert: bool,
+    ) -> Result<(), CphError> {
+        self.get_output_node(connector)?.set_invert_colors(invert);
+        Ok(())
+    }
+
+    fn handle_connector_set_default_workspace_capture(
+        &self,
+        connector: Connector,
+        capture: bool,
+    ) -> Result<(), CphError> {
+        self.get_output_node(connector)?
+            .set_default_workspace_capture(capture);
+        Ok(())
+    }
+
+    fn handle_connector_set_title_bar_position(
+        &self,
+        connector: Connector,
+        position: ConfigTitleBarPosition,
+    ) -> Result<(), CphError> {
+        let position = match position {
+            ConfigTitleBarPosition::Top => TitleBarPosition::Top,
+            ConfigTitleBarPosition::Bottom => TitleBarPosition::Bottom,
+        };
+        self.get_output_node(connector)?
+            .set_title_bar_position(position);
+        Ok(())
+    }
+
+    fn handle_seat_toggle_invert_colors(&self, seat: Seat) -> Result<(), CphError> {
+        self.get_seat(seat)?.toggle_invert_colors();
+        Ok(())
+    }
+
     fn handle_connector_set_transform(
         &self,
         connector: Connector,
@@ -1295,6 +2002,28 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_add_pointer_shortcut(
+        &self,
+        seat: Seat,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.add_pointer_shortcut(mods, trigger);
+        Ok(())
+    }
+
+    fn handle_remove_pointer_shortcut(
+        &self,
+        seat: Seat,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.remove_pointer_shortcut(mods, trigger);
+        Ok(())
+    }
+
     fn handle_get_input_devices(&self, seat: Option<Seat>) {
         let id = seat.map(|s| SeatId::from_raw(s.0 as _));
         let matches = |dhd: &DeviceHandlerData| {
@@ -1513,6 +2242,8 @@ impl ConfigProxyHandler {
         let sized = match sized {
             TITLE_HEIGHT => ThemeSized::title_height,
             BORDER_WIDTH => ThemeSized::border_width,
+            POINTER_LOCATOR_SIZE => ThemeSized::pointer_locator_size,
+            WINDOW_BORDER_WIDTH => ThemeSized::window_border_width,
             _ => return Err(CphError::UnknownSized(sized.0)),
         };
         Ok(sized)
@@ -1585,6 +2316,11 @@ impl ConfigProxyHandler {
             BAR_STATUS_TEXT_COLOR => &colors.bar_text,
             ATTENTION_REQUESTED_BACKGROUND_COLOR => &colors.attention_requested_background,
             HIGHLIGHT_COLOR => &colors.highlight,
+            POINTER_LOCATOR_COLOR => &colors.pointer_locator,
+            FOCUSED_WINDOW_BORDER_COLOR => &colors.focused_window_border,
+            UNFOCUSED_WINDOW_BORDER_COLOR => &colors.unfocused_window_border,
+            ATTENTION_REQUESTED_WINDOW_BORDER_COLOR => &colors.attention_requested_window_border,
+            FOCUSED_OUTPUT_INDICATOR_COLOR => &colors.focused_output_indicator,
             _ => return Err(CphError::UnknownColor(colorable.0)),
         };
         Ok(colorable)
@@ -1980,6 +2716,237 @@ impl ConfigProxyHandler {
             ClientMessage::SetXScalingMode { mode } => self
                 .handle_set_x_scaling_mode(mode)
                 .wrn("set_x_scaling_mode")?,
+            ClientMessage::ConnectorSetVblankCursorUpdates { connector, enabled } => self
+                .handle_connector_set_vblank_cursor_updates(connector, enabled)
+                .wrn("connector_set_vblank_cursor_updates")?,
+            ClientMessage::ConnectorSetUseHardwareCursor {
+                connector,
+                use_hardware_cursor,
+            } => self
+                .handle_connector_set_use_hardware_cursor(connector, use_hardware_cursor)
+                .wrn("connector_set_use_hardware_cursor")?,
+            ClientMessage::LocatePointer { seat } => {
+                self.handle_locate_pointer(seat).wrn("locate_pointer")?
+            }
+            ClientMessage::SetPointerLocatorDuration { duration } => {
+                self.handle_set_pointer_locator_duration(duration)
+            }
+            ClientMessage::SeatZoomIn { seat } => {
+                self.handle_seat_zoom_in(seat).wrn("seat_zoom_in")?
+            }
+            ClientMessage::SeatZoomOut { seat } => {
+                self.handle_seat_zoom_out(seat).wrn("seat_zoom_out")?
+            }
+            ClientMessage::SeatResetZoom { seat } => {
+                self.handle_seat_reset_zoom(seat).wrn("seat_reset_zoom")?
+            }
+            ClientMessage::SetMaxZoom { factor } => self.handle_set_max_zoom(factor),
+            ClientMessage::SetColorFilter { connector, filter } => self
+                .handle_set_color_filter(connector, filter)
+                .wrn("set_color_filter")?,
+            ClientMessage::ConnectorSetInvertColors { connector, invert } => self
+                .handle_connector_set_invert_colors(connector, invert)
+                .wrn("connector_set_invert_colors")?,
+            ClientMessage::SeatToggleInvertColors { seat } => self
+                .handle_seat_toggle_invert_colors(seat)
+                .wrn("seat_toggle_invert_colors")?,
+            ClientMessage::SetScaleOverride { app_id, scale } => self
+                .handle_set_scale_override(app_id, scale)
+                .wrn("set_scale_override")?,
+            ClientMessage::SetDecorationMode { app_id, mode } => self
+                .handle_set_decoration_mode(app_id, mode)
+                .wrn("set_decoration_mode")?,
+            ClientMessage::SetWindowBorderWidth { app_id, width } => self
+                .handle_set_window_border_width(app_id, width)
+                .wrn("set_window_border_width")?,
+            ClientMessage::SetWindowBorderColor {
+                app_id,
+                colorable,
+                color,
+            } => self
+                .handle_set_window_border_color(app_id, colorable, color)
+                .wrn("set_window_border_color")?,
+            ClientMessage::SetShowFocusedOutput { show } => {
+                self.handle_set_show_focused_output(show)
+            }
+            ClientMessage::SeatGetKeyboardLayout { seat } => self
+                .handle_get_keyboard_layout(seat)
+                .wrn("get_keyboard_layout")?,
+            ClientMessage::AddKeySequence { seat, keys } => self
+                .handle_add_key_sequence(seat, keys)
+                .wrn("add_key_sequence")?,
+            ClientMessage::RemoveKeySequence { seat, keys } => self
+                .handle_remove_key_sequence(seat, keys)
+                .wrn("remove_key_sequence")?,
+            ClientMessage::SetKeySequenceTimeout { seat, timeout } => self
+                .handle_set_key_sequence_timeout(seat, timeout)
+                .wrn("set_key_sequence_timeout")?,
+            ClientMessage::SetForwardAbortedKeySequences { seat, forward } => self
+                .handle_set_forward_aborted_key_sequences(seat, forward)
+                .wrn("set_forward_aborted_key_sequences")?,
+            ClientMessage::SetShowKeySequenceHint { seat, show } => self
+                .handle_set_show_key_sequence_hint(seat, show)
+                .wrn("set_show_key_sequence_hint")?,
+            ClientMessage::SetSharedClipboard { shared } => {
+                self.handle_set_shared_clipboard(shared)
+            }
+            ClientMessage::SeatGetIdleTime { seat } => {
+                self.handle_get_idle_time(seat).wrn("get_idle_time")?
+            }
+            ClientMessage::SeatSetIdleTimeInterval { seat, interval } => self
+                .handle_set_idle_time_interval(seat, interval)
+                .wrn("set_idle_time_interval")?,
+            ClientMessage::ConnectorSetDefaultWorkspaceCapture { connector, capture } => self
+                .handle_connector_set_default_workspace_capture(connector, capture)
+                .wrn("connector_set_default_workspace_capture")?,
+            ClientMessage::ConnectorSetTitleBarPosition {
+                connector,
+                position,
+            } => self
+                .handle_connector_set_title_bar_position(connector, position)
+                .wrn("connector_set_title_bar_position")?,
+            ClientMessage::SetBlockCapture { app_id, block } => {
+                self.handle_set_block_capture(app_id, block)
+            }
+            ClientMessage::SeatGetPointerPosition { seat } => self
+                .handle_get_pointer_position(seat)
+                .wrn("get_pointer_position")?,
+            ClientMessage::SetHotCornerSize { seat, size } => self
+                .handle_set_hot_corner_size(seat, size)
+                .wrn("set_hot_corner_size")?,
+            ClientMessage::SetHotCornerDwell { seat, dwell } => self
+                .handle_set_hot_corner_dwell(seat, dwell)
+                .wrn("set_hot_corner_dwell")?,
+            ClientMessage::SetPointerEdgeBehavior { edge, behavior } => {
+                self.handle_set_pointer_edge_behavior(edge, behavior)
+            }
+            ClientMessage::ConnectorSetRenderScale { connector, scale } => self
+                .handle_connector_set_render_scale(connector, scale)
+                .wrn("connector_set_render_scale")?,
+            ClientMessage::SetWorkspaceScrollInverted { inverted } => {
+                self.handle_set_workspace_scroll_inverted(inverted)
+            }
+            ClientMessage::SetWorkspaceScrollThreshold { threshold } => {
+                self.handle_set_workspace_scroll_threshold(threshold)
+            }
+            ClientMessage::SetWorkspaceScrollWrap { wrap } => {
+                self.handle_set_workspace_scroll_wrap(wrap)
+            }
+            ClientMessage::SetOutputThrottle { hz } => self.handle_set_output_throttle(hz),
+            ClientMessage::SetOutputThrottleDelay { delay } => {
+                self.handle_set_output_throttle_delay(delay)
+            }
+            ClientMessage::SetWorkspaceScrollSkipEmpty { skip_empty } => {
+                self.handle_set_workspace_scroll_skip_empty(skip_empty)
+            }
+            ClientMessage::ConnectorSetWorkspaceScrollWrap { connector, wrap } => self
+                .handle_connector_set_workspace_scroll_wrap(connector, wrap)
+                .wrn("connector_set_workspace_scroll_wrap")?,
+            ClientMessage::ConnectorSetWorkspaceScrollSkipEmpty {
+                connector,
+                skip_empty,
+            } => self
+                .handle_connector_set_workspace_scroll_skip_empty(connector, skip_empty)
+                .wrn("connector_set_workspace_scroll_skip_empty")?,
+            ClientMessage::SetWorkspaceOutputAffinity {
+                workspace,
+                preferred,
+                fallback,
+            } => self
+                .handle_set_workspace_output_affinity(workspace, preferred, fallback)
+                .wrn("set_workspace_output_affinity")?,
+            ClientMessage::ConnectorSetSpan {
+                connector,
+                secondary,
+            } => self
+                .handle_connector_set_span(connector, secondary)
+                .wrn("connector_set_span")?,
+            ClientMessage::ConnectorSetGameMode { connector, enabled } => self
+                .handle_connector_set_game_mode(connector, enabled)
+                .wrn("connector_set_game_mode")?,
+            ClientMessage::ConnectorSetVrrMinRefresh { connector, hz } => self
+                .handle_connector_set_vrr_min_refresh(connector, hz)
+                .wrn("connector_set_vrr_min_refresh")?,
+            ClientMessage::ConnectorSetMaxScale { connector, scale } => self
+                .handle_connector_set_max_scale(connector, scale)
+                .wrn("connector_set_max_scale")?,
+            ClientMessage::ConnectorSetMirrorSource { connector, source } => self
+                .handle_connector_set_mirror_source(connector, source)
+                .wrn("connector_set_mirror_source")?,
+            ClientMessage::SetIdleGracePeriod { period } => {
+                self.handle_set_idle_grace_period(period)
+            }
+            ClientMessage::SeatSetIdleGracePeriod { seat, period } => self
+                .handle_seat_set_idle_grace_period(seat, period)
+                .wrn("seat_set_idle_grace_period")?,
+            ClientMessage::SeatSetLayouts { seat, layouts } => {
+                self.handle_set_layouts(seat, layouts).wrn("set_layouts")?
+            }
+            ClientMessage::SeatCycleLayout { seat } => {
+                self.handle_cycle_layout(seat).wrn("cycle_layout")?
+            }
+            ClientMessage::DeviceSetRepeatRate { device, rate } => self
+                .handle_set_device_repeat_rate(device, rate)
+                .wrn("set_device_repeat_rate")?,
+            ClientMessage::SetFocusFollowsMouseDwell { seat, dwell } => self
+                .handle_set_focus_follows_mouse_dwell(seat, dwell)
+                .wrn("set_focus_follows_mouse_dwell")?,
+            ClientMessage::SetWarpPointerOnFocus { seat, warp } => self
+                .handle_set_warp_pointer_on_focus(seat, warp)
+                .wrn("set_warp_pointer_on_focus")?,
+            ClientMessage::SetShortcutsInhibitEscape { seat, escape } => self
+                .handle_set_shortcuts_inhibit_escape(seat, escape)
+                .wrn("set_shortcuts_inhibit_escape")?,
+            ClientMessage::SetZoomScrollModifiers { seat, mods } => self
+                .handle_set_zoom_scroll_modifiers(seat, mods)
+                .wrn("set_zoom_scroll_modifiers")?,
+            ClientMessage::SetWorkspaceSwipe {
+                seat,
+                fingers,
+                inverted,
+            } => self
+                .handle_set_workspace_swipe(seat, fingers, inverted)
+                .wrn("set_workspace_swipe")?,
+            ClientMessage::GetSticky { seat } => self.handle_get_sticky(seat).wrn("get_sticky")?,
+            ClientMessage::SetSticky { seat, sticky } => {
+                self.handle_set_sticky(seat, sticky).wrn("set_sticky")?
+            }
+            ClientMessage::SetDndThreshold { seat, threshold } => self
+                .handle_set_dnd_threshold(seat, threshold)
+                .wrn("set_dnd_threshold")?,
+            ClientMessage::SetMiddleClickPasteEnabled { seat, enabled } => self
+                .handle_set_middle_click_paste_enabled(seat, enabled)
+                .wrn("set_middle_click_paste_enabled")?,
+            ClientMessage::SetWhichKeyDelay { seat, delay } => self
+                .handle_set_which_key_delay(seat, delay)
+                .wrn("set_which_key_delay")?,
+            ClientMessage::AddPointerShortcut {
+                seat,
+                mods,
+                trigger,
+            } => self
+                .handle_add_pointer_shortcut(seat, mods, trigger)
+                .wrn("add_pointer_shortcut")?,
+            ClientMessage::RemovePointerShortcut {
+                seat,
+                mods,
+                trigger,
+            } => self
+                .handle_remove_pointer_shortcut(seat, mods, trigger)
+                .wrn("remove_pointer_shortcut")?,
+            ClientMessage::ConnectorHardwareCursorActive { connector } => self
+                .handle_connector_hardware_cursor_active(connector)
+                .wrn("connector_hardware_cursor_active")?,
+            ClientMessage::SetCursorTheme { seat, theme } => self
+                .handle_set_cursor_theme(seat, theme)
+                .wrn("set_cursor_theme")?,
+            ClientMessage::SetHideCursorWhileTyping { seat, hide } => self
+                .handle_set_hide_cursor_while_typing(seat, hide)
+                .wrn("set_hide_cursor_while_typing")?,
+            ClientMessage::SetHideCursorTimeout { seat, timeout } => self
+                .handle_set_hide_cursor_timeout(seat, timeout)
+                .wrn("set_hide_cursor_timeout")?,
+            ClientMessage::ResetWindowRules => self.handle_reset_window_rules(),
         }
         Ok(())
     }
@@ -2011,6 +2978,8 @@ enum CphError {
     OutputDoesNotExist(Connector),
     #[error("Output {0:?} is not a desktop output")]
     OutputIsNotDesktop(Connector),
+    #[error("Connector {0:?} does not support the mode {1:?}")]
+    UnsupportedMode(Connector, WireMode),
     #[error("{0}x{1} is not a valid connector position")]
     InvalidConnectorPosition(i32, i32),
     #[error("Keymap {0:?} does not exist")]
@@ -2049,8 +3018,18 @@ enum CphError {
     UnknownTearingMode(ConfigTearingMode),
     #[error("The format {0:?} is unknown")]
     UnknownFormat(ConfigFormat),
+    #[error("The render scale {0} is not in the range 0.1..=1.0")]
+    InvalidRenderScale(f64),
     #[error("Unknown x scaling mode {0:?}")]
     UnknownXScalingMode(XScalingMode),
+    #[error("Unknown decoration mode {0:?}")]
+    UnknownDecorationMode(ConfigDecorationMode),
+    #[error("Colorable {0} is not a window border color")]
+    NotAWindowBorderColor(u32),
+    #[error(transparent)]
+    OutputSpanError(#[from] OutputSpanError),
+    #[error(transparent)]
+    OutputMirrorError(#[from] OutputMirrorError),
 }
 
 trait WithRequestName {
@@ -23,7 +23,7 @@ use {
         convert::TryInto,
         env,
         fmt::{Debug, Formatter},
-        fs::File,
+        fs::{self, File},
         io::{self, BufRead, BufReader, Seek, SeekFrom},
         mem::MaybeUninit,
         rc::Rc,
@@ -153,6 +153,16 @@ pub enum KnownCursor {
 
 impl ServerCursors {
     pub fn load(ctx: &Rc<dyn GfxContext>, state: &State) -> Result<Option<Self>, CursorError> {
+        let xcursor_theme = env::var_os(XCURSOR_THEME);
+        let theme = xcursor_theme.as_ref().map(|theme| BStr::new(theme.bytes()));
+        Self::load_theme(ctx, state, theme)
+    }
+
+    pub fn load_theme(
+        ctx: &Rc<dyn GfxContext>,
+        state: &State,
+        theme: Option<&BStr>,
+    ) -> Result<Option<Self>, CursorError> {
         let paths = find_cursor_paths();
         log::debug!("Trying to load cursors from paths {:?}", paths);
         let sizes = state.cursor_sizes.to_vec();
@@ -160,8 +170,6 @@ impl ServerCursors {
         if sizes.is_empty() || scales.is_empty() {
             return Ok(None);
         }
-        let xcursor_theme = env::var_os(XCURSOR_THEME);
-        let theme = xcursor_theme.as_ref().map(|theme| BStr::new(theme.bytes()));
 
         let load =
             |names: &[&str]| ServerCursorTemplate::load(names, theme, &scales, &sizes, &paths, ctx);
@@ -568,6 +576,20 @@ fn open_cursor_file<'a>(
     None
 }
 
+/// Returns whether a cursor theme with this name is installed in one of the cursor
+/// paths.
+pub fn cursor_theme_exists(theme: &str) -> bool {
+    find_cursor_paths().iter().any(|path| {
+        let mut theme_dir = path.to_vec();
+        theme_dir.push(b'/');
+        theme_dir.extend_from_slice(theme.as_bytes());
+        let Ok(theme_dir) = theme_dir.to_os_str() else {
+            return false;
+        };
+        fs::metadata(theme_dir).map(|m| m.is_dir()).unwrap_or(false)
+    })
+}
+
 fn find_cursor_paths() -> Vec<BString> {
     let home = env::var_os(HOME).map(|h| Vec::from_os_string(h).unwrap());
     let cursor_paths = env::var_os(XCURSOR_PATH);
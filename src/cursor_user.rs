@@ -1,7 +1,7 @@
 use {
     crate::{
         backend::HardwareCursorUpdate,
-        cursor::{Cursor, KnownCursor, DEFAULT_CURSOR_SIZE},
+        cursor::{Cursor, KnownCursor, ServerCursors, DEFAULT_CURSOR_SIZE},
         fixed::Fixed,
         gfx_api::{AcquireSync, ReleaseSync},
         rect::Rect,
@@ -10,7 +10,8 @@ use {
         tree::OutputNode,
         utils::{
             clonecell::CloneCell, copyhashmap::CopyHashMap, errorfmt::ErrorFmt,
-            hash_map_ext::HashMapExt, rc_eq::rc_eq, transform_ext::TransformExt,
+            event_listener::EventSource, hash_map_ext::HashMapExt, rc_eq::rc_eq,
+            transform_ext::TransformExt,
         },
     },
     std::{cell::Cell, ops::Deref, rc::Rc},
@@ -23,6 +24,10 @@ pub trait CursorUserOwner {
     fn output_changed(&self, output: &Rc<OutputNode>);
 }
 
+pub trait CursorImageListener {
+    fn cursor_image_changed(self: Rc<Self>);
+}
+
 pub struct CursorUserGroup {
     pub id: CursorUserGroupId,
     state: Rc<State>,
@@ -30,8 +35,11 @@ pub struct CursorUserGroup {
     active: CloneCell<Option<Rc<CursorUser>>>,
     users: CopyHashMap<CursorUserId, Rc<CursorUser>>,
     hardware_cursor: Cell<bool>,
+    hidden: Cell<bool>,
     size: Cell<u32>,
+    theme: CloneCell<Option<Rc<String>>>,
     latest_output: CloneCell<Rc<OutputNode>>,
+    pub image_changed_event: EventSource<dyn CursorImageListener>,
 }
 
 pub struct CursorUser {
@@ -64,8 +72,11 @@ impl CursorUserGroup {
             active: Default::default(),
             users: Default::default(),
             hardware_cursor: Cell::new(hardware_cursor),
+            hidden: Cell::new(false),
             size: Cell::new(*DEFAULT_CURSOR_SIZE),
+            theme: Default::default(),
             latest_output: CloneCell::new(output),
+            image_changed_event: Default::default(),
         });
         state.add_cursor_size(*DEFAULT_CURSOR_SIZE);
         state.cursor_user_groups.set(group.id, group.clone());
@@ -77,7 +88,7 @@ impl CursorUserGroup {
         group
     }
 
-    fn damage_active(&self) {
+    pub fn damage_active(&self) {
         if let Some(active) = self.active.get() {
             if let Some(cursor) = active.cursor.get() {
                 let (x, y) = active.pos.get();
@@ -96,13 +107,29 @@ impl CursorUserGroup {
             self.damage_active();
         }
         self.active_id.take();
-        self.active.take();
+        if self.active.take().is_some() {
+            self.image_changed();
+        }
+    }
+
+    fn image_changed(&self) {
+        for listener in self.image_changed_event.iter() {
+            listener.cursor_image_changed();
+        }
     }
 
     pub fn latest_output(&self) -> Rc<OutputNode> {
         self.latest_output.get()
     }
 
+    fn set_latest_output(&self, output: &Rc<OutputNode>) {
+        let old = self.latest_output.set(output.clone());
+        if old.id != output.id {
+            old.update_focus_indicator();
+            output.update_focus_indicator();
+        }
+    }
+
     fn remove_hardware_cursor(&self) {
         self.state.hardware_tick_cursor.push(None);
         self.state.damage_hardware_cursors(false);
@@ -111,9 +138,11 @@ impl CursorUserGroup {
 
     pub fn detach(&self) {
         self.deactivate();
-        self.latest_output
-            .set(self.state.dummy_output.get().unwrap());
+        self.set_latest_output(&self.state.dummy_output.get().unwrap());
         self.state.remove_cursor_size(self.size.get());
+        if let Some(theme) = self.theme.take() {
+            self.state.remove_cursor_theme(&theme);
+        }
         self.state.cursor_user_groups.remove(&self.id);
         for user in self.users.lock().drain_values() {
             user.detach();
@@ -189,6 +218,24 @@ impl CursorUserGroup {
         self.hardware_cursor.get()
     }
 
+    /// Hides the cursor without changing the cursor image.
+    pub fn set_hidden(&self, hidden: bool) {
+        if self.hidden.replace(hidden) == hidden {
+            return;
+        }
+        if let Some(active) = self.active.get() {
+            if active.software_cursor() {
+                self.damage_active();
+            }
+            active.update_hardware_cursor();
+        }
+        self.image_changed();
+    }
+
+    pub fn hidden(&self) -> bool {
+        self.hidden.get()
+    }
+
     pub fn set_cursor_size(&self, size: u32) {
         let old = self.size.replace(size);
         if size != old {
@@ -198,6 +245,33 @@ impl CursorUserGroup {
         }
     }
 
+    pub fn set_cursor_theme(&self, theme: Option<String>) {
+        let theme = theme.map(Rc::new);
+        if self.theme.get() == theme {
+            return;
+        }
+        if let Some(theme) = &theme {
+            self.state.add_cursor_theme(theme);
+        }
+        if let Some(old) = self.theme.set(theme) {
+            self.state.remove_cursor_theme(&old);
+        }
+        self.reload_known_cursor();
+    }
+
+    /// Returns the cursors of the theme of this group.
+    ///
+    /// Falls back to the default cursors if no theme is set or if the theme could not
+    /// be loaded.
+    fn cursors(&self) -> Option<Rc<ServerCursors>> {
+        if let Some(theme) = self.theme.get() {
+            if let Some(cursors) = self.state.themed_cursors.get(&theme) {
+                return Some(cursors);
+            }
+        }
+        self.state.cursors.get()
+    }
+
     fn output_center(&self, output: &Rc<OutputNode>) -> (Fixed, Fixed) {
         let pos = output.global.pos.get();
         let x = Fixed::from_int((pos.x1() + pos.x2()) / 2);
@@ -206,7 +280,7 @@ impl CursorUserGroup {
     }
 
     pub fn first_output_connected(&self, output: &Rc<OutputNode>) {
-        self.latest_output.set(output.clone());
+        self.set_latest_output(output);
         let (x, y) = self.output_center(output);
         for user in self.users.lock().values() {
             user.set_output(output);
@@ -216,7 +290,7 @@ impl CursorUserGroup {
 
     pub fn output_disconnected(&self, output: &Rc<OutputNode>, next: &Rc<OutputNode>) {
         if self.latest_output.get().id == output.id {
-            self.latest_output.set(next.clone());
+            self.set_latest_output(next);
         }
         let (x, y) = self.output_center(next);
         for user in self.users.lock().values() {
@@ -246,6 +320,10 @@ impl CursorUserGroup {
             hc.set_enabled(false);
             return;
         };
+        if self.hidden.get() {
+            hc.set_enabled(false);
+            return;
+        }
         active.present_hardware_cursor(output, hc);
     }
 }
@@ -271,12 +349,13 @@ impl CursorUser {
         if self.software_cursor() {
             self.group.damage_active();
         }
-        self.group.latest_output.set(self.output.get());
+        self.group.set_latest_output(&self.output.get());
         self.group.active.set(Some(self.clone()));
         self.update_hardware_cursor();
         if self.software_cursor() {
             self.group.damage_active();
         }
+        self.group.image_changed();
     }
 
     #[cfg_attr(not(feature = "it"), expect(dead_code))]
@@ -286,7 +365,7 @@ impl CursorUser {
 
     pub fn set_known(&self, cursor: KnownCursor) {
         self.desired_known_cursor.set(Some(cursor));
-        let cursors = match self.group.state.cursors.get() {
+        let cursors = match self.group.cursors() {
             Some(c) => c,
             None => {
                 self.set_cursor2(None);
@@ -338,7 +417,7 @@ impl CursorUser {
         self.output.set(output.clone());
         self.output_pos.set(output.global.pos.get());
         if self.is_active() {
-            self.group.latest_output.set(output.clone());
+            self.group.set_latest_output(output);
         }
         if let Some(cursor) = self.cursor.get() {
             cursor.set_output(output);
@@ -369,7 +448,13 @@ impl CursorUser {
         if let Some(old) = self.cursor.get() {
             if let Some(new) = cursor.as_ref() {
                 if rc_eq(&old, new) {
-                    self.update_hardware_cursor();
+                    // Only the hotspot can have changed. The image in the cursor buffer is
+                    // still valid and only the hotspot of the cursor plane must be updated.
+                    self.update_hardware_cursor_hotspot();
+                    if self.software_cursor() {
+                        self.group.damage_active();
+                    }
+                    self.image_changed();
                     return;
                 }
             }
@@ -387,6 +472,14 @@ impl CursorUser {
         if self.software_cursor() {
             self.group.damage_active();
         }
+        self.image_changed();
+    }
+
+    /// Informs listeners that the image or hotspot of the cursor has changed.
+    pub fn image_changed(&self) {
+        if self.is_active() {
+            self.group.image_changed();
+        }
     }
 
     pub fn position(&self) -> (Fixed, Fixed) {
@@ -401,13 +494,19 @@ impl CursorUser {
     pub fn set_position(&self, mut x: Fixed, mut y: Fixed) -> (Fixed, Fixed) {
         let x_int = x.round_down();
         let y_int = y.round_down();
+        let mut software_cursor = self.software_cursor();
         if !self.output_pos.get().contains(x_int, y_int) {
-            let (output, x_tmp, y_tmp) = self.group.state.find_closest_output(x_int, y_int);
+            let (output, x_tmp, y_tmp) =
+                self.group
+                    .state
+                    .find_output_after_motion(&self.output.get(), x_int, y_int);
+            self.output.get().damage_cursor_screencasts();
             self.set_output(&output);
             x = x.apply_fract(x_tmp);
             y = y.apply_fract(y_tmp);
+            software_cursor |= self.software_cursor();
         }
-        if self.software_cursor() {
+        if software_cursor {
             if let Some(cursor) = self.cursor.get() {
                 let (old_x, old_y) = self.pos.get();
                 let old_x_int = old_x.round_down();
@@ -420,7 +519,15 @@ impl CursorUser {
             }
         }
         self.pos.set((x, y));
-        self.update_hardware_cursor_(false);
+        let output = self.output.get();
+        if output.is_zoomed() {
+            // The visible area of the output follows the pointer.
+            self.group.state.damage(output.global.pos.get());
+        }
+        self.update_hardware_cursor_position();
+        if !software_cursor && self.is_active() {
+            output.damage_cursor_screencasts();
+        }
         (x, y)
     }
 
@@ -428,12 +535,24 @@ impl CursorUser {
         self.update_hardware_cursor_(true);
     }
 
+    pub fn update_hardware_cursor_position(&self) {
+        self.update_hardware_cursor_(false);
+    }
+
+    pub fn update_hardware_cursor_hotspot(&self) {
+        self.update_hardware_cursor_(false);
+    }
+
     fn hardware_cursor(&self) -> bool {
         self.is_active() && self.group.hardware_cursor.get()
     }
 
     pub fn software_cursor(&self) -> bool {
-        self.is_active() && !self.group.hardware_cursor.get()
+        if !self.is_active() {
+            return false;
+        }
+        let output = self.output.get();
+        !self.group.hardware_cursor.get() || !output.uses_hardware_cursor()
     }
 
     fn update_hardware_cursor_(&self, render: bool) {
@@ -443,16 +562,11 @@ impl CursorUser {
         let cursor = self.cursor.get();
         self.group.state.hardware_tick_cursor.push(cursor);
         for output in self.group.state.root.outputs.lock().values() {
-            if let Some(hc) = output.hardware_cursor.get() {
+            if output.hardware_cursor.is_some() {
                 if render {
                     output.hardware_cursor_needs_render.set(true);
                 }
-                let defer = output.schedule.defer_cursor_updates();
-                if defer {
-                    output.schedule.hardware_cursor_changed();
-                } else {
-                    hc.damage();
-                }
+                output.hardware_cursor_changed();
             }
         }
     }
@@ -521,7 +635,8 @@ impl CursorUser {
         let (x_rel, y_rel) = transform.apply_point(mode.width, mode.height, (x_rel, y_rel));
         let (hot_x, hot_y) =
             transform.apply_point(hc_width, hc_height, (-extents.x1(), -extents.y1()));
-        hc.set_position(x_rel - hot_x, y_rel - hot_y);
+        hc.set_hotspot(hot_x, hot_y);
+        hc.set_position(x_rel, y_rel);
     }
 
     fn reload_known_cursor(&self) {
@@ -11,7 +11,7 @@ use {
         scale::Scale,
         state::State,
         theme::Color,
-        tree::{Node, OutputNode},
+        tree::{Node, OutputNode, OutputZoom},
         utils::{clonecell::UnsafeCellCloneSafe, transform_ext::TransformExt},
         video::{dmabuf::DmaBuf, drm::sync_obj::SyncObjCtx, Modifier},
     },
@@ -43,6 +43,82 @@ pub struct GfxRenderPass {
     pub clear: Option<Color>,
 }
 
+impl GfxRenderPass {
+    /// Transforms the colors produced by each operation of this pass.
+    ///
+    /// Since blending happens after each operation, this only produces the same result
+    /// as transforming the composited image if the operations do not overlap. See
+    /// [`OutputNode::apply_color_matrix`].
+    pub fn apply_color_matrix(&mut self, matrix: &ColorMatrix) {
+        for op in &mut self.ops {
+            match op {
+                GfxApiOpt::Sync => {}
+                GfxApiOpt::FillRect(fr) => fr.color = matrix.apply(&fr.color),
+                GfxApiOpt::CopyTexture(ct) => {
+                    ct.color_matrix = Some(match &ct.color_matrix {
+                        Some(m) => matrix.mul(m),
+                        None => *matrix,
+                    });
+                }
+            }
+        }
+        if let Some(clear) = &mut self.clear {
+            *clear = matrix.apply(clear);
+        }
+    }
+}
+
+/// A matrix that transforms premultiplied colors.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ColorMatrix(pub [[f32; 4]; 4]);
+
+impl ColorMatrix {
+    pub const INVERT: Self = Self([
+        [-1.0, 0.0, 0.0, 1.0],
+        [0.0, -1.0, 0.0, 1.0],
+        [0.0, 0.0, -1.0, 1.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    /// Creates a matrix from rows that transform the red, green, and blue channels of
+    /// unpremultiplied colors. The fourth column of each row is a constant offset.
+    pub fn from_affine(rows: &[[f32; 4]; 3]) -> Self {
+        // With premultiplied colors the offset has to be multiplied by the alpha value.
+        Self([rows[0], rows[1], rows[2], [0.0, 0.0, 0.0, 1.0]])
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut res = [[0.0; 4]; 4];
+        for (i, row) in res.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = (0..4).map(|k| self.0[i][k] * other.0[k][j]).sum();
+            }
+        }
+        Self(res)
+    }
+
+    pub fn scale(&self, f: f32) -> Self {
+        Self(self.0.map(|row| row.map(|v| v * f)))
+    }
+
+    pub fn apply(&self, color: &Color) -> Color {
+        let c = [color.r, color.g, color.b, color.a];
+        let [r, g, b, a] = self.0.map(|row| (0..4).map(|k| row[k] * c[k]).sum::<f32>());
+        Color {
+            r: r.clamp(0.0, a),
+            g: g.clamp(0.0, a),
+            b: b.clamp(0.0, a),
+            a,
+        }
+    }
+
+    /// Returns the matrix in column-major order.
+    pub fn to_columns(&self) -> [[f32; 4]; 4] {
+        let m = &self.0;
+        [0, 1, 2, 3].map(|j| [m[0][j], m[1][j], m[2][j], m[3][j]])
+    }
+}
+
 #[derive(Default, Debug, Copy, Clone, PartialEq)]
 pub struct SampleRect {
     pub x1: f32,
@@ -181,6 +257,7 @@ pub struct CopyTexture {
     pub acquire_sync: AcquireSync,
     pub release_sync: ReleaseSync,
     pub alpha: Option<f32>,
+    pub color_matrix: Option<ColorMatrix>,
 }
 
 #[derive(Clone, Debug)]
@@ -372,7 +449,9 @@ impl dyn GfxFramebuffer {
         render_hardware_cursor: bool,
         black_background: bool,
         transform: Transform,
+        zoom: Option<OutputZoom>,
         visualizer: Option<&DamageVisualizer>,
+        for_capture: bool,
     ) -> GfxRenderPass {
         create_render_pass(
             self.physical_size(),
@@ -384,7 +463,9 @@ impl dyn GfxFramebuffer {
             render_hardware_cursor,
             black_background,
             transform,
+            zoom,
             visualizer,
+            for_capture,
         )
     }
 
@@ -407,20 +488,31 @@ impl dyn GfxFramebuffer {
         scale: Scale,
         render_hardware_cursor: bool,
     ) -> Result<Option<SyncFile>, GfxError> {
-        self.render_node(
-            acquire_sync,
-            release_sync,
-            node,
+        let mirror = node.mirror_viewport(self.physical_size(), scale);
+        let (content, cursor_rect, zoom) = match &mirror {
+            Some((source, zoom)) => (&**source, Some(source.global.pos.get()), Some(*zoom)),
+            None => (node, cursor_rect, node.zoom_viewport()),
+        };
+        let mut pass = self.create_render_pass(
+            content,
             state,
             cursor_rect,
             scale,
             true,
             render_hardware_cursor,
-            node.has_fullscreen(),
+            content.has_fullscreen(),
             node.global.persistent.transform.get(),
-        )
+            zoom,
+            None,
+            false,
+        );
+        node.apply_color_matrix(&mut pass, self.physical_size());
+        self.perform_render_pass(acquire_sync, release_sync, &pass)
     }
 
+    /// Renders a node for a screen capture.
+    ///
+    /// Toplevels that block captures are rendered as black rectangles.
     pub fn render_node(
         &self,
         acquire_sync: AcquireSync,
@@ -444,6 +536,8 @@ impl dyn GfxFramebuffer {
             black_background,
             transform,
             None,
+            None,
+            true,
         );
         self.perform_render_pass(acquire_sync, release_sync, &pass)
     }
@@ -461,6 +555,8 @@ impl dyn GfxFramebuffer {
         let mut renderer = Renderer {
             base: self.renderer_base(&mut ops, scale, transform),
             state,
+            for_capture: false,
+            clear_color: Color::TRANSPARENT,
             logical_extents: Rect::new_empty(0, 0),
             pixel_extents: {
                 let (width, height) = self.logical_size(transform);
@@ -723,23 +819,53 @@ pub fn create_render_pass(
     render_hardware_cursor: bool,
     black_background: bool,
     transform: Transform,
+    zoom: Option<OutputZoom>,
     visualizer: Option<&DamageVisualizer>,
+    for_capture: bool,
 ) -> GfxRenderPass {
     let mut ops = vec![];
+    let unzoomed_scale = scale;
+    let unzoomed_cursor_rect = cursor_rect;
+    let (mut scale, mut dx, mut dy) = (scale, 0, 0);
+    let mut cursor_rect = cursor_rect;
+    let mut magnifier = None;
+    if let Some(zoom) = zoom {
+        scale = Scale::from_f64(scale.to_f64() * zoom.factor);
+        (dx, dy) = (zoom.x, zoom.y);
+        cursor_rect = cursor_rect.map(|r| r.move_(dx, dy));
+        if zoom.magnifier {
+            magnifier = Some(zoom);
+        }
+    }
+    let clear_color = match black_background {
+        true => Color::SOLID_BLACK,
+        false => node
+            .node_background_color()
+            .unwrap_or_else(|| state.theme.colors.background.get()),
+    };
     let mut renderer = Renderer {
         base: renderer_base(physical_size, &mut ops, scale, transform),
         state,
+        for_capture,
+        clear_color,
         logical_extents: node.node_absolute_position().at_point(0, 0),
         pixel_extents: {
             let (width, height) = logical_size(physical_size, transform);
             Rect::new(0, 0, width, height).unwrap()
         },
     };
-    node.node_render(&mut renderer, 0, 0, None);
+    node.node_render(&mut renderer, -dx, -dy, None);
     if let Some(rect) = cursor_rect {
         let seats = state.globals.lock_seats();
         for seat in seats.values() {
-            let (x, y) = seat.pointer_cursor().position_int();
+            let (mut x, mut y) = seat.pointer_cursor().position_int();
+            if let (Some(zoom), Some(unzoomed)) = (magnifier, unzoomed_cursor_rect) {
+                // Decorations that follow the pointer are attached to the content under
+                // the cursor.
+                let (zx, zy) = unzoomed.translate(x, y);
+                let (zx, zy) = zoom.content_position(zx, zy);
+                (x, y) = (unzoomed.x1() + zx, unzoomed.y1() + zy);
+            }
             if let Some(im) = seat.input_method() {
                 for (_, popup) in &im.popups {
                     if popup.surface.node_visible() {
@@ -761,16 +887,33 @@ pub fn create_render_pass(
             if let Some(dnd_icon) = seat.dnd_icon() {
                 dnd_icon.render(&mut renderer, &rect, x, y);
             }
+            if let Some(progress) = seat.pointer_locator() {
+                let (x, y) = rect.translate(x, y);
+                renderer.render_pointer_locator(x, y, progress);
+            }
             if render_cursor {
                 let cursor_user_group = seat.cursor_group();
-                if render_hardware_cursor || !cursor_user_group.hardware_cursor() {
+                let hidden = cursor_user_group.hidden();
+                if !hidden && (render_hardware_cursor || !cursor_user_group.hardware_cursor()) {
                     if let Some(cursor_user) = cursor_user_group.active() {
                         if let Some(cursor) = cursor_user.get() {
                             cursor.tick();
                             let (mut x, mut y) = cursor_user.position();
-                            x -= Fixed::from_int(rect.x1());
-                            y -= Fixed::from_int(rect.y1());
-                            cursor.render(&mut renderer, x, y);
+                            match (magnifier, unzoomed_cursor_rect) {
+                                (Some(_), Some(unzoomed)) => {
+                                    // Cursors are not magnified.
+                                    x -= Fixed::from_int(unzoomed.x1());
+                                    y -= Fixed::from_int(unzoomed.y1());
+                                    set_renderer_scale(&mut renderer.base, unzoomed_scale);
+                                    cursor.render(&mut renderer, x, y);
+                                    set_renderer_scale(&mut renderer.base, scale);
+                                }
+                                _ => {
+                                    x -= Fixed::from_int(rect.x1());
+                                    y -= Fixed::from_int(rect.y1());
+                                    cursor.render(&mut renderer, x, y);
+                                }
+                            }
                         }
                     }
                 }
@@ -782,13 +925,9 @@ pub fn create_render_pass(
             visualizer.render(&cursor_rect, &mut renderer.base);
         }
     }
-    let c = match black_background {
-        true => Color::SOLID_BLACK,
-        false => state.theme.colors.background.get(),
-    };
     GfxRenderPass {
         ops,
-        clear: Some(c),
+        clear: Some(clear_color),
     }
 }
 
@@ -810,6 +949,12 @@ pub fn renderer_base<'a>(
     }
 }
 
+fn set_renderer_scale(base: &mut RendererBase<'_>, scale: Scale) {
+    base.scaled = scale != 1;
+    base.scale = scale;
+    base.scalef = scale.to_f64();
+}
+
 pub fn logical_size(physical_size: (i32, i32), transform: Transform) -> (i32, i32) {
     transform.maybe_swap(physical_size)
 }
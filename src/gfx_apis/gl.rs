@@ -345,9 +345,10 @@ fn render_texture(ctx: &GlRenderContext, tex: &CopyTexture) {
             },
             false => &ctx.tex_internal,
         };
-        let copy_type = match tex.alpha.is_some() {
-            true => TexCopyType::Multiply,
-            false => TexCopyType::Identity,
+        let copy_type = match (tex.alpha.is_some(), tex.color_matrix.is_some()) {
+            (_, true) => TexCopyType::ColorMatrix,
+            (true, false) => TexCopyType::Multiply,
+            (false, false) => TexCopyType::Identity,
         };
         let source_type = match texture.gl.format.has_alpha {
             true => TexSourceType::HasAlpha,
@@ -367,7 +368,10 @@ fn render_texture(ctx: &GlRenderContext, tex: &CopyTexture) {
         let texcoord = tex.source.to_points();
         let pos = tex.target.to_points();
 
-        if let Some(alpha) = tex.alpha {
+        if let Some(matrix) = &tex.color_matrix {
+            let matrix = matrix.scale(tex.alpha.unwrap_or(1.0)).to_columns();
+            (gles.glUniformMatrix4fv)(prog.color_matrix, 1, GL_FALSE, matrix.as_ptr().cast());
+        } else if let Some(alpha) = tex.alpha {
             (gles.glUniform1f)(prog.alpha, alpha);
         }
 
@@ -126,6 +126,12 @@ dynload! {
         glUniform1i: unsafe fn(location: GLint, v0: GLint),
         glUniform1f: unsafe fn(location: GLint, v0: GLfloat),
         glUniform4f: unsafe fn(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat),
+        glUniformMatrix4fv: unsafe fn(
+            location: GLint,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
         glVertexAttribPointer: unsafe fn(
             index: GLuint,
             size: GLint,
@@ -40,20 +40,26 @@ pub(crate) struct TexProg {
     pub(crate) texcoord: GLint,
     pub(crate) tex: GLint,
     pub(crate) alpha: GLint,
+    pub(crate) color_matrix: GLint,
 }
 
 impl TexProg {
-    unsafe fn from(prog: GlProgram, alpha_multiplier: bool) -> Self {
+    unsafe fn from(prog: GlProgram, copy_type: TexCopyType) -> Self {
         unsafe {
-            let alpha = match alpha_multiplier {
-                true => prog.get_uniform_location(c"alpha"),
-                false => 0,
+            let alpha = match copy_type {
+                TexCopyType::Multiply => prog.get_uniform_location(c"alpha"),
+                _ => 0,
+            };
+            let color_matrix = match copy_type {
+                TexCopyType::ColorMatrix => prog.get_uniform_location(c"color_matrix"),
+                _ => 0,
             };
             Self {
                 pos: prog.get_attrib_location(c"pos"),
                 texcoord: prog.get_attrib_location(c"texcoord"),
                 tex: prog.get_uniform_location(c"tex"),
                 alpha,
+                color_matrix,
                 prog,
             }
         }
@@ -64,6 +70,7 @@ impl TexProg {
 pub(in crate::gfx_apis::gl) enum TexCopyType {
     Identity,
     Multiply,
+    ColorMatrix,
 }
 
 #[derive(Copy, Clone, PartialEq, Enum)]
@@ -119,13 +126,15 @@ impl GlRenderContext {
         let tex_vert = include_str!("../shaders/tex.vert.glsl");
         let tex_frag = include_str!("../shaders/tex.frag.glsl");
         let create_programs = |external: bool| {
-            let create_program = |alpha_multiplier: bool, alpha: bool| {
+            let create_program = |copy_type: TexCopyType, alpha: bool| {
                 let mut tex_frac_src = String::new();
                 if external {
                     tex_frac_src.push_str("#define EXTERNAL\n");
                 }
-                if alpha_multiplier {
-                    tex_frac_src.push_str("#define ALPHA_MULTIPLIER\n");
+                match copy_type {
+                    TexCopyType::Identity => {}
+                    TexCopyType::Multiply => tex_frac_src.push_str("#define ALPHA_MULTIPLIER\n"),
+                    TexCopyType::ColorMatrix => tex_frac_src.push_str("#define COLOR_MATRIX\n"),
                 }
                 if alpha {
                     tex_frac_src.push_str("#define ALPHA\n");
@@ -133,17 +142,21 @@ impl GlRenderContext {
                 tex_frac_src.push_str(tex_frag);
                 unsafe {
                     let prog = GlProgram::from_shaders(ctx, tex_vert, &tex_frac_src)?;
-                    Ok::<_, RenderError>(TexProg::from(prog, alpha_multiplier))
+                    Ok::<_, RenderError>(TexProg::from(prog, copy_type))
                 }
             };
             Ok::<_, RenderError>(enum_map! {
                 TexCopyType::Identity => enum_map! {
-                    TexSourceType::Opaque => create_program(false, false)?,
-                    TexSourceType::HasAlpha => create_program(false, true)?,
+                    TexSourceType::Opaque => create_program(TexCopyType::Identity, false)?,
+                    TexSourceType::HasAlpha => create_program(TexCopyType::Identity, true)?,
                 },
                 TexCopyType::Multiply => enum_map! {
-                    TexSourceType::Opaque => create_program(true, false)?,
-                    TexSourceType::HasAlpha => create_program(true, true)?,
+                    TexSourceType::Opaque => create_program(TexCopyType::Multiply, false)?,
+                    TexSourceType::HasAlpha => create_program(TexCopyType::Multiply, true)?,
+                },
+                TexCopyType::ColorMatrix => enum_map! {
+                    TexSourceType::Opaque => create_program(TexCopyType::ColorMatrix, false)?,
+                    TexSourceType::HasAlpha => create_program(TexCopyType::ColorMatrix, true)?,
                 },
             })
         };
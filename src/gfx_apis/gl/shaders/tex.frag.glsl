@@ -12,8 +12,22 @@ uniform sampler2D tex;
 #ifdef ALPHA_MULTIPLIER
 uniform float alpha;
 #endif
+#ifdef COLOR_MATRIX
+uniform mat4 color_matrix;
+#endif
 
 void main() {
+#ifdef COLOR_MATRIX
+
+	vec4 c = texture2D(tex, v_texcoord);
+#ifndef ALPHA
+	c.a = 1.0;
+#endif // !ALPHA
+	c = color_matrix * c;
+	gl_FragColor = vec4(clamp(c.rgb, 0.0, c.a), c.a);
+
+#else // !COLOR_MATRIX
+
 #ifdef ALPHA
 
 #ifdef ALPHA_MULTIPLIER
@@ -31,4 +45,6 @@ void main() {
 #endif // ALPHA_MULTIPLIER
 
 #endif // ALPHA
+
+#endif // COLOR_MATRIX
 }
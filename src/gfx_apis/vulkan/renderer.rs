@@ -17,9 +17,10 @@ use {
             pipeline::{PipelineCreateInfo, VulkanPipeline},
             semaphore::VulkanSemaphore,
             shaders::{
-                FillFragPushConstants, FillVertPushConstants, TexFragPushConstants,
-                TexVertPushConstants, VulkanShader, FILL_FRAG, FILL_VERT, TEX_FRAG,
-                TEX_FRAG_MULT_ALPHA, TEX_FRAG_MULT_OPAQUE, TEX_VERT,
+                FillFragPushConstants, FillVertPushConstants, TexFragMatrixPushConstants,
+                TexFragPushConstants, TexVertPushConstants, VulkanShader, FILL_FRAG, FILL_VERT,
+                TEX_FRAG, TEX_FRAG_MATRIX_ALPHA, TEX_FRAG_MATRIX_OPAQUE, TEX_FRAG_MULT_ALPHA,
+                TEX_FRAG_MULT_OPAQUE, TEX_VERT,
             },
             VulkanError,
         },
@@ -76,6 +77,8 @@ pub struct VulkanRenderer {
     pub(super) tex_frag_shader: Rc<VulkanShader>,
     pub(super) tex_frag_mult_opaque_shader: Rc<VulkanShader>,
     pub(super) tex_frag_mult_alpha_shader: Rc<VulkanShader>,
+    pub(super) tex_frag_matrix_opaque_shader: Rc<VulkanShader>,
+    pub(super) tex_frag_matrix_alpha_shader: Rc<VulkanShader>,
     pub(super) tex_descriptor_set_layout: Rc<VulkanDescriptorSetLayout>,
     pub(super) defunct: Cell<bool>,
     pub(super) pending_cpu_jobs: CopyHashMap<u64, PendingJob>,
@@ -113,6 +116,7 @@ pub(super) struct UsedTexture {
 pub(super) enum TexCopyType {
     Identity,
     Multiply,
+    ColorMatrix,
 }
 
 #[derive(Enum)]
@@ -162,6 +166,8 @@ impl VulkanDevice {
         let tex_frag_shader = self.create_shader(TEX_FRAG)?;
         let tex_frag_mult_opaque_shader = self.create_shader(TEX_FRAG_MULT_OPAQUE)?;
         let tex_frag_mult_alpha_shader = self.create_shader(TEX_FRAG_MULT_ALPHA)?;
+        let tex_frag_matrix_opaque_shader = self.create_shader(TEX_FRAG_MATRIX_OPAQUE)?;
+        let tex_frag_matrix_alpha_shader = self.create_shader(TEX_FRAG_MATRIX_ALPHA)?;
         let gfx_command_buffers = self.create_command_pool(self.graphics_queue_idx)?;
         let transfer_command_buffers = self
             .distinct_transfer_queue_family_idx
@@ -221,6 +227,8 @@ impl VulkanDevice {
             tex_frag_shader,
             tex_frag_mult_opaque_shader,
             tex_frag_mult_alpha_shader,
+            tex_frag_matrix_opaque_shader,
+            tex_frag_matrix_alpha_shader,
             tex_descriptor_set_layout,
             defunct: Cell::new(false),
             pending_cpu_jobs: Default::default(),
@@ -270,10 +278,24 @@ impl VulkanRenderer {
                     frag_descriptor_set_layout: Some(self.tex_descriptor_set_layout.clone()),
                 })
         };
+        let create_tex_matrix_pipeline = |frag: &Rc<VulkanShader>| {
+            self.device
+                .create_pipeline::<TexVertPushConstants, TexFragMatrixPushConstants>(
+                    PipelineCreateInfo {
+                        format,
+                        vert: self.tex_vert_shader.clone(),
+                        frag: frag.clone(),
+                        alpha: true,
+                        frag_descriptor_set_layout: Some(self.tex_descriptor_set_layout.clone()),
+                    },
+                )
+        };
         let tex_opaque = create_tex_pipeline(false)?;
         let tex_alpha = create_tex_pipeline(true)?;
         let tex_mult_opaque = create_tex_mult_pipeline(&self.tex_frag_mult_opaque_shader)?;
         let tex_mult_alpha = create_tex_mult_pipeline(&self.tex_frag_mult_alpha_shader)?;
+        let tex_matrix_opaque = create_tex_matrix_pipeline(&self.tex_frag_matrix_opaque_shader)?;
+        let tex_matrix_alpha = create_tex_matrix_pipeline(&self.tex_frag_matrix_alpha_shader)?;
         let pipelines = Rc::new(VulkanFormatPipelines {
             fill,
             tex: enum_map! {
@@ -285,6 +307,10 @@ impl VulkanRenderer {
                     TexSourceType::HasAlpha => tex_mult_alpha.clone(),
                     TexSourceType::Opaque => tex_mult_opaque.clone(),
                 },
+                TexCopyType::ColorMatrix => enum_map! {
+                    TexSourceType::HasAlpha => tex_matrix_alpha.clone(),
+                    TexSourceType::Opaque => tex_matrix_opaque.clone(),
+                },
             },
         });
         self.pipelines.set(format, pipelines.clone());
@@ -549,9 +575,10 @@ impl VulkanRenderer {
                         log::warn!("Ignoring texture owned by different queue");
                         continue;
                     }
-                    let copy_type = match c.alpha.is_some() {
-                        true => TexCopyType::Multiply,
-                        false => TexCopyType::Identity,
+                    let copy_type = match (c.alpha.is_some(), c.color_matrix.is_some()) {
+                        (_, true) => TexCopyType::ColorMatrix,
+                        (true, false) => TexCopyType::Multiply,
+                        (false, false) => TexCopyType::Identity,
                     };
                     let source_type = match tex.format.has_alpha {
                         true => TexSourceType::HasAlpha,
@@ -584,7 +611,19 @@ impl VulkanRenderer {
                             0,
                             uapi::as_bytes(&vert),
                         );
-                        if let Some(alpha) = c.alpha {
+                        if let Some(matrix) = &c.color_matrix {
+                            let matrix = matrix.scale(c.alpha.unwrap_or(1.0));
+                            let frag = TexFragMatrixPushConstants {
+                                matrix: matrix.to_columns(),
+                            };
+                            dev.cmd_push_constants(
+                                buf,
+                                pipeline.pipeline_layout,
+                                ShaderStageFlags::FRAGMENT,
+                                size_of_val(&vert) as _,
+                                uapi::as_bytes(&frag),
+                            );
+                        } else if let Some(alpha) = c.alpha {
                             let frag = TexFragPushConstants { alpha };
                             dev.cmd_push_constants(
                                 buf,
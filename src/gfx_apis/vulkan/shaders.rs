@@ -13,6 +13,10 @@ pub const TEX_FRAG_MULT_OPAQUE: &[u8] =
     include_bytes!(concat!(env!("OUT_DIR"), "/tex.frag.mult+opaque.spv"));
 pub const TEX_FRAG_MULT_ALPHA: &[u8] =
     include_bytes!(concat!(env!("OUT_DIR"), "/tex.frag.mult+alpha.spv"));
+pub const TEX_FRAG_MATRIX_OPAQUE: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/tex.frag.matrix+opaque.spv"));
+pub const TEX_FRAG_MATRIX_ALPHA: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/tex.frag.matrix+alpha.spv"));
 
 pub struct VulkanShader {
     pub(super) device: Rc<VulkanDevice>,
@@ -48,8 +52,15 @@ pub struct TexFragPushConstants {
     pub alpha: f32,
 }
 
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct TexFragMatrixPushConstants {
+    pub matrix: [[f32; 4]; 4],
+}
+
 unsafe impl Packed for TexVertPushConstants {}
 unsafe impl Packed for TexFragPushConstants {}
+unsafe impl Packed for TexFragMatrixPushConstants {}
 
 impl VulkanDevice {
     pub(super) fn create_shader(
@@ -5,12 +5,24 @@ layout(push_constant, std430) uniform Data {
 	layout(offset = 64) float mul;
 } data;
 #endif
+#ifdef COLOR_MATRIX
+layout(push_constant, std430) uniform Data {
+	layout(offset = 64) mat4 matrix;
+} data;
+#endif
 layout(set = 0, binding = 0) uniform sampler2D tex;
 layout(location = 0) in vec2 tex_pos;
 layout(location = 0) out vec4 out_color;
 
 void main() {
-#ifdef ALPHA_MULTIPLIER
+#ifdef COLOR_MATRIX
+	vec4 c = textureLod(tex, tex_pos, 0);
+#ifndef ALPHA
+	c.a = 1.0;
+#endif // !ALPHA
+	c = data.matrix * c;
+	out_color = vec4(clamp(c.rgb, 0.0, c.a), c.a);
+#elif defined(ALPHA_MULTIPLIER)
 #ifdef ALPHA
 	out_color = textureLod(tex, tex_pos, 0) * data.mul;
 #endif // !ALPHA
@@ -9,15 +9,22 @@ pub mod ext_output_image_capture_source_manager_v1;
 pub mod ext_session_lock_manager_v1;
 pub mod ext_session_lock_v1;
 pub mod ipc;
+pub mod jay_client_errors;
+pub mod jay_clipboard;
 pub mod jay_compositor;
+pub mod jay_cursor_image;
 pub mod jay_damage_tracking;
+pub mod jay_frame_timings;
 pub mod jay_ei_session;
 pub mod jay_ei_session_builder;
 pub mod jay_idle;
 pub mod jay_input;
 pub mod jay_log_file;
+pub mod jay_log_stream;
 pub mod jay_output;
+pub mod jay_output_watcher;
 pub mod jay_pointer;
+pub mod jay_presentation_feed;
 pub mod jay_randr;
 pub mod jay_render_ctx;
 pub mod jay_screencast;
@@ -27,6 +34,7 @@ pub mod jay_select_toplevel;
 pub mod jay_select_workspace;
 pub mod jay_toplevel;
 pub mod jay_tray_v1;
+pub mod jay_which_key;
 pub mod jay_workspace;
 pub mod jay_workspace_watcher;
 pub mod jay_xwayland;
@@ -77,6 +85,7 @@ pub mod zwlr_layer_shell_v1;
 pub mod zwlr_screencopy_frame_v1;
 pub mod zwlr_screencopy_manager_v1;
 pub mod zwp_idle_inhibit_manager_v1;
+pub mod zwp_keyboard_shortcuts_inhibit_manager_v1;
 pub mod zwp_linux_buffer_params_v1;
 pub mod zwp_linux_dmabuf_feedback_v1;
 pub mod zwp_linux_dmabuf_v1;
@@ -233,6 +233,14 @@ impl SourceData {
         }
     }
 
+    pub fn mime_types(&self) -> Vec<String> {
+        self.mime_types.borrow().iter().cloned().collect()
+    }
+
+    pub fn has_mime_type(&self, mime_type: &str) -> bool {
+        self.mime_types.borrow().contains(mime_type)
+    }
+
     pub fn was_used(&self) -> bool {
         self.state.get().contains(SOURCE_STATE_USED)
     }
@@ -299,6 +307,36 @@ pub fn detach_seat<S: DataSource>(src: &S, seat: &Rc<WlSeatGlobal>) {
     // data.client.flush();
 }
 
+/// Recomputes the action selected for a drag-and-drop source and sends it to its offers.
+///
+/// Returns the new action if it changed.
+pub fn update_selected_action<S: DynDataSource>(src: &S) -> Option<u32> {
+    let data = src.source_data();
+    let shared = data.shared.get();
+    let server_actions = match data.actions.get() {
+        Some(n) => n,
+        _ => {
+            log::error!("Server actions not set");
+            return None;
+        }
+    };
+    let actions = server_actions & shared.receiver_actions.get();
+    let action = if actions.contains(shared.receiver_preferred_action.get()) {
+        shared.receiver_preferred_action.get()
+    } else if actions != 0 {
+        1 << actions.trailing_zeros()
+    } else {
+        0
+    };
+    if shared.selected_action.replace(action) == action {
+        return None;
+    }
+    for (_, offer) in &data.offers {
+        offer.send_action(action);
+    }
+    Some(action)
+}
+
 fn offer_source_to_device<T: IpcVtable>(
     src: &Rc<dyn DynDataSource>,
     dd: &Rc<T::Device>,
@@ -335,7 +373,7 @@ fn offer_source_to_device<T: IpcVtable>(
     }
 }
 
-fn offer_source_to_x<T>(src: Rc<dyn DynDataSource>, dd: &Rc<XIpcDevice>)
+pub fn offer_source_to_x<T>(src: Rc<dyn DynDataSource>, dd: &Rc<XIpcDevice>)
 where
     T: IpcVtable<Device = XIpcDevice>,
 {
@@ -4,7 +4,7 @@ use {
         ifs::{
             ipc::{
                 add_data_source_mime_type, break_source_loops, cancel_offers, destroy_data_source,
-                detach_seat, offer_source_to_x,
+                detach_seat, offer_source_to_x, update_selected_action,
                 wl_data_device::ClipboardIpc,
                 wl_data_device_manager::{DND_ALL, DND_NONE},
                 x_data_device::{XClipboardIpc, XIpcDevice},
@@ -107,29 +107,8 @@ impl WlDataSource {
     }
 
     pub fn update_selected_action(&self) {
-        let shared = self.data.shared.get();
-        let server_actions = match self.data.actions.get() {
-            Some(n) => n,
-            _ => {
-                log::error!("Server actions not set");
-                return;
-            }
-        };
-        let actions = server_actions & shared.receiver_actions.get();
-        let action = if actions.contains(shared.receiver_preferred_action.get()) {
-            shared.receiver_preferred_action.get()
-        } else if actions != 0 {
-            1 << actions.trailing_zeros()
-        } else {
-            0
-        };
-        if shared.selected_action.replace(action) != action {
-            for (_, offer) in &self.data.offers {
-                offer.send_action(action);
-                // offer.client.flush();
-            }
+        if let Some(action) = update_selected_action(self) {
             self.send_action(action);
-            // self.data.client.flush();
         }
     }
 
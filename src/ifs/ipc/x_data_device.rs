@@ -3,8 +3,8 @@ use {
         client::{Client, ClientError},
         ifs::{
             ipc::{
-                x_data_offer::XDataOffer, x_data_source::XDataSource, DeviceData, IpcLocation,
-                IpcVtable, OfferData, Role,
+                x_data_offer::XDataOffer, x_data_source::XDataSource, DeviceData, IpcVtable,
+                OfferData, Role,
             },
             wl_seat::WlSeatGlobal,
         },
@@ -21,19 +21,30 @@ pub struct XIpcDevice {
     pub id: XIpcDeviceId,
     pub clipboard: DeviceData<XDataOffer>,
     pub primary_selection: DeviceData<XDataOffer>,
+    pub dnd: DeviceData<XDataOffer>,
     pub seat: Rc<WlSeatGlobal>,
     pub state: Rc<State>,
     pub client: Rc<Client>,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum XIpcLocation {
+    Clipboard,
+    PrimarySelection,
+    Dnd,
+}
+
 #[derive(Default)]
 pub struct XClipboardIpc;
 
 #[derive(Default)]
 pub struct XPrimarySelectionIpc;
 
+#[derive(Default)]
+pub struct XDndIpc;
+
 pub trait XIpc {
-    const LOCATION: IpcLocation;
+    const LOCATION: XIpcLocation;
 
     fn x_unset(seat: &Rc<WlSeatGlobal>);
 
@@ -41,7 +52,7 @@ pub trait XIpc {
 }
 
 impl XIpc for XClipboardIpc {
-    const LOCATION: IpcLocation = IpcLocation::Clipboard;
+    const LOCATION: XIpcLocation = XIpcLocation::Clipboard;
 
     fn x_unset(seat: &Rc<WlSeatGlobal>) {
         seat.unset_selection();
@@ -53,7 +64,7 @@ impl XIpc for XClipboardIpc {
 }
 
 impl XIpc for XPrimarySelectionIpc {
-    const LOCATION: IpcLocation = IpcLocation::PrimarySelection;
+    const LOCATION: XIpcLocation = XIpcLocation::PrimarySelection;
 
     fn x_unset(seat: &Rc<WlSeatGlobal>) {
         seat.unset_primary_selection();
@@ -64,6 +75,18 @@ impl XIpc for XPrimarySelectionIpc {
     }
 }
 
+impl XIpc for XDndIpc {
+    const LOCATION: XIpcLocation = XIpcLocation::Dnd;
+
+    fn x_unset(seat: &Rc<WlSeatGlobal>) {
+        seat.cancel_dnd();
+    }
+
+    fn x_device_data(dd: &XIpcDevice) -> &DeviceData<XDataOffer> {
+        &dd.dnd
+    }
+}
+
 impl<T: XIpc> IpcVtable for T {
     type Device = XIpcDevice;
     type Source = XDataSource;
@@ -1,19 +1,25 @@
 use {
     crate::{
         client::ClientId,
+        fixed::Fixed,
         ifs::{
             ipc::{
                 cancel_offer,
-                x_data_device::{XClipboardIpc, XIpcDevice, XPrimarySelectionIpc},
-                DataOffer, DataOfferId, DynDataOffer, IpcLocation, OfferData,
+                x_data_device::{
+                    XClipboardIpc, XDndIpc, XIpcDevice, XIpcLocation, XPrimarySelectionIpc,
+                },
+                DataOffer, DataOfferId, DynDataOffer, OfferData, OFFER_STATE_ACCEPTED,
+                OFFER_STATE_DROPPED, OFFER_STATE_FINISHED, SOURCE_STATE_FINISHED,
             },
             wl_seat::WlSeatGlobal,
         },
         leaks::Tracker,
+        utils::bitflags::BitflagsExt,
+        wire::WlSurfaceId,
         xwayland::XWaylandEvent,
     },
     std::rc::Rc,
-    XWaylandEvent::IpcAddOfferMimeType,
+    XWaylandEvent::{DndEnter, IpcAddOfferMimeType},
 };
 
 pub struct XDataOffer {
@@ -21,7 +27,7 @@ pub struct XDataOffer {
     pub device: Rc<XIpcDevice>,
     pub data: OfferData<XIpcDevice>,
     pub tracker: Tracker<Self>,
-    pub location: IpcLocation,
+    pub location: XIpcLocation,
 }
 
 impl DataOffer for XDataOffer {
@@ -52,12 +58,80 @@ impl DynDataOffer for XDataOffer {
 
     fn cancel(&self) {
         match self.location {
-            IpcLocation::Clipboard => cancel_offer::<XClipboardIpc>(self),
-            IpcLocation::PrimarySelection => cancel_offer::<XPrimarySelectionIpc>(self),
+            XIpcLocation::Clipboard => cancel_offer::<XClipboardIpc>(self),
+            XIpcLocation::PrimarySelection => cancel_offer::<XPrimarySelectionIpc>(self),
+            XIpcLocation::Dnd => cancel_offer::<XDndIpc>(self),
         }
     }
 
     fn get_seat(&self) -> Rc<WlSeatGlobal> {
         self.device.seat.clone()
     }
+
+    fn send_enter(&self, surface: WlSurfaceId, x: Fixed, y: Fixed, _serial: u64) {
+        self.device.state.xwayland.queue.push(DndEnter {
+            seat: self.device.seat.id(),
+            offer: self.offer_id,
+            surface,
+            x,
+            y,
+        })
+    }
+
+    fn send_source_actions(&self) {
+        // X clients learn about the source actions via XdndPosition messages.
+    }
+}
+
+impl XDataOffer {
+    pub fn source_actions(&self) -> u32 {
+        match self.data.source.get() {
+            Some(src) => src.source_data().actions.get().unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    pub fn dnd_status(&self, accepted: Option<&str>, action: u32) {
+        let shared = &self.data.shared;
+        let state = shared.state.get();
+        if state.contains(OFFER_STATE_FINISHED) {
+            return;
+        }
+        if accepted.is_some() {
+            shared.state.set(state | OFFER_STATE_ACCEPTED);
+        } else {
+            shared.state.set(state & !OFFER_STATE_ACCEPTED);
+        }
+        shared.receiver_actions.set(action);
+        shared.receiver_preferred_action.set(action);
+        if let Some(src) = self.data.source.get() {
+            src.send_target(accepted);
+            src.update_selected_action();
+        }
+    }
+
+    pub fn is_droppable(&self) -> bool {
+        self.data.source.is_some()
+            && self
+                .data
+                .shared
+                .state
+                .get()
+                .contains(OFFER_STATE_DROPPED | OFFER_STATE_ACCEPTED)
+    }
+
+    pub fn dnd_finished(&self) {
+        let shared = &self.data.shared;
+        let state = shared.state.get();
+        if state.contains(OFFER_STATE_FINISHED)
+            || !state.contains(OFFER_STATE_DROPPED | OFFER_STATE_ACCEPTED)
+        {
+            return;
+        }
+        shared.state.set(state | OFFER_STATE_FINISHED);
+        if let Some(src) = self.data.source.get() {
+            src.source_data().state.or_assign(SOURCE_STATE_FINISHED);
+            src.send_dnd_finished();
+        }
+    }
 }
@@ -2,13 +2,18 @@ use {
     crate::{
         ifs::{
             ipc::{
-                cancel_offers, detach_seat, x_data_device::XIpcDevice, DataSource, DynDataSource,
-                IpcLocation, SourceData,
+                cancel_offers, detach_seat, update_selected_action,
+                x_data_device::{XIpcDevice, XIpcLocation},
+                DataSource, DynDataOffer, DynDataSource, SharedState, SourceData,
+                OFFER_STATE_ACCEPTED, OFFER_STATE_DROPPED, SOURCE_STATE_DROPPED,
             },
             wl_seat::WlSeatGlobal,
         },
         state::State,
-        xwayland::XWaylandEvent::{IpcCancelSource, IpcSendSource, IpcSetSelection},
+        utils::bitflags::BitflagsExt,
+        xwayland::XWaylandEvent::{
+            DndSourceFinished, DndSourceStatus, IpcCancelSource, IpcSendSource, IpcSetSelection,
+        },
     },
     std::rc::Rc,
     uapi::OwnedFd,
@@ -18,7 +23,7 @@ pub struct XDataSource {
     pub state: Rc<State>,
     pub device: Rc<XIpcDevice>,
     pub data: SourceData,
-    pub location: IpcLocation,
+    pub location: XIpcLocation,
 }
 
 impl DataSource for XDataSource {
@@ -62,4 +67,84 @@ impl DynDataSource for XDataSource {
     fn cancel_unprivileged_offers(&self) {
         cancel_offers(self, false)
     }
+
+    fn send_target(&self, _mime_type: Option<&str>) {
+        self.send_dnd_status();
+    }
+
+    fn send_dnd_finished(&self) {
+        self.state.xwayland.queue.push(DndSourceFinished {
+            seat: self.device.seat.id(),
+            source: self.data.id,
+        });
+    }
+
+    fn update_selected_action(&self) {
+        if update_selected_action(self).is_some() {
+            self.send_dnd_status();
+        }
+    }
+}
+
+impl XDataSource {
+    /// Informs the X client that started the drag about a changed drag-and-drop state.
+    fn send_dnd_status(&self) {
+        self.state.xwayland.queue.push(DndSourceStatus {
+            seat: self.device.seat.id(),
+            source: self.data.id,
+        });
+    }
+
+    pub fn set_dnd_actions(&self, actions: u32) {
+        if self.data.actions.replace(Some(actions)) == Some(actions) {
+            return;
+        }
+        for (_, offer) in &self.data.offers {
+            offer.send_source_actions();
+        }
+        self.update_selected_action();
+    }
+
+    pub fn selected_action(&self) -> u32 {
+        self.data.shared.get().selected_action.get()
+    }
+
+    pub fn for_each_data_offer<C: FnMut(&dyn DynDataOffer)>(&self, mut f: C) {
+        for (_, offer) in &self.data.offers {
+            f(&*offer);
+        }
+    }
+
+    pub fn can_drop(&self) -> bool {
+        let shared = self.data.shared.get();
+        shared.selected_action.get() != 0 && shared.state.get().contains(OFFER_STATE_ACCEPTED)
+    }
+
+    pub fn on_leave(&self) {
+        if self
+            .data
+            .shared
+            .get()
+            .state
+            .get()
+            .contains(OFFER_STATE_DROPPED)
+        {
+            return;
+        }
+        self.data.shared.set(Rc::new(SharedState::default()));
+        cancel_offers(self, false);
+    }
+
+    pub fn on_drop(&self) {
+        self.data.state.or_assign(SOURCE_STATE_DROPPED);
+        self.data.shared.get().state.or_assign(OFFER_STATE_DROPPED);
+        // The drag is not managed by the seat. Detach the source so that destroying the
+        // last offer does not cancel an unrelated drag of the seat.
+        self.data.seat.take();
+    }
+
+    pub fn cancel_dnd(&self) {
+        self.data.seat.take();
+        cancel_offers(self, true);
+    }
 }
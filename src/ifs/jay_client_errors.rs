@@ -0,0 +1,72 @@
+use {
+    crate::{
+        client::{Client, ClientError, ClientErrorEntry},
+        leaks::Tracker,
+        object::{Object, Version},
+        wire::{jay_client_errors::*, JayClientErrorsId},
+    },
+    std::rc::Rc,
+    thiserror::Error,
+};
+
+pub struct JayClientErrors {
+    pub id: JayClientErrorsId,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+}
+
+impl JayClientErrors {
+    pub fn send_error(&self, entry: &ClientErrorEntry) {
+        self.client.event(Error {
+            self_id: self.id,
+            client_id: entry.client_id.raw(),
+            comm: &entry.comm,
+            interface: &entry.interface,
+            request: &entry.request,
+            msg: &entry.msg,
+        });
+    }
+
+    pub fn send_done(&self) {
+        self.client.event(Done { self_id: self.id });
+    }
+
+    fn remove_from_state(&self) {
+        self.client
+            .state
+            .client_errors
+            .watchers
+            .remove(&(self.client.id, self.id));
+    }
+}
+
+impl JayClientErrorsRequestHandler for JayClientErrors {
+    type Error = JayClientErrorsError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.remove_from_state();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayClientErrors;
+    version = self.version;
+}
+
+impl Object for JayClientErrors {
+    fn break_loops(&self) {
+        self.remove_from_state();
+    }
+}
+
+simple_add_obj!(JayClientErrors);
+
+#[derive(Debug, Error)]
+pub enum JayClientErrorsError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayClientErrorsError, ClientError);
@@ -0,0 +1,154 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        ifs::{
+            ipc::{DynDataSource, IpcLocation},
+            wl_seat::WlSeatGlobal,
+        },
+        leaks::Tracker,
+        object::{Object, Version},
+        state::State,
+        wire::{jay_clipboard::*, JayClipboardId},
+    },
+    std::{cell::Cell, rc::Rc},
+    thiserror::Error,
+};
+
+pub const LOCATION_CLIPBOARD: u32 = 0;
+pub const LOCATION_PRIMARY_SELECTION: u32 = 1;
+
+pub async fn send_clipboard_changes(state: Rc<State>) {
+    loop {
+        let clipboard = state.pending_clipboard_changes.pop().await;
+        clipboard.scheduled.set(false);
+        let pending = clipboard.pending.take();
+        for location in [LOCATION_CLIPBOARD, LOCATION_PRIMARY_SELECTION] {
+            if pending & (1 << location) == 0 {
+                continue;
+            }
+            if let Ok(selection) = clipboard.selection(location) {
+                if !clipboard.owns(selection.as_ref()) {
+                    clipboard.send_selection(location, selection.as_ref());
+                }
+            }
+        }
+    }
+}
+
+pub struct JayClipboard {
+    pub id: JayClipboardId,
+    pub client: Rc<Client>,
+    pub seat: Rc<WlSeatGlobal>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub pending: Cell<u32>,
+    pub scheduled: Cell<bool>,
+}
+
+impl JayClipboard {
+    fn owns(&self, selection: Option<&Rc<dyn DynDataSource>>) -> bool {
+        selection.is_some_and(|s| s.source_data().client.id == self.client.id)
+    }
+
+    pub fn selection_changed(
+        self: &Rc<Self>,
+        location: IpcLocation,
+        selection: Option<&Rc<dyn DynDataSource>>,
+    ) {
+        // Selections set by the clipboard manager itself are not reported back to it.
+        if self.owns(selection) {
+            return;
+        }
+        let location = match location {
+            IpcLocation::Clipboard => LOCATION_CLIPBOARD,
+            IpcLocation::PrimarySelection => LOCATION_PRIMARY_SELECTION,
+        };
+        self.pending.set(self.pending.get() | (1 << location));
+        // Changes are sent once per dispatch so that rapid changes are coalesced.
+        if !self.scheduled.replace(true) {
+            self.client
+                .state
+                .pending_clipboard_changes
+                .push(self.clone());
+        }
+    }
+
+    fn remove_from_seat(&self) {
+        self.pending.set(0);
+        self.seat.remove_clipboard_watcher(self);
+    }
+
+    fn selection(&self, location: u32) -> Result<Option<Rc<dyn DynDataSource>>, JayClipboardError> {
+        let selection = match location {
+            LOCATION_CLIPBOARD => self.seat.get_selection(),
+            LOCATION_PRIMARY_SELECTION => self.seat.get_primary_selection(),
+            _ => return Err(JayClipboardError::UnknownLocation(location)),
+        };
+        Ok(selection)
+    }
+
+    pub fn send_selection(&self, location: u32, selection: Option<&Rc<dyn DynDataSource>>) {
+        if let Some(src) = selection {
+            for mime_type in src.source_data().mime_types() {
+                self.client.event(MimeType {
+                    self_id: self.id,
+                    location,
+                    mime_type: &mime_type,
+                });
+            }
+        }
+        self.client.event(Done {
+            self_id: self.id,
+            location,
+        });
+    }
+
+    pub fn send_current(&self) {
+        for location in [LOCATION_CLIPBOARD, LOCATION_PRIMARY_SELECTION] {
+            if let Ok(selection) = self.selection(location) {
+                self.send_selection(location, selection.as_ref());
+            }
+        }
+    }
+}
+
+impl JayClipboardRequestHandler for JayClipboard {
+    type Error = JayClipboardError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.remove_from_seat();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+
+    fn receive(&self, req: Receive, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        if let Some(src) = self.selection(req.location)? {
+            if src.source_data().has_mime_type(req.mime_type) {
+                src.send_send(req.mime_type, req.fd);
+            }
+        }
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayClipboard;
+    version = self.version;
+}
+
+impl Object for JayClipboard {
+    fn break_loops(&self) {
+        self.remove_from_seat();
+    }
+}
+
+simple_add_obj!(JayClipboard);
+
+#[derive(Debug, Error)]
+pub enum JayClipboardError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+    #[error("Unknown selection location {0}")]
+    UnknownLocation(u32),
+}
+efrom!(JayClipboardError, ClientError);
@@ -4,12 +4,19 @@ use {
         client::{Client, ClientCaps, ClientError, CAP_JAY_COMPOSITOR},
         globals::{Global, GlobalName},
         ifs::{
+            jay_client_errors::JayClientErrors,
+            jay_clipboard::JayClipboard,
+            jay_cursor_image::JayCursorImage,
             jay_ei_session_builder::JayEiSessionBuilder,
+            jay_frame_timings::JayFrameTimings,
             jay_idle::JayIdle,
             jay_input::JayInput,
             jay_log_file::JayLogFile,
+            jay_log_stream::JayLogStream,
             jay_output::JayOutput,
+            jay_output_watcher::JayOutputWatcher,
             jay_pointer::JayPointer,
+            jay_presentation_feed::JayPresentationFeed,
             jay_randr::JayRandr,
             jay_render_ctx::JayRenderCtx,
             jay_screencast::JayScreencast,
@@ -17,18 +24,27 @@ use {
             jay_seat_events::JaySeatEvents,
             jay_select_toplevel::{JaySelectToplevel, JayToplevelSelector},
             jay_select_workspace::{JaySelectWorkspace, JayWorkspaceSelector},
+            jay_which_key::JayWhichKey,
             jay_workspace_watcher::JayWorkspaceWatcher,
             jay_xwayland::JayXwayland,
         },
         leaks::Tracker,
         object::{Object, Version},
-        screenshoter::take_screenshot,
-        utils::{errorfmt::ErrorFmt, toplevel_identifier::ToplevelIdentifier},
+        utils::{
+            errorfmt::ErrorFmt, event_listener::EventListener,
+            toplevel_identifier::ToplevelIdentifier,
+        },
         wire::{jay_compositor::*, JayCompositorId, JayScreenshotId},
+        xkbcommon::{XkbCommonError, XkbRuleNames},
     },
     bstr::ByteSlice,
     log::Level,
-    std::{cell::Cell, ops::Deref, rc::Rc, str::FromStr},
+    std::{
+        cell::Cell,
+        ops::Deref,
+        rc::{Rc, Weak},
+        str::FromStr,
+    },
     thiserror::Error,
 };
 
@@ -72,7 +88,7 @@ impl Global for JayCompositorGlobal {
     }
 
     fn version(&self) -> u32 {
-        12
+        14
     }
 
     fn required_caps(&self) -> ClientCaps {
@@ -109,47 +125,21 @@ impl JayCompositor {
         &self,
         id: JayScreenshotId,
         include_cursor: bool,
+        after_presentation: bool,
     ) -> Result<(), JayCompositorError> {
-        let ss = Rc::new(JayScreenshot {
+        let ss = Rc::new(JayScreenshot::new(
             id,
-            client: self.client.clone(),
-            tracker: Default::default(),
-        });
+            &self.client,
+            self.version,
+            include_cursor,
+        ));
         track!(self.client, ss);
         self.client.add_client_obj(&ss)?;
-        match take_screenshot(&self.client.state, include_cursor) {
-            Ok(s) => {
-                let dmabuf = s.bo.dmabuf();
-                if self.version < SCREENSHOT_SPLITUP_SINCE {
-                    if let Some(drm) = &s.drm {
-                        let plane = &dmabuf.planes[0];
-                        ss.send_dmabuf(
-                            drm,
-                            &plane.fd,
-                            dmabuf.width,
-                            dmabuf.height,
-                            plane.offset,
-                            plane.stride,
-                            dmabuf.modifier,
-                        );
-                    } else {
-                        ss.send_error("Buffer has no associated DRM device");
-                    }
-                } else {
-                    if let Some(drm) = &s.drm {
-                        ss.send_drm_dev(drm);
-                    }
-                    for plane in &dmabuf.planes {
-                        ss.send_plane(plane);
-                    }
-                    ss.send_dmabuf2(dmabuf);
-                }
-            }
-            Err(e) => {
-                let msg = ErrorFmt(e).to_string();
-                ss.send_error(&msg);
-            }
+        if after_presentation {
+            ss.capture_after_presentation();
+            return Ok(());
         }
+        ss.capture();
         self.client.remove_obj(ss.deref())?;
         Ok(())
     }
@@ -164,7 +154,7 @@ impl JayCompositorRequestHandler for JayCompositor {
     }
 
     fn get_log_file(&self, req: GetLogFile, _slf: &Rc<Self>) -> Result<(), Self::Error> {
-        let log_file = Rc::new(JayLogFile::new(req.id, &self.client));
+        let log_file = Rc::new(JayLogFile::new(req.id, &self.client, self.version));
         track!(self.client, log_file);
         self.client.add_client_obj(&log_file)?;
         match &self.client.state.logger {
@@ -181,19 +171,7 @@ impl JayCompositorRequestHandler for JayCompositor {
     }
 
     fn set_log_level(&self, req: SetLogLevel, _slf: &Rc<Self>) -> Result<(), Self::Error> {
-        const ERROR: u32 = CliLogLevel::Error as u32;
-        const WARN: u32 = CliLogLevel::Warn as u32;
-        const INFO: u32 = CliLogLevel::Info as u32;
-        const DEBUG: u32 = CliLogLevel::Debug as u32;
-        const TRACE: u32 = CliLogLevel::Trace as u32;
-        let level = match req.level {
-            ERROR => Level::Error,
-            WARN => Level::Warn,
-            INFO => Level::Info,
-            DEBUG => Level::Debug,
-            TRACE => Level::Trace,
-            _ => return Err(JayCompositorError::UnknownLogLevel(req.level)),
-        };
+        let level = parse_log_level(req.level)?;
         if let Some(logger) = &self.client.state.logger {
             logger.set_level(level);
         }
@@ -201,11 +179,80 @@ impl JayCompositorRequestHandler for JayCompositor {
     }
 
     fn take_screenshot(&self, req: TakeScreenshot, _slf: &Rc<Self>) -> Result<(), Self::Error> {
-        self.take_screenshot_impl(req.id, false)
+        self.take_screenshot_impl(req.id, false, false)
     }
 
     fn take_screenshot2(&self, req: TakeScreenshot2, _slf: &Rc<Self>) -> Result<(), Self::Error> {
-        self.take_screenshot_impl(req.id, req.include_cursor != 0)
+        self.take_screenshot_impl(req.id, req.include_cursor != 0, false)
+    }
+
+    fn take_screenshot3(&self, req: TakeScreenshot3, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.take_screenshot_impl(req.id, req.include_cursor != 0, req.after_presentation != 0)
+    }
+
+    fn take_output_screenshot(
+        &self,
+        req: TakeOutputScreenshot,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let ss = Rc::new(JayScreenshot::new(
+            req.id,
+            &self.client,
+            self.version,
+            req.include_cursor != 0,
+        ));
+        track!(self.client, ss);
+        self.client.add_client_obj(&ss)?;
+        let output = self
+            .client
+            .state
+            .root
+            .outputs
+            .lock()
+            .values()
+            .find(|o| o.global.connector.name == req.output)
+            .cloned();
+        match output {
+            Some(output) => ss.capture_output(&output),
+            _ => ss.send_error(&format!("Output {} does not exist", req.output)),
+        }
+        self.client.remove_obj(ss.deref())?;
+        Ok(())
+    }
+
+    fn take_screenshot_shm(
+        &self,
+        req: TakeScreenshotShm,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let ss = Rc::new(JayScreenshot::new(
+            req.id,
+            &self.client,
+            self.version,
+            req.include_cursor != 0,
+        ));
+        track!(self.client, ss);
+        self.client.add_client_obj(&ss)?;
+        ss.capture_shm();
+        Ok(())
+    }
+
+    fn get_client_errors(&self, req: GetClientErrors, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let errors = Rc::new(JayClientErrors {
+            id: req.id,
+            client: self.client.clone(),
+            tracker: Default::default(),
+            version: self.version,
+        });
+        track!(self.client, errors);
+        self.client.add_client_obj(&errors)?;
+        let log = &self.client.state.client_errors;
+        for entry in log.errors() {
+            errors.send_error(&entry);
+        }
+        errors.send_done();
+        log.watchers.set((self.client.id, req.id), errors);
+        Ok(())
     }
 
     fn get_idle(&self, req: GetIdle, _slf: &Rc<Self>) -> Result<(), Self::Error> {
@@ -213,6 +260,7 @@ impl JayCompositorRequestHandler for JayCompositor {
             id: req.id,
             client: self.client.clone(),
             tracker: Default::default(),
+            version: self.version,
         });
         track!(self.client, idle);
         self.client.add_client_obj(&idle)?;
@@ -329,6 +377,7 @@ impl JayCompositorRequestHandler for JayCompositor {
             id: req.id,
             client: self.client.clone(),
             tracker: Default::default(),
+            version: self.version,
         });
         track!(self.client, watcher);
         self.client.add_client_obj(&watcher)?;
@@ -383,6 +432,7 @@ impl JayCompositorRequestHandler for JayCompositor {
             client: self.client.clone(),
             tracker: Default::default(),
             destroyed: Cell::new(false),
+            version: self.version,
         });
         track!(self.client, obj);
         self.client.add_client_obj(&obj)?;
@@ -438,8 +488,382 @@ impl JayCompositorRequestHandler for JayCompositor {
         obj.done(tl);
         Ok(())
     }
+
+    fn get_keymap_names(&self, _req: GetKeymapNames, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        for seat in self.client.state.globals.seats.lock().values() {
+            let keymap = seat.keymap();
+            let names = keymap.names.clone().unwrap_or_default();
+            self.send_keymap_names(seat.seat_name(), &names);
+        }
+        Ok(())
+    }
+
+    fn set_keymap_names(
+        &self,
+        req: SetKeymapNames<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let names = XkbRuleNames {
+            rules: req.rules.to_string(),
+            model: req.model.to_string(),
+            layout: req.layout.to_string(),
+            variant: req.variant.to_string(),
+            options: req.options.to_string(),
+        };
+        if let Err(e) = self.apply_keymap_names(&names) {
+            let msg = ErrorFmt(e).to_string();
+            log::warn!("Could not apply keymap names: {}", msg);
+            self.client.event(KeymapError {
+                self_id: self.id,
+                msg: &msg,
+            });
+        }
+        Ok(())
+    }
+
+    fn reload_config(&self, _req: ReloadConfig, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        if let Err(e) = self.client.state.reload_config() {
+            let msg = ErrorFmt(e).to_string();
+            log::error!("Cannot reload config: {}", msg);
+            self.client.event(ConfigError {
+                self_id: self.id,
+                msg: &msg,
+            });
+        }
+        Ok(())
+    }
+
+    fn use_default_config(
+        &self,
+        _req: UseDefaultConfig,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        self.client.state.use_default_config();
+        Ok(())
+    }
+
+    fn rename_workspace(
+        &self,
+        req: RenameWorkspace<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        if let Err(e) = self.apply_workspace_name(req.name, req.new_name) {
+            let msg = ErrorFmt(e).to_string();
+            log::warn!("Could not rename workspace: {}", msg);
+            self.client.event(WorkspaceError {
+                self_id: self.id,
+                msg: &msg,
+            });
+        }
+        Ok(())
+    }
+
+    fn set_output_power(&self, req: SetOutputPower, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let output = self.client.lookup(req.output)?;
+        if let Some(node) = output.global.node() {
+            node.set_power(req.on != 0);
+        }
+        Ok(())
+    }
+
+    fn get_output_gamma_size(
+        &self,
+        req: GetOutputGammaSize,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let output = self.client.lookup(req.output)?;
+        let size = output
+            .global
+            .node()
+            .and_then(|n| n.global.connector.connector.gamma_size())
+            .unwrap_or(0);
+        self.client.event(OutputGammaSize {
+            self_id: self.id,
+            output: req.output,
+            size: size as _,
+        });
+        Ok(())
+    }
+
+    fn set_output_gamma(
+        &self,
+        req: SetOutputGamma<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let output = self.client.lookup(req.output)?;
+        let Some(node) = output.global.node() else {
+            return Ok(());
+        };
+        if let Err(e) = node.set_gamma_ramp(req.red, req.green, req.blue) {
+            let msg = ErrorFmt(e).to_string();
+            log::warn!("Could not set gamma ramp: {}", msg);
+            self.client.event(GammaError {
+                self_id: self.id,
+                output: req.output,
+                msg: &msg,
+            });
+        }
+        Ok(())
+    }
+
+    fn reset_output_gamma(
+        &self,
+        req: ResetOutputGamma,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let output = self.client.lookup(req.output)?;
+        if let Some(node) = output.global.node() {
+            node.reset_gamma_ramp();
+        }
+        Ok(())
+    }
+
+    fn get_presentation_feed(
+        &self,
+        req: GetPresentationFeed,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let output = self.client.lookup(req.output)?;
+        let feed = Rc::new_cyclic(|slf: &Weak<JayPresentationFeed>| JayPresentationFeed {
+            id: req.id,
+            client: self.client.clone(),
+            output: output.global.clone(),
+            tracker: Default::default(),
+            version: self.version,
+            listener: EventListener::new(slf.clone()),
+        });
+        track!(self.client, feed);
+        self.client.add_client_obj(&feed)?;
+        if let Some(node) = feed.output.node() {
+            feed.listener.attach(&node.presentation_event);
+            node.presentation_feeds
+                .set((self.client.id, req.id), feed.clone());
+        } else {
+            feed.send_destroyed();
+        }
+        Ok(())
+    }
+
+    fn get_frame_timings(&self, req: GetFrameTimings, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let output = self.client.lookup(req.output)?;
+        let timings = Rc::new_cyclic(|slf: &Weak<JayFrameTimings>| JayFrameTimings {
+            id: req.id,
+            client: self.client.clone(),
+            output: output.global.clone(),
+            tracker: Default::default(),
+            version: self.version,
+            latch_listener: EventListener::new(slf.clone()),
+            vblank_listener: EventListener::new(slf.clone()),
+        });
+        track!(self.client, timings);
+        self.client.add_client_obj(&timings)?;
+        if let Some(node) = timings.output.node() {
+            timings.attach(&node);
+            node.frame_timings
+                .set((self.client.id, req.id), timings.clone());
+        } else {
+            timings.send_destroyed();
+        }
+        Ok(())
+    }
+
+    fn get_clipboard(&self, req: GetClipboard, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let seat = self.client.lookup(req.seat)?;
+        let clipboard = Rc::new(JayClipboard {
+            id: req.id,
+            client: self.client.clone(),
+            seat: seat.global.clone(),
+            tracker: Default::default(),
+            version: self.version,
+            pending: Cell::new(0),
+            scheduled: Cell::new(false),
+        });
+        track!(self.client, clipboard);
+        self.client.add_client_obj(&clipboard)?;
+        clipboard.send_current();
+        clipboard.seat.add_clipboard_watcher(&clipboard);
+        Ok(())
+    }
+
+    fn set_module_log_level(
+        &self,
+        req: SetModuleLogLevel,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let level = parse_log_level(req.level)?;
+        if let Some(logger) = &self.client.state.logger {
+            logger.set_module_level(req.module, level);
+        }
+        Ok(())
+    }
+
+    fn reset_module_log_levels(
+        &self,
+        _req: ResetModuleLogLevels,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        if let Some(logger) = &self.client.state.logger {
+            logger.reset_module_levels();
+        }
+        Ok(())
+    }
+
+    fn get_log_stream(&self, req: GetLogStream, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let level = parse_log_level(req.level)?;
+        let stream = Rc::new(JayLogStream::new(req.id, &self.client, self.version));
+        track!(self.client, stream);
+        self.client.add_client_obj(&stream)?;
+        stream.subscribe(level);
+        Ok(())
+    }
+
+    fn get_shortcuts(&self, req: GetShortcuts, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let seat = self.client.lookup(req.seat)?;
+        for (mods, keysym) in seat.global.list_shortcuts() {
+            self.client.event(Shortcut {
+                self_id: self.id,
+                mods: mods.0,
+                keysym: keysym.0,
+            });
+        }
+        Ok(())
+    }
+
+    fn get_which_key(&self, req: GetWhichKey, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let seat = self.client.lookup(req.seat)?;
+        let which_key = Rc::new(JayWhichKey {
+            id: req.id,
+            client: self.client.clone(),
+            seat: seat.global.clone(),
+            tracker: Default::default(),
+            version: self.version,
+        });
+        track!(self.client, which_key);
+        self.client.add_client_obj(&which_key)?;
+        which_key.seat.add_which_key_watcher(&which_key);
+        Ok(())
+    }
+
+    fn watch_outputs(&self, req: WatchOutputs, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let watcher = Rc::new(JayOutputWatcher {
+            id: req.id,
+            client: self.client.clone(),
+            tracker: Default::default(),
+            version: self.version,
+        });
+        track!(self.client, watcher);
+        self.client.add_client_obj(&watcher)?;
+        self.client
+            .state
+            .output_watchers
+            .set((self.client.id, req.id), watcher.clone());
+        for output in self.client.state.root.outputs.lock().values() {
+            watcher.send_added(output);
+        }
+        Ok(())
+    }
+
+    fn get_cursor_image(&self, req: GetCursorImage, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let seat = self.client.lookup(req.seat)?;
+        let image = JayCursorImage::new(req.id, &self.client, &seat.global, self.version);
+        track!(self.client, image);
+        self.client.add_client_obj(&image)?;
+        image
+            .listener
+            .attach(&seat.global.cursor_group().image_changed_event);
+        Ok(())
+    }
+}
+
+fn parse_log_level(level: u32) -> Result<Level, JayCompositorError> {
+    const ERROR: u32 = CliLogLevel::Error as u32;
+    const WARN: u32 = CliLogLevel::Warn as u32;
+    const INFO: u32 = CliLogLevel::Info as u32;
+    const DEBUG: u32 = CliLogLevel::Debug as u32;
+    const TRACE: u32 = CliLogLevel::Trace as u32;
+    let level = match level {
+        ERROR => Level::Error,
+        WARN => Level::Warn,
+        INFO => Level::Info,
+        DEBUG => Level::Debug,
+        TRACE => Level::Trace,
+        _ => return Err(JayCompositorError::UnknownLogLevel(level)),
+    };
+    Ok(level)
 }
 
+impl JayCompositor {
+    fn send_keymap_names(&self, seat: &str, names: &XkbRuleNames) {
+        self.client.event(KeymapNames {
+            self_id: self.id,
+            seat,
+            rules: &names.rules,
+            model: &names.model,
+            layout: &names.layout,
+            variant: &names.variant,
+            options: &names.options,
+        });
+    }
+
+    fn apply_keymap_names(&self, names: &XkbRuleNames) -> Result<(), KeymapNamesError> {
+        let fields = [
+            ("rules", &names.rules),
+            ("model", &names.model),
+            ("layout", &names.layout),
+            ("variant", &names.variant),
+            ("options", &names.options),
+        ];
+        for (field, value) in fields {
+            if value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+                return Err(KeymapNamesError::InvalidCharacters(field));
+            }
+        }
+        let num_layouts = names.layout.split(',').count();
+        if num_layouts > XKB_MAX_LAYOUTS {
+            return Err(KeymapNamesError::TooManyLayouts);
+        }
+        if names.variant.split(',').count() > num_layouts {
+            return Err(KeymapNamesError::TooManyVariants);
+        }
+        let state = &self.client.state;
+        let keymap = state.xkb_ctx.keymap_from_names(names)?;
+        let seats: Vec<_> = state.globals.seats.lock().values().cloned().collect();
+        for seat in seats {
+            seat.set_seat_keymap(&keymap);
+            self.send_keymap_names(seat.seat_name(), names);
+        }
+        Ok(())
+    }
+
+    fn apply_workspace_name(&self, name: &str, new_name: &str) -> Result<(), RenameWorkspaceError> {
+        let state = &self.client.state;
+        let Some(ws) = state.workspaces.get(name) else {
+            return Err(RenameWorkspaceError::UnknownWorkspace(name.to_string()));
+        };
+        if new_name.is_empty() {
+            return Err(RenameWorkspaceError::EmptyName);
+        }
+        if name == new_name {
+            return Ok(());
+        }
+        if state.workspaces.contains(new_name) {
+            return Err(RenameWorkspaceError::AlreadyExists(new_name.to_string()));
+        }
+        state.workspaces.remove(name);
+        state.workspaces.set(new_name.to_string(), ws.clone());
+        ws.name.set(Rc::new(new_name.to_string()));
+        for jw in ws.jay_workspaces.lock().values() {
+            jw.send_name(&ws);
+        }
+        ws.flush_jay_workspaces();
+        ws.output.get().schedule_update_render_data();
+        state.tree_changed();
+        Ok(())
+    }
+}
+
+const XKB_MAX_LAYOUTS: usize = 4;
+
 object_base! {
     self = JayCompositor;
     version = self.version;
@@ -457,3 +881,25 @@ pub enum JayCompositorError {
     UnknownLogLevel(u32),
 }
 efrom!(JayCompositorError, ClientError);
+
+#[derive(Debug, Error)]
+enum KeymapNamesError {
+    #[error("The {0} field contains invalid characters")]
+    InvalidCharacters(&'static str),
+    #[error("A keymap can contain at most {} layouts", XKB_MAX_LAYOUTS)]
+    TooManyLayouts,
+    #[error("There are more variants than layouts")]
+    TooManyVariants,
+    #[error("Could not compile the keymap")]
+    XkbCommonError(#[from] XkbCommonError),
+}
+
+#[derive(Debug, Error)]
+enum RenameWorkspaceError {
+    #[error("There is no workspace named {0}")]
+    UnknownWorkspace(String),
+    #[error("The new name is empty")]
+    EmptyName,
+    #[error("A workspace named {0} already exists")]
+    AlreadyExists(String),
+}
@@ -0,0 +1,237 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        cursor::Cursor,
+        cursor_user::CursorImageListener,
+        gfx_api::{AsyncShmGfxTextureCallback, GfxError},
+        ifs::wl_seat::WlSeatGlobal,
+        leaks::Tracker,
+        object::{Object, Version},
+        scale::Scale,
+        screenshoter::{take_cursor_image, take_cursor_image_shm, ShmScreenshot},
+        utils::{errorfmt::ErrorFmt, event_listener::EventListener},
+        wire::{jay_cursor_image::*, JayCursorImageId},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::{Rc, Weak},
+    },
+    thiserror::Error,
+};
+
+pub struct JayCursorImage {
+    pub id: JayCursorImageId,
+    pub client: Rc<Client>,
+    pub seat: Rc<WlSeatGlobal>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub listener: EventListener<dyn CursorImageListener>,
+    /// Whether a `changed` event has been sent since the last capture.
+    changed_sent: Cell<bool>,
+    shm: RefCell<Option<(ShmScreenshot, CursorImageInfo)>>,
+}
+
+#[derive(Copy, Clone)]
+struct CursorImageInfo {
+    hotspot_x: i32,
+    hotspot_y: i32,
+    scale: Scale,
+}
+
+impl JayCursorImage {
+    pub fn new(
+        id: JayCursorImageId,
+        client: &Rc<Client>,
+        seat: &Rc<WlSeatGlobal>,
+        version: Version,
+    ) -> Rc<Self> {
+        Rc::new_cyclic(|slf: &Weak<Self>| Self {
+            id,
+            client: client.clone(),
+            seat: seat.clone(),
+            tracker: Default::default(),
+            version,
+            listener: EventListener::new(slf.clone()),
+            changed_sent: Cell::new(false),
+            shm: Default::default(),
+        })
+    }
+
+    /// Returns the visible cursor of the seat and the scale it is rendered at.
+    fn cursor(&self) -> Option<(Rc<dyn Cursor>, CursorImageInfo)> {
+        let group = self.seat.cursor_group();
+        if group.hidden() {
+            return None;
+        }
+        let user = group.active()?;
+        let cursor = user.get()?;
+        let scale = user.output().global.persistent.scale.get();
+        let extents = cursor.extents_at_scale(scale);
+        if extents.is_empty() {
+            return None;
+        }
+        let info = CursorImageInfo {
+            hotspot_x: -extents.x1(),
+            hotspot_y: -extents.y1(),
+            scale,
+        };
+        Some((cursor, info))
+    }
+
+    fn send_dmabuf_image(&self) {
+        let Some((cursor, info)) = self.cursor() else {
+            self.send_empty();
+            return;
+        };
+        match take_cursor_image(&self.client.state, &*cursor, info.scale) {
+            Ok(s) => {
+                let dmabuf = s.bo.dmabuf();
+                if let Some(drm) = &s.drm {
+                    self.client.event(DrmDev {
+                        self_id: self.id,
+                        drm_dev: drm.clone(),
+                    });
+                }
+                for plane in &dmabuf.planes {
+                    self.client.event(Plane {
+                        self_id: self.id,
+                        fd: plane.fd.clone(),
+                        offset: plane.offset,
+                        stride: plane.stride,
+                    });
+                }
+                self.client.event(Dmabuf {
+                    self_id: self.id,
+                    width: dmabuf.width,
+                    height: dmabuf.height,
+                    format: dmabuf.format.drm,
+                    modifier: dmabuf.modifier,
+                });
+                self.send_image(info);
+            }
+            Err(e) => self.send_error(&ErrorFmt(e).to_string()),
+        }
+    }
+
+    fn start_shm_capture(self: &Rc<Self>) {
+        if self.shm.borrow().is_some() {
+            // The result of the pending capture will also be the result of this capture.
+            return;
+        }
+        let Some((cursor, info)) = self.cursor() else {
+            self.send_empty();
+            return;
+        };
+        match take_cursor_image_shm(&self.client.state, &*cursor, info.scale, self.clone()) {
+            Ok(s) => {
+                let completed = s.pending.is_none();
+                *self.shm.borrow_mut() = Some((s, info));
+                if completed {
+                    self.complete_shm(Ok(()));
+                }
+            }
+            Err(e) => self.send_error(&ErrorFmt(e).to_string()),
+        }
+    }
+
+    fn complete_shm(&self, res: Result<(), GfxError>) {
+        let Some((s, info)) = self.shm.take() else {
+            return;
+        };
+        match res {
+            Ok(()) => {
+                self.client.event(Shm {
+                    self_id: self.id,
+                    fd: s.fd.clone(),
+                    width: s.width,
+                    height: s.height,
+                    stride: s.stride,
+                    format: s.format.drm,
+                });
+                self.send_image(info);
+            }
+            Err(e) => self.send_error(&ErrorFmt(e).to_string()),
+        }
+    }
+
+    fn send_image(&self, info: CursorImageInfo) {
+        self.client.event(Image {
+            self_id: self.id,
+            hotspot_x: info.hotspot_x,
+            hotspot_y: info.hotspot_y,
+            scale: info.scale.to_f64(),
+        });
+    }
+
+    fn send_empty(&self) {
+        self.client.event(Empty { self_id: self.id });
+    }
+
+    fn send_error(&self, msg: &str) {
+        self.client.event(Error {
+            self_id: self.id,
+            msg,
+        });
+    }
+
+    fn detach(&self) {
+        self.listener.detach();
+        self.shm.take();
+    }
+}
+
+impl CursorImageListener for JayCursorImage {
+    fn cursor_image_changed(self: Rc<Self>) {
+        if !self.changed_sent.replace(true) {
+            self.client.event(Changed { self_id: self.id });
+        }
+    }
+}
+
+impl AsyncShmGfxTextureCallback for JayCursorImage {
+    fn completed(self: Rc<Self>, res: Result<(), GfxError>) {
+        self.complete_shm(res);
+    }
+}
+
+impl JayCursorImageRequestHandler for JayCursorImage {
+    type Error = JayCursorImageError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.detach();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+
+    fn capture(&self, _req: Capture, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.changed_sent.set(false);
+        self.send_dmabuf_image();
+        Ok(())
+    }
+
+    fn capture_shm(&self, _req: CaptureShm, slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.changed_sent.set(false);
+        slf.start_shm_capture();
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayCursorImage;
+    version = self.version;
+}
+
+impl Object for JayCursorImage {
+    fn break_loops(&self) {
+        self.detach();
+    }
+}
+
+simple_add_obj!(JayCursorImage);
+
+#[derive(Debug, Error)]
+pub enum JayCursorImageError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayCursorImageError, ClientError);
@@ -0,0 +1,98 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        ifs::wl_output::OutputGlobalOpt,
+        leaks::Tracker,
+        object::{Object, Version},
+        time::Time,
+        tree::{LatchListener, OutputNode, VblankListener},
+        utils::event_listener::EventListener,
+        wire::{jay_frame_timings::*, JayFrameTimingsId},
+    },
+    std::rc::Rc,
+    thiserror::Error,
+};
+
+pub struct JayFrameTimings {
+    pub id: JayFrameTimingsId,
+    pub client: Rc<Client>,
+    pub output: Rc<OutputGlobalOpt>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub latch_listener: EventListener<dyn LatchListener>,
+    pub vblank_listener: EventListener<dyn VblankListener>,
+}
+
+impl JayFrameTimings {
+    pub fn attach(&self, output: &OutputNode) {
+        self.latch_listener.attach(&output.latch_event);
+        self.vblank_listener.attach(&output.passive_vblank_event);
+    }
+
+    fn detach(&self) {
+        self.latch_listener.detach();
+        self.vblank_listener.detach();
+    }
+
+    pub fn send_destroyed(&self) {
+        self.detach();
+        self.client.event(Destroyed { self_id: self.id });
+    }
+
+    fn remove_from_node(&self) {
+        self.detach();
+        if let Some(output) = self.output.node() {
+            output.frame_timings.remove(&(self.client.id, self.id));
+        }
+    }
+}
+
+impl LatchListener for JayFrameTimings {
+    fn after_latch(self: Rc<Self>, on: &OutputNode, tearing: bool) {
+        self.client.event(Latched {
+            self_id: self.id,
+            time_ns: Time::now_unchecked().nsec(),
+            flip_margin_ns: on.flip_margin_ns.get().unwrap_or_default(),
+            tearing: tearing as _,
+        });
+    }
+}
+
+impl VblankListener for JayFrameTimings {
+    fn after_vblank(self: Rc<Self>) {
+        self.client.event(Vblank {
+            self_id: self.id,
+            time_ns: Time::now_unchecked().nsec(),
+        });
+    }
+}
+
+impl JayFrameTimingsRequestHandler for JayFrameTimings {
+    type Error = JayFrameTimingsError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.remove_from_node();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayFrameTimings;
+    version = self.version;
+}
+
+impl Object for JayFrameTimings {
+    fn break_loops(&self) {
+        self.remove_from_node();
+    }
+}
+
+simple_add_obj!(JayFrameTimings);
+
+#[derive(Debug, Error)]
+pub enum JayFrameTimingsError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayFrameTimingsError, ClientError);
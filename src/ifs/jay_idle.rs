@@ -14,8 +14,12 @@ pub struct JayIdle {
     pub id: JayIdleId,
     pub client: Rc<Client>,
     pub tracker: Tracker<Self>,
+    pub version: Version,
 }
 
+const GRACE_PERIOD_SINCE: Version = Version(14);
+const INHIBITOR2_SINCE: Version = Version(14);
+
 impl JayIdle {
     fn send_interval(&self) {
         let to = self.client.state.idle.timeout.get();
@@ -25,16 +29,35 @@ impl JayIdle {
         });
     }
 
-    fn send_inhibitor(&self, surface: &ZwpIdleInhibitorV1) {
-        let surface = &surface.surface;
-        self.client.event(Inhibitor {
+    fn send_grace_period(&self) {
+        let period = self.client.state.idle.grace_period.get();
+        self.client.event(GracePeriod {
             self_id: self.id,
-            surface: surface.id,
-            client_id: surface.client.id.raw(),
-            pid: surface.client.pid_info.pid as _,
-            comm: &surface.client.pid_info.comm,
+            period: period.as_secs(),
         });
     }
+
+    fn send_inhibitor(&self, inhibitor: &ZwpIdleInhibitorV1) {
+        let surface = &inhibitor.surface;
+        if self.version >= INHIBITOR2_SINCE {
+            self.client.event(Inhibitor2 {
+                self_id: self.id,
+                surface: surface.id,
+                client_id: surface.client.id.raw(),
+                pid: surface.client.pid_info.pid as _,
+                comm: &surface.client.pid_info.comm,
+                since_usec: inhibitor.active_since_usec.get(),
+            });
+        } else {
+            self.client.event(Inhibitor {
+                self_id: self.id,
+                surface: surface.id,
+                client_id: surface.client.id.raw(),
+                pid: surface.client.pid_info.pid as _,
+                comm: &surface.client.pid_info.comm,
+            });
+        }
+    }
 }
 
 impl JayIdleRequestHandler for JayIdle {
@@ -42,6 +65,10 @@ impl JayIdleRequestHandler for JayIdle {
 
     fn get_status(&self, _req: GetStatus, _slf: &Rc<Self>) -> Result<(), Self::Error> {
         self.send_interval();
+        if self.version >= GRACE_PERIOD_SINCE {
+            self.send_grace_period();
+        }
+        self.client.state.idle.prune_inhibitors();
         {
             let inhibitors = self.client.state.idle.inhibitors.lock();
             for inhibitor in inhibitors.values() {
@@ -56,11 +83,17 @@ impl JayIdleRequestHandler for JayIdle {
         self.client.state.idle.set_timeout(interval);
         Ok(())
     }
+
+    fn set_grace_period(&self, req: SetGracePeriod, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let period = Duration::from_secs(req.period);
+        self.client.state.idle.set_grace_period(period);
+        Ok(())
+    }
 }
 
 object_base! {
     self = JayIdle;
-    version = Version(1);
+    version = self.version;
 }
 
 impl Object for JayIdle {}
@@ -28,6 +28,7 @@ pub struct JayInput {
 }
 
 const CALIBRATION_MATRIX_SINCE: Version = Version(4);
+const KEYBOARD_LAYOUT_SINCE: Version = Version(14);
 
 impl JayInput {
     pub fn new(id: JayInputId, client: &Rc<Client>, version: Version) -> Self {
@@ -63,6 +64,12 @@ impl JayInput {
             repeat_delay: data.get_rate().1,
             hardware_cursor: data.cursor_group().hardware_cursor() as _,
         });
+        if self.version >= KEYBOARD_LAYOUT_SINCE {
+            self.client.event(KeyboardLayout {
+                self_id: self.id,
+                name: &data.keyboard_layout(),
+            });
+        }
     }
 
     fn send_error(&self, error: &str) {
@@ -2,10 +2,12 @@ use {
     crate::{
         client::{Client, ClientError},
         leaks::Tracker,
+        logger::LogFileError,
         object::{Object, Version},
+        utils::errorfmt::ErrorFmt,
         wire::{jay_log_file::*, JayLogFileId},
     },
-    bstr::BStr,
+    bstr::{BStr, ByteSlice},
     std::rc::Rc,
     thiserror::Error,
 };
@@ -14,14 +16,16 @@ pub struct JayLogFile {
     pub id: JayLogFileId,
     pub client: Rc<Client>,
     pub tracker: Tracker<Self>,
+    pub version: Version,
 }
 
 impl JayLogFile {
-    pub fn new(id: JayLogFileId, client: &Rc<Client>) -> Self {
+    pub fn new(id: JayLogFileId, client: &Rc<Client>, version: Version) -> Self {
         Self {
             id,
             client: client.clone(),
             tracker: Default::default(),
+            version,
         }
     }
 
@@ -31,6 +35,20 @@ impl JayLogFile {
             path,
         });
     }
+
+    fn send_rotated(&self, path: &BStr) {
+        self.client.event(Rotated {
+            self_id: self.id,
+            path,
+        });
+    }
+
+    fn send_rotate_failed(&self, msg: &str) {
+        self.client.event(RotateFailed {
+            self_id: self.id,
+            msg,
+        });
+    }
 }
 
 impl JayLogFileRequestHandler for JayLogFile {
@@ -40,11 +58,27 @@ impl JayLogFileRequestHandler for JayLogFile {
         self.client.remove_obj(self)?;
         Ok(())
     }
+
+    fn rotate(&self, _req: Rotate, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let res = match &self.client.state.logger {
+            Some(logger) => logger.rotate(),
+            _ => Err(LogFileError::NotAFile),
+        };
+        match res {
+            Ok(path) => self.send_rotated(path.as_bytes().as_bstr()),
+            Err(e) => {
+                let msg = ErrorFmt(e).to_string();
+                log::warn!("Could not rotate the log file: {}", msg);
+                self.send_rotate_failed(&msg);
+            }
+        }
+        Ok(())
+    }
 }
 
 object_base! {
     self = JayLogFile;
-    version = Version(1);
+    version = self.version;
 }
 
 impl Object for JayLogFile {}
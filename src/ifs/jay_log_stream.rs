@@ -0,0 +1,144 @@
+use {
+    crate::{
+        async_engine::SpawnedFuture,
+        cli::CliLogLevel,
+        client::{Client, ClientError},
+        leaks::Tracker,
+        logger::{self, LogListener},
+        object::{Object, Version},
+        utils::{buf::TypedBuf, errorfmt::ErrorFmt, oserror::OsError},
+        wire::{jay_log_stream::*, JayLogStreamId},
+    },
+    log::Level,
+    std::{cell::Cell, rc::Rc, sync::Arc},
+    thiserror::Error,
+    uapi::{c, OwnedFd},
+};
+
+pub struct JayLogStream {
+    pub id: JayLogStreamId,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub listener: Cell<Option<Arc<LogListener>>>,
+    pub task: Cell<Option<SpawnedFuture<()>>>,
+}
+
+impl JayLogStream {
+    pub fn new(id: JayLogStreamId, client: &Rc<Client>, version: Version) -> Self {
+        Self {
+            id,
+            client: client.clone(),
+            tracker: Default::default(),
+            version,
+            listener: Default::default(),
+            task: Default::default(),
+        }
+    }
+
+    pub fn subscribe(self: &Rc<Self>, level: Level) {
+        let Some(logger) = &self.client.state.logger else {
+            return;
+        };
+        let eventfd = match uapi::eventfd(0, c::EFD_CLOEXEC) {
+            Ok(fd) => Rc::new(fd),
+            Err(e) => {
+                let e = OsError::from(e);
+                log::error!("Could not create an eventfd: {}", ErrorFmt(e));
+                return;
+            }
+        };
+        let listener = match logger.add_listener(level, &eventfd) {
+            Ok(l) => l,
+            Err(e) => {
+                log::error!("Could not add a log listener: {}", ErrorFmt(e));
+                return;
+            }
+        };
+        self.listener.set(Some(listener.clone()));
+        let slf = self.clone();
+        let future = self.client.state.eng.spawn("log stream", async move {
+            slf.forward_lines(listener, eventfd).await;
+        });
+        self.task.set(Some(future));
+    }
+
+    async fn forward_lines(&self, listener: Arc<LogListener>, eventfd: Rc<OwnedFd>) {
+        let mut buf = TypedBuf::<u64>::new();
+        loop {
+            if let Err(e) = self.client.state.ring.read(&eventfd, buf.buf()).await {
+                log::error!("Could not wait for log lines: {}", ErrorFmt(e));
+                return;
+            }
+            // Lines are buffered by the listener until the client catches up. If the
+            // client falls too far behind, the listener drops lines and we report them
+            // as lost.
+            self.client.wait_for_event_backlog().await;
+            let buffer = listener.take();
+            logger::without_listeners(|| {
+                if buffer.lost > 0 {
+                    self.client.event(Lost {
+                        self_id: self.id,
+                        count: buffer.lost,
+                    });
+                }
+                for (level, line) in &buffer.lines {
+                    self.client.event(Line {
+                        self_id: self.id,
+                        level: cli_log_level(*level) as u32,
+                        line,
+                    });
+                }
+            });
+        }
+    }
+
+    fn detach(&self) {
+        self.task.take();
+        if let Some(listener) = self.listener.take() {
+            if let Some(logger) = &self.client.state.logger {
+                logger.remove_listener(&listener);
+            }
+        }
+    }
+}
+
+fn cli_log_level(level: Level) -> CliLogLevel {
+    match level {
+        Level::Error => CliLogLevel::Error,
+        Level::Warn => CliLogLevel::Warn,
+        Level::Info => CliLogLevel::Info,
+        Level::Debug => CliLogLevel::Debug,
+        Level::Trace => CliLogLevel::Trace,
+    }
+}
+
+impl JayLogStreamRequestHandler for JayLogStream {
+    type Error = JayLogStreamError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.detach();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayLogStream;
+    version = self.version;
+}
+
+impl Object for JayLogStream {
+    fn break_loops(&self) {
+        self.detach();
+    }
+}
+
+simple_add_obj!(JayLogStream);
+
+#[derive(Debug, Error)]
+pub enum JayLogStreamError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayLogStreamError, ClientError);
@@ -0,0 +1,102 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        leaks::Tracker,
+        object::{Object, Version},
+        tree::OutputNode,
+        wire::{jay_output_watcher::*, JayOutputWatcherId},
+    },
+    std::rc::Rc,
+    thiserror::Error,
+};
+
+pub struct JayOutputWatcher {
+    pub id: JayOutputWatcherId,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+}
+
+impl JayOutputWatcher {
+    pub fn send_added(&self, node: &OutputNode) {
+        let global = &node.global;
+        let connector = &*global.connector.name;
+        let mode = global.mode.get();
+        self.client.event(Added {
+            self_id: self.id,
+            connector,
+            width_mm: global.width_mm,
+            height_mm: global.height_mm,
+            width: mode.width,
+            height: mode.height,
+            refresh_rate_millihz: mode.refresh_rate_millihz,
+        });
+        for mode in &global.modes {
+            self.client.event(Mode {
+                self_id: self.id,
+                connector,
+                width: mode.width,
+                height: mode.height,
+                refresh_rate_millihz: mode.refresh_rate_millihz,
+            });
+        }
+        self.send_hardware_cursor(node);
+    }
+
+    pub fn send_hardware_cursor(&self, node: &OutputNode) {
+        let connector = &*node.global.connector.name;
+        self.client.event(HardwareCursor {
+            self_id: self.id,
+            connector,
+            active: node.hardware_cursor.is_some() as _,
+        });
+        self.client.event(Done {
+            self_id: self.id,
+            connector,
+        });
+    }
+
+    pub fn send_removed(&self, connector: &str) {
+        self.client.event(Removed {
+            self_id: self.id,
+            connector,
+        });
+    }
+
+    fn remove_from_state(&self) {
+        self.client
+            .state
+            .output_watchers
+            .remove(&(self.client.id, self.id));
+    }
+}
+
+impl JayOutputWatcherRequestHandler for JayOutputWatcher {
+    type Error = JayOutputWatcherError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.remove_from_state();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayOutputWatcher;
+    version = self.version;
+}
+
+impl Object for JayOutputWatcher {
+    fn break_loops(&self) {
+        self.remove_from_state();
+    }
+}
+
+simple_add_obj!(JayOutputWatcher);
+
+#[derive(Debug, Error)]
+pub enum JayOutputWatcherError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayOutputWatcherError, ClientError);
@@ -0,0 +1,89 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        ifs::wl_output::OutputGlobalOpt,
+        leaks::Tracker,
+        object::{Object, Version},
+        tree::{OutputNode, PresentationListener},
+        utils::event_listener::EventListener,
+        wire::{jay_presentation_feed::*, JayPresentationFeedId},
+    },
+    std::rc::Rc,
+    thiserror::Error,
+};
+
+pub struct JayPresentationFeed {
+    pub id: JayPresentationFeedId,
+    pub client: Rc<Client>,
+    pub output: Rc<OutputGlobalOpt>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub listener: EventListener<dyn PresentationListener>,
+}
+
+impl JayPresentationFeed {
+    pub fn send_destroyed(&self) {
+        self.listener.detach();
+        self.client.event(Destroyed { self_id: self.id });
+    }
+
+    fn remove_from_node(&self) {
+        self.listener.detach();
+        if let Some(output) = self.output.node() {
+            output.presentation_feeds.remove(&(self.client.id, self.id));
+        }
+    }
+}
+
+impl PresentationListener for JayPresentationFeed {
+    fn presented(
+        self: Rc<Self>,
+        _output: &OutputNode,
+        tv_sec: u64,
+        tv_nsec: u32,
+        refresh: u32,
+        seq: u64,
+        flags: u32,
+        vrr: bool,
+    ) {
+        self.client.event(Presented {
+            self_id: self.id,
+            tv_sec,
+            tv_nsec,
+            refresh,
+            seq,
+            flags,
+            vrr: vrr as _,
+        });
+    }
+}
+
+impl JayPresentationFeedRequestHandler for JayPresentationFeed {
+    type Error = JayPresentationFeedError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.remove_from_node();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayPresentationFeed;
+    version = self.version;
+}
+
+impl Object for JayPresentationFeed {
+    fn break_loops(&self) {
+        self.remove_from_node();
+    }
+}
+
+simple_add_obj!(JayPresentationFeed);
+
+#[derive(Debug, Error)]
+pub enum JayPresentationFeedError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayPresentationFeedError, ClientError);
@@ -6,16 +6,18 @@ use {
         format::named_formats,
         leaks::Tracker,
         object::{Object, Version},
+        rect::Rect,
         scale::Scale,
         state::{ConnectorData, DrmDevData, OutputData},
-        tree::{OutputNode, TearingMode, VrrMode},
+        theme::Color,
+        tree::{calculate_logical_size, OutputNode, TearingMode, VrrMode},
         utils::{gfx_api_ext::GfxApiExt, transform_ext::TransformExt},
         wire::{jay_randr::*, JayRandrId},
     },
     jay_config::video::{
         GfxApi, TearingMode as ConfigTearingMode, Transform, VrrMode as ConfigVrrMode,
     },
-    std::rc::Rc,
+    std::{cell::RefCell, mem, rc::Rc},
     thiserror::Error,
 };
 
@@ -24,12 +26,24 @@ pub struct JayRandr {
     pub client: Rc<Client>,
     pub tracker: Tracker<Self>,
     pub version: Version,
+    staged_layout: RefCell<Vec<StagedOutput>>,
+}
+
+struct StagedOutput {
+    output: String,
+    x: i32,
+    y: i32,
+    mode: backend::Mode,
+    transform: i32,
+    scale: u32,
 }
 
 const VRR_CAPABLE_SINCE: Version = Version(2);
 const TEARING_SINCE: Version = Version(3);
 const FORMAT_SINCE: Version = Version(8);
 const FLIP_MARGIN_SINCE: Version = Version(10);
+const BACKGROUND_SINCE: Version = Version(14);
+const HARDWARE_CURSOR_SINCE: Version = Version(14);
 
 impl JayRandr {
     pub fn new(id: JayRandrId, client: &Rc<Client>, version: Version) -> Self {
@@ -38,6 +52,7 @@ impl JayRandr {
             client: client.clone(),
             tracker: Default::default(),
             version,
+            staged_layout: Default::default(),
         }
     }
 
@@ -153,6 +168,23 @@ impl JayRandr {
                 });
             }
         }
+        if self.version >= HARDWARE_CURSOR_SINCE {
+            self.client.event(HardwareCursor {
+                self_id: self.id,
+                active: node.hardware_cursor.is_some() as _,
+            });
+        }
+        if self.version >= BACKGROUND_SINCE {
+            if let Some(color) = node.global.persistent.background.get() {
+                self.client.event(Background {
+                    self_id: self.id,
+                    r: color.r,
+                    g: color.g,
+                    b: color.b,
+                    a: color.a,
+                });
+            }
+        }
         let current_mode = global.mode.get();
         for mode in &global.modes {
             self.client.event(Mode {
@@ -227,6 +259,87 @@ impl JayRandr {
         }
         None
     }
+
+    /// Applies the position, mode, transform, and scale of all staged outputs at once.
+    fn apply_staged_layout(&self, staged: Vec<StagedOutput>) {
+        let mut layout: Vec<(Rc<OutputNode>, Rect, backend::Mode, Transform, Scale)> = vec![];
+        for s in staged {
+            let Some(node) = self.get_output_node(&s.output) else {
+                return;
+            };
+            let Some(transform) = Transform::from_wl(s.transform) else {
+                self.send_error(&format!("Unknown transform {}", s.transform));
+                return;
+            };
+            if !node.global.modes.contains(&s.mode) {
+                self.send_error(&format!(
+                    "Connector {} does not support the mode {}x{}@{}",
+                    node.global.connector.name,
+                    s.mode.width,
+                    s.mode.height,
+                    s.mode.refresh_rate_millihz as f64 / 1000.0,
+                ));
+                return;
+            }
+            if s.x < 0 || s.y < 0 || s.x > MAX_EXTENTS || s.y > MAX_EXTENTS {
+                self.send_error(&format!("x and y must be in the range [0, {MAX_EXTENTS}]"));
+                return;
+            }
+            let zero_area = || {
+                self.send_error(&format!(
+                    "Output {} would have zero area",
+                    node.global.connector.name
+                ));
+            };
+            if s.scale == 0 {
+                zero_area();
+                return;
+            }
+            let scale = Scale::from_wl(s.scale);
+            let (width, height) = calculate_logical_size(
+                (s.mode.width, s.mode.height),
+                transform,
+                node.effective_scale(scale),
+            );
+            let rect = match Rect::new_sized(s.x, s.y, width, height) {
+                Some(r) if !r.is_empty() => r,
+                _ => {
+                    zero_area();
+                    return;
+                }
+            };
+            layout.push((node, rect, s.mode, transform, scale));
+        }
+        let mut rects: Vec<_> = layout
+            .iter()
+            .map(|(n, rect, ..)| (n.clone(), *rect))
+            .collect();
+        for output in self.client.state.root.outputs.lock().values() {
+            if !rects.iter().any(|(n, _)| n.id == output.id) {
+                rects.push((output.clone(), output.global.pos.get()));
+            }
+        }
+        for (i, (n1, r1)) in rects.iter().enumerate() {
+            for (n2, r2) in &rects[i + 1..] {
+                if r1.intersects(r2) {
+                    self.send_error(&format!(
+                        "Outputs {} and {} would overlap",
+                        n1.global.connector.name, n2.global.connector.name,
+                    ));
+                    return;
+                }
+            }
+        }
+        self.client.state.batch_output_extents_changes(|| {
+            for (node, rect, mode, transform, scale) in layout {
+                let mode_changed = node.global.mode.get() != mode;
+                node.apply_layout(rect.x1(), rect.y1(), mode, transform, scale);
+                if mode_changed {
+                    node.global.connector.connector.set_mode(mode);
+                }
+            }
+        });
+    }
 }
 
 impl JayRandrRequestHandler for JayRandr {
@@ -309,11 +422,22 @@ impl JayRandrRequestHandler for JayRandr {
         let Some(c) = self.get_output(req.output) else {
             return Ok(());
         };
-        c.connector.connector.set_mode(backend::Mode {
+        let mode = backend::Mode {
             width: req.width,
             height: req.height,
             refresh_rate_millihz: req.refresh_rate_millihz,
-        });
+        };
+        if !c.monitor_info.modes.contains(&mode) {
+            self.send_error(&format!(
+                "Connector {} does not support the mode {}x{}@{}",
+                c.connector.name,
+                mode.width,
+                mode.height,
+                mode.refresh_rate_millihz as f64 / 1000.0,
+            ));
+            return Ok(());
+        }
+        c.connector.connector.set_mode(mode);
         Ok(())
     }
 
@@ -412,6 +536,128 @@ impl JayRandrRequestHandler for JayRandr {
         dev.dev.set_flip_margin(req.margin_ns);
         Ok(())
     }
+
+    fn get_statistics(&self, req: GetStatistics<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let Some(c) = self.get_output_node(req.output) else {
+            return Ok(());
+        };
+        let stats = &c.stats;
+        self.client.event(Statistics {
+            self_id: self.id,
+            vblanks: stats.vblanks.get(),
+            latches: stats.latches.get(),
+            presentations: stats.presentations.get(),
+            missed_frames: stats.missed_frames.get(),
+            avg_flip_margin_ns: stats.avg_flip_margin_ns().unwrap_or_default(),
+            last_refresh_ns: stats.last_refresh_ns.get(),
+            last_flags: stats.last_flags.get(),
+        });
+        Ok(())
+    }
+
+    fn set_background(&self, req: SetBackground<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let Some(c) = self.get_output_node(req.output) else {
+            return Ok(());
+        };
+        c.set_background(Some(Color {
+            r: req.r,
+            g: req.g,
+            b: req.b,
+            a: req.a,
+        }));
+        Ok(())
+    }
+
+    fn reset_background(
+        &self,
+        req: ResetBackground<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let Some(c) = self.get_output_node(req.output) else {
+            return Ok(());
+        };
+        c.set_background(None);
+        Ok(())
+    }
+
+    fn get_output_flip_margin(
+        &self,
+        req: GetOutputFlipMargin<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let Some(c) = self.get_output_node(req.output) else {
+            return Ok(());
+        };
+        self.client.event(OutputFlipMargin {
+            self_id: self.id,
+            margin_ns: c.flip_margin_ns.get().unwrap_or_default(),
+            fixed: c.global.persistent.flip_margin_ns.get().is_some() as _,
+        });
+        Ok(())
+    }
+
+    fn set_output_flip_margin(
+        &self,
+        req: SetOutputFlipMargin<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let Some(c) = self.get_output_node(req.output) else {
+            return Ok(());
+        };
+        c.set_flip_margin(Some(req.margin_ns));
+        Ok(())
+    }
+
+    fn reset_output_flip_margin(
+        &self,
+        req: ResetOutputFlipMargin<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let Some(c) = self.get_output_node(req.output) else {
+            return Ok(());
+        };
+        c.set_flip_margin(None);
+        Ok(())
+    }
+
+    fn stage_output_layout(
+        &self,
+        req: StageOutputLayout<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let Some(node) = self.get_output_node(req.output) else {
+            return Ok(());
+        };
+        let output = &node.global.connector.name;
+        let staged = &mut *self.staged_layout.borrow_mut();
+        if staged.iter().any(|s| s.output == *output) {
+            self.send_error(&format!("Output {output} was staged more than once"));
+            return Ok(());
+        }
+        staged.push(StagedOutput {
+            output: output.clone(),
+            x: req.x,
+            y: req.y,
+            mode: backend::Mode {
+                width: req.width,
+                height: req.height,
+                refresh_rate_millihz: req.refresh_rate_millihz,
+            },
+            transform: req.transform,
+            scale: req.scale,
+        });
+        Ok(())
+    }
+
+    fn apply_output_layout(
+        &self,
+        _req: ApplyOutputLayout,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let staged = mem::take(&mut *self.staged_layout.borrow_mut());
+        self.apply_staged_layout(staged);
+        Ok(())
+    }
 }
 
 object_base! {
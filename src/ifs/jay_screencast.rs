@@ -2,13 +2,14 @@ use {
     crate::{
         allocator::{AllocatorError, BufferObject, BO_USE_LINEAR, BO_USE_RENDERING},
         client::{Client, ClientError},
-        format::XRGB8888,
+        format::{ARGB8888, XRGB8888},
         gfx_api::{
             AcquireSync, BufferResv, GfxContext, GfxError, GfxFramebuffer, GfxTexture, ReleaseSync,
         },
         ifs::{jay_output::JayOutput, jay_toplevel::JayToplevel, wl_buffer::WlBufferStorage},
         leaks::Tracker,
         object::{Object, Version},
+        rect::Rect,
         scale::Scale,
         state::State,
         tree::{LatchListener, OutputNode, ToplevelNode, WorkspaceNode, WorkspaceNodeId},
@@ -58,6 +59,7 @@ pub async fn perform_screencast_realloc(state: Rc<State>) {
 }
 
 pub const CLIENT_BUFFERS_SINCE: Version = Version(7);
+pub const CURSOR_ONLY_SINCE: Version = Version(14);
 
 pub struct JayScreencast {
     pub id: JayScreencastId,
@@ -76,6 +78,8 @@ pub struct JayScreencast {
     show_all: Cell<bool>,
     show_workspaces: RefCell<AHashSet<WorkspaceNodeId>>,
     linear: Cell<bool>,
+    region: Cell<Option<Rect>>,
+    cursor_only: Cell<bool>,
     pending: Pending,
     need_realloc_or_reconfigure: Cell<bool>,
     realloc_or_reconfigure_scheduled: Cell<bool>,
@@ -108,6 +112,8 @@ struct Pending {
     target: Cell<Option<Option<PendingTarget>>>,
     show_all: Cell<Option<bool>>,
     show_workspaces: RefCell<Option<AHashSet<WorkspaceNodeId>>>,
+    region: Cell<Option<Option<Rect>>>,
+    cursor_only: Cell<Option<bool>>,
     clear_buffers: Cell<bool>,
     buffers: RefCell<Vec<Rc<dyn GfxFramebuffer>>>,
 }
@@ -132,6 +138,10 @@ impl JayScreencast {
         false
     }
 
+    pub fn cursor_only(&self) -> bool {
+        self.cursor_only.get()
+    }
+
     pub fn new(
         id: JayScreencastId,
         client: &Rc<Client>,
@@ -155,6 +165,8 @@ impl JayScreencast {
             show_all: Cell::new(false),
             show_workspaces: Default::default(),
             linear: Cell::new(false),
+            region: Default::default(),
+            cursor_only: Cell::new(false),
             pending: Default::default(),
             need_realloc_or_reconfigure: Cell::new(false),
             realloc_or_reconfigure_scheduled: Cell::new(false),
@@ -259,7 +271,7 @@ impl JayScreencast {
         self.config_acked.set(false);
         let serial = self.config_serial.fetch_add(1) + 1;
         if let Some(target) = self.target.get() {
-            let (width, height) = target_size(Some(&target));
+            let (width, height) = target_size(Some(&target), self.region.get());
             if self.version >= CLIENT_BUFFERS_SINCE {
                 self.client.event(ConfigSize {
                     self_id: self.id,
@@ -292,6 +304,12 @@ impl JayScreencast {
             self_id: self.id,
             running: self.running.get() as _,
         });
+        if self.version >= CURSOR_ONLY_SINCE {
+            self.client.event(ConfigCursorOnly {
+                self_id: self.id,
+                cursor_only: self.cursor_only.get() as _,
+            });
+        }
         self.client.event(ConfigDone {
             self_id: self.id,
             serial,
@@ -322,9 +340,34 @@ impl JayScreencast {
                 return;
             }
         }
+        let (x_off, y_off) = match output_region(on, self.region.get()) {
+            Some(region) if region.is_empty() => return,
+            Some(region) => (x_off - region.x1(), y_off - region.y1()),
+            None => (x_off, y_off),
+        };
         let mut buffer = self.buffers.borrow_mut();
         for (idx, buffer) in buffer.deref_mut().iter_mut().enumerate() {
             if buffer.free {
+                if self.cursor_only.get() {
+                    let res = self.client.state.perform_cursor_screencopy(
+                        &buffer.fb,
+                        on.global.pos.get(),
+                        x_off,
+                        y_off,
+                        on.global.persistent.scale.get(),
+                    );
+                    if let Err(e) = res {
+                        log::error!("Could not perform cursor screencopy: {}", ErrorFmt(e));
+                        break;
+                    }
+                    self.send_cursor_position(on, idx);
+                    self.client.event(Ready {
+                        self_id: self.id,
+                        idx: idx as _,
+                    });
+                    buffer.free = false;
+                    return;
+                }
                 let res = self.client.state.perform_screencopy(
                     texture,
                     resv,
@@ -362,6 +405,36 @@ impl JayScreencast {
         self.client.event(MissedFrame { self_id: self.id })
     }
 
+    fn send_cursor_position(&self, on: &OutputNode, idx: usize) {
+        let mut event = CursorPosition {
+            self_id: self.id,
+            idx: idx as _,
+            visible: 0,
+            x: 0,
+            y: 0,
+            hotspot_x: 0,
+            hotspot_y: 0,
+        };
+        let state = &self.client.state;
+        if let Some(cursor_user) = state
+            .cursor_user_group_hardware_cursor
+            .get()
+            .and_then(|g| g.active())
+        {
+            if let Some(cursor) = cursor_user.get() {
+                let (x, y) = cursor_user.position_int();
+                let pos = on.global.pos.get();
+                let extents = cursor.extents_at_scale(Scale::default());
+                event.visible = pos.contains(x, y) as _;
+                event.x = x - pos.x1();
+                event.y = y - pos.y1();
+                event.hotspot_x = -extents.x1();
+                event.hotspot_y = -extents.y1();
+            }
+        }
+        self.client.event(event);
+    }
+
     fn detach(&self) {
         self.latch_listener.detach();
         if let Some(target) = self.target.take() {
@@ -414,12 +487,16 @@ impl JayScreencast {
         self.need_realloc_or_reconfigure.set(false);
         let mut buffers = vec![];
         let formats = ctx.formats();
-        let format = match formats.get(&XRGB8888.drm) {
+        let format = match self.cursor_only.get() {
+            true => ARGB8888,
+            false => XRGB8888,
+        };
+        let format = match formats.get(&format.drm) {
             Some(f) => f,
-            _ => return Err(JayScreencastError::XRGB8888),
+            _ => return Err(JayScreencastError::UnsupportedFormat(format.name)),
         };
         if let Some(target) = self.target.get() {
-            let (width, height) = target_size(Some(&target));
+            let (width, height) = target_size(Some(&target), self.region.get());
             let num = 3;
             for _ in 0..num {
                 if width == 0 || height == 0 {
@@ -436,7 +513,9 @@ impl JayScreencast {
                     }
                     true => return Err(JayScreencastError::Modifier),
                     false if format.write_modifiers.is_empty() => {
-                        return Err(JayScreencastError::XRGB8888Writing)
+                        return Err(JayScreencastError::UnsupportedWriteFormat(
+                            format.format.name,
+                        ))
                     }
                     false => format.write_modifiers.keys().copied().collect(),
                 };
@@ -579,6 +658,7 @@ impl JayScreencastRequestHandler for JayScreencast {
         }
 
         let mut need_realloc_or_reconfigure = false;
+        let old_size = target_size(self.target.get().as_ref(), self.region.get());
 
         if let Some(target) = self.pending.target.take() {
             self.detach();
@@ -609,10 +689,18 @@ impl JayScreencastRequestHandler for JayScreencast {
                     }
                 }
             }
-            if target_size(new_target.as_ref()) != target_size(self.target.get().as_ref()) {
+            self.target.set(new_target);
+        }
+        if let Some(cursor_only) = self.pending.cursor_only.take() {
+            if self.cursor_only.replace(cursor_only) != cursor_only {
                 need_realloc_or_reconfigure = true;
             }
-            self.target.set(new_target);
+        }
+        if let Some(region) = self.pending.region.take() {
+            self.region.set(region);
+        }
+        if target_size(self.target.get().as_ref(), self.region.get()) != old_size {
+            need_realloc_or_reconfigure = true;
         }
         if let Some(linear) = self.pending.linear.take() {
             if self.linear.replace(linear) != linear {
@@ -742,6 +830,34 @@ impl JayScreencastRequestHandler for JayScreencast {
         }
         Err(JayScreencastError::NotDmabuf)
     }
+
+    fn set_region(&self, req: SetRegion, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let region = match Rect::new_sized(req.x, req.y, req.width, req.height) {
+            Some(r) if !r.is_empty() => r,
+            _ => return Err(JayScreencastError::InvalidRegion),
+        };
+        if self.destroyed.get() {
+            return Ok(());
+        }
+        self.pending.region.set(Some(Some(region)));
+        Ok(())
+    }
+
+    fn unset_region(&self, _req: UnsetRegion, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        if self.destroyed.get() {
+            return Ok(());
+        }
+        self.pending.region.set(Some(None));
+        Ok(())
+    }
+
+    fn set_cursor_only(&self, req: SetCursorOnly, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        if self.destroyed.get() {
+            return Ok(());
+        }
+        self.pending.cursor_only.set(Some(req.cursor_only != 0));
+        Ok(())
+    }
 }
 
 object_base! {
@@ -767,23 +883,35 @@ pub enum JayScreencastError {
     AllocatorError(#[from] AllocatorError),
     #[error(transparent)]
     GfxError(#[from] GfxError),
-    #[error("Render context does not support XRGB8888 format")]
-    XRGB8888,
-    #[error("Render context does not support XRGB8888 format for rendering")]
-    XRGB8888Writing,
+    #[error("Render context does not support {0} format")]
+    UnsupportedFormat(&'static str),
+    #[error("Render context does not support {0} format for rendering")]
+    UnsupportedWriteFormat(&'static str),
     #[error("Render context supports neither linear or invalid modifier")]
     Modifier,
     #[error("Buffer is not a dmabuf")]
     NotDmabuf,
+    #[error("The capture region is invalid")]
+    InvalidRegion,
 }
 efrom!(JayScreencastError, ClientError);
 
-fn target_size(target: Option<&Target>) -> (i32, i32) {
+fn target_size(target: Option<&Target>, region: Option<Rect>) -> (i32, i32) {
     if let Some(target) = target {
         return match target {
-            Target::Output(o) => o.global.pixel_size(),
+            Target::Output(o) => match output_region(o, region) {
+                Some(region) => region.size(),
+                None => o.global.pixel_size(),
+            },
             Target::Toplevel(t) => t.tl_data().desired_pixel_size(),
         };
     }
     (0, 0)
 }
+
+fn output_region(output: &OutputNode, region: Option<Rect>) -> Option<Rect> {
+    let region = region?;
+    let (width, height) = output.global.pixel_size();
+    let bounds = Rect::new_sized(0, 0, width, height).unwrap_or_default();
+    Some(region.intersect(bounds))
+}
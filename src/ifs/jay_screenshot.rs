@@ -1,22 +1,212 @@
 use {
     crate::{
+        async_engine::SpawnedFuture,
         client::Client,
+        gfx_api::{AsyncShmGfxTextureCallback, GfxError},
+        ifs::jay_compositor::SCREENSHOT_SPLITUP_SINCE,
         leaks::Tracker,
         object::{Object, Version},
+        screenshoter::{
+            take_screenshot, take_screenshot_of_output, take_screenshot_shm, ScreenshooterError,
+            Screenshot, ShmScreenshot,
+        },
+        tree::{OutputNode, PresentationListener},
+        utils::{errorfmt::ErrorFmt, event_listener::EventListener, rc_eq::rc_eq},
         video::dmabuf::{DmaBuf, DmaBufPlane},
         wire::{jay_screenshot::*, JayScreenshotId},
     },
-    std::{convert::Infallible, rc::Rc},
+    isnt::std_1::vec::IsntVecExt,
+    std::{
+        cell::{Cell, RefCell},
+        convert::Infallible,
+        ops::Deref,
+        rc::{Rc, Weak},
+    },
     uapi::OwnedFd,
 };
 
+/// How long to wait for outputs to present before capturing anyway.
+const PRESENTATION_TIMEOUT_MS: u64 = 1000;
+
 pub struct JayScreenshot {
     pub id: JayScreenshotId,
     pub client: Rc<Client>,
     pub tracker: Tracker<Self>,
+    pub version: Version,
+    include_cursor: bool,
+    pending: RefCell<Vec<Rc<PendingPresentation>>>,
+    shm: RefCell<Option<ShmScreenshot>>,
+    timeout: Cell<Option<SpawnedFuture<()>>>,
+}
+
+struct PendingPresentation {
+    screenshot: Rc<JayScreenshot>,
+    output: Weak<OutputNode>,
+    listener: EventListener<dyn PresentationListener>,
 }
 
 impl JayScreenshot {
+    pub fn new(
+        id: JayScreenshotId,
+        client: &Rc<Client>,
+        version: Version,
+        include_cursor: bool,
+    ) -> Self {
+        Self {
+            id,
+            client: client.clone(),
+            tracker: Default::default(),
+            version,
+            include_cursor,
+            pending: Default::default(),
+            shm: Default::default(),
+            timeout: Default::default(),
+        }
+    }
+
+    pub fn capture(&self) {
+        self.send_screenshot(take_screenshot(&self.client.state, self.include_cursor));
+    }
+
+    pub fn capture_output(&self, output: &OutputNode) {
+        self.send_screenshot(take_screenshot_of_output(
+            &self.client.state,
+            output,
+            self.include_cursor,
+        ));
+    }
+
+    fn send_screenshot(&self, res: Result<Screenshot, ScreenshooterError>) {
+        match res {
+            Ok(s) => {
+                let dmabuf = s.bo.dmabuf();
+                if self.version < SCREENSHOT_SPLITUP_SINCE {
+                    if let Some(drm) = &s.drm {
+                        let plane = &dmabuf.planes[0];
+                        self.send_dmabuf(
+                            drm,
+                            &plane.fd,
+                            dmabuf.width,
+                            dmabuf.height,
+                            plane.offset,
+                            plane.stride,
+                            dmabuf.modifier,
+                        );
+                    } else {
+                        self.send_error("Buffer has no associated DRM device");
+                    }
+                } else {
+                    if let Some(drm) = &s.drm {
+                        self.send_drm_dev(drm);
+                    }
+                    for plane in &dmabuf.planes {
+                        self.send_plane(plane);
+                    }
+                    self.send_dmabuf2(dmabuf);
+                }
+            }
+            Err(e) => {
+                let msg = ErrorFmt(e).to_string();
+                self.send_error(&msg);
+            }
+        }
+    }
+
+    /// Captures the screenshot into a memfd and removes the object once the image is available.
+    pub fn capture_shm(self: &Rc<Self>) {
+        match take_screenshot_shm(&self.client.state, self.include_cursor, self.clone()) {
+            Ok(s) => {
+                let completed = s.pending.is_none();
+                *self.shm.borrow_mut() = Some(s);
+                if completed {
+                    self.complete_shm(Ok(()));
+                }
+            }
+            Err(e) => {
+                let msg = ErrorFmt(e).to_string();
+                self.send_error(&msg);
+                let _ = self.client.remove_obj(self.deref());
+            }
+        }
+    }
+
+    fn complete_shm(&self, res: Result<(), GfxError>) {
+        let Some(s) = self.shm.take() else {
+            return;
+        };
+        match res {
+            Ok(()) => self.send_shm(&s),
+            Err(e) => {
+                let msg = ErrorFmt(e).to_string();
+                self.send_error(&msg);
+            }
+        }
+        let _ = self.client.remove_obj(self);
+    }
+
+    /// Captures the screenshot once every output has presented its next frame.
+    ///
+    /// Outputs that are powered off are not waited for. If an output does not present
+    /// within [`PRESENTATION_TIMEOUT_MS`], for example because it has been removed, the
+    /// screenshot is captured anyway.
+    pub fn capture_after_presentation(self: &Rc<Self>) {
+        {
+            let pending = &mut *self.pending.borrow_mut();
+            for output in self.client.state.root.outputs.lock().values() {
+                if !output.power.get() {
+                    continue;
+                }
+                let p = Rc::new_cyclic(|slf: &Weak<PendingPresentation>| PendingPresentation {
+                    screenshot: self.clone(),
+                    output: Rc::downgrade(output),
+                    listener: EventListener::new(slf.clone()),
+                });
+                p.listener.attach(&output.presentation_event);
+                pending.push(p);
+                output.global.connector.damage();
+            }
+        }
+        if self.pending.borrow().is_not_empty() {
+            let slf = self.clone();
+            let state = &self.client.state;
+            let future = state
+                .eng
+                .spawn("screenshot presentation timeout", async move {
+                    let _ = slf
+                        .client
+                        .state
+                        .wheel
+                        .timeout(PRESENTATION_TIMEOUT_MS)
+                        .await;
+                    for p in slf.pending.take() {
+                        p.listener.detach();
+                    }
+                    slf.capture_if_presented();
+                });
+            self.timeout.set(Some(future));
+        }
+        self.capture_if_presented();
+    }
+
+    fn capture_if_presented(&self) {
+        self.pending.borrow_mut().retain(|p| {
+            let alive = p
+                .output
+                .upgrade()
+                .is_some_and(|o| !o.global.destroyed.get());
+            if !alive {
+                p.listener.detach();
+            }
+            alive
+        });
+        if self.pending.borrow().is_not_empty() {
+            return;
+        }
+        self.timeout.take();
+        self.capture();
+        let _ = self.client.remove_obj(self);
+    }
+
     pub fn send_dmabuf(
         &self,
         drm_dev: &Rc<OwnedFd>,
@@ -63,6 +253,17 @@ impl JayScreenshot {
         })
     }
 
+    pub fn send_shm(&self, s: &ShmScreenshot) {
+        self.client.event(Shm {
+            self_id: self.id,
+            fd: s.fd.clone(),
+            width: s.width,
+            height: s.height,
+            stride: s.stride,
+            format: s.format.drm,
+        })
+    }
+
     pub fn send_dmabuf2(&self, buf: &DmaBuf) {
         self.client.event(Dmabuf2 {
             self_id: self.id,
@@ -73,15 +274,47 @@ impl JayScreenshot {
     }
 }
 
+impl PresentationListener for PendingPresentation {
+    fn presented(
+        self: Rc<Self>,
+        _output: &OutputNode,
+        _tv_sec: u64,
+        _tv_nsec: u32,
+        _refresh: u32,
+        _seq: u64,
+        _flags: u32,
+        _vrr: bool,
+    ) {
+        self.listener.detach();
+        let screenshot = &self.screenshot;
+        screenshot.pending.borrow_mut().retain(|p| !rc_eq(p, &self));
+        screenshot.capture_if_presented();
+    }
+}
+
+impl AsyncShmGfxTextureCallback for JayScreenshot {
+    fn completed(self: Rc<Self>, res: Result<(), GfxError>) {
+        self.complete_shm(res);
+    }
+}
+
 impl JayScreenshotRequestHandler for JayScreenshot {
     type Error = Infallible;
 }
 
 object_base! {
     self = JayScreenshot;
-    version = Version(1);
+    version = self.version;
 }
 
-impl Object for JayScreenshot {}
+impl Object for JayScreenshot {
+    fn break_loops(&self) {
+        for p in self.pending.take() {
+            p.listener.detach();
+        }
+        self.shm.take();
+        self.timeout.take();
+    }
+}
 
 simple_add_obj!(JayScreenshot);
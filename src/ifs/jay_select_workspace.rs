@@ -17,6 +17,7 @@ pub struct JaySelectWorkspace {
     pub client: Rc<Client>,
     pub tracker: Tracker<Self>,
     pub destroyed: Cell<bool>,
+    pub version: Version,
 }
 
 pub struct JayWorkspaceSelector {
@@ -52,6 +53,7 @@ impl Drop for JayWorkspaceSelector {
                     client: self.jsw.client.clone(),
                     workspace: CloneCell::new(Some(ws.clone())),
                     tracker: Default::default(),
+                    version: self.jsw.version,
                 });
                 track!(self.jsw.client, jw);
                 self.jsw.client.add_server_obj(&jw);
@@ -86,7 +88,7 @@ impl JaySelectWorkspaceRequestHandler for JaySelectWorkspace {
 
 object_base! {
     self = JaySelectWorkspace;
-    version = Version(1);
+    version = self.version;
 }
 
 impl Object for JaySelectWorkspace {
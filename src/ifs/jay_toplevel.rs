@@ -60,11 +60,18 @@ impl JayToplevelRequestHandler for JayToplevel {
         self.client.remove_obj(self)?;
         Ok(())
     }
+
+    fn set_block_capture(&self, req: SetBlockCapture, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        if !self.destroyed.get() {
+            self.toplevel.tl_data().set_block_capture(req.block != 0);
+        }
+        Ok(())
+    }
 }
 
 object_base! {
     self = JayToplevel;
-    version = Version(1);
+    version = self.version;
 }
 
 impl Object for JayToplevel {
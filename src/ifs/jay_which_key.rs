@@ -0,0 +1,70 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        ifs::wl_seat::WlSeatGlobal,
+        leaks::Tracker,
+        object::{Object, Version},
+        wire::{jay_which_key::*, JayWhichKeyId},
+    },
+    jay_config::keyboard::{mods::Modifiers, syms::KeySym},
+    std::rc::Rc,
+    thiserror::Error,
+};
+
+pub struct JayWhichKey {
+    pub id: JayWhichKeyId,
+    pub client: Rc<Client>,
+    pub seat: Rc<WlSeatGlobal>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+}
+
+impl JayWhichKey {
+    pub fn send_shortcuts(&self, mods: u32, shortcuts: &[(Modifiers, KeySym)]) {
+        for (key_mods, keysym) in shortcuts {
+            self.client.event(Shortcut {
+                self_id: self.id,
+                mods: key_mods.0,
+                keysym: keysym.0,
+            });
+        }
+        self.client.event(Show {
+            self_id: self.id,
+            mods,
+        });
+    }
+
+    pub fn send_hide(&self) {
+        self.client.event(Hide { self_id: self.id });
+    }
+}
+
+impl JayWhichKeyRequestHandler for JayWhichKey {
+    type Error = JayWhichKeyError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.seat.remove_which_key_watcher(self);
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayWhichKey;
+    version = self.version;
+}
+
+impl Object for JayWhichKey {
+    fn break_loops(&self) {
+        self.seat.remove_which_key_watcher(self);
+    }
+}
+
+simple_add_obj!(JayWhichKey);
+
+#[derive(Debug, Error)]
+pub enum JayWhichKeyError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayWhichKeyError, ClientError);
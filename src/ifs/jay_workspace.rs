@@ -3,6 +3,7 @@ use {
         client::{Client, ClientError},
         leaks::Tracker,
         object::{Object, Version},
+        rect::Rect,
         tree::{OutputNode, WorkspaceNode},
         utils::clonecell::CloneCell,
         wire::{jay_workspace::*, JayWorkspaceId},
@@ -16,8 +17,11 @@ pub struct JayWorkspace {
     pub client: Rc<Client>,
     pub workspace: CloneCell<Option<Rc<WorkspaceNode>>>,
     pub tracker: Tracker<Self>,
+    pub version: Version,
 }
 
+pub const RENAME_REQUESTED_SINCE: Version = Version(14);
+
 impl JayWorkspace {
     pub fn send_initial_properties(&self, workspace: &WorkspaceNode) {
         self.send_linear_id(workspace);
@@ -37,7 +41,21 @@ impl JayWorkspace {
     pub fn send_name(&self, ws: &WorkspaceNode) {
         self.client.event(Name {
             self_id: self.id,
-            name: &ws.name,
+            name: &ws.name.get(),
+        });
+    }
+
+    pub fn send_rename_requested(&self, ws: &WorkspaceNode, rect: Rect) {
+        if self.version < RENAME_REQUESTED_SINCE {
+            return;
+        }
+        self.client.event(RenameRequested {
+            self_id: self.id,
+            name: &ws.name.get(),
+            x: rect.x1(),
+            y: rect.y1(),
+            width: rect.width(),
+            height: rect.height(),
         });
     }
 
@@ -82,7 +100,7 @@ impl JayWorkspaceRequestHandler for JayWorkspace {
 
 object_base! {
     self = JayWorkspace;
-    version = Version(1);
+    version = self.version;
 }
 
 impl Object for JayWorkspace {
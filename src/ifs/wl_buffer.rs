@@ -9,6 +9,7 @@ use {
         object::{Object, Version},
         rect::{Rect, Region},
         theme::Color,
+        tree::ScreencopyDamageMark,
         utils::errorfmt::ErrorFmt,
         video::dmabuf::DmaBuf,
         wire::{wl_buffer::*, WlBufferId},
@@ -46,6 +47,8 @@ pub struct WlBuffer {
     width: i32,
     height: i32,
     pub tracker: Tracker<Self>,
+    /// The output contents last copied into this buffer by a screencopy.
+    pub screencopy_damage_mark: Cell<Option<ScreencopyDamageMark>>,
 }
 
 impl WlBuffer {
@@ -83,6 +86,7 @@ impl WlBuffer {
             })),
             shm: false,
             tracker: Default::default(),
+            screencopy_damage_mark: Default::default(),
             color: None,
         }
     }
@@ -124,6 +128,7 @@ impl WlBuffer {
             width,
             height,
             tracker: Default::default(),
+            screencopy_damage_mark: Default::default(),
             color: None,
         })
     }
@@ -149,6 +154,7 @@ impl WlBuffer {
             width: 1,
             height: 1,
             tracker: Default::default(),
+            screencopy_damage_mark: Default::default(),
             color: Some(Color::from_u32_rgba_premultiplied(r, g, b, a)),
         }
     }
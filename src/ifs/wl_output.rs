@@ -5,12 +5,14 @@ use {
         backend,
         client::{Client, ClientError, ClientId},
         format::{Format, XRGB8888},
+        gfx_api::ColorMatrix,
         globals::{Global, GlobalName},
         ifs::{wl_surface::WlSurface, zxdg_output_v1::ZxdgOutputV1},
         leaks::Tracker,
         object::{Object, Version},
         rect::Rect,
         state::{ConnectorData, State},
+        theme::Color,
         tree::{calculate_logical_size, OutputNode, TearingMode, VrrMode},
         utils::{
             cell_ext::CellExt, clonecell::CloneCell, copyhashmap::CopyHashMap,
@@ -98,9 +100,23 @@ pub struct PersistentOutputState {
     pub transform: Cell<Transform>,
     pub scale: Cell<crate::scale::Scale>,
     pub pos: Cell<(i32, i32)>,
+    pub mode: Cell<Option<backend::Mode>>,
     pub vrr_mode: Cell<&'static VrrMode>,
     pub vrr_cursor_hz: Cell<Option<f64>>,
     pub tearing_mode: Cell<&'static TearingMode>,
+    pub vblank_cursor_updates: Cell<bool>,
+    pub use_hardware_cursor: Cell<bool>,
+    pub color_filter: Cell<Option<ColorMatrix>>,
+    pub invert_colors: Cell<bool>,
+    pub workspace_capture: Cell<Option<bool>>,
+    pub workspace_scroll_wrap: Cell<Option<bool>>,
+    pub workspace_scroll_skip_empty: Cell<Option<bool>>,
+    pub flip_margin_ns: Cell<Option<u64>>,
+    pub game_mode: Cell<bool>,
+    pub vrr_min_refresh_hz: Cell<Option<u32>>,
+    pub max_scale: Cell<Option<crate::scale::Scale>>,
+    /// Overrides the theme's background color for this output.
+    pub background: Cell<Option<Color>>,
 }
 
 #[derive(Eq, PartialEq, Hash, Debug)]
@@ -2,11 +2,14 @@ mod event_handling;
 pub mod ext_transient_seat_manager_v1;
 pub mod ext_transient_seat_v1;
 mod gesture_owner;
+mod hot_corners;
 mod kb_owner;
+mod key_sequences;
 mod pointer_owner;
 pub mod tablet;
 pub mod text_input;
 mod touch_owner;
+mod which_key;
 pub mod wl_keyboard;
 pub mod wl_pointer;
 pub mod wl_touch;
@@ -23,7 +26,7 @@ pub mod zwp_virtual_keyboard_v1;
 use {
     crate::{
         async_engine::SpawnedFuture,
-        backend::KeyState,
+        backend::{InputDeviceId, KeyState},
         client::{Client, ClientError, ClientId},
         cursor_user::{CursorUser, CursorUserGroup, CursorUserOwner},
         ei::ei_ifs::ei_seat::EiSeat,
@@ -44,10 +47,13 @@ use {
                 zwp_primary_selection_source_v1::ZwpPrimarySelectionSourceV1,
                 DynDataSource, IpcError, IpcLocation,
             },
+            jay_clipboard::JayClipboard,
             wl_output::WlOutputGlobal,
             wl_seat::{
                 gesture_owner::GestureOwnerHolder,
+                hot_corners::HotCorners,
                 kb_owner::KbOwnerHolder,
+                key_sequences::KeySequences,
                 pointer_owner::PointerOwnerHolder,
                 tablet::TabletSeatData,
                 text_input::{
@@ -55,6 +61,7 @@ use {
                     zwp_input_method_v2::ZwpInputMethodV2, zwp_text_input_v3::ZwpTextInputV3,
                 },
                 touch_owner::TouchOwnerHolder,
+                which_key::WhichKey,
                 wl_keyboard::{WlKeyboard, WlKeyboardError, REPEAT_INFO_SINCE},
                 wl_pointer::WlPointer,
                 wl_touch::WlTouch,
@@ -86,14 +93,15 @@ use {
             rc_eq::rc_eq, smallmap::SmallMap,
         },
         wire::{
-            wl_seat::*, ExtIdleNotificationV1Id, WlDataDeviceId, WlKeyboardId, WlPointerId,
-            WlSeatId, WlTouchId, XdgPopupId, ZwpPrimarySelectionDeviceV1Id, ZwpRelativePointerV1Id,
-            ZwpTextInputV3Id,
+            wl_seat::*, ExtIdleNotificationV1Id, JayClipboardId, WlDataDeviceId, WlKeyboardId,
+            WlPointerId, WlSeatId, WlTouchId, XdgPopupId, ZwpPrimarySelectionDeviceV1Id,
+            ZwpRelativePointerV1Id, ZwpTextInputV3Id,
         },
         wire_ei::EiSeatId,
         xkbcommon::{DynKeyboardState, KeyboardState, KeymapId, XkbKeymap, XkbState},
     },
-    ahash::AHashMap,
+    ahash::{AHashMap, AHashSet},
+    jay_config::input::PointerTrigger,
     smallvec::SmallVec,
     std::{
         cell::{Cell, RefCell},
@@ -101,12 +109,14 @@ use {
         mem,
         ops::{Deref, DerefMut},
         rc::{Rc, Weak},
+        time::Duration,
     },
     thiserror::Error,
     uapi::OwnedFd,
 };
 pub use {
     event_handling::NodeSeatState,
+    key_sequences::SequenceKey,
     pointer_owner::{ToplevelSelector, WorkspaceSelector},
 };
 
@@ -119,11 +129,14 @@ const MISSING_CAPABILITY: u32 = 0;
 
 pub const BTN_LEFT: u32 = 0x110;
 pub const BTN_RIGHT: u32 = 0x111;
+pub const BTN_MIDDLE: u32 = 0x112;
 
 pub const SEAT_NAME_SINCE: Version = Version(2);
 
 pub const PX_PER_SCROLL: f64 = 15.0;
 
+const ZOOM_STEP: f64 = 1.25;
+
 #[derive(Clone)]
 pub struct Dnd {
     pub seat: Rc<WlSeatGlobal>,
@@ -167,10 +180,14 @@ pub struct WlSeatGlobal {
         >,
     >,
     data_control_devices: CopyHashMap<DataControlDeviceId, Rc<dyn DynDataControlDevice>>,
+    clipboard_watchers: CopyHashMap<(ClientId, JayClipboardId), Rc<JayClipboard>>,
     repeat_rate: Cell<(i32, i32)>,
+    effective_repeat_rate: Cell<(i32, i32)>,
+    latest_kb_device: Cell<Option<InputDeviceId>>,
     seat_kb_map: CloneCell<Rc<XkbKeymap>>,
     seat_xkb_state: CloneCell<Rc<RefCell<XkbState>>>,
     latest_kb_state: CloneCell<Rc<dyn DynKeyboardState>>,
+    keyboard_layout: CloneCell<Rc<String>>,
     xkb_states: CopyHashMap<KeymapId, Weak<RefCell<XkbState>>>,
     cursor_user_group: Rc<CursorUserGroup>,
     pointer_cursor: Rc<CursorUser>,
@@ -182,28 +199,56 @@ pub struct WlSeatGlobal {
     primary_selection_serial: Cell<u64>,
     pointer_owner: PointerOwnerHolder,
     kb_owner: KbOwnerHolder,
+    key_sequences: KeySequences,
+    hot_corners: HotCorners,
+    which_key: WhichKey,
     gesture_owner: GestureOwnerHolder,
     touch_owner: TouchOwnerHolder,
     dropped_dnd: RefCell<Option<DroppedDnd>>,
     shortcuts: RefCell<AHashMap<u32, SmallMap<u32, u32, 2>>>,
+    pointer_shortcuts: RefCell<AHashSet<(u32, PointerTrigger)>>,
+    swallowed_buttons: RefCell<AHashSet<u32>>,
     queue_link: RefCell<Option<LinkedNode<Rc<Self>>>>,
     tree_changed_handler: Cell<Option<SpawnedFuture<()>>>,
     changes: NumCell<u32>,
     constraint: CloneCell<Option<Rc<SeatConstraint>>>,
     idle_notifications: CopyHashMap<(ClientId, ExtIdleNotificationV1Id), Rc<ExtIdleNotificationV1>>,
     last_input_usec: Cell<u64>,
+    idle_time_task: Cell<Option<SpawnedFuture<()>>>,
+    idle_time_reported: Cell<bool>,
+    idle_grace_period: Cell<Option<Duration>>,
+    layouts: RefCell<Vec<Rc<XkbKeymap>>>,
+    layout_idx: Cell<usize>,
     text_inputs: RefCell<AHashMap<ClientId, CopyHashMap<ZwpTextInputV3Id, Rc<ZwpTextInputV3>>>>,
     text_input: CloneCell<Option<Rc<ZwpTextInputV3>>>,
     input_method: CloneCell<Option<Rc<ZwpInputMethodV2>>>,
     input_method_grab: CloneCell<Option<Rc<ZwpInputMethodKeyboardGrabV2>>>,
     forward: Cell<bool>,
     focus_follows_mouse: Cell<bool>,
+    focus_follows_mouse_dwell_ms: Cell<u64>,
+    focus_follows_mouse_timeout: Cell<Option<SpawnedFuture<()>>>,
+    warp_pointer_on_focus: Cell<bool>,
+    hide_cursor_while_typing: Cell<bool>,
+    cursor_hidden_by_typing: Cell<bool>,
+    hide_cursor_timeout_ms: Cell<u64>,
+    hide_cursor_timeout: Cell<Option<SpawnedFuture<()>>>,
+    last_cursor_activity_usec: Cell<u64>,
+    cursor_hidden_by_inactivity: Cell<bool>,
+    shortcuts_inhibit_escape: Cell<Option<(u32, u32)>>,
+    zoom_scroll_mods: Cell<u32>,
+    workspace_swipe_fingers: Cell<u32>,
+    workspace_swipe_inverted: Cell<bool>,
+    dnd_threshold: Cell<i32>,
+    middle_click_paste: Cell<bool>,
     swipe_bindings: PerClientBindings<ZwpPointerGestureSwipeV1>,
     pinch_bindings: PerClientBindings<ZwpPointerGesturePinchV1>,
     hold_bindings: PerClientBindings<ZwpPointerGestureHoldV1>,
     tablet: TabletSeatData,
     ei_seats: CopyHashMap<(ClientId, EiSeatId), Rc<EiSeat>>,
     ui_drag_highlight: Cell<Option<Rect>>,
+    pointer_locator_start_usec: Cell<Option<u64>>,
+    pointer_locator_damage: Cell<Rect>,
+    pointer_locator_task: Cell<Option<SpawnedFuture<()>>>,
     keyboard_node_serial: Cell<u64>,
     tray_popups: CopyHashMap<(TrayItemId, XdgPopupId), Rc<dyn DynTrayItem>>,
 }
@@ -218,6 +263,7 @@ impl WlSeatGlobal {
             .state(state.keyboard_state_ids.next())
             .map(|s| Rc::new(RefCell::new(s)))
             .unwrap();
+        let keyboard_layout = Rc::new(seat_xkb_state.borrow().layout_name());
         let xkb_states = CopyHashMap::new();
         xkb_states.set(state.default_keymap.id, Rc::downgrade(&seat_xkb_state));
         let cursor_user_group = CursorUserGroup::create(state);
@@ -241,9 +287,12 @@ impl WlSeatGlobal {
             data_devices: RefCell::new(Default::default()),
             primary_selection_devices: RefCell::new(Default::default()),
             repeat_rate: Cell::new((25, 250)),
+            effective_repeat_rate: Cell::new((25, 250)),
+            latest_kb_device: Default::default(),
             seat_kb_map: CloneCell::new(state.default_keymap.clone()),
             seat_xkb_state: CloneCell::new(seat_xkb_state.clone()),
             latest_kb_state: CloneCell::new(seat_xkb_state.clone()),
+            keyboard_layout: CloneCell::new(keyboard_layout),
             xkb_states,
             cursor_user_group,
             pointer_cursor: cursor_user,
@@ -255,29 +304,58 @@ impl WlSeatGlobal {
             primary_selection_serial: Cell::new(0),
             pointer_owner: Default::default(),
             kb_owner: Default::default(),
+            key_sequences: Default::default(),
+            hot_corners: Default::default(),
+            which_key: Default::default(),
             gesture_owner: Default::default(),
             touch_owner: Default::default(),
             dropped_dnd: RefCell::new(None),
             shortcuts: Default::default(),
+            pointer_shortcuts: Default::default(),
+            swallowed_buttons: Default::default(),
             queue_link: Default::default(),
             tree_changed_handler: Cell::new(None),
             changes: NumCell::new(CHANGE_CURSOR_MOVED | CHANGE_TREE),
             constraint: Default::default(),
             idle_notifications: Default::default(),
             last_input_usec: Cell::new(state.now_usec()),
+            idle_time_task: Default::default(),
+            idle_time_reported: Cell::new(false),
+            idle_grace_period: Default::default(),
+            layouts: Default::default(),
+            layout_idx: Default::default(),
             data_control_devices: Default::default(),
+            clipboard_watchers: Default::default(),
             text_inputs: Default::default(),
             text_input: Default::default(),
             input_method: Default::default(),
             input_method_grab: Default::default(),
             forward: Cell::new(false),
             focus_follows_mouse: Cell::new(true),
+            focus_follows_mouse_dwell_ms: Cell::new(0),
+            focus_follows_mouse_timeout: Default::default(),
+            warp_pointer_on_focus: Cell::new(false),
+            hide_cursor_while_typing: Cell::new(false),
+            cursor_hidden_by_typing: Cell::new(false),
+            hide_cursor_timeout_ms: Cell::new(0),
+            hide_cursor_timeout: Default::default(),
+            last_cursor_activity_usec: Cell::new(0),
+            cursor_hidden_by_inactivity: Cell::new(false),
+            shortcuts_inhibit_escape: Default::default(),
+            zoom_scroll_mods: Default::default(),
+            workspace_swipe_fingers: Cell::new(0),
+            workspace_swipe_inverted: Cell::new(false),
+            dnd_threshold: Cell::new(0),
+            middle_click_paste: Cell::new(true),
             swipe_bindings: Default::default(),
             pinch_bindings: Default::default(),
             hold_bindings: Default::default(),
             tablet: Default::default(),
             ei_seats: Default::default(),
             ui_drag_highlight: Default::default(),
+            pointer_locator_start_usec: Default::default(),
+            pointer_locator_damage: Default::default(),
+            pointer_locator_task: Default::default(),
             tray_popups: Default::default(),
         });
         slf.pointer_cursor.set_owner(slf.clone());
@@ -333,6 +411,77 @@ impl WlSeatGlobal {
         self.ui_drag_highlight.get()
     }
 
+    /// Returns the progress of the pointer locator animation in the range `[0, 1)`.
+    pub fn pointer_locator(&self) -> Option<f64> {
+        let start = self.pointer_locator_start_usec.get()?;
+        let duration = self.state.pointer_locator_duration_usec.get();
+        let elapsed = self.state.now_usec().saturating_sub(start);
+        if elapsed >= duration {
+            return None;
+        }
+        Some(elapsed as f64 / duration as f64)
+    }
+
+    pub fn locate_pointer(self: &Rc<Self>) {
+        self.pointer_locator_start_usec
+            .set(Some(self.state.now_usec()));
+        let slf = self.clone();
+        let future = self.state.eng.spawn("pointer locator", async move {
+            slf.animate_pointer_locator().await;
+        });
+        self.pointer_locator_task.set(Some(future));
+    }
+
+    async fn animate_pointer_locator(&self) {
+        const FRAME_MS: u64 = 16;
+        while self.pointer_locator().is_some() {
+            self.damage_pointer_locator();
+            if let Err(e) = self.state.wheel.timeout(FRAME_MS).await {
+                log::error!("Could not wait for the next frame: {}", ErrorFmt(e));
+                break;
+            }
+        }
+        self.pointer_locator_start_usec.take();
+        self.damage_pointer_locator();
+    }
+
+    pub fn zoom_in(&self) {
+        self.change_zoom(|factor| factor * ZOOM_STEP);
+    }
+
+    pub fn zoom_out(&self) {
+        self.change_zoom(|factor| factor / ZOOM_STEP);
+    }
+
+    pub fn reset_zoom(&self) {
+        self.change_zoom(|_| 1.0);
+    }
+
+    fn change_zoom(&self, f: impl FnOnce(f64) -> f64) {
+        let output = self.pointer_cursor.output();
+        output.set_zoom(self.id, f(output.zoom.get()));
+    }
+
+    pub fn toggle_invert_colors(&self) {
+        self.pointer_cursor.output().toggle_invert_colors();
+    }
+
+    fn damage_pointer_locator(&self) {
+        let mut rect = Rect::default();
+        if self.pointer_locator_start_usec.get().is_some() {
+            let size = self.state.theme.sizes.pointer_locator_size.get();
+            let (x, y) = self.pointer_cursor.position_int();
+            rect = Rect::new_sized_unchecked(x - size, y - size, 2 * size, 2 * size);
+        }
+        let old = self.pointer_locator_damage.replace(rect);
+        if !old.is_empty() {
+            self.state.damage(old);
+        }
+        if !rect.is_empty() {
+            self.state.damage(rect);
+        }
+    }
+
     pub fn add_data_device(&self, device: &Rc<WlDataDevice>) {
         let mut dd = self.data_devices.borrow_mut();
         dd.entry(device.client.id)
@@ -502,8 +651,39 @@ impl WlSeatGlobal {
         self.seat_kb_map.set(keymap.clone());
         let old = self.seat_xkb_state.set(xkb_state.clone());
         if !rc_eq(&old, &xkb_state) {
+            let old_dyn: Rc<dyn DynKeyboardState> = old.clone();
+            if rc_eq(&self.latest_kb_state.get(), &old_dyn) {
+                self.latest_kb_state.set(xkb_state.clone());
+            }
             self.handle_xkb_state_change(&old.borrow(), &xkb_state.borrow());
         }
+        let name = xkb_state.borrow().layout_name();
+        self.set_keyboard_layout(name);
+    }
+
+    /// Sets the keymaps that [`Self::cycle_layout`] switches between and activates the
+    /// first one.
+    pub fn set_layouts(&self, layouts: Vec<Rc<XkbKeymap>>) {
+        let first = layouts.first().cloned();
+        *self.layouts.borrow_mut() = layouts;
+        self.layout_idx.set(0);
+        if let Some(first) = first {
+            self.set_seat_keymap(&first);
+        }
+    }
+
+    /// Activates the next keymap set with [`Self::set_layouts`].
+    pub fn cycle_layout(&self) {
+        let keymap = {
+            let layouts = self.layouts.borrow();
+            if layouts.is_empty() {
+                return;
+            }
+            let idx = (self.layout_idx.get() + 1) % layouts.len();
+            self.layout_idx.set(idx);
+            layouts[idx].clone()
+        };
+        self.set_seat_keymap(&keymap);
     }
 
     fn handle_xkb_state_change(&self, old: &XkbState, new: &XkbState) {
@@ -632,6 +812,19 @@ impl WlSeatGlobal {
         self.set_tl_floating(tl, floating);
     }
 
+    pub fn get_sticky(&self) -> Option<bool> {
+        match self.keyboard_node.get().node_toplevel() {
+            Some(tl) => Some(tl.tl_data().is_sticky.get()),
+            _ => None,
+        }
+    }
+
+    pub fn set_sticky(&self, sticky: bool) {
+        if let Some(tl) = self.keyboard_node.get().node_toplevel() {
+            tl.tl_data().is_sticky.set(sticky);
+        }
+    }
+
     pub fn set_tl_floating(self: &Rc<Self>, tl: Rc<dyn ToplevelNode>, floating: bool) {
         let data = tl.tl_data();
         if data.is_fullscreen.get() {
@@ -660,6 +853,27 @@ impl WlSeatGlobal {
 
     pub fn set_rate(&self, rate: i32, delay: i32) {
         self.repeat_rate.set((rate, delay));
+        self.update_repeat_info();
+    }
+
+    fn set_latest_kb_device(&self, dev: &DeviceHandlerData) {
+        self.latest_kb_device.set(Some(dev.device.id()));
+        self.update_repeat_info();
+    }
+
+    pub fn update_repeat_info(&self) {
+        let mut effective = self.repeat_rate.get();
+        if let Some(id) = self.latest_kb_device.get() {
+            if let Some(dev) = self.state.input_device_handlers.borrow().get(&id) {
+                if let Some(rate) = dev.data.repeat_rate.get() {
+                    effective = rate;
+                }
+            }
+        }
+        if self.effective_repeat_rate.replace(effective) == effective {
+            return;
+        }
+        let (rate, delay) = effective;
         let bindings = self.bindings.borrow_mut();
         for client in bindings.values() {
             for seat in client.values() {
@@ -708,9 +922,15 @@ impl WlSeatGlobal {
         }
     }
 
+    fn selection_field(&self, location: IpcLocation) -> &CloneCell<Option<Rc<dyn DynDataSource>>> {
+        match location {
+            IpcLocation::Clipboard => &self.selection,
+            IpcLocation::PrimarySelection => &self.primary_selection,
+        }
+    }
+
     fn set_selection_<T, X, S>(
         self: &Rc<Self>,
-        field: &CloneCell<Option<Rc<dyn DynDataSource>>>,
         src: Option<Rc<S>>,
         location: IpcLocation,
     ) -> Result<(), WlSeatError>
@@ -719,6 +939,7 @@ impl WlSeatGlobal {
         X: ipc::IpcVtable<Device = XIpcDevice>,
         S: DynDataSource,
     {
+        let field = self.selection_field(location);
         if let (Some(new), Some(old)) = (&src, &field.get()) {
             if new.source_data().id == old.source_data().id {
                 return Ok(());
@@ -727,19 +948,69 @@ impl WlSeatGlobal {
         if let Some(new) = &src {
             ipc::attach_seat(&**new, self, ipc::Role::Selection)?;
         }
+        let shared = self.state.shared_clipboard.get();
         let src_dyn = src.clone().map(|s| s as Rc<dyn DynDataSource>);
-        if let Some(old) = field.set(src_dyn) {
-            old.detach_seat(self);
+        if let Some(old) = field.set(src_dyn.clone()) {
+            // If the clipboard is no longer shared, sources that were shared from other
+            // seats are still in use by those seats.
+            let owner = old.source_data().seat.get();
+            let foreign = owner.is_some_and(|s| s.id != self.id);
+            if shared || !foreign {
+                old.detach_seat(self);
+            }
+        }
+        self.publish_selection::<T, X>(src_dyn.clone(), location);
+        if shared {
+            let seats: Vec<_> = self.state.globals.seats.lock().values().cloned().collect();
+            for seat in seats {
+                if seat.id != self.id {
+                    seat.share_selection::<T, X>(src_dyn.clone(), location);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn share_selection<T, X>(
+        self: &Rc<Self>,
+        src: Option<Rc<dyn DynDataSource>>,
+        location: IpcLocation,
+    ) where
+        T: ipc::IterableIpcVtable,
+        X: ipc::IpcVtable<Device = XIpcDevice>,
+    {
+        let field = self.selection_field(location);
+        if let (Some(new), Some(old)) = (&src, &field.get()) {
+            if new.source_data().id == old.source_data().id {
+                return;
+            }
         }
+        if let Some(old) = field.set(src.clone()) {
+            // Sources shared from other seats are detached by the seat they are attached
+            // to. Only sources that were set on this seat itself have to be detached here.
+            let owner = old.source_data().seat.get();
+            if owner.map(|s| s.id) == Some(self.id) {
+                old.detach_seat(self);
+            }
+        }
+        self.publish_selection::<T, X>(src, location);
+    }
+
+    fn publish_selection<T, X>(&self, src: Option<Rc<dyn DynDataSource>>, location: IpcLocation)
+    where
+        T: ipc::IterableIpcVtable,
+        X: ipc::IpcVtable<Device = XIpcDevice>,
+    {
         if let Some(client) = self.keyboard_node.get().node_client() {
-            self.offer_selection_to_client::<T, X>(src.clone().map(|v| v as Rc<_>), &client);
+            self.offer_selection_to_client::<T, X>(src.clone(), &client);
             // client.flush();
         }
-        let dyn_source = src.map(|s| s as Rc<dyn DynDataSource>);
         for dd in self.data_control_devices.lock().values() {
-            dd.clone().handle_new_source(location, dyn_source.clone());
+            dd.clone().handle_new_source(location, src.clone());
+        }
+        for watcher in self.clipboard_watchers.lock().values() {
+            watcher.selection_changed(location, src.as_ref());
         }
-        Ok(())
     }
 
     fn offer_selection_to_client<T, X>(
@@ -822,11 +1093,7 @@ impl WlSeatGlobal {
         self: &Rc<Self>,
         selection: Option<Rc<S>>,
     ) -> Result<(), WlSeatError> {
-        self.set_selection_::<ClipboardIpc, XClipboardIpc, _>(
-            &self.selection,
-            selection,
-            IpcLocation::Clipboard,
-        )
+        self.set_selection_::<ClipboardIpc, XClipboardIpc, _>(selection, IpcLocation::Clipboard)
     }
 
     pub fn get_selection(&self) -> Option<Rc<dyn DynDataSource>> {
@@ -870,7 +1137,6 @@ impl WlSeatGlobal {
         selection: Option<Rc<S>>,
     ) -> Result<(), WlSeatError> {
         self.set_selection_::<PrimarySelectionIpc, XPrimarySelectionIpc, _>(
-            &self.primary_selection,
             selection,
             IpcLocation::PrimarySelection,
         )
@@ -910,11 +1176,18 @@ impl WlSeatGlobal {
         self.data_devices.borrow_mut().clear();
         self.primary_selection_devices.borrow_mut().clear();
         self.data_control_devices.clear();
+        self.clipboard_watchers.clear();
         self.cursor_user_group.detach();
         self.selection.set(None);
         self.primary_selection.set(None);
         self.pointer_owner.clear();
         self.kb_owner.clear();
+        self.key_sequences.clear();
+        self.hot_corners.clear();
+        self.which_key.clear();
+        self.focus_follows_mouse_timeout.take();
+        self.hide_cursor_timeout.take();
+        self.idle_time_task.take();
         self.touch_owner.clear();
         *self.dropped_dnd.borrow_mut() = None;
         self.queue_link.take();
@@ -930,6 +1203,7 @@ impl WlSeatGlobal {
         self.cursor_user_group.detach();
         self.tablet_clear();
         self.ei_seats.clear();
+        self.pointer_locator_task.take();
     }
 
     pub fn id(&self) -> SeatId {
@@ -940,6 +1214,10 @@ impl WlSeatGlobal {
         &self.seat_name
     }
 
+    pub fn keyboard_node(&self) -> Rc<dyn Node> {
+        self.keyboard_node.get()
+    }
+
     fn bind_(
         self: Rc<Self>,
         id: WlSeatId,
@@ -983,10 +1261,71 @@ impl WlSeatGlobal {
             .remove(&(notification.client.id, notification.id));
     }
 
+    pub fn add_clipboard_watcher(&self, watcher: &Rc<JayClipboard>) {
+        self.clipboard_watchers
+            .set((watcher.client.id, watcher.id), watcher.clone());
+    }
+
+    pub fn remove_clipboard_watcher(&self, watcher: &JayClipboard) {
+        self.clipboard_watchers
+            .remove(&(watcher.client.id, watcher.id));
+    }
+
     pub fn last_input(&self) -> u64 {
         self.last_input_usec.get()
     }
 
+    pub fn idle_time(&self) -> Duration {
+        let elapsed = self
+            .state
+            .now_usec()
+            .saturating_sub(self.last_input_usec.get());
+        Duration::from_micros(elapsed)
+    }
+
+    pub fn set_idle_time_interval(self: &Rc<Self>, interval: Duration) {
+        self.idle_time_reported.set(false);
+        if interval.is_zero() {
+            self.idle_time_task.take();
+            return;
+        }
+        let ms = interval.as_millis().clamp(1, u64::MAX as u128) as u64;
+        let slf = self.clone();
+        let future = self.state.eng.spawn("idle time", async move {
+            loop {
+                if let Err(e) = slf.state.wheel.timeout(ms).await {
+                    log::error!("Could not wait for the idle time interval: {}", ErrorFmt(e));
+                    return;
+                }
+                if let Some(config) = slf.state.config.get() {
+                    config.idle_time(slf.id, slf.idle_time());
+                }
+                slf.idle_time_reported.set(true);
+            }
+        });
+        self.idle_time_task.set(Some(future));
+    }
+
+    /// Overrides the idle grace period of this seat.
+    pub fn set_idle_grace_period(&self, grace_period: Option<Duration>) {
+        self.idle_grace_period.set(grace_period);
+        self.state.idle.grace_period_changed();
+    }
+
+    pub fn idle_grace_period(&self) -> Duration {
+        self.idle_grace_period
+            .get()
+            .unwrap_or_else(|| self.state.idle.grace_period.get())
+    }
+
+    fn reset_idle_time(&self) {
+        if self.idle_time_reported.replace(false) {
+            if let Some(config) = self.state.config.get() {
+                config.idle_time(self.id, Duration::ZERO);
+            }
+        }
+    }
+
     pub fn set_visible(&self, visible: bool) {
         self.cursor_user_group.set_visible(visible);
         if let Some(icon) = self.dnd_icon() {
@@ -1018,6 +1357,46 @@ impl WlSeatGlobal {
 
     pub fn set_focus_follows_mouse(&self, focus_follows_mouse: bool) {
         self.focus_follows_mouse.set(focus_follows_mouse);
+        if !focus_follows_mouse {
+            self.focus_follows_mouse_timeout.take();
+        }
+    }
+
+    pub fn set_focus_follows_mouse_dwell(&self, ms: u64) {
+        self.focus_follows_mouse_dwell_ms.set(ms);
+    }
+
+    pub fn set_warp_pointer_on_focus(&self, warp: bool) {
+        self.warp_pointer_on_focus.set(warp);
+    }
+
+    pub fn set_hide_cursor_while_typing(&self, hide: bool) {
+        self.hide_cursor_while_typing.set(hide);
+        if !hide {
+            self.show_cursor_after_typing();
+        }
+    }
+
+    #[cfg_attr(not(feature = "it"), expect(dead_code))]
+    pub fn cursor_hidden_by_typing(&self) -> bool {
+        self.cursor_hidden_by_typing.get()
+    }
+
+    pub fn set_hide_cursor_timeout(self: &Rc<Self>, ms: u64) {
+        self.hide_cursor_timeout_ms.set(ms);
+        self.hide_cursor_timeout.take();
+        if self.cursor_hidden_by_inactivity.replace(false) {
+            self.update_cursor_hidden();
+        }
+        if ms > 0 {
+            self.last_cursor_activity_usec.set(self.state.now_usec());
+            self.start_hide_cursor_timeout();
+        }
+    }
+
+    #[cfg_attr(not(feature = "it"), expect(dead_code))]
+    pub fn cursor_hidden_by_inactivity(&self) -> bool {
+        self.cursor_hidden_by_inactivity.get()
     }
 
     pub fn set_window_management_enabled(self: &Rc<Self>, enabled: bool) {
@@ -1043,6 +1422,21 @@ impl WlSeatGlobal {
         self.latest_kb_state.get()
     }
 
+    /// Returns the name of the most recently activated keyboard layout.
+    pub fn keyboard_layout(&self) -> Rc<String> {
+        self.keyboard_layout.get()
+    }
+
+    fn set_keyboard_layout(&self, name: String) {
+        if *self.keyboard_layout.get() == name {
+            return;
+        }
+        self.keyboard_layout.set(Rc::new(name));
+        if let Some(config) = self.state.config.get() {
+            config.keyboard_layout_changed(self.id, &self.keyboard_layout.get());
+        }
+    }
+
     pub fn output_extents_changed(&self) {
         self.for_each_ei_seat(|ei_seat| {
             ei_seat.regions_changed();
@@ -1182,7 +1576,7 @@ impl WlSeatRequestHandler for WlSeat {
             }
         }
         if self.version >= REPEAT_INFO_SINCE {
-            let (rate, delay) = self.global.repeat_rate.get();
+            let (rate, delay) = self.global.effective_repeat_rate.get();
             p.send_repeat_info(rate, delay);
         }
         Ok(())
@@ -1306,6 +1700,13 @@ impl DeviceHandlerData {
         self.update_xkb_state();
     }
 
+    pub fn set_repeat_rate(&self, rate: Option<(i32, i32)>) {
+        self.repeat_rate.set(rate);
+        if let Some(seat) = self.seat.get() {
+            seat.update_repeat_info();
+        }
+    }
+
     fn get_effective_xkb_state(&self, seat: &WlSeatGlobal) -> Rc<RefCell<XkbState>> {
         match self.xkb_state.get() {
             Some(s) => s,
@@ -9,14 +9,16 @@ use {
         fixed::Fixed,
         ifs::{
             ipc::{
-                offer_source_to_regular_client,
+                offer_source_to_regular_client, offer_source_to_x,
                 wl_data_device::{ClipboardIpc, WlDataDevice},
-                x_data_device::{XClipboardIpc, XPrimarySelectionIpc},
+                x_data_device::{XClipboardIpc, XDndIpc, XPrimarySelectionIpc},
+                x_data_source::XDataSource,
                 zwp_primary_selection_device_v1::{
                     PrimarySelectionIpc, ZwpPrimarySelectionDeviceV1,
                 },
             },
             wl_seat::{
+                key_sequences::KeySequenceResult,
                 tablet::{TabletPad, TabletPadId, TabletTool, TabletToolId},
                 text_input::TextDisconnectReason,
                 wl_keyboard::{self, WlKeyboard},
@@ -29,21 +31,29 @@ use {
                 wl_touch::WlTouch,
                 zwp_pointer_constraints_v1::{ConstraintType, SeatConstraintStatus},
                 zwp_relative_pointer_v1::ZwpRelativePointerV1,
-                Dnd, SeatId, WlSeat, WlSeatGlobal, CHANGE_CURSOR_MOVED, CHANGE_TREE,
+                Dnd, SeatId, WlSeat, WlSeatGlobal, BTN_MIDDLE, CHANGE_CURSOR_MOVED, CHANGE_TREE,
+                ZOOM_STEP,
+            },
+            wl_surface::{
+                xdg_surface::xdg_popup::XdgPopup,
+                zwp_keyboard_shortcuts_inhibitor_v1::ZwpKeyboardShortcutsInhibitorV1, WlSurface,
             },
-            wl_surface::{xdg_surface::xdg_popup::XdgPopup, WlSurface},
         },
         object::Version,
         rect::Rect,
         state::DeviceHandlerData,
         tree::{Direction, Node, ToplevelNode},
-        utils::{bitflags::BitflagsExt, hash_map_ext::HashMapExt, smallmap::SmallMap},
+        utils::{
+            bitflags::BitflagsExt, errorfmt::ErrorFmt, hash_map_ext::HashMapExt,
+            smallmap::SmallMap, transform_ext::TransformExt,
+        },
         wire::WlDataOfferId,
         xkbcommon::{KeyboardState, XkbState, XKB_KEY_DOWN, XKB_KEY_UP},
+        xwayland::XWaylandEvent,
     },
     isnt::std_1::primitive::{IsntSlice2Ext, IsntSliceExt},
     jay_config::{
-        input::SwitchEvent,
+        input::{PointerTrigger, SwitchEvent},
         keyboard::{
             mods::{Modifiers, CAPS, NUM, RELEASE},
             syms::{KeySym, SYM_Escape},
@@ -262,6 +272,7 @@ impl WlSeatGlobal {
             | InputEvent::TabletPadStrip { time_usec, .. }
             | InputEvent::TouchFrame { time_usec, .. } => {
                 self.last_input_usec.set(time_usec);
+                self.reset_idle_time();
                 if self.idle_notifications.is_not_empty() {
                     for notification in self.idle_notifications.lock().drain_values() {
                         notification.resume.trigger();
@@ -319,7 +330,10 @@ impl WlSeatGlobal {
                 time_usec,
                 key,
                 state,
-            } => self.key_event(time_usec, key, state, || dev.get_effective_xkb_state(self)),
+            } => {
+                self.set_latest_kb_device(dev);
+                self.key_event(time_usec, key, state, || dev.get_effective_xkb_state(self))
+            }
             InputEvent::ConnectorPosition {
                 time_usec,
                 connector,
@@ -495,6 +509,17 @@ impl WlSeatGlobal {
             Some(o) => o,
             _ => return,
         };
+        let transform = output.global.persistent.transform.get();
+        let mode = output.global.mode.get();
+        let (width, height) =
+            transform.maybe_swap((Fixed::from_int(mode.width), Fixed::from_int(mode.height)));
+        (x, y) = transform.inverse().apply_point(width, height, (x, y));
+        let scale = output.global.persistent.scale.get();
+        if scale != 1 {
+            let scale = scale.to_f64();
+            x = Fixed::from_f64(x.to_f64() / scale);
+            y = Fixed::from_f64(y.to_f64() / scale);
+        }
         let pos = output.global.pos.get();
         x += Fixed::from_int(pos.x1());
         y += Fixed::from_int(pos.y1());
@@ -502,6 +527,8 @@ impl WlSeatGlobal {
     }
 
     pub fn motion_event_abs(self: &Rc<Self>, time_usec: u64, x: Fixed, y: Fixed) {
+        self.show_cursor_after_typing();
+        self.cursor_activity();
         self.for_each_ei_seat(|ei_seat| {
             ei_seat.handle_motion_abs(time_usec, x, y);
         });
@@ -525,6 +552,8 @@ impl WlSeatGlobal {
         dx_unaccelerated: Fixed,
         dy_unaccelerated: Fixed,
     ) {
+        self.show_cursor_after_typing();
+        self.cursor_activity();
         self.for_each_ei_seat(|ei_seat| {
             ei_seat.handle_motion(time_usec, dx, dy);
         });
@@ -573,12 +602,26 @@ impl WlSeatGlobal {
     }
 
     pub fn button_event(self: &Rc<Self>, time_usec: u64, button: u32, state: KeyState) {
+        self.cursor_activity();
         self.for_each_ei_seat(|ei_seat| {
             ei_seat.handle_button(time_usec, button, state);
         });
         self.state.for_each_seat_tester(|t| {
             t.send_button(self.id, time_usec, button, state);
         });
+        match state {
+            KeyState::Pressed => {
+                if self.invoke_pointer_shortcut(PointerTrigger::Button(button)) {
+                    self.swallowed_buttons.borrow_mut().insert(button);
+                    return;
+                }
+            }
+            KeyState::Released => {
+                if self.swallowed_buttons.borrow_mut().remove(&button) {
+                    return;
+                }
+            }
+        }
         self.pointer_owner.button(self, time_usec, button, state);
     }
 
@@ -599,6 +642,7 @@ impl WlSeatGlobal {
     }
 
     pub fn axis_frame(self: &Rc<Self>, px_per_scroll_wheel: f64, time_usec: u64) {
+        self.cursor_activity();
         self.pointer_owner
             .frame(px_per_scroll_wheel, self, time_usec);
     }
@@ -787,6 +831,75 @@ impl WlSeatGlobal {
         self.key_event(time_usec, key, key_state, || self.seat_xkb_state.get());
     }
 
+    fn hide_cursor_for_typing(&self) {
+        if self.cursor_hidden_by_typing.get() {
+            return;
+        }
+        // Hiding the cursor would interrupt drag-and-drop and other grabs.
+        if !self.pointer_owner.is_default() {
+            return;
+        }
+        self.cursor_hidden_by_typing.set(true);
+        self.update_cursor_hidden();
+    }
+
+    pub(super) fn show_cursor_after_typing(&self) {
+        if self.cursor_hidden_by_typing.replace(false) {
+            self.update_cursor_hidden();
+        }
+    }
+
+    pub(super) fn update_cursor_hidden(&self) {
+        let hidden = self.cursor_hidden_by_typing.get() || self.cursor_hidden_by_inactivity.get();
+        self.cursor_user_group.set_hidden(hidden);
+    }
+
+    /// Shows the cursor if it was hidden due to inactivity and restarts the inactivity
+    /// timeout.
+    fn cursor_activity(self: &Rc<Self>) {
+        if self.hide_cursor_timeout_ms.get() == 0 {
+            return;
+        }
+        self.last_cursor_activity_usec.set(self.state.now_usec());
+        if self.cursor_hidden_by_inactivity.replace(false) {
+            self.update_cursor_hidden();
+            self.start_hide_cursor_timeout();
+        }
+    }
+
+    pub(super) fn start_hide_cursor_timeout(self: &Rc<Self>) {
+        let slf = self.clone();
+        let future = self.state.eng.spawn("hide cursor timeout", async move {
+            loop {
+                let timeout_usec = slf.hide_cursor_timeout_ms.get().saturating_mul(1000);
+                let elapsed = slf
+                    .state
+                    .now_usec()
+                    .saturating_sub(slf.last_cursor_activity_usec.get());
+                if elapsed < timeout_usec {
+                    let ms = (timeout_usec - elapsed).div_ceil(1000);
+                    if let Err(e) = slf.state.wheel.timeout(ms).await {
+                        log::error!(
+                            "Could not wait for the hide-cursor timeout: {}",
+                            ErrorFmt(e)
+                        );
+                        return;
+                    }
+                    continue;
+                }
+                // Hiding the cursor would interrupt drag-and-drop and other grabs.
+                if !slf.pointer_owner.is_default() {
+                    slf.last_cursor_activity_usec.set(slf.state.now_usec());
+                    continue;
+                }
+                slf.cursor_hidden_by_inactivity.set(true);
+                slf.update_cursor_hidden();
+                return;
+            }
+        });
+        self.hide_cursor_timeout.set(Some(future));
+    }
+
     pub(super) fn key_event<F>(
         self: &Rc<Self>,
         time_usec: u64,
@@ -814,8 +927,18 @@ impl WlSeatGlobal {
                 }
             }
         };
+        if state == wl_keyboard::PRESSED {
+            if self.hide_cursor_while_typing.get() {
+                self.hide_cursor_for_typing();
+            } else {
+                self.cursor_activity();
+            }
+        }
         let mut shortcuts = SmallVec::<[_; 1]>::new();
         let new_mods;
+        let mut new_layout = None;
+        let mut sequence = KeySequenceResult::Ignored;
+        let mut escaped = false;
         {
             let mut mods = xkb_state.mods().mods_effective & !(CAPS.0 | NUM.0);
             if state == wl_keyboard::RELEASED {
@@ -824,11 +947,22 @@ impl WlSeatGlobal {
             let scs = &*self.shortcuts.borrow();
             let keysyms = xkb_state.unmodified_keysyms(key);
             let mut revert_pointer_to_default = false;
+            let mut inhibited = false;
+            if !self.state.lock.locked.get() {
+                if let Some(inhibitor) = self.shortcuts_inhibitor() {
+                    let escape = self.shortcuts_inhibit_escape.get();
+                    if escape.is_some_and(|(m, s)| mods == m && keysyms.contains(&s)) {
+                        inhibitor.toggle();
+                        escaped = true;
+                    }
+                    inhibited = inhibitor.active.get();
+                }
+            }
             for &sym in keysyms {
                 if sym == SYM_Escape.0 && mods == 0 {
                     revert_pointer_to_default = true;
                 }
-                if !self.state.lock.locked.get() {
+                if !self.state.lock.locked.get() && !inhibited && !escaped {
                     if let Some(key_mods) = scs.get(&sym) {
                         for (key_mods, mask) in key_mods {
                             if mods & mask == key_mods {
@@ -842,19 +976,52 @@ impl WlSeatGlobal {
                     }
                 }
             }
+            if state == wl_keyboard::PRESSED
+                && !self.state.lock.locked.get()
+                && !inhibited
+                && !escaped
+            {
+                sequence = self.handle_key_sequence(mods, keysyms);
+                if !matches!(sequence, KeySequenceResult::Ignored) {
+                    shortcuts.clear();
+                }
+            }
             if revert_pointer_to_default {
                 drop(xkb_state);
                 self.pointer_owner.revert_to_default(self);
                 xkb_state = xkb_state_rc.borrow_mut();
             }
+            let old_group = xkb_state.kb_state.mods.group;
             new_mods = xkb_state.update(key, xkb_dir);
+            if new_mods && xkb_state.kb_state.mods.group != old_group {
+                new_layout = Some(xkb_state.layout_name());
+            }
         }
+        let which_key_mods = new_mods.then(|| xkb_state.mods().mods_effective & !(CAPS.0 | NUM.0));
+        self.update_which_key(which_key_mods);
         self.state.for_each_seat_tester(|t| {
             t.send_key(self.id, time_usec, key, key_state);
         });
         let node = self.keyboard_node.get();
         let input_method_grab = self.input_method_grab.get();
-        let mut forward = true;
+        let mut forward = !escaped;
+        match sequence {
+            KeySequenceResult::Ignored => {}
+            KeySequenceResult::Consumed => forward = false,
+            KeySequenceResult::Completed(keys) => {
+                forward = false;
+                if let Some(config) = self.state.config.get() {
+                    let id = xkb_state.kb_state.id;
+                    drop(xkb_state);
+                    config.invoke_key_sequence(self.id(), &keys);
+                    xkb_state_rc = get_state();
+                    xkb_state = xkb_state_rc.borrow_mut();
+                    if id != xkb_state.kb_state.id {
+                        return;
+                    }
+                }
+            }
+        }
         if shortcuts.is_not_empty() {
             self.forward.set(state == wl_keyboard::RELEASED);
             if let Some(config) = self.state.config.get() {
@@ -902,6 +1069,9 @@ impl WlSeatGlobal {
         }
         drop(xkb_state);
         self.latest_kb_state.set(xkb_state_rc);
+        if let Some(name) = new_layout {
+            self.set_keyboard_layout(name);
+        }
     }
 
     pub(super) fn for_each_ei_seat(&self, mut f: impl FnMut(&Rc<EiSeat>)) {
@@ -919,6 +1089,9 @@ impl WlSeatGlobal {
     }
 
     pub fn focus_toplevel(self: &Rc<Self>, n: Rc<dyn ToplevelNode>) {
+        if self.warp_pointer_on_focus.get() {
+            self.warp_to_node(&*n);
+        }
         let node = match n.tl_focus_child(self.id) {
             Some(n) => n,
             _ => n.tl_into_node(),
@@ -926,6 +1099,42 @@ impl WlSeatGlobal {
         self.focus_node(node);
     }
 
+    /// Moves the pointer to the center of the node unless it is already inside of it.
+    pub fn warp_to_node<N: Node + ?Sized>(self: &Rc<Self>, node: &N) {
+        if !self.pointer_owner.is_default() || self.constraint.is_some() {
+            return;
+        }
+        let rect = node.node_absolute_position();
+        if rect.is_empty() {
+            return;
+        }
+        let (x, y) = self.pointer_cursor.position_int();
+        if rect.contains(x, y) {
+            return;
+        }
+        let (x, y) = rect.center();
+        self.set_pointer_cursor_position(Fixed::from_int(x), Fixed::from_int(y));
+        self.changes.or_assign(CHANGE_TREE);
+        self.apply_changes();
+        self.update_hot_corner();
+    }
+
+    /// Moves the pointer to a surface-local position if that position is inside of the surface.
+    pub fn warp_to_surface_position(self: &Rc<Self>, surface: &WlSurface, x: Fixed, y: Fixed) {
+        if !self.pointer_owner.is_default() || self.constraint.is_some() {
+            return;
+        }
+        let rect = surface.buffer_abs_pos.get();
+        let (x_int, y_int) = (x.round_down(), y.round_down());
+        if x_int < 0 || y_int < 0 || x_int >= rect.width() || y_int >= rect.height() {
+            return;
+        }
+        self.set_pointer_cursor_position(x + rect.x1(), y + rect.y1());
+        self.changes.or_assign(CHANGE_TREE);
+        self.apply_changes();
+        self.update_hot_corner();
+    }
+
     fn ungrab_kb(self: &Rc<Self>) {
         self.kb_owner.ungrab(self);
     }
@@ -1086,10 +1295,27 @@ impl WlSeatGlobal {
         self.pos_time_usec.set(time_usec);
         self.changes.or_assign(CHANGE_CURSOR_MOVED);
         self.apply_changes();
+        self.update_hot_corner();
+    }
+
+    /// Returns the registered shortcuts ordered by keysym and modifiers.
+    pub fn list_shortcuts(&self) -> Vec<(Modifiers, KeySym)> {
+        let mut res: Vec<_> = self
+            .shortcuts
+            .borrow()
+            .iter()
+            .flat_map(|(&keysym, mods)| mods.iter().map(move |(mods, _)| (mods, keysym)))
+            .collect();
+        res.sort_unstable_by_key(|&(mods, keysym)| (keysym, mods));
+        res.into_iter()
+            .map(|(mods, keysym)| (Modifiers(mods), KeySym(keysym)))
+            .collect()
     }
 
     pub fn clear_shortcuts(&self) {
         self.shortcuts.borrow_mut().clear();
+        self.pointer_shortcuts.borrow_mut().clear();
+        self.key_sequences.clear();
     }
 
     pub fn add_shortcut(&self, mod_mask: Modifiers, mods: Modifiers, keysym: KeySym) {
@@ -1109,6 +1335,141 @@ impl WlSeatGlobal {
         }
     }
 
+    pub fn add_pointer_shortcut(&self, mods: Modifiers, trigger: PointerTrigger) {
+        self.pointer_shortcuts
+            .borrow_mut()
+            .insert((mods.0 & !RELEASE.0, trigger));
+    }
+
+    pub fn remove_pointer_shortcut(&self, mods: Modifiers, trigger: PointerTrigger) {
+        self.pointer_shortcuts
+            .borrow_mut()
+            .remove(&(mods.0 & !RELEASE.0, trigger));
+    }
+
+    /// Invokes the pointer shortcut matching the trigger and the current modifiers.
+    ///
+    /// Returns whether a shortcut was invoked.
+    fn invoke_pointer_shortcut(&self, trigger: PointerTrigger) -> bool {
+        if self.state.lock.locked.get() {
+            return false;
+        }
+        if let Some(inhibitor) = self.shortcuts_inhibitor() {
+            if inhibitor.active.get() {
+                return false;
+            }
+        }
+        let mods = self.latest_kb_state.get().borrow().mods.mods_effective & !(CAPS.0 | NUM.0);
+        if !self.pointer_shortcuts.borrow().contains(&(mods, trigger)) {
+            return false;
+        }
+        if let Some(config) = self.state.config.get() {
+            config.invoke_pointer_shortcut(self.id, Modifiers(mods), trigger);
+        }
+        true
+    }
+
+    /// Invokes the pointer shortcuts bound to the scroll directions of the frame.
+    ///
+    /// Returns whether the scroll event was consumed.
+    pub(super) fn scroll_shortcut(&self, pending: &PendingScroll) -> bool {
+        let direction = |axis: u32| {
+            let axis = axis as usize;
+            match pending.v120[axis].get() {
+                Some(v120) => v120.signum(),
+                _ => match pending.px[axis].get() {
+                    Some(px) => px.0.signum(),
+                    _ => 0,
+                },
+            }
+        };
+        let mut consumed = false;
+        let mut invoke = |direction: i32, negative, positive| {
+            let trigger = match direction {
+                -1 => negative,
+                1 => positive,
+                _ => return,
+            };
+            consumed |= self.invoke_pointer_shortcut(trigger);
+        };
+        invoke(
+            direction(wl_pointer::VERTICAL_SCROLL),
+            PointerTrigger::ScrollUp,
+            PointerTrigger::ScrollDown,
+        );
+        invoke(
+            direction(wl_pointer::HORIZONTAL_SCROLL),
+            PointerTrigger::ScrollLeft,
+            PointerTrigger::ScrollRight,
+        );
+        consumed
+    }
+
+    pub fn set_shortcuts_inhibit_escape(&self, escape: Option<(Modifiers, KeySym)>) {
+        self.shortcuts_inhibit_escape
+            .set(escape.map(|(mods, keysym)| (mods.0, keysym.0)));
+        if let Some(inhibitor) = self.shortcuts_inhibitor() {
+            match escape {
+                Some(_) => inhibitor.activate(),
+                None => inhibitor.deactivate(),
+            }
+        }
+    }
+
+    /// Returns whether shortcuts inhibitors can be activated.
+    ///
+    /// Inhibitors are only honored while the user has a key to escape from them.
+    pub fn can_inhibit_shortcuts(&self) -> bool {
+        self.shortcuts_inhibit_escape.get().is_some()
+    }
+
+    pub fn set_zoom_scroll_modifiers(&self, mods: Modifiers) {
+        self.zoom_scroll_mods.set(mods.0 & !RELEASE.0);
+    }
+
+    pub fn set_workspace_swipe(&self, fingers: u32, inverted: bool) {
+        self.workspace_swipe_fingers.set(fingers);
+        self.workspace_swipe_inverted.set(inverted);
+    }
+
+    pub fn set_dnd_threshold(&self, threshold: i32) {
+        self.dnd_threshold.set(threshold.max(0));
+    }
+
+    pub fn set_middle_click_paste_enabled(&self, enabled: bool) {
+        self.middle_click_paste.set(enabled);
+    }
+
+    /// Changes the zoom of the output under the pointer if the zoom modifiers are
+    /// pressed.
+    ///
+    /// Returns whether the scroll event was consumed.
+    pub(super) fn zoom_scroll(&self, pending: &PendingScroll, px_per_scroll_wheel: f64) -> bool {
+        let zoom_mods = self.zoom_scroll_mods.get();
+        if zoom_mods == 0 {
+            return false;
+        }
+        let mods = self.latest_kb_state.get().borrow().mods.mods_effective & !(CAPS.0 | NUM.0);
+        if mods != zoom_mods {
+            return false;
+        }
+        if let Some(px) = pending.px[wl_pointer::VERTICAL_SCROLL as usize].get() {
+            if px_per_scroll_wheel > 0.0 {
+                let steps = -px.to_f64() / px_per_scroll_wheel;
+                self.change_zoom(|factor| factor * ZOOM_STEP.powf(steps));
+            }
+        }
+        true
+    }
+
+    fn shortcuts_inhibitor(&self) -> Option<Rc<ZwpKeyboardShortcutsInhibitorV1>> {
+        self.keyboard_node
+            .get()
+            .node_into_surface()?
+            .shortcuts_inhibitors
+            .get(&self.id)
+    }
+
     pub fn trigger_tree_changed(&self, needs_layout: bool) {
         // log::info!("trigger_tree_changed");
         if needs_layout {
@@ -1144,6 +1505,15 @@ impl WlSeatGlobal {
             }
         };
         let time = (time_usec / 1000) as u32;
+        // Clients paste the primary selection when the middle button is pressed or
+        // released. Hide the selection from the client while it handles the button.
+        let hide_primary_selection = button == BTN_MIDDLE && !self.middle_click_paste.get();
+        if hide_primary_selection {
+            self.offer_selection_to_client::<PrimarySelectionIpc, XPrimarySelectionIpc>(
+                None,
+                &surface.client,
+            );
+        }
         self.surface_pointer_event(Version::ALL, surface, |p| {
             p.send_button(serial, time, button, state)
         });
@@ -1152,6 +1522,13 @@ impl WlSeatGlobal {
             if let Some(node) = surface.get_focus_node(self.id) {
                 self.focus_node_with_serial(node, serial);
             }
+        } else if hide_primary_selection
+            && self.keyboard_node.get().node_client_id() == Some(surface.client.id)
+        {
+            self.offer_selection_to_client::<PrimarySelectionIpc, XPrimarySelectionIpc>(
+                self.primary_selection.get(),
+                &surface.client,
+            );
         }
     }
 }
@@ -1236,12 +1613,47 @@ impl WlSeatGlobal {
 // Enter callbacks
 impl WlSeatGlobal {
     pub fn enter_toplevel(self: &Rc<Self>, n: Rc<dyn ToplevelNode>) {
-        if n.tl_accepts_keyboard_focus()
-            && self.changes.get().contains(CHANGE_CURSOR_MOVED)
-            && self.focus_follows_mouse.get()
+        self.focus_follows_mouse_timeout.take();
+        if !n.tl_accepts_keyboard_focus()
+            || !self.changes.get().contains(CHANGE_CURSOR_MOVED)
+            || !self.focus_follows_mouse.get()
         {
-            self.focus_toplevel(n);
+            return;
+        }
+        let dwell = self.focus_follows_mouse_dwell_ms.get();
+        if dwell == 0 {
+            self.focus_toplevel_under_pointer(n);
+            return;
+        }
+        let slf = self.clone();
+        let future = self.state.eng.spawn("focus dwell", async move {
+            if let Err(e) = slf.state.wheel.timeout(dwell).await {
+                log::error!(
+                    "Could not wait for the focus-follows-mouse dwell time: {}",
+                    ErrorFmt(e)
+                );
+                return;
+            }
+            let id = n.node_id();
+            let under_pointer = slf
+                .pointer_stack
+                .borrow()
+                .iter()
+                .any(|node| node.node_id() == id);
+            if under_pointer {
+                slf.focus_toplevel_under_pointer(n);
+            }
+        });
+        self.focus_follows_mouse_timeout.set(Some(future));
+    }
+
+    fn focus_toplevel_under_pointer(self: &Rc<Self>, n: Rc<dyn ToplevelNode>) {
+        if let Some(surface) = self.keyboard_node.get().node_into_surface() {
+            if surface.is_layer_surface() {
+                return;
+            }
         }
+        self.focus_toplevel(n);
     }
 
     pub fn enter_popup(self: &Rc<Self>, _n: &Rc<XdgPopup>) {
@@ -1289,6 +1701,10 @@ impl WlSeatGlobal {
             }
         }
 
+        if let Some(inhibitor) = surface.shortcuts_inhibitors.get(&self.id) {
+            inhibitor.deactivate();
+        }
+
         let serial = surface.client.next_serial();
         self.surface_kb_event(Version::ALL, surface, |k| k.send_leave(serial, surface.id))
     }
@@ -1321,6 +1737,10 @@ impl WlSeatGlobal {
                 ti.send_done();
             }
         }
+
+        if let Some(inhibitor) = surface.shortcuts_inhibitors.get(&self.id) {
+            inhibitor.activate();
+        }
     }
 }
 
@@ -1403,7 +1823,14 @@ impl WlSeatGlobal {
 // Dnd callbacks
 impl WlSeatGlobal {
     pub fn dnd_surface_leave(&self, surface: &WlSurface, dnd: &Dnd) {
-        if dnd.src.is_some() || surface.client.id == dnd.client.id {
+        if surface.client.is_xwayland {
+            if dnd.src.is_some() {
+                self.state
+                    .xwayland
+                    .queue
+                    .push(XWaylandEvent::DndLeave { seat: self.id });
+            }
+        } else if dnd.src.is_some() || surface.client.id == dnd.client.id {
             self.for_each_data_device(Version::ALL, surface.client.id, |dd| {
                 dd.send_leave();
             })
@@ -1415,7 +1842,14 @@ impl WlSeatGlobal {
     }
 
     pub fn dnd_surface_drop(&self, surface: &WlSurface, dnd: &Dnd) {
-        if dnd.src.is_some() || surface.client.id == dnd.client.id {
+        if surface.client.is_xwayland {
+            if dnd.src.is_some() {
+                self.state
+                    .xwayland
+                    .queue
+                    .push(XWaylandEvent::DndDrop { seat: self.id });
+            }
+        } else if dnd.src.is_some() || surface.client.id == dnd.client.id {
             self.for_each_data_device(Version::ALL, surface.client.id, |dd| {
                 dd.send_drop();
             })
@@ -1432,7 +1866,11 @@ impl WlSeatGlobal {
         serial: u64,
     ) {
         if let Some(src) = &dnd.src {
-            if !surface.client.is_xwayland {
+            if surface.client.is_xwayland {
+                self.for_each_x_data_device(|dd| {
+                    offer_source_to_x::<XDndIpc>(src.clone(), dd);
+                });
+            } else {
                 offer_source_to_regular_client::<ClipboardIpc>(src.clone(), &surface.client);
             }
             src.for_each_data_offer(|offer| {
@@ -1455,7 +1893,16 @@ impl WlSeatGlobal {
         x: Fixed,
         y: Fixed,
     ) {
-        if dnd.src.is_some() || surface.client.id == dnd.client.id {
+        if surface.client.is_xwayland {
+            if dnd.src.is_some() {
+                self.state.xwayland.queue.push(XWaylandEvent::DndMotion {
+                    seat: self.id,
+                    surface: surface.id,
+                    x,
+                    y,
+                });
+            }
+        } else if dnd.src.is_some() || surface.client.id == dnd.client.id {
             self.for_each_data_device(Version::ALL, surface.client.id, |dd| {
                 dd.send_motion(time_usec, x, y);
             })
@@ -1464,6 +1911,43 @@ impl WlSeatGlobal {
     }
 }
 
+// Callbacks for drags started by X clients
+impl WlSeatGlobal {
+    pub fn x_dnd_surface_leave(&self, surface: &WlSurface, src: &XDataSource) {
+        self.for_each_data_device(Version::ALL, surface.client.id, |dd| {
+            dd.send_leave();
+        });
+        src.on_leave();
+    }
+
+    pub fn x_dnd_surface_drop(&self, surface: &WlSurface) {
+        self.for_each_data_device(Version::ALL, surface.client.id, |dd| {
+            dd.send_drop();
+        });
+    }
+
+    pub fn x_dnd_surface_enter(
+        &self,
+        surface: &WlSurface,
+        src: &Rc<XDataSource>,
+        x: Fixed,
+        y: Fixed,
+        serial: u64,
+    ) {
+        offer_source_to_regular_client::<ClipboardIpc>(src.clone(), &surface.client);
+        src.for_each_data_offer(|offer| {
+            offer.send_enter(surface.id, x, y, serial);
+            offer.send_source_actions();
+        });
+    }
+
+    pub fn x_dnd_surface_motion(&self, surface: &WlSurface, time_usec: u64, x: Fixed, y: Fixed) {
+        self.for_each_data_device(Version::ALL, surface.client.id, |dd| {
+            dd.send_motion(time_usec, x, y);
+        });
+    }
+}
+
 // Gesture callbacks
 impl WlSeatGlobal {
     pub fn swipe_begin_surface(&self, n: &WlSurface, time_usec: u64, finger_count: u32) {
@@ -1,8 +1,16 @@
 use {
-    crate::{fixed::Fixed, ifs::wl_seat::WlSeatGlobal, tree::Node, utils::clonecell::CloneCell},
-    std::rc::Rc,
+    crate::{
+        fixed::Fixed,
+        ifs::wl_seat::WlSeatGlobal,
+        tree::{Node, OutputNode},
+        utils::clonecell::CloneCell,
+    },
+    std::{cell::Cell, rc::Rc},
 };
 
+/// The horizontal distance that a workspace swipe has to travel to switch one workspace.
+const WORKSPACE_SWIPE_DISTANCE: f64 = 100.0;
+
 pub struct GestureOwnerHolder {
     default: Rc<NoGesture>,
     owner: CloneCell<Rc<dyn GestureOwner>>,
@@ -143,6 +151,14 @@ impl GestureOwner for NoGesture {
     }
 
     fn swipe_begin(&self, seat: &Rc<WlSeatGlobal>, time_usec: u64, finger_count: u32) {
+        if finger_count == seat.workspace_swipe_fingers.get() {
+            seat.gesture_owner.owner.set(Rc::new(WorkspaceSwipeGesture {
+                output: seat.get_output(),
+                distance: Cell::new(0.0),
+                switched: Cell::new(false),
+            }));
+            return;
+        }
         let Some(node) = seat.pointer_node() else {
             return;
         };
@@ -190,6 +206,45 @@ impl GestureOwner for SwipeGesture {
     }
 }
 
+struct WorkspaceSwipeGesture {
+    output: Rc<OutputNode>,
+    distance: Cell<f64>,
+    switched: Cell<bool>,
+}
+
+impl GestureOwner for WorkspaceSwipeGesture {
+    fn revert_to_default(&self, seat: &Rc<WlSeatGlobal>) {
+        let _ = seat;
+    }
+
+    fn swipe_update(&self, seat: &Rc<WlSeatGlobal>, _time_usec: u64, dx: Fixed, _dy: Fixed) {
+        // Moving the fingers to the left reveals the workspace to the right.
+        let mut dx = -dx.to_f64();
+        if seat.workspace_swipe_inverted.get() {
+            dx = -dx;
+        }
+        let distance = self.distance.get() + dx;
+        let steps = (distance / WORKSPACE_SWIPE_DISTANCE) as i32;
+        self.distance
+            .set(distance - steps as f64 * WORKSPACE_SWIPE_DISTANCE);
+        if steps != 0 {
+            self.switched.set(true);
+            self.output.step_workspace(seat, steps);
+        }
+    }
+
+    fn swipe_end(&self, seat: &Rc<WlSeatGlobal>, _time_usec: u64, cancelled: bool) {
+        seat.gesture_owner.set_default_owner();
+        if cancelled || self.switched.get() {
+            return;
+        }
+        let distance = self.distance.get();
+        if distance.abs() >= WORKSPACE_SWIPE_DISTANCE / 2.0 {
+            self.output.step_workspace(seat, distance.signum() as i32);
+        }
+    }
+}
+
 struct PinchGesture {
     node: Rc<dyn Node>,
 }
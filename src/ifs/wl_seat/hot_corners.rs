@@ -0,0 +1,97 @@
+use {
+    crate::{
+        async_engine::SpawnedFuture, backend::ConnectorId, ifs::wl_seat::WlSeatGlobal, rect::Rect,
+        utils::errorfmt::ErrorFmt,
+    },
+    jay_config::input::HotCorner,
+    std::{cell::Cell, rc::Rc},
+};
+
+const DEFAULT_SIZE: i32 = 2;
+const DEFAULT_DWELL_MS: u64 = 250;
+
+pub struct HotCorners {
+    size: Cell<i32>,
+    dwell_ms: Cell<u64>,
+    current: Cell<Option<(ConnectorId, HotCorner)>>,
+    timeout: Cell<Option<SpawnedFuture<()>>>,
+}
+
+impl Default for HotCorners {
+    fn default() -> Self {
+        Self {
+            size: Cell::new(DEFAULT_SIZE),
+            dwell_ms: Cell::new(DEFAULT_DWELL_MS),
+            current: Default::default(),
+            timeout: Default::default(),
+        }
+    }
+}
+
+impl HotCorners {
+    pub fn clear(&self) {
+        self.current.take();
+        self.timeout.take();
+    }
+}
+
+fn find_corner(rect: Rect, size: i32, x: i32, y: i32) -> Option<HotCorner> {
+    if size <= 0 || !rect.contains(x, y) {
+        return None;
+    }
+    let left = x < rect.x1() + size;
+    let right = x >= rect.x2() - size;
+    let top = y < rect.y1() + size;
+    let bottom = y >= rect.y2() - size;
+    let corner = match (left, right, top, bottom) {
+        (true, _, true, _) => HotCorner::TopLeft,
+        (_, true, true, _) => HotCorner::TopRight,
+        (true, _, _, true) => HotCorner::BottomLeft,
+        (_, true, _, true) => HotCorner::BottomRight,
+        _ => return None,
+    };
+    Some(corner)
+}
+
+impl WlSeatGlobal {
+    pub fn set_hot_corner_size(&self, size: i32) {
+        self.hot_corners.size.set(size);
+    }
+
+    pub fn set_hot_corner_dwell(&self, ms: u64) {
+        self.hot_corners.dwell_ms.set(ms);
+    }
+
+    pub(super) fn update_hot_corner(self: &Rc<Self>) {
+        let hc = &self.hot_corners;
+        let mut corner = None;
+        if !self.state.lock.locked.get() {
+            let output = self.pointer_cursor.output();
+            let (x, y) = self.pointer_cursor.position_int();
+            corner = find_corner(output.global.pos.get(), hc.size.get(), x, y)
+                .map(|c| (output.global.connector.connector.id(), c));
+        }
+        if hc.current.replace(corner) == corner {
+            return;
+        }
+        hc.timeout.take();
+        let Some((connector, corner)) = corner else {
+            return;
+        };
+        let slf = self.clone();
+        let dwell = hc.dwell_ms.get();
+        let future = self.state.eng.spawn("hot corner dwell", async move {
+            if let Err(e) = slf.state.wheel.timeout(dwell).await {
+                log::error!(
+                    "Could not wait for the hot corner dwell time: {}",
+                    ErrorFmt(e)
+                );
+                return;
+            }
+            if let Some(config) = slf.state.config.get() {
+                config.hot_corner(slf.id, connector, corner);
+            }
+        });
+        hc.timeout.set(Some(future));
+    }
+}
@@ -0,0 +1,217 @@
+use {
+    crate::{
+        async_engine::SpawnedFuture, ifs::wl_seat::WlSeatGlobal, utils::errorfmt::ErrorFmt,
+        xkbcommon::keysym_name,
+    },
+    ahash::AHashSet,
+    isnt::std_1::vec::IsntVecExt,
+    jay_config::keyboard::{
+        mods::{Modifiers, ALT, CTRL, LOGO, MOD3, MOD5, SHIFT},
+        syms::KeySym,
+        ModifiedKeySym,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        mem,
+        rc::Rc,
+    },
+};
+
+const DEFAULT_TIMEOUT_MS: u64 = 1000;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SequenceKey {
+    pub mods: u32,
+    pub sym: u32,
+}
+
+impl From<ModifiedKeySym> for SequenceKey {
+    fn from(key: ModifiedKeySym) -> Self {
+        Self {
+            mods: key.mods.0,
+            sym: key.sym.0,
+        }
+    }
+}
+
+impl From<SequenceKey> for ModifiedKeySym {
+    fn from(key: SequenceKey) -> Self {
+        Self {
+            mods: Modifiers(key.mods),
+            sym: KeySym(key.sym),
+        }
+    }
+}
+
+pub struct KeySequences {
+    sequences: RefCell<AHashSet<Vec<SequenceKey>>>,
+    pending: RefCell<Vec<SequenceKey>>,
+    timeout_ms: Cell<u64>,
+    forward_aborted: Cell<bool>,
+    show_hint: Cell<bool>,
+    timeout: Cell<Option<SpawnedFuture<()>>>,
+}
+
+pub enum KeySequenceResult {
+    /// The key does not belong to a sequence and should be handled normally.
+    Ignored,
+    /// The key started or continued a sequence.
+    Consumed,
+    /// The key completed the contained sequence.
+    Completed(Vec<SequenceKey>),
+}
+
+impl Default for KeySequences {
+    fn default() -> Self {
+        Self {
+            sequences: Default::default(),
+            pending: Default::default(),
+            timeout_ms: Cell::new(DEFAULT_TIMEOUT_MS),
+            forward_aborted: Cell::new(false),
+            show_hint: Cell::new(true),
+            timeout: Default::default(),
+        }
+    }
+}
+
+impl KeySequences {
+    pub fn clear(&self) {
+        self.sequences.borrow_mut().clear();
+        self.pending.borrow_mut().clear();
+        self.timeout.take();
+    }
+}
+
+fn is_modifier(sym: u32) -> bool {
+    matches!(
+        sym,
+        // ISO_Lock ..= ISO_Level5_Lock
+        0xfe01..=0xfe13
+        // Mode_switch, Num_Lock
+        | 0xff7e..=0xff7f
+        // Shift_L ..= Hyper_R
+        | 0xffe1..=0xffee
+    )
+}
+
+fn key_name(key: &SequenceKey) -> String {
+    let mut name = String::new();
+    for (m, n) in [
+        (SHIFT, "shift"),
+        (CTRL, "ctrl"),
+        (ALT, "alt"),
+        (MOD3, "mod3"),
+        (LOGO, "logo"),
+        (MOD5, "mod5"),
+    ] {
+        if key.mods & m.0 != 0 {
+            name.push_str(n);
+            name.push('-');
+        }
+    }
+    name.push_str(&keysym_name(key.sym));
+    name
+}
+
+impl WlSeatGlobal {
+    pub fn add_key_sequence(&self, keys: Vec<SequenceKey>) {
+        if keys.len() < 2 {
+            log::warn!("Ignoring key sequence with fewer than 2 keys");
+            return;
+        }
+        self.key_sequences.sequences.borrow_mut().insert(keys);
+    }
+
+    pub fn remove_key_sequence(&self, keys: &[SequenceKey]) {
+        self.key_sequences.sequences.borrow_mut().remove(keys);
+    }
+
+    pub fn set_key_sequence_timeout(&self, ms: u64) {
+        self.key_sequences.timeout_ms.set(ms);
+    }
+
+    pub fn set_forward_aborted_key_sequences(&self, forward: bool) {
+        self.key_sequences.forward_aborted.set(forward);
+    }
+
+    pub fn set_show_key_sequence_hint(&self, show: bool) {
+        self.key_sequences.show_hint.set(show);
+    }
+
+    pub(super) fn handle_key_sequence(
+        self: &Rc<Self>,
+        mods: u32,
+        syms: &[u32],
+    ) -> KeySequenceResult {
+        let ks = &self.key_sequences;
+        if ks.sequences.borrow().is_empty() {
+            return KeySequenceResult::Ignored;
+        }
+        if syms.iter().copied().any(is_modifier) {
+            return KeySequenceResult::Ignored;
+        }
+        let was_pending = ks.pending.borrow().is_not_empty();
+        for &sym in syms {
+            let mut pending = ks.pending.borrow_mut();
+            pending.push(SequenceKey { mods, sym });
+            let sequences = ks.sequences.borrow();
+            if sequences.contains(&*pending) {
+                let keys = mem::take(&mut *pending);
+                drop(pending);
+                drop(sequences);
+                self.end_key_sequence();
+                return KeySequenceResult::Completed(keys);
+            }
+            if sequences.iter().any(|s| s.starts_with(&pending)) {
+                drop(pending);
+                drop(sequences);
+                self.continue_key_sequence();
+                return KeySequenceResult::Consumed;
+            }
+            pending.pop();
+        }
+        if !was_pending {
+            return KeySequenceResult::Ignored;
+        }
+        ks.pending.borrow_mut().clear();
+        self.end_key_sequence();
+        if ks.forward_aborted.get() {
+            KeySequenceResult::Ignored
+        } else {
+            KeySequenceResult::Consumed
+        }
+    }
+
+    fn continue_key_sequence(self: &Rc<Self>) {
+        let ks = &self.key_sequences;
+        if ks.show_hint.get() {
+            let mut hint = String::new();
+            for key in &*ks.pending.borrow() {
+                hint.push_str(&key_name(key));
+                hint.push(' ');
+            }
+            hint.push('…');
+            self.state.set_status_hint(Some(hint));
+        }
+        let slf = self.clone();
+        let timeout = ks.timeout_ms.get();
+        let future = self.state.eng.spawn("key sequence timeout", async move {
+            if let Err(e) = slf.state.wheel.timeout(timeout).await {
+                log::error!(
+                    "Could not wait for the key sequence timeout: {}",
+                    ErrorFmt(e)
+                );
+            }
+            slf.key_sequences.pending.borrow_mut().clear();
+            slf.state.set_status_hint(None);
+        });
+        ks.timeout.set(Some(future));
+    }
+
+    fn end_key_sequence(&self) {
+        self.key_sequences.timeout.take();
+        if self.state.status_hint.get().is_some() {
+            self.state.set_status_hint(None);
+        }
+    }
+}
@@ -19,10 +19,10 @@ use {
             FoundNode, Node, PlaceholderNode, TddType, ToplevelNode, WorkspaceDragDestination,
             WorkspaceNode, WsMoveConfig,
         },
-        utils::{clonecell::CloneCell, smallmap::SmallMap},
+        utils::{clonecell::CloneCell, rc_eq::rc_eq, smallmap::SmallMap},
     },
     std::{
-        cell::Cell,
+        cell::{Cell, RefCell},
         rc::{Rc, Weak},
     },
 };
@@ -55,6 +55,11 @@ impl Default for PointerOwnerHolder {
 }
 
 impl PointerOwnerHolder {
+    pub fn is_default(&self) -> bool {
+        let default: Rc<dyn PointerOwner> = self.default.clone();
+        rc_eq(&self.owner.get(), &default)
+    }
+
     pub fn button(&self, seat: &Rc<WlSeatGlobal>, time_usec: u64, button: u32, state: KeyState) {
         self.owner.get().button(seat, time_usec, button, state)
     }
@@ -92,6 +97,12 @@ impl PointerOwnerHolder {
         seat.state.for_each_seat_tester(|t| {
             t.send_axis(seat.id, time_usec, &pending);
         });
+        if seat.zoom_scroll(&pending, px_per_scroll_wheel) {
+            return;
+        }
+        if seat.scroll_shortcut(&pending) {
+            return;
+        }
         if let Some(node) = self.owner.get().axis_node(seat) {
             node.node_on_axis_event(seat, &pending);
         }
@@ -285,6 +296,15 @@ struct SimpleGrabPointerOwner<T> {
     buttons: SmallMap<u32, (), 1>,
     node: Rc<dyn Node>,
     serial: u64,
+    start_pos: (Fixed, Fixed),
+    pending_dnd: RefCell<Option<PendingDnd>>,
+}
+
+struct PendingDnd {
+    button: u32,
+    origin: Rc<WlSurface>,
+    src: Option<Rc<WlDataSource>>,
+    icon: Option<Rc<DndIcon>>,
 }
 
 struct DndPointerOwner {
@@ -334,6 +354,8 @@ impl<T: SimplePointerOwnerUsecase> PointerOwner for SimplePointerOwner<T> {
                 buttons: SmallMap::new_with(button, ()),
                 node: pn.clone(),
                 serial,
+                start_pos: seat.pointer_cursor.position(),
+                pending_dnd: Default::default(),
             }));
         pn.node_seat_state().add_pointer_grab(seat);
         seat.handle_node_button(pn, time_usec, button, state, serial);
@@ -437,6 +459,7 @@ impl<T: SimplePointerOwnerUsecase> PointerOwner for SimpleGrabPointerOwner<T> {
             KeyState::Released => {
                 self.buttons.remove(&button);
                 if self.buttons.is_empty() {
+                    self.cancel_pending_dnd(seat);
                     self.node.node_seat_state().remove_pointer_grab(seat);
                     // log::info!("button");
                     self.usecase.release_grab(seat);
@@ -458,11 +481,20 @@ impl<T: SimplePointerOwnerUsecase> PointerOwner for SimpleGrabPointerOwner<T> {
     fn apply_changes(&self, seat: &Rc<WlSeatGlobal>) {
         let (x, y) = seat.pointer_cursor.position();
         let pos = self.node.node_absolute_position();
-        let (x_int, y_int) = pos.translate(x.round_down(), y.round_down());
+        let (x_int, y_int) = seat
+            .pointer_cursor
+            .output()
+            .content_position(x.round_down(), y.round_down());
+        let (x_int, y_int) = pos.translate(x_int, y_int);
         // log::info!("apply_changes");
         self.node
             .clone()
             .node_on_pointer_motion(seat, x.apply_fract(x_int), y.apply_fract(y_int));
+        if self.pending_dnd.borrow().is_some() && self.dnd_threshold_reached(seat) {
+            if let Some(dnd) = self.pending_dnd.take() {
+                self.start_dnd(seat, dnd);
+            }
+        }
     }
 
     fn start_drag(
@@ -477,11 +509,23 @@ impl<T: SimplePointerOwnerUsecase> PointerOwner for SimpleGrabPointerOwner<T> {
             .start_drag(self, seat, origin, src, icon, serial)
     }
 
+    fn cancel_dnd(&self, seat: &Rc<WlSeatGlobal>) {
+        self.cancel_pending_dnd(seat);
+        seat.dropped_dnd.borrow_mut().take();
+    }
+
     fn revert_to_default(&self, seat: &Rc<WlSeatGlobal>) {
+        self.cancel_pending_dnd(seat);
         self.node.node_seat_state().remove_pointer_grab(seat);
         seat.pointer_owner.set_default_pointer_owner(seat);
     }
 
+    fn remove_dnd_icon(&self) {
+        if let Some(dnd) = &mut *self.pending_dnd.borrow_mut() {
+            dnd.icon = None;
+        }
+    }
+
     fn start_tile_drag(&self, seat: &Rc<WlSeatGlobal>, tl: &Rc<dyn ToplevelNode>) {
         self.usecase.start_tile_drag(self, seat, tl);
     }
@@ -671,8 +715,8 @@ trait SimplePointerOwnerUsecase: Sized + Clone + 'static {
     }
 }
 
-impl DefaultPointerUsecase {
-    fn prepare_new_usecase(&self, grab: &SimpleGrabPointerOwner<Self>, seat: &Rc<WlSeatGlobal>) {
+impl<T> SimpleGrabPointerOwner<T> {
+    fn prepare_new_usecase(&self, seat: &Rc<WlSeatGlobal>) {
         {
             let mut stack = seat.pointer_stack.borrow_mut();
             for node in stack.drain(1..).rev() {
@@ -680,16 +724,66 @@ impl DefaultPointerUsecase {
                 node.node_seat_state().leave(seat);
             }
         }
-        grab.node.node_seat_state().remove_pointer_grab(seat);
+        self.node.node_seat_state().remove_pointer_grab(seat);
+    }
+
+    fn dnd_threshold_reached(&self, seat: &Rc<WlSeatGlobal>) -> bool {
+        let (x, y) = seat.pointer_cursor.position();
+        let dx = (x - self.start_pos.0).to_f64();
+        let dy = (y - self.start_pos.1).to_f64();
+        let threshold = seat.dnd_threshold.get() as f64;
+        dx * dx + dy * dy >= threshold * threshold
+    }
+
+    fn start_dnd(&self, seat: &Rc<WlSeatGlobal>, dnd: PendingDnd) {
+        if let Some(icon) = &dnd.icon {
+            icon.enable();
+        }
+        if let Some(src) = &dnd.src {
+            if let Some(drag) = src.toplevel_drag.get() {
+                drag.start_drag();
+            }
+        }
+        *seat.dropped_dnd.borrow_mut() = None;
+        let pointer_owner = Rc::new(DndPointerOwner {
+            button: dnd.button,
+            dnd: Dnd {
+                seat: seat.clone(),
+                client: dnd.origin.client.clone(),
+                src: dnd.src,
+            },
+            target: CloneCell::new(seat.state.root.clone()),
+            icon: CloneCell::new(dnd.icon),
+            pos_x: Cell::new(Fixed::from_int(0)),
+            pos_y: Cell::new(Fixed::from_int(0)),
+        });
+        self.prepare_new_usecase(seat);
+        // {
+        //     let old = seat.keyboard_node.set(seat.state.root.clone());
+        //     old.seat_state().unfocus(seat);
+        //     old.unfocus(seat);
+        // }
+        seat.pointer_owner.owner.set(pointer_owner.clone());
+        pointer_owner.apply_changes(seat);
+    }
+
+    fn cancel_pending_dnd(&self, seat: &Rc<WlSeatGlobal>) {
+        if let Some(dnd) = self.pending_dnd.take() {
+            if let Some(src) = &dnd.src {
+                ipc::detach_seat(&**src, seat);
+            }
+        }
     }
+}
 
+impl DefaultPointerUsecase {
     fn start_ui_drag<T: UiDragUsecase>(
         &self,
         grab: &SimpleGrabPointerOwner<Self>,
         seat: &Rc<WlSeatGlobal>,
         usecase: T,
     ) {
-        self.prepare_new_usecase(grab, seat);
+        grab.prepare_new_usecase(seat);
         usecase.node_seat_state().add_ui_drag(seat);
         let pointer_owner = Rc::new(UiDragPointerOwner { usecase });
         seat.pointer_owner.owner.set(pointer_owner.clone());
@@ -733,36 +827,26 @@ impl SimplePointerOwnerUsecase for DefaultPointerUsecase {
         if grab.node.node_id() != origin.node_id {
             return Ok(());
         }
-        if let Some(icon) = &icon {
-            icon.enable();
+        if grab.pending_dnd.borrow().is_some() {
+            if let Some(src) = src {
+                src.send_cancelled(seat);
+            }
+            return Ok(());
         }
         if let Some(new) = &src {
             ipc::attach_seat(&**new, seat, ipc::Role::Dnd)?;
-            if let Some(drag) = new.toplevel_drag.get() {
-                drag.start_drag();
-            }
         }
-        *seat.dropped_dnd.borrow_mut() = None;
-        let pointer_owner = Rc::new(DndPointerOwner {
+        let dnd = PendingDnd {
             button,
-            dnd: Dnd {
-                seat: seat.clone(),
-                client: origin.client.clone(),
-                src,
-            },
-            target: CloneCell::new(seat.state.root.clone()),
-            icon: CloneCell::new(icon),
-            pos_x: Cell::new(Fixed::from_int(0)),
-            pos_y: Cell::new(Fixed::from_int(0)),
-        });
-        self.prepare_new_usecase(grab, seat);
-        // {
-        //     let old = seat.keyboard_node.set(seat.state.root.clone());
-        //     old.seat_state().unfocus(seat);
-        //     old.unfocus(seat);
-        // }
-        seat.pointer_owner.owner.set(pointer_owner.clone());
-        pointer_owner.apply_changes(seat);
+            origin: origin.clone(),
+            src,
+            icon,
+        };
+        if grab.dnd_threshold_reached(seat) {
+            grab.start_dnd(seat, dnd);
+        } else {
+            *grab.pending_dnd.borrow_mut() = Some(dnd);
+        }
         Ok(())
     }
 
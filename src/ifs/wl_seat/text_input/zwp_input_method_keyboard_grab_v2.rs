@@ -86,7 +86,7 @@ impl ZwpInputMethodKeyboardGrabV2 {
     }
 
     pub fn send_repeat_info(&self) {
-        let (rate, delay) = self.input_method.seat.repeat_rate.get();
+        let (rate, delay) = self.input_method.seat.effective_repeat_rate.get();
         self.client.event(RepeatInfo {
             self_id: self.id,
             rate,
@@ -0,0 +1,98 @@
+use {
+    crate::{
+        async_engine::SpawnedFuture,
+        client::ClientId,
+        ifs::{jay_which_key::JayWhichKey, wl_seat::WlSeatGlobal},
+        utils::{copyhashmap::CopyHashMap, errorfmt::ErrorFmt},
+        wire::JayWhichKeyId,
+    },
+    jay_config::keyboard::{mods::Modifiers, syms::KeySym},
+    std::{cell::Cell, rc::Rc},
+};
+
+#[derive(Default)]
+pub struct WhichKey {
+    delay_ms: Cell<Option<u64>>,
+    shown: Cell<bool>,
+    timeout: Cell<Option<SpawnedFuture<()>>>,
+    watchers: CopyHashMap<(ClientId, JayWhichKeyId), Rc<JayWhichKey>>,
+}
+
+impl WhichKey {
+    pub fn clear(&self) {
+        self.timeout.take();
+        self.watchers.clear();
+    }
+}
+
+impl WlSeatGlobal {
+    pub fn set_which_key_delay(&self, ms: Option<u64>) {
+        self.which_key.delay_ms.set(ms);
+        if ms.is_none() {
+            self.which_key.timeout.take();
+        }
+    }
+
+    pub fn add_which_key_watcher(&self, watcher: &Rc<JayWhichKey>) {
+        self.which_key
+            .watchers
+            .set((watcher.client.id, watcher.id), watcher.clone());
+    }
+
+    pub fn remove_which_key_watcher(&self, watcher: &JayWhichKey) {
+        self.which_key
+            .watchers
+            .remove(&(watcher.client.id, watcher.id));
+    }
+
+    /// Returns the shortcuts that can be invoked while `mods` are pressed.
+    fn which_key_shortcuts(&self, mods: u32) -> Vec<(Modifiers, KeySym)> {
+        let mut res = vec![];
+        for (&keysym, key_mods) in &*self.shortcuts.borrow() {
+            for (key_mods, mask) in key_mods {
+                if mods & mask == key_mods {
+                    res.push((Modifiers(key_mods), KeySym(keysym)));
+                }
+            }
+        }
+        res.sort_unstable_by_key(|&(mods, keysym)| (keysym.0, mods.0));
+        res
+    }
+
+    /// Called after every key event.
+    ///
+    /// `mods` contains the new modifiers if the key event changed them. If the
+    /// modifiers are held for the configured delay, watchers are sent the shortcuts
+    /// available under them.
+    pub(super) fn update_which_key(self: &Rc<Self>, mods: Option<u32>) {
+        let wk = &self.which_key;
+        wk.timeout.take();
+        if wk.shown.replace(false) {
+            for watcher in wk.watchers.lock().values() {
+                watcher.send_hide();
+            }
+        }
+        let (Some(mods), Some(delay)) = (mods, wk.delay_ms.get()) else {
+            return;
+        };
+        if mods == 0 || wk.watchers.is_empty() || self.state.lock.locked.get() {
+            return;
+        }
+        let slf = self.clone();
+        let future = self.state.eng.spawn("which key delay", async move {
+            if let Err(e) = slf.state.wheel.timeout(delay).await {
+                log::error!("Could not wait for the which-key delay: {}", ErrorFmt(e));
+                return;
+            }
+            let shortcuts = slf.which_key_shortcuts(mods);
+            if shortcuts.is_empty() {
+                return;
+            }
+            slf.which_key.shown.set(true);
+            for watcher in slf.which_key.watchers.lock().values() {
+                watcher.send_shortcuts(mods, &shortcuts);
+            }
+        });
+        wk.timeout.set(Some(future));
+    }
+}
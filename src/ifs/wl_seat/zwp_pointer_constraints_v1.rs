@@ -246,6 +246,7 @@ impl ZwpPointerConstraintsV1RequestHandler for ZwpPointerConstraintsV1 {
             tracker: Default::default(),
             constraint,
             version: self.version,
+            cursor_hint: Default::default(),
         });
         track!(self.client, lp);
         self.client.add_client_obj(&lp)?;
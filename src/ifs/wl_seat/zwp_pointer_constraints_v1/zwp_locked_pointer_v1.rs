@@ -1,14 +1,15 @@
 use {
     crate::{
         client::ClientError,
+        fixed::Fixed,
         ifs::wl_seat::zwp_pointer_constraints_v1::{
-            ConstraintOwner, SeatConstraint, ZwpPointerConstraintsV1Error,
+            ConstraintOwner, SeatConstraint, SeatConstraintStatus, ZwpPointerConstraintsV1Error,
         },
         leaks::Tracker,
         object::{Object, Version},
         wire::{zwp_locked_pointer_v1::*, ZwpLockedPointerV1Id},
     },
-    std::rc::Rc,
+    std::{cell::Cell, rc::Rc},
     thiserror::Error,
 };
 
@@ -17,22 +18,33 @@ pub struct ZwpLockedPointerV1 {
     pub tracker: Tracker<Self>,
     pub constraint: Rc<SeatConstraint>,
     pub version: Version,
+    pub cursor_hint: Cell<Option<(Fixed, Fixed)>>,
 }
 
 impl ZwpLockedPointerV1RequestHandler for ZwpLockedPointerV1 {
     type Error = ZwpLockedPointerV1Error;
 
     fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let was_active = self.constraint.status.get() == SeatConstraintStatus::Active;
         self.constraint.detach();
         self.constraint.client.remove_obj(self)?;
+        if was_active {
+            if let Some((x, y)) = self.cursor_hint.get() {
+                self.constraint
+                    .seat
+                    .warp_to_surface_position(&self.constraint.surface, x, y);
+            }
+        }
         Ok(())
     }
 
     fn set_cursor_position_hint(
         &self,
-        _req: SetCursorPositionHint,
+        mut req: SetCursorPositionHint,
         _slf: &Rc<Self>,
     ) -> Result<(), Self::Error> {
+        client_wire_scale_to_logical!(self.constraint.client, req.surface_x, req.surface_y);
+        self.cursor_hint.set(Some((req.surface_x, req.surface_y)));
         Ok(())
     }
 
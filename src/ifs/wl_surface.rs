@@ -17,6 +17,7 @@ pub mod xwayland_shell_v1;
 pub mod zwlr_layer_surface_v1;
 pub mod zwp_idle_inhibitor_v1;
 pub mod zwp_input_popup_surface_v2;
+pub mod zwp_keyboard_shortcuts_inhibitor_v1;
 
 use {
     crate::{
@@ -69,6 +70,7 @@ use {
         object::{Object, Version},
         rect::{DamageQueue, Rect, Region},
         renderer::Renderer,
+        scale::Scale,
         tree::{
             BeforeLatchListener, BeforeLatchResult, ContainerNode, FindTreeResult, FoundNode,
             LatchListener, Node, NodeId, NodeVisitor, NodeVisitorBase, OutputNode, PlaceholderNode,
@@ -104,6 +106,7 @@ use {
     },
     thiserror::Error,
     zwp_idle_inhibitor_v1::ZwpIdleInhibitorV1,
+    zwp_keyboard_shortcuts_inhibitor_v1::ZwpKeyboardShortcutsInhibitorV1,
 };
 
 #[expect(dead_code)]
@@ -304,6 +307,7 @@ pub struct WlSurface {
     dnd_icons: SmallMap<SeatId, Rc<DndIcon>, 1>,
     pub tracker: Tracker<Self>,
     idle_inhibitors: SmallMap<ZwpIdleInhibitorV1Id, Rc<ZwpIdleInhibitorV1>, 1>,
+    pub shortcuts_inhibitors: SmallMap<SeatId, Rc<ZwpKeyboardShortcutsInhibitorV1>, 1>,
     viewporter: CloneCell<Option<Rc<WpViewport>>>,
     output: CloneCell<Rc<OutputNode>>,
     fractional_scale: CloneCell<Option<Rc<WpFractionalScaleV1>>>,
@@ -316,7 +320,7 @@ pub struct WlSurface {
     pub content_type: Cell<Option<ContentType>>,
     pub drm_feedback: CopyHashMap<ZwpLinuxDmabufFeedbackV1Id, Rc<ZwpLinuxDmabufFeedbackV1>>,
     sync_obj_surface: CloneCell<Option<Rc<WpLinuxDrmSyncobjSurfaceV1>>>,
-    destroyed: Cell<bool>,
+    pub destroyed: Cell<bool>,
     commit_timeline: CommitTimeline,
     alpha_modifier: CloneCell<Option<Rc<WpAlphaModifierSurfaceV1>>>,
     alpha: Cell<Option<f32>>,
@@ -420,6 +424,10 @@ trait SurfaceExt {
     fn tray_item(self: Rc<Self>) -> Option<TrayItemId> {
         None
     }
+
+    fn is_layer_surface(&self) -> bool {
+        false
+    }
 }
 
 pub struct NoneSurfaceExt;
@@ -641,6 +649,7 @@ impl WlSurface {
             dnd_icons: Default::default(),
             tracker: Default::default(),
             idle_inhibitors: Default::default(),
+            shortcuts_inhibitors: Default::default(),
             viewporter: Default::default(),
             output: CloneCell::new(client.state.dummy_output.get().unwrap()),
             fractional_scale: Default::default(),
@@ -702,7 +711,9 @@ impl WlSurface {
         }
         output.global.send_enter(self);
         old.global.send_leave(self);
-        if old.global.persistent.scale.get() != output.global.persistent.scale.get() {
+        if old.global.persistent.scale.get() != output.global.persistent.scale.get()
+            && self.scale_override().is_none()
+        {
             self.on_scale_change();
         }
         if old.global.persistent.transform.get() != output.global.persistent.transform.get() {
@@ -786,6 +797,10 @@ impl WlSurface {
         }
     }
 
+    pub fn is_layer_surface(&self) -> bool {
+        self.ext.get().is_layer_surface()
+    }
+
     pub fn send_enter(&self, output: WlOutputId) {
         self.client.event(Enter {
             self_id: self.id,
@@ -800,11 +815,21 @@ impl WlSurface {
         })
     }
 
+    /// Returns the scale that was forced for the toplevel containing this surface.
+    ///
+    /// The override is independent of the output the surface is on.
+    fn scale_override(&self) -> Option<Scale> {
+        self.toplevel.get()?.tl_data().scale_override.get()
+    }
+
     pub fn send_preferred_buffer_scale(&self) {
         if self.version >= BUFFER_SCALE_SINCE {
             let factor = match self.client.wire_scale.is_some() {
                 true => 1,
-                false => self.output.get().global.legacy_scale.get() as _,
+                false => match self.scale_override() {
+                    Some(scale) => scale.round_up() as _,
+                    None => self.output.get().global.legacy_scale.get() as _,
+                },
             };
             self.client.event(PreferredBufferScale {
                 self_id: self.id,
@@ -995,6 +1020,7 @@ impl WlSurfaceRequestHandler for WlSurface {
         self.toplevel.set(None);
         self.client.remove_obj(self)?;
         self.idle_inhibitors.clear();
+        self.shortcuts_inhibitors.clear();
         self.constraints.take();
         self.destroyed.set(true);
         Ok(())
@@ -1664,6 +1690,7 @@ impl Object for WlSurface {
         self.buffer.set(None);
         self.toplevel.set(None);
         self.idle_inhibitors.clear();
+        self.shortcuts_inhibitors.clear();
         mem::take(self.pending.borrow_mut().deref_mut());
         self.presentation_feedback.borrow_mut().clear();
         self.latched_presentation_feedback.borrow_mut().clear();
@@ -44,17 +44,30 @@ impl CursorSurface {
 
     pub fn handle_buffer_change(&self) {
         self.update_extents();
+        self.user.image_changed();
     }
 
     pub fn set_hotspot(&self, x: i32, y: i32) {
-        self.hotspot.set((x, y));
+        if self.hotspot.replace((x, y)) != (x, y) {
+            self.damage_software_cursor();
+            self.user.image_changed();
+        }
         self.update_extents();
     }
 
+    fn damage_software_cursor(&self) {
+        if self.user.software_cursor() {
+            let (x, y) = self.user.position_int();
+            let extents = self.extents.get().move_(x, y);
+            self.surface.client.state.damage2(true, extents);
+        }
+    }
+
     pub fn dec_hotspot(&self, hotspot_dx: i32, hotspot_dy: i32) {
         let (hot_x, hot_y) = self.hotspot.get();
         self.hotspot.set((hot_x - hotspot_dx, hot_y - hotspot_dy));
         self.update_extents();
+        self.user.image_changed();
     }
 
     pub fn update_hardware_cursor(&self) {
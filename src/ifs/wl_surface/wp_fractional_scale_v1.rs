@@ -42,7 +42,10 @@ impl WpFractionalScaleV1 {
     pub fn send_preferred_scale(&self) {
         let scale = match self.client.wire_scale.is_some() {
             true => Scale::from_int(1),
-            false => self.surface.output.get().global.persistent.scale.get(),
+            false => match self.surface.scale_override() {
+                Some(scale) => scale,
+                None => self.surface.output.get().global.persistent.scale.get(),
+            },
         };
         self.client.event(PreferredScale {
             self_id: self.id,
@@ -143,6 +143,10 @@ impl XdgPopupParent for Popup {
     fn tray_item(&self) -> Option<TrayItemId> {
         self.parent.clone().tray_item()
     }
+
+    fn capture_blocked(&self) -> bool {
+        self.parent.capture_blocked()
+    }
 }
 
 #[derive(Default, Debug)]
@@ -187,6 +191,10 @@ pub trait XdgSurfaceExt: Debug {
     fn tray_item(&self) -> Option<TrayItemId> {
         None
     }
+
+    fn capture_blocked(&self) -> bool {
+        false
+    }
 }
 
 impl XdgSurface {
@@ -289,6 +297,10 @@ impl XdgSurface {
         self.geometry.get()
     }
 
+    pub fn capture_blocked(&self) -> bool {
+        self.ext.get().is_some_and(|e| e.capture_blocked())
+    }
+
     pub fn do_send_configure(&self) {
         let serial = self.requested_serial.fetch_add(1) + 1;
         self.send_configure(serial);
@@ -50,6 +50,9 @@ pub trait XdgPopupParent {
     fn allow_popup_focus(&self) -> bool {
         false
     }
+    fn capture_blocked(&self) -> bool {
+        false
+    }
 }
 
 pub struct XdgPopup {
@@ -328,7 +331,7 @@ impl Node for XdgPopup {
     }
 
     fn node_render(&self, renderer: &mut Renderer, x: i32, y: i32, bounds: Option<&Rect>) {
-        renderer.render_xdg_surface(&self.xdg, x, y, bounds)
+        renderer.render_xdg_popup(self, x, y, bounds)
     }
 
     fn node_client(&self) -> Option<Rc<Client>> {
@@ -412,6 +415,10 @@ impl XdgSurfaceExt for XdgPopup {
     fn tray_item(&self) -> Option<TrayItemId> {
         self.parent.get()?.tray_item()
     }
+
+    fn capture_blocked(&self) -> bool {
+        self.parent.get().is_some_and(|p| p.capture_blocked())
+    }
 }
 
 #[derive(Debug, Error)]
@@ -18,6 +18,7 @@ use {
                 WlSurface,
             },
             xdg_toplevel_drag_v1::XdgToplevelDragV1,
+            zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1,
         },
         leaks::Tracker,
         object::{Object, Version},
@@ -79,13 +80,6 @@ const CAP_MINIMIZE: u32 = 4;
 pub const WM_CAPABILITIES_SINCE: Version = Version(5);
 pub const SUSPENDED_SINCE: Version = Version(6);
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub enum Decoration {
-    #[expect(dead_code)]
-    Client,
-    Server,
-}
-
 pub struct XdgToplevel {
     pub id: XdgToplevelId,
     pub state: Rc<State>,
@@ -94,7 +88,7 @@ pub struct XdgToplevel {
     pub parent: CloneCell<Option<Rc<XdgToplevel>>>,
     pub children: RefCell<AHashMap<XdgToplevelId, Rc<XdgToplevel>>>,
     states: RefCell<AHashSet<u32>>,
-    pub decoration: Cell<Decoration>,
+    pub decoration: CloneCell<Option<Rc<ZxdgToplevelDecorationV1>>>,
     bugs: Cell<&'static Bugs>,
     min_width: Cell<Option<i32>>,
     min_height: Cell<Option<i32>>,
@@ -130,7 +124,7 @@ impl XdgToplevel {
             parent: Default::default(),
             children: RefCell::new(Default::default()),
             states: RefCell::new(states),
-            decoration: Cell::new(Decoration::Server),
+            decoration: Default::default(),
             bugs: Cell::new(&bugs::NONE),
             min_width: Cell::new(None),
             min_height: Cell::new(None),
@@ -474,6 +468,7 @@ impl Object for XdgToplevel {
         self.tl_destroy();
         self.parent.set(None);
         self.dialog.set(None);
+        self.decoration.set(None);
         let _children = mem::take(&mut *self.children.borrow_mut());
     }
 }
@@ -567,6 +562,12 @@ impl ToplevelNodeBase for XdgToplevel {
         &self.toplevel_data
     }
 
+    fn tl_decoration_mode_changed(&self) {
+        if let Some(decoration) = self.decoration.get() {
+            decoration.do_send_configure();
+        }
+    }
+
     fn tl_set_active(&self, active: bool) {
         let changed = {
             let mut states = self.states.borrow_mut();
@@ -708,6 +709,10 @@ impl XdgSurfaceExt for XdgToplevel {
             .state
             .damage(self.node_absolute_position());
     }
+
+    fn capture_blocked(&self) -> bool {
+        self.toplevel_data.capture_blocked()
+    }
 }
 
 #[derive(Debug, Error)]
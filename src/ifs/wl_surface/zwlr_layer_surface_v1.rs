@@ -621,6 +621,10 @@ impl SurfaceExt for ZwlrLayerSurfaceV1 {
             None
         }
     }
+
+    fn is_layer_surface(&self) -> bool {
+        true
+    }
 }
 
 impl Node for ZwlrLayerSurfaceV1 {
@@ -6,7 +6,7 @@ use {
         object::{Object, Version},
         wire::{zwp_idle_inhibitor_v1::*, ZwpIdleInhibitorV1Id},
     },
-    std::rc::Rc,
+    std::{cell::Cell, rc::Rc},
     thiserror::Error,
 };
 
@@ -19,6 +19,8 @@ pub struct ZwpIdleInhibitorV1 {
     pub surface: Rc<WlSurface>,
     pub tracker: Tracker<Self>,
     pub version: Version,
+    /// The time at which the inhibitor was last activated.
+    pub active_since_usec: Cell<u64>,
 }
 
 impl ZwpIdleInhibitorV1RequestHandler for ZwpIdleInhibitorV1 {
@@ -43,6 +45,7 @@ impl ZwpIdleInhibitorV1 {
     }
 
     pub fn activate(self: &Rc<Self>) {
+        self.active_since_usec.set(self.client.state.now_usec());
         self.client.state.idle.add_inhibitor(self);
     }
 
@@ -0,0 +1,89 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        ifs::{wl_seat::WlSeatGlobal, wl_surface::WlSurface},
+        leaks::Tracker,
+        object::{Object, Version},
+        wire::{zwp_keyboard_shortcuts_inhibitor_v1::*, ZwpKeyboardShortcutsInhibitorV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+    thiserror::Error,
+};
+
+pub struct ZwpKeyboardShortcutsInhibitorV1 {
+    pub id: ZwpKeyboardShortcutsInhibitorV1Id,
+    pub client: Rc<Client>,
+    pub surface: Rc<WlSurface>,
+    pub seat: Rc<WlSeatGlobal>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub active: Cell<bool>,
+    /// Whether the user has disabled the inhibitor.
+    pub disabled: Cell<bool>,
+}
+
+impl ZwpKeyboardShortcutsInhibitorV1RequestHandler for ZwpKeyboardShortcutsInhibitorV1 {
+    type Error = ZwpKeyboardShortcutsInhibitorV1Error;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.client.remove_obj(self)?;
+        self.surface.shortcuts_inhibitors.remove(&self.seat.id());
+        self.active.set(false);
+        Ok(())
+    }
+}
+
+impl ZwpKeyboardShortcutsInhibitorV1 {
+    pub fn install(self: &Rc<Self>) {
+        self.surface
+            .shortcuts_inhibitors
+            .insert(self.seat.id(), self.clone());
+        let mut focused = false;
+        self.surface
+            .seat_state
+            .for_each_kb_focus(|s| focused |= s.id() == self.seat.id());
+        if focused {
+            self.activate();
+        }
+    }
+
+    pub fn activate(&self) {
+        if self.disabled.get() || !self.seat.can_inhibit_shortcuts() || self.active.replace(true) {
+            return;
+        }
+        self.client.event(Active { self_id: self.id });
+    }
+
+    pub fn deactivate(&self) {
+        if !self.active.replace(false) {
+            return;
+        }
+        self.client.event(Inactive { self_id: self.id });
+    }
+
+    pub fn toggle(&self) {
+        let disabled = !self.disabled.get();
+        self.disabled.set(disabled);
+        if disabled {
+            self.deactivate();
+        } else {
+            self.activate();
+        }
+    }
+}
+
+object_base! {
+    self = ZwpKeyboardShortcutsInhibitorV1;
+    version = self.version;
+}
+
+impl Object for ZwpKeyboardShortcutsInhibitorV1 {}
+
+simple_add_obj!(ZwpKeyboardShortcutsInhibitorV1);
+
+#[derive(Debug, Error)]
+pub enum ZwpKeyboardShortcutsInhibitorV1Error {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(ZwpKeyboardShortcutsInhibitorV1Error, ClientError);
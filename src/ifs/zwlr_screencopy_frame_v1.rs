@@ -10,7 +10,7 @@ use {
         leaks::Tracker,
         object::{Object, Version},
         rect::Rect,
-        utils::errorfmt::ErrorFmt,
+        utils::{clonecell::CloneCell, errorfmt::ErrorFmt},
         wire::{zwlr_screencopy_frame_v1::*, WlBufferId, ZwlrScreencopyFrameV1Id},
     },
     std::{cell::Cell, ops::Deref, rc::Rc},
@@ -20,6 +20,10 @@ use {
 #[expect(dead_code)]
 pub const FLAGS_Y_INVERT: u32 = 1;
 
+/// The number of consecutive captures without a buffer after which a client is
+/// disconnected.
+pub const MAX_SCREENCOPIES_WITHOUT_BUFFER: u32 = 16;
+
 pub struct ZwlrScreencopyFrameV1 {
     pub id: ZwlrScreencopyFrameV1Id,
     pub client: Rc<Client>,
@@ -32,6 +36,7 @@ pub struct ZwlrScreencopyFrameV1 {
     pub buffer: Cell<Option<Rc<WlBuffer>>>,
     pub version: Version,
     pub pending: Cell<Option<PendingShmTransfer>>,
+    pub pending_buffer: CloneCell<Option<Rc<WlBuffer>>>,
 }
 
 impl ZwlrScreencopyFrameV1 {
@@ -48,17 +53,25 @@ impl ZwlrScreencopyFrameV1 {
         self.client.event(Failed { self_id: self.id });
     }
 
-    pub fn send_damage(&self) {
-        if let Some(output) = self.output.get() {
-            let pos = output.pos.get();
-            self.client.event(Damage {
-                self_id: self.id,
-                x: 0,
-                y: 0,
-                width: pos.width() as _,
-                height: pos.height() as _,
-            });
+    pub fn handle_missing_buffer(&self) {
+        let n = self.client.screencopies_without_buffer.fetch_add(1) + 1;
+        if n >= MAX_SCREENCOPIES_WITHOUT_BUFFER {
+            self.client
+                .error(ZwlrScreencopyFrameV1Error::TooManyCapturesWithoutBuffer(n));
+            return;
         }
+        log::warn!("Capture frame is pending but has no buffer attached");
+        self.send_failed();
+    }
+
+    pub fn send_damage(&self, rect: Rect) {
+        self.client.event(Damage {
+            self_id: self.id,
+            x: rect.x1() as _,
+            y: rect.y1() as _,
+            width: rect.width() as _,
+            height: rect.height() as _,
+        });
     }
 
     pub fn send_buffer(&self) {
@@ -135,7 +148,15 @@ impl ZwlrScreencopyFrameV1 {
             node.screencopies.remove(&(self.client.id, self.id));
             node.screencast_changed();
         }
-        self.pending.take();
+        if self.pending.take().is_some() {
+            self.clear_pending_buffer_damage();
+        }
+    }
+
+    fn clear_pending_buffer_damage(&self) {
+        if let Some(buffer) = self.pending_buffer.take() {
+            buffer.screencopy_damage_mark.take();
+        }
     }
 }
 
@@ -162,11 +183,13 @@ impl AsyncShmGfxTextureCallback for ZwlrScreencopyFrameV1 {
         self.pending.take();
         match res {
             Ok(_) => {
+                self.pending_buffer.take();
                 let now = self.client.state.now();
                 self.send_ready(now.0.tv_sec as _, now.0.tv_nsec as _);
             }
             Err(e) => {
                 log::warn!("Could not perform shm screencopy: {}", ErrorFmt(e));
+                self.clear_pending_buffer_damage();
                 self.send_failed();
             }
         }
@@ -196,6 +219,8 @@ pub enum ZwlrScreencopyFrameV1Error {
     InvalidBufferStride,
     #[error("The buffer has an invalid format")]
     InvalidBufferFormat,
+    #[error("The client requested {0} captures without attaching a buffer")]
+    TooManyCapturesWithoutBuffer(u32),
     #[error(transparent)]
     WlBufferError(Box<WlBufferError>),
     #[error(transparent)]
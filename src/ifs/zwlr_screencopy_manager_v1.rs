@@ -131,6 +131,7 @@ impl ZwlrScreencopyManagerV1 {
             buffer: Cell::new(None),
             version: self.version,
             pending: Default::default(),
+            pending_buffer: Default::default(),
         });
         track!(self.client, frame);
         self.client.add_client_obj(&frame)?;
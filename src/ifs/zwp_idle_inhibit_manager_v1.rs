@@ -83,6 +83,7 @@ impl ZwpIdleInhibitManagerV1RequestHandler for ZwpIdleInhibitManagerV1 {
             surface,
             tracker: Default::default(),
             version: self.version,
+            active_since_usec: Default::default(),
         });
         track!(self.client, inhibit);
         self.client.add_client_obj(&inhibit)?;
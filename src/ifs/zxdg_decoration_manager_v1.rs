@@ -85,6 +85,7 @@ impl ZxdgDecorationManagerV1RequestHandler for ZxdgDecorationManagerV1 {
         ));
         track!(self.client, obj);
         self.client.add_client_obj(&obj)?;
+        tl.decoration.set(Some(obj.clone()));
         obj.do_send_configure();
         Ok(())
     }
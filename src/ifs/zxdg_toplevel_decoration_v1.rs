@@ -1,9 +1,10 @@
 use {
     crate::{
         client::{Client, ClientError},
-        ifs::wl_surface::xdg_surface::xdg_toplevel::{Decoration, XdgToplevel},
+        ifs::wl_surface::xdg_surface::xdg_toplevel::XdgToplevel,
         leaks::Tracker,
         object::{Object, Version},
+        tree::{DecorationMode, ToplevelNodeBase},
         wire::{zxdg_toplevel_decoration_v1::*, ZxdgToplevelDecorationV1Id},
     },
     std::rc::Rc,
@@ -45,9 +46,9 @@ impl ZxdgToplevelDecorationV1 {
     }
 
     pub fn do_send_configure(&self) {
-        let mode = match self.toplevel.decoration.get() {
-            Decoration::Client => CLIENT_SIDE,
-            Decoration::Server => SERVER_SIDE,
+        let mode = match self.toplevel.tl_data().decoration_mode.get() {
+            DecorationMode::Client => CLIENT_SIDE,
+            DecorationMode::Server | DecorationMode::None => SERVER_SIDE,
         };
         self.send_configure(mode);
         self.toplevel.send_current_configure();
@@ -58,6 +59,7 @@ impl ZxdgToplevelDecorationV1RequestHandler for ZxdgToplevelDecorationV1 {
     type Error = ZxdgToplevelDecorationV1Error;
 
     fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.toplevel.decoration.take();
         self.client.remove_obj(self)?;
         Ok(())
     }
@@ -78,7 +80,11 @@ object_base! {
     version = self.version;
 }
 
-impl Object for ZxdgToplevelDecorationV1 {}
+impl Object for ZxdgToplevelDecorationV1 {
+    fn break_loops(&self) {
+        self.toplevel.decoration.take();
+    }
+}
 
 simple_add_obj!(ZxdgToplevelDecorationV1);
 
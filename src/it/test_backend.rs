@@ -4,14 +4,14 @@ use {
         async_engine::SpawnedFuture,
         backend::{
             AxisSource, Backend, BackendEvent, Connector, ConnectorEvent, ConnectorId,
-            ConnectorKernelId, DrmDeviceId, InputDevice, InputDeviceAccelProfile,
-            InputDeviceCapability, InputDeviceId, InputEvent, KeyState, Mode, MonitorInfo,
-            ScrollAxis, TransformMatrix,
+            ConnectorKernelId, DrmDeviceId, GammaRamp, HardwareCursor, HardwareCursorUpdate,
+            InputDevice, InputDeviceAccelProfile, InputDeviceCapability, InputDeviceId, InputEvent,
+            KeyState, Mode, MonitorInfo, ScrollAxis, TransformMatrix,
         },
         compositor::TestFuture,
         drm_feedback::DrmFeedback,
         fixed::Fixed,
-        gfx_api::GfxError,
+        gfx_api::{GfxError, GfxFramebuffer, SyncFile},
         gfx_apis::create_vulkan_allocator,
         ifs::wl_output::OutputId,
         it::{
@@ -20,7 +20,7 @@ use {
         state::State,
         udmabuf::Udmabuf,
         utils::{
-            clonecell::CloneCell, copyhashmap::CopyHashMap, errorfmt::ErrorFmt,
+            clonecell::CloneCell, copyhashmap::CopyHashMap, errorfmt::ErrorFmt, numcell::NumCell,
             on_change::OnChange, oserror::OsError, syncqueue::SyncQueue,
         },
         video::{
@@ -76,6 +76,11 @@ impl TestBackend {
             },
             events: Default::default(),
             feedback: Default::default(),
+            gamma_size: Default::default(),
+            gamma_ramp: Default::default(),
+            power: Cell::new(true),
+            render_scale: Cell::new(1.0),
+            flip_margin: Default::default(),
         });
         let default_mouse = Rc::new(TestBackendMouse {
             common: TestInputDeviceCommon {
@@ -95,6 +100,9 @@ impl TestBackend {
             accel_speed: Cell::new(1.0),
             accel_profile: Cell::new(InputDeviceAccelProfile::Flat),
             left_handed: Cell::new(false),
+            tap_enabled: Cell::new(false),
+            drag_enabled: Cell::new(false),
+            drag_lock_enabled: Cell::new(false),
         });
         let default_kb = Rc::new(TestBackendKb {
             common: TestInputDeviceCommon {
@@ -172,6 +180,28 @@ impl TestBackend {
         Ok(())
     }
 
+    pub fn add_touch(&self) -> Rc<TestBackendTouch> {
+        let touch = Rc::new(TestBackendTouch {
+            common: TestInputDeviceCommon {
+                id: self.state.input_device_ids.next(),
+                removed: Cell::new(false),
+                events: Default::default(),
+                on_change: Default::default(),
+                capabilities: {
+                    let chm = CopyHashMap::new();
+                    chm.set(InputDeviceCapability::Touch, ());
+                    chm
+                },
+                name: Rc::new("touch".to_string()),
+                state: self.state.clone(),
+            },
+        });
+        self.state
+            .backend_events
+            .push(BackendEvent::NewInputDevice(touch.clone()));
+        touch
+    }
+
     fn create_render_context(&self, need_drm: bool) -> Result<(), TestBackendError> {
         macro_rules! constructor {
             ($c:expr) => {
@@ -297,11 +327,79 @@ impl Backend for TestBackend {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct TestHardwareCursor {
+    pub damaged: NumCell<u32>,
+}
+
+impl HardwareCursor for TestHardwareCursor {
+    fn damage(&self) {
+        self.damaged.fetch_add(1);
+    }
+}
+
+pub struct TestHardwareCursorUpdate {
+    pub buffer: Rc<dyn GfxFramebuffer>,
+    pub size: (i32, i32),
+    pub enabled: bool,
+    pub position: (i32, i32),
+    pub hotspot: (i32, i32),
+    pub swapped: bool,
+}
+
+impl TestHardwareCursorUpdate {
+    pub fn new(buffer: Rc<dyn GfxFramebuffer>, width: i32, height: i32) -> Self {
+        Self {
+            buffer,
+            size: (width, height),
+            enabled: false,
+            position: (0, 0),
+            hotspot: (0, 0),
+            swapped: false,
+        }
+    }
+}
+
+impl HardwareCursorUpdate for TestHardwareCursorUpdate {
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn get_buffer(&self) -> Rc<dyn GfxFramebuffer> {
+        self.buffer.clone()
+    }
+
+    fn set_position(&mut self, x: i32, y: i32) {
+        self.position = (x, y);
+    }
+
+    fn set_hotspot(&mut self, x: i32, y: i32) {
+        self.hotspot = (x, y);
+    }
+
+    fn swap_buffer(&mut self) {
+        self.swapped = true;
+    }
+
+    fn set_sync_file(&mut self, _sync_file: Option<SyncFile>) {
+        // nothing
+    }
+
+    fn size(&self) -> (i32, i32) {
+        self.size
+    }
+}
+
 pub struct TestConnector {
     pub id: ConnectorId,
     pub kernel_id: ConnectorKernelId,
     pub events: OnChange<ConnectorEvent>,
     pub feedback: CloneCell<Option<Rc<DrmFeedback>>>,
+    pub gamma_size: Cell<Option<usize>>,
+    pub gamma_ramp: CloneCell<Option<Rc<GammaRamp>>>,
+    pub power: Cell<bool>,
+    pub render_scale: Cell<f64>,
+    pub flip_margin: Cell<Option<u64>>,
 }
 
 impl Connector for TestConnector {
@@ -329,8 +427,28 @@ impl Connector for TestConnector {
         None
     }
 
-    fn set_mode(&self, _mode: Mode) {
-        // todo
+    fn gamma_size(&self) -> Option<usize> {
+        self.gamma_size.get()
+    }
+
+    fn set_gamma_ramp(&self, ramp: Option<Rc<GammaRamp>>) {
+        self.gamma_ramp.set(ramp);
+    }
+
+    fn set_power(&self, on: bool) {
+        self.power.set(on);
+    }
+
+    fn set_render_scale(&self, scale: f64) {
+        self.render_scale.set(scale);
+    }
+
+    fn set_flip_margin(&self, margin: Option<u64>) {
+        self.flip_margin.set(margin);
+    }
+
+    fn set_mode(&self, mode: Mode) {
+        self.events.send_event(ConnectorEvent::ModeChanged(mode));
     }
 
     fn drm_feedback(&self) -> Option<Rc<DrmFeedback>> {
@@ -359,6 +477,9 @@ pub struct TestBackendMouse {
     pub accel_speed: Cell<f64>,
     pub accel_profile: Cell<InputDeviceAccelProfile>,
     pub left_handed: Cell<bool>,
+    pub tap_enabled: Cell<bool>,
+    pub drag_enabled: Cell<bool>,
+    pub drag_lock_enabled: Cell<bool>,
 }
 
 impl TestBackendMouse {
@@ -393,6 +514,30 @@ impl TestBackendMouse {
         }
     }
 
+    pub fn swipe_begin(&self, finger_count: u32) {
+        self.common.event(InputEvent::SwipeBegin {
+            time_usec: self.common.state.now_usec(),
+            finger_count,
+        });
+    }
+
+    pub fn swipe_update(&self, dx: f64, dy: f64) {
+        self.common.event(InputEvent::SwipeUpdate {
+            time_usec: self.common.state.now_usec(),
+            dx: Fixed::from_f64(dx),
+            dy: Fixed::from_f64(dy),
+            dx_unaccelerated: Fixed::from_f64(dx),
+            dy_unaccelerated: Fixed::from_f64(dy),
+        });
+    }
+
+    pub fn swipe_end(&self, cancelled: bool) {
+        self.common.event(InputEvent::SwipeEnd {
+            time_usec: self.common.state.now_usec(),
+            cancelled,
+        });
+    }
+
     pub fn scroll(&self, dy: i32) {
         self.common.event(InputEvent::AxisSource {
             source: AxisSource::Wheel,
@@ -459,6 +604,49 @@ impl TestBackendKb {
     }
 }
 
+pub struct TestBackendTouch {
+    pub common: TestInputDeviceCommon,
+}
+
+impl TestBackendTouch {
+    pub fn down(&self, id: i32, x_normed: f64, y_normed: f64) {
+        self.common.event(InputEvent::TouchDown {
+            time_usec: self.common.state.now_usec(),
+            id,
+            x_normed: Fixed::from_f64(x_normed),
+            y_normed: Fixed::from_f64(y_normed),
+        });
+    }
+
+    pub fn motion(&self, id: i32, x_normed: f64, y_normed: f64) {
+        self.common.event(InputEvent::TouchMotion {
+            time_usec: self.common.state.now_usec(),
+            id,
+            x_normed: Fixed::from_f64(x_normed),
+            y_normed: Fixed::from_f64(y_normed),
+        });
+    }
+
+    pub fn up(&self, id: i32) {
+        self.common.event(InputEvent::TouchUp {
+            time_usec: self.common.state.now_usec(),
+            id,
+        });
+    }
+
+    pub fn frame(&self) {
+        self.common.event(InputEvent::TouchFrame {
+            time_usec: self.common.state.now_usec(),
+        });
+    }
+}
+
+impl TestInputDevice for TestBackendTouch {
+    fn common(&self) -> &TestInputDeviceCommon {
+        &self.common
+    }
+}
+
 impl TestInputDevice for TestBackendKb {
     fn common(&self) -> &TestInputDeviceCommon {
         &self.common
@@ -485,6 +673,18 @@ impl TestInputDevice for TestBackendMouse {
     fn set_transform_matrix(&self, matrix: TransformMatrix) {
         self.transform_matrix.set(matrix);
     }
+
+    fn set_tap_enabled(&self, enabled: bool) {
+        self.tap_enabled.set(enabled)
+    }
+
+    fn set_drag_enabled(&self, enabled: bool) {
+        self.drag_enabled.set(enabled)
+    }
+
+    fn set_drag_lock_enabled(&self, enabled: bool) {
+        self.drag_lock_enabled.set(enabled)
+    }
 }
 
 pub struct TestInputDeviceCommon {
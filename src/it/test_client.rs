@@ -9,8 +9,9 @@ use {
                 test_compositor::TestCompositor, test_cursor_shape_manager::TestCursorShapeManager,
                 test_data_device_manager::TestDataDeviceManager,
                 test_jay_compositor::TestJayCompositor, test_keyboard::TestKeyboard,
-                test_pointer::TestPointer, test_registry::TestRegistry, test_seat::TestSeat,
-                test_shm::TestShm, test_single_pixel_buffer_manager::TestSinglePixelBufferManager,
+                test_output::TestOutput, test_pointer::TestPointer, test_registry::TestRegistry,
+                test_seat::TestSeat, test_shm::TestShm,
+                test_single_pixel_buffer_manager::TestSinglePixelBufferManager,
                 test_subcompositor::TestSubcompositor, test_viewporter::TestViewporter,
                 test_xdg_activation::TestXdgActivation, test_xdg_base::TestXdgWmBase,
             },
@@ -19,13 +20,14 @@ use {
             testrun::TestRun,
         },
         theme::Color,
+        tree::OutputNode,
     },
     std::{cell::Cell, rc::Rc},
 };
 
 pub struct TestClient {
     pub run: Rc<TestRun>,
-    pub _server: Rc<Client>,
+    pub server: Rc<Client>,
     pub tran: Rc<TestTransport>,
     pub registry: Rc<TestRegistry>,
     pub jc: Rc<TestJayCompositor>,
@@ -53,14 +55,18 @@ impl TestClient {
     }
 
     pub async fn get_default_seat(&self) -> TestResult<DefaultSeat> {
+        self.get_seat("default").await
+    }
+
+    pub async fn get_seat(&self, name: &str) -> TestResult<DefaultSeat> {
         self.tran.sync().await;
         let seat = 'get_seat: {
             for seat in self.tran.run.state.globals.seats.lock().values() {
-                if seat.seat_name() == "default" {
+                if seat.seat_name() == name {
                     break 'get_seat seat.clone();
                 }
             }
-            bail!("Default seat not found");
+            bail!("Seat {} not found", name);
         };
         let id = self.tran.id();
         let tseat = Rc::new(TestSeat {
@@ -90,6 +96,12 @@ impl TestClient {
         self.run.state.idle().await;
     }
 
+    pub fn get_output(&self, output: &OutputNode) -> TestResult<Rc<TestOutput>> {
+        let obj = Rc::new(TestOutput { id: self.tran.id() });
+        self.registry.bind(&obj, output.global.name.raw(), 1)?;
+        Ok(obj)
+    }
+
     pub async fn take_screenshot(&self, include_cursor: bool) -> Result<Vec<u8>, TestError> {
         let (dmabuf, dev) = self.jc.take_screenshot(include_cursor).await?;
         let qoi = buf_to_bytes(dev.as_ref(), &dmabuf, ScreenshotFormat::Qoi)?;
@@ -11,12 +11,17 @@ use {
     jay_config::{
         _private::{
             bincode_ops,
-            ipc::{ClientMessage, Response, ServerMessage},
+            ipc::{ClientMessage, Response, ServerMessage, WorkspaceSource},
             ConfigEntry, VERSION,
         },
-        input::{InputDevice, Seat},
-        keyboard::{Keymap, ModifiedKeySym},
-        video::{Connector, Transform},
+        input::{
+            acceleration::AccelProfile, DesktopClick, HotCornerEvent, InputDevice, OutputEdge,
+            PointerEdgeBehavior, PointerPosition, PointerTrigger, Seat,
+        },
+        keyboard::{mods::Modifiers, Keymap, ModifiedKeySym},
+        theme::{colors::Colorable, sized::Resizable, Color},
+        video::{Connector, OutputId, TearingMode, TitleBarPosition, Transform, VrrMode},
+        window::DecorationMode,
         Axis, Direction,
     },
     std::{cell::Cell, ops::Deref, ptr, rc::Rc, time::Duration},
@@ -41,6 +46,12 @@ where
         srv: Cell::new(None),
         responses: Default::default(),
         invoked_shortcuts: Default::default(),
+        invoked_pointer_shortcuts: Default::default(),
+        desktop_clicks: Default::default(),
+        keyboard_layouts: Default::default(),
+        invoked_key_sequences: Default::default(),
+        idle_times: Default::default(),
+        hot_corners: Default::default(),
         graphics_initialized: Cell::new(false),
     });
     let old = CONFIG.get();
@@ -125,6 +136,32 @@ unsafe extern "C" fn handle_msg(data: *const u8, msg: *const u8, size: usize) {
         ServerMessage::InterestReady { .. } => {}
         ServerMessage::Features { .. } => {}
         ServerMessage::SwitchEvent { .. } => {}
+        ServerMessage::DesktopClick { seat, click } => {
+            tc.desktop_clicks
+                .push((SeatId::from_raw(seat.0 as _), click));
+        }
+        ServerMessage::KeyboardLayoutChanged { seat, name } => {
+            tc.keyboard_layouts
+                .push((SeatId::from_raw(seat.0 as _), name));
+        }
+        ServerMessage::InvokeKeySequence { seat, keys } => {
+            tc.invoked_key_sequences
+                .push((SeatId::from_raw(seat.0 as _), keys));
+        }
+        ServerMessage::IdleTime { seat, idle } => {
+            tc.idle_times.push((SeatId::from_raw(seat.0 as _), idle));
+        }
+        ServerMessage::HotCorner { seat, event } => {
+            tc.hot_corners.push((SeatId::from_raw(seat.0 as _), event));
+        }
+        ServerMessage::InvokePointerShortcut {
+            seat,
+            mods,
+            trigger,
+        } => {
+            tc.invoked_pointer_shortcuts
+                .set((SeatId::from_raw(seat.0 as _), mods, trigger), ());
+        }
     }
 }
 
@@ -139,6 +176,12 @@ pub struct TestConfig {
     srv: Cell<Option<ServerData>>,
     responses: Stack<Response>,
     pub invoked_shortcuts: CopyHashMap<(SeatId, ModifiedKeySym), ()>,
+    pub invoked_pointer_shortcuts: CopyHashMap<(SeatId, Modifiers, PointerTrigger), ()>,
+    pub desktop_clicks: Stack<(SeatId, DesktopClick)>,
+    pub keyboard_layouts: Stack<(SeatId, String)>,
+    pub invoked_key_sequences: Stack<(SeatId, Vec<ModifiedKeySym>)>,
+    pub idle_times: Stack<(SeatId, Duration)>,
+    pub hot_corners: Stack<(SeatId, HotCornerEvent)>,
     pub graphics_initialized: Cell<bool>,
 }
 
@@ -214,6 +257,79 @@ impl TestConfig {
         })
     }
 
+    pub fn set_layouts(&self, seat: SeatId, layouts: &[Keymap]) -> TestResult {
+        self.send(ClientMessage::SeatSetLayouts {
+            seat: Seat(seat.raw() as _),
+            layouts: layouts.to_vec(),
+        })
+    }
+
+    pub fn get_keyboard_layout(&self, seat: SeatId) -> TestResult<String> {
+        let reply = self.send_with_reply(ClientMessage::SeatGetKeyboardLayout {
+            seat: Seat(seat.raw() as _),
+        })?;
+        get_response!(reply, GetKeyboardLayout { name });
+        Ok(name)
+    }
+
+    pub fn get_idle_time(&self, seat: SeatId) -> TestResult<Duration> {
+        let reply = self.send_with_reply(ClientMessage::SeatGetIdleTime {
+            seat: Seat(seat.raw() as _),
+        })?;
+        get_response!(reply, GetIdleTime { idle });
+        Ok(idle)
+    }
+
+    pub fn set_idle_time_interval(&self, seat: SeatId, interval: Duration) -> TestResult {
+        self.send(ClientMessage::SeatSetIdleTimeInterval {
+            seat: Seat(seat.raw() as _),
+            interval,
+        })
+    }
+
+    pub fn move_workspace_to_output(&self, seat: SeatId, output: &OutputNode) -> TestResult {
+        self.send(ClientMessage::MoveToOutput {
+            workspace: WorkspaceSource::Seat(Seat(seat.raw() as _)),
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+        })
+    }
+
+    pub fn get_pointer_position(&self, seat: SeatId) -> TestResult<PointerPosition> {
+        let reply = self.send_with_reply(ClientMessage::SeatGetPointerPosition {
+            seat: Seat(seat.raw() as _),
+        })?;
+        get_response!(reply, GetPointerPosition { position });
+        Ok(position)
+    }
+
+    pub fn set_hot_corner_size(&self, seat: SeatId, size: i32) -> TestResult {
+        self.send(ClientMessage::SetHotCornerSize {
+            seat: Seat(seat.raw() as _),
+            size,
+        })
+    }
+
+    pub fn set_hot_corner_dwell(&self, seat: SeatId, dwell: Duration) -> TestResult {
+        self.send(ClientMessage::SetHotCornerDwell {
+            seat: Seat(seat.raw() as _),
+            dwell,
+        })
+    }
+
+    pub fn set_pointer_edge_behavior(
+        &self,
+        edge: OutputEdge,
+        behavior: PointerEdgeBehavior,
+    ) -> TestResult {
+        self.send(ClientMessage::SetPointerEdgeBehavior { edge, behavior })
+    }
+
+    pub fn cycle_layout(&self, seat: SeatId) -> TestResult {
+        self.send(ClientMessage::SeatCycleLayout {
+            seat: Seat(seat.raw() as _),
+        })
+    }
+
     pub fn create_split(&self, seat: SeatId, axis: Axis) -> TestResult {
         self.send(ClientMessage::CreateSplit {
             seat: Seat(seat.raw() as _),
@@ -241,6 +357,33 @@ impl TestConfig {
         })
     }
 
+    pub fn add_key_sequence(&self, seat: SeatId, keys: &[ModifiedKeySym]) -> TestResult {
+        self.send(ClientMessage::AddKeySequence {
+            seat: Seat(seat.raw() as _),
+            keys: keys.to_vec(),
+        })
+    }
+
+    pub fn set_forward_aborted_key_sequences(&self, seat: SeatId, forward: bool) -> TestResult {
+        self.send(ClientMessage::SetForwardAbortedKeySequences {
+            seat: Seat(seat.raw() as _),
+            forward,
+        })
+    }
+
+    pub fn add_pointer_shortcut(
+        &self,
+        seat: SeatId,
+        mods: Modifiers,
+        trigger: PointerTrigger,
+    ) -> TestResult {
+        self.send(ClientMessage::AddPointerShortcut {
+            seat: Seat(seat.raw() as _),
+            mods,
+            trigger,
+        })
+    }
+
     pub fn set_input_device_seat(&self, id: InputDeviceId, seat: SeatId) -> Result<(), TestError> {
         self.send(ClientMessage::SetSeat {
             device: InputDevice(id.raw() as _),
@@ -248,6 +391,91 @@ impl TestConfig {
         })
     }
 
+    pub fn set_device_repeat_rate(
+        &self,
+        id: InputDeviceId,
+        rate: Option<(i32, i32)>,
+    ) -> TestResult {
+        self.send(ClientMessage::DeviceSetRepeatRate {
+            device: InputDevice(id.raw() as _),
+            rate,
+        })
+    }
+
+    pub fn set_accel_profile(&self, id: InputDeviceId, profile: AccelProfile) -> TestResult {
+        self.send(ClientMessage::SetAccelProfile {
+            device: InputDevice(id.raw() as _),
+            profile,
+        })
+    }
+
+    pub fn set_accel_speed(&self, id: InputDeviceId, speed: f64) -> TestResult {
+        self.send(ClientMessage::SetAccelSpeed {
+            device: InputDevice(id.raw() as _),
+            speed,
+        })
+    }
+
+    pub fn set_workspace_swipe(&self, seat: SeatId, fingers: u32, inverted: bool) -> TestResult {
+        self.send(ClientMessage::SetWorkspaceSwipe {
+            seat: Seat(seat.raw() as _),
+            fingers,
+            inverted,
+        })
+    }
+
+    pub fn set_dnd_threshold(&self, seat: SeatId, threshold: i32) -> TestResult {
+        self.send(ClientMessage::SetDndThreshold {
+            seat: Seat(seat.raw() as _),
+            threshold,
+        })
+    }
+
+    pub fn set_which_key_delay(&self, seat: SeatId, delay: Option<Duration>) -> TestResult {
+        self.send(ClientMessage::SetWhichKeyDelay {
+            seat: Seat(seat.raw() as _),
+            delay,
+        })
+    }
+
+    pub fn set_middle_click_paste_enabled(&self, seat: SeatId, enabled: bool) -> TestResult {
+        self.send(ClientMessage::SetMiddleClickPasteEnabled {
+            seat: Seat(seat.raw() as _),
+            enabled,
+        })
+    }
+
+    pub fn locate_pointer(&self, seat: SeatId) -> TestResult {
+        self.send(ClientMessage::LocatePointer {
+            seat: Seat(seat.raw() as _),
+        })
+    }
+
+    pub fn set_pointer_locator_duration(&self, duration: Duration) -> TestResult {
+        self.send(ClientMessage::SetPointerLocatorDuration { duration })
+    }
+
+    pub fn set_tap_enabled(&self, id: InputDeviceId, enabled: bool) -> TestResult {
+        self.send(ClientMessage::SetTapEnabled {
+            device: InputDevice(id.raw() as _),
+            enabled,
+        })
+    }
+
+    pub fn set_drag_enabled(&self, id: InputDeviceId, enabled: bool) -> TestResult {
+        self.send(ClientMessage::SetDragEnabled {
+            device: InputDevice(id.raw() as _),
+            enabled,
+        })
+    }
+
+    pub fn set_drag_lock_enabled(&self, id: InputDeviceId, enabled: bool) -> TestResult {
+        self.send(ClientMessage::SetDragLockEnabled {
+            device: InputDevice(id.raw() as _),
+            enabled,
+        })
+    }
+
     pub fn focus(&self, seat: SeatId, direction: Direction) -> TestResult {
         self.send(ClientMessage::Focus {
             seat: Seat(seat.raw() as _),
@@ -266,6 +494,112 @@ impl TestConfig {
         self.send(ClientMessage::SetIdle { timeout })
     }
 
+    pub fn set_idle_grace_period(&self, period: Duration) -> TestResult {
+        self.send(ClientMessage::SetIdleGracePeriod { period })
+    }
+
+    pub fn set_seat_idle_grace_period(&self, seat: SeatId, period: Option<Duration>) -> TestResult {
+        self.send(ClientMessage::SeatSetIdleGracePeriod {
+            seat: Seat(seat.raw() as _),
+            period,
+        })
+    }
+
+    pub fn set_output_throttle(&self, hz: f64) -> TestResult {
+        self.send(ClientMessage::SetOutputThrottle { hz })
+    }
+
+    pub fn set_output_throttle_delay(&self, delay: Duration) -> TestResult {
+        self.send(ClientMessage::SetOutputThrottleDelay { delay })
+    }
+
+    pub fn set_focus_follows_mouse_dwell(&self, seat: SeatId, dwell: Duration) -> TestResult {
+        self.send(ClientMessage::SetFocusFollowsMouseDwell {
+            seat: Seat(seat.raw() as _),
+            dwell,
+        })
+    }
+
+    pub fn set_cursor_theme(&self, seat: SeatId, theme: Option<&str>) -> TestResult {
+        self.send(ClientMessage::SetCursorTheme {
+            seat: Seat(seat.raw() as _),
+            theme,
+        })
+    }
+
+    pub fn set_hide_cursor_while_typing(&self, seat: SeatId, hide: bool) -> TestResult {
+        self.send(ClientMessage::SetHideCursorWhileTyping {
+            seat: Seat(seat.raw() as _),
+            hide,
+        })
+    }
+
+    pub fn set_hide_cursor_timeout(&self, seat: SeatId, timeout: Duration) -> TestResult {
+        self.send(ClientMessage::SetHideCursorTimeout {
+            seat: Seat(seat.raw() as _),
+            timeout,
+        })
+    }
+
+    pub fn set_warp_pointer_on_focus(&self, seat: SeatId, warp: bool) -> TestResult {
+        self.send(ClientMessage::SetWarpPointerOnFocus {
+            seat: Seat(seat.raw() as _),
+            warp,
+        })
+    }
+
+    pub fn set_shortcuts_inhibit_escape<T: Into<ModifiedKeySym>>(
+        &self,
+        seat: SeatId,
+        key: T,
+    ) -> TestResult {
+        self.send(ClientMessage::SetShortcutsInhibitEscape {
+            seat: Seat(seat.raw() as _),
+            escape: Some(key.into()),
+        })
+    }
+
+    pub fn unset_shortcuts_inhibit_escape(&self, seat: SeatId) -> TestResult {
+        self.send(ClientMessage::SetShortcutsInhibitEscape {
+            seat: Seat(seat.raw() as _),
+            escape: None,
+        })
+    }
+
+    pub fn set_zoom_scroll_modifiers(&self, seat: SeatId, mods: Modifiers) -> TestResult {
+        self.send(ClientMessage::SetZoomScrollModifiers {
+            seat: Seat(seat.raw() as _),
+            mods,
+        })
+    }
+
+    pub fn set_workspace_scroll_inverted(&self, inverted: bool) -> TestResult {
+        self.send(ClientMessage::SetWorkspaceScrollInverted { inverted })
+    }
+
+    pub fn set_workspace_scroll_threshold(&self, threshold: i32) -> TestResult {
+        self.send(ClientMessage::SetWorkspaceScrollThreshold { threshold })
+    }
+
+    pub fn set_workspace_scroll_wrap(&self, wrap: bool) -> TestResult {
+        self.send(ClientMessage::SetWorkspaceScrollWrap { wrap })
+    }
+
+    pub fn set_workspace_scroll_skip_empty(&self, skip_empty: bool) -> TestResult {
+        self.send(ClientMessage::SetWorkspaceScrollSkipEmpty { skip_empty })
+    }
+
+    pub fn connector_set_workspace_scroll_skip_empty(
+        &self,
+        output: &OutputNode,
+        skip_empty: Option<bool>,
+    ) -> TestResult {
+        self.send(ClientMessage::ConnectorSetWorkspaceScrollSkipEmpty {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            skip_empty,
+        })
+    }
+
     pub fn set_floating(&self, seat: SeatId, floating: bool) -> TestResult {
         self.send(ClientMessage::SetFloating {
             seat: Seat(seat.raw() as _),
@@ -273,6 +607,13 @@ impl TestConfig {
         })
     }
 
+    pub fn set_sticky(&self, seat: SeatId, sticky: bool) -> TestResult {
+        self.send(ClientMessage::SetSticky {
+            seat: Seat(seat.raw() as _),
+            sticky,
+        })
+    }
+
     fn clear(&self) {
         unsafe {
             if let Some(srv) = self.srv.take() {
@@ -281,6 +622,24 @@ impl TestConfig {
         }
     }
 
+    pub fn set_title_bar_position(
+        &self,
+        output: &OutputNode,
+        position: TitleBarPosition,
+    ) -> TestResult {
+        self.send(ClientMessage::ConnectorSetTitleBarPosition {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            position,
+        })
+    }
+
+    pub fn set_render_scale(&self, output: &OutputNode, scale: f64) -> TestResult {
+        self.send(ClientMessage::ConnectorSetRenderScale {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            scale,
+        })
+    }
+
     pub fn set_scale(&self, output: &OutputNode, scale: f64) -> TestResult {
         self.send(ClientMessage::ConnectorSetScale {
             connector: Connector(output.global.connector.connector.id().raw() as _),
@@ -288,12 +647,167 @@ impl TestConfig {
         })
     }
 
+    pub fn set_vrr_min_refresh(&self, output: &OutputNode, hz: Option<u32>) -> TestResult {
+        self.send(ClientMessage::ConnectorSetVrrMinRefresh {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            hz,
+        })
+    }
+
+    pub fn set_max_scale(&self, output: &OutputNode, scale: Option<f64>) -> TestResult {
+        self.send(ClientMessage::ConnectorSetMaxScale {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            scale,
+        })
+    }
+
+    pub fn set_vblank_cursor_updates(&self, output: &OutputNode, enabled: bool) -> TestResult {
+        self.send(ClientMessage::ConnectorSetVblankCursorUpdates {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            enabled,
+        })
+    }
+
+    pub fn set_use_hardware_cursor(
+        &self,
+        output: &OutputNode,
+        use_hardware_cursor: bool,
+    ) -> TestResult {
+        self.send(ClientMessage::ConnectorSetUseHardwareCursor {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            use_hardware_cursor,
+        })
+    }
+
+    pub fn set_invert_colors(&self, output: &OutputNode, invert: bool) -> TestResult {
+        self.send(ClientMessage::ConnectorSetInvertColors {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            invert,
+        })
+    }
+
+    pub fn toggle_invert_colors(&self, seat: SeatId) -> TestResult {
+        self.send(ClientMessage::SeatToggleInvertColors {
+            seat: Seat(seat.raw() as _),
+        })
+    }
+
+    pub fn hardware_cursor_active(&self, output: &OutputNode) -> TestResult<bool> {
+        let reply = self.send_with_reply(ClientMessage::ConnectorHardwareCursorActive {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+        })?;
+        get_response!(reply, ConnectorHardwareCursorActive { active });
+        Ok(active)
+    }
+
     pub fn set_output_transform(&self, output: &OutputNode, transform: Transform) -> TestResult {
         self.send(ClientMessage::ConnectorSetTransform {
             connector: Connector(output.global.connector.connector.id().raw() as _),
             transform,
         })
     }
+
+    pub fn set_default_workspace_capture(&self, output: &OutputNode, capture: bool) -> TestResult {
+        self.send(ClientMessage::ConnectorSetDefaultWorkspaceCapture {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            capture,
+        })
+    }
+
+    pub fn set_span(&self, output: &OutputNode, secondary: Option<&OutputNode>) -> TestResult {
+        self.send(ClientMessage::ConnectorSetSpan {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            secondary: secondary.map(|s| Connector(s.global.connector.connector.id().raw() as _)),
+        })
+    }
+
+    pub fn set_mirror_source(
+        &self,
+        output: &OutputNode,
+        source: Option<&OutputNode>,
+    ) -> TestResult {
+        self.send(ClientMessage::ConnectorSetMirrorSource {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            source: source.map(|s| Connector(s.global.connector.connector.id().raw() as _)),
+        })
+    }
+
+    pub fn set_vrr_mode(&self, output: &OutputNode, mode: VrrMode) -> TestResult {
+        self.send(ClientMessage::SetVrrMode {
+            connector: Some(Connector(output.global.connector.connector.id().raw() as _)),
+            mode,
+        })
+    }
+
+    pub fn set_tearing_mode(&self, output: &OutputNode, mode: TearingMode) -> TestResult {
+        self.send(ClientMessage::SetTearingMode {
+            connector: Some(Connector(output.global.connector.connector.id().raw() as _)),
+            mode,
+        })
+    }
+
+    pub fn set_game_mode(&self, output: &OutputNode, enabled: bool) -> TestResult {
+        self.send(ClientMessage::ConnectorSetGameMode {
+            connector: Connector(output.global.connector.connector.id().raw() as _),
+            enabled,
+        })
+    }
+
+    pub fn set_block_capture(&self, app_id: &str, block: bool) -> TestResult {
+        self.send(ClientMessage::SetBlockCapture { app_id, block })
+    }
+
+    pub fn set_scale_override(&self, app_id: &str, scale: Option<f64>) -> TestResult {
+        self.send(ClientMessage::SetScaleOverride { app_id, scale })
+    }
+
+    pub fn set_decoration_mode(&self, app_id: &str, mode: Option<DecorationMode>) -> TestResult {
+        self.send(ClientMessage::SetDecorationMode { app_id, mode })
+    }
+
+    pub fn set_show_focused_output(&self, show: bool) -> TestResult {
+        self.send(ClientMessage::SetShowFocusedOutput { show })
+    }
+
+    pub fn set_window_border_width(&self, app_id: &str, width: Option<i32>) -> TestResult {
+        self.send(ClientMessage::SetWindowBorderWidth { app_id, width })
+    }
+
+    pub fn set_window_border_color(
+        &self,
+        app_id: &str,
+        colorable: Colorable,
+        color: Option<Color>,
+    ) -> TestResult {
+        self.send(ClientMessage::SetWindowBorderColor {
+            app_id,
+            colorable,
+            color,
+        })
+    }
+
+    pub fn reset_window_rules(&self) -> TestResult {
+        self.send(ClientMessage::ResetWindowRules)
+    }
+
+    pub fn set_workspace_output_affinity(
+        &self,
+        name: &str,
+        preferred: Option<OutputId>,
+        fallback: Option<OutputId>,
+    ) -> TestResult {
+        let reply = self.send_with_reply(ClientMessage::GetWorkspace { name })?;
+        get_response!(reply, GetWorkspace { workspace });
+        self.send(ClientMessage::SetWorkspaceOutputAffinity {
+            workspace,
+            preferred,
+            fallback,
+        })
+    }
+
+    pub fn set_size(&self, sized: Resizable, size: i32) -> TestResult {
+        self.send(ClientMessage::SetSize { sized, size })
+    }
 }
 
 impl Drop for TestConfig {
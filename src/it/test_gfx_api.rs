@@ -489,6 +489,9 @@ impl GfxFramebuffer for TestGfxFb {
                             if let Some(alpha) = c.alpha {
                                 color = color * alpha;
                             }
+                            if let Some(matrix) = &c.color_matrix {
+                                color = matrix.apply(&color);
+                            }
                             let dst = &mut staging[(f_y * width + f_x) as usize];
                             *dst = dst.and_then(&color);
                         }
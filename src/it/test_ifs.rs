@@ -15,20 +15,52 @@ pub mod test_data_device;
 pub mod test_data_device_manager;
 pub mod test_data_offer;
 pub mod test_data_source;
+pub mod test_decoration_manager;
 pub mod test_display;
 pub mod test_dmabuf;
 pub mod test_dmabuf_feedback;
+pub mod test_dmabuf_params;
 pub mod test_ext_foreign_toplevel_handle;
 pub mod test_ext_foreign_toplevel_list;
+pub mod test_idle_inhibit_manager;
+pub mod test_idle_inhibitor;
 pub mod test_input_method;
 pub mod test_input_method_keyboard_grab;
 pub mod test_input_method_manager;
 pub mod test_input_popup_surface;
+pub mod test_jay_clipboard;
 pub mod test_jay_compositor;
+pub mod test_jay_cursor_image;
+pub mod test_jay_frame_timings;
+pub mod test_jay_idle;
+pub mod test_jay_output;
+pub mod test_jay_output_watcher;
+pub mod test_jay_presentation_feed;
+pub mod test_jay_randr;
+pub mod test_jay_screencast;
+pub mod test_jay_select_toplevel;
+pub mod test_jay_toplevel;
+pub mod test_jay_which_key;
+pub mod test_jay_workspace;
+pub mod test_jay_workspace_watcher;
 pub mod test_keyboard;
+pub mod test_keyboard_shortcuts_inhibit_manager;
+pub mod test_keyboard_shortcuts_inhibitor;
+pub mod test_layer_shell;
+pub mod test_layer_surface;
+pub mod test_locked_pointer;
+pub mod test_output;
 pub mod test_pointer;
+pub mod test_pointer_constraints;
+pub mod test_primary_selection_device;
+pub mod test_primary_selection_device_manager;
+pub mod test_primary_selection_offer;
 pub mod test_region;
 pub mod test_registry;
+pub mod test_relative_pointer;
+pub mod test_relative_pointer_manager;
+pub mod test_screencopy_frame;
+pub mod test_screencopy_manager;
 pub mod test_screenshot;
 pub mod test_seat;
 pub mod test_shm;
@@ -43,8 +75,10 @@ pub mod test_syncobj_surface;
 pub mod test_syncobj_timeline;
 pub mod test_text_input;
 pub mod test_text_input_manager;
+pub mod test_toplevel_decoration;
 pub mod test_toplevel_drag;
 pub mod test_toplevel_drag_manager;
+pub mod test_touch;
 pub mod test_viewport;
 pub mod test_viewporter;
 pub mod test_virtual_keyboard;
@@ -52,5 +86,7 @@ pub mod test_virtual_keyboard_manager;
 pub mod test_xdg_activation;
 pub mod test_xdg_activation_token;
 pub mod test_xdg_base;
+pub mod test_xdg_popup;
+pub mod test_xdg_positioner;
 pub mod test_xdg_surface;
 pub mod test_xdg_toplevel;
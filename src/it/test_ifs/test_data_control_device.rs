@@ -45,7 +45,6 @@ impl TestDataControlDevice {
         Ok(())
     }
 
-    #[expect(dead_code)]
     pub fn set_primary_selection(&self, source: &TestDataControlSource) -> TestResult {
         self.tran.send(SetPrimarySelection {
             self_id: self.id,
@@ -33,6 +33,11 @@ impl TestDataDeviceManager {
             tran: self.tran.clone(),
             destroyed: Cell::new(false),
             sends: Rc::new(Default::default()),
+            cancelled: Cell::new(false),
+            target: Rc::new(Default::default()),
+            action: Rc::new(Default::default()),
+            dnd_drop_performed: Cell::new(false),
+            dnd_finished: Cell::new(false),
         });
         self.tran.add_obj(data_source.clone())?;
         self.tran.send(CreateDataSource {
@@ -16,6 +16,11 @@ pub struct TestDataSource {
     pub tran: Rc<TestTransport>,
     pub destroyed: Cell<bool>,
     pub sends: TEEH<(String, Rc<OwnedFd>)>,
+    pub cancelled: Cell<bool>,
+    pub target: TEEH<Option<String>>,
+    pub action: TEEH<u32>,
+    pub dnd_drop_performed: Cell<bool>,
+    pub dnd_finished: Cell<bool>,
 }
 
 impl TestDataSource {
@@ -43,7 +48,8 @@ impl TestDataSource {
     }
 
     fn handle_target(&self, parser: MsgParser<'_, '_>) -> TestResult {
-        let _ev = Target::parse_full(parser)?;
+        let ev = Target::parse_full(parser)?;
+        self.target.push(ev.mime_type.map(|m| m.to_string()));
         Ok(())
     }
 
@@ -55,21 +61,25 @@ impl TestDataSource {
 
     fn handle_cancelled(&self, parser: MsgParser<'_, '_>) -> TestResult {
         let _ev = Cancelled::parse_full(parser)?;
+        self.cancelled.set(true);
         Ok(())
     }
 
     fn handle_dnd_drop_performed(&self, parser: MsgParser<'_, '_>) -> TestResult {
         let _ev = DndDropPerformed::parse_full(parser)?;
+        self.dnd_drop_performed.set(true);
         Ok(())
     }
 
     fn handle_dnd_finished(&self, parser: MsgParser<'_, '_>) -> TestResult {
         let _ev = DndFinished::parse_full(parser)?;
+        self.dnd_finished.set(true);
         Ok(())
     }
 
     fn handle_action(&self, parser: MsgParser<'_, '_>) -> TestResult {
-        let _ev = Action::parse_full(parser)?;
+        let ev = Action::parse_full(parser)?;
+        self.action.push(ev.dnd_action);
         Ok(())
     }
 }
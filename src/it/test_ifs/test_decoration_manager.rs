@@ -0,0 +1,69 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult,
+            test_ifs::{
+                test_toplevel_decoration::TestToplevelDecoration,
+                test_xdg_toplevel::TestXdgToplevel,
+            },
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{zxdg_decoration_manager_v1::*, ZxdgDecorationManagerV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestDecorationManager {
+    pub id: ZxdgDecorationManagerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestDecorationManager {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn get_toplevel_decoration(
+        &self,
+        toplevel: &TestXdgToplevel,
+    ) -> TestResult<Rc<TestToplevelDecoration>> {
+        let obj = Rc::new(TestToplevelDecoration {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            mode: Cell::new(None),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetToplevelDecoration {
+            self_id: self.id,
+            id: obj.id,
+            toplevel: toplevel.core.id,
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestDecorationManager {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestDecorationManager, ZxdgDecorationManagerV1;
+}
+
+impl TestObject for TestDecorationManager {}
@@ -2,7 +2,10 @@ use {
     crate::{
         it::{
             test_error::TestResult,
-            test_ifs::{test_dmabuf_feedback::TestDmabufFeedback, test_surface::TestSurface},
+            test_ifs::{
+                test_dmabuf_feedback::TestDmabufFeedback, test_dmabuf_params::TestDmabufParams,
+                test_surface::TestSurface,
+            },
             test_object::TestObject,
             test_transport::TestTransport,
         },
@@ -33,6 +36,20 @@ impl TestDmabuf {
         Ok(())
     }
 
+    pub fn create_params(&self) -> TestResult<Rc<TestDmabufParams>> {
+        let obj = Rc::new(TestDmabufParams {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(CreateParams {
+            self_id: self.id,
+            params_id: obj.id,
+        })?;
+        Ok(obj)
+    }
+
     #[expect(dead_code)]
     pub fn get_default_feedback(&self) -> TestResult<Rc<TestDmabufFeedback>> {
         let obj = Rc::new(TestDmabufFeedback::new(&self.tran));
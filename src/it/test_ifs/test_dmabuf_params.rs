@@ -0,0 +1,82 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_ifs::test_buffer::TestBuffer, test_object::TestObject,
+            test_transport::TestTransport, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        video::dmabuf::DmaBuf,
+        wire::{zwp_linux_buffer_params_v1::*, ZwpLinuxBufferParamsV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestDmabufParams {
+    pub id: ZwpLinuxBufferParamsV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestDmabufParams {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn create_immed(&self, buf: &DmaBuf) -> TestResult<Rc<TestBuffer>> {
+        for (plane_idx, plane) in buf.planes.iter().enumerate() {
+            self.tran.send(Add {
+                self_id: self.id,
+                fd: plane.fd.clone(),
+                plane_idx: plane_idx as _,
+                offset: plane.offset,
+                stride: plane.stride,
+                modifier_hi: (buf.modifier >> 32) as _,
+                modifier_lo: buf.modifier as _,
+            })?;
+        }
+        let obj = Rc::new(TestBuffer {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            released: Cell::new(true),
+            destroyed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(CreateImmed {
+            self_id: self.id,
+            buffer_id: obj.id,
+            width: buf.width,
+            height: buf.height,
+            format: buf.format.drm,
+            flags: 0,
+        })?;
+        Ok(obj)
+    }
+
+    fn handle_created(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Created::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_failed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Failed::parse_full(parser)?;
+        Ok(())
+    }
+}
+
+impl Drop for TestDmabufParams {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestDmabufParams, ZwpLinuxBufferParamsV1;
+
+    CREATED => handle_created,
+    FAILED => handle_failed,
+}
+
+impl TestObject for TestDmabufParams {}
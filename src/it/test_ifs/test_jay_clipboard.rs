@@ -0,0 +1,68 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_clipboard::*, JayClipboardId},
+    },
+    std::{cell::Cell, rc::Rc},
+    uapi::{c, OwnedFd},
+};
+
+pub struct TestJayClipboard {
+    pub id: JayClipboardId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub mime_types: TEEH<(u32, String)>,
+    pub done: TEEH<u32>,
+}
+
+impl TestJayClipboard {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn receive(&self, location: u32, mime_type: &str) -> TestResult<Rc<OwnedFd>> {
+        let (read, write) = uapi::pipe2(c::O_CLOEXEC)?;
+        self.tran.send(Receive {
+            self_id: self.id,
+            location,
+            mime_type,
+            fd: Rc::new(write),
+        })?;
+        Ok(Rc::new(read))
+    }
+
+    fn handle_mime_type(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = MimeType::parse_full(parser)?;
+        self.mime_types
+            .push((ev.location, ev.mime_type.to_string()));
+        Ok(())
+    }
+
+    fn handle_done(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Done::parse_full(parser)?;
+        self.done.push(ev.location);
+        Ok(())
+    }
+}
+
+impl Drop for TestJayClipboard {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayClipboard, JayClipboard;
+
+    MIME_TYPE => handle_mime_type,
+    DONE => handle_done,
+}
+
+impl TestObject for TestJayClipboard {}
@@ -3,7 +3,17 @@ use {
         client::ClientId,
         it::{
             test_error::{TestError, TestResult},
-            test_ifs::test_screenshot::TestJayScreenshot,
+            test_ifs::{
+                test_jay_clipboard::TestJayClipboard, test_jay_cursor_image::TestJayCursorImage,
+                test_jay_frame_timings::TestJayFrameTimings, test_jay_idle::TestJayIdle,
+                test_jay_output::TestJayOutput, test_jay_output_watcher::TestJayOutputWatcher,
+                test_jay_presentation_feed::TestJayPresentationFeed, test_jay_randr::TestJayRandr,
+                test_jay_screencast::TestJayScreencast,
+                test_jay_select_toplevel::TestJaySelectToplevel,
+                test_jay_toplevel::TestJayToplevel, test_jay_which_key::TestJayWhichKey,
+                test_jay_workspace_watcher::TestJayWorkspaceWatcher, test_output::TestOutput,
+                test_screenshot::TestJayScreenshot, test_seat::TestSeat,
+            },
             test_object::TestObject,
             test_transport::TestTransport,
             testrun::ParseFull,
@@ -12,10 +22,14 @@ use {
         video::dmabuf::DmaBuf,
         wire::{
             jay_compositor::{self, *},
-            JayCompositorId,
+            JayCompositorId, WlOutputId,
         },
+        xkbcommon::XkbRuleNames,
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
     },
-    std::{cell::Cell, rc::Rc},
     uapi::OwnedFd,
 };
 
@@ -23,6 +37,12 @@ pub struct TestJayCompositor {
     pub id: JayCompositorId,
     pub tran: Rc<TestTransport>,
     pub client_id: Cell<Option<ClientId>>,
+    pub shortcuts: RefCell<Vec<(u32, u32)>>,
+    pub gamma_sizes: RefCell<Vec<(WlOutputId, u32)>>,
+    pub gamma_errors: RefCell<Vec<(WlOutputId, String)>>,
+    pub keymap_names: RefCell<Vec<(String, XkbRuleNames)>>,
+    pub keymap_errors: RefCell<Vec<String>>,
+    pub workspace_errors: RefCell<Vec<String>>,
 }
 
 impl TestJayCompositor {
@@ -31,6 +51,12 @@ impl TestJayCompositor {
             id: tran.id(),
             tran: tran.clone(),
             client_id: Cell::new(None),
+            shortcuts: Default::default(),
+            gamma_sizes: Default::default(),
+            gamma_errors: Default::default(),
+            keymap_names: Default::default(),
+            keymap_errors: Default::default(),
+            workspace_errors: Default::default(),
         }
     }
 
@@ -50,6 +76,234 @@ impl TestJayCompositor {
         Ok(())
     }
 
+    pub fn get_clipboard(&self, seat: &TestSeat) -> TestResult<Rc<TestJayClipboard>> {
+        let obj = Rc::new(TestJayClipboard {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            mime_types: Rc::new(Default::default()),
+            done: Rc::new(Default::default()),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetClipboard {
+            self_id: self.id,
+            id: obj.id,
+            seat: seat.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn get_which_key(&self, seat: &TestSeat) -> TestResult<Rc<TestJayWhichKey>> {
+        let obj = Rc::new(TestJayWhichKey {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            shortcuts: Rc::new(Default::default()),
+            show: Rc::new(Default::default()),
+            hide: Rc::new(Default::default()),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetWhichKey {
+            self_id: self.id,
+            id: obj.id,
+            seat: seat.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn get_idle(&self) -> TestResult<Rc<TestJayIdle>> {
+        let obj = Rc::new(TestJayIdle {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            interval: Rc::new(Default::default()),
+            grace_period: Rc::new(Default::default()),
+            inhibitors: Rc::new(Default::default()),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetIdle {
+            self_id: self.id,
+            id: obj.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn get_randr(&self) -> TestResult<Rc<TestJayRandr>> {
+        let obj = Rc::new(TestJayRandr {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            errors: Rc::new(Default::default()),
+            statistics: Default::default(),
+            flip_margins: Default::default(),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetRandr {
+            self_id: self.id,
+            id: obj.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn get_cursor_image(&self, seat: &TestSeat) -> TestResult<Rc<TestJayCursorImage>> {
+        let obj = Rc::new(TestJayCursorImage {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            changed: Rc::new(Default::default()),
+            empty: Rc::new(Default::default()),
+            error: Rc::new(Default::default()),
+            dmabuf: Rc::new(Default::default()),
+            shm: Rc::new(Default::default()),
+            image: Rc::new(Default::default()),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetCursorImage {
+            self_id: self.id,
+            id: obj.id,
+            seat: seat.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn watch_outputs(&self) -> TestResult<Rc<TestJayOutputWatcher>> {
+        let obj = Rc::new(TestJayOutputWatcher {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            added: Rc::new(Default::default()),
+            modes: Rc::new(Default::default()),
+            hardware_cursor: Rc::new(Default::default()),
+            done: Rc::new(Default::default()),
+            removed: Rc::new(Default::default()),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(WatchOutputs {
+            self_id: self.id,
+            id: obj.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn watch_workspaces(&self) -> TestResult<Rc<TestJayWorkspaceWatcher>> {
+        let obj = Rc::new(TestJayWorkspaceWatcher {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            workspaces: Default::default(),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(WatchWorkspaces {
+            self_id: self.id,
+            id: obj.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn get_output(&self, output: &TestOutput) -> TestResult<Rc<TestJayOutput>> {
+        let obj = Rc::new(TestJayOutput {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetOutput {
+            self_id: self.id,
+            id: obj.id,
+            output: output.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn get_presentation_feed(
+        &self,
+        output: &TestOutput,
+    ) -> TestResult<Rc<TestJayPresentationFeed>> {
+        let obj = Rc::new(TestJayPresentationFeed {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            presented: Default::default(),
+            server_destroyed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetPresentationFeed {
+            self_id: self.id,
+            id: obj.id,
+            output: output.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn get_frame_timings(&self, output: &TestOutput) -> TestResult<Rc<TestJayFrameTimings>> {
+        let obj = Rc::new(TestJayFrameTimings {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            latched: Default::default(),
+            vblanks: Cell::new(0),
+            server_destroyed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetFrameTimings {
+            self_id: self.id,
+            id: obj.id,
+            output: output.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn create_screencast(&self) -> TestResult<Rc<TestJayScreencast>> {
+        let obj = Rc::new(TestJayScreencast {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            server_destroyed: Cell::new(false),
+            config_size: Rc::new(Default::default()),
+            config_done: Rc::new(Default::default()),
+            ready: Rc::new(Default::default()),
+            config_cursor_only: Rc::new(Default::default()),
+            cursor_positions: Rc::new(Default::default()),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(CreateScreencast {
+            self_id: self.id,
+            id: obj.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub async fn get_toplevel(&self, toplevel_id: &str) -> TestResult<Rc<TestJayToplevel>> {
+        let obj = Rc::new(TestJaySelectToplevel {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            done: Cell::new(false),
+            toplevel: Default::default(),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetToplevel {
+            self_id: self.id,
+            id: obj.id,
+            toplevel_id,
+        })?;
+        self.tran.sync().await;
+        if !obj.done.get() {
+            bail!("Compositor did not send a toplevel");
+        }
+        match obj.toplevel.take() {
+            Some(tl) => Ok(tl),
+            _ => bail!("Compositor did not find toplevel {}", toplevel_id),
+        }
+    }
+
+    pub async fn get_shortcuts(&self, seat: &TestSeat) -> TestResult<Vec<(u32, u32)>> {
+        self.tran.send(GetShortcuts {
+            self_id: self.id,
+            seat: seat.id,
+        })?;
+        self.tran.sync().await;
+        Ok(self.shortcuts.take())
+    }
+
     pub async fn take_screenshot(
         &self,
         include_cursor: bool,
@@ -62,6 +316,7 @@ impl TestJayCompositor {
             drm_dev: Default::default(),
             planes: Default::default(),
             result: Default::default(),
+            shm: Default::default(),
         });
         self.tran.send(TakeScreenshot2 {
             self_id: self.id,
@@ -77,6 +332,139 @@ impl TestJayCompositor {
         }
     }
 
+    pub fn take_screenshot_after_presentation(&self) -> TestResult<Rc<TestJayScreenshot>> {
+        let js = Rc::new(TestJayScreenshot {
+            id: self.tran.id(),
+            state: self.tran.run.state.clone(),
+            drm_dev: Default::default(),
+            planes: Default::default(),
+            result: Default::default(),
+            shm: Default::default(),
+        });
+        self.tran.send(TakeScreenshot3 {
+            self_id: self.id,
+            id: js.id,
+            include_cursor: 0,
+            after_presentation: 1,
+        })?;
+        self.tran.add_obj(js.clone())?;
+        Ok(js)
+    }
+
+    pub fn take_output_screenshot(
+        &self,
+        output: &str,
+        include_cursor: bool,
+    ) -> TestResult<Rc<TestJayScreenshot>> {
+        let js = Rc::new(TestJayScreenshot {
+            id: self.tran.id(),
+            state: self.tran.run.state.clone(),
+            drm_dev: Default::default(),
+            planes: Default::default(),
+            result: Default::default(),
+            shm: Default::default(),
+        });
+        self.tran.send(TakeOutputScreenshot {
+            self_id: self.id,
+            id: js.id,
+            output,
+            include_cursor: include_cursor as _,
+        })?;
+        self.tran.add_obj(js.clone())?;
+        Ok(js)
+    }
+
+    pub fn take_screenshot_shm(&self, include_cursor: bool) -> TestResult<Rc<TestJayScreenshot>> {
+        let js = Rc::new(TestJayScreenshot {
+            id: self.tran.id(),
+            state: self.tran.run.state.clone(),
+            drm_dev: Default::default(),
+            planes: Default::default(),
+            result: Default::default(),
+            shm: Default::default(),
+        });
+        self.tran.send(TakeScreenshotShm {
+            self_id: self.id,
+            id: js.id,
+            include_cursor: include_cursor as _,
+        })?;
+        self.tran.add_obj(js.clone())?;
+        Ok(js)
+    }
+
+    pub fn get_output_gamma_size(&self, output: &TestOutput) -> TestResult {
+        self.tran.send(GetOutputGammaSize {
+            self_id: self.id,
+            output: output.id,
+        })?;
+        Ok(())
+    }
+
+    pub fn set_output_gamma(
+        &self,
+        output: &TestOutput,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> TestResult {
+        self.tran.send(SetOutputGamma {
+            self_id: self.id,
+            output: output.id,
+            red,
+            green,
+            blue,
+        })?;
+        Ok(())
+    }
+
+    pub fn reset_output_gamma(&self, output: &TestOutput) -> TestResult {
+        self.tran.send(ResetOutputGamma {
+            self_id: self.id,
+            output: output.id,
+        })?;
+        Ok(())
+    }
+
+    pub fn set_output_power(&self, output: &TestOutput, on: bool) -> TestResult {
+        self.tran.send(SetOutputPower {
+            self_id: self.id,
+            output: output.id,
+            on: on as _,
+        })?;
+        Ok(())
+    }
+
+    pub fn get_keymap_names(&self) -> TestResult {
+        self.tran.send(GetKeymapNames { self_id: self.id })?;
+        Ok(())
+    }
+
+    pub fn set_keymap_names(&self, names: &XkbRuleNames) -> TestResult {
+        self.tran.send(SetKeymapNames {
+            self_id: self.id,
+            rules: &names.rules,
+            model: &names.model,
+            layout: &names.layout,
+            variant: &names.variant,
+            options: &names.options,
+        })?;
+        Ok(())
+    }
+
+    pub fn rename_workspace(&self, name: &str, new_name: &str) -> TestResult {
+        self.tran.send(RenameWorkspace {
+            self_id: self.id,
+            name,
+            new_name,
+        })?;
+        Ok(())
+    }
+
+    pub fn use_default_config(&self) -> TestResult {
+        self.tran.send(UseDefaultConfig { self_id: self.id })?;
+        Ok(())
+    }
+
     fn handle_client_id(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
         let ev = jay_compositor::ClientId::parse_full(parser)?;
         self.client_id.set(Some(ClientId::from_raw(ev.client_id)));
@@ -93,6 +481,53 @@ impl TestJayCompositor {
         let _ev = Capabilities::parse_full(parser)?;
         Ok(())
     }
+
+    fn handle_shortcut(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = Shortcut::parse_full(parser)?;
+        self.shortcuts.borrow_mut().push((ev.mods, ev.keysym));
+        Ok(())
+    }
+
+    fn handle_output_gamma_size(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = OutputGammaSize::parse_full(parser)?;
+        self.gamma_sizes.borrow_mut().push((ev.output, ev.size));
+        Ok(())
+    }
+
+    fn handle_gamma_error(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = GammaError::parse_full(parser)?;
+        self.gamma_errors
+            .borrow_mut()
+            .push((ev.output, ev.msg.to_string()));
+        Ok(())
+    }
+
+    fn handle_keymap_names(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = KeymapNames::parse_full(parser)?;
+        let names = XkbRuleNames {
+            rules: ev.rules.to_string(),
+            model: ev.model.to_string(),
+            layout: ev.layout.to_string(),
+            variant: ev.variant.to_string(),
+            options: ev.options.to_string(),
+        };
+        self.keymap_names
+            .borrow_mut()
+            .push((ev.seat.to_string(), names));
+        Ok(())
+    }
+
+    fn handle_keymap_error(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = KeymapError::parse_full(parser)?;
+        self.keymap_errors.borrow_mut().push(ev.msg.to_string());
+        Ok(())
+    }
+
+    fn handle_workspace_error(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = WorkspaceError::parse_full(parser)?;
+        self.workspace_errors.borrow_mut().push(ev.msg.to_string());
+        Ok(())
+    }
 }
 
 test_object! {
@@ -101,6 +536,12 @@ test_object! {
     CLIENT_ID => handle_client_id,
     SEAT => handle_seat,
     CAPABILITIES => handle_capabilities,
+    SHORTCUT => handle_shortcut,
+    OUTPUT_GAMMA_SIZE => handle_output_gamma_size,
+    GAMMA_ERROR => handle_gamma_error,
+    KEYMAP_NAMES => handle_keymap_names,
+    KEYMAP_ERROR => handle_keymap_error,
+    WORKSPACE_ERROR => handle_workspace_error,
 }
 
 impl TestObject for TestJayCompositor {}
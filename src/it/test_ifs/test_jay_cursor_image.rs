@@ -0,0 +1,104 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_cursor_image::*, JayCursorImageId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestJayCursorImage {
+    pub id: JayCursorImageId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub changed: TEEH<()>,
+    pub empty: TEEH<()>,
+    pub error: TEEH<String>,
+    pub dmabuf: TEEH<(i32, i32, u32)>,
+    pub shm: TEEH<(i32, i32, i32, u32)>,
+    pub image: TEEH<(i32, i32, f64)>,
+}
+
+impl TestJayCursorImage {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn capture_shm(&self) -> TestResult {
+        self.tran.send(CaptureShm { self_id: self.id })?;
+        Ok(())
+    }
+
+    fn handle_changed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Changed::parse_full(parser)?;
+        self.changed.push(());
+        Ok(())
+    }
+
+    fn handle_empty(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Empty::parse_full(parser)?;
+        self.empty.push(());
+        Ok(())
+    }
+
+    fn handle_error(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Error::parse_full(parser)?;
+        self.error.push(ev.msg.to_string());
+        Ok(())
+    }
+
+    fn handle_drm_dev(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = DrmDev::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_plane(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Plane::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_dmabuf(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Dmabuf::parse_full(parser)?;
+        self.dmabuf.push((ev.width, ev.height, ev.format));
+        Ok(())
+    }
+
+    fn handle_shm(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Shm::parse_full(parser)?;
+        self.shm.push((ev.width, ev.height, ev.stride, ev.format));
+        Ok(())
+    }
+
+    fn handle_image(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Image::parse_full(parser)?;
+        self.image.push((ev.hotspot_x, ev.hotspot_y, ev.scale));
+        Ok(())
+    }
+}
+
+impl Drop for TestJayCursorImage {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayCursorImage, JayCursorImage;
+
+    CHANGED => handle_changed,
+    EMPTY => handle_empty,
+    ERROR => handle_error,
+    DRM_DEV => handle_drm_dev,
+    PLANE => handle_plane,
+    DMABUF => handle_dmabuf,
+    SHM => handle_shm,
+    IMAGE => handle_image,
+}
+
+impl TestObject for TestJayCursorImage {}
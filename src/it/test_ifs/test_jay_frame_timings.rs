@@ -0,0 +1,68 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_frame_timings::*, JayFrameTimingsId},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+pub struct TestJayFrameTimings {
+    pub id: JayFrameTimingsId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub latched: RefCell<Vec<(u64, bool)>>,
+    pub vblanks: Cell<usize>,
+    pub server_destroyed: Cell<bool>,
+}
+
+impl TestJayFrameTimings {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_latched(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Latched::parse_full(parser)?;
+        self.latched
+            .borrow_mut()
+            .push((ev.flip_margin_ns, ev.tearing != 0));
+        Ok(())
+    }
+
+    fn handle_vblank(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Vblank::parse_full(parser)?;
+        self.vblanks.set(self.vblanks.get() + 1);
+        Ok(())
+    }
+
+    fn handle_destroyed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Destroyed::parse_full(parser)?;
+        self.server_destroyed.set(true);
+        Ok(())
+    }
+}
+
+impl Drop for TestJayFrameTimings {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayFrameTimings, JayFrameTimings;
+
+    LATCHED => handle_latched,
+    VBLANK => handle_vblank,
+    DESTROYED => handle_destroyed,
+}
+
+impl TestObject for TestJayFrameTimings {}
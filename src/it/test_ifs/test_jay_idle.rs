@@ -0,0 +1,75 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_idle::*, JayIdleId, WlSurfaceId},
+    },
+    std::rc::Rc,
+};
+
+pub struct TestIdleInhibitorInfo {
+    pub surface: WlSurfaceId,
+    pub client_id: u64,
+    pub since_usec: Option<u64>,
+}
+
+pub struct TestJayIdle {
+    pub id: JayIdleId,
+    pub tran: Rc<TestTransport>,
+    pub interval: TEEH<u64>,
+    pub grace_period: TEEH<u64>,
+    pub inhibitors: TEEH<TestIdleInhibitorInfo>,
+}
+
+impl TestJayIdle {
+    pub fn get_status(&self) -> TestResult {
+        self.tran.send(GetStatus { self_id: self.id })?;
+        Ok(())
+    }
+
+    fn handle_interval(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Interval::parse_full(parser)?;
+        self.interval.push(ev.interval);
+        Ok(())
+    }
+
+    fn handle_inhibitor(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Inhibitor::parse_full(parser)?;
+        self.inhibitors.push(TestIdleInhibitorInfo {
+            surface: ev.surface,
+            client_id: ev.client_id,
+            since_usec: None,
+        });
+        Ok(())
+    }
+
+    fn handle_grace_period(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = GracePeriod::parse_full(parser)?;
+        self.grace_period.push(ev.period);
+        Ok(())
+    }
+
+    fn handle_inhibitor2(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Inhibitor2::parse_full(parser)?;
+        self.inhibitors.push(TestIdleInhibitorInfo {
+            surface: ev.surface,
+            client_id: ev.client_id,
+            since_usec: Some(ev.since_usec),
+        });
+        Ok(())
+    }
+}
+
+test_object! {
+    TestJayIdle, JayIdle;
+
+    INTERVAL => handle_interval,
+    INHIBITOR => handle_inhibitor,
+    GRACE_PERIOD => handle_grace_period,
+    INHIBITOR2 => handle_inhibitor2,
+}
+
+impl TestObject for TestJayIdle {}
@@ -0,0 +1,92 @@
+use {
+    crate::{
+        backend,
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_output_watcher::*, JayOutputWatcherId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestJayOutputWatcher {
+    pub id: JayOutputWatcherId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub added: TEEH<(String, i32, i32, backend::Mode)>,
+    pub modes: TEEH<(String, backend::Mode)>,
+    pub hardware_cursor: TEEH<(String, bool)>,
+    pub done: TEEH<String>,
+    pub removed: TEEH<String>,
+}
+
+impl TestJayOutputWatcher {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_added(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Added::parse_full(parser)?;
+        let mode = backend::Mode {
+            width: ev.width,
+            height: ev.height,
+            refresh_rate_millihz: ev.refresh_rate_millihz,
+        };
+        self.added
+            .push((ev.connector.to_string(), ev.width_mm, ev.height_mm, mode));
+        Ok(())
+    }
+
+    fn handle_mode(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Mode::parse_full(parser)?;
+        let mode = backend::Mode {
+            width: ev.width,
+            height: ev.height,
+            refresh_rate_millihz: ev.refresh_rate_millihz,
+        };
+        self.modes.push((ev.connector.to_string(), mode));
+        Ok(())
+    }
+
+    fn handle_hardware_cursor(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = HardwareCursor::parse_full(parser)?;
+        self.hardware_cursor
+            .push((ev.connector.to_string(), ev.active != 0));
+        Ok(())
+    }
+
+    fn handle_done(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Done::parse_full(parser)?;
+        self.done.push(ev.connector.to_string());
+        Ok(())
+    }
+
+    fn handle_removed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Removed::parse_full(parser)?;
+        self.removed.push(ev.connector.to_string());
+        Ok(())
+    }
+}
+
+impl Drop for TestJayOutputWatcher {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayOutputWatcher, JayOutputWatcher;
+
+    ADDED => handle_added,
+    MODE => handle_mode,
+    HARDWARE_CURSOR => handle_hardware_cursor,
+    DONE => handle_done,
+    REMOVED => handle_removed,
+}
+
+impl TestObject for TestJayOutputWatcher {}
@@ -0,0 +1,58 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_presentation_feed::*, JayPresentationFeedId},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+pub struct TestJayPresentationFeed {
+    pub id: JayPresentationFeedId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub presented: RefCell<Vec<Presented>>,
+    pub server_destroyed: Cell<bool>,
+}
+
+impl TestJayPresentationFeed {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_presented(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Presented::parse_full(parser)?;
+        self.presented.borrow_mut().push(ev);
+        Ok(())
+    }
+
+    fn handle_destroyed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Destroyed::parse_full(parser)?;
+        self.server_destroyed.set(true);
+        Ok(())
+    }
+}
+
+impl Drop for TestJayPresentationFeed {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayPresentationFeed, JayPresentationFeed;
+
+    PRESENTED => handle_presented,
+    DESTROYED => handle_destroyed,
+}
+
+impl TestObject for TestJayPresentationFeed {}
@@ -0,0 +1,137 @@
+use {
+    crate::{
+        backend,
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_randr::*, JayRandrId},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+pub struct TestJayRandr {
+    pub id: JayRandrId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub errors: TEEH<String>,
+    pub statistics: RefCell<Vec<Statistics>>,
+    pub flip_margins: RefCell<Vec<OutputFlipMargin>>,
+}
+
+impl TestJayRandr {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_mode(
+        &self,
+        output: &str,
+        width: i32,
+        height: i32,
+        refresh_rate_millihz: u32,
+    ) -> TestResult {
+        self.tran.send(SetMode {
+            self_id: self.id,
+            output,
+            width,
+            height,
+            refresh_rate_millihz,
+        })
+    }
+
+    pub fn stage_output_layout(
+        &self,
+        output: &str,
+        (x, y): (i32, i32),
+        mode: backend::Mode,
+        transform: i32,
+        scale: u32,
+    ) -> TestResult {
+        self.tran.send(StageOutputLayout {
+            self_id: self.id,
+            output,
+            x,
+            y,
+            width: mode.width,
+            height: mode.height,
+            refresh_rate_millihz: mode.refresh_rate_millihz,
+            transform,
+            scale,
+        })
+    }
+
+    pub fn apply_output_layout(&self) -> TestResult {
+        self.tran.send(ApplyOutputLayout { self_id: self.id })
+    }
+
+    pub fn get_statistics(&self, output: &str) -> TestResult {
+        self.tran.send(GetStatistics {
+            self_id: self.id,
+            output,
+        })
+    }
+
+    pub fn get_output_flip_margin(&self, output: &str) -> TestResult {
+        self.tran.send(GetOutputFlipMargin {
+            self_id: self.id,
+            output,
+        })
+    }
+
+    pub fn set_output_flip_margin(&self, output: &str, margin_ns: u64) -> TestResult {
+        self.tran.send(SetOutputFlipMargin {
+            self_id: self.id,
+            output,
+            margin_ns,
+        })
+    }
+
+    pub fn reset_output_flip_margin(&self, output: &str) -> TestResult {
+        self.tran.send(ResetOutputFlipMargin {
+            self_id: self.id,
+            output,
+        })
+    }
+
+    fn handle_error(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Error::parse_full(parser)?;
+        self.errors.push(ev.msg.to_string());
+        Ok(())
+    }
+
+    fn handle_statistics(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Statistics::parse_full(parser)?;
+        self.statistics.borrow_mut().push(ev);
+        Ok(())
+    }
+
+    fn handle_output_flip_margin(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = OutputFlipMargin::parse_full(parser)?;
+        self.flip_margins.borrow_mut().push(ev);
+        Ok(())
+    }
+}
+
+impl Drop for TestJayRandr {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayRandr, JayRandr;
+
+    ERROR => handle_error,
+    STATISTICS => handle_statistics,
+    OUTPUT_FLIP_MARGIN => handle_output_flip_margin,
+}
+
+impl TestObject for TestJayRandr {}
@@ -0,0 +1,239 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult,
+            test_ifs::{
+                test_buffer::TestBuffer, test_jay_output::TestJayOutput,
+                test_jay_toplevel::TestJayToplevel,
+            },
+            test_object::TestObject,
+            test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_screencast::*, JayScreencastId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestJayScreencast {
+    pub id: JayScreencastId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub server_destroyed: Cell<bool>,
+    pub config_size: TEEH<(i32, i32)>,
+    pub config_done: TEEH<u32>,
+    pub ready: TEEH<u32>,
+    pub config_cursor_only: TEEH<bool>,
+    pub cursor_positions: TEEH<TestCursorPosition>,
+}
+
+#[derive(Debug)]
+pub struct TestCursorPosition {
+    pub idx: u32,
+    pub visible: bool,
+    pub x: i32,
+    pub y: i32,
+}
+
+impl TestJayScreencast {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_output(&self, output: &TestJayOutput) -> TestResult {
+        self.tran.send(SetOutput {
+            self_id: self.id,
+            output: output.id,
+        })
+    }
+
+    pub fn set_allow_all_workspaces(&self, allow_all: bool) -> TestResult {
+        self.tran.send(SetAllowAllWorkspaces {
+            self_id: self.id,
+            allow_all: allow_all as _,
+        })
+    }
+
+    pub fn set_running(&self, running: bool) -> TestResult {
+        self.tran.send(SetRunning {
+            self_id: self.id,
+            running: running as _,
+        })
+    }
+
+    pub fn set_region(&self, x: i32, y: i32, width: i32, height: i32) -> TestResult {
+        self.tran.send(SetRegion {
+            self_id: self.id,
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    pub fn set_cursor_only(&self, cursor_only: bool) -> TestResult {
+        self.tran.send(SetCursorOnly {
+            self_id: self.id,
+            cursor_only: cursor_only as _,
+        })
+    }
+
+    pub fn unset_region(&self) -> TestResult {
+        self.tran.send(UnsetRegion { self_id: self.id })
+    }
+
+    pub fn clear_buffers(&self) -> TestResult {
+        self.tran.send(ClearBuffers { self_id: self.id })
+    }
+
+    pub fn add_buffer(&self, buffer: &TestBuffer) -> TestResult {
+        self.tran.send(AddBuffer {
+            self_id: self.id,
+            buffer: buffer.id,
+        })
+    }
+
+    pub fn release_buffer(&self, idx: u32) -> TestResult {
+        self.tran.send(ReleaseBuffer {
+            self_id: self.id,
+            idx,
+        })
+    }
+
+    pub fn set_toplevel(&self, toplevel: &TestJayToplevel) -> TestResult {
+        self.tran.send(SetToplevel {
+            self_id: self.id,
+            id: toplevel.id,
+        })
+    }
+
+    pub fn configure(&self) -> TestResult {
+        self.tran.send(Configure { self_id: self.id })
+    }
+
+    pub fn ack_config(&self, serial: u32) -> TestResult {
+        self.tran.send(AckConfig {
+            self_id: self.id,
+            serial,
+        })
+    }
+
+    fn handle_plane(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Plane::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_buffer(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Buffer::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_buffers_done(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = BuffersDone::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_ready(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Ready::parse_full(parser)?;
+        self.ready.push(ev.idx);
+        Ok(())
+    }
+
+    fn handle_destroyed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Destroyed::parse_full(parser)?;
+        self.server_destroyed.set(true);
+        Ok(())
+    }
+
+    fn handle_missed_frame(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = MissedFrame::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_config_output(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = ConfigOutput::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_config_allow_all_workspaces(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = ConfigAllowAllWorkspaces::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_config_allow_workspace(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = ConfigAllowWorkspace::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_config_use_linear_buffers(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = ConfigUseLinearBuffers::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_config_running(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = ConfigRunning::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_config_done(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = ConfigDone::parse_full(parser)?;
+        self.config_done.push(ev.serial);
+        Ok(())
+    }
+
+    fn handle_config_size(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = ConfigSize::parse_full(parser)?;
+        self.config_size.push((ev.width, ev.height));
+        Ok(())
+    }
+
+    fn handle_config_cursor_only(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = ConfigCursorOnly::parse_full(parser)?;
+        self.config_cursor_only.push(ev.cursor_only != 0);
+        Ok(())
+    }
+
+    fn handle_cursor_position(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = CursorPosition::parse_full(parser)?;
+        self.cursor_positions.push(TestCursorPosition {
+            idx: ev.idx,
+            visible: ev.visible != 0,
+            x: ev.x,
+            y: ev.y,
+        });
+        Ok(())
+    }
+}
+
+impl Drop for TestJayScreencast {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayScreencast, JayScreencast;
+
+    PLANE => handle_plane,
+    BUFFER => handle_buffer,
+    BUFFERS_DONE => handle_buffers_done,
+    READY => handle_ready,
+    DESTROYED => handle_destroyed,
+    MISSED_FRAME => handle_missed_frame,
+    CONFIG_OUTPUT => handle_config_output,
+    CONFIG_ALLOW_ALL_WORKSPACES => handle_config_allow_all_workspaces,
+    CONFIG_ALLOW_WORKSPACE => handle_config_allow_workspace,
+    CONFIG_USE_LINEAR_BUFFERS => handle_config_use_linear_buffers,
+    CONFIG_RUNNING => handle_config_running,
+    CONFIG_DONE => handle_config_done,
+    CONFIG_SIZE => handle_config_size,
+    CONFIG_CURSOR_ONLY => handle_config_cursor_only,
+    CURSOR_POSITION => handle_cursor_position,
+}
+
+impl TestObject for TestJayScreencast {}
@@ -0,0 +1,43 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_ifs::test_jay_toplevel::TestJayToplevel,
+            test_object::TestObject, test_transport::TestTransport, testrun::ParseFull,
+        },
+        utils::{buffd::MsgParser, clonecell::CloneCell},
+        wire::{jay_select_toplevel::*, JaySelectToplevelId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestJaySelectToplevel {
+    pub id: JaySelectToplevelId,
+    pub tran: Rc<TestTransport>,
+    pub done: Cell<bool>,
+    pub toplevel: CloneCell<Option<Rc<TestJayToplevel>>>,
+}
+
+impl TestJaySelectToplevel {
+    fn handle_done(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Done::parse_full(parser)?;
+        self.done.set(true);
+        if ev.id.is_some() {
+            let tl = Rc::new(TestJayToplevel {
+                id: ev.id,
+                tran: self.tran.clone(),
+                destroyed: Cell::new(false),
+            });
+            self.tran.add_obj(tl.clone())?;
+            self.toplevel.set(Some(tl));
+        }
+        Ok(())
+    }
+}
+
+test_object! {
+    TestJaySelectToplevel, JaySelectToplevel;
+
+    DONE => handle_done,
+}
+
+impl TestObject for TestJaySelectToplevel {}
@@ -0,0 +1,57 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_toplevel::*, JayToplevelId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestJayToplevel {
+    pub id: JayToplevelId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestJayToplevel {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_destroyed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Destroyed::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_id(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Id::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_done(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Done::parse_full(parser)?;
+        Ok(())
+    }
+}
+
+impl Drop for TestJayToplevel {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayToplevel, JayToplevel;
+
+    DESTROYED => handle_destroyed,
+    ID => handle_id,
+    DONE => handle_done,
+}
+
+impl TestObject for TestJayToplevel {}
@@ -0,0 +1,63 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_which_key::*, JayWhichKeyId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestJayWhichKey {
+    pub id: JayWhichKeyId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub shortcuts: TEEH<(u32, u32)>,
+    pub show: TEEH<u32>,
+    pub hide: TEEH<()>,
+}
+
+impl TestJayWhichKey {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_shortcut(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Shortcut::parse_full(parser)?;
+        self.shortcuts.push((ev.mods, ev.keysym));
+        Ok(())
+    }
+
+    fn handle_show(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Show::parse_full(parser)?;
+        self.show.push(ev.mods);
+        Ok(())
+    }
+
+    fn handle_hide(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Hide::parse_full(parser)?;
+        self.hide.push(());
+        Ok(())
+    }
+}
+
+impl Drop for TestJayWhichKey {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayWhichKey, JayWhichKey;
+
+    SHORTCUT => handle_shortcut,
+    SHOW => handle_show,
+    HIDE => handle_hide,
+}
+
+impl TestObject for TestJayWhichKey {}
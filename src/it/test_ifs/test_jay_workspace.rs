@@ -0,0 +1,91 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        rect::Rect,
+        utils::buffd::MsgParser,
+        wire::{jay_workspace::*, JayWorkspaceId},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+pub struct TestJayWorkspace {
+    pub id: JayWorkspaceId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub name: RefCell<String>,
+    pub rename_requested: TEEH<(String, Rect)>,
+}
+
+impl TestJayWorkspace {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_linear_id(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = LinearId::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_name(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Name::parse_full(parser)?;
+        *self.name.borrow_mut() = ev.name.to_string();
+        Ok(())
+    }
+
+    fn handle_destroyed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Destroyed::parse_full(parser)?;
+        self.destroy()?;
+        Ok(())
+    }
+
+    fn handle_done(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Done::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_output(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Output::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_visible(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Visible::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_rename_requested(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = RenameRequested::parse_full(parser)?;
+        let rect = Rect::new_sized(ev.x, ev.y, ev.width, ev.height).unwrap_or_default();
+        self.rename_requested.push((ev.name.to_string(), rect));
+        Ok(())
+    }
+}
+
+impl Drop for TestJayWorkspace {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayWorkspace, JayWorkspace;
+
+    LINEAR_ID => handle_linear_id,
+    NAME => handle_name,
+    DESTROYED => handle_destroyed,
+    DONE => handle_done,
+    OUTPUT => handle_output,
+    VISIBLE => handle_visible,
+    RENAME_REQUESTED => handle_rename_requested,
+}
+
+impl TestObject for TestJayWorkspace {}
@@ -0,0 +1,69 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_ifs::test_jay_workspace::TestJayWorkspace,
+            test_object::TestObject, test_transport::TestTransport, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{jay_workspace_watcher::*, JayWorkspaceWatcherId},
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+pub struct TestJayWorkspaceWatcher {
+    pub id: JayWorkspaceWatcherId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub workspaces: RefCell<Vec<Rc<TestJayWorkspace>>>,
+}
+
+impl TestJayWorkspaceWatcher {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> TestResult<Rc<TestJayWorkspace>> {
+        let workspaces = self.workspaces.borrow();
+        let ws = workspaces
+            .iter()
+            .find(|ws| !ws.destroyed.get() && *ws.name.borrow() == name);
+        match ws {
+            Some(ws) => Ok(ws.clone()),
+            _ => bail!("There is no workspace {}", name),
+        }
+    }
+
+    fn handle_new(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = New::parse_full(parser)?;
+        let ws = Rc::new(TestJayWorkspace {
+            id: ev.id,
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            name: Default::default(),
+            rename_requested: Default::default(),
+        });
+        self.tran.add_obj(ws.clone())?;
+        self.workspaces.borrow_mut().push(ws);
+        Ok(())
+    }
+}
+
+impl Drop for TestJayWorkspaceWatcher {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestJayWorkspaceWatcher, JayWorkspaceWatcher;
+
+    NEW => handle_new,
+}
+
+impl TestObject for TestJayWorkspaceWatcher {}
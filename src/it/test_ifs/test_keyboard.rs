@@ -8,7 +8,7 @@ use {
         utils::{buffd::MsgParser, clonecell::CloneCell, numcell::NumCell, once::Once},
         wire::{wl_keyboard::*, WlKeyboardId, WlSurfaceId},
     },
-    std::rc::Rc,
+    std::{cell::Cell, rc::Rc},
 };
 
 pub struct TestEnterEvent {
@@ -27,6 +27,7 @@ pub struct TestKeyboard {
     pub modifiers: TEEH<(usize, Modifiers)>,
     pub enter: TEEH<TestEnterEvent>,
     pub leave: TEEH<Leave>,
+    pub repeat_info: Cell<Option<(i32, i32)>>,
     pub event_id: NumCell<usize>,
 }
 
@@ -73,7 +74,8 @@ impl TestKeyboard {
     }
 
     fn handle_repeat_info(&self, parser: MsgParser<'_, '_>) -> TestResult {
-        let _ev = RepeatInfo::parse_full(parser)?;
+        let ev = RepeatInfo::parse_full(parser)?;
+        self.repeat_info.set(Some((ev.rate, ev.delay)));
         Ok(())
     }
 }
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult,
+            test_ifs::{test_layer_surface::TestLayerSurface, test_surface::TestSurface},
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{zwlr_layer_shell_v1::*, WlOutputId, ZwlrLayerShellV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestLayerShell {
+    pub id: ZwlrLayerShellV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestLayerShell {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn get_layer_surface(
+        &self,
+        surface: &TestSurface,
+        layer: u32,
+    ) -> TestResult<Rc<TestLayerSurface>> {
+        let obj = Rc::new(TestLayerSurface {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            last_serial: Cell::new(0),
+            width: Cell::new(0),
+            height: Cell::new(0),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetLayerSurface {
+            self_id: self.id,
+            id: obj.id,
+            surface: surface.id,
+            output: WlOutputId::NONE,
+            layer,
+            namespace: "test",
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestLayerShell {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestLayerShell, ZwlrLayerShellV1;
+}
+
+impl TestObject for TestLayerShell {}
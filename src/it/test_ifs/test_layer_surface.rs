@@ -0,0 +1,82 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{zwlr_layer_surface_v1::*, ZwlrLayerSurfaceV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestLayerSurface {
+    pub id: ZwlrLayerSurfaceV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub last_serial: Cell<u32>,
+    pub width: Cell<u32>,
+    pub height: Cell<u32>,
+}
+
+impl TestLayerSurface {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_size(&self, width: u32, height: u32) -> TestResult {
+        self.tran.send(SetSize {
+            self_id: self.id,
+            width,
+            height,
+        })?;
+        Ok(())
+    }
+
+    pub fn set_anchor(&self, anchor: u32) -> TestResult {
+        self.tran.send(SetAnchor {
+            self_id: self.id,
+            anchor,
+        })?;
+        Ok(())
+    }
+
+    pub fn ack_configure(&self, serial: u32) -> TestResult {
+        self.tran.send(AckConfigure {
+            self_id: self.id,
+            serial,
+        })?;
+        Ok(())
+    }
+
+    fn handle_configure(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Configure::parse_full(parser)?;
+        self.last_serial.set(ev.serial);
+        self.width.set(ev.width);
+        self.height.set(ev.height);
+        Ok(())
+    }
+
+    fn handle_closed(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Closed::parse_full(parser)?;
+        Ok(())
+    }
+}
+
+impl Drop for TestLayerSurface {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestLayerSurface, ZwlrLayerSurfaceV1;
+
+    CONFIGURE => handle_configure,
+    CLOSED => handle_closed,
+}
+
+impl TestObject for TestLayerSurface {}
@@ -0,0 +1,66 @@
+use {
+    crate::{
+        fixed::Fixed,
+        it::{
+            test_error::{TestError, TestResult},
+            test_object::TestObject,
+            test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{zwp_locked_pointer_v1::*, ZwpLockedPointerV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestLockedPointer {
+    pub id: ZwpLockedPointerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub locked: Cell<bool>,
+}
+
+impl TestLockedPointer {
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_cursor_position_hint(&self, x: Fixed, y: Fixed) -> Result<(), TestError> {
+        self.tran.send(SetCursorPositionHint {
+            self_id: self.id,
+            surface_x: x,
+            surface_y: y,
+        })?;
+        Ok(())
+    }
+
+    fn handle_locked(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Locked::parse_full(parser)?;
+        self.locked.set(true);
+        Ok(())
+    }
+
+    fn handle_unlocked(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Unlocked::parse_full(parser)?;
+        self.locked.set(false);
+        Ok(())
+    }
+}
+
+impl Drop for TestLockedPointer {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestLockedPointer, ZwpLockedPointerV1;
+
+    LOCKED => handle_locked,
+    UNLOCKED => handle_unlocked,
+}
+
+impl TestObject for TestLockedPointer {}
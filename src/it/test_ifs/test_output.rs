@@ -0,0 +1,30 @@
+use crate::{
+    it::{test_error::TestError, test_object::TestObject, testrun::ParseFull},
+    utils::buffd::MsgParser,
+    wire::{wl_output::*, WlOutputId},
+};
+
+pub struct TestOutput {
+    pub id: WlOutputId,
+}
+
+impl TestOutput {
+    fn handle_geometry(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Geometry::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_mode(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Mode::parse_full(parser)?;
+        Ok(())
+    }
+}
+
+test_object! {
+    TestOutput, WlOutput;
+
+    GEOMETRY => handle_geometry,
+    MODE => handle_mode,
+}
+
+impl TestObject for TestOutput {}
@@ -0,0 +1,76 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestError, TestResult},
+            test_ifs::{
+                test_locked_pointer::TestLockedPointer, test_pointer::TestPointer,
+                test_surface::TestSurface,
+            },
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{zwp_pointer_constraints_v1::*, WlRegionId, ZwpPointerConstraintsV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub const LIFETIME_PERSISTENT: u32 = 2;
+
+pub struct TestPointerConstraints {
+    pub id: ZwpPointerConstraintsV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestPointerConstraints {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn lock_pointer(
+        &self,
+        surface: &TestSurface,
+        pointer: &TestPointer,
+        lifetime: u32,
+    ) -> TestResult<Rc<TestLockedPointer>> {
+        let obj = Rc::new(TestLockedPointer {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            locked: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(LockPointer {
+            self_id: self.id,
+            id: obj.id,
+            surface: surface.id,
+            pointer: pointer.id,
+            region: WlRegionId::NONE,
+            lifetime,
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestPointerConstraints {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestPointerConstraints, ZwpPointerConstraintsV1;
+}
+
+impl TestObject for TestPointerConstraints {}
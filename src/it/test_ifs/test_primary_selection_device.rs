@@ -0,0 +1,65 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult,
+            test_ifs::test_primary_selection_offer::TestPrimarySelectionOffer,
+            test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{
+            zwp_primary_selection_device_v1::*, ZwpPrimarySelectionDeviceV1Id,
+            ZwpPrimarySelectionOfferV1Id,
+        },
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestPrimarySelectionDevice {
+    pub id: ZwpPrimarySelectionDeviceV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub selection: TEEH<ZwpPrimarySelectionOfferV1Id>,
+}
+
+impl TestPrimarySelectionDevice {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_data_offer(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = DataOffer::parse_full(parser)?;
+        let offer = Rc::new(TestPrimarySelectionOffer {
+            id: ev.offer,
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+        });
+        self.tran.add_obj(offer.clone())?;
+        offer.destroy()?;
+        Ok(())
+    }
+
+    fn handle_selection(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Selection::parse_full(parser)?;
+        self.selection.push(ev.id);
+        Ok(())
+    }
+}
+
+impl Drop for TestPrimarySelectionDevice {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestPrimarySelectionDevice, ZwpPrimarySelectionDeviceV1;
+
+    DATA_OFFER => handle_data_offer,
+    SELECTION => handle_selection,
+}
+
+impl TestObject for TestPrimarySelectionDevice {}
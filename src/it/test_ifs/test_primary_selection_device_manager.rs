@@ -0,0 +1,65 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult,
+            test_ifs::{
+                test_primary_selection_device::TestPrimarySelectionDevice, test_seat::TestSeat,
+            },
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{zwp_primary_selection_device_manager_v1::*, ZwpPrimarySelectionDeviceManagerV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestPrimarySelectionDeviceManager {
+    pub id: ZwpPrimarySelectionDeviceManagerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestPrimarySelectionDeviceManager {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn get_device(&self, seat: &TestSeat) -> TestResult<Rc<TestPrimarySelectionDevice>> {
+        let obj = Rc::new(TestPrimarySelectionDevice {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            selection: Default::default(),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetDevice {
+            self_id: self.id,
+            id: obj.id,
+            seat: seat.id,
+        })?;
+        Ok(obj)
+    }
+
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TestPrimarySelectionDeviceManager {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestPrimarySelectionDeviceManager, ZwpPrimarySelectionDeviceManagerV1;
+}
+
+impl TestObject for TestPrimarySelectionDeviceManager {}
@@ -0,0 +1,45 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{zwp_primary_selection_offer_v1::*, ZwpPrimarySelectionOfferV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestPrimarySelectionOffer {
+    pub id: ZwpPrimarySelectionOfferV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestPrimarySelectionOffer {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_offer(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Offer::parse_full(parser)?;
+        Ok(())
+    }
+}
+
+impl Drop for TestPrimarySelectionOffer {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestPrimarySelectionOffer, ZwpPrimarySelectionOfferV1;
+
+    OFFER => handle_offer,
+}
+
+impl TestObject for TestPrimarySelectionOffer {}
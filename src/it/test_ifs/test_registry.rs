@@ -9,10 +9,17 @@ use {
                 test_content_type_manager::TestContentTypeManager,
                 test_cursor_shape_manager::TestCursorShapeManager,
                 test_data_control_manager::TestDataControlManager,
-                test_data_device_manager::TestDataDeviceManager, test_dmabuf::TestDmabuf,
+                test_data_device_manager::TestDataDeviceManager,
+                test_decoration_manager::TestDecorationManager, test_dmabuf::TestDmabuf,
                 test_ext_foreign_toplevel_list::TestExtForeignToplevelList,
+                test_idle_inhibit_manager::TestIdleInhibitManager,
                 test_input_method_manager::TestInputMethodManager,
-                test_jay_compositor::TestJayCompositor, test_shm::TestShm,
+                test_jay_compositor::TestJayCompositor,
+                test_keyboard_shortcuts_inhibit_manager::TestKeyboardShortcutsInhibitManager,
+                test_layer_shell::TestLayerShell, test_pointer_constraints::TestPointerConstraints,
+                test_primary_selection_device_manager::TestPrimarySelectionDeviceManager,
+                test_relative_pointer_manager::TestRelativePointerManager,
+                test_screencopy_manager::TestScreencopyManager, test_shm::TestShm,
                 test_single_pixel_buffer_manager::TestSinglePixelBufferManager,
                 test_subcompositor::TestSubcompositor, test_syncobj_manager::TestSyncobjManager,
                 test_text_input_manager::TestTextInputManager,
@@ -58,6 +65,14 @@ pub struct TestRegistrySingletons {
     pub zwp_virtual_keyboard_manager_v1: u32,
     pub zwp_input_method_manager_v2: u32,
     pub zwp_text_input_manager_v3: u32,
+    pub zwlr_screencopy_manager_v1: u32,
+    pub zwp_keyboard_shortcuts_inhibit_manager_v1: u32,
+    pub zwp_relative_pointer_manager_v1: u32,
+    pub zwp_pointer_constraints_v1: u32,
+    pub zwp_primary_selection_device_manager_v1: u32,
+    pub zwp_idle_inhibit_manager_v1: u32,
+    pub zwlr_layer_shell_v1: u32,
+    pub zxdg_decoration_manager_v1: u32,
 }
 
 pub struct TestRegistry {
@@ -85,6 +100,14 @@ pub struct TestRegistry {
     pub virtual_keyboard_manager: CloneCell<Option<Rc<TestVirtualKeyboardManager>>>,
     pub input_method_manager: CloneCell<Option<Rc<TestInputMethodManager>>>,
     pub text_input_manager: CloneCell<Option<Rc<TestTextInputManager>>>,
+    pub screencopy_manager: CloneCell<Option<Rc<TestScreencopyManager>>>,
+    pub shortcuts_inhibit_manager: CloneCell<Option<Rc<TestKeyboardShortcutsInhibitManager>>>,
+    pub relative_pointer_manager: CloneCell<Option<Rc<TestRelativePointerManager>>>,
+    pub pointer_constraints: CloneCell<Option<Rc<TestPointerConstraints>>>,
+    pub primary_selection_device_manager: CloneCell<Option<Rc<TestPrimarySelectionDeviceManager>>>,
+    pub idle_inhibit_manager: CloneCell<Option<Rc<TestIdleInhibitManager>>>,
+    pub layer_shell: CloneCell<Option<Rc<TestLayerShell>>>,
+    pub decoration_manager: CloneCell<Option<Rc<TestDecorationManager>>>,
     pub seats: CopyHashMap<GlobalName, Rc<WlSeatGlobal>>,
 }
 
@@ -156,6 +179,14 @@ impl TestRegistry {
             zwp_virtual_keyboard_manager_v1,
             zwp_input_method_manager_v2,
             zwp_text_input_manager_v3,
+            zwlr_screencopy_manager_v1,
+            zwp_keyboard_shortcuts_inhibit_manager_v1,
+            zwp_relative_pointer_manager_v1,
+            zwp_pointer_constraints_v1,
+            zwp_primary_selection_device_manager_v1,
+            zwp_idle_inhibit_manager_v1,
+            zwlr_layer_shell_v1,
+            zxdg_decoration_manager_v1,
         };
         self.singletons.set(Some(singletons.clone()));
         Ok(singletons)
@@ -165,7 +196,7 @@ impl TestRegistry {
         get_jay_compositor,
         jay_compositor,
         jay_compositor,
-        6,
+        14,
         TestJayCompositor
     );
     create_singleton!(get_compositor, compositor, wl_compositor, 6, TestCompositor);
@@ -271,6 +302,62 @@ impl TestRegistry {
         1,
         TestTextInputManager
     );
+    create_singleton!(
+        get_screencopy_manager,
+        screencopy_manager,
+        zwlr_screencopy_manager_v1,
+        2,
+        TestScreencopyManager
+    );
+    create_singleton!(
+        get_shortcuts_inhibit_manager,
+        shortcuts_inhibit_manager,
+        zwp_keyboard_shortcuts_inhibit_manager_v1,
+        1,
+        TestKeyboardShortcutsInhibitManager
+    );
+    create_singleton!(
+        get_relative_pointer_manager,
+        relative_pointer_manager,
+        zwp_relative_pointer_manager_v1,
+        1,
+        TestRelativePointerManager
+    );
+    create_singleton!(
+        get_pointer_constraints,
+        pointer_constraints,
+        zwp_pointer_constraints_v1,
+        1,
+        TestPointerConstraints
+    );
+    create_singleton!(
+        get_primary_selection_device_manager,
+        primary_selection_device_manager,
+        zwp_primary_selection_device_manager_v1,
+        1,
+        TestPrimarySelectionDeviceManager
+    );
+    create_singleton!(
+        get_idle_inhibit_manager,
+        idle_inhibit_manager,
+        zwp_idle_inhibit_manager_v1,
+        1,
+        TestIdleInhibitManager
+    );
+    create_singleton!(
+        get_layer_shell,
+        layer_shell,
+        zwlr_layer_shell_v1,
+        3,
+        TestLayerShell
+    );
+    create_singleton!(
+        get_decoration_manager,
+        decoration_manager,
+        zxdg_decoration_manager_v1,
+        1,
+        TestDecorationManager
+    );
 
     pub fn bind<O: TestObject>(
         &self,
@@ -0,0 +1,50 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestError, TestResult},
+            test_object::TestObject,
+            test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{zwp_relative_pointer_v1::*, ZwpRelativePointerV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestRelativePointer {
+    pub id: ZwpRelativePointerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub relative_motion: TEEH<RelativeMotion>,
+}
+
+impl TestRelativePointer {
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_relative_motion(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = RelativeMotion::parse_full(parser)?;
+        self.relative_motion.push(ev);
+        Ok(())
+    }
+}
+
+impl Drop for TestRelativePointer {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestRelativePointer, ZwpRelativePointerV1;
+
+    RELATIVE_MOTION => handle_relative_motion,
+}
+
+impl TestObject for TestRelativePointer {}
@@ -0,0 +1,66 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestError, TestResult},
+            test_ifs::{test_pointer::TestPointer, test_relative_pointer::TestRelativePointer},
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{zwp_relative_pointer_manager_v1::*, ZwpRelativePointerManagerV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestRelativePointerManager {
+    pub id: ZwpRelativePointerManagerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestRelativePointerManager {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn get_relative_pointer(
+        &self,
+        pointer: &TestPointer,
+    ) -> TestResult<Rc<TestRelativePointer>> {
+        let obj = Rc::new(TestRelativePointer {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            relative_motion: Rc::new(Default::default()),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetRelativePointer {
+            self_id: self.id,
+            id: obj.id,
+            pointer: pointer.id,
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestRelativePointerManager {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestRelativePointerManager, ZwpRelativePointerManagerV1;
+}
+
+impl TestObject for TestRelativePointerManager {}
@@ -0,0 +1,85 @@
+use {
+    crate::{
+        it::{
+            test_error::TestError, test_ifs::test_buffer::TestBuffer, test_object::TestObject,
+            test_transport::TestTransport, testrun::ParseFull,
+        },
+        rect::Rect,
+        utils::buffd::MsgParser,
+        wire::{zwlr_screencopy_frame_v1::*, ZwlrScreencopyFrameV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestScreencopyFrame {
+    pub id: ZwlrScreencopyFrameV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub failed: Cell<bool>,
+    pub ready: Cell<bool>,
+    pub damage: Cell<Option<Rect>>,
+}
+
+impl TestScreencopyFrame {
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn copy(&self, buffer: &TestBuffer) -> Result<(), TestError> {
+        self.tran.send(Copy {
+            self_id: self.id,
+            buffer: buffer.id,
+        })
+    }
+
+    pub fn copy_with_damage(&self, buffer: &TestBuffer) -> Result<(), TestError> {
+        self.tran.send(CopyWithDamage {
+            self_id: self.id,
+            buffer: buffer.id,
+        })
+    }
+
+    fn handle_buffer(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Buffer::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_failed(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Failed::parse_full(parser)?;
+        self.failed.set(true);
+        Ok(())
+    }
+
+    fn handle_ready(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Ready::parse_full(parser)?;
+        self.ready.set(true);
+        Ok(())
+    }
+
+    fn handle_damage(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = Damage::parse_full(parser)?;
+        let rect = Rect::new_sized(ev.x as _, ev.y as _, ev.width as _, ev.height as _);
+        self.damage.set(rect);
+        Ok(())
+    }
+}
+
+impl Drop for TestScreencopyFrame {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestScreencopyFrame, ZwlrScreencopyFrameV1;
+
+    BUFFER => handle_buffer,
+    FAILED => handle_failed,
+    READY => handle_ready,
+    DAMAGE => handle_damage,
+}
+
+impl TestObject for TestScreencopyFrame {}
@@ -0,0 +1,66 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestError, TestResult},
+            test_ifs::{test_output::TestOutput, test_screencopy_frame::TestScreencopyFrame},
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{zwlr_screencopy_manager_v1::*, ZwlrScreencopyManagerV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestScreencopyManager {
+    pub id: ZwlrScreencopyManagerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestScreencopyManager {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn capture_output(&self, output: &TestOutput) -> TestResult<Rc<TestScreencopyFrame>> {
+        let obj = Rc::new(TestScreencopyFrame {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            failed: Cell::new(false),
+            ready: Cell::new(false),
+            damage: Cell::new(None),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(CaptureOutput {
+            self_id: self.id,
+            frame: obj.id,
+            overlay_cursor: 0,
+            output: output.id,
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestScreencopyManager {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestScreencopyManager, ZwlrScreencopyManagerV1;
+}
+
+impl TestObject for TestScreencopyManager {}
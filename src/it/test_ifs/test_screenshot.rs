@@ -20,6 +20,15 @@ pub struct TestJayScreenshot {
     pub drm_dev: Cell<Option<Rc<OwnedFd>>>,
     pub planes: RefCell<PlaneVec<DmaBufPlane>>,
     pub result: Cell<Option<Result<DmaBuf, String>>>,
+    pub shm: Cell<Option<TestShmScreenshot>>,
+}
+
+pub struct TestShmScreenshot {
+    pub fd: Rc<OwnedFd>,
+    pub width: i32,
+    pub height: i32,
+    pub stride: i32,
+    pub format: u32,
 }
 
 impl TestJayScreenshot {
@@ -76,6 +85,18 @@ impl TestJayScreenshot {
         })));
         Ok(())
     }
+
+    fn handle_shm(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = Shm::parse_full(parser)?;
+        self.shm.set(Some(TestShmScreenshot {
+            fd: ev.fd,
+            width: ev.width,
+            height: ev.height,
+            stride: ev.stride,
+            format: ev.format,
+        }));
+        Ok(())
+    }
 }
 
 test_object! {
@@ -86,6 +107,7 @@ test_object! {
     DRM_DEV => handle_drm_dev,
     PLANE => handle_plane,
     DMABUF2 => handle_dmabuf2,
+    SHM => handle_shm,
 }
 
 impl TestObject for TestJayScreenshot {}
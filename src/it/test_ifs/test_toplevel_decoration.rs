@@ -0,0 +1,54 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{zxdg_toplevel_decoration_v1::*, ZxdgToplevelDecorationV1Id},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestToplevelDecoration {
+    pub id: ZxdgToplevelDecorationV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub mode: Cell<Option<u32>>,
+}
+
+impl TestToplevelDecoration {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_mode(&self, mode: u32) -> TestResult {
+        self.tran.send(SetMode {
+            self_id: self.id,
+            mode,
+        })?;
+        Ok(())
+    }
+
+    fn handle_configure(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Configure::parse_full(parser)?;
+        self.mode.set(Some(ev.mode));
+        Ok(())
+    }
+}
+
+impl Drop for TestToplevelDecoration {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestToplevelDecoration, ZxdgToplevelDecorationV1;
+    CONFIGURE => handle_configure,
+}
+
+impl TestObject for TestToplevelDecoration {}
@@ -0,0 +1,89 @@
+use {
+    crate::{
+        it::{
+            test_error::TestResult, test_object::TestObject, test_transport::TestTransport,
+            test_utils::test_expected_event::TEEH, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{wl_touch::*, WlTouchId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestTouch {
+    pub id: WlTouchId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub down: TEEH<Down>,
+    pub up: TEEH<Up>,
+    pub motion: TEEH<Motion>,
+    pub cancel: TEEH<Cancel>,
+}
+
+impl TestTouch {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Release { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_down(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Down::parse_full(parser)?;
+        self.down.push(ev);
+        Ok(())
+    }
+
+    fn handle_up(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Up::parse_full(parser)?;
+        self.up.push(ev);
+        Ok(())
+    }
+
+    fn handle_motion(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Motion::parse_full(parser)?;
+        self.motion.push(ev);
+        Ok(())
+    }
+
+    fn handle_frame(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Frame::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_cancel(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let ev = Cancel::parse_full(parser)?;
+        self.cancel.push(ev);
+        Ok(())
+    }
+
+    fn handle_shape(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Shape::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_orientation(&self, parser: MsgParser<'_, '_>) -> TestResult {
+        let _ev = Orientation::parse_full(parser)?;
+        Ok(())
+    }
+}
+
+impl Drop for TestTouch {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestTouch, WlTouch;
+
+    DOWN => handle_down,
+    UP => handle_up,
+    MOTION => handle_motion,
+    FRAME => handle_frame,
+    CANCEL => handle_cancel,
+    SHAPE => handle_shape,
+    ORIENTATION => handle_orientation,
+}
+
+impl TestObject for TestTouch {}
@@ -1,8 +1,11 @@
 use {
     crate::{
         it::{
-            test_error::TestError, test_ifs::test_xdg_surface::TestXdgSurface,
-            test_object::TestObject, test_transport::TestTransport, testrun::ParseFull,
+            test_error::{TestError, TestResult},
+            test_ifs::{test_xdg_positioner::TestXdgPositioner, test_xdg_surface::TestXdgSurface},
+            test_object::TestObject,
+            test_transport::TestTransport,
+            testrun::ParseFull,
         },
         utils::buffd::MsgParser,
         wire::{xdg_wm_base::*, WlSurfaceId, XdgWmBaseId},
@@ -56,6 +59,20 @@ impl TestXdgWmBase {
         Ok(xdg)
     }
 
+    pub fn create_positioner(&self) -> TestResult<Rc<TestXdgPositioner>> {
+        let obj = Rc::new(TestXdgPositioner {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(CreatePositioner {
+            self_id: self.id,
+            id: obj.id,
+        })?;
+        Ok(obj)
+    }
+
     fn handle_ping(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
         let _ev = Ping::parse_full(parser)?;
         Ok(())
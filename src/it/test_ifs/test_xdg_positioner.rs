@@ -0,0 +1,54 @@
+use {
+    crate::{
+        it::{test_error::TestResult, test_object::TestObject, test_transport::TestTransport},
+        wire::{xdg_positioner::*, XdgPositionerId},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestXdgPositioner {
+    pub id: XdgPositionerId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestXdgPositioner {
+    pub fn destroy(&self) -> TestResult {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_size(&self, width: i32, height: i32) -> TestResult {
+        self.tran.send(SetSize {
+            self_id: self.id,
+            width,
+            height,
+        })?;
+        Ok(())
+    }
+
+    pub fn set_anchor_rect(&self, x: i32, y: i32, width: i32, height: i32) -> TestResult {
+        self.tran.send(SetAnchorRect {
+            self_id: self.id,
+            x,
+            y,
+            width,
+            height,
+        })?;
+        Ok(())
+    }
+}
+
+impl Drop for TestXdgPositioner {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestXdgPositioner, XdgPositioner;
+}
+
+impl TestObject for TestXdgPositioner {}
@@ -73,6 +73,14 @@ impl TestTransport {
             virtual_keyboard_manager: Default::default(),
             input_method_manager: Default::default(),
             text_input_manager: Default::default(),
+            screencopy_manager: Default::default(),
+            shortcuts_inhibit_manager: Default::default(),
+            relative_pointer_manager: Default::default(),
+            pointer_constraints: Default::default(),
+            primary_selection_device_manager: Default::default(),
+            idle_inhibit_manager: Default::default(),
+            layer_shell: Default::default(),
+            decoration_manager: Default::default(),
             seats: Default::default(),
         });
         self.send(wl_display::GetRegistry {
@@ -1,13 +1,16 @@
 use {
     crate::{
-        client::{ClientId, RequestParser},
+        backend::{
+            BackendEvent, ConnectorEvent, ConnectorId, ConnectorKernelId, Mode, MonitorInfo,
+        },
+        client::{ClientCaps, ClientId, RequestParser},
         fixed::Fixed,
-        ifs::wl_seat::WlSeatGlobal,
+        ifs::{wl_output::OutputId, wl_seat::WlSeatGlobal},
         it::{
             test_backend::{TestBackend, TestBackendKb, TestBackendMouse, TestConnector},
             test_client::TestClient,
             test_config::TestConfig,
-            test_error::{TestError, TestErrorExt},
+            test_error::{TestError, TestErrorExt, TestResult},
             test_ifs::test_display::TestDisplay,
             test_transport::TestTransport,
         },
@@ -15,12 +18,13 @@ use {
         state::State,
         tree::OutputNode,
         utils::{bitfield::Bitfield, buffd::MsgParser, oserror::OsErrorExt, stack::Stack},
+        video::drm::ConnectorType,
     },
     std::{
         cell::{Cell, RefCell},
         rc::Rc,
     },
-    uapi::c,
+    uapi::{c, Errno, OwnedFd},
 };
 
 pub struct TestRun {
@@ -51,6 +55,37 @@ impl TestRun {
             .connect(&socket, &self.server_addr)
             .await
             .with_context(|| "Could not connect to the compositor")?;
+        self.init_client(socket).await
+    }
+
+    /// Creates a client that is treated like the Xwayland client by the compositor.
+    pub async fn create_xwayland_client(self: &Rc<Self>) -> Result<Rc<TestClient>, TestError> {
+        self.create_xwayland_client2()
+            .await
+            .with_context(|| "Could not create an Xwayland client")
+    }
+
+    async fn create_xwayland_client2(self: &Rc<Self>) -> Result<Rc<TestClient>, TestError> {
+        let (server, socket) = uapi::socketpair(c::AF_UNIX, c::SOCK_STREAM | c::SOCK_CLOEXEC, 0)
+            .to_os_error()
+            .with_context(|| "Could not create a socketpair")?;
+        self.state.clients.spawn2(
+            self.state.clients.id(),
+            &self.state,
+            Rc::new(server),
+            uapi::getuid(),
+            uapi::getpid(),
+            ClientCaps::all(),
+            ClientCaps::all(),
+            true,
+        )?;
+        self.init_client(Rc::new(socket)).await
+    }
+
+    async fn init_client(
+        self: &Rc<Self>,
+        socket: Rc<OwnedFd>,
+    ) -> Result<Rc<TestClient>, TestError> {
         let mut obj_ids = Bitfield::default();
         obj_ids.take(0);
         obj_ids.take(1);
@@ -79,7 +114,7 @@ impl TestRun {
         let client = self.state.clients.get(client_id)?;
         Ok(Rc::new(TestClient {
             run: self.clone(),
-            _server: client,
+            server: client,
             tran,
             jc,
             comp: registry.get_compositor().await?,
@@ -133,9 +168,105 @@ impl TestRun {
         })
     }
 
+    /// Adds a second connector `VGA-2` to the backend.
+    ///
+    /// The connector starts out disconnected. The first mode is the initial mode of the
+    /// monitor.
+    pub fn create_second_connector(&self, modes: &[Mode]) -> TestSecondConnector {
+        let connector = Rc::new(TestConnector {
+            id: self.state.connector_ids.next(),
+            kernel_id: ConnectorKernelId {
+                ty: ConnectorType::VGA,
+                idx: 2,
+            },
+            events: Default::default(),
+            feedback: Default::default(),
+            gamma_size: Default::default(),
+            gamma_ramp: Default::default(),
+            power: Cell::new(true),
+            render_scale: Cell::new(1.0),
+            flip_margin: Default::default(),
+        });
+        let monitor_info = MonitorInfo {
+            modes: modes.to_vec(),
+            output_id: Rc::new(OutputId::new(
+                "VGA-2".to_string(),
+                "jay".to_string(),
+                "jay second connector".to_string(),
+                "2".to_string(),
+            )),
+            initial_mode: modes[0],
+            width_mm: 0,
+            height_mm: 0,
+            non_desktop: false,
+            vrr_capable: false,
+        };
+        self.backend
+            .state
+            .backend_events
+            .push(BackendEvent::NewConnector(connector.clone()));
+        TestSecondConnector {
+            connector,
+            monitor_info,
+            state: self.state.clone(),
+        }
+    }
+
     pub async fn sync(&self) {
         self.state.eng.yield_now().await;
     }
+
+    /// Sends a signal to the thread that runs the compositor.
+    pub fn raise(&self, signal: c::c_int) -> TestResult {
+        let res = unsafe { c::pthread_kill(c::pthread_self(), signal) };
+        if res != 0 {
+            return Err(Errno(res))
+                .to_os_error()
+                .with_context(|| "Could not send a signal");
+        }
+        Ok(())
+    }
+
+    /// Returns the log that has been written by this test so far.
+    pub fn log(&self) -> TestResult<String> {
+        std::fs::read_to_string(format!("{}/log", self.out_dir))
+            .with_context(|| "Could not read the log")
+    }
+}
+
+pub struct TestSecondConnector {
+    pub connector: Rc<TestConnector>,
+    pub monitor_info: MonitorInfo,
+    state: Rc<State>,
+}
+
+impl TestSecondConnector {
+    pub fn id(&self) -> ConnectorId {
+        self.connector.id
+    }
+
+    pub fn output(&self) -> Option<Rc<OutputNode>> {
+        self.state.root.outputs.get(&self.connector.id)
+    }
+
+    /// Connects the monitor and returns the output that was created for it.
+    pub async fn connect(&self) -> TestResult<Rc<OutputNode>> {
+        self.connector
+            .events
+            .send_event(ConnectorEvent::Connected(self.monitor_info.clone()));
+        self.state.eng.yield_now().await;
+        match self.output() {
+            Some(o) => Ok(o),
+            _ => bail!("output was not created"),
+        }
+    }
+
+    pub async fn disconnect(&self) {
+        self.connector
+            .events
+            .send_event(ConnectorEvent::Disconnected);
+        self.state.eng.yield_now().await;
+    }
 }
 
 pub trait ParseFull<'a>: Sized {
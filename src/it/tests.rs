@@ -74,6 +74,101 @@ mod t0039_alpha_modifier;
 mod t0040_virtual_keyboard;
 mod t0041_input_method;
 mod t0042_toplevel_select;
+mod t0043_seat_clipboard;
+mod t0044_output_workspace_capture;
+mod t0045_block_capture;
+mod t0046_screencopy_without_buffer;
+mod t0047_zero_title_height;
+mod t0048_workspace_output_affinity;
+mod t0049_output_span;
+mod t0050_game_mode;
+mod t0051_close_workspace;
+mod t0052_vrr_content_type;
+mod t0053_max_scale;
+mod t0054_output_mirror;
+mod t0055_idle_grace_period;
+mod t0056_cycle_layout;
+mod t0057_device_repeat_rate;
+mod t0058_focus_follows_mouse_dwell;
+mod t0059_warp_pointer_on_focus;
+mod t0060_shortcuts_inhibit;
+mod t0061_zoom_scroll;
+mod t0063_accel;
+mod t0064_relative_pointer;
+mod t0065_pointer_lock_hint;
+mod t0066_tap;
+mod t0067_touch;
+mod t0068_workspace_swipe;
+mod t0069_sticky;
+mod t0070_dnd_threshold;
+mod t0071_middle_click_paste;
+mod t0072_jay_clipboard;
+mod t0073_jay_clipboard_changes;
+mod t0074_list_shortcuts;
+mod t0075_which_key;
+mod t0076_pointer_shortcuts;
+mod t0077_output_watcher;
+mod t0078_randr_set_mode;
+mod t0079_randr_layout;
+mod t0080_restore_output_layout;
+mod t0081_output_transform_pointer;
+mod t0082_hardware_cursor;
+mod t0083_cursor_theme;
+mod t0084_hide_cursor_while_typing;
+mod t0085_hide_cursor_timeout;
+mod t0086_toplevel_screencast;
+mod t0087_screencopy_damage;
+mod t0088_cursor_image;
+mod t0089_xwayland_dnd;
+mod t0090_screencast_region;
+mod t0091_client_errors;
+mod t0092_idle_inhibitor_status;
+mod t0093_vrr_min_refresh;
+mod t0094_output_throttle;
+mod t0095_output_background;
+mod t0096_tearing_content_type;
+mod t0097_presentation_feed;
+mod t0098_frame_timings;
+mod t0099_workspace_scroll_skip_empty;
+mod t0100_reset_window_rules;
+mod t0101_screenshot_after_presentation;
+mod t0102_zoom_input;
+mod t0103_color_matrix_composite;
+mod t0104_output_gamma;
+mod t0105_presentation_statistics;
+mod t0106_vblank_cursor_updates;
+mod t0107_disable_hardware_cursor;
+mod t0108_cursor_hotspot_update;
+mod t0109_pointer_locator;
+mod t0110_invert_colors;
+mod t0111_decoration_mode;
+mod t0112_window_border;
+mod t0113_focused_output_indicator;
+mod t0114_desktop_click;
+mod t0115_keyboard_layout_name;
+mod t0116_keymap_names;
+mod t0117_config_safe_mode;
+mod t0118_use_default_config;
+mod t0119_key_sequences;
+mod t0120_idle_time;
+mod t0121_move_workspace_to_output;
+mod t0122_title_bar_position;
+mod t0123_output_power;
+mod t0124_pointer_position;
+mod t0125_screencast_cursor_only;
+mod t0126_hot_corners;
+mod t0127_pointer_edge_behavior;
+mod t0128_output_screenshot;
+mod t0129_render_scale;
+mod t0130_screenshot_shm;
+mod t0131_workspace_scroll_settings;
+mod t0132_flip_margin;
+mod t0133_status_truncation;
+mod t0134_workspace_rename_request;
+mod t0135_sighup_reload;
+mod t0136_sigusr1_dump;
+mod t0137_config_load_errors;
+mod t0138_rename_workspace;
 
 pub trait TestCase: Sync {
     fn name(&self) -> &'static str;
@@ -135,5 +230,100 @@ pub fn tests() -> Vec<&'static dyn TestCase> {
         t0040_virtual_keyboard,
         t0041_input_method,
         t0042_toplevel_select,
+        t0043_seat_clipboard,
+        t0044_output_workspace_capture,
+        t0045_block_capture,
+        t0046_screencopy_without_buffer,
+        t0047_zero_title_height,
+        t0048_workspace_output_affinity,
+        t0049_output_span,
+        t0050_game_mode,
+        t0051_close_workspace,
+        t0052_vrr_content_type,
+        t0053_max_scale,
+        t0054_output_mirror,
+        t0055_idle_grace_period,
+        t0056_cycle_layout,
+        t0057_device_repeat_rate,
+        t0058_focus_follows_mouse_dwell,
+        t0059_warp_pointer_on_focus,
+        t0060_shortcuts_inhibit,
+        t0061_zoom_scroll,
+        t0063_accel,
+        t0064_relative_pointer,
+        t0065_pointer_lock_hint,
+        t0066_tap,
+        t0067_touch,
+        t0068_workspace_swipe,
+        t0069_sticky,
+        t0070_dnd_threshold,
+        t0071_middle_click_paste,
+        t0072_jay_clipboard,
+        t0073_jay_clipboard_changes,
+        t0074_list_shortcuts,
+        t0075_which_key,
+        t0076_pointer_shortcuts,
+        t0077_output_watcher,
+        t0078_randr_set_mode,
+        t0079_randr_layout,
+        t0080_restore_output_layout,
+        t0081_output_transform_pointer,
+        t0082_hardware_cursor,
+        t0083_cursor_theme,
+        t0084_hide_cursor_while_typing,
+        t0085_hide_cursor_timeout,
+        t0086_toplevel_screencast,
+        t0087_screencopy_damage,
+        t0088_cursor_image,
+        t0089_xwayland_dnd,
+        t0090_screencast_region,
+        t0091_client_errors,
+        t0092_idle_inhibitor_status,
+        t0093_vrr_min_refresh,
+        t0094_output_throttle,
+        t0095_output_background,
+        t0096_tearing_content_type,
+        t0097_presentation_feed,
+        t0098_frame_timings,
+        t0099_workspace_scroll_skip_empty,
+        t0100_reset_window_rules,
+        t0101_screenshot_after_presentation,
+        t0102_zoom_input,
+        t0103_color_matrix_composite,
+        t0104_output_gamma,
+        t0105_presentation_statistics,
+        t0106_vblank_cursor_updates,
+        t0107_disable_hardware_cursor,
+        t0108_cursor_hotspot_update,
+        t0109_pointer_locator,
+        t0110_invert_colors,
+        t0111_decoration_mode,
+        t0112_window_border,
+        t0113_focused_output_indicator,
+        t0114_desktop_click,
+        t0115_keyboard_layout_name,
+        t0116_keymap_names,
+        t0117_config_safe_mode,
+        t0118_use_default_config,
+        t0119_key_sequences,
+        t0120_idle_time,
+        t0121_move_workspace_to_output,
+        t0122_title_bar_position,
+        t0123_output_power,
+        t0124_pointer_position,
+        t0125_screencast_cursor_only,
+        t0126_hot_corners,
+        t0127_pointer_edge_behavior,
+        t0128_output_screenshot,
+        t0129_render_scale,
+        t0130_screenshot_shm,
+        t0131_workspace_scroll_settings,
+        t0132_flip_margin,
+        t0133_status_truncation,
+        t0134_workspace_rename_request,
+        t0135_sighup_reload,
+        t0136_sigusr1_dump,
+        t0137_config_load_errors,
+        t0138_rename_workspace,
     }
 }
@@ -27,7 +27,11 @@ async fn test(run: Rc<TestRun>) -> TestResult {
 
     client.sync().await;
 
-    let name = ds.output.workspace.get().map(|ws| ws.name.clone());
+    let name = ds
+        .output
+        .workspace
+        .get()
+        .map(|ws| ws.name.get().to_string());
     tassert_eq!(name.as_deref(), Some("1"));
 
     let pos = {
@@ -39,7 +43,11 @@ async fn test(run: Rc<TestRun>) -> TestResult {
 
     client.sync().await;
 
-    let name = ds.output.workspace.get().map(|ws| ws.name.clone());
+    let name = ds
+        .output
+        .workspace
+        .get()
+        .map(|ws| ws.name.get().to_string());
     tassert_eq!(name.as_deref(), Some("2"));
 
     Ok(())
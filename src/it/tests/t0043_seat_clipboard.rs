@@ -0,0 +1,60 @@
+use {
+    crate::it::{
+        test_error::{TestErrorExt, TestResult},
+        testrun::TestRun,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that seats have separate clipboards unless the clipboard is shared
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+    run.get_seat("seat2")?;
+
+    let client = run.create_client().await?;
+    let seat1 = client.get_default_seat().await?;
+    let seat2 = client.get_seat("seat2").await?;
+    let data_control = client.registry.get_data_control_manager().await?;
+    let dev1 = data_control.get_data_device(&seat1.seat)?;
+    let dev2 = data_control.get_data_device(&seat2.seat)?;
+    let selection1 = dev1.selection.expect()?;
+    let selection2 = dev2.selection.expect()?;
+
+    let source1 = data_control.create_data_source()?;
+    source1.offer("one")?;
+    let source2 = data_control.create_data_source()?;
+    source2.offer("two")?;
+    dev1.set_selection(&source1)?;
+    dev2.set_selection(&source2)?;
+    client.sync().await;
+
+    let Some(sel) = selection1.last().with_context(|| "selection 1")? else {
+        bail!("no selection on seat 1");
+    };
+    tassert!(sel.offers.borrow().contains("one"));
+    tassert!(!sel.offers.borrow().contains("two"));
+    let Some(sel) = selection2.last().with_context(|| "selection 2")? else {
+        bail!("no selection on seat 2");
+    };
+    tassert!(sel.offers.borrow().contains("two"));
+    tassert!(!sel.offers.borrow().contains("one"));
+    tassert_eq!(source1.cancelled.get(), false);
+    tassert_eq!(source2.cancelled.get(), false);
+
+    run.state.shared_clipboard.set(true);
+    let source3 = data_control.create_data_source()?;
+    source3.offer("three")?;
+    dev1.set_selection(&source3)?;
+    client.sync().await;
+
+    tassert_eq!(source1.cancelled.get(), true);
+    tassert_eq!(source2.cancelled.get(), true);
+    let Some(sel) = selection2.last().with_context(|| "selection 3")? else {
+        bail!("no shared selection on seat 2");
+    };
+    tassert!(sel.offers.borrow().contains("three"));
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use {
+    crate::{
+        format::XRGB8888,
+        gfx_api::{AcquireSync, ReleaseSync},
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+testcase!();
+
+/// Test that workspaces on an output that disallows capturing cannot be captured
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+
+    let Some(ws1) = ds.output.workspace.get() else {
+        bail!("no workspace");
+    };
+    tassert!(ws1.may_capture.get());
+
+    run.cfg.set_default_workspace_capture(&ds.output, false)?;
+    tassert!(!ds.output.default_workspace_capture());
+    tassert!(!ws1.may_capture.get());
+
+    run.cfg.show_workspace(ds.seat.id(), "2")?;
+    let Some(ws2) = ds.output.workspace.get() else {
+        bail!("no workspace");
+    };
+    tassert_eq!(ws2.name.get().as_str(), "2");
+    tassert!(!ws2.may_capture.get());
+
+    tassert!(run.state.default_workspace_capture.get());
+
+    let client = run.create_client().await?;
+    let output = client.get_output(&ds.output)?;
+    let manager = client.registry.get_screencopy_manager().await?;
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    let data = [const { Cell::new(0) }; 4];
+    let tex = ctx
+        .shmem_texture(None, &data, XRGB8888, 1, 1, 4, None)?
+        .into_texture();
+    let render = || {
+        ds.output.latched(false);
+        ds.output.perform_wlr_screencopies(
+            &tex,
+            None,
+            &AcquireSync::Unnecessary,
+            ReleaseSync::None,
+            false,
+            0,
+            0,
+            None,
+        );
+    };
+    let pos = ds.output.global.pos.get();
+    let (width, height) = (pos.width(), pos.height());
+    let pool = client.shm.create_pool((width * height * 4) as _)?;
+    let buffer = pool.create_buffer(0, width, height, width * 4, XRGB8888)?;
+
+    let frame = manager.capture_output(&output)?;
+    frame.copy(&buffer.buffer)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    tassert!(!ws2.has_capture.get());
+    tassert!(!frame.ready.get());
+
+    run.cfg.set_default_workspace_capture(&ds.output, true)?;
+    tassert!(ws1.may_capture.get());
+    tassert!(ws2.may_capture.get());
+    tassert!(ws2.has_capture.get());
+    render();
+    client.sync().await;
+    tassert!(frame.ready.get());
+
+    Ok(())
+}
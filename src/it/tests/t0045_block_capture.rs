@@ -0,0 +1,39 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::ToplevelNodeBase,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that windows that block captures are rendered black in screenshots
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.tl.core.set_app_id("secret")?;
+    win.set_color(0, 0, 0, 255);
+    win.map2().await?;
+
+    let black = client.take_screenshot(false).await?;
+
+    win.set_color(255, 0, 0, 255);
+    win.map2().await?;
+
+    let red = client.take_screenshot(false).await?;
+    tassert!(red != black);
+
+    run.cfg.set_block_capture("secret", true)?;
+    tassert!(win.tl.server.tl_data().capture_blocked());
+    tassert!(client.take_screenshot(false).await? == black);
+
+    run.cfg.set_block_capture("secret", false)?;
+    tassert!(!win.tl.server.tl_data().capture_blocked());
+    tassert!(client.take_screenshot(false).await? == red);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use {
+    crate::{
+        format::XRGB8888,
+        gfx_api::{AcquireSync, ReleaseSync},
+        ifs::zwlr_screencopy_frame_v1::{ZwlrScreencopyFrameV1, MAX_SCREENCOPIES_WITHOUT_BUFFER},
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+testcase!();
+
+/// Test that clients that repeatedly request captures without buffers are disconnected
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let output = client.get_output(&ds.output)?;
+    let manager = client.registry.get_screencopy_manager().await?;
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    let data = [const { Cell::new(0) }; 4];
+    let tex = ctx
+        .shmem_texture(None, &data, XRGB8888, 1, 1, 4, None)?
+        .into_texture();
+
+    for i in 1..=MAX_SCREENCOPIES_WITHOUT_BUFFER {
+        let frame = manager.capture_output(&output)?;
+        client.sync().await;
+        let server_frame = client
+            .tran
+            .get_server_obj::<_, ZwlrScreencopyFrameV1>(frame.id)?;
+        ds.output
+            .screencopies
+            .set((client.server.id, frame.id), server_frame);
+        ds.output.perform_wlr_screencopies(
+            &tex,
+            None,
+            &AcquireSync::Unnecessary,
+            ReleaseSync::None,
+            false,
+            0,
+            0,
+            None,
+        );
+        if i < MAX_SCREENCOPIES_WITHOUT_BUFFER {
+            client.sync().await;
+            tassert!(frame.failed.get());
+        }
+    }
+
+    tassert!(run.state.clients.get(client.server.id).is_err());
+
+    run.sync().await;
+    run.state.idle().await;
+    let errors = run.errors.take();
+    tassert!(errors
+        .iter()
+        .any(|e| e.contains("captures without attaching a buffer")));
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::theme::sized::TITLE_HEIGHT,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that a title height of 0 hides the bar completely
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    tassert!(ds.output.workspace_rect.get() != ds.output.non_exclusive_rect.get());
+
+    run.cfg.set_size(TITLE_HEIGHT, 0)?;
+    tassert_eq!(
+        ds.output.workspace_rect.get(),
+        ds.output.non_exclusive_rect.get()
+    );
+    tassert_eq!(ds.output.workspace_y_rel(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        backend::{ConnectorId, Mode},
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::video::OutputId as ConfigOutputId,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that workspaces are placed according to their output affinity
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let second_connector = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60000,
+    }]);
+    second_connector.connect().await?;
+
+    let second = ConfigOutputId {
+        connector: "VGA-2".to_string(),
+        manufacturer: "jay".to_string(),
+        model: "jay second connector".to_string(),
+        serial_number: "2".to_string(),
+    };
+    let absent1 = ConfigOutputId {
+        serial_number: "3".to_string(),
+        ..second.clone()
+    };
+    let absent2 = ConfigOutputId {
+        serial_number: "4".to_string(),
+        ..second.clone()
+    };
+
+    let ws_connector = |name: &str| -> TestResult<ConnectorId> {
+        let Some(ws) = run.state.workspaces.get(name) else {
+            bail!("workspace {name} does not exist");
+        };
+        Ok(ws.output.get().global.connector.connector.id())
+    };
+
+    // preferred output present
+    run.cfg
+        .set_workspace_output_affinity("2", Some(second.clone()), None)?;
+    run.cfg.show_workspace(ds.seat.id(), "2")?;
+    tassert_eq!(ws_connector("2")?, second_connector.id());
+
+    // preferred output absent, fallback present
+    run.cfg
+        .set_workspace_output_affinity("3", Some(absent1.clone()), Some(second.clone()))?;
+    run.cfg.show_workspace(ds.seat.id(), "3")?;
+    tassert_eq!(ws_connector("3")?, second_connector.id());
+
+    // preferred and fallback output absent
+    run.cfg
+        .set_workspace_output_affinity("4", Some(absent1.clone()), Some(absent2.clone()))?;
+    run.cfg.show_workspace(ds.seat.id(), "4")?;
+    tassert_eq!(ws_connector("4")?, ds.connector.id);
+
+    // affinities are re-evaluated on hotplug
+    second_connector.disconnect().await;
+    tassert_eq!(ws_connector("3")?, ds.connector.id);
+
+    second_connector.connect().await?;
+    tassert_eq!(ws_connector("3")?, second_connector.id());
+    tassert_eq!(ws_connector("4")?, ds.connector.id);
+
+    run.cfg.show_workspace(ds.seat.id(), "2")?;
+    tassert_eq!(ws_connector("2")?, second_connector.id());
+
+    Ok(())
+}
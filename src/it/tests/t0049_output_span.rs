@@ -0,0 +1,67 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::OutputNode,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that two outputs can be combined into a single logical output
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let second_connector = run.create_second_connector(&[Mode {
+        width: 800,
+        height: 600,
+        refresh_rate_millihz: 60_000,
+    }]);
+    let second = second_connector.connect().await?;
+    let client = run.create_client().await?;
+    let advertised =
+        |output: &OutputNode| client.registry.globals.contains(&output.global.name.raw());
+    client.sync().await;
+    tassert!(advertised(&second));
+
+    // outputs with different scales cannot be spanned
+    run.cfg.set_scale(&second, 2.0)?;
+    run.cfg.set_span(&ds.output, Some(&second))?;
+    tassert!(!ds.output.is_spanned());
+    run.cfg.set_scale(&second, 1.0)?;
+
+    run.cfg.set_span(&ds.output, Some(&second))?;
+    tassert!(ds.output.is_spanned());
+    tassert!(second_connector.output().is_none());
+    let pos = ds.output.global.pos.get();
+    tassert_eq!(pos.width(), 1600);
+    tassert_eq!(pos.height(), 600);
+    tassert!(second.workspaces.is_empty());
+    client.sync().await;
+    tassert!(!advertised(&second));
+
+    // unplugging the secondary output degrades to a single output
+    second_connector.disconnect().await;
+    tassert!(!ds.output.is_spanned());
+    tassert_eq!(ds.output.global.pos.get().width(), 800);
+
+    let second = second_connector.connect().await?;
+    run.cfg.set_span(&ds.output, Some(&second))?;
+    tassert!(ds.output.is_spanned());
+
+    // dissolving the span restores the secondary output
+    run.cfg.set_span(&second, None)?;
+    tassert!(!ds.output.is_spanned());
+    tassert!(second_connector.output().is_some());
+    tassert_eq!(ds.output.global.pos.get().width(), 800);
+    tassert_eq!(
+        second.global.pos.get().x1(),
+        ds.output.global.pos.get().x2()
+    );
+    tassert!(second.workspace.is_some());
+    client.sync().await;
+    tassert!(advertised(&second));
+
+    Ok(())
+}
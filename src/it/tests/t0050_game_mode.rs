@@ -0,0 +1,78 @@
+use {
+    crate::{
+        ifs::zwlr_layer_shell_v1::TOP,
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+const ANCHOR_TOP: u32 = 1;
+const ANCHOR_LEFT: u32 = 4;
+
+/// Test that game mode is only active while a fullscreen window is shown and that it
+/// hides the upper layers and the bar
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let window = client.create_window().await?;
+    window.map().await?;
+
+    let layer_shell = client.registry.get_layer_shell().await?;
+    let layer = client.create_surface_ext().await?;
+    let layer_surface = layer_shell.get_layer_surface(&layer, TOP)?;
+    layer_surface.set_size(100, 100)?;
+    layer_surface.set_anchor(ANCHOR_TOP | ANCHOR_LEFT)?;
+    layer.commit()?;
+    client.sync().await;
+    layer_surface.ack_configure(layer_surface.last_serial.get())?;
+    layer.map(100, 100).await?;
+
+    run.cfg.set_fullscreen(ds.seat.id(), true)?;
+    tassert!(!ds.output.game_mode_active.get());
+    run.cfg.set_fullscreen(ds.seat.id(), false)?;
+
+    run.cfg.set_game_mode(&ds.output, true)?;
+    tassert!(!ds.output.game_mode_active.get());
+
+    run.cfg.set_fullscreen(ds.seat.id(), true)?;
+    tassert!(ds.output.game_mode_active.get());
+
+    run.cfg.set_fullscreen(ds.seat.id(), false)?;
+    tassert!(!ds.output.game_mode_active.get());
+    tassert!(ds.output.update_render_data_scheduled.get());
+
+    run.cfg.set_fullscreen(ds.seat.id(), true)?;
+    tassert!(ds.output.game_mode_active.get());
+    run.cfg.set_game_mode(&ds.output, false)?;
+    tassert!(!ds.output.game_mode_active.get());
+
+    let pos = ds.output.global.pos.get();
+    let enter = seat.pointer.enter.expect()?;
+    tassert!(layer.server.node_visible());
+    ds.move_to(pos.x1() + 50, pos.y1() + 50);
+    client.sync().await;
+    tassert_eq!(enter.last()?.surface, layer.id);
+
+    run.cfg.set_game_mode(&ds.output, true)?;
+    tassert!(ds.output.game_mode_active.get());
+    tassert!(!layer.server.node_visible());
+    ds.move_to(pos.x1() + 60, pos.y1() + 60);
+    client.sync().await;
+    tassert_eq!(enter.last()?.surface, window.surface.id);
+
+    let ws = ds.output.create_workspace("2");
+    client.sync().await;
+    tassert!(ws.title_texture.borrow().is_none());
+
+    run.cfg.set_game_mode(&ds.output, false)?;
+    tassert!(layer.server.node_visible());
+    client.sync().await;
+    tassert!(ws.title_texture.borrow().is_some());
+
+    Ok(())
+}
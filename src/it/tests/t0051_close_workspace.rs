@@ -0,0 +1,73 @@
+use {
+    crate::{
+        ifs::wl_seat::BTN_MIDDLE,
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::ToplevelNodeBase,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that middle-clicking the title of a workspace moves its windows and popups to
+/// an adjacent workspace
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let window1 = client.create_window().await?;
+    window1.map().await?;
+
+    run.cfg.show_workspace(ds.seat.id(), "2")?;
+    let window = client.create_window().await?;
+    window.map().await?;
+
+    let popup_surface = client.create_surface_ext().await?;
+    let popup_xdg = client.xdg.create_xdg_surface(popup_surface.id).await?;
+    let positioner = client.xdg.create_positioner()?;
+    positioner.set_size(10, 10)?;
+    positioner.set_anchor_rect(0, 0, 1, 1)?;
+    let _popup = popup_xdg.create_popup(&window.xdg, &positioner)?;
+    popup_surface.commit()?;
+    client.sync().await;
+    popup_xdg.ack_configure(popup_xdg.last_serial.get())?;
+    popup_surface.map(10, 10).await?;
+
+    let Some(ws) = run.state.workspaces.get("2") else {
+        bail!("workspace 2 does not exist");
+    };
+    tassert_eq!(ws.output.get().workspaces.iter().count(), 2);
+    tassert_eq!(ws.stacked.iter().count(), 1);
+
+    let title = {
+        let rd = ds.output.render_data.borrow_mut();
+        rd.titles
+            .iter()
+            .find(|t| t.ws.id == ws.id)
+            .map(|t| (t.x1 + t.x2) / 2)
+    };
+    let Some(x) = title else {
+        bail!("workspace 2 has no title");
+    };
+    ds.mouse.abs(&ds.connector, x as _, 0.0);
+    ds.mouse.click(BTN_MIDDLE);
+    client.sync().await;
+
+    tassert!(run.state.workspaces.get("2").is_none());
+    tassert_eq!(ds.output.workspaces.iter().count(), 1);
+    let Some(ws) = ds.output.workspace.get() else {
+        bail!("output has no workspace");
+    };
+    tassert_eq!(ws.name.get().as_str(), "");
+    let Some(tl_ws) = window.tl.server.tl_data().workspace.get() else {
+        bail!("window has no workspace");
+    };
+    tassert_eq!(tl_ws.id, ws.id);
+    tassert_eq!(ws.stacked.iter().count(), 1);
+
+    // the last workspace cannot be closed
+    ds.output.close_workspace(&ws);
+    tassert!(run.state.workspaces.get("").is_some());
+
+    Ok(())
+}
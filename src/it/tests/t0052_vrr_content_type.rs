@@ -0,0 +1,40 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::video::VrrMode,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that all VRR content type combinations round-trip through the config
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    for bits in 0..8 {
+        let photo = bits & 0b001 != 0;
+        let video = bits & 0b010 != 0;
+        let game = bits & 0b100 != 0;
+        let mode = VrrMode::content_type(photo, video, game);
+        tassert_eq!(mode.content_types(), Some((photo, video, game)));
+        run.cfg.set_vrr_mode(&ds.output, mode)?;
+        tassert_eq!(ds.output.global.persistent.vrr_mode.get().to_config(), mode);
+    }
+    tassert_eq!(VrrMode::content_type(false, true, true), VrrMode::VARIANT_3);
+    tassert_eq!(
+        VrrMode::VARIANT_3.content_types(),
+        Some((false, true, true))
+    );
+
+    for mode in [
+        VrrMode::NEVER,
+        VrrMode::ALWAYS,
+        VrrMode::VARIANT_1,
+        VrrMode::VARIANT_2,
+    ] {
+        tassert_eq!(mode.content_types(), None);
+        run.cfg.set_vrr_mode(&ds.output, mode)?;
+        tassert_eq!(ds.output.global.persistent.vrr_mode.get().to_config(), mode);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        scale::Scale,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the scale of an output is clamped to its maximum scale
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let scale = || ds.output.global.persistent.scale.get();
+
+    run.cfg.set_max_scale(&ds.output, Some(1.5))?;
+    run.cfg.set_scale(&ds.output, 2.0)?;
+    tassert_eq!(scale(), Scale::from_f64(1.5));
+
+    run.cfg.set_scale(&ds.output, 1.25)?;
+    tassert_eq!(scale(), Scale::from_f64(1.25));
+
+    run.cfg.set_scale(&ds.output, 3.0)?;
+    tassert_eq!(scale(), Scale::from_f64(1.5));
+
+    // removing the limit restores the requested scale
+    run.cfg.set_max_scale(&ds.output, None)?;
+    tassert_eq!(scale(), Scale::from_f64(3.0));
+
+    Ok(())
+}
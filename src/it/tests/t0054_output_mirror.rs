@@ -0,0 +1,59 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+        scale::Scale,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that an output can mirror another output
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let second_connector = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60_000,
+    }]);
+    let second = second_connector.connect().await?;
+
+    // an output cannot mirror itself
+    run.cfg.set_mirror_source(&second, Some(&second))?;
+    tassert!(second.mirror_source.is_none());
+
+    run.cfg.set_mirror_source(&second, Some(&ds.output))?;
+    tassert!(second.mirror_source().is_some());
+    tassert!(ds.output.mirrors.is_not_empty());
+    tassert!(second_connector.output().is_none());
+    tassert!(second.workspaces.is_empty());
+
+    // the contents are scaled down and centered vertically
+    let Some((source, viewport)) = second.mirror_viewport((400, 400), Scale::from_int(1)) else {
+        bail!("mirror has no viewport");
+    };
+    tassert_eq!(source.id, ds.output.id);
+    tassert_eq!(viewport.factor, 0.5);
+    tassert_eq!((viewport.x, viewport.y), (0, -100));
+
+    // mirrors cannot be mirrored
+    run.cfg.set_mirror_source(&ds.output, Some(&second))?;
+    tassert!(ds.output.mirror_source.is_none());
+
+    // stopping the mirror restores the output
+    run.cfg.set_mirror_source(&second, None)?;
+    tassert!(second.mirror_source.is_none());
+    tassert!(ds.output.mirrors.is_empty());
+    tassert!(second_connector.output().is_some());
+    tassert!(second.workspace.is_some());
+
+    // unplugging the mirror breaks the mirror
+    run.cfg.set_mirror_source(&second, Some(&ds.output))?;
+    tassert!(ds.output.mirrors.is_not_empty());
+    second_connector.disconnect().await;
+    tassert!(ds.output.mirrors.is_empty());
+
+    Ok(())
+}
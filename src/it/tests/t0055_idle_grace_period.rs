@@ -0,0 +1,46 @@
+use {
+    crate::it::{
+        test_error::{TestErrorExt, TestResult},
+        testrun::TestRun,
+    },
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that input during the idle grace period cancels going idle and that seats can
+/// override the grace period
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    // The grace period is long enough that it never expires during the test.
+    run.cfg.set_idle(Duration::from_micros(100))?;
+    run.cfg.set_idle_grace_period(Duration::from_secs(3600))?;
+
+    let idle = run.backend.idle.expect()?;
+
+    run.state.wheel.timeout(3).await?;
+    tassert!(idle.next().is_err());
+
+    ds.mouse.rel(1.0, 1.0);
+    run.state.eng.yield_now().await;
+
+    run.state.wheel.timeout(3).await?;
+    tassert!(idle.next().is_err());
+
+    // the override of the seat replaces the global grace period
+    run.cfg
+        .set_seat_idle_grace_period(ds.seat.id(), Some(Duration::ZERO))?;
+    run.state.wheel.timeout(3).await?;
+    tassert_eq!(idle.next().with_context(|| "idle")?, true);
+
+    ds.mouse.rel(1.0, 1.0);
+    run.state.eng.yield_now().await;
+    tassert_eq!(idle.next().with_context(|| "wake")?, false);
+
+    run.cfg.set_seat_idle_grace_period(ds.seat.id(), None)?;
+    run.state.wheel.timeout(3).await?;
+    tassert!(idle.next().is_err());
+
+    Ok(())
+}
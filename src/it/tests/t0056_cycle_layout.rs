@@ -0,0 +1,73 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::keyboard::syms::{SYM_F13, SYM_F14},
+    std::rc::Rc,
+};
+
+testcase!();
+
+fn keymap(sym: &str) -> String {
+    format!(
+        r#"
+xkb_keymap {{
+    xkb_keycodes {{
+          <1> = 9; # ESC
+    }};
+    xkb_types {{
+    }};
+    xkb_compatibility {{
+    }};
+    xkb_symbols {{
+        key <1> {{ [ {sym} ] }};
+    }};
+}};
+    "#
+    )
+}
+
+/// Test that cycling the layout switches between the keymaps of a seat
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F13)?;
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F14)?;
+
+    let f13 = run.cfg.parse_keymap(&keymap("F13"))?;
+    let f14 = run.cfg.parse_keymap(&keymap("F14"))?;
+    run.cfg.set_layouts(ds.seat.id(), &[f13, f14])?;
+    run.sync().await;
+
+    let f13_invoked = || {
+        run.cfg
+            .invoked_shortcuts
+            .remove(&(ds.seat.id(), SYM_F13.into()))
+            .is_some()
+    };
+    let f14_invoked = || {
+        run.cfg
+            .invoked_shortcuts
+            .remove(&(ds.seat.id(), SYM_F14.into()))
+            .is_some()
+    };
+
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(f13_invoked());
+    tassert!(!f14_invoked());
+
+    run.cfg.cycle_layout(ds.seat.id())?;
+    run.sync().await;
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(!f13_invoked());
+    tassert!(f14_invoked());
+
+    run.cfg.cycle_layout(ds.seat.id())?;
+    run.sync().await;
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(f13_invoked());
+    tassert!(!f14_invoked());
+
+    Ok(())
+}
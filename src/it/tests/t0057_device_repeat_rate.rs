@@ -0,0 +1,43 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the repeat rate of the most recently used keyboard is sent to clients
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let dev = ds.kb.common.id;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    tassert_eq!(seat.kb.repeat_info.get(), Some((25, 250)));
+
+    run.cfg.set_device_repeat_rate(dev, Some((40, 200)))?;
+    client.sync().await;
+    tassert_eq!(seat.kb.repeat_info.get(), Some((25, 250)));
+
+    ds.kb.press(1);
+    client.sync().await;
+    tassert_eq!(seat.kb.repeat_info.get(), Some((40, 200)));
+
+    let seat2 = client.get_default_seat().await?;
+    tassert_eq!(seat2.kb.repeat_info.get(), Some((40, 200)));
+
+    run.cfg.set_device_repeat_rate(dev, Some((-1, 200)))?;
+    run.cfg.set_device_repeat_rate(dev, Some((40, -1)))?;
+    client.sync().await;
+    tassert_eq!(seat.kb.repeat_info.get(), Some((40, 200)));
+
+    run.cfg.set_device_repeat_rate(dev, Some((0, 0)))?;
+    client.sync().await;
+    tassert_eq!(seat.kb.repeat_info.get(), Some((0, 0)));
+
+    run.cfg.set_device_repeat_rate(dev, None)?;
+    client.sync().await;
+    tassert_eq!(seat.kb.repeat_info.get(), Some((25, 250)));
+    tassert_eq!(seat2.kb.repeat_info.get(), Some((25, 250)));
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use {
+    crate::it::{
+        test_error::{TestErrorExt, TestResult},
+        testrun::TestRun,
+    },
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that focus-follows-mouse waits for the dwell time before moving the focus
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.mouse.rel(1.0, 1.0);
+
+    let client = run.create_client().await?;
+    let dss = client.get_default_seat().await?;
+
+    let left = client.create_window().await?;
+    left.map2().await?;
+    let right = client.create_window().await?;
+    right.map2().await?;
+
+    run.cfg
+        .set_focus_follows_mouse_dwell(ds.seat.id(), Duration::from_millis(50))?;
+
+    let rect = ds.output.workspace_rect.get();
+    let y = (rect.y1() + rect.height() / 2) as f64;
+    let left_x = (rect.x1() + rect.width() / 4) as f64;
+    let right_x = (rect.x2() - rect.width() / 4) as f64;
+
+    ds.mouse.abs(&ds.connector, right_x, y);
+    client.sync().await;
+
+    let enters = dss.kb.enter.expect()?;
+
+    ds.mouse.abs(&ds.connector, left_x, y);
+    client.sync().await;
+    tassert!(enters.next().is_err());
+
+    run.state.wheel.timeout(100).await?;
+    client.sync().await;
+    let enter = enters.next().with_context(|| "no enter event")?;
+    tassert_eq!(enter.surface, left.surface.id);
+
+    // leaving the window before the dwell time has elapsed cancels the focus change
+    ds.mouse.abs(&ds.connector, right_x, y);
+    client.sync().await;
+    ds.mouse.abs(&ds.connector, left_x, y);
+    client.sync().await;
+    run.state.wheel.timeout(100).await?;
+    client.sync().await;
+    tassert!(enters.next().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::Node,
+    },
+    jay_config::Direction,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the pointer is moved to the window that receives the keyboard focus
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.mouse.rel(1.0, 1.0);
+
+    let client = run.create_client().await?;
+    let _dss = client.get_default_seat().await?;
+
+    let left = client.create_window().await?;
+    left.map2().await?;
+    let right = client.create_window().await?;
+    right.map2().await?;
+
+    run.cfg.set_warp_pointer_on_focus(ds.seat.id(), true)?;
+
+    let left_rect = left.tl.server.node_absolute_position();
+    let right_rect = right.tl.server.node_absolute_position();
+    let pointer = || ds.seat.pointer_cursor().position_int();
+
+    let (x, y) = right_rect.center();
+    let (x, y) = (x + 10, y + 10);
+    ds.mouse.abs(&ds.connector, x as f64, y as f64);
+    client.sync().await;
+
+    run.cfg.focus(ds.seat.id(), Direction::Left)?;
+    client.sync().await;
+    tassert_eq!(pointer(), left_rect.center());
+
+    // the pointer is not moved if it is already inside of the window
+    ds.mouse.abs(&ds.connector, x as f64, y as f64);
+    client.sync().await;
+    run.cfg.focus(ds.seat.id(), Direction::Right)?;
+    client.sync().await;
+    tassert_eq!(pointer(), (x, y));
+
+    Ok(())
+}
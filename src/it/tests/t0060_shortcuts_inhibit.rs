@@ -0,0 +1,83 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::keyboard::syms::{SYM_Escape, SYM_a},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that shortcuts are not invoked while the focused surface inhibits them
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let dss = client.get_default_seat().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+
+    run.cfg.add_shortcut(ds.seat.id(), SYM_a)?;
+
+    let manager = client.registry.get_shortcuts_inhibit_manager().await?;
+    let inhibitor = manager.inhibit_shortcuts(&window.surface.surface, &dss.seat)?;
+    client.sync().await;
+
+    // without an escape key, the inhibitor is ignored
+    tassert!(!inhibitor.active.get());
+
+    run.cfg
+        .set_shortcuts_inhibit_escape(ds.seat.id(), SYM_Escape)?;
+    client.sync().await;
+    tassert!(inhibitor.active.get());
+
+    let invoked = || {
+        run.cfg
+            .invoked_shortcuts
+            .remove(&(ds.seat.id(), SYM_a.into()))
+            .is_some()
+    };
+
+    ds.kb.press(30);
+    client.sync().await;
+    tassert!(!invoked());
+
+    // the escape key disables the inhibitor
+    ds.kb.press(1);
+    client.sync().await;
+    tassert!(!inhibitor.active.get());
+
+    ds.kb.press(30);
+    client.sync().await;
+    tassert!(invoked());
+
+    // and enables it again
+    ds.kb.press(1);
+    client.sync().await;
+    tassert!(inhibitor.active.get());
+
+    ds.kb.press(30);
+    client.sync().await;
+    tassert!(!invoked());
+
+    // unsetting the escape key deactivates the inhibitor
+    run.cfg.unset_shortcuts_inhibit_escape(ds.seat.id())?;
+    client.sync().await;
+    tassert!(!inhibitor.active.get());
+
+    ds.kb.press(30);
+    client.sync().await;
+    tassert!(invoked());
+
+    run.cfg
+        .set_shortcuts_inhibit_escape(ds.seat.id(), SYM_Escape)?;
+    client.sync().await;
+    tassert!(inhibitor.active.get());
+
+    inhibitor.destroy()?;
+    client.sync().await;
+
+    ds.kb.press(30);
+    client.sync().await;
+    tassert!(invoked());
+
+    Ok(())
+}
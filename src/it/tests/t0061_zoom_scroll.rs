@@ -0,0 +1,44 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::keyboard::mods::CTRL,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that scrolling with the zoom modifiers pressed changes the magnification
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let zoom = |factor: f64| (ds.output.zoom.get() - factor).abs() < 1e-6;
+
+    ds.mouse.scroll(-1);
+    run.sync().await;
+    tassert!(zoom(1.0));
+
+    run.cfg.set_zoom_scroll_modifiers(ds.seat.id(), CTRL)?;
+
+    ds.mouse.scroll(-1);
+    run.sync().await;
+    tassert!(zoom(1.0));
+
+    {
+        let _ctrl = ds.kb.press(29);
+        ds.mouse.scroll(-2);
+        run.sync().await;
+        tassert!(zoom(1.25 * 1.25));
+
+        ds.mouse.scroll(1);
+        run.sync().await;
+        tassert!(zoom(1.25));
+
+        ds.mouse.scroll(5);
+        run.sync().await;
+        tassert!(zoom(1.0));
+    }
+
+    ds.mouse.scroll(-1);
+    run.sync().await;
+    tassert!(zoom(1.0));
+
+    Ok(())
+}
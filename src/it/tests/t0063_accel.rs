@@ -0,0 +1,33 @@
+use {
+    crate::{
+        backend::InputDeviceAccelProfile,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::input::acceleration::{ACCEL_PROFILE_ADAPTIVE, ACCEL_PROFILE_FLAT},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the acceleration settings are applied to the device
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let id = ds.mouse.common.id;
+
+    run.cfg.set_accel_profile(id, ACCEL_PROFILE_ADAPTIVE)?;
+    tassert_eq!(
+        ds.mouse.accel_profile.get(),
+        InputDeviceAccelProfile::Adaptive
+    );
+    run.cfg.set_accel_profile(id, ACCEL_PROFILE_FLAT)?;
+    tassert_eq!(ds.mouse.accel_profile.get(), InputDeviceAccelProfile::Flat);
+
+    run.cfg.set_accel_speed(id, 0.5)?;
+    tassert_eq!(ds.mouse.accel_speed.get(), 0.5);
+    run.cfg.set_accel_speed(id, 3.0)?;
+    tassert_eq!(ds.mouse.accel_speed.get(), 1.0);
+    run.cfg.set_accel_speed(id, -3.0)?;
+    tassert_eq!(ds.mouse.accel_speed.get(), -1.0);
+
+    Ok(())
+}
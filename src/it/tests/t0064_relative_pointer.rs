@@ -0,0 +1,44 @@
+use {
+    crate::{
+        fixed::Fixed,
+        it::{
+            test_error::{TestErrorExt, TestResult},
+            testrun::TestRun,
+        },
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that relative pointer events contain accelerated and unaccelerated deltas
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let dss = client.get_default_seat().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+
+    let rpm = client.registry.get_relative_pointer_manager().await?;
+    let rp = rpm.get_relative_pointer(&dss.pointer)?;
+    run.cfg.set_accel_speed(ds.mouse.common.id, 0.5)?;
+
+    let (x, y) = window.tl.server.node_absolute_position().center();
+    ds.mouse.abs(&ds.connector, x as f64, y as f64);
+    client.sync().await;
+
+    let events = rp.relative_motion.expect()?;
+    ds.mouse.rel(4.0, 6.0);
+    client.sync().await;
+
+    let ev = events.next().with_context(|| "no relative motion")?;
+    tassert_eq!(ev.dx, Fixed::from_int(2));
+    tassert_eq!(ev.dy, Fixed::from_int(3));
+    tassert_eq!(ev.dx_unaccelerated, Fixed::from_int(4));
+    tassert_eq!(ev.dy_unaccelerated, Fixed::from_int(6));
+    events.none()?;
+
+    Ok(())
+}
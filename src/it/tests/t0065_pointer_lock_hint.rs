@@ -0,0 +1,48 @@
+use {
+    crate::{
+        fixed::Fixed,
+        it::{
+            test_error::TestResult, test_ifs::test_pointer_constraints::LIFETIME_PERSISTENT,
+            testrun::TestRun,
+        },
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the pointer is moved to the cursor position hint when a lock is destroyed
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let dss = client.get_default_seat().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+
+    let (x, y) = window.tl.server.node_absolute_position().center();
+    ds.mouse.abs(&ds.connector, x as f64, y as f64);
+    client.sync().await;
+
+    let constraints = client.registry.get_pointer_constraints().await?;
+    let lp = constraints.lock_pointer(&window.surface, &dss.pointer, LIFETIME_PERSISTENT)?;
+    client.sync().await;
+    tassert!(lp.locked.get());
+
+    // the pointer does not move while it is locked
+    ds.mouse.rel(10.0, 10.0);
+    client.sync().await;
+    tassert_eq!(ds.seat.pointer_cursor().position_int(), (x, y));
+
+    lp.set_cursor_position_hint(Fixed::from_int(5), Fixed::from_int(7))?;
+    lp.destroy()?;
+    client.sync().await;
+    let pos = window.surface.server.buffer_abs_pos.get();
+    tassert_eq!(
+        ds.seat.pointer_cursor().position_int(),
+        (pos.x1() + 5, pos.y1() + 7)
+    );
+
+    Ok(())
+}
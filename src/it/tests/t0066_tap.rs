@@ -0,0 +1,25 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the tap settings are applied to the device
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let id = ds.mouse.common.id;
+
+    run.cfg.set_tap_enabled(id, true)?;
+    tassert!(ds.mouse.tap_enabled.get());
+    run.cfg.set_drag_enabled(id, true)?;
+    tassert!(ds.mouse.drag_enabled.get());
+    run.cfg.set_drag_lock_enabled(id, true)?;
+    tassert!(ds.mouse.drag_lock_enabled.get());
+
+    run.cfg.set_tap_enabled(id, false)?;
+    tassert!(!ds.mouse.tap_enabled.get());
+    tassert!(ds.mouse.drag_enabled.get());
+
+    Ok(())
+}
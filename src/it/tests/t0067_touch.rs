@@ -0,0 +1,80 @@
+use {
+    crate::it::{
+        test_error::{TestErrorExt, TestResult},
+        testrun::TestRun,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that touch points are routed to the surface under the first touch point
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let touch = run.backend.add_touch();
+    run.sync().await;
+    run.cfg
+        .set_input_device_seat(touch.common.id, ds.seat.id())?;
+
+    let client = run.create_client().await?;
+    let dss = client.get_default_seat().await?;
+    let wl_touch = dss.seat.get_touch().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+
+    let extents = run.state.root.extents.get();
+    let normed = |x: i32, y: i32| {
+        (
+            (x - extents.x1()) as f64 / extents.width() as f64,
+            (y - extents.y1()) as f64 / extents.height() as f64,
+        )
+    };
+    let rect = window.surface.server.buffer_abs_pos.get();
+    let (x, y) = rect.center();
+    let (nx, ny) = normed(x, y);
+
+    let down = wl_touch.down.expect()?;
+    let motion = wl_touch.motion.expect()?;
+    let up = wl_touch.up.expect()?;
+    let cancel = wl_touch.cancel.expect()?;
+
+    touch.down(0, nx, ny);
+    touch.frame();
+    client.sync().await;
+    let ev = down.next().with_context(|| "no down event")?;
+    tassert_eq!(ev.surface, window.surface.id);
+    tassert_eq!(ev.id, 0);
+    tassert!((ev.x.to_f64() - (x - rect.x1()) as f64).abs() < 0.01);
+    tassert!((ev.y.to_f64() - (y - rect.y1()) as f64).abs() < 0.01);
+
+    // additional touch points are delivered to the same surface
+    touch.down(1, 0.0, 0.0);
+    touch.motion(1, nx, ny);
+    touch.frame();
+    client.sync().await;
+    let ev = down.next().with_context(|| "no down event")?;
+    tassert_eq!(ev.surface, window.surface.id);
+    tassert_eq!(ev.id, 1);
+    let ev = motion.next().with_context(|| "no motion event")?;
+    tassert_eq!(ev.id, 1);
+    motion.none()?;
+
+    touch.up(0);
+    touch.up(1);
+    touch.frame();
+    client.sync().await;
+    tassert_eq!(up.next().with_context(|| "no up event")?.id, 0);
+    tassert_eq!(up.next().with_context(|| "no up event")?.id, 1);
+    cancel.none()?;
+
+    // destroying the surface cancels the touch sequence
+    touch.down(0, nx, ny);
+    touch.frame();
+    client.sync().await;
+    tassert!(down.next().is_ok());
+    window.tl.core.destroy()?;
+    client.sync().await;
+    tassert!(cancel.next().is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that touchpad swipes switch workspaces
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let mut windows = vec![];
+    for name in ["1", "2", "3"] {
+        run.cfg.show_workspace(ds.seat.id(), name)?;
+        let window = client.create_window().await?;
+        window.map().await?;
+        windows.push(window);
+    }
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+    run.cfg.set_workspace_swipe(ds.seat.id(), 3, false)?;
+
+    let ws = || {
+        ds.output
+            .workspace
+            .get()
+            .map(|ws| ws.name.get().to_string())
+            .unwrap_or_default()
+    };
+
+    // workspaces switch while the fingers move
+    ds.mouse.swipe_begin(3);
+    ds.mouse.swipe_update(-250.0, 0.0);
+    client.sync().await;
+    tassert_eq!(ws(), "3");
+    ds.mouse.swipe_update(60.0, 0.0);
+    client.sync().await;
+    tassert_eq!(ws(), "2");
+    ds.mouse.swipe_end(false);
+    client.sync().await;
+    tassert_eq!(ws(), "2");
+
+    // short swipes switch when the fingers are lifted
+    ds.mouse.swipe_begin(3);
+    ds.mouse.swipe_update(-60.0, 0.0);
+    client.sync().await;
+    tassert_eq!(ws(), "2");
+    ds.mouse.swipe_end(false);
+    client.sync().await;
+    tassert_eq!(ws(), "3");
+
+    // cancelled swipes do not switch
+    ds.mouse.swipe_begin(3);
+    ds.mouse.swipe_update(60.0, 0.0);
+    ds.mouse.swipe_end(true);
+    client.sync().await;
+    tassert_eq!(ws(), "3");
+
+    // swipes with a different number of fingers do not switch
+    ds.mouse.swipe_begin(4);
+    ds.mouse.swipe_update(300.0, 0.0);
+    ds.mouse.swipe_end(false);
+    client.sync().await;
+    tassert_eq!(ws(), "3");
+
+    // the direction can be inverted
+    run.cfg.set_workspace_swipe(ds.seat.id(), 3, true)?;
+    ds.mouse.swipe_begin(3);
+    ds.mouse.swipe_update(-100.0, 0.0);
+    ds.mouse.swipe_end(false);
+    client.sync().await;
+    tassert_eq!(ws(), "2");
+
+    Ok(())
+}
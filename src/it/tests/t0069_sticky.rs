@@ -0,0 +1,51 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::{Node, ToplevelNodeBase},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that sticky floating windows follow the workspace of their output
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+    run.cfg.set_floating(ds.seat.id(), true)?;
+    run.cfg.set_sticky(ds.seat.id(), true)?;
+    tassert!(win.tl.server.tl_data().is_sticky.get());
+
+    let ws = || {
+        win.tl
+            .server
+            .tl_data()
+            .workspace
+            .get()
+            .map(|ws| ws.name.get().to_string())
+            .unwrap_or_default()
+    };
+
+    run.cfg.show_workspace(ds.seat.id(), "2")?;
+    client.sync().await;
+    tassert_eq!(ws(), "2");
+    tassert!(win.tl.server.node_visible());
+    tassert!(win.tl.float_parent()?.node_visible());
+
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+    client.sync().await;
+    tassert_eq!(ws(), "1");
+    tassert!(win.tl.server.node_visible());
+
+    run.cfg.set_sticky(ds.seat.id(), false)?;
+    run.cfg.show_workspace(ds.seat.id(), "3")?;
+    client.sync().await;
+    tassert_eq!(ws(), "1");
+    tassert!(!win.tl.server.node_visible());
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use {
+    crate::{
+        ifs::wl_seat::BTN_LEFT,
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+const KEY_ESC: u32 = 1;
+
+/// Test the drag-and-drop threshold and cancelling drag-and-drop with escape
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    run.cfg.set_dnd_threshold(ds.seat.id(), 10)?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let seat = client.get_default_seat().await?;
+    let dev = client.data_device_manager.get_data_device(&seat.seat)?;
+    let button = seat.pointer.button.expect()?;
+    let leave = seat.pointer.leave.expect()?;
+
+    let (x, y) = win.tl.server.node_absolute_position().center();
+    ds.move_to(x, y);
+
+    // a click that does not reach the threshold is delivered to the surface
+    let click = ds.mouse.click(BTN_LEFT);
+    client.sync().await;
+    let src = client.data_device_manager.create_data_source()?;
+    src.set_actions(1)?;
+    dev.start_drag(&src, &win.surface, None, button.next()?.serial)?;
+    client.sync().await;
+    ds.move_to(x + 5, y);
+    client.sync().await;
+    tassert!(leave.next().is_err());
+    drop(click);
+    client.sync().await;
+    tassert_eq!(button.next()?.state, 0);
+    tassert!(src.cancelled.get());
+
+    // the drag starts once the threshold is reached
+    let click = ds.mouse.click(BTN_LEFT);
+    client.sync().await;
+    let src = client.data_device_manager.create_data_source()?;
+    src.set_actions(1)?;
+    dev.start_drag(&src, &win.surface, None, button.next()?.serial)?;
+    client.sync().await;
+    tassert!(leave.next().is_err());
+    ds.move_to(x + 20, y);
+    client.sync().await;
+    tassert_eq!(leave.next()?.surface, win.surface.id);
+
+    // escape cancels the drag
+    ds.kb.press(KEY_ESC);
+    client.sync().await;
+    tassert!(src.cancelled.get());
+    drop(click);
+    client.sync().await;
+    tassert!(button.next().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use {
+    crate::{
+        ifs::wl_seat::BTN_MIDDLE,
+        it::{
+            test_error::{TestErrorExt, TestResult},
+            testrun::TestRun,
+        },
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the primary selection is hidden from middle clicks if middle-click paste is
+/// disabled
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let psm = client
+        .registry
+        .get_primary_selection_device_manager()
+        .await?;
+    let dev = psm.get_device(&seat.seat)?;
+    let selection = dev.selection.expect()?;
+    let button = seat.pointer.button.expect()?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let client2 = run.create_client().await?;
+    let seat2 = client2.get_default_seat().await?;
+    let data_control = client2.registry.get_data_control_manager().await?;
+    let dev2 = data_control.get_data_device(&seat2.seat)?;
+    let source = data_control.create_data_source()?;
+    source.offer("text")?;
+    dev2.set_primary_selection(&source)?;
+    client2.sync().await;
+    client.sync().await;
+    let offer = selection.last().with_context(|| "initial selection")?;
+    tassert!(offer.is_some());
+
+    let (x, y) = win.tl.server.node_absolute_position().center();
+    ds.move_to(x, y);
+
+    ds.mouse.click(BTN_MIDDLE);
+    client.sync().await;
+    tassert_eq!(button.next()?.button, BTN_MIDDLE);
+    tassert_eq!(button.next()?.button, BTN_MIDDLE);
+    selection.none()?;
+
+    run.cfg
+        .set_middle_click_paste_enabled(ds.seat.id(), false)?;
+    ds.mouse.click(BTN_MIDDLE);
+    client.sync().await;
+    tassert_eq!(button.next()?.button, BTN_MIDDLE);
+    tassert_eq!(button.next()?.button, BTN_MIDDLE);
+    tassert!(selection.next()?.is_none());
+    tassert!(selection.next()?.is_none());
+    tassert!(selection.next()?.is_some());
+
+    Ok(())
+}
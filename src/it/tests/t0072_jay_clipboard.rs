@@ -0,0 +1,57 @@
+use {
+    crate::{
+        ifs::jay_clipboard::{LOCATION_CLIPBOARD, LOCATION_PRIMARY_SELECTION},
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::{
+        io::{Read, Write},
+        rc::Rc,
+    },
+};
+
+testcase!();
+
+/// Test reading the clipboard through the jay protocol
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+
+    let client1 = run.create_client().await?;
+    let seat1 = client1.get_default_seat().await?;
+    let data_control = client1.registry.get_data_control_manager().await?;
+    let dev = data_control.get_data_device(&seat1.seat)?;
+    let source = data_control.create_data_source()?;
+    source.offer("text")?;
+    let sends = source.sends.expect()?;
+    dev.set_selection(&source)?;
+    client1.sync().await;
+
+    let client2 = run.create_client().await?;
+    let seat2 = client2.get_default_seat().await?;
+    let clipboard = client2.jc.get_clipboard(&seat2.seat)?;
+    let mime_types = clipboard.mime_types.expect()?;
+    let done = clipboard.done.expect()?;
+    client2.sync().await;
+    tassert_eq!(mime_types.next()?, (LOCATION_CLIPBOARD, "text".to_string()));
+    mime_types.none()?;
+    tassert_eq!(done.next()?, LOCATION_CLIPBOARD);
+    tassert_eq!(done.next()?, LOCATION_PRIMARY_SELECTION);
+
+    let rfd = clipboard.receive(LOCATION_CLIPBOARD, "text")?;
+    client2.sync().await;
+    client1.sync().await;
+    let (mime, sfd) = sends.next()?;
+    tassert_eq!(mime, "text");
+    sfd.borrow().write_all(b"abcd")?;
+    drop(sfd);
+    let mut buf = vec![];
+    rfd.borrow().read_to_end(&mut buf)?;
+    tassert_eq!(buf, b"abcd");
+
+    // unknown mime types are ignored
+    let _rfd = clipboard.receive(LOCATION_CLIPBOARD, "image")?;
+    client2.sync().await;
+    client1.sync().await;
+    sends.none()?;
+
+    Ok(())
+}
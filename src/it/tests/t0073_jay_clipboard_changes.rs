@@ -0,0 +1,57 @@
+use {
+    crate::{
+        ifs::jay_clipboard::{LOCATION_CLIPBOARD, LOCATION_PRIMARY_SELECTION},
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that jay clipboard objects are notified about selection changes
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+
+    let manager = run.create_client().await?;
+    let manager_seat = manager.get_default_seat().await?;
+    let clipboard = manager.jc.get_clipboard(&manager_seat.seat)?;
+    let mime_types = clipboard.mime_types.expect()?;
+    let done = clipboard.done.expect()?;
+    manager.sync().await;
+    mime_types.none()?;
+    tassert_eq!(done.next()?, LOCATION_CLIPBOARD);
+    tassert_eq!(done.next()?, LOCATION_PRIMARY_SELECTION);
+    done.none()?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let data_control = client.registry.get_data_control_manager().await?;
+    let dev = data_control.get_data_device(&seat.seat)?;
+    let source = data_control.create_data_source()?;
+    source.offer("text")?;
+    dev.set_primary_selection(&source)?;
+    client.sync().await;
+    run.state.idle().await;
+    manager.sync().await;
+    tassert_eq!(
+        mime_types.next()?,
+        (LOCATION_PRIMARY_SELECTION, "text".to_string())
+    );
+    mime_types.none()?;
+    tassert_eq!(done.next()?, LOCATION_PRIMARY_SELECTION);
+    done.none()?;
+
+    // selections set by the manager itself are not reported back to it
+    let manager_data_control = manager.registry.get_data_control_manager().await?;
+    let manager_dev = manager_data_control.get_data_device(&manager_seat.seat)?;
+    let manager_source = manager_data_control.create_data_source()?;
+    manager_source.offer("text")?;
+    manager_dev.set_selection(&manager_source)?;
+    manager.sync().await;
+    run.state.idle().await;
+    manager.sync().await;
+    mime_types.none()?;
+    done.none()?;
+
+    Ok(())
+}
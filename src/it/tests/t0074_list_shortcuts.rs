@@ -0,0 +1,29 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::keyboard::{
+        mods::MOD4,
+        syms::{SYM_a, SYM_F1, SYM_F13},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the shortcuts of a seat can be listed in a stable order
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F13)?;
+    run.cfg.add_shortcut(ds.seat.id(), MOD4 | SYM_a)?;
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F1)?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let shortcuts = client.jc.get_shortcuts(&seat.seat).await?;
+    tassert_eq!(
+        shortcuts,
+        vec![(MOD4.0, SYM_a.0), (0, SYM_F1.0), (0, SYM_F13.0)]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::keyboard::{
+        mods::MOD4,
+        syms::{SYM_a, SYM_F13},
+    },
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that holding modifiers announces the shortcuts available under them
+async fn test(run: Rc<TestRun>) -> TestResult {
+    const KEY_LEFTMETA: u32 = 125;
+
+    let ds = run.create_default_setup().await?;
+
+    run.cfg.add_shortcut(ds.seat.id(), MOD4 | SYM_a)?;
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F13)?;
+    run.cfg
+        .set_which_key_delay(ds.seat.id(), Some(Duration::from_millis(50)))?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let which_key = client.jc.get_which_key(&seat.seat)?;
+    let shortcuts = which_key.shortcuts.expect()?;
+    let show = which_key.show.expect()?;
+    let hide = which_key.hide.expect()?;
+    client.sync().await;
+
+    let logo = ds.kb.press(KEY_LEFTMETA);
+    client.sync().await;
+    show.none()?;
+
+    run.state.wheel.timeout(100).await?;
+    client.sync().await;
+    tassert_eq!(shortcuts.next()?, (MOD4.0, SYM_a.0));
+    shortcuts.none()?;
+    tassert_eq!(show.next()?, MOD4.0);
+    hide.none()?;
+
+    drop(logo);
+    client.sync().await;
+    hide.next()?;
+
+    // releasing the modifiers before the delay has elapsed cancels the announcement
+    let logo = ds.kb.press(KEY_LEFTMETA);
+    client.sync().await;
+    drop(logo);
+    run.state.wheel.timeout(100).await?;
+    client.sync().await;
+    show.none()?;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use {
+    crate::{
+        ifs::wl_seat::{BTN_LEFT, BTN_RIGHT},
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::Node,
+    },
+    jay_config::{
+        input::PointerTrigger,
+        keyboard::mods::{Modifiers, MOD4},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that buttons and scroll events bound to pointer shortcuts invoke the config and
+/// are not delivered to clients
+async fn test(run: Rc<TestRun>) -> TestResult {
+    const KEY_LEFTMETA: u32 = 125;
+
+    let ds = run.create_default_setup().await?;
+
+    let seat_id = ds.seat.id();
+    run.cfg
+        .add_pointer_shortcut(seat_id, MOD4, PointerTrigger::Button(BTN_LEFT))?;
+    run.cfg
+        .add_pointer_shortcut(seat_id, Modifiers(0), PointerTrigger::ScrollDown)?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let button = seat.pointer.button.expect()?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let (x, y) = win.tl.server.node_absolute_position().center();
+    ds.move_to(x, y);
+
+    // the shortcut requires the logo modifier
+    ds.mouse.click(BTN_LEFT);
+    client.sync().await;
+    tassert_eq!(button.next()?.button, BTN_LEFT);
+    tassert_eq!(button.next()?.button, BTN_LEFT);
+    tassert!(run.cfg.invoked_pointer_shortcuts.is_empty());
+
+    let logo = ds.kb.press(KEY_LEFTMETA);
+    let click = ds.mouse.click(BTN_LEFT);
+    drop(logo);
+    drop(click);
+    client.sync().await;
+    button.none()?;
+    tassert!(run.cfg.invoked_pointer_shortcuts.contains(&(
+        seat_id,
+        MOD4,
+        PointerTrigger::Button(BTN_LEFT)
+    )));
+
+    // unbound buttons are still delivered while the modifier is held
+    let logo = ds.kb.press(KEY_LEFTMETA);
+    ds.mouse.click(BTN_RIGHT);
+    drop(logo);
+    client.sync().await;
+    tassert_eq!(button.next()?.button, BTN_RIGHT);
+    tassert_eq!(button.next()?.button, BTN_RIGHT);
+
+    ds.mouse.scroll(1);
+    client.sync().await;
+    tassert!(run.cfg.invoked_pointer_shortcuts.contains(&(
+        seat_id,
+        Modifiers(0),
+        PointerTrigger::ScrollDown
+    )));
+
+    Ok(())
+}
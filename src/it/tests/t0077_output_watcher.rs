@@ -0,0 +1,59 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that output watchers are informed about hotplugged outputs and their modes
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let watcher = client.jc.watch_outputs()?;
+    let added = watcher.added.expect()?;
+    let modes = watcher.modes.expect()?;
+    let done = watcher.done.expect()?;
+    let removed = watcher.removed.expect()?;
+    client.sync().await;
+
+    let first = ds.output.global.connector.name.clone();
+    tassert_eq!(added.next()?.0, first.clone());
+    tassert_eq!(modes.next()?.0, first.clone());
+    modes.none()?;
+    tassert_eq!(done.next()?, first);
+    added.none()?;
+
+    let mode1 = Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60000,
+    };
+    let mode2 = Mode {
+        width: 800,
+        height: 600,
+        refresh_rate_millihz: 75000,
+    };
+    let mut connector = run.create_second_connector(&[mode1, mode2]);
+    connector.monitor_info.width_mm = 300;
+    connector.monitor_info.height_mm = 200;
+    connector.connect().await?;
+    client.sync().await;
+
+    tassert_eq!(added.next()?, ("VGA-2".to_string(), 300, 200, mode1));
+    tassert_eq!(modes.next()?, ("VGA-2".to_string(), mode1));
+    tassert_eq!(modes.next()?, ("VGA-2".to_string(), mode2));
+    modes.none()?;
+    tassert_eq!(done.next()?, "VGA-2");
+    removed.none()?;
+
+    connector.disconnect().await;
+    client.sync().await;
+    tassert_eq!(removed.next()?, "VGA-2");
+    added.none()?;
+
+    Ok(())
+}
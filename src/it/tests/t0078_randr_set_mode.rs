@@ -0,0 +1,48 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that modes set via jay_randr are validated against the modes of the connector
+async fn test(run: Rc<TestRun>) -> TestResult {
+    run.create_default_setup().await?;
+
+    let mode1 = Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60000,
+    };
+    let mode2 = Mode {
+        width: 800,
+        height: 600,
+        refresh_rate_millihz: 75000,
+    };
+    let output = run
+        .create_second_connector(&[mode1, mode2])
+        .connect()
+        .await?;
+
+    let client = run.create_client().await?;
+    let randr = client.jc.get_randr()?;
+    let errors = randr.errors.expect()?;
+
+    randr.set_mode("VGA-2", 1024, 768, 60000)?;
+    client.sync().await;
+    run.state.eng.yield_now().await;
+    errors.next()?;
+    tassert_eq!(output.global.mode.get(), mode1);
+
+    randr.set_mode("VGA-2", 800, 600, 75000)?;
+    client.sync().await;
+    run.state.eng.yield_now().await;
+    errors.none()?;
+    tassert_eq!(output.global.mode.get(), mode2);
+    tassert_eq!(output.global.pos.get().width(), 800);
+
+    Ok(())
+}
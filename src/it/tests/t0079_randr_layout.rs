@@ -0,0 +1,119 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+        rect::Rect,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that output layouts applied via jay_randr are validated as a whole
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let mode = Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60000,
+    };
+    let small_mode = Mode {
+        width: 200,
+        height: 200,
+        refresh_rate_millihz: 60000,
+    };
+    let second = run
+        .create_second_connector(&[mode, small_mode])
+        .connect()
+        .await?;
+    let first = ds.output.clone();
+    let first_name = first.global.connector.name.clone();
+    let first_mode = first.global.mode.get();
+    tassert_eq!(
+        first.global.pos.get(),
+        Rect::new_sized_unchecked(0, 0, 800, 600)
+    );
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(800, 0, 400, 400)
+    );
+
+    let client = run.create_client().await?;
+    let randr = client.jc.get_randr()?;
+    let errors = randr.errors.expect()?;
+
+    // swapping the outputs would overlap if done one at a time
+    randr.stage_output_layout(&first_name, (400, 0), first_mode, 0, 120)?;
+    randr.stage_output_layout("VGA-2", (0, 0), mode, 0, 120)?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    errors.none()?;
+    tassert_eq!(
+        first.global.pos.get(),
+        Rect::new_sized_unchecked(400, 0, 800, 600)
+    );
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(0, 0, 400, 400)
+    );
+    tassert_eq!(
+        run.state.root.extents.get(),
+        Rect::new_sized_unchecked(0, 0, 1200, 600)
+    );
+
+    // layouts with overlapping outputs are rejected
+    randr.stage_output_layout(&first_name, (0, 0), first_mode, 0, 120)?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    errors.next()?;
+    tassert_eq!(
+        first.global.pos.get(),
+        Rect::new_sized_unchecked(400, 0, 800, 600)
+    );
+
+    // layouts with empty outputs are rejected
+    randr.stage_output_layout("VGA-2", (0, 0), mode, 0, 0)?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    errors.next()?;
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(0, 0, 400, 400)
+    );
+
+    // outputs cannot be staged more than once
+    randr.stage_output_layout("VGA-2", (0, 0), mode, 0, 120)?;
+    randr.stage_output_layout("VGA-2", (1200, 0), mode, 0, 120)?;
+    client.sync().await;
+    errors.next()?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    errors.none()?;
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(0, 0, 400, 400)
+    );
+
+    // mode changes are part of the layout
+    randr.stage_output_layout(&first_name, (200, 0), first_mode, 0, 120)?;
+    randr.stage_output_layout("VGA-2", (0, 0), small_mode, 0, 120)?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    errors.none()?;
+    tassert_eq!(second.global.mode.get(), small_mode);
+    tassert_eq!(
+        first.global.pos.get(),
+        Rect::new_sized_unchecked(200, 0, 800, 600)
+    );
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(0, 0, 200, 200)
+    );
+    tassert_eq!(
+        run.state.root.extents.get(),
+        Rect::new_sized_unchecked(0, 0, 1000, 600)
+    );
+
+    Ok(())
+}
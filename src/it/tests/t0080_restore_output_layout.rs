@@ -0,0 +1,87 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{
+            test_error::{TestError, TestResult},
+            testrun::TestRun,
+        },
+        rect::Rect,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the layout of a re-plugged monitor is restored and that restored positions
+/// do not overlap other outputs
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let mode1 = Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60000,
+    };
+    let mode2 = Mode {
+        width: 800,
+        height: 600,
+        refresh_rate_millihz: 60000,
+    };
+    let connector = run.create_second_connector(&[mode1, mode2]);
+    let connect = || async {
+        let output = connector.connect().await?;
+        // restored modes are applied asynchronously
+        run.state.eng.yield_now().await;
+        Ok::<_, TestError>(output)
+    };
+
+    let first = ds.output.clone();
+    let first_name = first.global.connector.name.clone();
+    let first_mode = first.global.mode.get();
+
+    let client = run.create_client().await?;
+    let randr = client.jc.get_randr()?;
+    let errors = randr.errors.expect()?;
+
+    connect().await?;
+    randr.stage_output_layout("VGA-2", (800, 100), mode2, 0, 120)?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    run.state.eng.yield_now().await;
+    errors.none()?;
+    connector.disconnect().await;
+
+    // the position and mode are restored
+    let second = connect().await?;
+    tassert_eq!(second.global.mode.get(), mode2);
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(800, 100, 800, 600)
+    );
+    connector.disconnect().await;
+
+    // the restored position overlaps the first output
+    randr.stage_output_layout(&first_name, (500, 0), first_mode, 0, 120)?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    errors.none()?;
+    let second = connect().await?;
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(1300, 0, 800, 600)
+    );
+    connector.disconnect().await;
+
+    // the fallback position is not persisted
+    randr.stage_output_layout(&first_name, (0, 0), first_mode, 0, 120)?;
+    randr.apply_output_layout()?;
+    client.sync().await;
+    errors.none()?;
+    let second = connect().await?;
+    tassert_eq!(
+        second.global.pos.get(),
+        Rect::new_sized_unchecked(800, 100, 800, 600)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use {
+    crate::{
+        fixed::Fixed,
+        it::{
+            test_error::{TestErrorExt, TestResult},
+            testrun::TestRun,
+        },
+    },
+    jay_config::video::Transform,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that connector positions are mapped to the correct logical position under all
+/// transforms
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let enter = seat.pointer.enter.expect()?;
+    ds.mouse.abs(&ds.connector, 400.0, 300.0);
+    client.sync().await;
+    enter.next().with_context(|| "no enter event")?;
+    let motion = seat.pointer.motion.expect()?;
+
+    // the output is 800x600 and the connector position is always (300, 250)
+    let cases = [
+        (Transform::None, (800, 600), (300, 250)),
+        (Transform::Rotate90, (600, 800), (350, 300)),
+        (Transform::Rotate180, (800, 600), (500, 350)),
+        (Transform::Rotate270, (600, 800), (250, 500)),
+        (Transform::Flip, (800, 600), (500, 250)),
+        (Transform::FlipRotate90, (600, 800), (250, 300)),
+        (Transform::FlipRotate180, (800, 600), (300, 350)),
+        (Transform::FlipRotate270, (600, 800), (350, 500)),
+    ];
+    for (transform, (width, height), (x, y)) in cases {
+        run.cfg.set_output_transform(&ds.output, transform)?;
+        client.sync().await;
+        let pos = ds.output.global.pos.get();
+        tassert_eq!((pos.width(), pos.height()), (width, height));
+
+        ds.mouse.abs(&ds.connector, 300.0, 250.0);
+        client.sync().await;
+        tassert_eq!(
+            ds.seat.pointer_cursor().position(),
+            (Fixed::from_int(x), Fixed::from_int(y))
+        );
+        let ev = motion
+            .next()
+            .with_context(|| format!("no motion event for {transform:?}"))?;
+        let surface = win.surface.server.buffer_abs_pos.get();
+        tassert_eq!(
+            (ev.surface_x, ev.surface_y),
+            (
+                Fixed::from_int(x - surface.x1()),
+                Fixed::from_int(y - surface.y1())
+            )
+        );
+    }
+
+    run.cfg.set_scale(&ds.output, 2.0)?;
+    run.cfg
+        .set_output_transform(&ds.output, Transform::FlipRotate90)?;
+    client.sync().await;
+    let pos = ds.output.global.pos.get();
+    tassert_eq!((pos.width(), pos.height()), (300, 400));
+    ds.mouse.abs(&ds.connector, 300.0, 250.0);
+    client.sync().await;
+    tassert_eq!(
+        ds.seat.pointer_cursor().position(),
+        (Fixed::from_int(125), Fixed::from_int(150))
+    );
+
+    Ok(())
+}
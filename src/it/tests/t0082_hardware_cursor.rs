@@ -0,0 +1,61 @@
+use {
+    crate::{
+        backend::ConnectorEvent,
+        it::{test_backend::TestHardwareCursor, test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::video::Transform,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that output watchers and the config are informed whether outputs use hardware
+/// cursors
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let name = ds.output.global.connector.name.clone();
+
+    let client = run.create_client().await?;
+    let watcher = client.jc.watch_outputs()?;
+    let hardware_cursor = watcher.hardware_cursor.expect()?;
+    client.sync().await;
+    tassert_eq!(hardware_cursor.next()?, (name.clone(), false));
+    tassert!(!run.cfg.hardware_cursor_active(&ds.output)?);
+
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::HardwareCursor(Some(Rc::new(
+            TestHardwareCursor::default(),
+        ))));
+    run.state.eng.yield_now().await;
+    client.sync().await;
+    tassert_eq!(hardware_cursor.next()?, (name.clone(), true));
+    tassert!(run.cfg.hardware_cursor_active(&ds.output)?);
+
+    // transforms are re-evaluated but don't disable the hardware cursor
+    run.cfg
+        .set_output_transform(&ds.output, Transform::FlipRotate90)?;
+    client.sync().await;
+    hardware_cursor.none()?;
+    tassert!(run.cfg.hardware_cursor_active(&ds.output)?);
+
+    run.cfg.set_use_hardware_cursor(&ds.output, false)?;
+    client.sync().await;
+    tassert_eq!(hardware_cursor.next()?, (name.clone(), false));
+    tassert!(!run.cfg.hardware_cursor_active(&ds.output)?);
+
+    run.cfg.set_use_hardware_cursor(&ds.output, true)?;
+    client.sync().await;
+    tassert_eq!(hardware_cursor.next()?, (name.clone(), true));
+
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::HardwareCursor(None));
+    run.state.eng.yield_now().await;
+    client.sync().await;
+    tassert_eq!(hardware_cursor.next()?, (name.clone(), false));
+    tassert!(ds.output.hardware_cursor_unavailable_logged.get());
+    tassert!(!run.cfg.hardware_cursor_active(&ds.output)?);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use {
+    crate::{
+        cursor::KnownCursor,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that seats fall back to the default cursors if their cursor theme is missing
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    ds.move_to(400, 300);
+    run.sync().await;
+    let cursor = ds.seat.pointer_cursor();
+    tassert_eq!(cursor.desired_known_cursor(), Some(KnownCursor::Default));
+
+    let theme = "jay-missing-cursor-theme";
+    let has_theme = || {
+        run.state
+            .cursor_themes
+            .lock()
+            .iter()
+            .any(|(t, _)| t.as_str() == theme)
+    };
+
+    run.cfg.set_cursor_theme(ds.seat.id(), Some(theme))?;
+    tassert!(has_theme());
+    tassert!(run.state.themed_cursors.is_empty());
+    tassert_eq!(cursor.desired_known_cursor(), Some(KnownCursor::Default));
+    tassert_eq!(cursor.get().is_some(), run.state.cursors.is_some());
+
+    run.cfg.set_cursor_theme(ds.seat.id(), None)?;
+    tassert!(!has_theme());
+    tassert_eq!(cursor.desired_known_cursor(), Some(KnownCursor::Default));
+    tassert_eq!(cursor.get().is_some(), run.state.cursors.is_some());
+
+    Ok(())
+}
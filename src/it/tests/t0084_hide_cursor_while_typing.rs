@@ -0,0 +1,57 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the cursor is hidden while typing and shown again when the pointer moves
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    ds.move_to(400, 300);
+    run.sync().await;
+    let group = ds.seat.cursor_group();
+
+    // disabled by default
+    ds.kb.press(30);
+    run.sync().await;
+    tassert!(!ds.seat.cursor_hidden_by_typing());
+    tassert!(!group.hidden());
+
+    run.cfg.set_hide_cursor_while_typing(ds.seat.id(), true)?;
+    ds.kb.press(30);
+    run.sync().await;
+    tassert!(ds.seat.cursor_hidden_by_typing());
+    tassert!(group.hidden());
+
+    ds.move_to(410, 310);
+    run.sync().await;
+    tassert!(!ds.seat.cursor_hidden_by_typing());
+    tassert!(!group.hidden());
+
+    // the cursor stays visible during pointer grabs
+    {
+        let _click = ds.mouse.click(1);
+        run.sync().await;
+        ds.kb.press(30);
+        run.sync().await;
+        tassert!(!group.hidden());
+    }
+    run.sync().await;
+
+    ds.kb.press(30);
+    run.sync().await;
+    tassert!(group.hidden());
+
+    // disabling the option shows the cursor
+    run.cfg.set_hide_cursor_while_typing(ds.seat.id(), false)?;
+    tassert!(!ds.seat.cursor_hidden_by_typing());
+    tassert!(!group.hidden());
+
+    Ok(())
+}
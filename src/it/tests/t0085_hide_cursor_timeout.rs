@@ -0,0 +1,75 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that the cursor is hidden after the pointer has not been used for the timeout
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    ds.move_to(400, 300);
+    run.sync().await;
+    let group = ds.seat.cursor_group();
+
+    run.cfg
+        .set_hide_cursor_timeout(ds.seat.id(), Duration::from_millis(50))?;
+    run.state.wheel.timeout(100).await?;
+    run.sync().await;
+    tassert!(ds.seat.cursor_hidden_by_inactivity());
+    tassert!(group.hidden());
+
+    ds.move_to(410, 310);
+    run.sync().await;
+    tassert!(!ds.seat.cursor_hidden_by_inactivity());
+    tassert!(!group.hidden());
+
+    // key presses show the cursor if hide-cursor-while-typing is disabled
+    run.state.wheel.timeout(100).await?;
+    run.sync().await;
+    tassert!(group.hidden());
+    ds.kb.press(30);
+    run.sync().await;
+    tassert!(!group.hidden());
+
+    // the cursor stays visible during pointer grabs
+    {
+        let _click = ds.mouse.click(1);
+        run.state.wheel.timeout(100).await?;
+        run.sync().await;
+        tassert!(!group.hidden());
+    }
+    run.state.wheel.timeout(100).await?;
+    run.sync().await;
+    tassert!(group.hidden());
+
+    // key presses keep the cursor hidden if hide-cursor-while-typing is enabled
+    run.cfg.set_hide_cursor_while_typing(ds.seat.id(), true)?;
+    ds.kb.press(30);
+    run.sync().await;
+    tassert!(ds.seat.cursor_hidden_by_typing());
+    tassert!(group.hidden());
+    ds.move_to(400, 300);
+    run.sync().await;
+    tassert!(!ds.seat.cursor_hidden_by_typing());
+    tassert!(!ds.seat.cursor_hidden_by_inactivity());
+    tassert!(!group.hidden());
+
+    // a timeout of 0 disables hiding
+    run.state.wheel.timeout(100).await?;
+    run.sync().await;
+    tassert!(group.hidden());
+    run.cfg
+        .set_hide_cursor_timeout(ds.seat.id(), Duration::ZERO)?;
+    tassert!(!group.hidden());
+    run.state.wheel.timeout(100).await?;
+    run.sync().await;
+    tassert!(!group.hidden());
+
+    Ok(())
+}
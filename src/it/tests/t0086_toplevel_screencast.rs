@@ -0,0 +1,64 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestErrorExt, TestResult},
+            testrun::TestRun,
+        },
+        tree::ToplevelNodeBase,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that toplevel screencasts are reconfigured when the scale of the output changes
+/// and destroyed when the toplevel is destroyed
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+    // the logical size of floating windows does not depend on the output scale
+    run.cfg.set_floating(ds.seat.id(), true)?;
+    client.sync().await;
+
+    let data = win.tl.server.tl_data();
+    let id = data.identifier.get().to_string();
+    let tl = client.jc.get_toplevel(&id).await?;
+
+    let sc = client.jc.create_screencast()?;
+    let sizes = sc.config_size.expect()?;
+    let done = sc.config_done.expect()?;
+    sc.set_toplevel(&tl)?;
+    sc.configure()?;
+    client.sync().await;
+    run.sync().await;
+    client.sync().await;
+    let (width, height) = data.desired_pixel_size();
+    tassert!(width > 0 && height > 0);
+    tassert_eq!(
+        sizes.next().with_context(|| "no initial size")?,
+        (width, height)
+    );
+    sc.ack_config(done.next()?)?;
+
+    run.cfg.set_scale(&ds.output, 2.0)?;
+    client.sync().await;
+    run.sync().await;
+    client.sync().await;
+    let size = data.desired_pixel_size();
+    tassert!(size != (width, height));
+    tassert_eq!(
+        sizes
+            .last()
+            .with_context(|| "no size after the scale change")?,
+        size
+    );
+
+    win.tl.core.destroy()?;
+    client.sync().await;
+    tassert!(sc.server_destroyed.get());
+
+    Ok(())
+}
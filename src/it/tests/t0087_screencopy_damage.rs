@@ -0,0 +1,106 @@
+use {
+    crate::{
+        format::XRGB8888,
+        gfx_api::{AcquireSync, ReleaseSync},
+        it::{test_error::TestResult, testrun::TestRun},
+        rect::Rect,
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+testcase!();
+
+/// Test that screencopies into shm buffers only report the damage since the buffer was
+/// last used
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    // Cursors are always included in the damage of shm copies.
+    ds.seat.pointer_cursor().set(None);
+
+    let client = run.create_client().await?;
+    let output = client.get_output(&ds.output)?;
+    let manager = client.registry.get_screencopy_manager().await?;
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    let data = [const { Cell::new(0) }; 4];
+    let tex = ctx
+        .shmem_texture(None, &data, XRGB8888, 1, 1, 4, None)?
+        .into_texture();
+    let render = || {
+        ds.output.latched(false);
+        ds.output.perform_wlr_screencopies(
+            &tex,
+            None,
+            &AcquireSync::Unnecessary,
+            ReleaseSync::None,
+            false,
+            0,
+            0,
+            None,
+        );
+    };
+
+    let pos = ds.output.global.pos.get();
+    let full = pos.at_point(0, 0);
+    let (width, height) = (pos.width(), pos.height());
+    let create_buffer = || {
+        let pool = client.shm.create_pool((width * height * 4) as _)?;
+        pool.create_buffer(0, width, height, width * 4, XRGB8888)
+    };
+    let buffer = create_buffer()?;
+
+    let frame = manager.capture_output(&output)?;
+    frame.copy_with_damage(&buffer.buffer)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    tassert!(frame.ready.get());
+    tassert_eq!(frame.damage.get(), Some(full));
+
+    let damage = Rect::new_sized_unchecked(10, 20, 30, 40);
+    run.state.damage(damage);
+    let frame = manager.capture_output(&output)?;
+    frame.copy_with_damage(&buffer.buffer)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    tassert!(frame.ready.get());
+    tassert_eq!(frame.damage.get(), Some(damage));
+
+    // copies without damage complete immediately
+    let frame = manager.capture_output(&output)?;
+    frame.copy(&buffer.buffer)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    tassert!(frame.ready.get());
+    tassert_eq!(frame.damage.get(), None);
+
+    // copies with damage wait for the output to be damaged
+    let frame = manager.capture_output(&output)?;
+    frame.copy_with_damage(&buffer.buffer)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    tassert!(!frame.ready.get());
+    let damage = Rect::new_sized_unchecked(100, 100, 1, 1);
+    run.state.damage(damage);
+    render();
+    client.sync().await;
+    tassert!(frame.ready.get());
+    tassert_eq!(frame.damage.get(), Some(damage));
+
+    // new buffers are fully damaged
+    let buffer = create_buffer()?;
+    let frame = manager.capture_output(&output)?;
+    frame.copy_with_damage(&buffer.buffer)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    tassert!(frame.ready.get());
+    tassert_eq!(frame.damage.get(), Some(full));
+
+    Ok(())
+}
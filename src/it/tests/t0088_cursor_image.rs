@@ -0,0 +1,73 @@
+use {
+    crate::{
+        format::ARGB8888,
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that jay clients can capture the cursor image of a seat and are notified when it
+/// changes
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let jc = run.create_client().await?;
+    let jc_seat = jc.get_default_seat().await?;
+    let image = jc.jc.get_cursor_image(&jc_seat.seat)?;
+    let changed = image.changed.expect()?;
+    let empty = image.empty.expect()?;
+    let shm = image.shm.expect()?;
+    let info = image.image.expect()?;
+    jc.sync().await;
+
+    let enter = seat.pointer.enter.expect()?;
+    let (x, y) = win.tl.server.node_absolute_position().center();
+    ds.move_to(x, y);
+    client.sync().await;
+    let serial = enter.next()?.serial;
+
+    let buffer = client.shm.create_buffer(16, 8)?;
+    let surface = client.comp.create_surface().await?;
+    surface.attach(buffer.buffer.id)?;
+    surface.commit()?;
+    seat.pointer.set_cursor(serial, Some(&surface), 3, 4)?;
+    client.sync().await;
+    jc.sync().await;
+    changed.last()?;
+
+    image.capture_shm()?;
+    jc.sync().await;
+    tassert_eq!(shm.next()?, (16, 8, 64, ARGB8888.drm));
+    tassert_eq!(info.next()?, (3, 4, 1.0));
+    empty.none()?;
+
+    // changes are only reported once until the next capture
+    let buffer2 = client.shm.create_buffer(8, 8)?;
+    surface.attach(buffer2.buffer.id)?;
+    surface.commit()?;
+    client.sync().await;
+    surface.attach(buffer.buffer.id)?;
+    surface.commit()?;
+    client.sync().await;
+    jc.sync().await;
+    changed.next()?;
+    changed.none()?;
+
+    seat.pointer.set_cursor(serial, None, 0, 0)?;
+    client.sync().await;
+    image.capture_shm()?;
+    jc.sync().await;
+    empty.next()?;
+    shm.none()?;
+    changed.none()?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use {
+    crate::{
+        ifs::{
+            ipc::{
+                wl_data_device_manager::DND_COPY,
+                x_data_device::{XIpcDevice, XIpcLocation},
+            },
+            wl_seat::BTN_LEFT,
+            wl_surface::x_surface::xwindow::{Xwindow, XwindowData},
+        },
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::Node,
+        wire_xcon::CreateNotify,
+        xwayland::XWaylandEvent,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test the lifecycle of drags from wayland clients onto Xwayland windows
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let seat = client.get_default_seat().await?;
+    let dev = client.data_device_manager.get_data_device(&seat.seat)?;
+    let button = seat.pointer.button.expect()?;
+
+    // emulate an override-redirect window created by the window manager
+    let xclient = run.create_xwayland_client().await?;
+    let xsurface = xclient.comp.create_surface().await?;
+    let data = Rc::new(XwindowData::new(
+        &run.state,
+        &CreateNotify {
+            parent: 0,
+            window: 1,
+            x: 0,
+            y: 0,
+            width: 20,
+            height: 20,
+            border_width: 0,
+            override_redirect: 1,
+        },
+        &xclient.server,
+    ));
+    let xwindow = Xwindow::install(&data, &xsurface.server)?;
+    data.window.set(Some(xwindow.clone()));
+    data.info.mapped.set(true);
+    let buffer = xclient.shm.create_buffer(20, 20)?;
+    xsurface.attach(buffer.buffer.id)?;
+    xsurface.commit()?;
+    xclient.sync().await;
+    tassert!(xwindow.is_mapped());
+
+    let dd = Rc::new(XIpcDevice {
+        id: run.state.xwayland.ipc_device_ids.next(),
+        clipboard: Default::default(),
+        primary_selection: Default::default(),
+        dnd: Default::default(),
+        seat: ds.seat.clone(),
+        state: run.state.clone(),
+        client: xclient.server.clone(),
+    });
+    ds.seat.set_x_data_device(&dd);
+
+    let (x, y) = win.tl.server.node_absolute_position().center();
+    ds.move_to(x, y);
+    let click = ds.mouse.click(BTN_LEFT);
+    client.sync().await;
+    let src = client.data_device_manager.create_data_source()?;
+    src.offer("text/plain")?;
+    src.set_actions(DND_COPY)?;
+    let target = src.target.expect()?;
+    let action = src.action.expect()?;
+    dev.start_drag(&src, &win.surface, None, button.next()?.serial)?;
+    client.sync().await;
+
+    // entering the X window creates an offer for the window manager
+    let queue = &run.state.xwayland.queue;
+    queue.clear();
+    ds.move_to(5, 5);
+    client.sync().await;
+    ds.move_to(10, 10);
+    client.sync().await;
+    let mut offer = None;
+    let mut mime_types = vec![];
+    let mut entered = false;
+    let mut moved = false;
+    while let Some(event) = queue.try_pop() {
+        match event {
+            XWaylandEvent::IpcSetOffer {
+                location: XIpcLocation::Dnd,
+                offer: o,
+                ..
+            } => offer = Some(o),
+            XWaylandEvent::IpcAddOfferMimeType {
+                location: XIpcLocation::Dnd,
+                mime_type,
+                ..
+            } => mime_types.push(mime_type),
+            XWaylandEvent::DndEnter { surface, .. } => entered = surface == xsurface.id,
+            XWaylandEvent::DndMotion { surface, .. } => moved = surface == xsurface.id,
+            _ => {}
+        }
+    }
+    let Some(offer) = offer else {
+        bail!("no offer was created for the X window");
+    };
+    tassert_eq!(mime_types, ["text/plain"]);
+    tassert!(entered);
+    tassert!(moved);
+
+    // emulate the XdndStatus message of a target that accepts the drop
+    offer.dnd_status(Some("text/plain"), DND_COPY);
+    client.sync().await;
+    tassert_eq!(target.last()?, Some("text/plain".to_string()));
+    tassert_eq!(action.last()?, DND_COPY);
+
+    drop(click);
+    client.sync().await;
+    tassert!(src.dnd_drop_performed.get());
+    let mut dropped = false;
+    while let Some(event) = queue.try_pop() {
+        dropped |= matches!(event, XWaylandEvent::DndDrop { .. });
+    }
+    tassert!(dropped);
+    tassert!(offer.is_droppable());
+
+    // emulate the XdndFinished message of the target
+    offer.dnd_finished();
+    client.sync().await;
+    tassert!(src.dnd_finished.get());
+    tassert!(!src.cancelled.get());
+
+    ds.seat.unset_x_data_device(dd.id);
+    xsurface.destroy()?;
+    xclient.sync().await;
+
+    Ok(())
+}
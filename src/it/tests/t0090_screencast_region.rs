@@ -0,0 +1,140 @@
+use {
+    crate::{
+        allocator::{BufferObject, BO_USE_RENDERING},
+        format::XRGB8888,
+        gfx_api::{AcquireSync, ReleaseSync},
+        it::{
+            test_error::{TestErrorExt, TestResult},
+            testrun::TestRun,
+        },
+        rect::Rect,
+        video::LINEAR_MODIFIER,
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+testcase!();
+
+const RED: [u8; 3] = [0, 0, 255];
+const BLUE: [u8; 3] = [255, 0, 0];
+
+/// Test that output screencasts can be restricted to a region of the output
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let dmabuf = client.registry.get_dmabuf().await?;
+    let output = client.get_output(&ds.output)?;
+    let jay_output = client.jc.get_output(&output)?;
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    // the region is red and the rest of the output is blue
+    let (width, height) = ds.output.global.pixel_size();
+    let region = Rect::new_sized_unchecked(100, 50, 200, 100);
+    let mut data = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            let color = match region.contains(x, y) {
+                true => RED,
+                false => BLUE,
+            };
+            data.extend(color.map(Cell::new));
+            data.push(Cell::new(255));
+        }
+    }
+    let tex = ctx
+        .clone()
+        .shmem_texture(None, &data, XRGB8888, width, height, width * 4, None)?
+        .into_texture();
+    let render = || {
+        ds.output.perform_screencopies(
+            &tex,
+            None,
+            &AcquireSync::Unnecessary,
+            ReleaseSync::None,
+            false,
+            0,
+            0,
+            None,
+        );
+    };
+    let create_buffer = |(width, height): (i32, i32)| -> TestResult<_> {
+        let bo = ctx.allocator().create_bo(
+            &run.state.dma_buf_ids,
+            width,
+            height,
+            XRGB8888,
+            &[LINEAR_MODIFIER],
+            BO_USE_RENDERING,
+        )?;
+        let params = dmabuf.create_params()?;
+        let buffer = params.create_immed(bo.dmabuf())?;
+        params.destroy()?;
+        Ok((bo, buffer))
+    };
+    let read_pixels = |bo: &Rc<dyn BufferObject>| -> TestResult<Vec<[u8; 3]>> {
+        let dmabuf = bo.dmabuf();
+        let map = bo.clone().map_read()?;
+        let data = unsafe { map.data() };
+        let mut pixels = vec![];
+        for y in 0..dmabuf.height {
+            for x in 0..dmabuf.width {
+                let offset = (y * map.stride() + x * 4) as usize;
+                pixels.push([data[offset], data[offset + 1], data[offset + 2]]);
+            }
+        }
+        Ok(pixels)
+    };
+
+    let sc = client.jc.create_screencast()?;
+    let sizes = sc.config_size.expect()?;
+    let done = sc.config_done.expect()?;
+    let ready = sc.ready.expect()?;
+    sc.set_output(&jay_output)?;
+    sc.set_allow_all_workspaces(true)?;
+    sc.set_region(region.x1(), region.y1(), region.width(), region.height())?;
+    sc.configure()?;
+    client.sync().await;
+    run.sync().await;
+    client.sync().await;
+    let size = sizes.last().with_context(|| "no size with a region")?;
+    tassert_eq!(size, region.size());
+
+    let (bo, buffer) = create_buffer(size)?;
+    sc.add_buffer(&buffer)?;
+    sc.set_running(true)?;
+    sc.configure()?;
+    sc.ack_config(done.last()?)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    let idx = ready.next().with_context(|| "no frame with a region")?;
+    tassert!(read_pixels(&bo)?.iter().all(|p| *p == RED));
+
+    // unsetting the region captures the entire output again
+    sc.release_buffer(idx)?;
+    sc.unset_region()?;
+    sc.configure()?;
+    client.sync().await;
+    run.sync().await;
+    client.sync().await;
+    let size = sizes.last().with_context(|| "no size without a region")?;
+    tassert_eq!(size, (width, height));
+
+    let (bo, buffer) = create_buffer(size)?;
+    sc.clear_buffers()?;
+    sc.add_buffer(&buffer)?;
+    sc.configure()?;
+    sc.ack_config(done.last()?)?;
+    client.sync().await;
+    render();
+    client.sync().await;
+    ready.next().with_context(|| "no frame without a region")?;
+    let pixels = read_pixels(&bo)?;
+    tassert_eq!(pixels[0], BLUE);
+    tassert_eq!(pixels[(region.y1() * width + region.x1()) as usize], RED);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that protocol errors of clients are recorded exactly once
+async fn test(run: Rc<TestRun>) -> TestResult {
+    run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let _window = client.create_window().await?;
+    client.sync().await;
+
+    // destroying xdg_wm_base before its surfaces is a protocol error
+    client.xdg.destroy()?;
+
+    run.sync().await;
+    run.state.idle().await;
+    tassert!(run.state.clients.get(client.server.id).is_err());
+
+    let errors: Vec<_> = run
+        .state
+        .client_errors
+        .errors()
+        .into_iter()
+        .filter(|e| e.client_id == client.server.id)
+        .collect();
+    tassert_eq!(errors.len(), 1);
+    tassert_eq!(errors[0].interface.as_str(), "xdg_wm_base");
+    tassert!(errors[0].msg.contains("before destroying its surfaces"));
+
+    let errors = run.errors.take();
+    tassert!(errors
+        .iter()
+        .any(|e| e.contains("before destroying its surfaces")));
+
+    Ok(())
+}
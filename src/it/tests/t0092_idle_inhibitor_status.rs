@@ -0,0 +1,38 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that jay_idle reports idle inhibitors together with their activation time
+async fn test(run: Rc<TestRun>) -> TestResult {
+    run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+
+    let manager = client.registry.get_idle_inhibit_manager().await?;
+    let before = run.state.now_usec();
+    let _inhibitor = manager.create_inhibitor(&window.surface.surface)?;
+    client.sync().await;
+    tassert_eq!(run.state.idle.inhibitors.len(), 1);
+
+    let idle = client.jc.get_idle()?;
+    let inhibitors = idle.inhibitors.expect()?;
+    idle.get_status()?;
+    client.sync().await;
+
+    let info = inhibitors.next()?;
+    inhibitors.none()?;
+    tassert_eq!(info.surface, window.surface.surface.id);
+    tassert_eq!(info.client_id, client.server.id.raw());
+    let Some(since) = info.since_usec else {
+        bail!("The compositor did not send the activation time of the inhibitor");
+    };
+    tassert!(since >= before);
+    tassert!(since <= run.state.now_usec());
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use {
+    crate::{
+        backend::ConnectorEvent,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that outputs with a minimum VRR refresh rate present frames without damage
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::VrrChanged(true));
+    run.state.eng.yield_now().await;
+    tassert!(ds.output.schedule.vrr_enabled());
+
+    let damaged = &ds.output.global.connector.damaged;
+    let present = || {
+        damaged.set(false);
+        ds.output.schedule.latched();
+    };
+
+    // without a minimum refresh rate, the output is only presented on damage
+    present();
+    run.state.wheel.timeout(50).await?;
+    tassert!(!damaged.get());
+
+    // with a minimum refresh rate, the output is damaged once the frame time has elapsed
+    run.cfg.set_vrr_min_refresh(&ds.output, Some(100))?;
+    present();
+    tassert!(!damaged.get());
+    run.state.wheel.timeout(50).await?;
+    tassert!(damaged.get());
+
+    // the floor can be removed
+    run.cfg.set_vrr_min_refresh(&ds.output, None)?;
+    present();
+    run.state.wheel.timeout(50).await?;
+    tassert!(!damaged.get());
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that outputs are only throttled while they are unfocused and undamaged
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let second = run
+        .create_second_connector(&[Mode {
+            width: 400,
+            height: 400,
+            refresh_rate_millihz: 60_000,
+        }])
+        .connect()
+        .await?;
+
+    tassert!(second.throttled_present_nsec().is_none());
+
+    run.cfg.set_output_throttle(10.0)?;
+    run.cfg
+        .set_output_throttle_delay(Duration::from_millis(10))?;
+    ds.output.schedule.latched();
+    second.schedule.latched();
+    run.state.wheel.timeout(20).await?;
+
+    // only the unfocused output is throttled
+    tassert!(ds.output.throttled_present_nsec().is_none());
+    tassert!(second.throttled_present_nsec().is_some());
+
+    // damage ramps the output back up
+    run.state.damage(second.global.pos.get());
+    tassert!(second.throttled_present_nsec().is_none());
+
+    // until it has been idle again for the delay
+    second.schedule.latched();
+    run.state.wheel.timeout(20).await?;
+    tassert!(second.throttled_present_nsec().is_some());
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        backend::Mode,
+        gfx_api::{create_render_pass, GfxApiOpt},
+        it::{test_error::TestResult, testrun::TestRun},
+        scale::Scale,
+        theme::Color,
+    },
+    jay_config::video::Transform,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the output background is used as the clear color and survives reconnects
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+
+    let second = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60_000,
+    }]);
+    let output = second.connect().await?;
+
+    let color = Color::from_rgba_straight(10, 20, 30, 255);
+    output.set_background(Some(color));
+
+    second.disconnect().await;
+    let output = second.connect().await?;
+    tassert_eq!(output.global.persistent.background.get(), Some(color));
+
+    let pass = create_render_pass(
+        (400, 400),
+        &*output,
+        &run.state,
+        None,
+        Scale::from_int(1),
+        false,
+        false,
+        false,
+        Transform::None,
+        None,
+        None,
+        false,
+    );
+    tassert_eq!(pass.clear, Some(color));
+    let overdraw = pass.ops.iter().any(|op| match op {
+        GfxApiOpt::FillRect(f) => f.color == color,
+        _ => false,
+    });
+    tassert!(!overdraw);
+
+    output.set_background(None);
+    let pass = create_render_pass(
+        (400, 400),
+        &*output,
+        &run.state,
+        None,
+        Scale::from_int(1),
+        false,
+        false,
+        false,
+        Transform::None,
+        None,
+        None,
+        false,
+    );
+    tassert_eq!(pass.clear, Some(run.state.theme.colors.background.get()));
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::video::TearingMode,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that all tearing content type combinations round-trip through the config
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    for bits in 0..16 {
+        let photo = bits & 0b0001 != 0;
+        let video = bits & 0b0010 != 0;
+        let game = bits & 0b0100 != 0;
+        let requested = bits & 0b1000 != 0;
+        let mode = TearingMode::content_type(requested, photo, video, game);
+        tassert_eq!(mode.content_types(), Some((requested, photo, video, game)));
+        run.cfg.set_tearing_mode(&ds.output, mode)?;
+        tassert_eq!(
+            ds.output.global.persistent.tearing_mode.get().to_config(),
+            mode
+        );
+    }
+    tassert_eq!(
+        TearingMode::VARIANT_4.content_types(),
+        Some((false, false, false, true))
+    );
+    tassert_eq!(
+        TearingMode::VARIANT_5.content_types(),
+        Some((true, false, false, true))
+    );
+
+    for mode in [
+        TearingMode::NEVER,
+        TearingMode::ALWAYS,
+        TearingMode::VARIANT_1,
+        TearingMode::VARIANT_2,
+        TearingMode::VARIANT_3,
+    ] {
+        tassert_eq!(mode.content_types(), None);
+        run.cfg.set_tearing_mode(&ds.output, mode)?;
+        tassert_eq!(
+            ds.output.global.persistent.tearing_mode.get().to_config(),
+            mode
+        );
+    }
+
+    Ok(())
+}
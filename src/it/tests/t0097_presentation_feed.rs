@@ -0,0 +1,57 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that presentation feeds forward presentation events until the output is removed
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+
+    let second = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60_000,
+    }]);
+    let output = second.connect().await?;
+
+    let client = run.create_client().await?;
+    let test_output = client.get_output(&output)?;
+    let feed = client.jc.get_presentation_feed(&test_output)?;
+    client.sync().await;
+    tassert_eq!(output.presentation_feeds.len(), 1);
+
+    output.presented(1, 2, 16_666_666, 3, 4, true);
+    client.sync().await;
+    {
+        let presented = feed.presented.borrow();
+        tassert_eq!(presented.len(), 1);
+        let ev = &presented[0];
+        tassert_eq!(
+            (ev.tv_sec, ev.tv_nsec, ev.refresh, ev.seq, ev.flags, ev.vrr),
+            (1, 2, 16_666_666, 3, 4, 1)
+        );
+    }
+
+    // destroying the feed deregisters it
+    feed.destroy()?;
+    client.sync().await;
+    tassert!(output.presentation_feeds.is_empty());
+    tassert!(!output.presentation_event.has_listeners());
+
+    // removing the output destroys the feed
+    let feed = client.jc.get_presentation_feed(&test_output)?;
+    client.sync().await;
+    tassert!(output.presentation_event.has_listeners());
+    second.disconnect().await;
+    client.sync().await;
+    tassert!(feed.server_destroyed.get());
+    tassert!(output.presentation_feeds.is_empty());
+    tassert!(!output.presentation_event.has_listeners());
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that frame timings are forwarded without keeping emulated vblanks going
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let test_output = client.get_output(&ds.output)?;
+    let timings = client.jc.get_frame_timings(&test_output)?;
+    client.sync().await;
+    tassert_eq!(ds.output.frame_timings.len(), 1);
+
+    let connector = &ds.output.global.connector;
+    connector.needs_vblank_emulation.set(true);
+    connector.damaged.set(false);
+    ds.output.vblank();
+    tassert!(!connector.damaged.get());
+    client.sync().await;
+    tassert_eq!(timings.vblanks.get(), 1);
+
+    ds.output.flip_margin_ns.set(Some(1234));
+    ds.output.latched(true);
+    client.sync().await;
+    tassert_eq!(timings.latched.borrow().as_slice(), &[(1234, true)]);
+
+    // destroying the object deregisters its listeners
+    timings.destroy()?;
+    client.sync().await;
+    tassert!(ds.output.frame_timings.is_empty());
+    tassert!(!ds.output.passive_vblank_event.has_listeners());
+    ds.output.vblank();
+    client.sync().await;
+    tassert_eq!(timings.vblanks.get(), 1);
+
+    Ok(())
+}
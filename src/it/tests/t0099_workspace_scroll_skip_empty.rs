@@ -0,0 +1,45 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that skipping empty workspaces terminates when all workspaces are empty and
+/// that per-output overrides can be removed
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+    ds.output.create_workspace("2");
+    ds.output.create_workspace("3");
+
+    let current = || {
+        ds.output
+            .workspace
+            .get()
+            .map(|ws| ws.name.get().to_string())
+            .unwrap_or_default()
+    };
+
+    run.cfg.set_workspace_scroll_wrap(true)?;
+    run.cfg.set_workspace_scroll_skip_empty(true)?;
+    ds.output.step_workspace(&ds.seat, 1);
+    tassert_eq!(current(), "1");
+    ds.output.step_workspace(&ds.seat, -2);
+    tassert_eq!(current(), "1");
+
+    // the per-output override takes precedence over the global setting
+    run.cfg
+        .connector_set_workspace_scroll_skip_empty(&ds.output, Some(false))?;
+    ds.output.step_workspace(&ds.seat, 1);
+    tassert_eq!(current(), "2");
+
+    // removing the override falls back to the global setting
+    run.cfg
+        .connector_set_workspace_scroll_skip_empty(&ds.output, None)?;
+    tassert!(ds.output.workspace_scroll_skip_empty.get());
+    ds.output.step_workspace(&ds.seat, 1);
+    tassert_eq!(current(), "2");
+
+    Ok(())
+}
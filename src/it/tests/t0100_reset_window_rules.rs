@@ -0,0 +1,35 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        scale::Scale,
+        tree::ToplevelNodeBase,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that resetting the window rules removes all per-app-id overrides
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.tl.core.set_app_id("rules")?;
+    win.map2().await?;
+
+    run.cfg.set_scale_override("rules", Some(2.0))?;
+    run.cfg.set_block_capture("rules", true)?;
+    let data = win.tl.server.tl_data();
+    tassert_eq!(data.scale_override.get(), Some(Scale::from_int(2)));
+    tassert!(data.capture_blocked());
+
+    run.cfg.reset_window_rules()?;
+    tassert_eq!(data.scale_override.get(), None);
+    tassert!(!data.capture_blocked());
+    tassert!(run.state.scale_overrides.is_empty());
+    tassert!(run.state.capture_blocked_app_ids.is_empty());
+
+    Ok(())
+}
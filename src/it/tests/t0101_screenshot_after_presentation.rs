@@ -0,0 +1,41 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that deferred screenshots wait for presentation but not for removed outputs
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let client = run.create_client().await?;
+
+    let ss = client.jc.take_screenshot_after_presentation()?;
+    client.sync().await;
+    tassert!(ss.result.take().is_none());
+    ds.output.presented(0, 0, 0, 0, 0, false);
+    client.sync().await;
+    tassert!(ss.result.take().is_some_and(|r| r.is_ok()));
+
+    let second = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60_000,
+    }]);
+    second.connect().await?;
+
+    let ss = client.jc.take_screenshot_after_presentation()?;
+    client.sync().await;
+    ds.output.presented(0, 0, 0, 0, 0, false);
+    client.sync().await;
+    tassert!(ss.result.take().is_none());
+    second.disconnect().await;
+    run.state.wheel.timeout(1100).await?;
+    client.sync().await;
+    tassert!(ss.result.take().is_some_and(|r| r.is_ok()));
+
+    Ok(())
+}
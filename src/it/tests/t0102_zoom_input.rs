@@ -0,0 +1,50 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that pointer input goes to the content shown under the cursor while zoomed
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let win1 = client.create_window().await?;
+    win1.map2().await?;
+    let win2 = client.create_window().await?;
+    win2.map2().await?;
+    let win3 = client.create_window().await?;
+    win3.map2().await?;
+    client.sync().await;
+
+    // Find a position in win2 that shows the left edge of win3 while zoomed in by a
+    // factor of 2. The visible area is centered on the pointer.
+    let output = ds.output.global.pos.get();
+    let win2_pos = win2.tl.server.node_absolute_position();
+    let win3_pos = win3.tl.server.node_absolute_position();
+    let target = win3_pos.x1() - output.x1() + 10;
+    let x = output.x1() + (target + output.width() / 4) * 2 / 3;
+    let y = output.y1() + output.height() / 2;
+    tassert!(win2_pos.contains(x, y));
+    tassert!(win3_pos.contains(output.x1() + target, y));
+
+    let enter = seat.pointer.enter.expect()?;
+    ds.move_to(x, y);
+    client.sync().await;
+    tassert_eq!(enter.last()?.surface, win2.surface.id);
+
+    ds.move_to(output.x1(), output.y1());
+    ds.output.set_zoom(ds.seat.id(), 2.0);
+    ds.move_to(x, y);
+    client.sync().await;
+    tassert_eq!(enter.last()?.surface, win3.surface.id);
+    let (cx, _) = ds.output.content_position(x, y);
+    tassert!(win3_pos.contains(cx, y));
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use {
+    crate::{
+        allocator::BO_USE_RENDERING,
+        format::XRGB8888,
+        gfx_api::{AcquireSync, ColorMatrix, GfxApiOpt, ReleaseSync},
+        it::{test_error::TestResult, testrun::TestRun},
+        video::LINEAR_MODIFIER,
+    },
+    jay_config::video::Transform,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that color matrices are applied to the composited contents of an output
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let win = client.create_window().await?;
+    win.set_color(255, 0, 0, 128);
+    win.map2().await?;
+    client.sync().await;
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    let (width, height) = ds.output.global.pixel_size();
+    let render = || -> TestResult<Vec<[u8; 3]>> {
+        let bo = ctx.allocator().create_bo(
+            &run.state.dma_buf_ids,
+            width,
+            height,
+            XRGB8888,
+            &[LINEAR_MODIFIER],
+            BO_USE_RENDERING,
+        )?;
+        let fb = ctx.clone().dmabuf_img(bo.dmabuf())?.to_framebuffer()?;
+        fb.render_output(
+            AcquireSync::Unnecessary,
+            ReleaseSync::None,
+            &ds.output,
+            &run.state,
+            Some(ds.output.global.pos.get()),
+            ds.output.global.persistent.scale.get(),
+            true,
+        )?;
+        let map = bo.clone().map_read()?;
+        let data = unsafe { map.data() };
+        let mut pixels = vec![];
+        for y in 0..height {
+            for x in 0..width {
+                let offset = (y * map.stride() + x * 4) as usize;
+                pixels.push([data[offset], data[offset + 1], data[offset + 2]]);
+            }
+        }
+        Ok(pixels)
+    };
+
+    let normal = render()?;
+    ds.output.set_invert_colors(true);
+
+    // the pass only copies the composited contents
+    let fb = ctx
+        .clone()
+        .create_internal_fb(&run.state.cpu_worker, width, height, width * 4, XRGB8888)?
+        .into_fb();
+    let mut pass = fb.create_render_pass(
+        &*ds.output,
+        &run.state,
+        Some(ds.output.global.pos.get()),
+        ds.output.global.persistent.scale.get(),
+        true,
+        true,
+        false,
+        Transform::None,
+        None,
+        None,
+        false,
+    );
+    ds.output.apply_color_matrix(&mut pass, (width, height));
+    tassert_eq!(pass.ops.len(), 1);
+    let GfxApiOpt::CopyTexture(ct) = &pass.ops[0] else {
+        bail!("pass does not copy the composited contents");
+    };
+    tassert_eq!(ct.color_matrix, Some(ColorMatrix::INVERT));
+
+    let inverted = render()?;
+    tassert_eq!(normal.len(), inverted.len());
+    for (n, i) in normal.iter().zip(inverted.iter()) {
+        tassert_eq!(n.map(|c| 255 - c), *i);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that gamma ramps are validated per output and re-applied after mode changes
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let mode1 = Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60000,
+    };
+    let mode2 = Mode {
+        width: 800,
+        height: 600,
+        refresh_rate_millihz: 75000,
+    };
+    let second = run.create_second_connector(&[mode1, mode2]);
+    second.connector.gamma_size.set(Some(4));
+    let node2 = second.connect().await?;
+
+    let client = run.create_client().await?;
+    let output1 = client.get_output(&ds.output)?;
+    let output2 = client.get_output(&node2)?;
+    let jc = &client.jc;
+
+    jc.get_output_gamma_size(&output1)?;
+    jc.get_output_gamma_size(&output2)?;
+    client.sync().await;
+    tassert_eq!(
+        &*jc.gamma_sizes.borrow(),
+        &[(output1.id, 0), (output2.id, 4)]
+    );
+
+    let ramp = [0, 100, 200, 300];
+    jc.set_output_gamma(&output1, &ramp, &ramp, &ramp)?;
+    jc.set_output_gamma(&output2, &ramp, &ramp, &ramp)?;
+    jc.set_output_gamma(&output2, &ramp[..3], &ramp, &ramp)?;
+    client.sync().await;
+    {
+        let errors = jc.gamma_errors.borrow();
+        tassert_eq!(errors.len(), 2);
+        tassert_eq!(errors[0].0, output1.id);
+        tassert_eq!(errors[1].0, output2.id);
+    }
+    tassert!(ds.connector.gamma_ramp.is_none());
+    let Some(applied) = second.connector.gamma_ramp.get() else {
+        bail!("gamma ramp was not applied");
+    };
+    tassert_eq!(&applied.red, &ramp);
+
+    second.connector.gamma_ramp.set(None);
+    node2.update_mode_and_transform(mode2, node2.global.persistent.transform.get());
+    tassert!(second.connector.gamma_ramp.is_some());
+
+    jc.reset_output_gamma(&output2)?;
+    client.sync().await;
+    tassert!(second.connector.gamma_ramp.is_none());
+    tassert!(node2.gamma_ramp.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+const REFRESH_NS: u32 = 16_666_666;
+
+/// Test that missed frames are counted per output and that idle vblanks are not
+/// counted as missed
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let randr = client.jc.get_randr()?;
+    let name = ds.output.global.connector.name.clone();
+
+    randr.get_statistics(&name)?;
+    client.sync().await;
+    let Some(initial) = randr.statistics.borrow_mut().pop() else {
+        bail!("no statistics");
+    };
+
+    let present = |seq: u64, flags: u32| {
+        ds.output.latched(false);
+        ds.output.vblank();
+        ds.output.presented(0, 0, REFRESH_NS, seq, flags, false);
+    };
+    present(10, 0);
+    // two frames were missed
+    present(13, 0);
+    // an idle vblank followed by a frame that was presented in time
+    ds.output.vblank();
+    present(15, 5);
+
+    randr.get_statistics(&name)?;
+    client.sync().await;
+    let Some(stats) = randr.statistics.borrow_mut().pop() else {
+        bail!("no statistics");
+    };
+    tassert_eq!(stats.vblanks - initial.vblanks, 4);
+    tassert_eq!(stats.latches - initial.latches, 3);
+    tassert_eq!(stats.presentations - initial.presentations, 3);
+    tassert_eq!(stats.missed_frames - initial.missed_frames, 2);
+    tassert_eq!(stats.last_refresh_ns, REFRESH_NS);
+    tassert_eq!(stats.last_flags, 5);
+
+    randr.get_statistics("does-not-exist")?;
+    client.sync().await;
+    tassert!(randr.statistics.borrow().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use {
+    crate::{
+        backend::ConnectorEvent,
+        it::{test_backend::TestHardwareCursor, test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that hardware cursor updates can be coalesced until the next vblank
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.seat.cursor_group().set_hardware_cursor(true);
+
+    let hc = Rc::new(TestHardwareCursor::default());
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::HardwareCursor(Some(hc.clone())));
+    run.state.eng.yield_now().await;
+    tassert!(ds.output.hardware_cursor.is_some());
+
+    // updates are applied immediately by default
+    let damaged = hc.damaged.get();
+    ds.mouse.rel(1.0, 1.0);
+    run.sync().await;
+    tassert!(hc.damaged.get() > damaged);
+
+    run.cfg.set_vblank_cursor_updates(&ds.output, true)?;
+    let damaged = hc.damaged.get();
+    ds.mouse.rel(1.0, 1.0);
+    ds.mouse.rel(1.0, 1.0);
+    run.sync().await;
+    tassert_eq!(hc.damaged.get(), damaged);
+    tassert!(ds.output.hardware_cursor_vblank_pending.get());
+    ds.output.vblank();
+    tassert_eq!(hc.damaged.get(), damaged + 1);
+    tassert!(!ds.output.hardware_cursor_vblank_pending.get());
+    ds.output.vblank();
+    tassert_eq!(hc.damaged.get(), damaged + 1);
+
+    // disabling the option flushes pending updates
+    ds.mouse.rel(1.0, 1.0);
+    run.sync().await;
+    tassert_eq!(hc.damaged.get(), damaged + 1);
+    run.cfg.set_vblank_cursor_updates(&ds.output, false)?;
+    tassert_eq!(hc.damaged.get(), damaged + 2);
+    tassert!(!ds.output.hardware_cursor_vblank_pending.get());
+
+    // the software cursor is not delayed
+    run.cfg.set_vblank_cursor_updates(&ds.output, true)?;
+    run.cfg.set_use_hardware_cursor(&ds.output, false)?;
+    ds.mouse.rel(1.0, 1.0);
+    run.sync().await;
+    tassert!(ds.seat.pointer_cursor().software_cursor());
+    tassert!(!ds.output.hardware_cursor_vblank_pending.get());
+
+    Ok(())
+}
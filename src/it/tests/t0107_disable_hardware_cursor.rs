@@ -0,0 +1,81 @@
+use {
+    crate::{
+        backend::ConnectorEvent,
+        format::ARGB8888,
+        it::{
+            test_backend::{TestHardwareCursor, TestHardwareCursorUpdate},
+            test_error::TestResult,
+            testrun::TestRun,
+        },
+        tree::Node,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the hardware cursor can be disabled per output in favor of the software
+/// cursor
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.seat.cursor_group().set_hardware_cursor(true);
+
+    let hc = Rc::new(TestHardwareCursor::default());
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::HardwareCursor(Some(hc.clone())));
+    run.state.eng.yield_now().await;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let enter = seat.pointer.enter.expect()?;
+    let (x, y) = win.tl.server.node_absolute_position().center();
+    ds.move_to(x, y);
+    client.sync().await;
+    let serial = enter.next()?.serial;
+
+    let buffer = client.shm.create_buffer(16, 8)?;
+    let surface = client.comp.create_surface().await?;
+    surface.attach(buffer.buffer.id)?;
+    surface.commit()?;
+    seat.pointer.set_cursor(serial, Some(&surface), 3, 4)?;
+    client.sync().await;
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    let present = || -> TestResult<TestHardwareCursorUpdate> {
+        let fb = ctx
+            .clone()
+            .create_internal_fb(&run.state.cpu_worker, 64, 64, 64 * 4, ARGB8888)?
+            .into_fb();
+        let mut update = TestHardwareCursorUpdate::new(fb, 64, 64);
+        run.state.present_hardware_cursor(&ds.output, &mut update);
+        Ok(update)
+    };
+    let cursor = ds.seat.pointer_cursor();
+
+    tassert!(ds.output.hardware_cursor.is_some());
+    tassert!(!cursor.software_cursor());
+    tassert!(present()?.enabled);
+
+    let damaged = hc.damaged.get();
+    run.cfg.set_use_hardware_cursor(&ds.output, false)?;
+    tassert!(hc.damaged.get() > damaged);
+    tassert!(ds.output.hardware_cursor.is_none());
+    tassert!(ds.output.backend_hardware_cursor.is_some());
+    tassert!(cursor.software_cursor());
+    tassert!(!present()?.enabled);
+
+    run.cfg.set_use_hardware_cursor(&ds.output, true)?;
+    tassert!(ds.output.hardware_cursor.is_some());
+    tassert!(!cursor.software_cursor());
+    let update = present()?;
+    tassert!(update.enabled);
+    tassert!(update.swapped);
+
+    Ok(())
+}
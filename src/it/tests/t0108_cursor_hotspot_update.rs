@@ -0,0 +1,91 @@
+use {
+    crate::{
+        backend::ConnectorEvent,
+        format::ARGB8888,
+        it::{
+            test_backend::{TestHardwareCursor, TestHardwareCursorUpdate},
+            test_error::TestResult,
+            testrun::TestRun,
+        },
+        tree::Node,
+        utils::transform_ext::TransformExt,
+    },
+    jay_config::video::Transform,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that hotspot-only changes of the cursor don't re-render the hardware cursor
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.seat.cursor_group().set_hardware_cursor(true);
+
+    let hc = Rc::new(TestHardwareCursor::default());
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::HardwareCursor(Some(hc.clone())));
+    run.state.eng.yield_now().await;
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let win = client.create_window().await?;
+    win.map2().await?;
+
+    let enter = seat.pointer.enter.expect()?;
+    let (x, y) = win.tl.server.node_absolute_position().center();
+    ds.move_to(x, y);
+    client.sync().await;
+    let serial = enter.next()?.serial;
+
+    let buffer = client.shm.create_buffer(16, 8)?;
+    let surface = client.comp.create_surface().await?;
+    surface.attach(buffer.buffer.id)?;
+    surface.commit()?;
+    seat.pointer.set_cursor(serial, Some(&surface), 3, 4)?;
+    client.sync().await;
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    let present = || -> TestResult<TestHardwareCursorUpdate> {
+        let fb = ctx
+            .clone()
+            .create_internal_fb(&run.state.cpu_worker, 64, 64, 64 * 4, ARGB8888)?
+            .into_fb();
+        let mut update = TestHardwareCursorUpdate::new(fb, 64, 64);
+        run.state.present_hardware_cursor(&ds.output, &mut update);
+        Ok(update)
+    };
+
+    let update = present()?;
+    tassert!(update.enabled);
+    tassert!(update.swapped);
+    tassert_eq!(update.hotspot, (3, 4));
+
+    let damaged = hc.damaged.get();
+    seat.pointer.set_cursor(serial, Some(&surface), 5, 6)?;
+    client.sync().await;
+    tassert!(hc.damaged.get() > damaged);
+    tassert!(!ds.output.hardware_cursor_needs_render.get());
+    let update = present()?;
+    tassert!(update.enabled);
+    tassert!(!update.swapped);
+    tassert_eq!(update.hotspot, (5, 6));
+
+    // the hotspot is transformed together with the cursor plane
+    run.cfg
+        .set_output_transform(&ds.output, Transform::Rotate90)?;
+    client.sync().await;
+    present()?;
+    seat.pointer.set_cursor(serial, Some(&surface), 7, 8)?;
+    client.sync().await;
+    let update = present()?;
+    tassert!(!update.swapped);
+    tassert_eq!(
+        update.hotspot,
+        Transform::Rotate90.apply_point(64, 64, (7, 8))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use {
+    crate::{
+        format::XRGB8888,
+        gfx_api::GfxApiOpt,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::video::Transform,
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that the pointer locator is drawn around the pointer until it expires
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.move_to(100, 100);
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    let pos = ds.output.global.pos.get();
+    let (width, height) = (pos.width(), pos.height());
+    let fb = ctx
+        .clone()
+        .create_internal_fb(&run.state.cpu_worker, width, height, width * 4, XRGB8888)?
+        .into_fb();
+    let fills = || {
+        let pass = fb.create_render_pass(
+            &*ds.output,
+            &run.state,
+            Some(pos),
+            ds.output.global.persistent.scale.get(),
+            true,
+            true,
+            false,
+            Transform::None,
+            None,
+            None,
+            false,
+        );
+        pass.ops
+            .iter()
+            .filter(|op| matches!(op, GfxApiOpt::FillRect(_)))
+            .count()
+    };
+
+    tassert!(ds.seat.pointer_locator().is_none());
+    let without = fills();
+
+    run.cfg
+        .set_pointer_locator_duration(Duration::from_secs(100))?;
+    run.cfg.locate_pointer(ds.seat.id())?;
+    let Some(progress) = ds.seat.pointer_locator() else {
+        bail!("pointer locator is not active");
+    };
+    tassert!((0.0..1.0).contains(&progress));
+    tassert!(fills() > without);
+
+    run.cfg
+        .set_pointer_locator_duration(Duration::from_millis(10))?;
+    run.state.wheel.timeout(50).await?;
+    tassert!(ds.seat.pointer_locator().is_none());
+    tassert_eq!(fills(), without);
+
+    Ok(())
+}
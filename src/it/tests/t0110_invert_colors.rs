@@ -0,0 +1,55 @@
+use {
+    crate::{
+        backend::ConnectorEvent,
+        gfx_api::ColorMatrix,
+        it::{test_backend::TestHardwareCursor, test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that output colors can be inverted and that the inversion is applied after the
+/// color filter
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.seat.cursor_group().set_hardware_cursor(true);
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::HardwareCursor(Some(Rc::new(
+            TestHardwareCursor::default(),
+        ))));
+    run.state.eng.yield_now().await;
+    tassert!(run.cfg.hardware_cursor_active(&ds.output)?);
+    tassert_eq!(ds.output.color_matrix(), None);
+
+    run.cfg.toggle_invert_colors(ds.seat.id())?;
+    tassert!(ds.output.global.persistent.invert_colors.get());
+    tassert_eq!(ds.output.color_matrix(), Some(ColorMatrix::INVERT));
+    tassert!(!run.cfg.hardware_cursor_active(&ds.output)?);
+
+    let filter = ColorMatrix::from_affine(&[
+        [0.5, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.25, 0.0],
+    ]);
+    ds.output.set_color_filter(Some(filter));
+    tassert_eq!(
+        ds.output.color_matrix(),
+        Some(ColorMatrix::INVERT.mul(&filter))
+    );
+
+    run.cfg.toggle_invert_colors(ds.seat.id())?;
+    tassert!(!ds.output.global.persistent.invert_colors.get());
+    tassert_eq!(ds.output.color_matrix(), Some(filter));
+
+    ds.output.set_color_filter(None);
+    tassert!(run.cfg.hardware_cursor_active(&ds.output)?);
+
+    run.cfg.set_invert_colors(&ds.output, true)?;
+    tassert_eq!(ds.output.color_matrix(), Some(ColorMatrix::INVERT));
+    run.cfg.set_invert_colors(&ds.output, false)?;
+    tassert_eq!(ds.output.color_matrix(), None);
+
+    Ok(())
+}
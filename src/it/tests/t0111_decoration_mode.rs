@@ -0,0 +1,57 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::{DecorationMode, ToplevelNodeBase},
+    },
+    jay_config::window::DecorationMode as ConfigDecorationMode,
+    std::rc::Rc,
+};
+
+testcase!();
+
+const CLIENT_SIDE: u32 = 1;
+const SERVER_SIDE: u32 = 2;
+
+/// Test that the decoration mode of windows can be forced by app id
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let decoration_manager = client.registry.get_decoration_manager().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+    run.cfg.set_floating(ds.seat.id(), true)?;
+    let float = window.tl.float_parent()?;
+    let th = run.state.theme.sizes.title_height.get() + 1;
+    tassert_eq!(float.title_height(), th);
+
+    let decoration = decoration_manager.get_toplevel_decoration(&window.tl)?;
+    decoration.set_mode(CLIENT_SIDE)?;
+    client.sync().await;
+    tassert_eq!(decoration.mode.get(), Some(SERVER_SIDE));
+
+    run.cfg
+        .set_decoration_mode("client", Some(ConfigDecorationMode::CLIENT))?;
+    run.cfg
+        .set_decoration_mode("none", Some(ConfigDecorationMode::NONE))?;
+
+    window.tl.core.set_app_id("client")?;
+    client.sync().await;
+    let data = window.tl.server.tl_data();
+    tassert_eq!(data.decoration_mode.get(), DecorationMode::Client);
+    tassert_eq!(decoration.mode.get(), Some(CLIENT_SIDE));
+    tassert_eq!(float.title_height(), 0);
+
+    window.tl.core.set_app_id("none")?;
+    client.sync().await;
+    tassert_eq!(data.decoration_mode.get(), DecorationMode::None);
+    tassert_eq!(decoration.mode.get(), Some(SERVER_SIDE));
+    tassert_eq!(float.title_height(), 0);
+
+    run.cfg.set_decoration_mode("none", None)?;
+    client.sync().await;
+    tassert_eq!(data.decoration_mode.get(), DecorationMode::Server);
+    tassert_eq!(float.title_height(), th);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        theme::Color,
+        tree::{window_border_inset, Node},
+    },
+    jay_config::theme::{colors::UNFOCUSED_WINDOW_BORDER_COLOR, Color as ConfigColor},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that window borders can be configured per app id and are drawn inside the body
+/// of the window
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let win1 = client.create_window().await?;
+    win1.tl.core.set_app_id("bordered")?;
+    win1.map2().await?;
+    let win2 = client.create_window().await?;
+    win2.map2().await?;
+
+    let container = win1.tl.container_parent()?;
+    let pos1 = win1.tl.server.node_absolute_position();
+    let pos2 = win2.tl.server.node_absolute_position();
+    tassert!(container.render_data.borrow().window_borders.is_empty());
+
+    let red = ConfigColor::new(255, 0, 0);
+    run.cfg
+        .set_window_border_color("bordered", UNFOCUSED_WINDOW_BORDER_COLOR, Some(red))?;
+    run.cfg.set_window_border_width("bordered", Some(5))?;
+    client.sync().await;
+    tassert_eq!(
+        win1.tl.server.node_absolute_position(),
+        window_border_inset(&pos1, 5)
+    );
+    tassert_eq!(win2.tl.server.node_absolute_position(), pos2);
+    {
+        let rd = container.render_data.borrow();
+        tassert_eq!(rd.window_borders.len(), 1);
+        tassert_eq!(rd.window_borders[0].1, Color::from(red));
+    }
+
+    run.cfg.set_window_border_width("bordered", None)?;
+    client.sync().await;
+    tassert_eq!(win1.tl.server.node_absolute_position(), pos1);
+    tassert!(container.render_data.borrow().window_borders.is_empty());
+
+    Ok(())
+}
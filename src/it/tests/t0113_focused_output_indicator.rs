@@ -0,0 +1,40 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the focused output indicator follows the pointer between outputs
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let second = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 400,
+        refresh_rate_millihz: 60000,
+    }]);
+    let node2 = second.connect().await?;
+
+    ds.mouse.abs(&ds.connector, 10.0, 10.0);
+    run.sync().await;
+    tassert!(!ds.output.focus_indicator.get());
+    tassert!(!node2.focus_indicator.get());
+
+    run.cfg.set_show_focused_output(true)?;
+    tassert!(ds.output.focus_indicator.get());
+    tassert!(!node2.focus_indicator.get());
+
+    ds.mouse.abs(&second.connector, 10.0, 10.0);
+    run.sync().await;
+    tassert!(!ds.output.focus_indicator.get());
+    tassert!(node2.focus_indicator.get());
+
+    run.cfg.set_show_focused_output(false)?;
+    tassert!(!ds.output.focus_indicator.get());
+    tassert!(!node2.focus_indicator.get());
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use {
+    crate::{
+        ifs::wl_seat::BTN_RIGHT,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::{input::DesktopClick, video::Connector},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the config is notified about clicks on the desktop but not about clicks on
+/// the bar or on windows
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    ds.mouse.abs(&ds.connector, 100.0, 100.0);
+    ds.mouse.click(BTN_RIGHT);
+    run.sync().await;
+    tassert_eq!(
+        run.cfg.desktop_clicks.take(),
+        vec![(
+            ds.seat.id(),
+            DesktopClick {
+                connector: Connector(ds.connector.id.raw() as _),
+                button: BTN_RIGHT,
+                x: 100,
+                y: 100,
+            },
+        )]
+    );
+
+    // the bar
+    ds.mouse.abs(&ds.connector, 100.0, 5.0);
+    ds.mouse.click(BTN_RIGHT);
+    run.sync().await;
+    tassert!(run.cfg.desktop_clicks.take().is_empty());
+
+    let client = run.create_client().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+    ds.mouse.abs(&ds.connector, 100.0, 100.0);
+    ds.mouse.click(BTN_RIGHT);
+    client.sync().await;
+    tassert!(run.cfg.desktop_clicks.take().is_empty());
+
+    Ok(())
+}
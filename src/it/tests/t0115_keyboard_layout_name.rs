@@ -0,0 +1,62 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+const KEYMAP: &str = r#"
+xkb_keymap {
+    xkb_keycodes {
+        <1> = 9;
+        <2> = 10;
+    };
+    xkb_types {
+    };
+    xkb_compatibility {
+        interpret ISO_Next_Group {
+            action = LockGroup(group = +1);
+        };
+    };
+    xkb_symbols {
+        name[Group1] = "First";
+        key <1> { [ a ], [ b ] };
+        key <2> { [ ISO_Next_Group ] };
+    };
+};
+"#;
+
+/// Test that the config is informed about the name of the active layout and that
+/// unnamed layouts are reported by their index
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let seat = ds.seat.id();
+
+    let keymap = run.cfg.parse_keymap(KEYMAP)?;
+    run.cfg.set_keymap(seat, keymap)?;
+    run.sync().await;
+    run.cfg.keyboard_layouts.take();
+    tassert_eq!(run.cfg.get_keyboard_layout(seat)?, "First");
+
+    ds.kb.press(2);
+    run.sync().await;
+    tassert_eq!(
+        run.cfg.keyboard_layouts.take(),
+        vec![(seat, "1".to_string())]
+    );
+    tassert_eq!(run.cfg.get_keyboard_layout(seat)?, "1");
+
+    // keys that don't change the group are not reported
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(run.cfg.keyboard_layouts.take().is_empty());
+
+    ds.kb.press(2);
+    run.sync().await;
+    tassert_eq!(
+        run.cfg.keyboard_layouts.take(),
+        vec![(seat, "First".to_string())]
+    );
+
+    Ok(())
+}
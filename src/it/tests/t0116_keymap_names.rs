@@ -0,0 +1,48 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        xkbcommon::XkbRuleNames,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that keymap names can be queried and that invalid names are rejected
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let jc = &client.jc;
+    let seat_name = ds.seat.seat_name().to_string();
+
+    jc.get_keymap_names()?;
+    client.sync().await;
+    tassert_eq!(
+        &*jc.keymap_names.borrow(),
+        &[(seat_name.clone(), XkbRuleNames::default())]
+    );
+    jc.keymap_names.borrow_mut().clear();
+
+    let names = |layout: &str, variant: &str| XkbRuleNames {
+        layout: layout.to_string(),
+        variant: variant.to_string(),
+        ..Default::default()
+    };
+    jc.set_keymap_names(&names("us de", ""))?;
+    jc.set_keymap_names(&names("us,de,fr,it,es", ""))?;
+    jc.set_keymap_names(&names("us", "intl,nodeadkeys"))?;
+    client.sync().await;
+    tassert_eq!(jc.keymap_errors.borrow().len(), 3);
+    tassert!(jc.keymap_names.borrow().is_empty());
+    tassert!(ds.seat.keymap().names.is_none());
+
+    let valid = names("us", "");
+    jc.set_keymap_names(&valid)?;
+    client.sync().await;
+    tassert_eq!(jc.keymap_errors.borrow().len(), 3);
+    tassert_eq!(&*jc.keymap_names.borrow(), &[(seat_name, valid.clone())]);
+    tassert_eq!(ds.seat.keymap().names.as_ref(), Some(&valid));
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use {
+    crate::{
+        config::ConfigError,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::{io, rc::Rc},
+};
+
+testcase!();
+
+/// Test that broken config libraries are distinguished from missing ones and that safe
+/// mode is shown in the status
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    tassert!(ConfigError::ConfigDirNotSet.is_missing());
+    let not_found = io::Error::from(io::ErrorKind::NotFound);
+    tassert!(ConfigError::CopyConfigFile(not_found).is_missing());
+    let denied = io::Error::from(io::ErrorKind::PermissionDenied);
+    tassert!(!ConfigError::CopyConfigFile(denied).is_missing());
+    tassert!(!ConfigError::XrdNotSet.is_missing());
+
+    run.state.set_status("status");
+    tassert_eq!(ds.output.status.get().as_str(), "status");
+
+    run.state.enter_config_safe_mode();
+    tassert!(ds
+        .output
+        .status
+        .get()
+        .contains("config.so could not be loaded"));
+    run.state.set_status("status");
+    let status = ds.output.status.get();
+    tassert!(status.contains("config.so could not be loaded"));
+    tassert!(status.ends_with("status"));
+
+    run.state.config_safe_mode.set(false);
+    run.state.set_status("status");
+    tassert_eq!(ds.output.status.get().as_str(), "status");
+
+    Ok(())
+}
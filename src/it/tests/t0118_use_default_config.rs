@@ -0,0 +1,74 @@
+use {
+    crate::{
+        config::ConfigProxy,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::keyboard::syms::SYM_F13,
+    std::rc::Rc,
+};
+
+testcase!();
+
+const KEYMAP: &str = r#"
+xkb_keymap {
+    xkb_keycodes {
+          <1> = 9; # ESC
+    };
+    xkb_types {
+    };
+    xkb_compatibility {
+    };
+    xkb_symbols {
+        key <1> { [ F13 ] };
+    };
+};
+"#;
+
+/// Test that switching to the default config destroys the old config and its shortcuts
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let keymap = run.cfg.parse_keymap(KEYMAP)?;
+    run.cfg.set_keymap(ds.seat.id(), keymap)?;
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F13)?;
+    run.sync().await;
+
+    ds.kb.press(1);
+    run.sync().await;
+    tassert_eq!(run.cfg.invoked_shortcuts.clear().len(), 1);
+
+    let Some(old) = run.state.config.get() else {
+        bail!("no config");
+    };
+    let client = run.create_client().await?;
+    client.jc.use_default_config()?;
+    client.sync().await;
+    let Some(new) = run.state.config.get() else {
+        bail!("no config");
+    };
+    tassert!(!Rc::ptr_eq(&old, &new));
+
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.is_empty());
+
+    run.state.swap_config(ConfigProxy::for_test(&run.state));
+    let keymap = run.cfg.parse_keymap(KEYMAP)?;
+    run.cfg.set_keymap(ds.seat.id(), keymap)?;
+    run.sync().await;
+
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.is_empty());
+
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F13)?;
+    run.sync().await;
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(run
+        .cfg
+        .invoked_shortcuts
+        .contains(&(ds.seat.id(), SYM_F13.into())));
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::keyboard::{
+        syms::{SYM_F13, SYM_F14, SYM_F15},
+        ModifiedKeySym,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that key sequences are invoked, take precedence over shortcuts, and consume
+/// aborting keys unless configured otherwise
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let seat = ds.seat.id();
+
+    let keymap = r#"
+xkb_keymap {
+    xkb_keycodes {
+          <1> = 9;
+          <2> = 10;
+          <3> = 11;
+    };
+    xkb_types {
+    };
+    xkb_compatibility {
+    };
+    xkb_symbols {
+        key <1> { [ F13 ] };
+        key <2> { [ F14 ] };
+        key <3> { [ F15 ] };
+    };
+};
+    "#;
+    let keymap = run.cfg.parse_keymap(keymap)?;
+    run.cfg.set_keymap(seat, keymap)?;
+    let sequence: Vec<ModifiedKeySym> = vec![SYM_F13.into(), SYM_F14.into()];
+    run.cfg.add_key_sequence(seat, &sequence)?;
+    run.cfg.add_shortcut(seat, SYM_F13)?;
+    run.cfg.add_shortcut(seat, SYM_F15)?;
+    run.sync().await;
+
+    ds.kb.press(1);
+    run.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.is_empty());
+    let Some(hint) = run.state.status_hint.get() else {
+        bail!("no key sequence hint");
+    };
+    tassert!(hint.starts_with("F13 "));
+
+    ds.kb.press(2);
+    run.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.is_empty());
+    tassert!(run.state.status_hint.get().is_none());
+    tassert_eq!(&run.cfg.invoked_key_sequences.take(), &[(seat, sequence)]);
+
+    ds.kb.press(1);
+    ds.kb.press(3);
+    run.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.is_empty());
+    tassert!(run.cfg.invoked_key_sequences.take().is_empty());
+    tassert!(run.state.status_hint.get().is_none());
+
+    run.cfg.set_forward_aborted_key_sequences(seat, true)?;
+    ds.kb.press(1);
+    ds.kb.press(3);
+    run.sync().await;
+    tassert!(run.cfg.invoked_key_sequences.take().is_empty());
+    tassert!(run.cfg.invoked_shortcuts.contains(&(seat, SYM_F15.into())));
+
+    Ok(())
+}
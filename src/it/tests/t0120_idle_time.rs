@@ -0,0 +1,39 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that the idle time of a seat can be queried and is reported periodically
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let seat = ds.seat.id();
+
+    ds.move_to(100, 100);
+    run.sync().await;
+    run.state.wheel.timeout(60).await?;
+    tassert!(run.cfg.get_idle_time(seat)? >= Duration::from_millis(50));
+
+    run.cfg
+        .set_idle_time_interval(seat, Duration::from_millis(20))?;
+    run.state.wheel.timeout(100).await?;
+    let reported = run.cfg.idle_times.take();
+    tassert!(reported.len() > 0);
+    for (s, idle) in reported {
+        tassert_eq!(s, seat);
+        tassert!(idle > Duration::ZERO);
+    }
+
+    ds.move_to(200, 200);
+    run.sync().await;
+    tassert!(run.cfg.get_idle_time(seat)? < Duration::from_millis(50));
+    tassert_eq!(run.cfg.idle_times.pop(), Some((seat, Duration::ZERO)));
+
+    run.cfg.set_idle_time_interval(seat, Duration::ZERO)?;
+    run.cfg.idle_times.take();
+    run.state.wheel.timeout(60).await?;
+    tassert!(run.cfg.idle_times.take().is_empty());
+
+    Ok(())
+}
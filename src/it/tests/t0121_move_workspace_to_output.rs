@@ -0,0 +1,55 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::{Node, ToplevelNodeBase},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that moving a visible workspace to another output keeps it visible and focused
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+
+    let Some(ws) = window.tl.server.tl_data().workspace.get() else {
+        bail!("window has no workspace");
+    };
+    tassert_eq!(ws.output.get().id, ds.output.id);
+    tassert_eq!(
+        ds.seat.keyboard_node().node_id(),
+        window.tl.server.node_id()
+    );
+
+    let second = run.create_second_connector(&[Mode {
+        width: 800,
+        height: 600,
+        refresh_rate_millihz: 60000,
+    }]);
+    let node2 = second.connect().await?;
+
+    run.cfg.move_workspace_to_output(ds.seat.id(), &node2)?;
+    run.sync().await;
+
+    tassert_eq!(ws.output.get().id, node2.id);
+    tassert!(ws.visible.get());
+    let Some(visible) = node2.workspace.get() else {
+        bail!("second output has no workspace");
+    };
+    tassert_eq!(visible.id, ws.id);
+    let Some(replacement) = ds.output.workspace.get() else {
+        bail!("first output has no workspace");
+    };
+    tassert!(replacement.id != ws.id);
+    tassert_eq!(
+        ds.seat.keyboard_node().node_id(),
+        window.tl.server.node_id()
+    );
+
+    Ok(())
+}
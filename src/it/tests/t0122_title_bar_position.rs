@@ -0,0 +1,59 @@
+use {
+    crate::{
+        ifs::wl_seat::BTN_LEFT,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::video::TitleBarPosition,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the bar can be moved to the bottom of an output and still be clicked
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let th = run.state.theme.sizes.title_height.get();
+
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+    let client = run.create_client().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+    run.cfg.show_workspace(ds.seat.id(), "2")?;
+
+    let ne = ds.output.non_exclusive_rect.get();
+    tassert_eq!(ds.output.workspace_rect.get().y1(), ne.y1() + th + 1);
+
+    run.cfg
+        .set_title_bar_position(&ds.output, TitleBarPosition::Bottom)?;
+    run.sync().await;
+
+    let ws_rect = ds.output.workspace_rect.get();
+    tassert_eq!(ws_rect.y1(), ne.y1());
+    tassert_eq!(ws_rect.height(), ne.height() - th - 1);
+
+    let title = {
+        let rd = ds.output.render_data.borrow_mut();
+        tassert_eq!(rd.underline.y1(), ne.height() - th - 1);
+        rd.titles
+            .iter()
+            .find(|t| t.ws.name.get().as_str() == "1")
+            .map(|t| (t.x1 + t.x2) / 2)
+    };
+    let Some(x) = title else {
+        bail!("workspace 1 has no title");
+    };
+    ds.mouse.abs(&ds.connector, x as _, (ne.y2() - th / 2) as _);
+    ds.mouse.click(BTN_LEFT);
+    run.sync().await;
+    let Some(ws) = ds.output.workspace.get() else {
+        bail!("output has no workspace");
+    };
+    tassert_eq!(ws.name.get().as_str(), "1");
+
+    run.cfg
+        .set_title_bar_position(&ds.output, TitleBarPosition::Top)?;
+    run.sync().await;
+    tassert_eq!(ds.output.workspace_rect.get().y1(), ne.y1() + th + 1);
+
+    Ok(())
+}
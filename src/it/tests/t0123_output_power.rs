@@ -0,0 +1,37 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that turning an output off hides its contents until it is turned on again
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+    let output = client.get_output(&ds.output)?;
+
+    let Some(ws) = ds.output.workspace.get() else {
+        bail!("output has no workspace");
+    };
+    tassert!(ws.visible.get());
+
+    client.jc.set_output_power(&output, false)?;
+    client.sync().await;
+    tassert!(!ds.output.power.get());
+    tassert!(!ds.connector.power.get());
+    tassert!(!ws.visible.get());
+    tassert!(!ds.output.title_visible.get());
+
+    client.jc.set_output_power(&output, true)?;
+    client.sync().await;
+    tassert!(ds.output.power.get());
+    tassert!(ds.connector.power.get());
+    tassert!(ws.visible.get());
+    tassert!(ds.output.title_visible.get());
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    jay_config::video::Connector,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the pointer position is reported relative to the output containing it
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let second = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 300,
+        refresh_rate_millihz: 60000,
+    }]);
+    let node2 = second.connect().await?;
+
+    ds.mouse.abs(&ds.connector, 10.0, 20.0);
+    run.sync().await;
+    let pos = run.cfg.get_pointer_position(ds.seat.id())?;
+    let rect = ds.output.global.pos.get();
+    tassert_eq!(pos.connector, Connector(ds.connector.id.raw() as _));
+    tassert_eq!(pos.output_x, 10.0);
+    tassert_eq!(pos.output_y, 20.0);
+    tassert_eq!(pos.x, rect.x1() as f64 + 10.0);
+    tassert_eq!(pos.y, rect.y1() as f64 + 20.0);
+    tassert_eq!(pos.output_width, rect.width());
+    tassert_eq!(pos.output_height, rect.height());
+
+    ds.mouse.abs(&second.connector, 30.0, 40.0);
+    run.sync().await;
+    let pos = run.cfg.get_pointer_position(ds.seat.id())?;
+    let rect = node2.global.pos.get();
+    tassert_eq!(pos.connector, Connector(second.connector.id.raw() as _));
+    tassert_eq!(pos.output_x, 30.0);
+    tassert_eq!(pos.output_y, 40.0);
+    tassert_eq!(pos.x, rect.x1() as f64 + 30.0);
+    tassert_eq!(pos.y, rect.y1() as f64 + 40.0);
+    tassert_eq!(pos.output_width, 400);
+    tassert_eq!(pos.output_height, 300);
+
+    Ok(())
+}
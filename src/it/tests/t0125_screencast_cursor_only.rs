@@ -0,0 +1,105 @@
+use {
+    crate::{
+        allocator::BO_USE_RENDERING,
+        backend::ConnectorEvent,
+        format::{ARGB8888, XRGB8888},
+        gfx_api::{AcquireSync, ReleaseSync},
+        it::{
+            test_backend::TestHardwareCursor,
+            test_error::{TestErrorExt, TestResult},
+            testrun::TestRun,
+        },
+        video::LINEAR_MODIFIER,
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+testcase!();
+
+/// Test that cursor-only screencasts contain only the cursor and report its position
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.seat.cursor_group().set_hardware_cursor(true);
+    ds.connector
+        .events
+        .send_event(ConnectorEvent::HardwareCursor(Some(Rc::new(
+            TestHardwareCursor::default(),
+        ))));
+    run.state.eng.yield_now().await;
+    ds.mouse.abs(&ds.connector, 100.0, 100.0);
+    run.sync().await;
+
+    let client = run.create_client().await?;
+    let dmabuf = client.registry.get_dmabuf().await?;
+    let output = client.get_output(&ds.output)?;
+    let jay_output = client.jc.get_output(&output)?;
+
+    let Some(ctx) = run.state.render_ctx.get() else {
+        bail!("no render context");
+    };
+    // the output contents are opaque
+    let (width, height) = ds.output.global.pixel_size();
+    let data: Vec<_> = (0..width * height * 4).map(|_| Cell::new(255)).collect();
+    let tex = ctx
+        .clone()
+        .shmem_texture(None, &data, XRGB8888, width, height, width * 4, None)?
+        .into_texture();
+
+    let sc = client.jc.create_screencast()?;
+    let sizes = sc.config_size.expect()?;
+    let done = sc.config_done.expect()?;
+    let ready = sc.ready.expect()?;
+    let cursor_only = sc.config_cursor_only.expect()?;
+    let positions = sc.cursor_positions.expect()?;
+    sc.set_output(&jay_output)?;
+    sc.set_allow_all_workspaces(true)?;
+    sc.set_cursor_only(true)?;
+    sc.configure()?;
+    client.sync().await;
+    run.sync().await;
+    client.sync().await;
+    tassert!(cursor_only.last()?);
+    let size = sizes.last()?;
+
+    let bo = ctx.allocator().create_bo(
+        &run.state.dma_buf_ids,
+        size.0,
+        size.1,
+        ARGB8888,
+        &[LINEAR_MODIFIER],
+        BO_USE_RENDERING,
+    )?;
+    let params = dmabuf.create_params()?;
+    let buffer = params.create_immed(bo.dmabuf())?;
+    params.destroy()?;
+    sc.add_buffer(&buffer)?;
+    sc.set_running(true)?;
+    sc.configure()?;
+    sc.ack_config(done.last()?)?;
+    client.sync().await;
+    ds.output.perform_screencopies(
+        &tex,
+        None,
+        &AcquireSync::Unnecessary,
+        ReleaseSync::None,
+        false,
+        0,
+        0,
+        None,
+    );
+    client.sync().await;
+
+    let idx = ready.next().with_context(|| "no frame")?;
+    let position = positions.next().with_context(|| "no cursor position")?;
+    tassert_eq!(position.idx, idx);
+    if position.visible {
+        tassert_eq!((position.x, position.y), (100, 100));
+    }
+
+    // everything but the cursor is transparent
+    let map = bo.clone().map_read()?;
+    let data = unsafe { map.data() };
+    tassert_eq!(data[3], 0);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::{
+        input::{HotCorner, HotCornerEvent},
+        video::Connector,
+    },
+    std::{rc::Rc, time::Duration},
+};
+
+testcase!();
+
+/// Test that hot corners are triggered after the pointer dwells in a corner
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let seat = ds.seat.id();
+    let connector = Connector(ds.connector.id.raw() as _);
+    let (width, height) = ds.output.global.pos.get().size();
+
+    run.cfg.set_hot_corner_size(seat, 10)?;
+    run.cfg
+        .set_hot_corner_dwell(seat, Duration::from_millis(20))?;
+
+    ds.mouse.abs(&ds.connector, 100.0, 100.0);
+    run.sync().await;
+    ds.mouse.abs(&ds.connector, 1.0, 1.0);
+    run.sync().await;
+    run.state.wheel.timeout(50).await?;
+    ds.mouse.abs(&ds.connector, 5.0, 5.0);
+    run.sync().await;
+    run.state.wheel.timeout(50).await?;
+    tassert_eq!(
+        &run.cfg.hot_corners.take(),
+        &[(
+            seat,
+            HotCornerEvent {
+                connector,
+                corner: HotCorner::TopLeft,
+            }
+        )]
+    );
+
+    // leaving the corner before the dwell time has passed does not trigger it
+    ds.mouse
+        .abs(&ds.connector, (width - 1) as _, (height - 1) as _);
+    run.sync().await;
+    ds.mouse.abs(&ds.connector, 100.0, 100.0);
+    run.sync().await;
+    run.state.wheel.timeout(50).await?;
+    tassert!(run.cfg.hot_corners.take().is_empty());
+
+    ds.mouse
+        .abs(&ds.connector, (width - 1) as _, (height - 1) as _);
+    run.sync().await;
+    run.state.wheel.timeout(50).await?;
+    tassert_eq!(
+        &run.cfg.hot_corners.take(),
+        &[(
+            seat,
+            HotCornerEvent {
+                connector,
+                corner: HotCorner::BottomRight,
+            }
+        )]
+    );
+
+    // a size of 0 disables hot corners
+    run.cfg.set_hot_corner_size(seat, 0)?;
+    ds.mouse.abs(&ds.connector, 100.0, 100.0);
+    run.sync().await;
+    ds.mouse.abs(&ds.connector, 1.0, 1.0);
+    run.sync().await;
+    run.state.wheel.timeout(50).await?;
+    tassert!(run.cfg.hot_corners.take().is_empty());
+
+    Ok(())
+}
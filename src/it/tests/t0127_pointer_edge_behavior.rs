@@ -0,0 +1,33 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::input::{OutputEdge, PointerEdgeBehavior},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the pointer wraps around the layout if configured for an edge
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let rect = ds.output.global.pos.get();
+    let position = || ds.seat.pointer_cursor().position_int();
+
+    // the pointer is clamped by default
+    ds.mouse.abs(&ds.connector, (rect.width() - 5) as _, 100.0);
+    ds.mouse.rel(10.0, 0.0);
+    run.sync().await;
+    tassert_eq!(position(), (rect.x2() - 1, rect.y1() + 100));
+
+    run.cfg
+        .set_pointer_edge_behavior(OutputEdge::Right, PointerEdgeBehavior::Wrap)?;
+    ds.mouse.rel(10.0, 0.0);
+    run.sync().await;
+    tassert_eq!(position(), (rect.x1(), rect.y1() + 100));
+
+    // other edges are unaffected
+    ds.mouse.rel(-10.0, 0.0);
+    run.sync().await;
+    tassert_eq!(position(), (rect.x1(), rect.y1() + 100));
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that screenshots can be restricted to a single output
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let second = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 300,
+        refresh_rate_millihz: 60000,
+    }]);
+    let node2 = second.connect().await?;
+
+    let client = run.create_client().await?;
+    run.state.idle().await;
+
+    let name = node2.global.connector.name.clone();
+    let js = client.jc.take_output_screenshot(&name, false)?;
+    client.sync().await;
+    match js.result.take() {
+        Some(Ok(buf)) => {
+            tassert_eq!((buf.width, buf.height), (400, 300));
+        }
+        Some(Err(e)) => bail!("Compositor could not take a screenshot: {}", e),
+        None => bail!("Compositor did not send a screenshot"),
+    }
+
+    let name = ds.output.global.connector.name.clone();
+    let (width, height) = ds.output.global.pixel_size();
+    let js = client.jc.take_output_screenshot(&name, false)?;
+    client.sync().await;
+    match js.result.take() {
+        Some(Ok(buf)) => {
+            tassert_eq!((buf.width, buf.height), (width, height));
+        }
+        _ => bail!("Compositor did not send a screenshot of the first output"),
+    }
+
+    let js = client.jc.take_output_screenshot("does-not-exist", false)?;
+    client.sync().await;
+    tassert!(matches!(js.result.take(), Some(Err(_))));
+
+    Ok(())
+}
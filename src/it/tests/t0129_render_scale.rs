@@ -0,0 +1,28 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that the render scale is forwarded to the connector and validated
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let pos = ds.output.global.pos.get();
+    tassert_eq!(ds.connector.render_scale.get(), 1.0);
+
+    run.cfg.set_render_scale(&ds.output, 0.5)?;
+    tassert_eq!(ds.connector.render_scale.get(), 0.5);
+    // the render scale does not affect the logical size of the output
+    tassert_eq!(ds.output.global.pos.get(), pos);
+
+    run.cfg.set_render_scale(&ds.output, 2.0)?;
+    tassert_eq!(ds.connector.render_scale.get(), 0.5);
+    run.cfg.set_render_scale(&ds.output, 0.05)?;
+    tassert_eq!(ds.connector.render_scale.get(), 0.5);
+
+    run.cfg.set_render_scale(&ds.output, 1.0)?;
+    tassert_eq!(ds.connector.render_scale.get(), 1.0);
+
+    Ok(())
+}
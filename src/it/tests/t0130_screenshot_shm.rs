@@ -0,0 +1,43 @@
+use {
+    crate::{
+        format::XRGB8888,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that screenshots can be taken into shared memory
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    run.state.idle().await;
+
+    let js = client.jc.take_screenshot_shm(false)?;
+    let mut shm = None;
+    for _ in 0..100 {
+        client.sync().await;
+        if let Some(Err(e)) = js.result.take() {
+            bail!("Compositor could not take a screenshot: {}", e);
+        }
+        shm = js.shm.take();
+        if shm.is_some() {
+            break;
+        }
+        run.state.idle().await;
+    }
+    let Some(shm) = shm else {
+        bail!("Compositor did not send a screenshot");
+    };
+    let extents = run.state.root.extents.get();
+    tassert_eq!((shm.width, shm.height), extents.size());
+    tassert_eq!(extents, ds.output.global.pos.get());
+    tassert_eq!(shm.format, XRGB8888.drm);
+    tassert!(shm.stride >= shm.width * 4);
+    let stat = uapi::fstat(shm.fd.raw())?;
+    tassert!(stat.st_size >= (shm.stride * shm.height) as i64);
+
+    Ok(())
+}
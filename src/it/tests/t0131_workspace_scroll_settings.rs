@@ -0,0 +1,73 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that scrolling over the bar honors the configured direction, threshold and wrapping
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    let client = run.create_client().await?;
+    let mut windows = vec![];
+    for name in ["1", "2", "3"] {
+        run.cfg.show_workspace(ds.seat.id(), name)?;
+        let window = client.create_window().await?;
+        window.map2().await?;
+        windows.push(window);
+    }
+    run.cfg.show_workspace(ds.seat.id(), "1")?;
+
+    let current = || {
+        ds.output
+            .workspace
+            .get()
+            .map(|ws| ws.name.get().to_string())
+            .unwrap_or_default()
+    };
+
+    ds.mouse.abs(&ds.connector, 0.0, 0.0);
+    run.sync().await;
+
+    ds.mouse.scroll(1);
+    run.sync().await;
+    tassert_eq!(current(), "2");
+
+    run.cfg.set_workspace_scroll_inverted(true)?;
+    ds.mouse.scroll(1);
+    run.sync().await;
+    tassert_eq!(current(), "1");
+
+    // without wrapping, scrolling past the first workspace does nothing
+    ds.mouse.scroll(1);
+    run.sync().await;
+    tassert_eq!(current(), "1");
+
+    run.cfg.set_workspace_scroll_wrap(true)?;
+    ds.mouse.scroll(1);
+    run.sync().await;
+    tassert_eq!(current(), "3");
+    run.cfg.set_workspace_scroll_inverted(false)?;
+    ds.mouse.scroll(1);
+    run.sync().await;
+    tassert_eq!(current(), "1");
+
+    run.cfg.set_workspace_scroll_threshold(2)?;
+    ds.mouse.scroll(1);
+    run.sync().await;
+    tassert_eq!(current(), "1");
+    ds.mouse.scroll(1);
+    run.sync().await;
+    tassert_eq!(current(), "2");
+
+    // changing the direction discards accumulated steps
+    ds.mouse.scroll(1);
+    ds.mouse.scroll(-1);
+    run.sync().await;
+    tassert_eq!(current(), "2");
+    ds.mouse.scroll(-1);
+    run.sync().await;
+    tassert_eq!(current(), "1");
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use {
+    crate::{
+        backend::Mode,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that fixed flip margins are applied, reported, and restored on reconnect
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+    let second = run.create_second_connector(&[Mode {
+        width: 400,
+        height: 300,
+        refresh_rate_millihz: 60000,
+    }]);
+    let node2 = second.connect().await?;
+    let name = node2.global.connector.name.clone();
+
+    let client = run.create_client().await?;
+    let randr = client.jc.get_randr()?;
+
+    randr.get_output_flip_margin(&name)?;
+    client.sync().await;
+    tassert_eq!(randr.flip_margins.borrow().last().map(|m| m.fixed), Some(0));
+
+    let margin = 3_000_000;
+    randr.set_output_flip_margin(&name, margin)?;
+    client.sync().await;
+    tassert_eq!(second.connector.flip_margin.get(), Some(margin));
+    randr.get_output_flip_margin(&name)?;
+    client.sync().await;
+    tassert_eq!(randr.flip_margins.borrow().last().map(|m| m.fixed), Some(1));
+
+    // the fixed margin persists across reconnects
+    second.connector.flip_margin.set(None);
+    second.disconnect().await;
+    second.connect().await?;
+    tassert_eq!(second.connector.flip_margin.get(), Some(margin));
+
+    randr.reset_output_flip_margin(&name)?;
+    client.sync().await;
+    tassert_eq!(second.connector.flip_margin.get(), None);
+    randr.get_output_flip_margin(&name)?;
+    client.sync().await;
+    tassert_eq!(randr.flip_margins.borrow().last().map(|m| m.fixed), Some(0));
+
+    Ok(())
+}
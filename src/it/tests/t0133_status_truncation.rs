@@ -0,0 +1,46 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that a long status is truncated so that it does not overlap the workspace titles
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    for name in ["1", "2"] {
+        run.cfg.show_workspace(ds.seat.id(), name)?;
+        let window = client.create_window().await?;
+        window.map2().await?;
+    }
+
+    run.state.set_status(&"status ".repeat(1000));
+    let mut truncated = false;
+    let mut titles_end = 0;
+    for _ in 0..100 {
+        run.state.idle().await;
+        let rd = ds.output.render_data.borrow_mut();
+        if rd.titles.len() != 2 {
+            continue;
+        }
+        titles_end = rd.titles.iter().map(|t| t.x2).max().unwrap_or_default();
+        if let Some(status) = &rd.status {
+            if status.tex.texture().is_some() && status.tex_x >= titles_end {
+                truncated = true;
+                break;
+            }
+        }
+    }
+    if !truncated {
+        bail!("The status overlaps the workspace titles");
+    }
+    let tray_start = ds.output.tray_start_rel.get();
+    tassert_eq!(
+        ds.output.status_max_width.get(),
+        Some(tray_start - titles_end - 1)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use {
+    crate::{
+        ifs::wl_seat::BTN_LEFT,
+        it::{test_error::TestResult, testrun::TestRun},
+        rect::Rect,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that double-clicking a workspace title requests an inline rename
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    for name in ["1", "2"] {
+        run.cfg.show_workspace(ds.seat.id(), name)?;
+        let window = client.create_window().await?;
+        window.map2().await?;
+    }
+    let watcher = client.jc.watch_workspaces()?;
+    client.sync().await;
+    let ws1 = watcher.get("1")?;
+    let ws2 = watcher.get("2")?;
+
+    let th = run.state.theme.sizes.title_height.get();
+    let ne = ds.output.non_exclusive_rect.get();
+    let (bar_y, _) = ds.output.title_bar_y_rel();
+    let title = |name: &str| {
+        let rd = ds.output.render_data.borrow_mut();
+        rd.titles
+            .iter()
+            .find(|t| t.ws.name.get().as_str() == name)
+            .map(|t| (t.x1, t.x2))
+    };
+    let (Some((x1_1, x2_1)), Some((x1_2, x2_2))) = (title("1"), title("2")) else {
+        bail!("the workspaces have no titles");
+    };
+    let click = |x1: i32, x2: i32| {
+        ds.mouse.abs(
+            &ds.connector,
+            ((x1 + x2) / 2) as _,
+            (ne.y1() + bar_y + th / 2) as _,
+        );
+        ds.mouse.click(BTN_LEFT);
+    };
+
+    let rename1 = ws1.rename_requested.expect()?;
+    let rename2 = ws2.rename_requested.expect()?;
+
+    click(x1_1, x2_1);
+    click(x1_2, x2_2);
+    run.sync().await;
+    client.sync().await;
+    rename1.none()?;
+    rename2.none()?;
+
+    click(x1_2, x2_2);
+    run.sync().await;
+    client.sync().await;
+    rename1.none()?;
+    let (name, rect) = rename2.next()?;
+    tassert_eq!(name, "2");
+    tassert_eq!(
+        rect,
+        Rect::new_sized(ne.x1() + x1_2, ne.y1() + bar_y, x2_2 - x1_2, th).unwrap()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+    uapi::c,
+};
+
+testcase!();
+
+/// Test that SIGHUP reloads the config
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    run.raise(c::SIGHUP)?;
+    let mut reloaded = false;
+    for _ in 0..100 {
+        run.state.wheel.timeout(1).await?;
+        if run.log()?.contains("Cannot reload config") {
+            reloaded = true;
+            break;
+        }
+    }
+    tassert!(reloaded);
+    tassert!(run.log()?.contains("Reloading config"));
+
+    // Tests have no config dir, so the test config must still be in use.
+    run.cfg.show_workspace(ds.seat.id(), "reloaded")?;
+    run.sync().await;
+    let Some(ws) = ds.output.workspace.get() else {
+        bail!("output has no workspace");
+    };
+    tassert_eq!(ws.name.get().as_str(), "reloaded");
+
+    Ok(())
+}
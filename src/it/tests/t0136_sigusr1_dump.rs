@@ -0,0 +1,34 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+    uapi::c,
+};
+
+testcase!();
+
+/// Test that SIGUSR1 dumps the compositor tree to the log
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    run.cfg.show_workspace(ds.seat.id(), "dumped")?;
+    let client = run.create_client().await?;
+    let window = client.create_window().await?;
+    window.tl.core.set_title("dump-me")?;
+    window.map2().await?;
+
+    run.raise(c::SIGUSR1)?;
+    let mut log = String::new();
+    for _ in 0..100 {
+        run.state.wheel.timeout(1).await?;
+        log = run.log()?;
+        if log.contains("Dumping compositor state") {
+            break;
+        }
+    }
+    tassert!(log.contains("Dumping compositor state"));
+    tassert!(log.contains(&format!("connector = {}", ds.output.global.connector.name)));
+    tassert!(log.contains("name = \"dumped\""));
+    tassert!(log.contains("title = \"dump-me\""));
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use {
+    crate::{
+        config::{ConfigError, ConfigProxy},
+        it::{test_error::TestResult, testrun::TestRun},
+        utils::errorfmt::ErrorFmt,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that errors from loading a config library name the library
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let path = format!("{}/config.so", run.out_dir);
+    std::fs::write(&path, "not a library")?;
+    let res = unsafe { ConfigProxy::from_file(&path, &run.state) };
+    let Err(e) = res else {
+        bail!("Loading a broken config library succeeded");
+    };
+    tassert!(matches!(e, ConfigError::CouldNotLoadLibrary(..)));
+    tassert!(!e.is_missing());
+    tassert!(ErrorFmt(e).to_string().contains(&path));
+
+    let e = ConfigError::LibraryDoesNotContainEntry(path.clone(), libloading::Error::DlSymUnknown);
+    tassert!(ErrorFmt(e).to_string().contains(&path));
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that workspaces can be renamed via the jay protocol
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    for name in ["1", "2"] {
+        run.cfg.show_workspace(ds.seat.id(), name)?;
+        let window = client.create_window().await?;
+        window.map2().await?;
+    }
+    let watcher = client.jc.watch_workspaces()?;
+    client.sync().await;
+    let ws = watcher.get("1")?;
+
+    client.jc.rename_workspace("1", "renamed")?;
+    client.sync().await;
+    tassert!(client.jc.workspace_errors.borrow().is_empty());
+    tassert!(run.state.workspaces.get("1").is_none());
+    let Some(node) = run.state.workspaces.get("renamed") else {
+        bail!("the workspace was not renamed");
+    };
+    tassert_eq!(node.name.get().as_str(), "renamed");
+    tassert_eq!(ws.name.borrow().as_str(), "renamed");
+
+    client.jc.rename_workspace("unknown", "x")?;
+    client.jc.rename_workspace("renamed", "")?;
+    client.jc.rename_workspace("renamed", "2")?;
+    client.sync().await;
+    tassert_eq!(client.jc.workspace_errors.borrow().len(), 3);
+    tassert!(run.state.workspaces.get("renamed").is_some());
+    tassert!(run.state.workspaces.get("x").is_none());
+
+    Ok(())
+}
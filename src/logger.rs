@@ -1,33 +1,89 @@
 use {
     crate::utils::{errorfmt::ErrorFmt, oserror::OsError},
+    ahash::AHashMap,
     backtrace::Backtrace,
-    bstr::BString,
-    log::{Level, Log, Metadata, Record},
+    bstr::{BString, ByteSlice},
+    log::{Level, LevelFilter, Log, Metadata, Record},
     parking_lot::Mutex,
     std::{
-        cell::Cell,
+        cell::{Cell, RefCell},
+        cmp::Reverse,
+        collections::VecDeque,
         fs::DirBuilder,
         io::Write,
+        mem,
         os::unix::{ffi::OsStringExt, fs::DirBuilderExt},
         ptr,
         sync::{
-            atomic::{AtomicI32, AtomicU32, Ordering::Relaxed},
+            atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering::Relaxed},
             Arc,
         },
         time::SystemTime,
     },
+    thiserror::Error,
     uapi::{c, format_ustr, Errno, Fd, OwnedFd, Ustring},
 };
 
+#[cfg(test)]
+mod tests;
+
 thread_local! {
     static BUFFER: Cell<*mut Vec<u8>> = const { Cell::new(ptr::null_mut()) };
+    static LISTENERS_SUSPENDED: Cell<bool> = const { Cell::new(false) };
+    static MODULE_LEVEL_CACHE: RefCell<ModuleLevelCache> = Default::default();
+}
+
+/// Incremented whenever the module levels of any logger change.
+static MODULE_LEVELS_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// The levels of the modules that have been logged from on this thread.
+///
+/// This avoids locking and scanning the module levels for every record.
+#[derive(Default)]
+struct ModuleLevelCache {
+    logger: usize,
+    generation: u64,
+    levels: AHashMap<String, Option<u32>>,
+}
+
+/// The maximum number of lines buffered for a listener before further lines are dropped.
+const MAX_BUFFERED_LINES: usize = 1024;
+
+#[derive(Debug, Error)]
+pub enum LogFileError {
+    #[error("The log is not written to a file")]
+    NotAFile,
+    #[error("Could not create the log file")]
+    CreateFile(#[source] OsError),
+    #[error("Could not rename the log file")]
+    Rename(#[source] OsError),
 }
 
 pub struct Logger {
     level: AtomicU32,
+    has_module_levels: AtomicBool,
+    module_levels: Mutex<Vec<(String, u32)>>,
     path: Mutex<Arc<BString>>,
-    _file: Mutex<OwnedFd>,
-    file_fd: AtomicI32,
+    ty: Mutex<Option<String>>,
+    file: Mutex<OwnedFd>,
+    listener_level: AtomicU32,
+    listeners: Mutex<Vec<Arc<LogListener>>>,
+}
+
+/// A subscriber that receives formatted log lines.
+///
+/// Lines are buffered until the owner of the listener drains them. The eventfd is
+/// signaled whenever the buffer becomes non-empty.
+pub struct LogListener {
+    level: u32,
+    eventfd: OwnedFd,
+    buffer: Mutex<ListenerBuffer>,
+}
+
+#[derive(Default)]
+pub struct ListenerBuffer {
+    pub lines: VecDeque<(Level, String)>,
+    pub lost: u64,
 }
 
 impl Logger {
@@ -44,20 +100,30 @@ impl Logger {
 
     pub fn install_compositor(level: Level) -> Arc<Self> {
         let (path, file) = open_log_file("jay");
-        Self::install(level, path.as_bytes(), file)
+        let slf = Self::install(level, path.as_bytes(), file);
+        *slf.ty.lock() = Some("jay".to_string());
+        slf
     }
 
     pub fn install_pipe(file: OwnedFd, level: Level) -> Arc<Self> {
         Self::install(level, b"PIPE", file)
     }
 
-    fn install(level: Level, path: &[u8], file: OwnedFd) -> Arc<Self> {
-        let slf = Arc::new(Self {
+    fn new(level: Level, path: &[u8], file: OwnedFd) -> Self {
+        Self {
             level: AtomicU32::new(level as _),
+            has_module_levels: AtomicBool::new(false),
+            module_levels: Default::default(),
             path: Mutex::new(Arc::new(path.to_vec().into())),
-            file_fd: AtomicI32::new(file.raw()),
-            _file: Mutex::new(file),
-        });
+            ty: Default::default(),
+            file: Mutex::new(file),
+            listener_level: AtomicU32::new(0),
+            listeners: Default::default(),
+        }
+    }
+
+    fn install(level: Level, path: &[u8], file: OwnedFd) -> Arc<Self> {
+        let slf = Arc::new(Self::new(level, path, file));
         log::set_boxed_logger(Box::new(LogWrapper {
             logger: slf.clone(),
         }))
@@ -69,7 +135,124 @@ impl Logger {
 
     pub fn set_level(&self, level: Level) {
         self.level.store(level as _, Relaxed);
-        log::set_max_level(level.to_level_filter());
+        self.update_max_level(&self.module_levels.lock());
+    }
+
+    /// Sets the log level of a module and its submodules.
+    ///
+    /// If several prefixes match a module, the longest one is used. Modules that don't
+    /// match any prefix use the global log level.
+    pub fn set_module_level(&self, prefix: &str, level: Level) {
+        let mut levels = self.module_levels.lock();
+        levels.retain(|(p, _)| p != prefix);
+        levels.push((prefix.to_string(), level as _));
+        levels.sort_by_key(|(p, _)| Reverse(p.len()));
+        self.has_module_levels.store(true, Relaxed);
+        MODULE_LEVELS_GENERATION.fetch_add(1, Relaxed);
+        self.update_max_level(&levels);
+    }
+
+    /// Removes the log levels of all modules.
+    pub fn reset_module_levels(&self) {
+        let mut levels = self.module_levels.lock();
+        levels.clear();
+        self.has_module_levels.store(false, Relaxed);
+        MODULE_LEVELS_GENERATION.fetch_add(1, Relaxed);
+        self.update_max_level(&levels);
+    }
+
+    /// Adds a listener that receives all log lines at or above `level`.
+    ///
+    /// The eventfd is duplicated and signaled when new lines become available.
+    pub fn add_listener(
+        &self,
+        level: Level,
+        eventfd: &OwnedFd,
+    ) -> Result<Arc<LogListener>, OsError> {
+        let eventfd = uapi::fcntl_dupfd_cloexec(eventfd.raw(), 0)?;
+        let listener = Arc::new(LogListener {
+            level: level as _,
+            eventfd,
+            buffer: Default::default(),
+        });
+        let mut listeners = self.listeners.lock();
+        listeners.push(listener.clone());
+        self.update_listener_level(&listeners);
+        Ok(listener)
+    }
+
+    pub fn remove_listener(&self, listener: &Arc<LogListener>) {
+        let mut listeners = self.listeners.lock();
+        listeners.retain(|l| !Arc::ptr_eq(l, listener));
+        self.update_listener_level(&listeners);
+    }
+
+    fn update_listener_level(&self, listeners: &[Arc<LogListener>]) {
+        let level = listeners.iter().map(|l| l.level).max().unwrap_or(0);
+        self.listener_level.store(level, Relaxed);
+        self.update_max_level(&self.module_levels.lock());
+    }
+
+    fn forward(&self, level: Level, line: &[u8]) {
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+        let line = String::from_utf8_lossy(line);
+        for listener in &*self.listeners.lock() {
+            if level as u32 <= listener.level {
+                listener.push(level, &line);
+            }
+        }
+    }
+
+    fn update_max_level(&self, module_levels: &[(String, u32)]) {
+        let max = module_levels
+            .iter()
+            .map(|(_, l)| *l)
+            .fold(self.level.load(Relaxed), u32::max)
+            .max(self.listener_level.load(Relaxed));
+        let filter = LevelFilter::iter()
+            .nth(max as usize)
+            .unwrap_or(LevelFilter::Trace);
+        log::set_max_level(filter);
+    }
+
+    fn level_for(&self, module_path: Option<&str>) -> u32 {
+        if self.has_module_levels.load(Relaxed) {
+            if let Some(mp) = module_path {
+                if let Some(level) = self.cached_module_level(mp) {
+                    return level;
+                }
+            }
+        }
+        self.level.load(Relaxed)
+    }
+
+    fn cached_module_level(&self, module_path: &str) -> Option<u32> {
+        let logger = self as *const Self as usize;
+        let generation = MODULE_LEVELS_GENERATION.load(Relaxed);
+        MODULE_LEVEL_CACHE.with_borrow_mut(|cache| {
+            if (cache.logger, cache.generation) != (logger, generation) {
+                cache.logger = logger;
+                cache.generation = generation;
+                cache.levels.clear();
+            }
+            if let Some(level) = cache.levels.get(module_path) {
+                return *level;
+            }
+            let level = self.module_level(module_path);
+            cache.levels.insert(module_path.to_string(), level);
+            level
+        })
+    }
+
+    fn module_level(&self, module_path: &str) -> Option<u32> {
+        for (prefix, level) in &*self.module_levels.lock() {
+            if let Some(rest) = module_path.strip_prefix(prefix.as_str()) {
+                if rest.is_empty() || rest.starts_with("::") {
+                    return Some(*level);
+                }
+            }
+        }
+        None
     }
 
     pub fn path(&self) -> Arc<BString> {
@@ -79,18 +262,90 @@ impl Logger {
     pub fn redirect(&self, ty: &str) -> Ustring {
         let (file, fd) = open_log_file(ty);
         log::info!("Redirecting logs to {}", file.display());
-        *self.path.lock() = Arc::new(file.as_bytes().into());
-        self.file_fd.store(fd.raw(), Relaxed);
-        *self._file.lock() = fd;
+        *self.ty.lock() = Some(ty.to_string());
+        self.set_file(&file, fd);
         file
     }
 
+    /// Moves the current log file aside and continues logging to a new file at the
+    /// same path.
+    ///
+    /// The old file is renamed to include the time of the rotation. Returns the new
+    /// path of the old file.
+    pub fn rotate(&self) -> Result<Ustring, LogFileError> {
+        if self.ty.lock().is_none() {
+            return Err(LogFileError::NotAFile);
+        }
+        let path = self.path();
+        let stem = path.strip_suffix(b".txt").unwrap_or(&path);
+        let rotated = format_ustr!(
+            "{}.{}.txt",
+            stem.as_bstr(),
+            humantime::format_rfc3339_millis(SystemTime::now()),
+        );
+        let path = Ustring::from_vec(path.to_vec());
+        let mut file = self.file.lock();
+        if let Err(e) = uapi::rename(&path, &rotated) {
+            return Err(LogFileError::Rename(e.into()));
+        }
+        let fd = match uapi::open(
+            &path,
+            c::O_CREAT | c::O_EXCL | c::O_CLOEXEC | c::O_WRONLY,
+            0o644,
+        ) {
+            Ok(fd) => fd,
+            Err(e) => {
+                let _ = uapi::rename(&rotated, &path);
+                return Err(LogFileError::CreateFile(e.into()));
+            }
+        };
+        *file = fd;
+        drop(file);
+        log::info!("Rotated the previous log file to {}", rotated.display());
+        Ok(rotated)
+    }
+
+    fn set_file(&self, file: &Ustring, fd: OwnedFd) {
+        *self.path.lock() = Arc::new(file.as_bytes().into());
+        *self.file.lock() = fd;
+    }
+
     pub fn write_raw(&self, buf: &[u8]) {
-        let mut fd = Fd::new(self.file_fd.load(Relaxed));
-        let _ = fd.write_all(buf);
+        let file = self.file.lock();
+        let _ = Fd::new(file.raw()).write_all(buf);
     }
 }
 
+impl LogListener {
+    fn push(&self, level: Level, line: &str) {
+        let mut buffer = self.buffer.lock();
+        let was_empty = buffer.lines.is_empty() && buffer.lost == 0;
+        if buffer.lines.len() >= MAX_BUFFERED_LINES {
+            buffer.lost += 1;
+        } else {
+            buffer.lines.push_back((level, line.to_string()));
+        }
+        if was_empty {
+            let _ = uapi::eventfd_write(self.eventfd.raw(), 1);
+        }
+    }
+
+    /// Takes all buffered lines and the number of lines that were dropped.
+    pub fn take(&self) -> ListenerBuffer {
+        mem::take(&mut *self.buffer.lock())
+    }
+}
+
+/// Runs `f` without forwarding log lines to listeners.
+///
+/// This is used while delivering lines to listeners to avoid feedback loops.
+pub fn without_listeners<T>(f: impl FnOnce() -> T) -> T {
+    let prev = LISTENERS_SUSPENDED.replace(true);
+    let res = f();
+    LISTENERS_SUSPENDED.set(prev);
+    res
+}
+
 pub fn open_log_file(ty: &str) -> (Ustring, OwnedFd) {
     let log_dir = create_log_dir(ty);
     for i in 0.. {
@@ -166,11 +421,17 @@ struct LogWrapper {
 
 impl Log for LogWrapper {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() as u32 <= self.logger.level.load(Relaxed)
+        let level = metadata.level() as u32;
+        level <= self.logger.listener_level.load(Relaxed)
+            || level <= self.logger.level_for(Some(metadata.target()))
     }
 
     fn log(&self, record: &Record) {
-        if record.level() as u32 > self.logger.level.load(Relaxed) {
+        let level = record.level() as u32;
+        let to_file = level <= self.logger.level_for(record.module_path());
+        let to_listeners =
+            level <= self.logger.listener_level.load(Relaxed) && !LISTENERS_SUSPENDED.get();
+        if !to_file && !to_listeners {
             return;
         }
         let mut buffer = BUFFER.get();
@@ -199,8 +460,12 @@ impl Log for LogWrapper {
                 record.args(),
             )
         };
-        let mut fd = Fd::new(self.logger.file_fd.load(Relaxed));
-        let _ = fd.write_all(buffer);
+        if to_file {
+            self.logger.write_raw(buffer);
+        }
+        if to_listeners {
+            self.logger.forward(record.level(), buffer);
+        }
     }
 
     fn flush(&self) {
@@ -0,0 +1,100 @@
+use {
+    crate::logger::{LogFileError, Logger, MAX_BUFFERED_LINES},
+    log::Level,
+    std::{fs, time::SystemTime},
+    uapi::c,
+};
+
+fn temp_dir(name: &str) -> String {
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let dir =
+        std::env::temp_dir().join(format!("jay-logger-{name}-{}-{nanos}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.to_str().unwrap().to_string()
+}
+
+fn open(path: &str) -> uapi::OwnedFd {
+    uapi::open(path, c::O_CREAT | c::O_CLOEXEC | c::O_WRONLY, 0o644).unwrap()
+}
+
+#[test]
+fn rotate() {
+    let dir = temp_dir("rotate");
+    let path = format!("{dir}/jay.txt");
+    let logger = Logger::new(Level::Info, path.as_bytes(), open(&path));
+    *logger.ty.lock() = Some("jay".to_string());
+    logger.write_raw(b"old\n");
+    let rotated = logger.rotate().unwrap();
+    let rotated = rotated.as_str().unwrap();
+    assert_ne!(rotated, path);
+    assert!(rotated.starts_with(&format!("{dir}/jay.")));
+    assert!(rotated.ends_with(".txt"));
+    logger.write_raw(b"new\n");
+    assert_eq!(fs::read_to_string(rotated).unwrap(), "old\n");
+    assert_eq!(fs::read_to_string(&path).unwrap(), "new\n");
+    assert_eq!(logger.path().as_slice(), path.as_bytes());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rotate_without_file() {
+    let dir = temp_dir("rotate-without-file");
+    let path = format!("{dir}/pipe");
+    let logger = Logger::new(Level::Info, b"PIPE", open(&path));
+    assert!(matches!(logger.rotate(), Err(LogFileError::NotAFile)));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn listener_drops_lines() {
+    let dir = temp_dir("listener");
+    let path = format!("{dir}/pipe");
+    let logger = Logger::new(Level::Info, b"PIPE", open(&path));
+    let eventfd = uapi::eventfd(0, c::EFD_CLOEXEC).unwrap();
+    let listener = logger.add_listener(Level::Info, &eventfd).unwrap();
+    logger.forward(Level::Debug, b"debug\n");
+    for i in 0..MAX_BUFFERED_LINES + 3 {
+        logger.forward(Level::Info, format!("line {i}\n").as_bytes());
+    }
+    let buffer = listener.take();
+    assert_eq!(buffer.lines.len(), MAX_BUFFERED_LINES);
+    assert_eq!(buffer.lines[0], (Level::Info, "line 0".to_string()));
+    assert_eq!(buffer.lost, 3);
+    let buffer = listener.take();
+    assert!(buffer.lines.is_empty());
+    assert_eq!(buffer.lost, 0);
+    logger.remove_listener(&listener);
+    logger.forward(Level::Info, b"line\n");
+    assert!(listener.take().lines.is_empty());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn module_levels() {
+    let dir = temp_dir("module-levels");
+    let path = format!("{dir}/pipe");
+    let logger = Logger::new(Level::Info, b"PIPE", open(&path));
+    let level = |module: &str| logger.level_for(Some(module));
+    logger.set_module_level("jay", Level::Warn);
+    logger.set_module_level("jay::tree", Level::Debug);
+    logger.set_module_level("jay::tree::output", Level::Trace);
+    assert_eq!(level("jay"), Level::Warn as u32);
+    assert_eq!(level("jay::ifs"), Level::Warn as u32);
+    assert_eq!(level("jay::tree"), Level::Debug as u32);
+    assert_eq!(level("jay::tree::float"), Level::Debug as u32);
+    assert_eq!(level("jay::tree::output"), Level::Trace as u32);
+    assert_eq!(level("jay::tree::output::x"), Level::Trace as u32);
+    assert_eq!(level("jay::treeish"), Level::Warn as u32);
+    assert_eq!(level("jayish"), Level::Info as u32);
+    assert_eq!(logger.level_for(None), Level::Info as u32);
+    logger.set_module_level("jay::tree", Level::Error);
+    assert_eq!(level("jay::tree::float"), Level::Error as u32);
+    logger.reset_module_levels();
+    assert_eq!(level("jay::tree::output"), Level::Info as u32);
+    logger.set_level(Level::Debug);
+    assert_eq!(level("jay"), Level::Debug as u32);
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -25,7 +25,9 @@ pub struct OutputSchedule {
     persistent: Rc<PersistentOutputState>,
 
     last_present_nsec: Cell<u64>,
+    last_active_nsec: Cell<u64>,
     cursor_delta_nsec: Cell<Option<u64>>,
+    min_refresh_delta_nsec: Cell<Option<u64>>,
 
     ring: Rc<IoUring>,
     eng: Rc<AsyncEngine>,
@@ -59,7 +61,9 @@ impl OutputSchedule {
             hardware_cursor: Default::default(),
             persistent: persistent.clone(),
             last_present_nsec: Default::default(),
+            last_active_nsec: Default::default(),
             cursor_delta_nsec: Default::default(),
+            min_refresh_delta_nsec: Default::default(),
             iteration: Default::default(),
         };
         if let Some(hz) = persistent.vrr_cursor_hz.get() {
@@ -77,11 +81,15 @@ impl OutputSchedule {
         }
     }
 
+    fn needs_cursor_update(&self) -> bool {
+        self.needs_software_cursor_damage.get() || self.needs_hardware_cursor_commit.get()
+    }
+
     fn trigger(&self) {
         let trigger = self.vrr_enabled.get()
             && !self.present_scheduled.get()
-            && self.cursor_delta_nsec.is_some()
-            && (self.needs_software_cursor_damage.get() || self.needs_hardware_cursor_commit.get());
+            && ((self.cursor_delta_nsec.is_some() && self.needs_cursor_update())
+                || self.min_refresh_delta_nsec.is_some());
         if trigger {
             self.run.set(true);
             self.changed.trigger();
@@ -95,6 +103,31 @@ impl OutputSchedule {
         self.trigger();
     }
 
+    /// Records that the contents of the output have changed.
+    ///
+    /// This counts as activity for the purpose of [`Self::throttle`].
+    pub fn content_damaged(&self) {
+        self.last_active_nsec.set(self.eng.now().nsec());
+    }
+
+    /// Returns the time until which the next present should be delayed.
+    ///
+    /// Presents are delayed once the output has neither been active nor had its contents
+    /// damaged for `delay_nsec`. In that case, consecutive presents are spaced at least
+    /// `frame_nsec` apart.
+    pub fn throttle(&self, active: bool, delay_nsec: u64, frame_nsec: u64) -> Option<u64> {
+        let now = self.eng.now().nsec();
+        if active {
+            self.last_active_nsec.set(now);
+            return None;
+        }
+        if now.saturating_sub(self.last_active_nsec.get()) < delay_nsec {
+            return None;
+        }
+        let next_present = self.last_present_nsec.get().saturating_add(frame_nsec);
+        (next_present > now).then_some(next_present)
+    }
+
     pub fn vrr_enabled(&self) -> bool {
         self.vrr_enabled.get()
     }
@@ -117,6 +150,16 @@ impl OutputSchedule {
         self.trigger();
     }
 
+    /// Sets the minimum refresh rate while VRR is enabled.
+    ///
+    /// If no frame has been presented for the corresponding duration, the output is
+    /// damaged to force a present.
+    pub fn set_min_refresh_hz(&self, hz: Option<u32>) {
+        let delta = hz.filter(|&hz| hz > 0).map(|hz| 1_000_000_000 / hz as u64);
+        self.min_refresh_delta_nsec.set(delta);
+        self.trigger();
+    }
+
     pub fn set_hardware_cursor(&self, hc: &Option<Rc<dyn HardwareCursor>>) {
         self.hardware_cursor.set(hc.clone());
     }
@@ -141,15 +184,18 @@ impl OutputSchedule {
         if self.present_scheduled.get() {
             return;
         }
-        if !self.needs_hardware_cursor_commit.get() && !self.needs_software_cursor_damage.get() {
-            return;
-        }
         loop {
             if !self.vrr_enabled.get() {
                 return;
             }
-            let Some(duration) = self.cursor_delta_nsec.get() else {
-                return;
+            let cursor_delta_nsec = match self.needs_cursor_update() {
+                true => self.cursor_delta_nsec.get(),
+                false => None,
+            };
+            let duration = match (cursor_delta_nsec, self.min_refresh_delta_nsec.get()) {
+                (Some(a), Some(b)) => a.min(b),
+                (Some(a), None) | (None, Some(a)) => a,
+                (None, None) => return,
             };
             let iteration = self.iteration.get();
             let next_present = self.last_present_nsec.get().saturating_add(duration);
@@ -175,6 +221,10 @@ impl OutputSchedule {
             self.connector.damage();
             self.present_scheduled.set(true);
         }
+        if !self.present_scheduled.get() && self.min_refresh_delta_nsec.is_some() {
+            self.connector.damage();
+            self.present_scheduled.set(true);
+        }
     }
 }
 
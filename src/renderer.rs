@@ -3,7 +3,7 @@ use {
         gfx_api::{AcquireSync, GfxApiOpt, ReleaseSync, SampleRect},
         ifs::wl_surface::{
             x_surface::xwindow::Xwindow,
-            xdg_surface::{xdg_toplevel::XdgToplevel, XdgSurface},
+            xdg_surface::{xdg_popup::XdgPopup, xdg_toplevel::XdgToplevel, XdgSurface},
             zwlr_layer_surface_v1::ZwlrLayerSurfaceV1,
             SurfaceBuffer, WlSurface,
         },
@@ -13,8 +13,8 @@ use {
         state::State,
         theme::Color,
         tree::{
-            ContainerNode, DisplayNode, FloatNode, OutputNode, PlaceholderNode, ToplevelData,
-            ToplevelNodeBase, WorkspaceNode,
+            window_border_inset, window_border_rects, ContainerNode, DisplayNode, FloatNode,
+            OutputNode, PlaceholderNode, ToplevelData, ToplevelNodeBase, WorkspaceNode,
         },
     },
     std::{ops::Deref, rc::Rc, slice},
@@ -25,6 +25,10 @@ pub mod renderer_base;
 pub struct Renderer<'a> {
     pub base: RendererBase<'a>,
     pub state: &'a State,
+    /// Whether the result is shown to a screen capture client.
+    pub for_capture: bool,
+    /// The color that the framebuffer is cleared to before rendering.
+    pub clear_color: Color,
     pub logical_extents: Rect,
     pub pixel_extents: Rect,
 }
@@ -80,15 +84,22 @@ impl Renderer<'_> {
         if let Some(fs) = fullscreen {
             fs.tl_as_node().node_render(self, x, y, None);
         } else {
+            if let Some(c) = output.global.persistent.background.get() {
+                if c != self.clear_color {
+                    let rect = opos.at_point(0, 0);
+                    self.base.fill_boxes2(slice::from_ref(&rect), &c, x, y);
+                }
+            }
             render_layer!(output.layers[0]);
             render_layer!(output.layers[1]);
             let non_exclusive_rect = output.non_exclusive_rect_rel.get();
             let (x, y) = non_exclusive_rect.translate_inv(x, y);
             {
+                let (bar_y, _) = output.title_bar_y_rel();
                 let c = theme.colors.bar_background.get();
                 self.base.fill_boxes2(
                     slice::from_ref(
-                        &Rect::new_sized(0, 0, non_exclusive_rect.width(), th).unwrap(),
+                        &Rect::new_sized(0, bar_y, non_exclusive_rect.width(), th).unwrap(),
                     ),
                     &c,
                     x,
@@ -102,7 +113,10 @@ impl Renderer<'_> {
                     };
                     self.base.fill_boxes2(slice::from_ref(&aw.rect), &c, x, y);
                 }
-                let c = theme.colors.separator.get();
+                let c = match output.focus_indicator.get() {
+                    true => theme.colors.focused_output_indicator.get(),
+                    false => theme.colors.separator.get(),
+                };
                 self.base
                     .fill_boxes2(slice::from_ref(&rd.underline), &c, x, y);
                 let c = theme.colors.unfocused_title_background.get();
@@ -132,7 +146,7 @@ impl Renderer<'_> {
                 }
                 if let Some(status) = &rd.status {
                     if let Some(texture) = status.tex.texture() {
-                        let (x, y) = self.base.scale_point(x + status.tex_x, y);
+                        let (x, y) = self.base.scale_point(x + status.tex_x, y + status.tex_y);
                         self.base.render_texture(
                             &texture,
                             None,
@@ -158,7 +172,7 @@ impl Renderer<'_> {
                 }
             }
             if let Some(ws) = output.workspace.get() {
-                self.render_workspace(&ws, x, y + th + 1);
+                self.render_workspace(&ws, x, y + output.workspace_y_rel());
             }
         }
         macro_rules! render_stacked {
@@ -176,13 +190,15 @@ impl Renderer<'_> {
             };
         }
         render_stacked!(self.state.root.stacked);
-        render_layer!(output.layers[2]);
-        render_layer!(output.layers[3]);
+        if !output.game_mode_active.get() {
+            render_layer!(output.layers[2]);
+            render_layer!(output.layers[3]);
+        }
         render_stacked!(self.state.root.stacked_above_layers);
         if let Some(ws) = output.workspace.get() {
             if ws.render_highlight.get() > 0 {
                 let color = self.state.theme.colors.highlight.get();
-                let bounds = ws.position.get().at_point(x, y + th + 1);
+                let bounds = ws.position.get().at_point(x, y + output.workspace_y_rel());
                 self.base.fill_boxes(&[bounds], &color);
             }
         }
@@ -242,6 +258,9 @@ impl Renderer<'_> {
             self.base.fill_boxes2(&rd.underline_rects, &c, x, y);
             let c = self.state.theme.colors.border.get();
             self.base.fill_boxes2(&rd.border_rects, &c, x, y);
+            for (rects, c) in &rd.window_borders {
+                self.base.fill_boxes2(rects, c, x, y);
+            }
             if let Some(lar) = &rd.last_active_rect {
                 let c = self
                     .state
@@ -272,6 +291,7 @@ impl Renderer<'_> {
         }
         if let Some(child) = container.mono_child.get() {
             let body = container.mono_body.get().move_(x, y);
+            let body = window_border_inset(&body, child.node.tl_window_border_width());
             let body = self.base.scale_rect(body);
             let content = container.mono_content.get();
             child
@@ -284,6 +304,7 @@ impl Renderer<'_> {
                     break;
                 }
                 let body = body.move_(x, y);
+                let body = window_border_inset(&body, child.node.tl_window_border_width());
                 let body = self.base.scale_rect(body);
                 let content = child.content.get();
                 child
@@ -295,15 +316,59 @@ impl Renderer<'_> {
     }
 
     pub fn render_xwindow(&mut self, tl: &Xwindow, x: i32, y: i32, bounds: Option<&Rect>) {
-        self.render_surface(&tl.x.surface, x, y, bounds);
+        if !self.render_capture_blocked(tl.tl_data(), x, y, bounds) {
+            self.render_surface(&tl.x.surface, x, y, bounds);
+        }
         self.render_tl_aux(tl.tl_data(), bounds, true);
     }
 
     pub fn render_xdg_toplevel(&mut self, tl: &XdgToplevel, x: i32, y: i32, bounds: Option<&Rect>) {
-        self.render_xdg_surface(&tl.xdg, x, y, bounds);
+        if !self.render_capture_blocked(tl.tl_data(), x, y, bounds) {
+            self.render_xdg_surface(&tl.xdg, x, y, bounds);
+        }
         self.render_tl_aux(tl.tl_data(), bounds, true);
     }
 
+    /// Renders a black rectangle instead of the toplevel if the toplevel blocks captures
+    /// and this is a capture.
+    fn render_capture_blocked(
+        &mut self,
+        tl_data: &ToplevelData,
+        x: i32,
+        y: i32,
+        bounds: Option<&Rect>,
+    ) -> bool {
+        if !self.for_capture || !tl_data.capture_blocked() {
+            return false;
+        }
+        let rect = tl_data.pos.get().at_point(x, y);
+        self.render_blocked_rect(rect, bounds);
+        true
+    }
+
+    fn render_blocked_rect(&mut self, rect: Rect, bounds: Option<&Rect>) {
+        let mut rect = self.base.scale_rect(rect);
+        if let Some(bounds) = bounds {
+            rect = rect.intersect(*bounds);
+        }
+        self.base
+            .fill_scaled_boxes(slice::from_ref(&rect), &Color::SOLID_BLACK);
+    }
+
+    pub fn render_xdg_popup(&mut self, popup: &XdgPopup, x: i32, y: i32, bounds: Option<&Rect>) {
+        let xdg = &popup.xdg;
+        if self.for_capture && xdg.capture_blocked() {
+            let (mut x, mut y) = (x, y);
+            if let Some(geo) = xdg.geometry() {
+                (x, y) = geo.translate(x, y);
+            }
+            let rect = xdg.surface.extents.get().move_(x, y);
+            self.render_blocked_rect(rect, bounds);
+            return;
+        }
+        self.render_xdg_surface(xdg, x, y, bounds)
+    }
+
     pub fn render_xdg_surface(
         &mut self,
         xdg: &XdgSurface,
@@ -347,6 +412,30 @@ impl Renderer<'_> {
         self.base.fill_boxes(slice::from_ref(rect), &color);
     }
 
+    pub fn render_pointer_locator(&mut self, x: i32, y: i32, progress: f64) {
+        const WIDTH: i32 = 2;
+        const GAP: i32 = 8;
+        let size = self.state.theme.sizes.pointer_locator_size.get();
+        let remaining = 1.0 - progress;
+        let color = self.state.theme.colors.pointer_locator.get() * remaining as f32;
+        let r = ((size as f64 * remaining).round() as i32).max(WIDTH);
+        let (x1, y1) = (x - WIDTH / 2, y - WIDTH / 2);
+        let (x2, y2) = (x1 + WIDTH, y1 + WIDTH);
+        let boxes = [
+            Rect::new(x - size, y1, x - GAP, y2),
+            Rect::new(x + GAP, y1, x + size, y2),
+            Rect::new(x1, y - size, x2, y - GAP),
+            Rect::new(x1, y + GAP, x2, y + size),
+            Rect::new(x - r, y - r, x + r, y - r + WIDTH),
+            Rect::new(x - r, y + r - WIDTH, x + r, y + r),
+            Rect::new(x - r, y - r + WIDTH, x - r + WIDTH, y + r - WIDTH),
+            Rect::new(x + r - WIDTH, y - r + WIDTH, x + r, y + r - WIDTH),
+        ];
+        let boxes: Vec<_> = boxes.into_iter().flatten().collect();
+        self.base.ops.push(GfxApiOpt::Sync);
+        self.base.fill_boxes(&boxes, &color);
+    }
+
     pub fn render_surface(&mut self, surface: &WlSurface, x: i32, y: i32, bounds: Option<&Rect>) {
         let (x, y) = self.base.scale_point(x, y);
         self.render_surface_scaled(surface, x, y, None, bounds, false);
@@ -461,7 +550,7 @@ impl Renderer<'_> {
         };
         let pos = floating.position.get();
         let theme = &self.state.theme;
-        let th = theme.sizes.title_height.get();
+        let th = child.tl_data().float_title_height();
         let bw = theme.sizes.border_width.get();
         let bc = theme.colors.border.get();
         let tc = if floating.active.get() {
@@ -479,36 +568,46 @@ impl Renderer<'_> {
             Rect::new_sized(x + bw, y + pos.height() - bw, pos.width() - 2 * bw, bw).unwrap(),
         ];
         self.base.fill_boxes(&borders, &bc);
-        let title = [Rect::new_sized(x + bw, y + bw, pos.width() - 2 * bw, th).unwrap()];
-        self.base.fill_boxes(&title, &tc);
-        let title_underline =
-            [Rect::new_sized(x + bw, y + bw + th, pos.width() - 2 * bw, 1).unwrap()];
-        self.base.fill_boxes(&title_underline, &uc);
-        if let Some(title) = floating.title_textures.borrow().get(&self.base.scale) {
-            if let Some(texture) = title.texture() {
-                let (x, y) = self.base.scale_point(x + bw, y + bw);
-                self.base.render_texture(
-                    &texture,
-                    None,
-                    x,
-                    y,
-                    None,
-                    None,
-                    self.base.scale,
-                    None,
-                    None,
-                    AcquireSync::None,
-                    ReleaseSync::None,
-                );
+        if th > 0 {
+            let title = [Rect::new_sized(x + bw, y + bw, pos.width() - 2 * bw, th - 1).unwrap()];
+            self.base.fill_boxes(&title, &tc);
+            let title_underline =
+                [Rect::new_sized(x + bw, y + bw + th - 1, pos.width() - 2 * bw, 1).unwrap()];
+            self.base.fill_boxes(&title_underline, &uc);
+            if let Some(title) = floating.title_textures.borrow().get(&self.base.scale) {
+                if let Some(texture) = title.texture() {
+                    let (x, y) = self.base.scale_point(x + bw, y + bw);
+                    self.base.render_texture(
+                        &texture,
+                        None,
+                        x,
+                        y,
+                        None,
+                        None,
+                        self.base.scale,
+                        None,
+                        None,
+                        AcquireSync::None,
+                        ReleaseSync::None,
+                    );
+                }
             }
         }
-        let body = Rect::new_sized(
+        let mut body = Rect::new_sized(
             x + bw,
-            y + bw + th + 1,
+            y + bw + th,
             pos.width() - 2 * bw,
-            pos.height() - 2 * bw - th - 1,
+            pos.height() - 2 * bw - th,
         )
         .unwrap();
+        let wbw = child.tl_window_border_width();
+        if wbw > 0 {
+            let c = child
+                .tl_data()
+                .window_border_color(floating.active.get(), floating.attention_requested.get());
+            self.base.fill_boxes(&window_border_rects(&body, wbw), &c);
+            body = window_border_inset(&body, wbw);
+        }
         let scissor_body = self.base.scale_rect(body);
         child.node_render(self, body.x1(), body.y1(), Some(&scissor_body));
     }
@@ -188,6 +188,7 @@ impl RendererBase<'_> {
             buffer_resv,
             acquire_sync,
             release_sync,
+            color_matrix: None,
         }));
     }
 }
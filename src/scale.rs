@@ -1,4 +1,7 @@
-use std::fmt::{Debug, Display, Formatter};
+use {
+    crate::utils::clonecell::UnsafeCellCloneSafe,
+    std::fmt::{Debug, Display, Formatter},
+};
 
 const BASE: u32 = 120;
 const BASE64: i64 = BASE as i64;
@@ -8,6 +11,8 @@ const BASEF: f64 = BASE as f64;
 #[repr(transparent)]
 pub struct Scale(u32);
 
+unsafe impl UnsafeCellCloneSafe for Scale {}
+
 impl Default for Scale {
     fn default() -> Self {
         Scale::from_int(1)
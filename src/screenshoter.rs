@@ -1,17 +1,25 @@
 use {
     crate::{
         allocator::{AllocatorError, BufferObject, BufferUsage, BO_USE_RENDERING},
-        format::XRGB8888,
-        gfx_api::{needs_render_usage, AcquireSync, GfxError, ReleaseSync},
+        clientmem::{ClientMem, ClientMemError, ClientMemOffset},
+        cursor::Cursor,
+        format::{Format, ARGB8888, XRGB8888},
+        gfx_api::{
+            needs_render_usage, AcquireSync, AsyncShmGfxTextureCallback, GfxError, GfxFramebuffer,
+            PendingShmTransfer, ReleaseSync, STAGING_DOWNLOAD,
+        },
+        rect::{Rect, Region},
         scale::Scale,
         state::State,
+        tree::{Node, OutputNode},
+        utils::oserror::OsError,
         video::drm::DrmError,
     },
     indexmap::IndexMap,
     jay_config::video::Transform,
     std::{ops::Deref, rc::Rc},
     thiserror::Error,
-    uapi::OwnedFd,
+    uapi::{c, OwnedFd},
 };
 
 #[derive(Debug, Error)]
@@ -26,10 +34,18 @@ pub enum ScreenshooterError {
     RenderError(#[from] GfxError),
     #[error(transparent)]
     DrmError(#[from] DrmError),
-    #[error("Render context does not support XRGB8888")]
-    XRGB8888,
-    #[error("Render context supports no modifiers for XRGB8888 rendering")]
-    Modifiers,
+    #[error("Render context does not support {0}")]
+    Format(&'static str),
+    #[error("Render context supports no modifiers for {0} rendering")]
+    Modifiers(&'static str),
+    #[error("The cursor is empty")]
+    EmptyCursor,
+    #[error("Could not create a memfd")]
+    CreateMemfd(#[source] OsError),
+    #[error("Could not resize the memfd")]
+    ResizeMemfd(#[source] OsError),
+    #[error("Could not map the memfd")]
+    MapMemfd(#[source] ClientMemError),
 }
 
 pub struct Screenshot {
@@ -37,21 +53,72 @@ pub struct Screenshot {
     pub bo: Rc<dyn BufferObject>,
 }
 
+pub struct ShmScreenshot {
+    pub fd: Rc<OwnedFd>,
+    pub width: i32,
+    pub height: i32,
+    pub stride: i32,
+    pub format: &'static Format,
+    pub pending: Option<PendingShmTransfer>,
+}
+
 pub fn take_screenshot(
     state: &State,
     include_cursor: bool,
 ) -> Result<Screenshot, ScreenshooterError> {
-    let ctx = match state.render_ctx.get() {
-        Some(ctx) => ctx,
-        _ => return Err(ScreenshooterError::NoRenderContext),
-    };
     let extents = state.root.extents.get();
     if extents.is_empty() {
         return Err(ScreenshooterError::EmptyDisplay);
     }
+    render_screenshot(
+        state,
+        state.root.deref(),
+        extents,
+        Scale::from_int(1),
+        extents.width(),
+        extents.height(),
+        include_cursor,
+    )
+}
+
+fn render_screenshot(
+    state: &State,
+    node: &dyn Node,
+    cursor_rect: Rect,
+    scale: Scale,
+    width: i32,
+    height: i32,
+    include_cursor: bool,
+) -> Result<Screenshot, ScreenshooterError> {
+    let (bo, fb) = create_dmabuf_fb(state, width, height, XRGB8888)?;
+    fb.render_node(
+        AcquireSync::Unnecessary,
+        ReleaseSync::Implicit,
+        node,
+        state,
+        Some(cursor_rect),
+        scale,
+        include_cursor,
+        true,
+        false,
+        Transform::None,
+    )?;
+    finish_dmabuf_screenshot(state, bo)
+}
+
+fn create_dmabuf_fb(
+    state: &State,
+    width: i32,
+    height: i32,
+    format: &'static Format,
+) -> Result<(Rc<dyn BufferObject>, Rc<dyn GfxFramebuffer>), ScreenshooterError> {
+    let ctx = match state.render_ctx.get() {
+        Some(ctx) => ctx,
+        _ => return Err(ScreenshooterError::NoRenderContext),
+    };
     let formats = ctx.formats();
-    let modifiers: IndexMap<_, _> = match formats.get(&XRGB8888.drm) {
-        None => return Err(ScreenshooterError::XRGB8888),
+    let modifiers: IndexMap<_, _> = match formats.get(&format.drm) {
+        None => return Err(ScreenshooterError::Format(format.name)),
         Some(f) => f
             .write_modifiers
             .iter()
@@ -59,7 +126,7 @@ pub fn take_screenshot(
             .collect(),
     };
     if modifiers.is_empty() {
-        return Err(ScreenshooterError::Modifiers);
+        return Err(ScreenshooterError::Modifiers(format.name));
     }
     let mut usage = BO_USE_RENDERING;
     if !needs_render_usage(modifiers.values().copied()) {
@@ -67,30 +134,182 @@ pub fn take_screenshot(
     }
     let modifiers: Vec<_> = modifiers.keys().copied().copied().collect();
     let allocator = ctx.allocator();
-    let bo = allocator.create_bo(
-        &state.dma_buf_ids,
-        extents.width(),
-        extents.height(),
-        XRGB8888,
-        &modifiers,
-        usage,
-    )?;
+    let bo = allocator.create_bo(&state.dma_buf_ids, width, height, format, &modifiers, usage)?;
     let fb = ctx.clone().dmabuf_fb(bo.dmabuf())?;
-    fb.render_node(
+    Ok((bo, fb))
+}
+
+fn finish_dmabuf_screenshot(
+    state: &State,
+    bo: Rc<dyn BufferObject>,
+) -> Result<Screenshot, ScreenshooterError> {
+    let Some(ctx) = state.render_ctx.get() else {
+        return Err(ScreenshooterError::NoRenderContext);
+    };
+    let drm = match ctx.allocator().drm() {
+        Some(drm) => Some(drm.dup_render()?.fd().clone()),
+        _ => None,
+    };
+    Ok(Screenshot { drm, bo })
+}
+
+pub fn take_screenshot_of_output(
+    state: &State,
+    output: &OutputNode,
+    include_cursor: bool,
+) -> Result<Screenshot, ScreenshooterError> {
+    let (width, height) = output.global.pixel_size();
+    if width <= 0 || height <= 0 {
+        return Err(ScreenshooterError::EmptyDisplay);
+    }
+    render_screenshot(
+        state,
+        output,
+        output.global.pos.get(),
+        output.global.persistent.scale.get(),
+        width,
+        height,
+        include_cursor,
+    )
+}
+
+/// Renders the whole compositor into a memfd.
+///
+/// The download might complete asynchronously. In that case `pending` is set and `callback`
+/// is invoked once the memfd contains the image.
+pub fn take_screenshot_shm(
+    state: &State,
+    include_cursor: bool,
+    callback: Rc<dyn AsyncShmGfxTextureCallback>,
+) -> Result<ShmScreenshot, ScreenshooterError> {
+    let ctx = match state.render_ctx.get() {
+        Some(ctx) => ctx,
+        _ => return Err(ScreenshooterError::NoRenderContext),
+    };
+    let extents = state.root.extents.get();
+    if extents.is_empty() {
+        return Err(ScreenshooterError::EmptyDisplay);
+    }
+    let (width, height) = (extents.width(), extents.height());
+    let format = XRGB8888;
+    let stride = width * 4;
+    let (fd, mem) = create_memfd(state, stride, height)?;
+    let fb = ctx
+        .clone()
+        .create_internal_fb(&state.cpu_worker, width, height, stride, format)?;
+    fb.clone().into_fb().render_node(
         AcquireSync::Unnecessary,
-        ReleaseSync::Implicit,
+        ReleaseSync::None,
         state.root.deref(),
         state,
-        Some(state.root.extents.get()),
+        Some(extents),
         Scale::from_int(1),
         include_cursor,
         true,
         false,
         Transform::None,
     )?;
-    let drm = match allocator.drm() {
-        Some(drm) => Some(drm.dup_render()?.fd().clone()),
-        _ => None,
+    let staging = ctx.create_staging_buffer(fb.staging_size(), STAGING_DOWNLOAD);
+    let pending = fb.download(
+        &staging,
+        callback,
+        mem,
+        Region::new2(extents.at_point(0, 0)),
+    )?;
+    Ok(ShmScreenshot {
+        fd,
+        width,
+        height,
+        stride,
+        format,
+        pending,
+    })
+}
+
+fn create_memfd(
+    state: &State,
+    stride: i32,
+    height: i32,
+) -> Result<(Rc<OwnedFd>, Rc<ClientMemOffset>), ScreenshooterError> {
+    let len = stride as usize * height as usize;
+    let fd = uapi::memfd_create("screenshot", c::MFD_CLOEXEC | c::MFD_ALLOW_SEALING)
+        .map_err(|e| ScreenshooterError::CreateMemfd(e.into()))?;
+    uapi::ftruncate(fd.raw(), len as _).map_err(|e| ScreenshooterError::ResizeMemfd(e.into()))?;
+    let _ = uapi::fcntl_add_seals(fd.raw(), c::F_SEAL_SEAL | c::F_SEAL_GROW | c::F_SEAL_SHRINK);
+    let fd = Rc::new(fd);
+    let mem = ClientMem::new(&fd, len, false, None, Some(&state.cpu_worker))
+        .map_err(ScreenshooterError::MapMemfd)?;
+    Ok((fd, Rc::new(Rc::new(mem).offset(0))))
+}
+
+/// Renders the image of a cursor at the given scale into a dmabuf.
+pub fn take_cursor_image(
+    state: &State,
+    cursor: &dyn Cursor,
+    scale: Scale,
+) -> Result<Screenshot, ScreenshooterError> {
+    let extents = cursor.extents_at_scale(scale);
+    if extents.is_empty() {
+        return Err(ScreenshooterError::EmptyCursor);
+    }
+    let (bo, fb) = create_dmabuf_fb(state, extents.width(), extents.height(), ARGB8888)?;
+    fb.render_hardware_cursor(
+        AcquireSync::Unnecessary,
+        ReleaseSync::Implicit,
+        cursor,
+        state,
+        scale,
+        Transform::None,
+    )?;
+    finish_dmabuf_screenshot(state, bo)
+}
+
+/// Renders the image of a cursor at the given scale into a memfd.
+///
+/// The download might complete asynchronously. In that case `pending` is set and `callback`
+/// is invoked once the memfd contains the image.
+pub fn take_cursor_image_shm(
+    state: &State,
+    cursor: &dyn Cursor,
+    scale: Scale,
+    callback: Rc<dyn AsyncShmGfxTextureCallback>,
+) -> Result<ShmScreenshot, ScreenshooterError> {
+    let ctx = match state.render_ctx.get() {
+        Some(ctx) => ctx,
+        _ => return Err(ScreenshooterError::NoRenderContext),
     };
-    Ok(Screenshot { drm, bo })
+    let extents = cursor.extents_at_scale(scale);
+    if extents.is_empty() {
+        return Err(ScreenshooterError::EmptyCursor);
+    }
+    let (width, height) = (extents.width(), extents.height());
+    let format = ARGB8888;
+    let stride = width * 4;
+    let (fd, mem) = create_memfd(state, stride, height)?;
+    let fb = ctx
+        .clone()
+        .create_internal_fb(&state.cpu_worker, width, height, stride, format)?;
+    fb.clone().into_fb().render_hardware_cursor(
+        AcquireSync::Unnecessary,
+        ReleaseSync::None,
+        cursor,
+        state,
+        scale,
+        Transform::None,
+    )?;
+    let staging = ctx.create_staging_buffer(fb.staging_size(), STAGING_DOWNLOAD);
+    let pending = fb.download(
+        &staging,
+        callback,
+        mem,
+        Region::new2(Rect::new_sized_unchecked(0, 0, width, height)),
+    )?;
+    Ok(ShmScreenshot {
+        fd,
+        width,
+        height,
+        stride,
+        format,
+        pending,
+    })
 }
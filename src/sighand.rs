@@ -2,7 +2,7 @@ use {
     crate::{
         async_engine::{AsyncEngine, SpawnedFuture},
         io_uring::IoUring,
-        utils::{buf::TypedBuf, errorfmt::ErrorFmt, oserror::OsError},
+        utils::{asyncevent::AsyncEvent, buf::TypedBuf, errorfmt::ErrorFmt, oserror::OsError},
     },
     std::rc::Rc,
     thiserror::Error,
@@ -20,10 +20,14 @@ pub enum SighandError {
 pub fn install(
     eng: &Rc<AsyncEngine>,
     ring: &Rc<IoUring>,
+    reload_config: &Rc<AsyncEvent>,
+    dump_state: &Rc<AsyncEvent>,
 ) -> Result<SpawnedFuture<()>, SighandError> {
     let mut set: c::sigset_t = uapi::pod_zeroed();
     uapi::sigaddset(&mut set, c::SIGINT).unwrap();
     uapi::sigaddset(&mut set, c::SIGTERM).unwrap();
+    uapi::sigaddset(&mut set, c::SIGHUP).unwrap();
+    uapi::sigaddset(&mut set, c::SIGUSR1).unwrap();
     uapi::sigaddset(&mut set, c::SIGPIPE).unwrap();
     if let Err(e) = uapi::pthread_sigmask(c::SIG_BLOCK, Some(&set), None) {
         return Err(SighandError::BlockFailed(e.into()));
@@ -32,10 +36,18 @@ pub fn install(
         Ok(fd) => Rc::new(fd),
         Err(e) => return Err(SighandError::CreateFailed(e.into())),
     };
-    Ok(eng.spawn("signal handler", handle_signals(fd, ring.clone())))
+    Ok(eng.spawn(
+        "signal handler",
+        handle_signals(fd, ring.clone(), reload_config.clone(), dump_state.clone()),
+    ))
 }
 
-async fn handle_signals(fd: Rc<OwnedFd>, ring: Rc<IoUring>) {
+async fn handle_signals(
+    fd: Rc<OwnedFd>,
+    ring: Rc<IoUring>,
+    reload_config: Rc<AsyncEvent>,
+    dump_state: Rc<AsyncEvent>,
+) {
     let mut buf = TypedBuf::<c::signalfd_siginfo>::new();
     loop {
         if let Err(e) = ring.read(&fd, buf.buf()).await {
@@ -44,9 +56,14 @@ async fn handle_signals(fd: Rc<OwnedFd>, ring: Rc<IoUring>) {
         }
         let sig = buf.t().ssi_signo as i32;
         log::info!("Received signal {}", sig);
-        if matches!(sig, c::SIGINT | c::SIGTERM) {
-            log::info!("Exiting");
-            ring.stop();
+        match sig {
+            c::SIGINT | c::SIGTERM => {
+                log::info!("Exiting");
+                ring.stop();
+            }
+            c::SIGHUP => reload_config.trigger(),
+            c::SIGUSR1 => dump_state.trigger(),
+            _ => {}
         }
     }
 }
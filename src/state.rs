@@ -9,12 +9,14 @@ use {
         },
         backends::dummy::DummyBackend,
         cli::RunArgs,
-        client::{Client, ClientId, Clients, SerialRange, NUM_CACHED_SERIAL_RANGES},
+        client::{
+            Client, ClientErrorLog, ClientId, Clients, SerialRange, NUM_CACHED_SERIAL_RANGES,
+        },
         clientmem::ClientMemOffset,
         compositor::LIBEI_SOCKET,
-        config::ConfigProxy,
+        config::{ConfigError, ConfigProxy},
         cpu_worker::CpuWorker,
-        cursor::{Cursor, ServerCursors},
+        cursor::{cursor_theme_exists, Cursor, ServerCursors},
         cursor_user::{CursorUserGroup, CursorUserGroupId, CursorUserGroupIds, CursorUserIds},
         damage::DamageVisualizer,
         dbus::Dbus,
@@ -27,7 +29,7 @@ use {
         forker::ForkerProxy,
         format::Format,
         gfx_api::{
-            AcquireSync, BufferResv, GfxContext, GfxError, GfxFramebuffer, GfxTexture,
+            AcquireSync, BufferResv, ColorMatrix, GfxContext, GfxError, GfxFramebuffer, GfxTexture,
             PendingShmTransfer, ReleaseSync, SampleRect, SyncFile, STAGING_DOWNLOAD,
         },
         gfx_apis::create_gfx_context,
@@ -39,6 +41,8 @@ use {
                 data_control::DataControlDeviceIds, x_data_device::XIpcDeviceIds, DataOfferIds,
                 DataSourceIds,
             },
+            jay_clipboard::JayClipboard,
+            jay_output_watcher::JayOutputWatcher,
             jay_render_ctx::JayRenderCtx,
             jay_screencast::JayScreencast,
             jay_seat_events::JaySeatEvents,
@@ -73,9 +77,10 @@ use {
         theme::{Color, Theme},
         time::Time,
         tree::{
-            ContainerNode, ContainerSplit, Direction, DisplayNode, FloatNode, LatchListener, Node,
-            NodeIds, NodeVisitorBase, OutputNode, PlaceholderNode, TearingMode, ToplevelNode,
-            ToplevelNodeBase, VrrMode, WorkspaceNode,
+            ContainerNode, ContainerSplit, DecorationMode, Direction, DisplayNode, FloatNode,
+            LatchListener, Node, NodeIds, NodeVisitorBase, OutputNode, PlaceholderNode,
+            TearingMode, ToplevelNode, ToplevelNodeBase, VrrMode, WindowBorder, WorkspaceNode,
+            WorkspaceOutputAffinity,
         },
         utils::{
             activation_token::ActivationToken, asyncevent::AsyncEvent, bindings::Bindings,
@@ -94,8 +99,8 @@ use {
         },
         wheel::Wheel,
         wire::{
-            ExtForeignToplevelListV1Id, JayRenderCtxId, JaySeatEventsId, JayWorkspaceWatcherId,
-            ZwpLinuxDmabufFeedbackV1Id,
+            ExtForeignToplevelListV1Id, JayOutputWatcherId, JayRenderCtxId, JaySeatEventsId,
+            JayWorkspaceWatcherId, ZwpLinuxDmabufFeedbackV1Id,
         },
         xkbcommon::{KeyboardStateIds, XkbContext, XkbKeymap, XkbState},
         xwayland::{self, XWaylandEvent},
@@ -103,6 +108,7 @@ use {
     ahash::{AHashMap, AHashSet},
     bstr::ByteSlice,
     jay_config::{
+        input::{OutputEdge, PointerEdgeBehavior},
         video::{GfxApi, Transform},
         PciId,
     },
@@ -118,6 +124,9 @@ use {
     thiserror::Error,
 };
 
+const CONFIG_SAFE_MODE_WARNING: &str =
+    "<span color='#ff0000'>config.so could not be loaded. Using the default config.</span>";
+
 pub struct State {
     pub xkb_ctx: XkbContext,
     pub backend: CloneCell<Rc<dyn Backend>>,
@@ -142,6 +151,7 @@ pub struct State {
     pub node_ids: NodeIds,
     pub root: Rc<DisplayNode>,
     pub workspaces: CopyHashMap<String, Rc<WorkspaceNode>>,
+    pub workspace_output_affinities: CopyHashMap<String, Rc<WorkspaceOutputAffinity>>,
     pub dummy_output: CloneCell<Option<Rc<OutputNode>>>,
     pub backend_events: AsyncQueue<BackendEvent>,
     pub input_device_handlers: RefCell<AHashMap<InputDeviceId, InputDeviceData>>,
@@ -160,6 +170,7 @@ pub struct State {
     pub pending_input_popup_positioning: AsyncQueue<Rc<ZwpInputPopupSurfaceV2>>,
     pub pending_toplevel_screencasts: AsyncQueue<Rc<JayScreencast>>,
     pub pending_screencast_reallocs_or_reconfigures: AsyncQueue<Rc<JayScreencast>>,
+    pub pending_clipboard_changes: AsyncQueue<Rc<JayClipboard>>,
     pub pending_placeholder_render_textures: AsyncQueue<Rc<PlaceholderNode>>,
     pub dbus: Dbus,
     pub fdcloser: Arc<FdCloser>,
@@ -168,12 +179,17 @@ pub struct State {
     pub outputs: CopyHashMap<ConnectorId, Rc<OutputData>>,
     pub drm_devs: CopyHashMap<DrmDeviceId, Rc<DrmDevData>>,
     pub status: CloneCell<Rc<String>>,
+    pub config_status: CloneCell<Rc<String>>,
+    pub status_hint: CloneCell<Option<Rc<String>>>,
+    pub config_safe_mode: Cell<bool>,
     pub idle: IdleState,
     pub run_args: RunArgs,
     pub xwayland: XWaylandState,
     pub acceptor: CloneCell<Option<Rc<Acceptor>>>,
     pub serial: NumCell<u64>,
     pub run_toplevel: Rc<RunToplevel>,
+    pub config_reload_requested: Rc<AsyncEvent>,
+    pub state_dump_requested: Rc<AsyncEvent>,
     pub config_dir: Option<String>,
     pub config_file_id: NumCell<u64>,
     pub tracker: Tracker<Self>,
@@ -183,10 +199,16 @@ pub struct State {
     pub lock: ScreenlockState,
     pub scales: RefCounted<Scale>,
     pub cursor_sizes: RefCounted<u32>,
+    pub cursor_themes: RefCounted<Rc<String>>,
+    pub themed_cursors: CopyHashMap<Rc<String>, Rc<ServerCursors>>,
     pub hardware_tick_cursor: AsyncQueue<Option<Rc<dyn Cursor>>>,
     pub testers: RefCell<AHashMap<(ClientId, JaySeatEventsId), Rc<JaySeatEvents>>>,
     pub render_ctx_watchers: CopyHashMap<(ClientId, JayRenderCtxId), Rc<JayRenderCtx>>,
     pub workspace_watchers: CopyHashMap<(ClientId, JayWorkspaceWatcherId), Rc<JayWorkspaceWatcher>>,
+    pub output_watchers: CopyHashMap<(ClientId, JayOutputWatcherId), Rc<JayOutputWatcher>>,
+    pub output_extents_batched: Cell<bool>,
+    pub output_extents_change_pending: Cell<bool>,
+    pub client_errors: ClientErrorLog,
     pub default_workspace_capture: Cell<bool>,
     pub default_gfx_api: Cell<GfxApi>,
     pub activation_tokens: CopyHashMap<ActivationToken, ()>,
@@ -216,6 +238,7 @@ pub struct State {
     pub default_vrr_mode: Cell<&'static VrrMode>,
     pub default_vrr_cursor_hz: Cell<Option<f64>>,
     pub default_tearing_mode: Cell<&'static TearingMode>,
+    pub default_color_filter: Cell<Option<ColorMatrix>>,
     pub ei_acceptor: CloneCell<Option<Rc<EiAcceptor>>>,
     pub ei_acceptor_future: CloneCell<Option<SpawnedFuture<()>>>,
     pub enable_ei_acceptor: Cell<bool>,
@@ -224,10 +247,25 @@ pub struct State {
     pub cpu_worker: Rc<CpuWorker>,
     pub ui_drag_enabled: Cell<bool>,
     pub ui_drag_threshold_squared: Cell<i32>,
+    pub pointer_locator_duration_usec: Cell<u64>,
+    pub pointer_edge_behaviors: [Cell<PointerEdgeBehavior>; 4],
+    pub workspace_scroll_inverted: Cell<bool>,
+    pub workspace_scroll_threshold: Cell<i32>,
+    pub workspace_scroll_wrap: Cell<bool>,
+    pub workspace_scroll_skip_empty: Cell<bool>,
+    pub output_throttle_frame_nsec: Cell<Option<u64>>,
+    pub output_throttle_delay_nsec: Cell<u64>,
+    pub max_zoom: Cell<f64>,
+    pub scale_overrides: CopyHashMap<String, Scale>,
+    pub decoration_modes: CopyHashMap<String, DecorationMode>,
+    pub window_borders: CopyHashMap<String, WindowBorder>,
+    pub capture_blocked_app_ids: CopyHashMap<String, ()>,
+    pub show_focused_output: Cell<bool>,
     pub toplevels: CopyHashMap<ToplevelIdentifier, Weak<dyn ToplevelNode>>,
     pub const_40hz_latch: EventSource<dyn LatchListener>,
     pub tray_item_ids: TrayItemIds,
     pub data_control_device_ids: DataControlDeviceIds,
+    pub shared_clipboard: Cell<bool>,
 }
 
 // impl Drop for State {
@@ -264,6 +302,10 @@ pub struct IdleState {
     pub inhibitors: CopyHashMap<IdleInhibitorId, Rc<ZwpIdleInhibitorV1>>,
     pub inhibitors_changed: Cell<bool>,
     pub backend_idle: Cell<bool>,
+    /// The time after the timeout during which input cancels going idle.
+    ///
+    /// Seats can override this value for themselves.
+    pub grace_period: Cell<Duration>,
 }
 
 impl IdleState {
@@ -273,6 +315,16 @@ impl IdleState {
         self.change.trigger();
     }
 
+    pub fn set_grace_period(&self, grace_period: Duration) {
+        self.grace_period.set(grace_period);
+        self.grace_period_changed();
+    }
+
+    pub fn grace_period_changed(&self) {
+        self.timeout_changed.set(true);
+        self.change.trigger();
+    }
+
     pub fn add_inhibitor(&self, inhibitor: &Rc<ZwpIdleInhibitorV1>) {
         self.inhibitors.set(inhibitor.inhibit_id, inhibitor.clone());
         self.inhibitors_changed.set(true);
@@ -284,6 +336,20 @@ impl IdleState {
         self.inhibitors_changed.set(true);
         self.change.trigger();
     }
+
+    /// Removes inhibitors whose surface has been destroyed.
+    pub fn prune_inhibitors(&self) {
+        let dead: Vec<_> = self
+            .inhibitors
+            .lock()
+            .values()
+            .filter(|i| i.surface.destroyed.get())
+            .cloned()
+            .collect();
+        for inhibitor in dead {
+            self.remove_inhibitor(&inhibitor);
+        }
+    }
 }
 
 pub struct InputDeviceData {
@@ -300,6 +366,7 @@ pub struct DeviceHandlerData {
     pub syspath: Option<String>,
     pub devnode: Option<String>,
     pub keymap: CloneCell<Option<Rc<XkbKeymap>>>,
+    pub repeat_rate: Cell<Option<(i32, i32)>>,
     pub xkb_state: CloneCell<Option<Rc<RefCell<XkbState>>>>,
     pub output: CloneCell<Option<Rc<OutputGlobalOpt>>>,
     pub tablet_init: Option<Box<TabletInit>>,
@@ -422,6 +489,43 @@ impl State {
         }
     }
 
+    pub fn add_cursor_theme(&self, theme: &Rc<String>) {
+        if !self.cursor_themes.add(theme.clone()) {
+            return;
+        }
+        if !cursor_theme_exists(theme) {
+            log::warn!(
+                "Cursor theme {} is not installed. Using the default cursors instead.",
+                theme,
+            );
+            return;
+        }
+        if let Some(ctx) = self.render_ctx.get() {
+            self.load_cursor_theme(&ctx, theme);
+        }
+    }
+
+    pub fn remove_cursor_theme(&self, theme: &Rc<String>) {
+        if self.cursor_themes.remove(theme) {
+            self.themed_cursors.remove(theme);
+        }
+    }
+
+    fn load_cursor_theme(&self, ctx: &Rc<dyn GfxContext>, theme: &Rc<String>) {
+        if !cursor_theme_exists(theme) {
+            return;
+        }
+        match ServerCursors::load_theme(ctx, self, Some(theme.as_bytes().as_bstr())) {
+            Ok(Some(cursors)) => {
+                self.themed_cursors.set(theme.clone(), Rc::new(cursors));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::error!("Could not load cursor theme {}: {}", theme, ErrorFmt(e));
+            }
+        }
+    }
+
     fn output_scales_changed(&self) {
         UpdateTextTexturesVisitor.visit_display(&self.root);
         self.reload_cursors();
@@ -460,6 +564,7 @@ impl State {
         self.render_ctx.set(ctx.clone());
         self.render_ctx_version.fetch_add(1);
         self.cursors.set(None);
+        self.themed_cursors.clear();
         self.drm_feedback.set(None);
         self.wait_for_sync_obj
             .set_ctx(ctx.as_ref().and_then(|c| c.sync_obj_ctx().cloned()));
@@ -498,6 +603,9 @@ impl State {
                     node.render_data.borrow_mut().titles.clear();
                     node.render_data.borrow_mut().status.take();
                     node.hardware_cursor.set(None);
+                    node.backend_hardware_cursor.set(None);
+                    node.capture_buffer.take();
+                    node.color_filter_buffer.take();
                     node.node_visit_children(self);
                 }
                 fn visit_float(&mut self, node: &Rc<FloatNode>) {
@@ -582,6 +690,10 @@ impl State {
                 }
             };
             self.cursors.set(cursors);
+            self.themed_cursors.clear();
+            for (theme, _) in self.cursor_themes.lock().iter() {
+                self.load_cursor_theme(&ctx, theme);
+            }
             for cursor_user_group in self.cursor_user_groups.lock().values() {
                 cursor_user_group.reload_known_cursor();
             }
@@ -657,8 +769,10 @@ impl State {
         workspace: &Rc<WorkspaceNode>,
         abs_pos: Option<(i32, i32)>,
     ) {
-        width += 2 * self.theme.sizes.border_width.get();
-        height += 2 * self.theme.sizes.border_width.get() + self.theme.sizes.title_height.get() + 1;
+        let th = node.tl_data().float_title_height();
+        let bw = self.theme.sizes.border_width.get() + node.tl_window_border_width();
+        width += 2 * bw;
+        height += 2 * bw + th;
         let output = workspace.output.get();
         let output_rect = output.global.pos.get();
         let position = if let Some((mut x1, mut y1)) = abs_pos {
@@ -668,8 +782,8 @@ impl State {
             if y1 > output_rect.y2() {
                 y1 = output_rect.y2();
             }
-            y1 -= self.theme.sizes.border_width.get() + self.theme.sizes.title_height.get() + 1;
-            x1 -= self.theme.sizes.border_width.get();
+            y1 -= bw + th;
+            x1 -= bw;
             Rect::new_sized(x1, y1, width, height).unwrap()
         } else {
             let mut x1 = output_rect.x1();
@@ -706,7 +820,7 @@ impl State {
                 (output, ws)
             }
             _ => {
-                let output = seat.get_output();
+                let output = self.new_workspace_output(seat, name);
                 if output.is_dummy {
                     log::warn!("Not showing workspace because seat is on dummy output");
                     return;
@@ -725,6 +839,36 @@ impl State {
         // }
     }
 
+    pub fn find_output(&self, id: &OutputId) -> Option<Rc<OutputNode>> {
+        self.root
+            .outputs
+            .lock()
+            .values()
+            .find(|o| *o.global.output_id == *id)
+            .cloned()
+    }
+
+    /// Returns the preferred output of the affinity if it is connected, otherwise the
+    /// fallback output if it is connected.
+    pub fn affinity_output(&self, affinity: &WorkspaceOutputAffinity) -> Option<Rc<OutputNode>> {
+        if let Some(output) = self.find_output(&affinity.preferred) {
+            return Some(output);
+        }
+        self.find_output(affinity.fallback.as_ref()?)
+    }
+
+    /// Returns the output on which a workspace with the given name should be created.
+    ///
+    /// This is the output determined by the output affinity of the workspace, if any,
+    /// otherwise the output of the seat.
+    pub fn new_workspace_output(&self, seat: &WlSeatGlobal, name: &str) -> Rc<OutputNode> {
+        let output = match self.workspace_output_affinities.get(name) {
+            Some(affinity) => self.affinity_output(&affinity),
+            _ => None,
+        };
+        output.unwrap_or_else(|| seat.get_output())
+    }
+
     pub fn float_map_ws(&self) -> Rc<WorkspaceNode> {
         if let Some(seat) = self.seat_queue.last() {
             let output = seat.get_output();
@@ -739,7 +883,27 @@ impl State {
     }
 
     pub fn set_status(&self, status: &str) {
-        let status = Rc::new(status.to_owned());
+        self.config_status.set(Rc::new(status.to_owned()));
+        self.update_status();
+    }
+
+    pub fn set_status_hint(&self, hint: Option<String>) {
+        self.status_hint.set(hint.map(Rc::new));
+        self.update_status();
+    }
+
+    fn update_status(&self) {
+        let mut status = String::new();
+        if self.config_safe_mode.get() {
+            status.push_str(CONFIG_SAFE_MODE_WARNING);
+            status.push_str("  ");
+        }
+        if let Some(hint) = self.status_hint.get() {
+            status.push_str(&hint);
+            status.push_str("  ");
+        }
+        status.push_str(&self.config_status.get());
+        let status = Rc::new(status);
         self.status.set(status.clone());
         let outputs = self.root.outputs.lock();
         for output in outputs.values() {
@@ -747,6 +911,42 @@ impl State {
         }
     }
 
+    /// Replaces the current config by the given config.
+    ///
+    /// The old config is destroyed before the new config is configured. This cancels all
+    /// timers and pollables of the old config and removes all of its shortcuts.
+    pub fn swap_config(&self, config: ConfigProxy) {
+        if let Some(old) = self.config.take() {
+            old.destroy();
+            for seat in self.globals.seats.lock().values() {
+                seat.clear_shortcuts();
+                seat.set_idle_time_interval(Duration::ZERO);
+            }
+        }
+        config.configure(true);
+        self.config.set(Some(Rc::new(config)));
+    }
+
+    pub fn reload_config(self: &Rc<Self>) -> Result<(), ConfigError> {
+        log::info!("Reloading config");
+        let config = ConfigProxy::from_config_dir(self)?;
+        if self.config_safe_mode.replace(false) {
+            self.update_status();
+        }
+        self.swap_config(config);
+        Ok(())
+    }
+
+    pub fn use_default_config(self: &Rc<Self>) {
+        log::info!("Switching to the default config");
+        self.swap_config(ConfigProxy::default(self));
+    }
+
+    pub fn enter_config_safe_mode(&self) {
+        self.config_safe_mode.set(true);
+        self.update_status();
+    }
+
     pub fn input_occurred(&self) {
         if !self.idle.input.replace(true) {
             self.idle.change.trigger();
@@ -795,12 +995,24 @@ impl State {
             return;
         }
         self.damage_visualizer.add(rect);
+        let damage = |output: &OutputNode| {
+            output.add_screencopy_damage(rect);
+            if !cursor {
+                output.schedule.content_damaged();
+            }
+            if cursor && output.schedule.defer_cursor_updates() {
+                output.schedule.software_cursor_changed();
+            } else {
+                output.global.connector.damage();
+            }
+        };
         for output in self.root.outputs.lock().values() {
             if output.global.pos.get().intersects(&rect) {
-                if cursor && output.schedule.defer_cursor_updates() {
-                    output.schedule.software_cursor_changed();
-                } else {
-                    output.global.connector.damage();
+                damage(output);
+                if let Some(secondary) = output.span_secondary.get() {
+                    if secondary.global.pos.get().intersects(&rect) {
+                        damage(&secondary);
+                    }
                 }
             }
         }
@@ -831,6 +1043,8 @@ impl State {
         self.acceptor.set(None);
         self.backend.set(Rc::new(DummyBackend)).clear();
         self.run_toplevel.clear();
+        self.config_reload_requested.clear();
+        self.state_dump_requested.clear();
         self.xwayland.handler.borrow_mut().take();
         self.xwayland.queue.clear();
         self.idle.inhibitors.clear();
@@ -857,9 +1071,12 @@ impl State {
         self.pending_input_popup_positioning.clear();
         self.pending_toplevel_screencasts.clear();
         self.pending_screencast_reallocs_or_reconfigures.clear();
+        self.pending_clipboard_changes.clear();
         self.pending_placeholder_render_textures.clear();
         self.render_ctx_watchers.clear();
         self.workspace_watchers.clear();
+        self.output_watchers.clear();
+        self.client_errors.clear();
         self.toplevel_lists.clear();
         self.security_context_acceptors.clear();
         self.slow_clients.clear();
@@ -868,6 +1085,7 @@ impl State {
         }
         self.backend_events.clear();
         self.workspaces.clear();
+        self.workspace_output_affinities.clear();
         {
             let seats = mem::take(self.globals.seats.lock().deref_mut());
             for seat in seats.values() {
@@ -900,6 +1118,40 @@ impl State {
         }
     }
 
+    /// Returns the logical extents of the hardware cursor, if any.
+    pub fn capture_cursor_rect(&self) -> Option<Rect> {
+        let user = self.cursor_user_group_hardware_cursor.get()?.active()?;
+        let cursor = user.get()?;
+        let (x, y) = user.position_int();
+        Some(cursor.extents_at_scale(Scale::default()).move_(x, y))
+    }
+
+    /// Returns the union of the logical extents of all software cursors, if any.
+    pub fn software_cursor_rect(&self) -> Option<Rect> {
+        let mut rect = None::<Rect>;
+        for group in self.cursor_user_groups.lock().values() {
+            let Some(user) = group.active() else {
+                continue;
+            };
+            if !user.software_cursor() {
+                continue;
+            }
+            let Some(cursor) = user.get() else {
+                continue;
+            };
+            let (x, y) = user.position_int();
+            let extents = cursor.extents_at_scale(Scale::default()).move_(x, y);
+            rect = Some(rect.map_or(extents, |r| r.union(extents)));
+        }
+        rect
+    }
+
+    pub fn damage_hardware_cursor_user(&self) {
+        if let Some(g) = self.cursor_user_group_hardware_cursor.get() {
+            g.damage_active();
+        }
+    }
+
     pub fn refresh_hardware_cursors(&self) {
         if let Some(g) = self.cursor_user_group_hardware_cursor.get() {
             if let Some(u) = g.active() {
@@ -915,6 +1167,10 @@ impl State {
         output: &Rc<OutputNode>,
         hc: &mut dyn HardwareCursorUpdate,
     ) {
+        if !output.uses_hardware_cursor() {
+            hc.set_enabled(false);
+            return;
+        }
         let Some(g) = self.cursor_user_group_hardware_cursor.get() else {
             hc.set_enabled(false);
             return;
@@ -983,6 +1239,8 @@ impl State {
         let mut renderer = Renderer {
             base: target.renderer_base(&mut ops, scale, target_transform),
             state: self,
+            for_capture: true,
+            clear_color: Color::SOLID_BLACK,
             logical_extents: position.at_point(0, 0),
             pixel_extents: {
                 let (width, height) = target.logical_size(target_transform);
@@ -1005,16 +1263,7 @@ impl State {
             release_sync,
         );
         if render_hardware_cursors {
-            if let Some(cursor_user_group) = self.cursor_user_group_hardware_cursor.get() {
-                if let Some(cursor_user) = cursor_user_group.active() {
-                    if let Some(cursor) = cursor_user.get() {
-                        let (mut x, mut y) = cursor_user.position();
-                        x = x + x_off - Fixed::from_int(position.x1());
-                        y = y + y_off - Fixed::from_int(position.y1());
-                        cursor.render(&mut renderer, x, y);
-                    }
-                }
-            }
+            self.render_capture_cursor(&mut renderer, position, x_off, y_off);
         }
         target.render(
             target_acquire_sync,
@@ -1024,6 +1273,55 @@ impl State {
         )
     }
 
+    /// Renders only the cursor onto a transparent framebuffer.
+    pub fn perform_cursor_screencopy(
+        &self,
+        target: &Rc<dyn GfxFramebuffer>,
+        position: Rect,
+        x_off: i32,
+        y_off: i32,
+        scale: Scale,
+    ) -> Result<Option<SyncFile>, GfxError> {
+        let mut ops = vec![];
+        let mut renderer = Renderer {
+            base: target.renderer_base(&mut ops, scale, Transform::None),
+            state: self,
+            for_capture: true,
+            clear_color: Color::TRANSPARENT,
+            logical_extents: position.at_point(0, 0),
+            pixel_extents: {
+                let (width, height) = target.logical_size(Transform::None);
+                Rect::new_sized(0, 0, width, height).unwrap()
+            },
+        };
+        self.render_capture_cursor(&mut renderer, position, x_off, y_off);
+        target.render(
+            AcquireSync::Implicit,
+            ReleaseSync::Implicit,
+            &ops,
+            Some(&Color::TRANSPARENT),
+        )
+    }
+
+    fn render_capture_cursor(
+        &self,
+        renderer: &mut Renderer,
+        position: Rect,
+        x_off: i32,
+        y_off: i32,
+    ) {
+        if let Some(cursor_user_group) = self.cursor_user_group_hardware_cursor.get() {
+            if let Some(cursor_user) = cursor_user_group.active() {
+                if let Some(cursor) = cursor_user.get() {
+                    let (mut x, mut y) = cursor_user.position();
+                    x = x + x_off - Fixed::from_int(position.x1());
+                    y = y + y_off - Fixed::from_int(position.y1());
+                    cursor.render(renderer, x, y);
+                }
+            }
+        }
+    }
+
     pub fn perform_shm_screencopy(
         &self,
         src: &Rc<dyn GfxTexture>,
@@ -1038,7 +1336,13 @@ impl State {
         format: &'static Format,
         transform: Transform,
         scale: Scale,
+        damage: Option<Rect>,
     ) -> Result<Option<PendingShmTransfer>, ShmScreencopyError> {
+        let full = capture.rect.at_point(0, 0);
+        let damage = damage.unwrap_or(full).intersect(full);
+        if damage.is_empty() {
+            return Ok(None);
+        }
         let Some(ctx) = self.render_ctx.get() else {
             return Err(ShmScreencopyError::NoRenderContext);
         };
@@ -1072,12 +1376,7 @@ impl State {
         .map_err(ShmScreencopyError::CopyToTemporary)?;
         let staging = ctx.create_staging_buffer(fb.staging_size(), STAGING_DOWNLOAD);
         let pending = fb
-            .download(
-                &staging,
-                capture.clone(),
-                mem.clone(),
-                Region::new2(capture.rect.at_point(0, 0)),
-            )
+            .download(&staging, capture.clone(), mem.clone(), Region::new2(damage))
             .map_err(ShmScreencopyError::ReadPixels)?;
         Ok(pending)
     }
@@ -1151,6 +1450,83 @@ impl State {
         (self.dummy_output.get().unwrap(), 0, 0)
     }
 
+    /// Returns the output and position of the pointer after it moved from `output` to
+    /// `(x, y)`.
+    ///
+    /// This applies the configured [`PointerEdgeBehavior`] of the edge that the pointer
+    /// crossed if no output contains the new position.
+    pub fn find_output_after_motion(
+        &self,
+        output: &OutputNode,
+        x: i32,
+        y: i32,
+    ) -> (Rc<OutputNode>, i32, i32) {
+        let old = output.global.pos.get();
+        let edge = if x < old.x1() {
+            OutputEdge::Left
+        } else if x >= old.x2() {
+            OutputEdge::Right
+        } else if y < old.y1() {
+            OutputEdge::Top
+        } else if y >= old.y2() {
+            OutputEdge::Bottom
+        } else {
+            return self.find_closest_output(x, y);
+        };
+        let behavior = self.pointer_edge_behaviors[edge as usize].get();
+        if behavior == PointerEdgeBehavior::Clamp {
+            return self.find_closest_output(x, y);
+        }
+        let mut beyond = None;
+        let mut optimal_dist = i32::MAX;
+        for output in self.root.outputs.lock().values() {
+            let pos = output.global.pos.get();
+            if pos.is_empty() {
+                continue;
+            }
+            if pos.contains(x, y) {
+                return (output.clone(), x, y);
+            }
+            let is_beyond = match edge {
+                OutputEdge::Left => pos.x2() <= old.x1(),
+                OutputEdge::Right => pos.x1() >= old.x2(),
+                OutputEdge::Top => pos.y2() <= old.y1(),
+                OutputEdge::Bottom => pos.y1() >= old.y2(),
+            };
+            if is_beyond {
+                let dist = pos.dist_squared(x, y);
+                if dist < optimal_dist {
+                    optimal_dist = dist;
+                    beyond = Some(output.clone());
+                }
+            }
+        }
+        match behavior {
+            PointerEdgeBehavior::PushThrough => {
+                if let Some(output) = beyond {
+                    let pos = output.global.pos.get();
+                    let x = x.clamp(pos.x1(), pos.x2() - 1);
+                    let y = y.clamp(pos.y1(), pos.y2() - 1);
+                    return (output, x, y);
+                }
+            }
+            PointerEdgeBehavior::Wrap => {
+                if beyond.is_none() {
+                    let extents = self.root.extents.get();
+                    let (x, y) = match edge {
+                        OutputEdge::Left => (extents.x2() - 1, y),
+                        OutputEdge::Right => (extents.x1(), y),
+                        OutputEdge::Top => (x, extents.y2() - 1),
+                        OutputEdge::Bottom => (x, extents.y1()),
+                    };
+                    return self.find_closest_output(x, y);
+                }
+            }
+            PointerEdgeBehavior::Clamp => {}
+        }
+        self.find_closest_output(x, y)
+    }
+
     pub fn now(&self) -> Time {
         self.eng.now()
     }
@@ -1168,12 +1544,27 @@ impl State {
     }
 
     pub fn output_extents_changed(&self) {
+        if self.output_extents_batched.get() {
+            self.output_extents_change_pending.set(true);
+            return;
+        }
         self.root.update_extents();
         for seat in self.globals.seats.lock().values() {
             seat.output_extents_changed();
         }
     }
 
+    /// Runs `f` and updates the global extents at most once afterwards, even if `f`
+    /// changes the extents of multiple outputs.
+    pub fn batch_output_extents_changes(&self, f: impl FnOnce()) {
+        let batched = self.output_extents_batched.replace(true);
+        f();
+        self.output_extents_batched.set(batched);
+        if !batched && self.output_extents_change_pending.take() {
+            self.output_extents_changed();
+        }
+    }
+
     pub fn update_ei_acceptor(self: &Rc<Self>) {
         self.update_ei_acceptor2();
         if let Some(forker) = self.forker.get() {
@@ -16,6 +16,8 @@ use {
             const_clock::run_const_clock,
             slow_clients::{SlowClientHandler, SlowEiClientHandler},
         },
+        tree::dump_tree,
+        utils::errorfmt::ErrorFmt,
     },
     std::{rc::Rc, time::Duration},
 };
@@ -31,6 +33,22 @@ pub async fn handle_slow_clients(state: Rc<State>) {
     sch.handle_events().await;
 }
 
+pub async fn handle_config_reload_requests(state: Rc<State>) {
+    loop {
+        state.config_reload_requested.triggered().await;
+        if let Err(e) = state.reload_config() {
+            log::error!("Cannot reload config: {}", ErrorFmt(e));
+        }
+    }
+}
+
+pub async fn handle_state_dump_requests(state: Rc<State>) {
+    loop {
+        state.state_dump_requested.triggered().await;
+        dump_tree(&state);
+    }
+}
+
 pub async fn handle_slow_ei_clients(state: Rc<State>) {
     let mut sch = SlowEiClientHandler { state };
     sch.handle_events().await;
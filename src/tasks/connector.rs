@@ -1,20 +1,26 @@
 use {
     crate::{
-        backend::{Connector, ConnectorEvent, ConnectorId, MonitorInfo},
+        backend::{Connector, ConnectorEvent, ConnectorId, Mode, MonitorInfo},
         globals::GlobalName,
         ifs::{
             jay_tray_v1::JayTrayV1Global,
             wl_output::{PersistentOutputState, WlOutputGlobal},
         },
         output_schedule::OutputSchedule,
+        rect::Rect,
         state::{ConnectorData, OutputData, State},
-        tree::{move_ws_to_output, OutputNode, OutputRenderData, WsMoveConfig},
-        utils::{asyncevent::AsyncEvent, clonecell::CloneCell, hash_map_ext::HashMapExt},
+        tree::{
+            calculate_logical_size, move_ws_to_output, OutputNode, OutputRenderData, WsMoveConfig,
+        },
+        utils::{
+            asyncevent::AsyncEvent, clonecell::CloneCell, event_listener::EventListener,
+            hash_map_ext::HashMapExt,
+        },
     },
     std::{
         cell::{Cell, RefCell},
         collections::VecDeque,
-        rc::Rc,
+        rc::{Rc, Weak},
     },
 };
 
@@ -105,22 +111,26 @@ impl ConnectorHandler {
         let desired_state = match self.state.persistent_output_states.get(&output_id) {
             Some(ds) => ds,
             _ => {
-                let x1 = self
-                    .state
-                    .root
-                    .outputs
-                    .lock()
-                    .values()
-                    .map(|o| o.global.pos.get().x2())
-                    .max()
-                    .unwrap_or(0);
                 let ds = Rc::new(PersistentOutputState {
                     transform: Default::default(),
                     scale: Default::default(),
-                    pos: Cell::new((x1, 0)),
+                    pos: Cell::new((self.right_of_outputs(), 0)),
+                    mode: Default::default(),
                     vrr_mode: Cell::new(self.state.default_vrr_mode.get()),
                     vrr_cursor_hz: Cell::new(self.state.default_vrr_cursor_hz.get()),
                     tearing_mode: Cell::new(self.state.default_tearing_mode.get()),
+                    vblank_cursor_updates: Default::default(),
+                    use_hardware_cursor: Cell::new(true),
+                    color_filter: Cell::new(self.state.default_color_filter.get()),
+                    invert_colors: Default::default(),
+                    workspace_capture: Default::default(),
+                    workspace_scroll_wrap: Default::default(),
+                    workspace_scroll_skip_empty: Default::default(),
+                    flip_margin_ns: Default::default(),
+                    game_mode: Default::default(),
+                    vrr_min_refresh_hz: Default::default(),
+                    max_scale: Default::default(),
+                    background: Default::default(),
                 });
                 self.state
                     .persistent_output_states
@@ -128,6 +138,12 @@ impl ConnectorHandler {
                 ds
             }
         };
+        let restored_mode = desired_state
+            .mode
+            .get()
+            .filter(|m| *m != info.initial_mode && info.modes.contains(m));
+        let fallback_pos = self
+            .resolve_position_conflict(&desired_state, restored_mode.unwrap_or(info.initial_mode));
         let global = Rc::new(WlOutputGlobal::new(
             name,
             &self.state,
@@ -139,6 +155,9 @@ impl ConnectorHandler {
             &output_id,
             &desired_state,
         ));
+        if let Some((x, y)) = fallback_pos {
+            global.pos.set(global.pos.get().at_point(x, y));
+        }
         let schedule = Rc::new(OutputSchedule::new(
             &self.state.ring,
             &self.state.eng,
@@ -153,7 +172,7 @@ impl ConnectorHandler {
             name: self.state.globals.name(),
             output: global.opt.clone(),
         });
-        let on = Rc::new(OutputNode {
+        let on = Rc::new_cyclic(|slf: &Weak<OutputNode>| OutputNode {
             id: self.state.node_ids.next(),
             workspaces: Default::default(),
             workspace: CloneCell::new(None),
@@ -176,20 +195,32 @@ impl ConnectorHandler {
             state: self.state.clone(),
             is_dummy: false,
             status: self.state.status.clone(),
+            status_max_width: Default::default(),
             scroll: Default::default(),
+            workspace_scroll_steps: Default::default(),
+            workspace_scroll_wrap: Default::default(),
+            workspace_scroll_skip_empty: Default::default(),
             pointer_positions: Default::default(),
             pointer_down: Default::default(),
             lock_surface: Default::default(),
             hardware_cursor: Default::default(),
+            backend_hardware_cursor: Default::default(),
+            hardware_cursor_unavailable_logged: Default::default(),
             jay_outputs: Default::default(),
+            presentation_feeds: Default::default(),
+            frame_timings: Default::default(),
             screencasts: Default::default(),
             update_render_data_scheduled: Cell::new(false),
             hardware_cursor_needs_render: Cell::new(false),
+            hardware_cursor_vblank_pending: Cell::new(false),
+            cursor_vblank_listener: EventListener::new(slf.clone()),
             screencopies: Default::default(),
             title_visible: Default::default(),
+            focus_indicator: Default::default(),
             schedule,
             latch_event: Default::default(),
             vblank_event: Default::default(),
+            passive_vblank_event: Default::default(),
             presentation_event: Default::default(),
             render_margin_ns: Default::default(),
             flip_margin_ns: Default::default(),
@@ -197,9 +228,30 @@ impl ConnectorHandler {
             before_latch_event: Default::default(),
             tray_start_rel: Default::default(),
             tray_items: Default::default(),
+            stats: Default::default(),
+            zoom: Cell::new(1.0),
+            zoom_seat: Default::default(),
+            title_bar_position: Default::default(),
+            capture_buffer: Default::default(),
+            color_filter_buffer: Default::default(),
+            power: Cell::new(true),
+            gamma_ramp: Default::default(),
+            span_secondary: Default::default(),
+            span_primary: Default::default(),
+            game_mode_active: Default::default(),
+            title_double_click_state: Default::default(),
+            title_last_click: Default::default(),
+            requested_scale: Cell::new(desired_state.scale.get()),
+            max_scale: Cell::new(desired_state.max_scale.get()),
+            mirror_source: Default::default(),
+            mirrors: Default::default(),
+            screencopy_damage: Default::default(),
+            screencopy_damage_seq: Default::default(),
+            screencopy_damage_latched: Default::default(),
         });
         on.update_visible();
         on.update_rects();
+        on.update_workspace_scroll_behavior();
         self.state
             .add_output_scale(on.global.persistent.scale.get());
         let output_data = Rc::new(OutputData {
@@ -209,9 +261,19 @@ impl ConnectorHandler {
             lease_connectors: Default::default(),
         });
         self.state.outputs.set(self.id, output_data);
+        if let Some(margin) = on.global.persistent.flip_margin_ns.get() {
+            on.set_flip_margin(Some(margin));
+        }
+        on.apply_vrr_min_refresh();
         on.schedule_update_render_data();
         self.state.root.outputs.set(self.id, on.clone());
         self.state.output_extents_changed();
+        if let Some(mode) = restored_mode {
+            self.data.connector.set_mode(mode);
+        }
+        for watcher in self.state.output_watchers.lock().values() {
+            watcher.send_added(&on);
+        }
         global.opt.node.set(Some(on.clone()));
         global.opt.global.set(Some(global.clone()));
         let mut ws_to_move = VecDeque::new();
@@ -237,6 +299,14 @@ impl ConnectorHandler {
                 }
                 if ws.desired_output.get() == global.output_id {
                     ws_to_move.push_back(ws.clone());
+                    continue;
+                }
+                if let Some(fallback) = ws.fallback_output.get() {
+                    if fallback == global.output_id
+                        && self.state.find_output(&ws.desired_output.get()).is_none()
+                    {
+                        ws_to_move.push_back(ws.clone());
+                    }
                 }
             }
         }
@@ -264,8 +334,7 @@ impl ConnectorHandler {
                 match event {
                     ConnectorEvent::Disconnected => break 'outer,
                     ConnectorEvent::HardwareCursor(hc) => {
-                        on.schedule.set_hardware_cursor(&hc);
-                        on.hardware_cursor.set(hc);
+                        on.set_backend_hardware_cursor(hc);
                         self.state.refresh_hardware_cursors();
                     }
                     ConnectorEvent::ModeChanged(mode) => {
@@ -283,13 +352,29 @@ impl ConnectorHandler {
             }
             self.data.async_event.triggered().await;
         }
+        if let Some(primary) = on.span_primary.get() {
+            primary.detach_span_secondary();
+        }
+        on.unspan();
+        on.detach_mirror_source();
+        on.stop_mirrors();
         if let Some(config) = self.state.config.get() {
             config.connector_disconnected(self.id);
         }
         global.clear();
+        if !on.power.get() {
+            self.data.connector.set_power(true);
+        }
+        on.reset_gamma_ramp();
         for jo in on.jay_outputs.lock().drain_values() {
             jo.send_destroyed();
         }
+        for feed in on.presentation_feeds.lock().drain_values() {
+            feed.send_destroyed();
+        }
+        for timings in on.frame_timings.lock().drain_values() {
+            timings.send_destroyed();
+        }
         let screencasts: Vec<_> = on.screencasts.lock().values().cloned().collect();
         for sc in screencasts {
             sc.do_destroy();
@@ -303,6 +388,9 @@ impl ConnectorHandler {
         global.destroyed.set(true);
         self.state.root.outputs.remove(&self.id);
         self.state.output_extents_changed();
+        for watcher in self.state.output_watchers.lock().values() {
+            watcher.send_removed(&self.data.name);
+        }
         self.state.outputs.remove(&self.id);
         on.lock_surface.take();
         {
@@ -329,6 +417,7 @@ impl ConnectorHandler {
                 source_is_destroyed: true,
                 before: None,
             };
+            let target = ws.affinity_output().unwrap_or_else(|| target.clone());
             move_ws_to_output(&ws, &target, config);
         }
         for seat in self.state.globals.seats.lock().values() {
@@ -345,6 +434,54 @@ impl ConnectorHandler {
         self.state.damage(self.state.root.extents.get());
     }
 
+    /// Returns the x coordinate to the right of all outputs.
+    fn right_of_outputs(&self) -> i32 {
+        self.state
+            .root
+            .outputs
+            .lock()
+            .values()
+            .map(|o| o.global.pos.get().x2())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns a position to the right of all other outputs if the restored position
+    /// of the output overlaps one of them.
+    ///
+    /// The persisted position is not modified so that it is used again once the
+    /// conflict is gone.
+    fn resolve_position_conflict(
+        &self,
+        ds: &PersistentOutputState,
+        mode: Mode,
+    ) -> Option<(i32, i32)> {
+        let (x, y) = ds.pos.get();
+        let mut scale = ds.scale.get();
+        if let Some(max) = ds.max_scale.get() {
+            scale = scale.min(max);
+        }
+        let (width, height) =
+            calculate_logical_size((mode.width, mode.height), ds.transform.get(), scale);
+        let rect = Rect::new_sized(x, y, width, height)?;
+        let overlaps = self
+            .state
+            .root
+            .outputs
+            .lock()
+            .values()
+            .any(|o| o.global.pos.get().intersects(&rect));
+        if !overlaps {
+            return None;
+        }
+        let x1 = self.right_of_outputs();
+        log::info!(
+            "Restored position of connector {} overlaps another output. Moving it to ({x1}, 0)",
+            self.data.name,
+        );
+        Some((x1, 0))
+    }
+
     async fn handle_non_desktop_connected(&self, monitor_info: MonitorInfo) {
         let output_data = Rc::new(OutputData {
             connector: self.data.clone(),
@@ -29,6 +29,7 @@ pub async fn idle(state: Rc<State>, backend: Rc<dyn Backend>) {
         idle: false,
         dead: false,
         is_inhibited: false,
+        in_grace_period: false,
         last_input: now(),
     };
     idle.run().await;
@@ -41,6 +42,7 @@ struct Idle {
     idle: bool,
     dead: bool,
     is_inhibited: bool,
+    in_grace_period: bool,
     last_input: c::timespec,
 }
 
@@ -65,12 +67,19 @@ impl Idle {
         let since = duration_since(self.last_input);
         if since >= timeout {
             if !timeout.is_zero() && !self.is_inhibited {
+                let grace_period = self.remaining_grace_period(timeout, since);
+                if !grace_period.is_zero() {
+                    self.in_grace_period = true;
+                    self.program_timer2(grace_period);
+                    return;
+                }
                 if let Some(config) = self.state.config.get() {
                     config.idle();
                 }
                 self.backend.set_idle(true);
                 self.idle = true;
             }
+            self.in_grace_period = false;
         } else {
             self.program_timer2(timeout - since);
         }
@@ -87,10 +96,15 @@ impl Idle {
             }
         }
         if self.state.idle.timeout_changed.replace(false) {
+            self.in_grace_period = false;
             self.program_timer();
         }
         if self.state.idle.input.replace(false) {
             self.last_input = now();
+            if self.in_grace_period {
+                self.in_grace_period = false;
+                self.program_timer();
+            }
             if self.idle {
                 self.backend.set_idle(false);
                 self.idle = false;
@@ -99,6 +113,23 @@ impl Idle {
         }
     }
 
+    /// Returns the time until the grace periods of all seats have expired.
+    ///
+    /// `since` is the time since the last input on any seat.
+    fn remaining_grace_period(&self, timeout: Duration, since: Duration) -> Duration {
+        let remaining =
+            |idle: Duration, grace_period: Duration| (timeout + grace_period).saturating_sub(idle);
+        let seats = self.state.globals.seats.lock();
+        if seats.is_empty() {
+            return remaining(since, self.state.idle.grace_period.get());
+        }
+        seats
+            .values()
+            .map(|seat| remaining(seat.idle_time(), seat.idle_grace_period()))
+            .max()
+            .unwrap_or_default()
+    }
+
     fn program_timer(&mut self) {
         self.program_timer2(self.state.idle.timeout.get());
     }
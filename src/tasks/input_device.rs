@@ -22,6 +22,7 @@ pub fn handle(state: &Rc<State>, dev: Rc<dyn InputDevice>) {
         syspath: props.syspath,
         devnode: props.devnode,
         keymap: Default::default(),
+        repeat_rate: Default::default(),
         xkb_state: Default::default(),
         output: Default::default(),
         tablet_init: dev.tablet_info(),
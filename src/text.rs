@@ -56,6 +56,7 @@ impl<'a> Config<'a> {
             Config::None => Config::None,
             Config::RenderFitting {
                 height,
+                max_width,
                 font,
                 text,
                 color,
@@ -63,6 +64,7 @@ impl<'a> Config<'a> {
                 scale,
             } => Config::RenderFitting {
                 height,
+                max_width,
                 font,
                 text: text.into_owned().into(),
                 color,
@@ -204,6 +206,7 @@ fn render(
 
 fn render_fitting(
     height: Option<i32>,
+    max_width: Option<i32>,
     font: &str,
     text: &str,
     color: Color,
@@ -216,10 +219,17 @@ fn render_fitting(
         Some(_) => None,
         _ => Some(measurement.ink_rect.y1().neg()),
     };
-    let width = measurement.ink_rect.width();
+    let mut width = measurement.ink_rect.width();
     let height = height.unwrap_or(measurement.ink_rect.height());
+    let mut ellipsize = false;
+    if let Some(max_width) = max_width {
+        if width > max_width {
+            width = max_width.max(0);
+            ellipsize = true;
+        }
+    }
     render(
-        x, y, width, height, 0, font, text, color, false, markup, scale,
+        x, y, width, height, 0, font, text, color, ellipsize, markup, scale,
     )
 }
 
@@ -259,12 +269,13 @@ impl RenderWork {
             Config::None => unreachable!(),
             Config::RenderFitting {
                 height,
+                max_width,
                 ref font,
                 ref text,
                 color,
                 markup,
                 scale,
-            } => render_fitting(height, font, text, color, markup, scale),
+            } => render_fitting(height, max_width, font, text, color, markup, scale),
             Config::Render {
                 x,
                 y,
@@ -332,6 +343,7 @@ enum Config<'a> {
     None,
     RenderFitting {
         height: Option<i32>,
+        max_width: Option<i32>,
         font: Arc<String>,
         text: Cow<'a, str>,
         color: Color,
@@ -454,6 +466,7 @@ impl TextTexture {
         &self,
         on_completed: Rc<dyn OnCompleted>,
         height: Option<i32>,
+        max_width: Option<i32>,
         font: &Arc<String>,
         text: &str,
         color: Color,
@@ -462,6 +475,7 @@ impl TextTexture {
     ) {
         let config = Config::RenderFitting {
             height,
+            max_width,
             font: font.clone(),
             text: text.into(),
             color,
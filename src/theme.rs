@@ -110,11 +110,23 @@ impl Color {
         }
     }
 
-    #[cfg_attr(not(feature = "it"), expect(dead_code))]
     pub fn to_rgba_premultiplied(self) -> [u8; 4] {
         [to_u8(self.r), to_u8(self.g), to_u8(self.b), to_u8(self.a)]
     }
 
+    pub fn to_rgba_straight(self) -> [u8; 4] {
+        if self.a == 0.0 {
+            return [0, 0, 0, 0];
+        }
+        let straight = Self {
+            r: self.r / self.a,
+            g: self.g / self.a,
+            b: self.b / self.a,
+            a: self.a,
+        };
+        straight.to_rgba_premultiplied()
+    }
+
     pub fn to_array_srgb(self) -> [f32; 4] {
         [self.r, self.g, self.b, self.a]
     }
@@ -205,6 +217,11 @@ colors! {
     bar_text = (0xff, 0xff, 0xff),
     attention_requested_background = (0x23, 0x09, 0x2c),
     highlight = (0x9d, 0x28, 0xc6, 0x7f),
+    pointer_locator = (0xff, 0x8c, 0x00),
+    focused_window_border = (0x4c, 0x78, 0x99),
+    unfocused_window_border = (0x33, 0x33, 0x33),
+    attention_requested_window_border = (0x90, 0x00, 0x00),
+    focused_output_indicator = (0x4c, 0x78, 0x99),
 }
 
 macro_rules! sizes {
@@ -280,8 +297,10 @@ macro_rules! sizes {
 }
 
 sizes! {
-    title_height = (1, 1000, 17),
+    title_height = (0, 1000, 17),
     border_width = (1, 1000, 4),
+    pointer_locator_size = (1, 1000, 100),
+    window_border_width = (0, 1000, 0),
 }
 
 pub const DEFAULT_FONT: &str = "monospace 8";
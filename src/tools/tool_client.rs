@@ -332,7 +332,7 @@ impl ToolClient {
             self_id: s.registry,
             name: s.jay_compositor.0,
             interface: JayCompositor.name(),
-            version: s.jay_compositor.1.min(11),
+            version: s.jay_compositor.1.min(14),
             id: id.into(),
         });
         self.jay_compositor.set(Some(id));
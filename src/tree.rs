@@ -17,6 +17,7 @@ use {
         },
         rect::Rect,
         renderer::Renderer,
+        theme::Color,
         utils::numcell::NumCell,
         xkbcommon::KeyboardState,
     },
@@ -27,13 +28,14 @@ use {
     },
 };
 pub use {
-    container::*, containing::*, display::*, float::*, output::*, placeholder::*, stacked::*,
-    toplevel::*, walker::*, workspace::*,
+    container::*, containing::*, display::*, dump::*, float::*, output::*, placeholder::*,
+    stacked::*, toplevel::*, walker::*, workspace::*,
 };
 
 mod container;
 mod containing;
 mod display;
+mod dump;
 mod float;
 mod output;
 mod placeholder;
@@ -166,6 +168,10 @@ pub trait Node: 'static {
         let _ = bounds;
     }
 
+    fn node_background_color(&self) -> Option<Color> {
+        None
+    }
+
     fn node_client(&self) -> Option<Rc<Client>> {
         None
     }
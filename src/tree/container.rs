@@ -16,10 +16,12 @@ use {
         scale::Scale,
         state::State,
         text::TextTexture,
+        theme::Color,
         tree::{
-            default_tile_drag_bounds, walker::NodeVisitor, ContainingNode, Direction,
-            FindTreeResult, FindTreeUsecase, FoundNode, Node, NodeId, TddType, TileDragDestination,
-            ToplevelData, ToplevelNode, ToplevelNodeBase, WorkspaceNode,
+            default_tile_drag_bounds, walker::NodeVisitor, window_border_inset,
+            window_border_rects, ContainingNode, Direction, FindTreeResult, FindTreeUsecase,
+            FoundNode, Node, NodeId, TddType, TileDragDestination, ToplevelData, ToplevelNode,
+            ToplevelNodeBase, WorkspaceNode,
         },
         utils::{
             asyncevent::AsyncEvent,
@@ -105,6 +107,7 @@ pub struct ContainerRenderData {
     pub last_active_rect: Option<Rect>,
     pub border_rects: Vec<Rect>,
     pub underline_rects: Vec<Rect>,
+    pub window_borders: Vec<([Rect; 4], Color)>,
     pub titles: SmallMapMut<Scale, Vec<ContainerTitle>, 2>,
 }
 
@@ -181,8 +184,9 @@ impl ContainerChild {
         let height = content.height();
         // let x1 = body.x1() + (body.width() - width) / 2;
         // let y1 = body.y1() + (body.height() - height) / 2;
-        let x1 = body.x1();
-        let y1 = body.y1();
+        let wbw = self.node.tl_window_border_width();
+        let x1 = body.x1() + wbw;
+        let y1 = body.y1() + wbw;
         content = Rect::new_sized(x1, y1, width, height).unwrap();
         // log::debug!("body: {:?}", body);
         // log::debug!("content: {:?}", content);
@@ -408,7 +412,7 @@ impl ContainerNode {
     }
 
     fn perform_mono_layout(self: &Rc<Self>, child: &ContainerChild) {
-        let mb = self.mono_body.get();
+        let mb = window_border_inset(&self.mono_body.get(), child.node.tl_window_border_width());
         child
             .node
             .clone()
@@ -517,7 +521,8 @@ impl ContainerNode {
                 )
                 .unwrap(),
             );
-            let body = body.move_(self.abs_x1.get(), self.abs_y1.get());
+            let body = window_border_inset(&body, child.node.tl_window_border_width())
+                .move_(self.abs_x1.get(), self.abs_y1.get());
             child.node.clone().tl_change_extents(&body);
             child.position_content();
         }
@@ -808,11 +813,38 @@ impl ContainerNode {
         let have_active = self.children.iter().any(|c| c.active.get());
         let abs_x = self.abs_x1.get();
         let abs_y = self.abs_y1.get();
+        for (rects, _) in rd.window_borders.drain(..) {
+            if self.toplevel_data.visible.get() {
+                for rect in rects {
+                    self.state.damage(rect.move_(abs_x, abs_y));
+                }
+            }
+        }
+        let mono_child = self.mono_child.get().map(|c| c.node.node_id());
         for (i, child) in self.children.iter().enumerate() {
             let rect = child.title_rect.get();
             if self.toplevel_data.visible.get() {
                 self.state.damage(rect.move_(abs_x, abs_y));
             }
+            let wbw = child.node.tl_window_border_width();
+            if wbw > 0 && (!mono || mono_child == Some(child.node.node_id())) {
+                let body = if mono {
+                    self.mono_body.get()
+                } else {
+                    child.body.get()
+                };
+                let rects = window_border_rects(&body, wbw);
+                if self.toplevel_data.visible.get() {
+                    for rect in rects {
+                        self.state.damage(rect.move_(abs_x, abs_y));
+                    }
+                }
+                let color = child
+                    .node
+                    .tl_data()
+                    .window_border_color(child.active.get(), child.attention_requested.get());
+                rd.window_borders.push((rects, color));
+            }
             if i > 0 {
                 let rect = if mono {
                     Rect::new_sized(rect.x1() - bw, 0, bw, th)
@@ -1119,7 +1151,8 @@ impl ContainerNode {
         node.position_content();
         if let Some(mono) = self.mono_child.get() {
             if mono.node.node_id() == node.node.node_id() {
-                let body = self.mono_body.get();
+                let body =
+                    window_border_inset(&self.mono_body.get(), mono.node.tl_window_border_width());
                 self.mono_content.set(rect.at_point(body.x1(), body.y1()));
             }
         }
@@ -1820,7 +1853,8 @@ impl ContainingNode for ContainerNode {
         new.tl_set_visible(visible);
         if let Some(body) = body {
             let body = body.move_(self.abs_x1.get(), self.abs_y1.get());
-            new.clone().tl_change_extents(&body);
+            let extents = window_border_inset(&body, new.tl_window_border_width());
+            new.clone().tl_change_extents(&extents);
             self.state.damage(body);
         }
     }
@@ -1900,6 +1934,12 @@ impl ContainingNode for ContainerNode {
         self.workspace.get()
     }
 
+    fn cnode_child_window_border_changed(self: Rc<Self>, child: &dyn Node) {
+        if self.child_nodes.borrow().contains_key(&child.node_id()) {
+            self.schedule_layout();
+        }
+    }
+
     fn cnode_set_child_position(self: Rc<Self>, child: &dyn Node, x: i32, y: i32) {
         let Some(parent) = self.toplevel_data.parent.get() else {
             return;
@@ -2090,14 +2130,15 @@ impl ToplevelNodeBase for ContainerNode {
             }
         } else {
             if let Some(c) = self.mono_child.get() {
-                let body = self
-                    .mono_body
-                    .get()
-                    .move_(self.abs_x1.get(), self.abs_y1.get());
+                let body =
+                    window_border_inset(&self.mono_body.get(), c.node.tl_window_border_width())
+                        .move_(self.abs_x1.get(), self.abs_y1.get());
                 c.node.clone().tl_change_extents(&body);
             } else {
                 for child in self.children.iter() {
-                    let body = child.body.get().move_(self.abs_x1.get(), self.abs_y1.get());
+                    let body =
+                        window_border_inset(&child.body.get(), child.node.tl_window_border_width())
+                            .move_(self.abs_x1.get(), self.abs_y1.get());
                     child.node.clone().tl_change_extents(&body);
                 }
             }
@@ -31,4 +31,7 @@ pub trait ContainingNode: Node {
         let _ = new_y1;
         let _ = new_y2;
     }
+    fn cnode_child_window_border_changed(self: Rc<Self>, child: &dyn Node) {
+        let _ = child;
+    }
 }
@@ -158,6 +158,7 @@ impl Node for DisplayNode {
         for output in outputs.values() {
             let pos = output.global.pos.get();
             if pos.contains(x, y) {
+                let (x, y) = output.content_position(x, y);
                 let (x, y) = pos.translate(x, y);
                 tree.push(FoundNode {
                     node: output.clone(),
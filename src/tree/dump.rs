@@ -0,0 +1,121 @@
+use {
+    crate::{
+        ifs::wl_surface::{
+            x_surface::xwindow::Xwindow, xdg_surface::xdg_toplevel::XdgToplevel,
+            zwlr_layer_surface_v1::ZwlrLayerSurfaceV1,
+        },
+        state::State,
+        tree::{
+            ContainerNode, FloatNode, Node, NodeVisitorBase, OutputNode, PlaceholderNode,
+            ToplevelData, ToplevelNodeBase, WorkspaceNode,
+        },
+    },
+    std::{fmt::Arguments, rc::Rc},
+};
+
+/// Logs the outputs, workspaces, and toplevels of the tree as well as the focus of each
+/// seat.
+pub fn dump_tree(state: &State) {
+    log::info!("Dumping compositor state");
+    TreeDumper { depth: 0 }.visit_display(&state.root);
+    for seat in state.globals.seats.lock().values() {
+        let keyboard = seat.keyboard_node();
+        log::info!(
+            "seat {}: keyboard focus = node {}, pointer focus = {:?}",
+            seat.seat_name(),
+            keyboard.node_id(),
+            seat.pointer_node().map(|n| n.node_id()),
+        );
+    }
+}
+
+struct TreeDumper {
+    depth: usize,
+}
+
+impl TreeDumper {
+    fn log(&self, args: Arguments) {
+        log::info!("{:indent$}{}", "", args, indent = 2 * self.depth);
+    }
+
+    fn log_toplevel(&self, kind: &str, node: &dyn Node, data: &ToplevelData) {
+        self.log(format_args!(
+            "{} {}: title = {:?}, app-id = {:?}, pos = {:?}, active = {}, visible = {}",
+            kind,
+            node.node_id(),
+            data.title.borrow(),
+            data.app_id.borrow(),
+            data.pos.get(),
+            data.active(),
+            data.visible.get(),
+        ));
+    }
+
+    fn nested(&mut self, node: &dyn Node) {
+        self.depth += 1;
+        node.node_visit_children(self);
+        self.depth -= 1;
+    }
+}
+
+impl NodeVisitorBase for TreeDumper {
+    fn visit_container(&mut self, node: &Rc<ContainerNode>) {
+        self.log(format_args!(
+            "container {}: split = {:?}, mono = {}, pos = {:?}",
+            node.id,
+            node.split.get(),
+            node.mono_child.is_some(),
+            node.tl_data().pos.get(),
+        ));
+        self.nested(&**node);
+    }
+
+    fn visit_toplevel(&mut self, node: &Rc<XdgToplevel>) {
+        self.log_toplevel("xdg toplevel", &**node, node.tl_data());
+    }
+
+    fn visit_output(&mut self, node: &Rc<OutputNode>) {
+        self.log(format_args!(
+            "output {}: connector = {}, pos = {:?}",
+            node.id,
+            node.global.connector.name,
+            node.global.pos.get(),
+        ));
+        self.nested(&**node);
+    }
+
+    fn visit_float(&mut self, node: &Rc<FloatNode>) {
+        self.log(format_args!(
+            "float {}: pos = {:?}",
+            node.id,
+            node.position.get()
+        ));
+        self.nested(&**node);
+    }
+
+    fn visit_workspace(&mut self, node: &Rc<WorkspaceNode>) {
+        self.log(format_args!(
+            "workspace {}: name = {:?}, visible = {}",
+            node.id,
+            node.name.get(),
+            node.visible.get(),
+        ));
+        self.nested(&**node);
+    }
+
+    fn visit_layer_surface(&mut self, node: &Rc<ZwlrLayerSurfaceV1>) {
+        self.log(format_args!(
+            "layer surface {}: pos = {:?}",
+            node.node_id(),
+            node.node_absolute_position(),
+        ));
+    }
+
+    fn visit_xwindow(&mut self, node: &Rc<Xwindow>) {
+        self.log_toplevel("xwindow", &**node, node.tl_data());
+    }
+
+    fn visit_placeholder(&mut self, node: &Rc<PlaceholderNode>) {
+        self.log_toplevel("placeholder", &**node, node.tl_data());
+    }
+}
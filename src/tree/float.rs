@@ -14,8 +14,9 @@ use {
         state::State,
         text::TextTexture,
         tree::{
-            walker::NodeVisitor, ContainingNode, Direction, FindTreeResult, FindTreeUsecase,
-            FoundNode, Node, NodeId, StackedNode, TileDragDestination, ToplevelNode, WorkspaceNode,
+            walker::NodeVisitor, window_border_rects, ContainingNode, Direction, FindTreeResult,
+            FindTreeUsecase, FoundNode, Node, NodeId, StackedNode, TileDragDestination,
+            ToplevelNode, WorkspaceNode,
         },
         utils::{
             asyncevent::AsyncEvent, clonecell::CloneCell, double_click_state::DoubleClickState,
@@ -165,13 +166,13 @@ impl FloatNode {
         };
         let pos = self.position.get();
         let theme = &self.state.theme;
-        let bw = theme.sizes.border_width.get();
-        let th = theme.sizes.title_height.get();
+        let bw = theme.sizes.border_width.get() + child.tl_window_border_width();
+        let th = child.tl_data().float_title_height();
         let cpos = Rect::new_sized(
             pos.x1() + bw,
-            pos.y1() + bw + th + 1,
+            pos.y1() + bw + th,
             (pos.width() - 2 * bw).max(0),
-            (pos.height() - 2 * bw - th - 1).max(0),
+            (pos.height() - 2 * bw - th).max(0),
         )
         .unwrap();
         child.clone().tl_change_extents(&cpos);
@@ -179,6 +180,52 @@ impl FloatNode {
         self.schedule_render_titles();
     }
 
+    /// Returns the height of the title bar including the separator below it.
+    pub fn title_height(&self) -> i32 {
+        match self.child.get() {
+            Some(c) => c.tl_data().float_title_height(),
+            _ => self.state.theme.sizes.title_height.get() + 1,
+        }
+    }
+
+    /// Returns the width of the window border drawn inside the float borders.
+    fn window_border_width(&self) -> i32 {
+        match self.child.get() {
+            Some(c) => c.tl_window_border_width(),
+            _ => 0,
+        }
+    }
+
+    /// Returns the area inside the float borders and below the title.
+    fn body(&self) -> Rect {
+        let pos = self.position.get();
+        let bw = self.state.theme.sizes.border_width.get();
+        let th = self.title_height();
+        Rect::new_sized(
+            pos.x1() + bw,
+            pos.y1() + bw + th,
+            (pos.width() - 2 * bw).max(0),
+            (pos.height() - 2 * bw - th).max(0),
+        )
+        .unwrap()
+    }
+
+    fn damage_window_border(&self) {
+        let wbw = self.window_border_width();
+        if wbw > 0 && self.visible.get() {
+            for rect in window_border_rects(&self.body(), wbw) {
+                self.state.damage(rect);
+            }
+        }
+    }
+
+    pub fn on_decoration_mode_changed(self: &Rc<Self>) {
+        if self.visible.get() {
+            self.state.damage(self.position.get());
+        }
+        self.schedule_layout();
+    }
+
     pub fn schedule_render_titles(self: &Rc<Self>) {
         if !self.render_titles_scheduled.replace(true) {
             self.state.pending_float_titles.push(self.clone());
@@ -197,7 +244,7 @@ impl FloatNode {
         let font = theme.font.get();
         let title = self.title.borrow_mut();
         let pos = self.position.get();
-        if pos.width() <= 2 * bw {
+        if pos.width() <= 2 * bw || self.title_height() == 0 {
             return on_completed.event();
         }
         let ctx = match self.state.render_ctx.get() {
@@ -271,7 +318,7 @@ impl FloatNode {
         let y = y.round_down();
         let theme = &self.state.theme;
         let bw = theme.sizes.border_width.get();
-        let th = theme.sizes.title_height.get();
+        let th = self.title_height();
         let mut seats = self.cursors.borrow_mut();
         let seat_state = seats.entry(id).or_insert_with(|| CursorState {
             cursor: KnownCursor::Default,
@@ -307,7 +354,7 @@ impl FloatNode {
                 }
                 OpType::ResizeTop => {
                     y1 += y - seat_state.dist_ver;
-                    y1 = y1.min(y2 - 2 * bw - th - 1);
+                    y1 = y1.min(y2 - 2 * bw - th);
                 }
                 OpType::ResizeRight => {
                     x2 += x - pos.width() + seat_state.dist_hor;
@@ -315,31 +362,31 @@ impl FloatNode {
                 }
                 OpType::ResizeBottom => {
                     y2 += y - pos.height() + seat_state.dist_ver;
-                    y2 = y2.max(y1 + 2 * bw + th + 1);
+                    y2 = y2.max(y1 + 2 * bw + th);
                 }
                 OpType::ResizeTopLeft => {
                     x1 += x - seat_state.dist_hor;
                     y1 += y - seat_state.dist_ver;
                     x1 = x1.min(x2 - 2 * bw);
-                    y1 = y1.min(y2 - 2 * bw - th - 1);
+                    y1 = y1.min(y2 - 2 * bw - th);
                 }
                 OpType::ResizeTopRight => {
                     x2 += x - pos.width() + seat_state.dist_hor;
                     y1 += y - seat_state.dist_ver;
                     x2 = x2.max(x1 + 2 * bw);
-                    y1 = y1.min(y2 - 2 * bw - th - 1);
+                    y1 = y1.min(y2 - 2 * bw - th);
                 }
                 OpType::ResizeBottomLeft => {
                     x1 += x - seat_state.dist_hor;
                     y2 += y - pos.height() + seat_state.dist_ver;
                     x1 = x1.min(x2 - 2 * bw);
-                    y2 = y2.max(y1 + 2 * bw + th + 1);
+                    y2 = y2.max(y1 + 2 * bw + th);
                 }
                 OpType::ResizeBottomRight => {
                     x2 += x - pos.width() + seat_state.dist_hor;
                     y2 += y - pos.height() + seat_state.dist_ver;
                     x2 = x2.max(x1 + 2 * bw);
-                    y2 = y2.max(y1 + 2 * bw + th + 1);
+                    y2 = y2.max(y1 + 2 * bw + th);
                 }
             }
             let new_pos = Rect::new(x1, y1, x2, y2).unwrap();
@@ -398,7 +445,7 @@ impl FloatNode {
         }
     }
 
-    fn set_workspace(self: &Rc<Self>, ws: &Rc<WorkspaceNode>) {
+    pub fn set_workspace(self: &Rc<Self>, ws: &Rc<WorkspaceNode>) {
         if let Some(c) = self.child.get() {
             c.tl_set_workspace(ws);
         }
@@ -420,6 +467,7 @@ impl FloatNode {
     fn update_child_active(self: &Rc<Self>, active: bool) {
         if self.active.replace(active) != active {
             self.schedule_render_titles();
+            self.damage_window_border();
         }
     }
 
@@ -538,11 +586,11 @@ impl FloatNode {
         let child = self.child.get()?;
         let theme = &self.state.theme.sizes;
         let bw = theme.border_width.get();
-        let th = theme.title_height.get();
+        let th = child.tl_data().float_title_height();
         let pos = self.position.get();
         let body = Rect::new(
             pos.x1() + bw,
-            pos.y1() + bw + th + 1,
+            pos.y1() + bw + th,
             pos.x2() - bw,
             pos.y2() - bw,
         )?;
@@ -595,13 +643,13 @@ impl Node for FloatNode {
         usecase: FindTreeUsecase,
     ) -> FindTreeResult {
         let theme = &self.state.theme;
-        let th = theme.sizes.title_height.get();
-        let bw = theme.sizes.border_width.get();
+        let th = self.title_height();
+        let bw = theme.sizes.border_width.get() + self.window_border_width();
         let pos = self.position.get();
         if x < bw || x >= pos.width() - bw {
             return FindTreeResult::AcceptsInput;
         }
-        if y < bw + th + 1 || y >= pos.height() - bw {
+        if y < bw + th || y >= pos.height() - bw {
             return FindTreeResult::AcceptsInput;
         }
         let child = match self.child.get() {
@@ -609,7 +657,7 @@ impl Node for FloatNode {
             _ => return FindTreeResult::Other,
         };
         let x = x - bw;
-        let y = y - bw - th - 1;
+        let y = y - bw - th;
         tree.push(FoundNode {
             node: child.clone().tl_into_node(),
             x,
@@ -767,6 +815,7 @@ impl ContainingNode for FloatNode {
             self.workspace
                 .get()
                 .cnode_child_attention_request_changed(&*self, set);
+            self.damage_window_border();
         }
     }
 
@@ -776,9 +825,9 @@ impl ContainingNode for FloatNode {
 
     fn cnode_set_child_position(self: Rc<Self>, _child: &dyn Node, x: i32, y: i32) {
         let theme = &self.state.theme;
-        let th = theme.sizes.title_height.get();
-        let bw = theme.sizes.border_width.get();
-        let (x, y) = (x - bw, y - th - bw - 1);
+        let th = self.title_height();
+        let bw = theme.sizes.border_width.get() + self.window_border_width();
+        let (x, y) = (x - bw, y - th - bw);
         let pos = self.position.get();
         if pos.position() != (x, y) {
             let new_pos = pos.at_point(x, y);
@@ -798,8 +847,8 @@ impl ContainingNode for FloatNode {
         new_y2: Option<i32>,
     ) {
         let theme = &self.state.theme;
-        let th = theme.sizes.title_height.get();
-        let bw = theme.sizes.border_width.get();
+        let th = self.title_height();
+        let bw = theme.sizes.border_width.get() + self.window_border_width();
         let pos = self.position.get();
         let mut x1 = pos.x1();
         let mut x2 = pos.x2();
@@ -812,10 +861,10 @@ impl ContainingNode for FloatNode {
             x2 = (v + bw).max(x1 + bw + bw);
         }
         if let Some(v) = new_y1 {
-            y1 = (v - th - bw - 1).min(y2 - bw - th - bw - 1);
+            y1 = (v - th - bw).min(y2 - bw - th - bw);
         }
         if let Some(v) = new_y2 {
-            y2 = (v + bw).max(y1 + bw + th + bw + 1);
+            y2 = (v + bw).max(y1 + bw + th + bw);
         }
         let new_pos = Rect::new(x1, y1, x2, y2).unwrap();
         if new_pos != pos {
@@ -827,6 +876,13 @@ impl ContainingNode for FloatNode {
             self.schedule_layout();
         }
     }
+
+    fn cnode_child_window_border_changed(self: Rc<Self>, _child: &dyn Node) {
+        if self.visible.get() {
+            self.state.damage(self.position.get());
+        }
+        self.schedule_layout();
+    }
 }
 
 impl StackedNode for FloatNode {
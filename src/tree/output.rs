@@ -1,13 +1,20 @@
 use {
     crate::{
-        backend::{HardwareCursor, KeyState, Mode},
+        allocator::{AllocatorError, BufferObject, BO_USE_RENDERING},
+        backend::{ConnectorId, GammaRamp, HardwareCursor, KeyState, Mode},
         client::ClientId,
         cursor::KnownCursor,
         fixed::Fixed,
-        gfx_api::{AcquireSync, BufferResv, GfxTexture, ReleaseSync},
+        format::XRGB8888,
+        gfx_api::{
+            AcquireSync, BufferResv, ColorMatrix, GfxError, GfxFramebuffer, GfxRenderPass,
+            GfxTexture, ReleaseSync,
+        },
         ifs::{
             ext_image_copy::ext_image_copy_capture_session_v1::ExtImageCopyCaptureSessionV1,
+            jay_frame_timings::JayFrameTimings,
             jay_output::JayOutput,
+            jay_presentation_feed::JayPresentationFeed,
             jay_screencast::JayScreencast,
             wl_buffer::WlBufferStorage,
             wl_output::WlOutputGlobal,
@@ -15,7 +22,7 @@ use {
                 collect_kb_foci2,
                 tablet::{TabletTool, TabletToolChanges, TabletToolId},
                 wl_pointer::PendingScroll,
-                NodeSeatState, SeatId, WlSeatGlobal, BTN_LEFT,
+                NodeSeatState, SeatId, WlSeatGlobal, BTN_LEFT, BTN_MIDDLE,
             },
             wl_surface::{
                 ext_session_lock_surface_v1::ExtSessionLockSurfaceV1,
@@ -33,19 +40,29 @@ use {
         scale::Scale,
         state::State,
         text::TextTexture,
+        theme::Color,
         tree::{
-            walker::NodeVisitor, Direction, FindTreeResult, FindTreeUsecase, FoundNode, Node,
-            NodeId, StackedNode, TddType, TileDragDestination, WorkspaceDragDestination,
-            WorkspaceNode, WorkspaceNodeId,
+            move_ws_to_output, walker::NodeVisitor, Direction, FindTreeResult, FindTreeUsecase,
+            FoundNode, Node, NodeId, StackedNode, TddType, TileDragDestination,
+            WorkspaceDragDestination, WorkspaceNode, WorkspaceNodeId, WsMoveConfig,
         },
         utils::{
-            asyncevent::AsyncEvent, clonecell::CloneCell, copyhashmap::CopyHashMap,
-            errorfmt::ErrorFmt, event_listener::EventSource, hash_map_ext::HashMapExt,
-            linkedlist::LinkedList, on_drop_event::OnDropEvent, scroller::Scroller,
+            asyncevent::AsyncEvent,
+            clonecell::CloneCell,
+            copyhashmap::CopyHashMap,
+            double_click_state::DoubleClickState,
+            errorfmt::ErrorFmt,
+            event_listener::{EventListener, EventSource},
+            hash_map_ext::HashMapExt,
+            linkedlist::{LinkedList, NodeRef},
+            numcell::NumCell,
+            on_drop_event::OnDropEvent,
+            scroller::Scroller,
             transform_ext::TransformExt,
         },
         wire::{
-            ExtImageCopyCaptureSessionV1Id, JayOutputId, JayScreencastId, ZwlrScreencopyFrameV1Id,
+            ExtImageCopyCaptureSessionV1Id, JayFrameTimingsId, JayOutputId, JayPresentationFeedId,
+            JayScreencastId, ZwlrScreencopyFrameV1Id,
         },
     },
     ahash::AHashMap,
@@ -53,10 +70,12 @@ use {
     smallvec::SmallVec,
     std::{
         cell::{Cell, RefCell},
+        collections::VecDeque,
         fmt::{Debug, Formatter},
         ops::{BitOrAssign, Deref},
         rc::Rc,
     },
+    thiserror::Error,
 };
 
 tree_id!(OutputNodeId);
@@ -64,6 +83,8 @@ pub struct OutputNode {
     pub id: OutputNodeId,
     pub global: Rc<WlOutputGlobal>,
     pub jay_outputs: CopyHashMap<(ClientId, JayOutputId), Rc<JayOutput>>,
+    pub presentation_feeds: CopyHashMap<(ClientId, JayPresentationFeedId), Rc<JayPresentationFeed>>,
+    pub frame_timings: CopyHashMap<(ClientId, JayFrameTimingsId), Rc<JayFrameTimings>>,
     pub workspaces: LinkedList<Rc<WorkspaceNode>>,
     pub workspace: CloneCell<Option<Rc<WorkspaceNode>>>,
     pub seat_state: NodeSeatState,
@@ -76,19 +97,31 @@ pub struct OutputNode {
     pub state: Rc<State>,
     pub is_dummy: bool,
     pub status: CloneCell<Rc<String>>,
+    /// The width that the status text was last truncated to, in logical pixels.
+    pub status_max_width: Cell<Option<i32>>,
     pub scroll: Scroller,
+    pub workspace_scroll_steps: Cell<i32>,
+    pub workspace_scroll_wrap: Cell<bool>,
+    pub workspace_scroll_skip_empty: Cell<bool>,
     pub pointer_positions: CopyHashMap<PointerType, (i32, i32)>,
     pub pointer_down: CopyHashMap<SeatId, (i32, i32)>,
     pub lock_surface: CloneCell<Option<Rc<ExtSessionLockSurfaceV1>>>,
     pub hardware_cursor: CloneCell<Option<Rc<dyn HardwareCursor>>>,
+    pub backend_hardware_cursor: CloneCell<Option<Rc<dyn HardwareCursor>>>,
+    pub hardware_cursor_unavailable_logged: Cell<bool>,
     pub hardware_cursor_needs_render: Cell<bool>,
+    pub hardware_cursor_vblank_pending: Cell<bool>,
+    pub cursor_vblank_listener: EventListener<dyn VblankListener>,
     pub update_render_data_scheduled: Cell<bool>,
     pub screencasts: CopyHashMap<(ClientId, JayScreencastId), Rc<JayScreencast>>,
     pub screencopies: CopyHashMap<(ClientId, ZwlrScreencopyFrameV1Id), Rc<ZwlrScreencopyFrameV1>>,
     pub title_visible: Cell<bool>,
+    pub focus_indicator: Cell<bool>,
     pub schedule: Rc<OutputSchedule>,
     pub latch_event: EventSource<dyn LatchListener>,
     pub vblank_event: EventSource<dyn VblankListener>,
+    /// Vblank listeners that do not keep emulated vblanks going.
+    pub passive_vblank_event: EventSource<dyn VblankListener>,
     pub presentation_event: EventSource<dyn PresentationListener>,
     pub render_margin_ns: Cell<u64>,
     pub flip_margin_ns: Cell<Option<u64>>,
@@ -97,6 +130,241 @@ pub struct OutputNode {
     pub before_latch_event: EventSource<dyn BeforeLatchListener>,
     pub tray_start_rel: Cell<i32>,
     pub tray_items: LinkedList<Rc<dyn DynTrayItem>>,
+    pub stats: OutputStats,
+    pub zoom: Cell<f64>,
+    pub zoom_seat: Cell<Option<SeatId>>,
+    pub title_bar_position: Cell<TitleBarPosition>,
+    pub capture_buffer: RefCell<Option<OutputCaptureBuffer>>,
+    /// The buffer that the contents are composited to before the color matrix is
+    /// applied.
+    pub color_filter_buffer: RefCell<Option<OutputCaptureBuffer>>,
+    pub power: Cell<bool>,
+    pub gamma_ramp: CloneCell<Option<Rc<GammaRamp>>>,
+    /// The output that shows the right half of this output while it is spanned.
+    pub span_secondary: CloneCell<Option<Rc<OutputNode>>>,
+    /// The output whose right half this output shows while it is spanned.
+    pub span_primary: CloneCell<Option<Rc<OutputNode>>>,
+    /// Whether the output is currently showing a single fullscreen surface in game mode.
+    pub game_mode_active: Cell<bool>,
+    pub title_double_click_state: RefCell<DoubleClickState>,
+    /// The workspace whose title was clicked last.
+    pub title_last_click: Cell<Option<WorkspaceNodeId>>,
+    /// The scale most recently passed to `set_preferred_scale`, before clamping.
+    pub requested_scale: Cell<Scale>,
+    pub max_scale: Cell<Option<Scale>>,
+    /// The output whose contents this output shows while it is mirroring.
+    pub mirror_source: CloneCell<Option<Rc<OutputNode>>>,
+    /// The outputs that mirror this output.
+    pub mirrors: CopyHashMap<ConnectorId, Rc<OutputNode>>,
+    /// The most recent damage of this output together with the value of
+    /// `screencopy_damage_seq` after the damage was added.
+    pub screencopy_damage: RefCell<VecDeque<(u64, Rect)>>,
+    pub screencopy_damage_seq: NumCell<u64>,
+    /// The value of `screencopy_damage_seq` when the last frame was latched.
+    pub screencopy_damage_latched: Cell<u64>,
+}
+
+/// The maximum number of damage rectangles remembered for screencopies.
+const MAX_SCREENCOPY_DAMAGE: usize = 32;
+
+/// The state of an output when its contents were last copied into a screencopy buffer.
+#[derive(Copy, Clone)]
+pub struct ScreencopyDamageMark {
+    output: OutputNodeId,
+    capture: Rect,
+    pos: Rect,
+    mode: (i32, i32),
+    transform: Transform,
+    scale: Scale,
+    offset: (i32, i32),
+    size: Option<(i32, i32)>,
+    seq: u64,
+    cursor: Option<Rect>,
+}
+
+impl ScreencopyDamageMark {
+    fn same_geometry(&self, other: &Self) -> bool {
+        self.output == other.output
+            && self.capture == other.capture
+            && self.pos == other.pos
+            && self.mode == other.mode
+            && self.transform == other.transform
+            && self.scale == other.scale
+            && self.offset == other.offset
+            && self.size == other.size
+    }
+}
+
+/// Converts logical damage to the coordinates of the screencopy buffer.
+fn screencopy_damage_to_buffer(mark: &ScreencopyDamageMark, rect: Rect) -> Option<Rect> {
+    let rect = rect.intersect(mark.pos);
+    if rect.is_empty() {
+        return None;
+    }
+    let scale = mark.scale.to_f64();
+    let x1 = ((rect.x1() - mark.pos.x1()) as f64 * scale).floor() as i32;
+    let y1 = ((rect.y1() - mark.pos.y1()) as f64 * scale).floor() as i32;
+    let x2 = ((rect.x2() - mark.pos.x1()) as f64 * scale).ceil() as i32;
+    let y2 = ((rect.y2() - mark.pos.y1()) as f64 * scale).ceil() as i32;
+    let (width, height) = mark.mode;
+    let (x1, y1) = mark.transform.apply_point(width, height, (x1, y1));
+    let (x2, y2) = mark.transform.apply_point(width, height, (x2, y2));
+    let rect = Rect::new(x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2))?;
+    let rect = rect.intersect(mark.capture);
+    if rect.is_empty() {
+        return None;
+    }
+    Some(rect.move_(-mark.capture.x1(), -mark.capture.y1()))
+}
+
+/// An offscreen buffer of an output.
+///
+/// Output captures are rendered to such a buffer while a toplevel that blocks captures
+/// is visible on the output. Color matrices are applied to the contents of such a
+/// buffer.
+pub struct OutputCaptureBuffer {
+    width: i32,
+    height: i32,
+    _bo: Rc<dyn BufferObject>,
+    fb: Rc<dyn GfxFramebuffer>,
+    tex: Rc<dyn GfxTexture>,
+}
+
+#[derive(Debug, Error)]
+enum OutputCaptureError {
+    #[error("There is no render context")]
+    NoRenderContext,
+    #[error("Render context does not support XRGB8888")]
+    XRGB8888,
+    #[error("Render context supports no modifiers for XRGB8888 rendering")]
+    Modifiers,
+    #[error(transparent)]
+    AllocatorError(#[from] AllocatorError),
+    #[error(transparent)]
+    RenderError(#[from] GfxError),
+}
+
+#[derive(Debug, Error)]
+pub enum OutputSpanError {
+    #[error("An output cannot be spanned with itself")]
+    SameOutput,
+    #[error("One of the outputs is already part of a span")]
+    AlreadySpanned,
+    #[error("One of the outputs is mirroring or mirrored")]
+    Mirrored,
+    #[error("The outputs use different modes")]
+    ModeMismatch,
+    #[error("The outputs use different scales")]
+    ScaleMismatch,
+    #[error("The outputs use different transforms")]
+    TransformMismatch,
+}
+
+#[derive(Debug, Error)]
+pub enum OutputMirrorError {
+    #[error("An output cannot mirror itself")]
+    SameOutput,
+    #[error("One of the outputs is part of a span")]
+    Spanned,
+    #[error("The source output is itself mirroring another output")]
+    SourceIsMirror,
+    #[error("The output is mirrored by another output")]
+    IsMirrored,
+    #[error("The source output has been removed")]
+    SourceDestroyed,
+}
+
+#[derive(Debug, Error)]
+pub enum GammaRampError {
+    #[error("The output does not support gamma ramps")]
+    NotSupported,
+    #[error("The gamma ramp has {0} entries but the output requires {1}")]
+    InvalidSize(usize, usize),
+}
+
+/// The edge of the output along which the bar is shown.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum TitleBarPosition {
+    #[default]
+    Top,
+    Bottom,
+}
+
+/// The area of an output that is shown while the output is zoomed.
+#[derive(Copy, Clone, Debug)]
+pub struct OutputZoom {
+    pub factor: f64,
+    /// The position of the visible area relative to the output in logical pixels.
+    pub x: i32,
+    pub y: i32,
+    /// Whether this is the zoom of the output itself. Cursors are then rendered
+    /// unscaled at their position on the output.
+    pub magnifier: bool,
+}
+
+impl OutputZoom {
+    /// Returns the position of the content that is shown at a position relative to the
+    /// output.
+    pub fn content_position(&self, x: i32, y: i32) -> (i32, i32) {
+        (
+            self.x + (x as f64 / self.factor).floor() as i32,
+            self.y + (y as f64 / self.factor).floor() as i32,
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct OutputStats {
+    pub vblanks: NumCell<u64>,
+    pub latches: NumCell<u64>,
+    pub presentations: NumCell<u64>,
+    pub missed_frames: NumCell<u64>,
+    pub flip_margin_sum_ns: NumCell<u64>,
+    pub flip_margin_samples: NumCell<u64>,
+    pub last_refresh_ns: Cell<u32>,
+    pub last_flags: Cell<u32>,
+    last_seq: Cell<Option<u64>>,
+    vblanks_at_last_presentation: Cell<u64>,
+    expected_seq: Cell<Option<u64>>,
+}
+
+impl OutputStats {
+    pub fn avg_flip_margin_ns(&self) -> Option<u64> {
+        let samples = self.flip_margin_samples.get();
+        if samples == 0 {
+            return None;
+        }
+        Some(self.flip_margin_sum_ns.get() / samples)
+    }
+
+    fn latched(&self) {
+        self.latches.fetch_add(1);
+        if self.expected_seq.get().is_some() {
+            return;
+        }
+        if let Some(seq) = self.last_seq.get() {
+            // Vblanks that passed without a presentation were idle, not missed.
+            let idle = self.vblanks.get() - self.vblanks_at_last_presentation.get();
+            self.expected_seq.set(Some(seq + 1 + idle));
+        }
+    }
+
+    fn presented(&self, refresh: u32, seq: u64, flags: u32, flip_margin_ns: Option<u64>) {
+        self.presentations.fetch_add(1);
+        if let Some(expected) = self.expected_seq.take() {
+            if seq > expected {
+                self.missed_frames.fetch_add(seq - expected);
+            }
+        }
+        if let Some(margin) = flip_margin_ns {
+            self.flip_margin_sum_ns.fetch_add(margin);
+            self.flip_margin_samples.fetch_add(1);
+        }
+        self.last_seq.set(Some(seq));
+        self.vblanks_at_last_presentation.set(self.vblanks.get());
+        self.last_refresh_ns.set(refresh);
+        self.last_flags.set(flags);
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -152,8 +420,10 @@ pub async fn output_render_data(state: Rc<State>) {
         }
         if output.update_render_data_scheduled.get() {
             output.update_render_data_scheduled.set(false);
-            output.update_render_data_phase1().triggered().await;
-            output.update_render_data_phase2();
+            if let Some(completed) = output.update_render_data_phase1() {
+                completed.triggered().await;
+                output.update_render_data_phase2();
+            }
         }
     }
 }
@@ -169,7 +439,32 @@ impl OutputNode {
         }
     }
 
+    /// Returns the time until which presenting this output should be delayed to save power.
+    ///
+    /// Outputs that are focused by a seat or that are being captured are never throttled.
+    /// Outputs whose contents were damaged recently are not throttled either.
+    pub fn throttled_present_nsec(&self) -> Option<u64> {
+        let frame_nsec = self.state.output_throttle_frame_nsec.get()?;
+        let captured = !self.screencopies.is_empty()
+            || !self.screencasts.is_empty()
+            || !self.ext_copy_sessions.is_empty();
+        let focused = self
+            .state
+            .globals
+            .lock_seats()
+            .values()
+            .any(|s| s.get_output().id == self.id);
+        self.schedule.throttle(
+            captured || focused,
+            self.state.output_throttle_delay_nsec.get(),
+            frame_nsec,
+        )
+    }
+
     pub fn latched(&self, tearing: bool) {
+        self.screencopy_damage_latched
+            .set(self.screencopy_damage_seq.get());
+        self.stats.latched();
         self.schedule.latched();
         for listener in self.latch_event.iter() {
             listener.after_latch(self, tearing);
@@ -177,9 +472,13 @@ impl OutputNode {
     }
 
     pub fn vblank(&self) {
+        self.stats.vblanks.fetch_add(1);
         for listener in self.vblank_event.iter() {
             listener.after_vblank();
         }
+        for listener in self.passive_vblank_event.iter() {
+            listener.after_vblank();
+        }
         if self.global.connector.needs_vblank_emulation.get() {
             if self.vblank_event.has_listeners() {
                 self.global.connector.damage();
@@ -192,6 +491,297 @@ impl OutputNode {
         }
     }
 
+    pub fn uses_hardware_cursor(&self) -> bool {
+        // Color matrices are not applied to the cursor plane. Spanned outputs render the
+        // cursor in software since it can cross the seam between the panels. Mirrors
+        // show the cursor of their source output.
+        self.global.persistent.use_hardware_cursor.get()
+            && self.color_matrix().is_none()
+            && !self.is_spanned()
+            && self.mirror_source.is_none()
+    }
+
+    pub fn set_backend_hardware_cursor(&self, hc: Option<Rc<dyn HardwareCursor>>) {
+        if hc.is_none() && !self.hardware_cursor_unavailable_logged.replace(true) {
+            log::warn!(
+                "Connector {} does not support hardware cursors. Falling back to software cursors.",
+                self.global.connector.name,
+            );
+        }
+        self.backend_hardware_cursor.set(hc);
+        self.update_hardware_cursor();
+    }
+
+    pub fn update_hardware_cursor(&self) {
+        let mut hc = self.backend_hardware_cursor.get();
+        if !self.uses_hardware_cursor() {
+            hc = None;
+        }
+        self.schedule.set_hardware_cursor(&hc);
+        let active = hc.is_some();
+        if self.hardware_cursor.set(hc).is_some() != active {
+            for watcher in self.state.output_watchers.lock().values() {
+                watcher.send_hardware_cursor(self);
+            }
+        }
+    }
+
+    pub fn set_use_hardware_cursor(&self, use_hardware_cursor: bool) {
+        let persistent = &self.global.persistent;
+        if persistent.use_hardware_cursor.replace(use_hardware_cursor) == use_hardware_cursor {
+            return;
+        }
+        self.hardware_cursor_usage_changed();
+    }
+
+    fn hardware_cursor_usage_changed(&self) {
+        // Make the backend disable or re-enable the cursor plane.
+        if let Some(hc) = self.backend_hardware_cursor.get() {
+            hc.damage();
+        }
+        self.update_hardware_cursor();
+        self.state.refresh_hardware_cursors();
+        self.state.damage_hardware_cursor_user();
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom.get() > 1.0
+    }
+
+    pub fn set_zoom(&self, seat: SeatId, factor: f64) {
+        self.zoom_seat.set(Some(seat));
+        self.apply_zoom(factor);
+    }
+
+    pub fn clamp_zoom(&self) {
+        self.apply_zoom(self.zoom.get());
+    }
+
+    fn apply_zoom(&self, factor: f64) {
+        let factor = factor.clamp(1.0, self.state.max_zoom.get().max(1.0));
+        let old = self.zoom.replace(factor);
+        if old == factor {
+            return;
+        }
+        self.state.damage(self.global.pos.get());
+    }
+
+    /// Returns the area that should be shown if the output is zoomed.
+    ///
+    /// The area is centered on the pointer of the seat that zoomed the output unless this
+    /// would cause the area to extend beyond the output.
+    ///
+    /// Cursors are rendered unscaled at their positions on the output. Pointer input is
+    /// delivered to the content shown under the cursor, see [`Self::content_position`].
+    pub fn zoom_viewport(&self) -> Option<OutputZoom> {
+        if !self.is_zoomed() {
+            return None;
+        }
+        let factor = self.zoom.get();
+        let pos = self.global.pos.get();
+        let width = (pos.width() as f64 / factor).round() as i32;
+        let height = (pos.height() as f64 / factor).round() as i32;
+        let mut center = (pos.width() / 2, pos.height() / 2);
+        if let Some(id) = self.zoom_seat.get() {
+            for seat in self.state.globals.lock_seats().values() {
+                if seat.id() == id {
+                    let (x, y) = seat.pointer_cursor().position_int();
+                    center = pos.translate(x, y);
+                }
+            }
+        }
+        Some(OutputZoom {
+            factor,
+            x: (center.0 - width / 2).clamp(0, pos.width() - width),
+            y: (center.1 - height / 2).clamp(0, pos.height() - height),
+            magnifier: true,
+        })
+    }
+
+    /// Returns the absolute position of the content that is shown at an absolute
+    /// position on this output.
+    ///
+    /// This is the identity unless the output is zoomed.
+    pub fn content_position(&self, x: i32, y: i32) -> (i32, i32) {
+        let Some(zoom) = self.zoom_viewport() else {
+            return (x, y);
+        };
+        let pos = self.global.pos.get();
+        let (x, y) = pos.translate(x, y);
+        let (x, y) = zoom.content_position(x, y);
+        (pos.x1() + x, pos.y1() + y)
+    }
+
+    pub fn set_color_filter(&self, filter: Option<ColorMatrix>) {
+        let old = self.color_matrix();
+        self.global.persistent.color_filter.set(filter);
+        self.color_matrix_changed(old);
+    }
+
+    pub fn set_invert_colors(&self, invert: bool) {
+        let old = self.color_matrix();
+        self.global.persistent.invert_colors.set(invert);
+        self.color_matrix_changed(old);
+    }
+
+    pub fn toggle_invert_colors(&self) {
+        self.set_invert_colors(!self.global.persistent.invert_colors.get());
+    }
+
+    pub fn default_workspace_capture(&self) -> bool {
+        self.global
+            .persistent
+            .workspace_capture
+            .get()
+            .unwrap_or_else(|| self.state.default_workspace_capture.get())
+    }
+
+    pub fn set_title_bar_position(self: &Rc<Self>, position: TitleBarPosition) {
+        if self.title_bar_position.replace(position) == position {
+            return;
+        }
+        self.on_spaces_changed();
+        if self.node_visible() {
+            self.state.damage(self.global.pos.get());
+        }
+        self.state.tree_changed();
+    }
+
+    pub fn set_power(&self, on: bool) {
+        if self.power.replace(on) == on {
+            return;
+        }
+        self.global.connector.connector.set_power(on);
+        self.update_visible();
+        if on {
+            self.state.damage(self.global.pos.get());
+            self.state.refresh_hardware_cursors();
+        }
+    }
+
+    pub fn set_gamma_ramp(
+        &self,
+        red: &[u16],
+        green: &[u16],
+        blue: &[u16],
+    ) -> Result<(), GammaRampError> {
+        let connector = &self.global.connector.connector;
+        let Some(size) = connector.gamma_size() else {
+            return Err(GammaRampError::NotSupported);
+        };
+        for len in [red.len(), green.len(), blue.len()] {
+            if len != size {
+                return Err(GammaRampError::InvalidSize(len, size));
+            }
+        }
+        let ramp = Rc::new(GammaRamp {
+            red: red.to_vec(),
+            green: green.to_vec(),
+            blue: blue.to_vec(),
+        });
+        self.gamma_ramp.set(Some(ramp.clone()));
+        connector.set_gamma_ramp(Some(ramp));
+        Ok(())
+    }
+
+    pub fn reset_gamma_ramp(&self) {
+        if self.gamma_ramp.take().is_some() {
+            self.global.connector.connector.set_gamma_ramp(None);
+        }
+    }
+
+    /// Returns the y coordinates of the bar and of the line separating it from the
+    /// workspace, relative to the non-exclusive area.
+    pub fn title_bar_y_rel(&self) -> (i32, i32) {
+        let th = self.state.theme.sizes.title_height.get();
+        match self.title_bar_position.get() {
+            TitleBarPosition::Top => (0, th),
+            TitleBarPosition::Bottom => {
+                let height = self.non_exclusive_rect.get().height();
+                (height - th, height - th - 1)
+            }
+        }
+    }
+
+    /// Returns the height of the bar including the line separating it from the
+    /// workspace.
+    ///
+    /// If the title height is 0, the bar is hidden completely and this returns 0.
+    fn bar_height(&self) -> i32 {
+        match self.state.theme.sizes.title_height.get() {
+            0 => 0,
+            th => th + 1,
+        }
+    }
+
+    /// Returns the y coordinate of the workspace relative to the non-exclusive area.
+    pub fn workspace_y_rel(&self) -> i32 {
+        match self.title_bar_position.get() {
+            TitleBarPosition::Top => self.bar_height(),
+            TitleBarPosition::Bottom => 0,
+        }
+    }
+
+    /// Returns whether the y coordinate, relative to the non-exclusive area, lies in the
+    /// bar or in the line below or above it.
+    fn title_bar_contains_rel(&self, y: i32) -> bool {
+        let bh = self.bar_height();
+        match self.title_bar_position.get() {
+            TitleBarPosition::Top => y < bh,
+            TitleBarPosition::Bottom => y >= self.non_exclusive_rect.get().height() - bh,
+        }
+    }
+
+    pub fn set_default_workspace_capture(&self, capture: bool) {
+        self.global.persistent.workspace_capture.set(Some(capture));
+        for ws in self.workspaces.iter() {
+            ws.may_capture.set(capture);
+            ws.update_has_captures();
+        }
+    }
+
+    fn color_matrix_changed(&self, old: Option<ColorMatrix>) {
+        let new = self.color_matrix();
+        if old == new {
+            return;
+        }
+        if old.is_some() != new.is_some() {
+            self.hardware_cursor_usage_changed();
+        }
+        self.state.damage(self.global.pos.get());
+    }
+
+    /// Returns the matrix that is applied to the composited contents of the output.
+    ///
+    /// Inversion is applied after the color filter.
+    pub fn color_matrix(&self) -> Option<ColorMatrix> {
+        let persistent = &self.global.persistent;
+        let filter = persistent.color_filter.get();
+        if !persistent.invert_colors.get() {
+            return filter;
+        }
+        let invert = ColorMatrix::INVERT;
+        Some(match filter {
+            Some(filter) => invert.mul(&filter),
+            None => invert,
+        })
+    }
+
+    pub fn hardware_cursor_changed(&self) {
+        let Some(hc) = self.hardware_cursor.get() else {
+            return;
+        };
+        if self.schedule.defer_cursor_updates() {
+            self.schedule.hardware_cursor_changed();
+        } else if self.global.persistent.vblank_cursor_updates.get() {
+            if !self.hardware_cursor_vblank_pending.replace(true) {
+                self.cursor_vblank_listener.attach(&self.vblank_event);
+            }
+        } else {
+            hc.damage();
+        }
+    }
+
     pub fn presented(
         &self,
         tv_sec: u64,
@@ -201,6 +791,8 @@ impl OutputNode {
         flags: u32,
         vrr: bool,
     ) {
+        self.stats
+            .presented(refresh, seq, flags, self.flip_margin_ns.get());
         for listener in self.presentation_event.iter() {
             listener.presented(self, tv_sec, tv_nsec, refresh, seq, flags, vrr);
         }
@@ -239,6 +831,15 @@ impl OutputNode {
         self.screencast_changed();
     }
 
+    /// Damages the output if one of its screencasts only captures the cursor.
+    ///
+    /// Hardware cursor movements do not otherwise cause the output to be rendered.
+    pub fn damage_cursor_screencasts(&self) {
+        if self.screencasts.lock().values().any(|sc| sc.cursor_only()) {
+            self.global.connector.damage();
+        }
+    }
+
     pub fn screencast_changed(&self) {
         for ws in self.workspaces.iter() {
             ws.update_has_captures();
@@ -256,11 +857,64 @@ impl OutputNode {
         y_off: i32,
         size: Option<(i32, i32)>,
     ) {
+        for mirror in self.mirrors.lock().values() {
+            if !mirror.global.destroyed.get() {
+                mirror.add_screencopy_damage(mirror.global.pos.get());
+                mirror.global.connector.damage();
+            }
+        }
         if let Some(workspace) = self.workspace.get() {
             if !workspace.may_capture.get() {
                 return;
             }
         }
+        if self.screencopies.is_empty()
+            && self.screencasts.is_empty()
+            && self.ext_copy_sessions.is_empty()
+        {
+            return;
+        }
+        if self.is_zoomed() || self.shows_capture_blocked_toplevel() {
+            match self.render_capture_texture(render_hardware_cursor) {
+                Ok(tex) => self.copy_texture_to_captures(
+                    &tex,
+                    None,
+                    &AcquireSync::Implicit,
+                    ReleaseSync::Implicit,
+                    render_hardware_cursor,
+                    0,
+                    0,
+                    None,
+                ),
+                Err(e) => {
+                    log::error!("Could not render output for capture: {}", ErrorFmt(e));
+                }
+            }
+            return;
+        }
+        self.copy_texture_to_captures(
+            tex,
+            resv,
+            acquire_sync,
+            release_sync,
+            render_hardware_cursor,
+            x_off,
+            y_off,
+            size,
+        );
+    }
+
+    fn copy_texture_to_captures(
+        &self,
+        tex: &Rc<dyn GfxTexture>,
+        resv: Option<&Rc<dyn BufferResv>>,
+        acquire_sync: &AcquireSync,
+        release_sync: ReleaseSync,
+        render_hardware_cursor: bool,
+        x_off: i32,
+        y_off: i32,
+        size: Option<(i32, i32)>,
+    ) {
         self.perform_wlr_screencopies(
             tex,
             resv,
@@ -299,6 +953,230 @@ impl OutputNode {
         }
     }
 
+    /// Returns whether a toplevel that blocks captures is visible on this output.
+    fn shows_capture_blocked_toplevel(&self) -> bool {
+        let pos = self.global.pos.get();
+        self.state.toplevels.lock().values().any(|tl| {
+            tl.upgrade().is_some_and(|tl| {
+                let data = tl.tl_data();
+                data.visible.get()
+                    && data.capture_blocked()
+                    && tl.tl_as_node().node_absolute_position().intersects(&pos)
+            })
+        })
+    }
+
+    fn offscreen_buffer<'a>(
+        &self,
+        buffer: &'a mut Option<OutputCaptureBuffer>,
+        width: i32,
+        height: i32,
+    ) -> Result<&'a mut OutputCaptureBuffer, OutputCaptureError> {
+        let Some(ctx) = self.state.render_ctx.get() else {
+            return Err(OutputCaptureError::NoRenderContext);
+        };
+        if buffer
+            .as_ref()
+            .is_some_and(|b| (b.width, b.height) != (width, height))
+        {
+            *buffer = None;
+        }
+        Ok(match buffer {
+            Some(b) => b,
+            buffer => {
+                let formats = ctx.formats();
+                let Some(format) = formats.get(&XRGB8888.drm) else {
+                    return Err(OutputCaptureError::XRGB8888);
+                };
+                let modifiers: Vec<_> = format
+                    .write_modifiers
+                    .keys()
+                    .filter(|m| format.read_modifiers.contains(*m))
+                    .copied()
+                    .collect();
+                if modifiers.is_empty() {
+                    return Err(OutputCaptureError::Modifiers);
+                }
+                let bo = ctx.allocator().create_bo(
+                    &self.state.dma_buf_ids,
+                    width,
+                    height,
+                    XRGB8888,
+                    &modifiers,
+                    BO_USE_RENDERING,
+                )?;
+                let img = ctx.clone().dmabuf_img(bo.dmabuf())?;
+                buffer.insert(OutputCaptureBuffer {
+                    width,
+                    height,
+                    _bo: bo,
+                    fb: img.clone().to_framebuffer()?,
+                    tex: img.to_texture()?,
+                })
+            }
+        })
+    }
+
+    /// Applies the color matrix of this output to the composited contents of a render
+    /// pass.
+    ///
+    /// The contents are first rendered to an offscreen buffer. Afterwards the pass only
+    /// copies this buffer with the color matrix applied.
+    pub fn apply_color_matrix(&self, pass: &mut GfxRenderPass, physical_size: (i32, i32)) {
+        let Some(matrix) = self.color_matrix() else {
+            return;
+        };
+        if let Err(e) = self.composite_color_matrix(pass, physical_size, &matrix) {
+            log::error!("Could not composite the output contents: {}", ErrorFmt(e));
+            pass.apply_color_matrix(&matrix);
+        }
+    }
+
+    fn composite_color_matrix(
+        &self,
+        pass: &mut GfxRenderPass,
+        (width, height): (i32, i32),
+        matrix: &ColorMatrix,
+    ) -> Result<(), OutputCaptureError> {
+        let mut buffer = self.color_filter_buffer.borrow_mut();
+        let buffer = self.offscreen_buffer(&mut buffer, width, height)?;
+        buffer
+            .fb
+            .perform_render_pass(AcquireSync::Implicit, ReleaseSync::Implicit, pass)?;
+        let mut ops = vec![];
+        let scale = Scale::from_int(1);
+        let mut renderer = buffer.fb.renderer_base(&mut ops, scale, Transform::None);
+        renderer.render_texture(
+            &buffer.tex,
+            None,
+            0,
+            0,
+            None,
+            None,
+            scale,
+            None,
+            None,
+            AcquireSync::Implicit,
+            ReleaseSync::Implicit,
+        );
+        *pass = GfxRenderPass {
+            ops,
+            clear: Some(Color::SOLID_BLACK),
+        };
+        pass.apply_color_matrix(matrix);
+        Ok(())
+    }
+
+    /// Renders the output with all toplevels that block captures blacked out.
+    ///
+    /// The zoom of the output is not applied. Captures always show the unzoomed
+    /// contents.
+    fn render_capture_texture(
+        &self,
+        render_hardware_cursor: bool,
+    ) -> Result<Rc<dyn GfxTexture>, OutputCaptureError> {
+        let mode = self.global.mode.get();
+        let mut buffer = self.capture_buffer.borrow_mut();
+        let buffer = self.offscreen_buffer(&mut buffer, mode.width, mode.height)?;
+        let mut pass = buffer.fb.create_render_pass(
+            self,
+            &self.state,
+            Some(self.global.pos.get()),
+            self.global.persistent.scale.get(),
+            true,
+            !render_hardware_cursor,
+            self.has_fullscreen(),
+            self.global.persistent.transform.get(),
+            None,
+            None,
+            true,
+        );
+        self.apply_color_matrix(&mut pass, (mode.width, mode.height));
+        buffer
+            .fb
+            .perform_render_pass(AcquireSync::Implicit, ReleaseSync::Implicit, &pass)?;
+        Ok(buffer.tex.clone())
+    }
+
+    /// Remembers damage of this output for screencopies.
+    ///
+    /// If too many rectangles are stored, the oldest ones are merged. This over-estimates
+    /// the damage of buffers that were last copied a long time ago.
+    pub fn add_screencopy_damage(&self, rect: Rect) {
+        let seq = self.screencopy_damage_seq.fetch_add(1) + 1;
+        let mut damage = self.screencopy_damage.borrow_mut();
+        if damage.len() >= MAX_SCREENCOPY_DAMAGE {
+            if let Some((_, oldest)) = damage.pop_front() {
+                if let Some((_, next)) = damage.front_mut() {
+                    *next = next.union(oldest);
+                }
+            }
+        }
+        damage.push_back((seq, rect));
+    }
+
+    fn screencopy_damage_mark(
+        &self,
+        capture: &ZwlrScreencopyFrameV1,
+        render_hardware_cursors: bool,
+        x_off: i32,
+        y_off: i32,
+        size: Option<(i32, i32)>,
+    ) -> ScreencopyDamageMark {
+        let mode = self.global.mode.get();
+        ScreencopyDamageMark {
+            output: self.id,
+            capture: capture.rect,
+            pos: self.global.pos.get(),
+            mode: (mode.width, mode.height),
+            transform: self.global.persistent.transform.get(),
+            scale: self.global.persistent.scale.get(),
+            offset: (x_off, y_off),
+            size,
+            seq: self.screencopy_damage_latched.get(),
+            cursor: {
+                let hardware = match render_hardware_cursors {
+                    true => self.state.capture_cursor_rect(),
+                    false => None,
+                };
+                let software = self.state.software_cursor_rect();
+                match (hardware, software) {
+                    (Some(h), Some(s)) => Some(h.union(s)),
+                    (h, s) => h.or(s),
+                }
+            },
+        }
+    }
+
+    /// Returns the damage of a buffer whose previous contents were copied at `prev`.
+    ///
+    /// The damage is in buffer coordinates. Returns `None` if the damage is unknown.
+    fn screencopy_damage(
+        &self,
+        prev: Option<ScreencopyDamageMark>,
+        next: &ScreencopyDamageMark,
+    ) -> Option<Rect> {
+        let prev = prev?;
+        if !prev.same_geometry(next) {
+            return None;
+        }
+        let mut damage = None::<Rect>;
+        let mut add = |rect: Rect| {
+            if let Some(rect) = screencopy_damage_to_buffer(next, rect) {
+                damage = Some(damage.map_or(rect, |d| d.union(rect)));
+            }
+        };
+        for &(seq, rect) in &*self.screencopy_damage.borrow() {
+            if seq > prev.seq {
+                add(rect);
+            }
+        }
+        for cursor in [prev.cursor, next.cursor].into_iter().flatten() {
+            add(cursor);
+        }
+        Some(damage.unwrap_or_default())
+    }
+
     pub fn perform_wlr_screencopies(
         &self,
         tex: &Rc<dyn GfxTexture>,
@@ -314,19 +1192,35 @@ impl OutputNode {
             return;
         }
         let now = self.state.now();
+        let mut undamaged = vec![];
         for capture in self.screencopies.lock().drain_values() {
             let wl_buffer = match capture.buffer.take() {
                 Some(b) => b,
                 _ => {
-                    log::warn!("Capture frame is pending but has no buffer attached");
-                    capture.send_failed();
+                    capture.handle_missing_buffer();
                     continue;
                 }
             };
+            capture.client.screencopies_without_buffer.set(0);
             if wl_buffer.destroyed() {
                 capture.send_failed();
                 continue;
             }
+            let mark = self.screencopy_damage_mark(
+                &capture,
+                render_hardware_cursors || wl_buffer.is_shm(),
+                x_off,
+                y_off,
+                size,
+            );
+            let damage = self.screencopy_damage(wl_buffer.screencopy_damage_mark.take(), &mark);
+            if capture.with_damage.get() && damage.is_some_and(|d| d.is_empty()) {
+                // The buffer already contains the current contents. Wait for damage.
+                wl_buffer.screencopy_damage_mark.set(Some(mark));
+                capture.buffer.set(Some(wl_buffer));
+                undamaged.push(capture);
+                continue;
+            }
             let mut ready = true;
             if let Some(storage) = wl_buffer.storage.borrow_mut().deref() {
                 match storage {
@@ -344,10 +1238,14 @@ impl OutputNode {
                             wl_buffer.format,
                             self.global.persistent.transform.get(),
                             self.global.persistent.scale.get(),
+                            damage,
                         );
                         match res {
                             Ok(p) => {
                                 ready = p.is_none();
+                                if !ready {
+                                    capture.pending_buffer.set(Some(wl_buffer.clone()));
+                                }
                                 capture.pending.set(p);
                             }
                             Err(e) => {
@@ -391,13 +1289,19 @@ impl OutputNode {
                     }
                 }
             }
+            wl_buffer.screencopy_damage_mark.set(Some(mark));
             if capture.with_damage.get() {
-                capture.send_damage();
+                let full = capture.rect.at_point(0, 0);
+                capture.send_damage(damage.unwrap_or(full));
             }
             if ready {
                 capture.send_ready(now.0.tv_sec as _, now.0.tv_nsec as _);
             }
         }
+        for capture in undamaged {
+            self.screencopies
+                .set((capture.client.id, capture.id), capture);
+        }
         self.screencast_changed();
     }
 
@@ -411,9 +1315,20 @@ impl OutputNode {
         self.render_data.borrow_mut().titles.clear();
         self.lock_surface.take();
         self.jay_outputs.clear();
+        self.presentation_feeds.clear();
+        self.frame_timings.clear();
         self.screencasts.clear();
         self.screencopies.clear();
         self.ext_copy_sessions.clear();
+        if let Some(secondary) = self.span_secondary.take() {
+            secondary.span_primary.take();
+            secondary.clear();
+        }
+        self.mirror_source.take();
+        for mirror in self.mirrors.lock().drain_values() {
+            mirror.mirror_source.take();
+            mirror.clear();
+        }
     }
 
     pub fn on_spaces_changed(self: &Rc<Self>) {
@@ -427,18 +1342,53 @@ impl OutputNode {
     }
 
     pub fn set_preferred_scale(self: &Rc<Self>, scale: Scale) {
+        if !self.replace_scale(scale) {
+            return;
+        }
+        let rect = self.calculate_extents();
+        self.change_extents_(&rect);
+        self.scale_changed();
+    }
+
+    /// Returns the scale that will be used if `scale` is requested.
+    pub fn effective_scale(&self, scale: Scale) -> Scale {
+        match self.max_scale.get() {
+            Some(max) if scale > max => max,
+            _ => scale,
+        }
+    }
+
+    /// Updates the scale without changing the extents.
+    ///
+    /// Returns whether the scale changed. If so, the caller must update the extents
+    /// and then call [`Self::scale_changed`].
+    fn replace_scale(self: &Rc<Self>, scale: Scale) -> bool {
+        self.requested_scale.set(scale);
+        let effective = self.effective_scale(scale);
+        if effective != scale {
+            log::info!(
+                "Clamping scale of output {} from {} to {}",
+                self.global.connector.name,
+                scale,
+                effective,
+            );
+        }
+        let scale = effective;
         let old_scale = self.global.persistent.scale.replace(scale);
         if scale == old_scale {
-            return;
+            return false;
         }
+        self.leave_span();
         let legacy_scale = scale.round_up();
         if self.global.legacy_scale.replace(legacy_scale) != legacy_scale {
             self.global.send_mode();
         }
         self.state.remove_output_scale(old_scale);
         self.state.add_output_scale(scale);
-        let rect = self.calculate_extents();
-        self.change_extents_(&rect);
+        true
+    }
+
+    fn scale_changed(self: &Rc<Self>) {
         let mut visitor = SurfaceSendPreferredScaleVisitor;
         self.node_visit_children(&mut visitor);
         for ws in self.workspaces.iter() {
@@ -446,20 +1396,46 @@ impl OutputNode {
                 stacked.deref().clone().node_visit(&mut visitor);
             }
         }
+        for sc in self.screencasts.lock().values() {
+            sc.schedule_realloc_or_reconfigure();
+        }
+        for tl in self.state.toplevels.lock().values() {
+            if let Some(tl) = tl.upgrade() {
+                let data = tl.tl_data();
+                if data.output().id == self.id {
+                    data.pixel_size_changed();
+                }
+            }
+        }
         self.schedule_update_render_data();
     }
 
+    /// Sets the maximum scale of this output.
+    ///
+    /// Larger scales requested via [`Self::set_preferred_scale`] are clamped to this
+    /// value.
+    pub fn set_max_scale(self: &Rc<Self>, max: Option<Scale>) {
+        self.max_scale.set(max);
+        self.global.persistent.max_scale.set(max);
+        self.set_preferred_scale(self.requested_scale.get());
+    }
+
     pub fn schedule_update_render_data(self: &Rc<Self>) {
         if !self.update_render_data_scheduled.replace(true) {
             self.state.pending_output_render_data.push(self.clone());
         }
     }
 
-    fn update_render_data_phase1(self: &Rc<Self>) -> Rc<AsyncEvent> {
+    fn update_render_data_phase1(self: &Rc<Self>) -> Option<Rc<AsyncEvent>> {
         let on_completed = Rc::new(OnDropEvent::default());
         let Some(ctx) = self.state.render_ctx.get() else {
-            return on_completed.event();
+            return Some(on_completed.event());
         };
+        let game_mode_active = self.game_mode_eligible();
+        self.set_game_mode_active(game_mode_active);
+        if game_mode_active {
+            return None;
+        }
         let font = self.state.theme.font.get();
         let theme = &self.state.theme;
         let th = theme.sizes.title_height.get();
@@ -484,8 +1460,9 @@ impl OutputNode {
             tex.schedule_render_fitting(
                 on_completed.clone(),
                 Some(texture_height),
+                None,
                 &font,
-                &ws.name,
+                &ws.name.get(),
                 tc,
                 false,
                 scale,
@@ -494,23 +1471,29 @@ impl OutputNode {
         let mut rd = self.render_data.borrow_mut();
         let tex = rd.status.get_or_insert_with(|| OutputStatus {
             tex_x: 0,
+            tex_y: 0,
             tex: TextTexture::new(&self.state.cpu_worker, &ctx),
         });
         let status = self.status.get();
         let tc = self.state.theme.colors.bar_text.get();
+        let mut max_width = self.status_max_width.get();
+        if let (Some(width), Some(scale)) = (&mut max_width, scale) {
+            *width = (*width as f64 * scale).round() as _;
+        }
         tex.tex.schedule_render_fitting(
             on_completed.clone(),
             Some(texture_height),
+            max_width,
             &font,
             &status,
             tc,
             true,
             scale,
         );
-        on_completed.event()
+        Some(on_completed.event())
     }
 
-    fn update_render_data_phase2(&self) {
+    fn update_render_data_phase2(self: &Rc<Self>) {
         let mut rd = self.render_data.borrow_mut();
         rd.titles.clear();
         rd.inactive_workspaces.clear();
@@ -529,7 +1512,11 @@ impl OutputNode {
         let active_id = self.workspace.get().map(|w| w.id);
         let non_exclusive_rect = self.non_exclusive_rect.get();
         let output_width = non_exclusive_rect.width();
-        rd.underline = Rect::new_sized(0, th, output_width, 1).unwrap();
+        let (bar_y, underline_y) = self.title_bar_y_rel();
+        rd.underline = match th {
+            0 => Rect::default(),
+            _ => Rect::new_sized(0, underline_y, output_width, 1).unwrap(),
+        };
         for ws in self.workspaces.iter() {
             let mut title_width = th;
             let title = &*ws.title_texture.borrow();
@@ -552,13 +1539,13 @@ impl OutputNode {
                         x1: pos,
                         x2: pos + title_width,
                         tex_x: x,
-                        tex_y: 0,
+                        tex_y: bar_y,
                         tex: texture,
                         ws: ws.deref().clone(),
                     });
                 }
             }
-            let rect = Rect::new_sized(pos, 0, title_width, th).unwrap();
+            let rect = Rect::new_sized(pos, bar_y, title_width, th).unwrap();
             if Some(ws.id) == active_id {
                 rd.active_workspace = Some(OutputWorkspaceRenderData {
                     rect,
@@ -576,6 +1563,12 @@ impl OutputNode {
             }
             pos += title_width;
         }
+        let status_max_width = Some((self.tray_start_rel.get() - pos - 1).max(0));
+        if self.status_max_width.replace(status_max_width) != status_max_width {
+            // The status has been rendered before the titles were laid out. Render it
+            // again so that it does not overlap the titles.
+            self.schedule_update_render_data();
+        }
         if let Some(status) = &mut rd.status {
             if let Err(e) = status.tex.flip() {
                 log::error!("Could not render status: {}", ErrorFmt(e));
@@ -587,12 +1580,13 @@ impl OutputNode {
                 }
                 let pos = self.tray_start_rel.get() - width - 1;
                 status.tex_x = pos;
+                status.tex_y = bar_y;
             }
         }
         if self.title_visible.get() {
             let title_rect = Rect::new_sized(
                 non_exclusive_rect.x1(),
-                non_exclusive_rect.y1(),
+                non_exclusive_rect.y1() + bar_y,
                 non_exclusive_rect.width(),
                 th,
             )
@@ -601,6 +1595,23 @@ impl OutputNode {
         }
     }
 
+    pub fn update_focus_indicator(&self) {
+        let focused = self.state.show_focused_output.get()
+            && self
+                .state
+                .cursor_user_groups
+                .lock()
+                .values()
+                .any(|g| g.latest_output().id == self.id);
+        if self.focus_indicator.replace(focused) != focused && self.title_visible.get() {
+            let rect = self.non_exclusive_rect.get();
+            let (_, underline_y) = self.title_bar_y_rel();
+            self.state.damage(
+                Rect::new_sized(rect.x1(), rect.y1() + underline_y, rect.width(), 1).unwrap(),
+            );
+        }
+    }
+
     pub fn ensure_workspace(self: &Rc<Self>) -> Rc<WorkspaceNode> {
         if let Some(ws) = self.workspace.get() {
             if !ws.is_dummy {
@@ -623,20 +1634,75 @@ impl OutputNode {
         self.create_workspace(&name)
     }
 
+    /// Moves `steps` workspaces to the right or, if `steps` is negative, to the left and
+    /// focuses the new workspace.
+    pub fn step_workspace(self: &Rc<Self>, seat: &Rc<WlSeatGlobal>, steps: i32) {
+        if steps == 0 {
+            return;
+        }
+        let ws = match self.workspace.get() {
+            Some(ws) => ws,
+            _ => return,
+        };
+        let mut ws = 'ws: {
+            for r in self.workspaces.iter() {
+                if r.id == ws.id {
+                    break 'ws r;
+                }
+            }
+            return;
+        };
+        let wrap = self.workspace_scroll_wrap.get();
+        let skip_empty = self.workspace_scroll_skip_empty.get();
+        let advance = |ws: &NodeRef<Rc<WorkspaceNode>>| {
+            let mut new = if steps < 0 { ws.prev() } else { ws.next() };
+            if new.is_none() && wrap {
+                new = if steps < 0 {
+                    self.workspaces.last()
+                } else {
+                    self.workspaces.first()
+                };
+            }
+            new
+        };
+        // With wrapping enabled, every workspace is visited at most once per step. This
+        // ensures that we terminate even if all workspaces are empty.
+        let num_workspaces = self.workspaces.iter().count();
+        'steps: for _ in 0..steps.abs() {
+            let mut candidate = ws.clone();
+            for _ in 0..num_workspaces {
+                candidate = match advance(&candidate) {
+                    Some(n) => n,
+                    None => break 'steps,
+                };
+                if !skip_empty || !candidate.is_empty() {
+                    ws = candidate;
+                    continue 'steps;
+                }
+            }
+            break;
+        }
+        if !self.show_workspace(&ws) {
+            return;
+        }
+        ws.flush_jay_workspaces();
+        ws.deref()
+            .clone()
+            .node_do_focus(seat, Direction::Unspecified);
+        self.schedule_update_render_data();
+        self.state.tree_changed();
+    }
+
     pub fn show_workspace(&self, ws: &Rc<WorkspaceNode>) -> bool {
         let mut seats = SmallVec::new();
         if let Some(old) = self.workspace.set(Some(ws.clone())) {
             if old.id == ws.id {
                 return false;
             }
+            move_sticky_windows(&old, ws);
             collect_kb_foci2(old.clone(), &mut seats);
             if old.is_empty() {
-                for jw in old.jay_workspaces.lock().values() {
-                    jw.send_destroyed();
-                    jw.workspace.set(None);
-                }
-                old.clear();
-                self.state.workspaces.remove(&old.name);
+                self.destroy_workspace(&old);
             } else {
                 old.set_visible(false);
                 old.flush_jay_workspaces();
@@ -656,6 +1722,53 @@ impl OutputNode {
         true
     }
 
+    fn destroy_workspace(&self, ws: &WorkspaceNode) {
+        for jw in ws.jay_workspaces.lock().values() {
+            jw.send_destroyed();
+            jw.workspace.set(None);
+        }
+        ws.clear();
+        self.state.workspaces.remove(&*ws.name.get());
+    }
+
+    /// Destroys `ws` after moving its toplevels to an adjacent workspace.
+    ///
+    /// Does nothing if `ws` is the only workspace of this output.
+    pub fn close_workspace(self: &Rc<Self>, ws: &Rc<WorkspaceNode>) {
+        let target = 'target: {
+            for r in self.workspaces.iter() {
+                if r.id == ws.id {
+                    match r.prev().or_else(|| r.next()) {
+                        Some(t) => break 'target t.deref().clone(),
+                        _ => return,
+                    }
+                }
+            }
+            return;
+        };
+        let mut seats = SmallVec::new();
+        collect_kb_foci2(ws.clone(), &mut seats);
+        ws.move_toplevels_to(&target);
+        if !ws.is_empty() {
+            log::warn!(
+                "Workspace {} is not empty after moving its toplevels",
+                ws.name.get()
+            );
+            return;
+        }
+        if self.workspace.get().map(|w| w.id) == Some(ws.id) {
+            self.show_workspace(&target);
+        } else {
+            self.destroy_workspace(ws);
+        }
+        for seat in seats {
+            target.clone().node_do_focus(&seat, Direction::Unspecified);
+        }
+        target.flush_jay_workspaces();
+        self.schedule_update_render_data();
+        self.state.tree_changed();
+    }
+
     pub fn create_workspace(self: &Rc<Self>, name: &str) -> Rc<WorkspaceNode> {
         let ws = Rc::new(WorkspaceNode {
             id: self.state.node_ids.next(),
@@ -666,19 +1779,23 @@ impl OutputNode {
             container: Default::default(),
             stacked: Default::default(),
             seat_state: Default::default(),
-            name: name.to_string(),
+            name: CloneCell::new(Rc::new(name.to_string())),
             output_link: Default::default(),
             visible: Cell::new(false),
             fullscreen: Default::default(),
             visible_on_desired_output: Cell::new(false),
             desired_output: CloneCell::new(self.global.output_id.clone()),
+            fallback_output: Default::default(),
             jay_workspaces: Default::default(),
-            may_capture: self.state.default_workspace_capture.clone(),
+            may_capture: Cell::new(self.default_workspace_capture()),
             has_capture: Cell::new(false),
             title_texture: Default::default(),
             attention_requests: Default::default(),
             render_highlight: Default::default(),
         });
+        if let Some(affinity) = self.state.workspace_output_affinities.get(name) {
+            ws.set_output_affinity(Some(&affinity));
+        }
         ws.update_has_captures();
         *ws.output_link.borrow_mut() = Some(self.workspaces.add_last(ws.clone()));
         self.state.workspaces.set(name.to_string(), ws.clone());
@@ -698,9 +1815,49 @@ impl OutputNode {
         ws
     }
 
+    pub fn move_workspace_here(self: &Rc<Self>, ws: &Rc<WorkspaceNode>) {
+        if ws.is_dummy || self.is_dummy {
+            return;
+        }
+        let source = ws.output.get();
+        if source.id == self.id {
+            return;
+        }
+        let link = match &*ws.output_link.borrow() {
+            None => return,
+            Some(l) => l.to_ref(),
+        };
+        let mut seats = SmallVec::new();
+        let was_visible = ws.visible.get();
+        if was_visible {
+            collect_kb_foci2(ws.clone(), &mut seats);
+        }
+        let config = WsMoveConfig {
+            make_visible_always: was_visible,
+            make_visible_if_empty: true,
+            source_is_destroyed: false,
+            before: None,
+        };
+        move_ws_to_output(&link, self, config);
+        ws.desired_output.set(self.global.output_id.clone());
+        ws.change_extents(&self.workspace_rect.get());
+        if let Some(fs) = ws.fullscreen.get() {
+            fs.tl_change_extents(&self.global.pos.get());
+        }
+        if !source.is_dummy && source.workspace.is_none() {
+            source.generate_workspace().flush_jay_workspaces();
+        }
+        if ws.visible.get() {
+            for seat in seats {
+                ws.clone().node_do_focus(&seat, Direction::Unspecified);
+            }
+        }
+        self.state.tree_changed();
+    }
+
     pub fn update_rects(self: &Rc<Self>) {
         let rect = self.global.pos.get();
-        let th = self.state.theme.sizes.title_height.get();
+        let bh = self.bar_height();
         let exclusive = self.exclusive_zones.get();
         let y1 = rect.y1() + exclusive.top;
         let x2 = rect.x2() - exclusive.right;
@@ -716,8 +1873,11 @@ impl OutputNode {
             width,
             height,
         ));
-        let y1 = y1 + th + 1;
-        let height = (y2 - y1).max(0);
+        let height = (height - bh).max(0);
+        let y1 = match self.title_bar_position.get() {
+            TitleBarPosition::Top => y1 + bh,
+            TitleBarPosition::Bottom => y1,
+        };
         self.workspace_rect
             .set(Rect::new_sized_unchecked(x1, y1, width, height));
         self.update_tray_positions();
@@ -733,6 +1893,311 @@ impl OutputNode {
         self.change_extents_(&rect);
     }
 
+    /// Sets a fixed flip margin for this output.
+    ///
+    /// If `None`, the margin is adjusted automatically based on missed page flips.
+    pub fn set_flip_margin(&self, margin_ns: Option<u64>) {
+        self.global.persistent.flip_margin_ns.set(margin_ns);
+        self.global.connector.connector.set_flip_margin(margin_ns);
+        self.global.connector.damage();
+    }
+
+    /// Sets the minimum refresh rate while VRR is enabled.
+    pub fn set_vrr_min_refresh(&self, hz: Option<u32>) {
+        self.global.persistent.vrr_min_refresh_hz.set(hz);
+        self.apply_vrr_min_refresh();
+    }
+
+    pub fn apply_vrr_min_refresh(&self) {
+        let mut hz = self.global.persistent.vrr_min_refresh_hz.get();
+        if let Some(hz) = &mut hz {
+            let max = self.global.mode.get().refresh_rate_millihz / 1000;
+            if max > 0 {
+                *hz = (*hz).min(max);
+            }
+        }
+        self.schedule.set_min_refresh_hz(hz);
+    }
+
+    pub fn set_background(&self, color: Option<Color>) {
+        if self.global.persistent.background.replace(color) != color {
+            self.state.damage(self.global.pos.get());
+        }
+    }
+
+    pub fn is_spanned(&self) -> bool {
+        self.span_primary.is_some() || self.span_secondary.is_some()
+    }
+
+    /// If this output shows the right half of a spanned output, returns the primary
+    /// output and the area of it that is shown on this output.
+    pub fn span_viewport(&self) -> Option<(Rc<OutputNode>, OutputZoom)> {
+        let primary = self.span_primary.get()?;
+        let (x, _) = primary.own_logical_size();
+        Some((
+            primary,
+            OutputZoom {
+                factor: 1.0,
+                x,
+                y: 0,
+                magnifier: false,
+            },
+        ))
+    }
+
+    /// Combines this output and `secondary` into a single logical output.
+    ///
+    /// The secondary output is removed from the tree and shows the right half of
+    /// this output. Its workspaces are moved to this output and its `wl_output`
+    /// global is withdrawn until the span is dissolved.
+    pub fn span(self: &Rc<Self>, secondary: &Rc<OutputNode>) -> Result<(), OutputSpanError> {
+        if self.id == secondary.id {
+            return Err(OutputSpanError::SameOutput);
+        }
+        if self.is_spanned() || secondary.is_spanned() {
+            return Err(OutputSpanError::AlreadySpanned);
+        }
+        if self.is_mirroring_or_mirrored() || secondary.is_mirroring_or_mirrored() {
+            return Err(OutputSpanError::Mirrored);
+        }
+        if self.global.mode.get() != secondary.global.mode.get() {
+            return Err(OutputSpanError::ModeMismatch);
+        }
+        if self.global.persistent.scale.get() != secondary.global.persistent.scale.get() {
+            return Err(OutputSpanError::ScaleMismatch);
+        }
+        if self.global.persistent.transform.get() != secondary.global.persistent.transform.get() {
+            return Err(OutputSpanError::TransformMismatch);
+        }
+        self.state
+            .root
+            .outputs
+            .remove(&secondary.global.connector.connector.id());
+        let _ = self.state.remove_global(&secondary.global);
+        self.span_secondary.set(Some(secondary.clone()));
+        secondary.span_primary.set(Some(self.clone()));
+        for ws in secondary.workspaces.iter() {
+            let config = WsMoveConfig {
+                make_visible_always: false,
+                make_visible_if_empty: false,
+                source_is_destroyed: true,
+                before: None,
+            };
+            move_ws_to_output(&ws, self, config);
+        }
+        for seat in self.state.globals.seats.lock().values() {
+            seat.cursor_group().output_disconnected(secondary, self);
+        }
+        self.change_extents_(&self.calculate_extents());
+        self.hardware_cursor_usage_changed();
+        secondary.hardware_cursor_usage_changed();
+        self.schedule_update_render_data();
+        Ok(())
+    }
+
+    /// Removes the secondary output from the span in which this output is the primary
+    /// output without adding the secondary output back to the tree.
+    pub fn detach_span_secondary(self: &Rc<Self>) -> Option<Rc<OutputNode>> {
+        let secondary = self.span_secondary.take()?;
+        secondary.span_primary.take();
+        self.change_extents_(&self.calculate_extents());
+        self.hardware_cursor_usage_changed();
+        self.schedule_update_render_data();
+        Some(secondary)
+    }
+
+    /// Dissolves the span in which this output is the primary output.
+    pub fn unspan(self: &Rc<Self>) {
+        let Some(secondary) = self.detach_span_secondary() else {
+            return;
+        };
+        self.state
+            .root
+            .outputs
+            .set(secondary.global.connector.connector.id(), secondary.clone());
+        self.state.add_global(&secondary.global);
+        let pos = self.global.pos.get();
+        secondary.set_position(pos.x2(), pos.y1());
+        let workspaces: Vec<_> = self
+            .workspaces
+            .iter()
+            .filter(|ws| ws.desired_output.get() == secondary.global.output_id)
+            .collect();
+        for ws in workspaces {
+            let config = WsMoveConfig {
+                make_visible_always: false,
+                make_visible_if_empty: true,
+                source_is_destroyed: false,
+                before: None,
+            };
+            move_ws_to_output(&ws, &secondary, config);
+        }
+        secondary.ensure_workspace();
+        secondary.hardware_cursor_usage_changed();
+        secondary.schedule_update_render_data();
+        self.state.output_extents_changed();
+        self.state.tree_changed();
+    }
+
+    /// Dissolves the span that this output is part of, if any.
+    pub fn leave_span(self: &Rc<Self>) {
+        if let Some(primary) = self.span_primary.get() {
+            primary.unspan();
+        }
+        self.unspan();
+    }
+
+    fn is_mirroring_or_mirrored(&self) -> bool {
+        self.mirror_source.is_some() || self.mirrors.is_not_empty()
+    }
+
+    /// Returns the output whose contents this output shows, if it is mirroring an
+    /// output that has not been removed.
+    pub fn mirror_source(&self) -> Option<Rc<OutputNode>> {
+        self.mirror_source
+            .get()
+            .filter(|source| !source.global.destroyed.get())
+    }
+
+    /// If this output is mirroring another output, returns the source output and the
+    /// viewport that fits its contents into a framebuffer of this output.
+    ///
+    /// The contents are scaled uniformly and centered, leaving bars on the sides if the
+    /// aspect ratios differ.
+    pub fn mirror_viewport(
+        &self,
+        physical_size: (i32, i32),
+        scale: Scale,
+    ) -> Option<(Rc<OutputNode>, OutputZoom)> {
+        let source = self.mirror_source()?;
+        let (dst_width, dst_height) = calculate_logical_size(
+            physical_size,
+            self.global.persistent.transform.get(),
+            Scale::from_int(1),
+        );
+        let (src_width, src_height) = source.own_logical_size();
+        if src_width <= 0 || src_height <= 0 || dst_width <= 0 || dst_height <= 0 {
+            return None;
+        }
+        let fit = (dst_width as f64 / src_width as f64).min(dst_height as f64 / src_height as f64);
+        let pad_x = (dst_width as f64 - src_width as f64 * fit) / 2.0;
+        let pad_y = (dst_height as f64 - src_height as f64 * fit) / 2.0;
+        Some((
+            source,
+            OutputZoom {
+                factor: fit / scale.to_f64(),
+                x: -(pad_x / fit).round() as i32,
+                y: -(pad_y / fit).round() as i32,
+                magnifier: false,
+            },
+        ))
+    }
+
+    /// Makes this output show the contents of `source`.
+    ///
+    /// This output is removed from the tree while it is mirroring and its workspaces
+    /// are moved to `source`. `None` stops mirroring and adds the output back to the
+    /// tree.
+    pub fn set_mirror_source(
+        self: &Rc<Self>,
+        source: Option<Rc<OutputNode>>,
+    ) -> Result<(), OutputMirrorError> {
+        let Some(source) = source else {
+            self.stop_mirroring();
+            return Ok(());
+        };
+        if self.id == source.id {
+            return Err(OutputMirrorError::SameOutput);
+        }
+        if source.global.destroyed.get() {
+            return Err(OutputMirrorError::SourceDestroyed);
+        }
+        if self.is_spanned() || source.is_spanned() {
+            return Err(OutputMirrorError::Spanned);
+        }
+        if source.mirror_source.is_some() {
+            return Err(OutputMirrorError::SourceIsMirror);
+        }
+        if self.mirrors.is_not_empty() {
+            return Err(OutputMirrorError::IsMirrored);
+        }
+        if let Some(old) = self.mirror_source.get() {
+            if old.id == source.id {
+                return Ok(());
+            }
+        }
+        self.detach_mirror_source();
+        let id = self.global.connector.connector.id();
+        self.state.root.outputs.remove(&id);
+        self.mirror_source.set(Some(source.clone()));
+        source.mirrors.set(id, self.clone());
+        for ws in self.workspaces.iter() {
+            let config = WsMoveConfig {
+                make_visible_always: false,
+                make_visible_if_empty: false,
+                source_is_destroyed: true,
+                before: None,
+            };
+            move_ws_to_output(&ws, &source, config);
+        }
+        for seat in self.state.globals.seats.lock().values() {
+            seat.cursor_group().output_disconnected(self, &source);
+        }
+        self.hardware_cursor_usage_changed();
+        self.add_screencopy_damage(self.global.pos.get());
+        self.global.connector.damage();
+        self.state.output_extents_changed();
+        self.state.tree_changed();
+        Ok(())
+    }
+
+    /// Stops mirroring without adding this output back to the tree.
+    pub fn detach_mirror_source(&self) -> Option<Rc<OutputNode>> {
+        let source = self.mirror_source.take()?;
+        source.mirrors.remove(&self.global.connector.connector.id());
+        Some(source)
+    }
+
+    /// Stops mirroring and adds this output back to the tree.
+    pub fn stop_mirroring(self: &Rc<Self>) {
+        let Some(source) = self.detach_mirror_source() else {
+            return;
+        };
+        self.state
+            .root
+            .outputs
+            .set(self.global.connector.connector.id(), self.clone());
+        let workspaces: Vec<_> = source
+            .workspaces
+            .iter()
+            .filter(|ws| ws.desired_output.get() == self.global.output_id)
+            .collect();
+        for ws in workspaces {
+            let config = WsMoveConfig {
+                make_visible_always: false,
+                make_visible_if_empty: true,
+                source_is_destroyed: false,
+                before: None,
+            };
+            move_ws_to_output(&ws, self, config);
+        }
+        self.ensure_workspace();
+        self.hardware_cursor_usage_changed();
+        self.schedule_update_render_data();
+        self.add_screencopy_damage(self.global.pos.get());
+        self.global.connector.damage();
+        self.state.output_extents_changed();
+        self.state.tree_changed();
+    }
+
+    /// Makes all outputs that mirror this output show their own contents again.
+    pub fn stop_mirrors(&self) {
+        let mirrors: Vec<_> = self.mirrors.lock().values().cloned().collect();
+        for mirror in mirrors {
+            mirror.stop_mirroring();
+        }
+    }
+
     pub fn update_mode(self: &Rc<Self>, mode: Mode) {
         self.update_mode_and_transform(mode, self.global.persistent.transform.get());
     }
@@ -742,17 +2207,75 @@ impl OutputNode {
     }
 
     pub fn update_mode_and_transform(self: &Rc<Self>, mode: Mode, transform: Transform) {
+        let Some(old) = self.replace_mode_and_transform(mode, transform) else {
+            return;
+        };
+        self.change_extents_(&self.calculate_extents());
+        self.mode_and_transform_changed(old);
+    }
+
+    /// Changes the position, mode, transform, and scale of the output while updating
+    /// its extents only once.
+    ///
+    /// The caller is responsible for applying the mode to the connector.
+    pub fn apply_layout(
+        self: &Rc<Self>,
+        x: i32,
+        y: i32,
+        mode: Mode,
+        transform: Transform,
+        scale: Scale,
+    ) {
+        let scale_changed = self.replace_scale(scale);
+        let old = self.replace_mode_and_transform(mode, transform);
+        let rect = self.calculate_extents().at_point(x, y);
+        if scale_changed || old.is_some() || rect != self.global.pos.get() {
+            self.change_extents_(&rect);
+        }
+        if let Some(old) = old {
+            self.mode_and_transform_changed(old);
+        }
+        if scale_changed {
+            self.scale_changed();
+        }
+    }
+
+    /// Updates the mode and transform without changing the extents.
+    ///
+    /// Returns the previous state if anything changed. In that case the caller must
+    /// update the extents and then call [`Self::mode_and_transform_changed`].
+    fn replace_mode_and_transform(
+        self: &Rc<Self>,
+        mode: Mode,
+        transform: Transform,
+    ) -> Option<OldModeAndTransform> {
         let old_mode = self.global.mode.get();
         let old_transform = self.global.persistent.transform.get();
         if (old_mode, old_transform) == (mode, transform) {
-            return;
+            return None;
         }
-        let (old_width, old_height) = self.global.pixel_size();
+        self.leave_span();
+        let old_size = self.global.pixel_size();
         self.global.mode.set(mode);
         self.global.refresh_nsec.set(mode.refresh_nsec());
+        self.global.persistent.mode.set(Some(mode));
         self.global.persistent.transform.set(transform);
+        Some(OldModeAndTransform {
+            mode: old_mode,
+            transform: old_transform,
+            size: old_size,
+        })
+    }
+
+    fn mode_and_transform_changed(self: &Rc<Self>, old: OldModeAndTransform) {
+        let OldModeAndTransform {
+            mode: old_mode,
+            transform: old_transform,
+            size: (old_width, old_height),
+        } = old;
+        let mode = self.global.mode.get();
+        let transform = self.global.persistent.transform.get();
         let (new_width, new_height) = self.global.pixel_size();
-        self.change_extents_(&self.calculate_extents());
 
         if (old_width, old_height) != (new_width, new_height) {
             for sc in self.screencasts.lock().values() {
@@ -764,18 +2287,36 @@ impl OutputNode {
         }
 
         if transform != old_transform {
+            self.update_hardware_cursor();
             self.state.refresh_hardware_cursors();
             self.node_visit_children(&mut SurfaceSendPreferredTransformVisitor);
         }
+
+        if mode != old_mode {
+            if let Some(ramp) = self.gamma_ramp.get() {
+                self.global.connector.connector.set_gamma_ramp(Some(ramp));
+            }
+            if let Some(margin) = self.global.persistent.flip_margin_ns.get() {
+                self.set_flip_margin(Some(margin));
+            }
+            self.apply_vrr_min_refresh();
+        }
     }
 
-    fn calculate_extents(&self) -> Rect {
+    fn own_logical_size(&self) -> (i32, i32) {
         let mode = self.global.mode.get();
-        let (width, height) = calculate_logical_size(
+        calculate_logical_size(
             (mode.width, mode.height),
             self.global.persistent.transform.get(),
             self.global.persistent.scale.get(),
-        );
+        )
+    }
+
+    fn calculate_extents(&self) -> Rect {
+        let (mut width, height) = self.own_logical_size();
+        if let Some(secondary) = self.span_secondary.get() {
+            width += secondary.own_logical_size().0;
+        }
         let pos = self.global.pos.get();
         pos.with_size(width, height).unwrap()
     }
@@ -786,8 +2327,10 @@ impl OutputNode {
             self.state.damage(old_pos);
             self.state.damage(*rect);
         }
-        self.global.persistent.pos.set((rect.x1(), rect.y1()));
-        self.global.pos.set(*rect);
+        let old_pos = self.global.pos.replace(*rect);
+        if (old_pos.x1(), old_pos.y1()) != (rect.x1(), rect.y1()) {
+            self.global.persistent.pos.set((rect.x1(), rect.y1()));
+        }
         self.state.output_extents_changed();
         self.update_rects();
         if let Some(ls) = self.lock_surface.get() {
@@ -808,6 +2351,10 @@ impl OutputNode {
         for seat in self.state.globals.seats.lock().values() {
             seat.cursor_group().output_pos_changed(self)
         }
+        if let Some(secondary) = self.span_secondary.get() {
+            let (width, _) = self.own_logical_size();
+            secondary.set_position(rect.x1() + width, rect.y1());
+        }
         self.state.tree_changed();
     }
 
@@ -881,6 +2428,9 @@ impl OutputNode {
     }
 
     pub fn set_status(self: &Rc<Self>, status: &Rc<String>) {
+        if *self.status.get() == **status {
+            return;
+        }
         self.status.set(status.clone());
         self.schedule_update_render_data();
     }
@@ -913,8 +2463,22 @@ impl OutputNode {
         }
     }
 
+    pub fn update_workspace_scroll_behavior(&self) {
+        let persistent = &self.global.persistent;
+        let wrap = persistent
+            .workspace_scroll_wrap
+            .get()
+            .unwrap_or(self.state.workspace_scroll_wrap.get());
+        let skip_empty = persistent
+            .workspace_scroll_skip_empty
+            .get()
+            .unwrap_or(self.state.workspace_scroll_skip_empty.get());
+        self.workspace_scroll_wrap.set(wrap);
+        self.workspace_scroll_skip_empty.set(skip_empty);
+    }
+
     pub fn update_visible(&self) {
-        let mut visible = self.state.root_visible();
+        let mut visible = self.state.root_visible() && self.power.get();
         if self.state.lock.locked.get() {
             if let Some(surface) = self.lock_surface.get() {
                 surface.surface.set_visible(visible);
@@ -942,40 +2506,130 @@ impl OutputNode {
         if let Some(ws) = self.workspace.get() {
             ws.set_visible(visible);
         }
-        set_layer_visible!(self.layers[2], visible);
-        set_layer_visible!(self.layers[3], visible);
+        let upper_visible = visible && !self.game_mode_active.get();
+        set_layer_visible!(self.layers[2], upper_visible);
+        set_layer_visible!(self.layers[3], upper_visible);
     }
 
-    fn button(self: Rc<Self>, id: PointerType) {
-        let (x, y) = match self.pointer_positions.get(&id) {
-            Some(p) => p,
-            _ => return,
-        };
-        if let PointerType::Seat(s) = id {
-            self.pointer_down.set(s, (x, y));
-        }
+    /// Returns the workspace whose title is at the position of the pointer.
+    ///
+    /// Also returns the pointer position relative to the bar and the horizontal extents
+    /// of the title.
+    fn title_at_pointer(
+        &self,
+        id: PointerType,
+    ) -> Option<(Rc<WorkspaceNode>, (i32, i32), i32, i32)> {
+        let (x, y) = self.pointer_positions.get(&id)?;
         let (x, y) = self.non_exclusive_rect_rel.get().translate(x, y);
-        if y >= self.state.theme.sizes.title_height.get() {
-            return;
+        let (bar_y, _) = self.title_bar_y_rel();
+        if y < bar_y || y >= bar_y + self.state.theme.sizes.title_height.get() {
+            return None;
         }
-        let ws = 'ws: {
-            let rd = self.render_data.borrow_mut();
-            for title in &rd.titles {
-                if x >= title.x1 && x < title.x2 {
-                    break 'ws title.ws.clone();
-                }
+        let rd = self.render_data.borrow_mut();
+        for title in &rd.titles {
+            if x >= title.x1 && x < title.x2 {
+                return Some((title.ws.clone(), (x, y), title.x1, title.x2));
             }
+        }
+        None
+    }
+
+    fn button(self: Rc<Self>, id: PointerType, time_usec: u64) {
+        let Some(pos) = self.pointer_positions.get(&id) else {
+            return;
+        };
+        if let PointerType::Seat(s) = id {
+            self.pointer_down.set(s, pos);
+        }
+        let Some((ws, (x, y), x1, x2)) = self.title_at_pointer(id) else {
             return;
         };
+        let double_click =
+            self.title_double_click_state
+                .borrow_mut()
+                .click(&self.state, time_usec, x, y);
+        let same_title = self.title_last_click.replace(Some(ws.id)) == Some(ws.id);
         self.show_workspace(&ws);
         ws.flush_jay_workspaces();
         self.schedule_update_render_data();
         self.state.tree_changed();
+        if double_click && same_title {
+            let (bar_y, _) = self.title_bar_y_rel();
+            let th = self.state.theme.sizes.title_height.get();
+            let rect = Rect::new_sized(x1, bar_y, x2 - x1, th).unwrap();
+            let non_exclusive_rect = self.non_exclusive_rect.get();
+            ws.request_rename(rect.move_(non_exclusive_rect.x1(), non_exclusive_rect.y1()));
+        }
+    }
+
+    /// Notifies the config about a button press on the desktop background.
+    ///
+    /// This is only called if no window, bar, or layer surface is below the pointer.
+    fn desktop_click(&self, seat: &Rc<WlSeatGlobal>, button: u32) {
+        if self.state.lock.locked.get() {
+            return;
+        }
+        let Some((x, y)) = self.pointer_positions.get(&PointerType::Seat(seat.id())) else {
+            return;
+        };
+        let non_exclusive_rect = self.non_exclusive_rect_rel.get();
+        if non_exclusive_rect.contains(x, y) {
+            let (_, y) = non_exclusive_rect.translate(x, y);
+            if self.title_bar_contains_rel(y) {
+                return;
+            }
+        }
+        if let Some(config) = self.state.config.get() {
+            config.desktop_click(
+                seat.id(),
+                self.global.connector.connector.id(),
+                button,
+                x,
+                y,
+            );
+        }
     }
 
-    pub fn update_presentation_type(&self) {
+    pub fn update_presentation_type(self: &Rc<Self>) {
         self.update_vrr_state();
         self.update_tearing();
+        self.update_game_mode();
+    }
+
+    pub fn set_game_mode(self: &Rc<Self>, enabled: bool) {
+        self.global.persistent.game_mode.set(enabled);
+        self.update_game_mode();
+    }
+
+    fn game_mode_eligible(&self) -> bool {
+        if !self.global.persistent.game_mode.get() {
+            return false;
+        }
+        let Some(ws) = self.workspace.get() else {
+            return false;
+        };
+        let Some(tl) = ws.fullscreen.get() else {
+            return false;
+        };
+        tl.tl_scanout_surface().is_some()
+    }
+
+    fn update_game_mode(self: &Rc<Self>) {
+        let active = self.game_mode_eligible();
+        if self.set_game_mode_active(active) && !active {
+            // The title and status textures have not been updated while game mode was
+            // active.
+            self.schedule_update_render_data();
+        }
+    }
+
+    fn set_game_mode_active(&self, active: bool) -> bool {
+        if self.game_mode_active.replace(active) == active {
+            return false;
+        }
+        self.update_visible();
+        self.state.damage(self.global.pos.get());
+        true
     }
 
     fn update_vrr_state(&self) {
@@ -1031,6 +2685,17 @@ impl OutputNode {
                             break 'get false;
                         }
                     }
+                    if let Some(req) = req.content_type {
+                        let Some(content_type) = surface.content_type.get() else {
+                            break 'get false;
+                        };
+                        match content_type {
+                            ContentType::Photo if !req.photo => break 'get false,
+                            ContentType::Video if !req.video => break 'get false,
+                            ContentType::Game if !req.game => break 'get false,
+                            _ => {}
+                        }
+                    }
                 }
                 true
             }
@@ -1076,14 +2741,20 @@ impl OutputNode {
             return None;
         }
         let th = self.state.theme.sizes.title_height.get();
-        if y_abs < rect.y1() + th + 1 {
+        let (x, y) = rect.translate(x_abs, y_abs);
+        if self.title_bar_contains_rel(y) {
             let rd = &*self.render_data.borrow();
-            let (x, _) = rect.translate(x_abs, y_abs);
+            let (bar_y, _) = self.title_bar_y_rel();
             let mut last_x2 = 0;
             for t in &rd.titles {
                 if x < t.x2 {
                     return Some(TileDragDestination {
-                        highlight: Rect::new_sized(rect.x1() + t.x1, rect.y1(), t.x2 - t.x1, th)?,
+                        highlight: Rect::new_sized(
+                            rect.x1() + t.x1,
+                            rect.y1() + bar_y,
+                            t.x2 - t.x1,
+                            th,
+                        )?,
                         ty: TddType::MoveToWorkspace {
                             workspace: t.ws.clone(),
                         },
@@ -1094,7 +2765,7 @@ impl OutputNode {
             return Some(TileDragDestination {
                 highlight: Rect::new_sized(
                     rect.x1() + last_x2,
-                    rect.y1(),
+                    rect.y1() + bar_y,
                     rect.x2() - last_x2,
                     th,
                 )?,
@@ -1103,8 +2774,7 @@ impl OutputNode {
                 },
             });
         }
-        let thp1 = self.state.theme.sizes.title_height.get() + 1;
-        let rect = Rect::new(rect.x1(), rect.y1() + thp1, rect.x2(), rect.y2())?;
+        let rect = self.workspace_rect.get();
         if !rect.contains(x_abs, y_abs) {
             return None;
         }
@@ -1128,11 +2798,12 @@ impl OutputNode {
             return None;
         }
         let th = self.state.theme.sizes.title_height.get();
-        if y_abs - rect.y1() > th + 1 {
+        let (x, y) = rect.translate(x_abs, y_abs);
+        if !self.title_bar_contains_rel(y) {
             return None;
         }
+        let (bar_y, _) = self.title_bar_y_rel();
         let rd = &*self.render_data.borrow();
-        let (x, _) = rect.translate(x_abs, y_abs);
         let mut prev_is_source = false;
         let mut prev_center = 0;
         for t in &rd.titles {
@@ -1148,7 +2819,7 @@ impl OutputNode {
                     Some(WorkspaceDragDestination {
                         highlight: Rect::new_sized(
                             rect.x1() + prev_center,
-                            rect.y1(),
+                            rect.y1() + bar_y,
                             center - prev_center,
                             th,
                         )?,
@@ -1166,7 +2837,7 @@ impl OutputNode {
         return Some(WorkspaceDragDestination {
             highlight: Rect::new_sized(
                 rect.x1() + prev_center,
-                rect.y1(),
+                rect.y1() + bar_y,
                 rect.x2() - prev_center,
                 th,
             )?,
@@ -1182,13 +2853,14 @@ impl OutputNode {
         let mut right = output_width;
         let mut have_any = false;
         let icon_size = self.state.tray_icon_size();
+        let (bar_y, _) = self.title_bar_y_rel();
         for item in self.tray_items.rev_iter() {
             if item.data().surface.buffer.is_none() {
                 continue;
             }
             have_any = true;
             right -= th;
-            let rel_pos = Rect::new_sized(right, 1, icon_size, icon_size).unwrap();
+            let rel_pos = Rect::new_sized(right, bar_y + 1, icon_size, icon_size).unwrap();
             let abs_pos = rel_pos.move_(rect.x1(), rect.y1());
             item.set_position(abs_pos, rel_pos);
         }
@@ -1218,6 +2890,7 @@ pub struct OutputTitle {
 
 pub struct OutputStatus {
     pub tex_x: i32,
+    pub tex_y: i32,
     pub tex: TextTexture,
 }
 
@@ -1238,6 +2911,17 @@ pub struct OutputRenderData {
     pub status: Option<OutputStatus>,
 }
 
+impl VblankListener for OutputNode {
+    fn after_vblank(self: Rc<Self>) {
+        self.cursor_vblank_listener.detach();
+        if self.hardware_cursor_vblank_pending.take() {
+            if let Some(hc) = self.hardware_cursor.get() {
+                hc.damage();
+            }
+        }
+    }
+}
+
 impl Debug for OutputNode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("OutputNode").finish_non_exhaustive()
@@ -1282,6 +2966,10 @@ impl Node for OutputNode {
         self.global.pos.get()
     }
 
+    fn node_background_color(&self) -> Option<Color> {
+        self.global.persistent.background.get()
+    }
+
     fn node_do_focus(self: Rc<Self>, seat: &Rc<WlSeatGlobal>, direction: Direction) {
         if self.state.lock.locked.get() {
             if let Some(lock) = self.lock_surface.get() {
@@ -1297,7 +2985,7 @@ impl Node for OutputNode {
     fn node_find_tree_at(
         &self,
         x: i32,
-        mut y: i32,
+        y: i32,
         tree: &mut Vec<FoundNode>,
         usecase: FindTreeUsecase,
     ) -> FindTreeResult {
@@ -1314,11 +3002,12 @@ impl Node for OutputNode {
             }
             return FindTreeResult::AcceptsInput;
         }
-        let bar_height = self.state.theme.sizes.title_height.get() + 1;
         if usecase == FindTreeUsecase::SelectWorkspace {
-            if y >= bar_height {
-                y -= bar_height;
-                if let Some(ws) = self.workspace.get() {
+            if let Some(ws) = self.workspace.get() {
+                let pos = self.global.pos.get();
+                let ws_rect = self.workspace_rect.get().move_(-pos.x1(), -pos.y1());
+                if ws_rect.contains(x, y) {
+                    let (x, y) = ws_rect.translate(x, y);
                     tree.push(FoundNode {
                         node: ws.clone(),
                         x,
@@ -1335,7 +3024,7 @@ impl Node for OutputNode {
                 return res;
             }
         }
-        {
+        if !self.game_mode_active.get() {
             let res = self.find_layer_surface_at(x, y, &[OVERLAY, TOP], tree, usecase);
             if res.accepts_input() {
                 return res;
@@ -1363,7 +3052,7 @@ impl Node for OutputNode {
             let non_exclusive_rect = self.non_exclusive_rect_rel.get();
             if non_exclusive_rect.contains(x, y) {
                 let (x, y) = non_exclusive_rect.translate(x, y);
-                if y < bar_height {
+                if self.title_bar_contains_rel(y) {
                     search_layers = false;
                     for item in self.tray_items.iter() {
                         let data = item.data();
@@ -1380,7 +3069,7 @@ impl Node for OutputNode {
                     }
                 } else {
                     if let Some(ws) = self.workspace.get() {
-                        let y = y - bar_height;
+                        let y = y - self.workspace_y_rel();
                         let len = tree.len();
                         tree.push(FoundNode {
                             node: ws.clone(),
@@ -1404,17 +3093,29 @@ impl Node for OutputNode {
     }
 
     fn node_render(&self, renderer: &mut Renderer, x: i32, y: i32, _bounds: Option<&Rect>) {
-        renderer.render_output(self, x, y);
+        match self.mirror_source() {
+            Some(source) => renderer.render_output(&source, x, y),
+            None => renderer.render_output(self, x, y),
+        }
     }
 
     fn node_on_button(
         self: Rc<Self>,
         seat: &Rc<WlSeatGlobal>,
-        _time_usec: u64,
+        time_usec: u64,
         button: u32,
         state: KeyState,
         _serial: u64,
     ) {
+        if state == KeyState::Pressed {
+            self.desktop_click(seat, button);
+        }
+        if button == BTN_MIDDLE && state == KeyState::Pressed {
+            if let Some((ws, _, _, _)) = self.title_at_pointer(PointerType::Seat(seat.id())) {
+                self.close_workspace(&ws);
+            }
+            return;
+        }
         if button != BTN_LEFT {
             return;
         }
@@ -1422,45 +3123,26 @@ impl Node for OutputNode {
             self.pointer_down.remove(&seat.id());
             return;
         }
-        self.button(PointerType::Seat(seat.id()));
+        self.button(PointerType::Seat(seat.id()), time_usec);
     }
 
     fn node_on_axis_event(self: Rc<Self>, seat: &Rc<WlSeatGlobal>, event: &PendingScroll) {
-        let steps = match self.scroll.handle(event) {
+        let mut steps = match self.scroll.handle(event) {
             Some(e) => e,
             _ => return,
         };
-        if steps == 0 {
-            return;
-        }
-        let ws = match self.workspace.get() {
-            Some(ws) => ws,
-            _ => return,
-        };
-        let mut ws = 'ws: {
-            for r in self.workspaces.iter() {
-                if r.id == ws.id {
-                    break 'ws r;
-                }
-            }
-            return;
-        };
-        for _ in 0..steps.abs() {
-            let new = if steps < 0 { ws.prev() } else { ws.next() };
-            ws = match new {
-                Some(n) => n,
-                None => break,
-            };
+        if self.state.workspace_scroll_inverted.get() {
+            steps = -steps;
         }
-        if !self.show_workspace(&ws) {
-            return;
+        let mut acc = self.workspace_scroll_steps.get();
+        if acc.signum() == -steps.signum() {
+            acc = 0;
         }
-        ws.flush_jay_workspaces();
-        ws.deref()
-            .clone()
-            .node_do_focus(seat, Direction::Unspecified);
-        self.schedule_update_render_data();
-        self.state.tree_changed();
+        acc = acc.saturating_add(steps);
+        let threshold = self.state.workspace_scroll_threshold.get();
+        let steps = acc / threshold;
+        self.workspace_scroll_steps.set(acc - steps * threshold);
+        self.step_workspace(seat, steps);
     }
 
     fn node_on_leave(&self, seat: &WlSeatGlobal) {
@@ -1515,7 +3197,7 @@ impl Node for OutputNode {
     fn node_on_tablet_tool_apply_changes(
         self: Rc<Self>,
         tool: &Rc<TabletTool>,
-        _time_usec: u64,
+        time_usec: u64,
         changes: Option<&TabletToolChanges>,
         x: Fixed,
         y: Fixed,
@@ -1524,12 +3206,18 @@ impl Node for OutputNode {
         self.pointer_move(id, x, y);
         if let Some(changes) = changes {
             if changes.down == Some(true) {
-                self.button(id);
+                self.button(id, time_usec);
             }
         }
     }
 }
 
+struct OldModeAndTransform {
+    mode: Mode,
+    transform: Transform,
+    size: (i32, i32),
+}
+
 pub fn calculate_logical_size(
     mode: (i32, i32),
     transform: Transform,
@@ -1582,6 +3270,24 @@ impl VrrMode {
         }),
     };
 
+    const CONTENT_TYPE_VARIANTS: &'static [Self; 8] = &{
+        let mut variants = [Self::Never; 8];
+        let mut i = 0;
+        while i < variants.len() {
+            variants[i] = Self::Fullscreen {
+                surface: Some(VrrSurfaceRequirements {
+                    content_type: Some(VrrContentTypeRequirements {
+                        photo: i & 0b001 != 0,
+                        video: i & 0b010 != 0,
+                        game: i & 0b100 != 0,
+                    }),
+                }),
+            };
+            i += 1;
+        }
+        variants
+    };
+
     pub fn from_config(mode: ConfigVrrMode) -> Option<&'static Self> {
         let res = match mode {
             ConfigVrrMode::NEVER => Self::NEVER,
@@ -1589,7 +3295,11 @@ impl VrrMode {
             ConfigVrrMode::VARIANT_1 => Self::VARIANT_1,
             ConfigVrrMode::VARIANT_2 => Self::VARIANT_2,
             ConfigVrrMode::VARIANT_3 => Self::VARIANT_3,
-            _ => return None,
+            _ => {
+                let (photo, video, game) = mode.content_types()?;
+                let idx = photo as usize | (video as usize) << 1 | (game as usize) << 2;
+                &Self::CONTENT_TYPE_VARIANTS[idx]
+            }
         };
         Some(res)
     }
@@ -1601,10 +3311,12 @@ impl VrrMode {
             Self::VARIANT_1 => ConfigVrrMode::VARIANT_1,
             Self::VARIANT_2 => ConfigVrrMode::VARIANT_2,
             Self::VARIANT_3 => ConfigVrrMode::VARIANT_3,
-            _ => {
-                log::error!("VRR mode {self:?} has no config representation");
-                ConfigVrrMode::NEVER
-            }
+            Self::Fullscreen {
+                surface:
+                    Some(VrrSurfaceRequirements {
+                        content_type: Some(ct),
+                    }),
+            } => ConfigVrrMode::content_type(ct.photo, ct.video, ct.game),
         }
     }
 }
@@ -1621,6 +3333,14 @@ pub enum TearingMode {
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct TearingSurfaceRequirements {
     tearing_requested: bool,
+    content_type: Option<TearingContentTypeRequirements>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TearingContentTypeRequirements {
+    photo: bool,
+    video: bool,
+    game: bool,
 }
 
 impl TearingMode {
@@ -1630,14 +3350,35 @@ impl TearingMode {
     pub const VARIANT_2: &'static Self = &Self::Fullscreen {
         surface: Some(TearingSurfaceRequirements {
             tearing_requested: false,
+            content_type: None,
         }),
     };
     pub const VARIANT_3: &'static Self = &Self::Fullscreen {
         surface: Some(TearingSurfaceRequirements {
             tearing_requested: true,
+            content_type: None,
         }),
     };
 
+    const CONTENT_TYPE_VARIANTS: &'static [Self; 16] = &{
+        let mut variants = [Self::Never; 16];
+        let mut i = 0;
+        while i < variants.len() {
+            variants[i] = Self::Fullscreen {
+                surface: Some(TearingSurfaceRequirements {
+                    tearing_requested: i & 0b1000 != 0,
+                    content_type: Some(TearingContentTypeRequirements {
+                        photo: i & 0b001 != 0,
+                        video: i & 0b010 != 0,
+                        game: i & 0b100 != 0,
+                    }),
+                }),
+            };
+            i += 1;
+        }
+        variants
+    };
+
     pub fn from_config(mode: ConfigTearingMode) -> Option<&'static Self> {
         let res = match mode {
             ConfigTearingMode::NEVER => Self::NEVER,
@@ -1645,18 +3386,48 @@ impl TearingMode {
             ConfigTearingMode::VARIANT_1 => Self::VARIANT_1,
             ConfigTearingMode::VARIANT_2 => Self::VARIANT_2,
             ConfigTearingMode::VARIANT_3 => Self::VARIANT_3,
-            _ => return None,
+            _ => {
+                let (requested, photo, video, game) = mode.content_types()?;
+                let idx = photo as usize
+                    | (video as usize) << 1
+                    | (game as usize) << 2
+                    | (requested as usize) << 3;
+                &Self::CONTENT_TYPE_VARIANTS[idx]
+            }
         };
         Some(res)
     }
 
-    pub fn to_config(&self) -> ConfigVrrMode {
+    pub fn to_config(&self) -> ConfigTearingMode {
         match self {
-            Self::NEVER => ConfigVrrMode::NEVER,
-            Self::ALWAYS => ConfigVrrMode::ALWAYS,
-            Self::VARIANT_1 => ConfigVrrMode::VARIANT_1,
-            Self::VARIANT_2 => ConfigVrrMode::VARIANT_2,
-            Self::VARIANT_3 => ConfigVrrMode::VARIANT_3,
+            Self::NEVER => ConfigTearingMode::NEVER,
+            Self::ALWAYS => ConfigTearingMode::ALWAYS,
+            Self::VARIANT_1 => ConfigTearingMode::VARIANT_1,
+            Self::VARIANT_2 => ConfigTearingMode::VARIANT_2,
+            Self::VARIANT_3 => ConfigTearingMode::VARIANT_3,
+            Self::Fullscreen {
+                surface:
+                    Some(TearingSurfaceRequirements {
+                        tearing_requested,
+                        content_type: Some(ct),
+                    }),
+            } => ConfigTearingMode::content_type(*tearing_requested, ct.photo, ct.video, ct.game),
         }
     }
 }
+
+/// Moves the sticky floating windows of `old` to `new`.
+fn move_sticky_windows(old: &WorkspaceNode, new: &Rc<WorkspaceNode>) {
+    let floats: Vec<_> = old
+        .stacked
+        .iter()
+        .filter_map(|s| s.deref().clone().stacked_into_node().node_into_float())
+        .filter(|f| match f.child.get() {
+            Some(c) => c.tl_data().is_sticky.get(),
+            _ => false,
+        })
+        .collect();
+    for float in floats {
+        float.set_workspace(new);
+    }
+}
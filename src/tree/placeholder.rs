@@ -110,6 +110,7 @@ impl PlaceholderNode {
                 tex.schedule_render_fitting(
                     on_completed.clone(),
                     Some(height),
+                    None,
                     &font,
                     "Fullscreen",
                     self.toplevel.state.theme.colors.unfocused_title_text.get(),
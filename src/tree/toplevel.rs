@@ -8,17 +8,19 @@ use {
             jay_screencast::JayScreencast,
             jay_toplevel::JayToplevel,
             wl_seat::{collect_kb_foci, collect_kb_foci2, NodeSeatState, SeatId},
-            wl_surface::WlSurface,
+            wl_surface::{SurfaceSendPreferredScaleVisitor, WlSurface},
         },
         rect::Rect,
+        scale::Scale,
         state::State,
+        theme::Color,
         tree::{
             ContainerNode, ContainerSplit, ContainingNode, Direction, Node, NodeId, OutputNode,
             PlaceholderNode, WorkspaceNode,
         },
         utils::{
             array_to_tuple::ArrayToTuple,
-            clonecell::CloneCell,
+            clonecell::{CloneCell, UnsafeCellCloneSafe},
             copyhashmap::CopyHashMap,
             hash_map_ext::HashMapExt,
             numcell::NumCell,
@@ -54,6 +56,7 @@ pub trait ToplevelNode: ToplevelNodeBase {
     fn tl_change_extents(self: Rc<Self>, rect: &Rect);
     fn tl_set_visible(&self, visible: bool);
     fn tl_destroy(&self);
+    fn tl_window_border_width(&self) -> i32;
 }
 
 impl<T: ToplevelNodeBase> ToplevelNode for T {
@@ -138,18 +141,14 @@ impl<T: ToplevelNodeBase> ToplevelNode for T {
         for sc in data.ext_copy_sessions.lock().values() {
             sc.update_latch_listener();
         }
+        data.pixel_size_changed();
     }
 
     fn tl_change_extents(self: Rc<Self>, rect: &Rect) {
         let data = self.tl_data();
         let prev = data.desired_extents.replace(*rect);
         if prev.size() != rect.size() {
-            for sc in data.jay_screencasts.lock().values() {
-                sc.schedule_realloc_or_reconfigure();
-            }
-            for sc in data.ext_copy_sessions.lock().values() {
-                sc.buffer_size_changed();
-            }
+            data.pixel_size_changed();
         }
         if data.is_floating.get() {
             data.float_width.set(rect.width());
@@ -167,6 +166,13 @@ impl<T: ToplevelNodeBase> ToplevelNode for T {
         self.tl_data().destroy_node(self);
         self.tl_destroy_impl();
     }
+
+    fn tl_window_border_width(&self) -> i32 {
+        if self.tl_admits_children() {
+            return 0;
+        }
+        self.tl_data().window_border_width()
+    }
 }
 
 pub trait ToplevelNodeBase: Node {
@@ -215,6 +221,10 @@ pub trait ToplevelNodeBase: Node {
         // nothing
     }
 
+    fn tl_decoration_mode_changed(&self) {
+        // nothing
+    }
+
     fn tl_admits_children(&self) -> bool;
 
     fn tl_tile_drag_destination(
@@ -232,6 +242,55 @@ pub trait ToplevelNodeBase: Node {
     }
 }
 
+/// How a toplevel is decorated.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum DecorationMode {
+    /// The client does not draw decorations. Floating windows have a title bar.
+    #[default]
+    Server,
+    /// The client draws decorations. Floating windows have no title bar.
+    Client,
+    /// The client does not draw decorations. Floating windows have no title bar.
+    None,
+}
+
+unsafe impl UnsafeCellCloneSafe for DecorationMode {}
+
+/// Per-window overrides of the window border configured in the theme.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct WindowBorder {
+    pub width: Option<i32>,
+    pub focused_color: Option<Color>,
+    pub unfocused_color: Option<Color>,
+    pub attention_requested_color: Option<Color>,
+}
+
+unsafe impl UnsafeCellCloneSafe for WindowBorder {}
+
+/// Returns the rectangles of a border of the given width drawn along the inside of
+/// `rect`.
+pub fn window_border_rects(rect: &Rect, width: i32) -> [Rect; 4] {
+    let width = width.min(rect.width() / 2).min(rect.height() / 2).max(0);
+    let (x1, y1, x2, y2) = (rect.x1(), rect.y1(), rect.x2(), rect.y2());
+    [
+        Rect::new_unchecked(x1, y1, x2, y1 + width),
+        Rect::new_unchecked(x1, y2 - width, x2, y2),
+        Rect::new_unchecked(x1, y1 + width, x1 + width, y2 - width),
+        Rect::new_unchecked(x2 - width, y1 + width, x2, y2 - width),
+    ]
+}
+
+/// Returns the part of `rect` that is not covered by a border of the given width.
+pub fn window_border_inset(rect: &Rect, width: i32) -> Rect {
+    Rect::new_sized(
+        rect.x1() + width,
+        rect.y1() + width,
+        (rect.width() - 2 * width).max(0),
+        (rect.height() - 2 * width).max(0),
+    )
+    .unwrap()
+}
+
 pub struct FullscreenedData {
     pub placeholder: Rc<PlaceholderNode>,
     pub workspace: Rc<WorkspaceNode>,
@@ -262,6 +321,7 @@ pub struct ToplevelData {
     pub focus_node: SmallMap<SeatId, Rc<dyn Node>, 1>,
     pub visible: Cell<bool>,
     pub is_floating: Cell<bool>,
+    pub is_sticky: Cell<bool>,
     pub float_width: Cell<i32>,
     pub float_height: Cell<i32>,
     pub is_fullscreen: Cell<bool>,
@@ -275,6 +335,11 @@ pub struct ToplevelData {
     pub wants_attention: Cell<bool>,
     pub requested_attention: Cell<bool>,
     pub app_id: RefCell<String>,
+    pub scale_override: Cell<Option<Scale>>,
+    pub decoration_mode: Cell<DecorationMode>,
+    pub window_border: Cell<WindowBorder>,
+    pub block_capture: Cell<bool>,
+    pub block_capture_rule: Cell<bool>,
     pub identifier: Cell<ToplevelIdentifier>,
     pub handles:
         CopyHashMap<(ClientId, ExtForeignToplevelHandleV1Id), Rc<ExtForeignToplevelHandleV1>>,
@@ -303,6 +368,7 @@ impl ToplevelData {
             focus_node: Default::default(),
             visible: Cell::new(false),
             is_floating: Default::default(),
+            is_sticky: Default::default(),
             float_width: Default::default(),
             float_height: Default::default(),
             is_fullscreen: Default::default(),
@@ -316,6 +382,11 @@ impl ToplevelData {
             wants_attention: Cell::new(false),
             requested_attention: Cell::new(false),
             app_id: Default::default(),
+            scale_override: Default::default(),
+            decoration_mode: Default::default(),
+            window_border: Default::default(),
+            block_capture: Cell::new(false),
+            block_capture_rule: Cell::new(false),
             identifier: Cell::new(id),
             handles: Default::default(),
             render_highlight: Default::default(),
@@ -450,6 +521,127 @@ impl ToplevelData {
             handle.send_app_id(app_id);
             handle.send_done();
         }
+        self.update_scale_override();
+        self.update_decoration_mode();
+        self.update_window_border();
+        self.update_block_capture_rule();
+    }
+
+    pub fn update_scale_override(&self) {
+        let scale = self.state.scale_overrides.get(&*self.app_id.borrow());
+        if self.scale_override.replace(scale) == scale {
+            return;
+        }
+        if let Some(tl) = self.slf.upgrade() {
+            tl.tl_into_node()
+                .node_visit(&mut SurfaceSendPreferredScaleVisitor);
+        }
+    }
+
+    pub fn update_decoration_mode(&self) {
+        let mode = self
+            .state
+            .decoration_modes
+            .get(&*self.app_id.borrow())
+            .unwrap_or_default();
+        if self.decoration_mode.replace(mode) == mode {
+            return;
+        }
+        if let Some(tl) = self.slf.upgrade() {
+            tl.tl_decoration_mode_changed();
+        }
+        if let Some(parent) = self.parent.get() {
+            if let Some(float) = parent.node_into_float() {
+                float.on_decoration_mode_changed();
+            }
+        }
+    }
+
+    pub fn update_window_border(&self) {
+        let border = self
+            .state
+            .window_borders
+            .get(&*self.app_id.borrow())
+            .unwrap_or_default();
+        if self.window_border.replace(border) == border {
+            return;
+        }
+        if let Some(parent) = self.parent.get() {
+            if let Some(tl) = self.slf.upgrade() {
+                parent.cnode_child_window_border_changed(tl.tl_as_node());
+            }
+        }
+    }
+
+    /// Returns whether this toplevel must not be shown in screen captures.
+    ///
+    /// Such toplevels are rendered as black rectangles in screenshots, screencasts, and
+    /// screencopies while still being shown normally on the outputs.
+    pub fn capture_blocked(&self) -> bool {
+        self.block_capture.get() || self.block_capture_rule.get()
+    }
+
+    pub fn set_block_capture(&self, block: bool) {
+        let old = self.capture_blocked();
+        self.block_capture.set(block);
+        self.capture_blocked_changed(old);
+    }
+
+    pub fn update_block_capture_rule(&self) {
+        let old = self.capture_blocked();
+        let block = self
+            .state
+            .capture_blocked_app_ids
+            .contains(&*self.app_id.borrow());
+        self.block_capture_rule.set(block);
+        self.capture_blocked_changed(old);
+    }
+
+    fn capture_blocked_changed(&self, old: bool) {
+        if self.capture_blocked() != old && self.visible.get() {
+            self.state.damage(self.pos.get());
+        }
+    }
+
+    /// Returns the width of the border drawn around this toplevel.
+    ///
+    /// Use [`ToplevelNode::tl_window_border_width`] to account for containers, which
+    /// never have a border.
+    pub fn window_border_width(&self) -> i32 {
+        if self.is_fullscreen.get() {
+            return 0;
+        }
+        self.window_border
+            .get()
+            .width
+            .unwrap_or_else(|| self.state.theme.sizes.window_border_width.get())
+    }
+
+    pub fn window_border_color(&self, active: bool, attention_requested: bool) -> Color {
+        let border = self.window_border.get();
+        let colors = &self.state.theme.colors;
+        if active {
+            border
+                .focused_color
+                .unwrap_or_else(|| colors.focused_window_border.get())
+        } else if attention_requested {
+            border
+                .attention_requested_color
+                .unwrap_or_else(|| colors.attention_requested_window_border.get())
+        } else {
+            border
+                .unfocused_color
+                .unwrap_or_else(|| colors.unfocused_window_border.get())
+        }
+    }
+
+    /// Returns the height of the title bar of the float containing this toplevel,
+    /// including the separator below it.
+    pub fn float_title_height(&self) -> i32 {
+        match self.decoration_mode.get() {
+            DecorationMode::Server => self.state.theme.sizes.title_height.get() + 1,
+            DecorationMode::Client | DecorationMode::None => 0,
+        }
     }
 
     pub fn set_fullscreen(
@@ -613,6 +805,17 @@ impl ToplevelData {
         };
         (0, 0)
     }
+
+    /// Informs screencasts of this toplevel that [`Self::desired_pixel_size`] might have
+    /// changed.
+    pub fn pixel_size_changed(&self) {
+        for sc in self.jay_screencasts.lock().values() {
+            sc.schedule_realloc_or_reconfigure();
+        }
+        for sc in self.ext_copy_sessions.lock().values() {
+            sc.buffer_size_changed();
+        }
+    }
 }
 
 impl Drop for ToplevelData {
@@ -39,6 +39,11 @@ use {
 
 tree_id!(WorkspaceNodeId);
 
+pub struct WorkspaceOutputAffinity {
+    pub preferred: Rc<OutputId>,
+    pub fallback: Option<Rc<OutputId>>,
+}
+
 pub struct WorkspaceNode {
     pub id: WorkspaceNodeId,
     pub state: Rc<State>,
@@ -48,12 +53,13 @@ pub struct WorkspaceNode {
     pub container: CloneCell<Option<Rc<ContainerNode>>>,
     pub stacked: LinkedList<Rc<dyn StackedNode>>,
     pub seat_state: NodeSeatState,
-    pub name: String,
+    pub name: CloneCell<Rc<String>>,
     pub output_link: RefCell<Option<LinkedNode<Rc<WorkspaceNode>>>>,
     pub visible: Cell<bool>,
     pub fullscreen: CloneCell<Option<Rc<dyn ToplevelNode>>>,
     pub visible_on_desired_output: Cell<bool>,
     pub desired_output: CloneCell<Rc<OutputId>>,
+    pub fallback_output: CloneCell<Option<Rc<OutputId>>>,
     pub jay_workspaces: CopyHashMap<(ClientId, JayWorkspaceId), Rc<JayWorkspace>>,
     pub may_capture: Cell<bool>,
     pub has_capture: Cell<bool>,
@@ -63,6 +69,28 @@ pub struct WorkspaceNode {
 }
 
 impl WorkspaceNode {
+    /// Returns the desired output of this workspace if it is connected, otherwise the
+    /// fallback output if it is connected.
+    pub fn affinity_output(&self) -> Option<Rc<OutputNode>> {
+        if let Some(output) = self.state.find_output(&self.desired_output.get()) {
+            return Some(output);
+        }
+        let fallback = self.fallback_output.get()?;
+        self.state.find_output(&fallback)
+    }
+
+    pub fn set_output_affinity(&self, affinity: Option<&WorkspaceOutputAffinity>) {
+        match affinity {
+            Some(affinity) => {
+                self.desired_output.set(affinity.preferred.clone());
+                self.fallback_output.set(affinity.fallback.clone());
+            }
+            None => {
+                self.fallback_output.set(None);
+            }
+        }
+    }
+
     pub fn clear(&self) {
         self.container.set(None);
         *self.output_link.borrow_mut() = None;
@@ -166,6 +194,42 @@ impl WorkspaceNode {
         }
     }
 
+    /// Moves all toplevels of this workspace and their popups to `target`.
+    pub fn move_toplevels_to(self: &Rc<Self>, target: &Rc<WorkspaceNode>) {
+        if let Some(fs) = self.fullscreen.get() {
+            fs.tl_set_fullscreen(false);
+        }
+        let floats: Vec<_> = self
+            .stacked
+            .iter()
+            .filter_map(|s| s.deref().clone().stacked_into_node().node_into_float())
+            .collect();
+        for float in floats {
+            let Some(tl) = float.child.get() else {
+                continue;
+            };
+            float.cnode_remove_child2(tl.tl_as_node(), true);
+            let data = tl.tl_data();
+            let (width, height) = (data.float_width.get(), data.float_height.get());
+            self.state.map_floating(tl, width, height, target, None);
+        }
+        if let Some(container) = self.container.get() {
+            self.clone().cnode_remove_child2(&*container, true);
+            self.state.map_tiled_on(container, target);
+        }
+        // Popups remain linked to the workspace on which they were mapped.
+        let stacked: Vec<_> = self.stacked.iter().collect();
+        for node in stacked {
+            target.stacked.add_last_existing(&node);
+        }
+    }
+
+    pub fn request_rename(&self, rect: Rect) {
+        for jw in self.jay_workspaces.lock().values() {
+            jw.send_rename_requested(self, rect);
+        }
+    }
+
     pub fn set_visible(&self, visible: bool) {
         self.visible.set(visible);
         for jw in self.jay_workspaces.lock().values() {
@@ -60,6 +60,7 @@ pub struct OutBufferSwapchain {
     pub cur: OutBuffer,
     pub pending: VecDeque<OutBuffer>,
     pub free: Vec<OutBuffer>,
+    pub in_flight: usize,
 }
 
 impl OutBufferSwapchain {
@@ -67,6 +68,10 @@ impl OutBufferSwapchain {
         self.pending.len() > LIMIT_PENDING
     }
 
+    pub fn is_backlogged(&self) -> bool {
+        self.pending.len() + self.in_flight >= LIMIT_PENDING
+    }
+
     pub fn commit(&mut self) {
         if self.cur.meta.write_pos > 0 {
             let new = self.free.pop().unwrap_or_default();
@@ -8,6 +8,7 @@ use {
             Flip, FlipRotate180, FlipRotate270, FlipRotate90, None, Rotate180, Rotate270, Rotate90,
         },
     },
+    std::ops::Sub,
 };
 
 pub trait TransformExt: Sized {
@@ -17,7 +18,11 @@ pub trait TransformExt: Sized {
 
     fn from_wl(wl: i32) -> Option<Self>;
 
-    fn apply_point(self, width: i32, height: i32, point: (i32, i32)) -> (i32, i32);
+    fn apply_point<T>(self, width: T, height: T, point: (T, T)) -> (T, T)
+    where
+        T: Copy + Sub<Output = T>;
+
+    fn inverse(self) -> Self;
 }
 
 impl TransformExt for Transform {
@@ -56,7 +61,10 @@ impl TransformExt for Transform {
         Some(tf)
     }
 
-    fn apply_point(self, width: i32, height: i32, (x, y): (i32, i32)) -> (i32, i32) {
+    fn apply_point<T>(self, width: T, height: T, (x, y): (T, T)) -> (T, T)
+    where
+        T: Copy + Sub<Output = T>,
+    {
         match self {
             None => (x, y),
             Rotate90 => (y, height - x),
@@ -68,4 +76,12 @@ impl TransformExt for Transform {
             FlipRotate270 => (width - y, height - x),
         }
     }
+
+    fn inverse(self) -> Self {
+        match self {
+            Rotate90 => Rotate270,
+            Rotate270 => Rotate90,
+            _ => self,
+        }
+    }
 }
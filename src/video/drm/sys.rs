@@ -715,6 +715,15 @@ pub struct drm_mode_modeinfo {
 
 unsafe impl Pod for drm_mode_modeinfo {}
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct drm_color_lut {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub reserved: u16,
+}
+
 impl Into<DrmModeInfo> for drm_mode_modeinfo {
     fn into(self) -> DrmModeInfo {
         DrmModeInfo {
@@ -917,9 +926,9 @@ struct drm_mode_create_blob {
 
 const DRM_IOCTL_MODE_CREATEPROPBLOB: u64 = drm_iowr::<drm_mode_create_blob>(0xbd);
 
-pub fn mode_create_blob<T>(fd: c::c_int, t: &T) -> Result<DrmBlob, OsError> {
+pub fn mode_create_blob<T: ?Sized>(fd: c::c_int, t: &T) -> Result<DrmBlob, OsError> {
     let mut res = drm_mode_create_blob {
-        data: t as *const T as _,
+        data: t as *const T as *const u8 as _,
         length: size_of_val(t) as _,
         blob_id: 0,
     };
@@ -217,6 +217,34 @@ impl JayCompositorEventHandler for UsrJayCompositor {
         }
         Ok(())
     }
+
+    fn keymap_names(&self, _ev: KeymapNames<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn keymap_error(&self, _ev: KeymapError<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn config_error(&self, _ev: ConfigError<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn workspace_error(&self, _ev: WorkspaceError<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn output_gamma_size(&self, _ev: OutputGammaSize, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn gamma_error(&self, _ev: GammaError<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn shortcut(&self, _ev: Shortcut, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 usr_object_base! {
@@ -247,6 +247,18 @@ impl JayScreencastEventHandler for UsrJayScreencast {
         self.pending_config.borrow_mut().height = ev.height;
         Ok(())
     }
+
+    fn config_cursor_only(
+        &self,
+        _ev: ConfigCursorOnly,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn cursor_position(&self, _ev: CursorPosition, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 usr_object_base! {
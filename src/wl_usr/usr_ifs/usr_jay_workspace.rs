@@ -81,6 +81,14 @@ impl JayWorkspaceEventHandler for UsrJayWorkspace {
         }
         Ok(())
     }
+
+    fn rename_requested(
+        &self,
+        _ev: RenameRequested<'_>,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 usr_object_base! {
@@ -13,7 +13,7 @@ use {
     isnt::std_1::primitive::IsntConstPtrExt,
     std::{
         cell::{Ref, RefCell},
-        ffi::CStr,
+        ffi::{CStr, CString},
         io::Write,
         ops::Deref,
         ptr,
@@ -31,6 +31,10 @@ pub enum XkbCommonError {
     CreateState,
     #[error("Could not create keymap from buffer")]
     KeymapFromBuffer,
+    #[error("Could not create keymap from names")]
+    KeymapFromNames,
+    #[error("Keymap names must not contain nul bytes")]
+    NulInNames,
     #[error("Could not convert the keymap to a string")]
     AsStr,
     #[error("Could not create a keymap memfd")]
@@ -70,6 +74,18 @@ impl Default for xkb_rule_names {
     }
 }
 
+/// The RMLVO names a keymap was compiled from.
+///
+/// Empty fields use the xkbcommon defaults.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct XkbRuleNames {
+    pub rules: String,
+    pub model: String,
+    pub layout: String,
+    pub variant: String,
+    pub options: String,
+}
+
 #[link(name = "xkbcommon")]
 unsafe extern "C" {
     fn xkb_context_new(flags: xkb_context_flags) -> *mut xkb_context;
@@ -83,6 +99,11 @@ unsafe extern "C" {
         format: xkb_keymap_format,
         flags: xkb_keymap_compile_flags,
     ) -> *mut xkb_keymap;
+    fn xkb_keymap_new_from_names(
+        context: *mut xkb_context,
+        names: *const xkb_rule_names,
+        flags: xkb_keymap_compile_flags,
+    ) -> *mut xkb_keymap;
     fn xkb_keymap_get_as_string(
         keymap: *mut xkb_keymap,
         format: xkb_keymap_format,
@@ -96,6 +117,11 @@ unsafe extern "C" {
         level: xkb_level_index_t,
         syms_out: *mut *const xkb_keysym_t,
     ) -> c::c_int;
+    fn xkb_keymap_layout_get_name(
+        keymap: *mut xkb_keymap,
+        idx: xkb_layout_index_t,
+    ) -> *const c::c_char;
+    fn xkb_keysym_get_name(keysym: xkb_keysym_t, buffer: *mut c::c_char, size: usize) -> c::c_int;
     fn xkb_state_unref(state: *mut xkb_state);
     fn xkb_state_new(keymap: *mut xkb_keymap) -> *mut xkb_state;
     fn xkb_state_update_key(
@@ -116,6 +142,17 @@ unsafe extern "C" {
     ) -> xkb_state_component;
 }
 
+/// Returns the name of a keysym, e.g. `Return` or `a`.
+pub fn keysym_name(sym: u32) -> String {
+    let mut buf = [0u8; 64];
+    let len = unsafe { xkb_keysym_get_name(sym, buf.as_mut_ptr().cast(), buf.len()) };
+    if len < 0 {
+        return format!("{:#x}", sym);
+    }
+    let len = (len as usize).min(buf.len() - 1);
+    String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
 pub struct XkbContext {
     context: *mut xkb_context,
     ids: KeymapIds,
@@ -143,7 +180,11 @@ impl XkbContext {
         })
     }
 
-    fn raw_to_map(&self, raw: *mut xkb_keymap) -> Result<Rc<XkbKeymap>, XkbCommonError> {
+    fn raw_to_map(
+        &self,
+        raw: *mut xkb_keymap,
+        names: Option<XkbRuleNames>,
+    ) -> Result<Rc<XkbKeymap>, XkbCommonError> {
         let res = unsafe { xkb_keymap_get_as_string(raw, XKB_KEYMAP_FORMAT_TEXT_V1.raw() as _) };
         if res.is_null() {
             unsafe {
@@ -169,6 +210,7 @@ impl XkbContext {
             keymap: raw,
             map: Rc::new(memfd),
             map_len: str.len() + 1,
+            names,
         }))
     }
 
@@ -188,7 +230,38 @@ impl XkbContext {
             if keymap.is_null() {
                 return Err(XkbCommonError::KeymapFromBuffer);
             }
-            self.raw_to_map(keymap)
+            self.raw_to_map(keymap, None)
+        }
+    }
+
+    pub fn keymap_from_names(&self, names: &XkbRuleNames) -> Result<Rc<XkbKeymap>, XkbCommonError> {
+        let cstr = |s: &str| {
+            if s.is_empty() {
+                return Ok(None);
+            }
+            CString::new(s)
+                .map(Some)
+                .map_err(|_| XkbCommonError::NulInNames)
+        };
+        let ptr = |s: &Option<CString>| s.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null());
+        let rules = cstr(&names.rules)?;
+        let model = cstr(&names.model)?;
+        let layout = cstr(&names.layout)?;
+        let variant = cstr(&names.variant)?;
+        let options = cstr(&names.options)?;
+        let raw = xkb_rule_names {
+            rules: ptr(&rules),
+            model: ptr(&model),
+            layout: ptr(&layout),
+            variant: ptr(&variant),
+            options: ptr(&options),
+        };
+        unsafe {
+            let keymap = xkb_keymap_new_from_names(self.context, &raw, 0);
+            if keymap.is_null() {
+                return Err(XkbCommonError::KeymapFromNames);
+            }
+            self.raw_to_map(keymap, Some(names.clone()))
         }
     }
 }
@@ -206,6 +279,7 @@ pub struct XkbKeymap {
     keymap: *mut xkb_keymap,
     pub map: Rc<OwnedFd>,
     pub map_len: usize,
+    pub names: Option<XkbRuleNames>,
 }
 
 impl XkbKeymap {
@@ -226,6 +300,20 @@ impl XkbKeymap {
             },
         })
     }
+
+    /// Returns the name of the layout with the given index.
+    ///
+    /// Falls back to the index if the layout does not have a name.
+    pub fn layout_name(&self, idx: u32) -> String {
+        let name = unsafe { xkb_keymap_layout_get_name(self.keymap, idx) };
+        if name.is_not_null() {
+            let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+            if !name.is_empty() {
+                return name.into_owned();
+            }
+        }
+        idx.to_string()
+    }
 }
 
 impl Drop for XkbKeymap {
@@ -381,6 +469,10 @@ impl XkbState {
         }
     }
 
+    pub fn layout_name(&self) -> String {
+        self.map.layout_name(self.kb_state.mods.group)
+    }
+
     pub fn unmodified_keysyms(&self, key: u32) -> &[xkb_keysym_t] {
         let mut res = ptr::null();
         unsafe {
@@ -5,9 +5,12 @@ use {
     crate::{
         client::{ClientCaps, ClientError},
         compositor::DISPLAY,
+        fixed::Fixed,
         forker::{ForkerError, ForkerProxy},
         ifs::{
-            ipc::{x_data_offer::XDataOffer, DataOfferId, DataSourceId, IpcLocation},
+            ipc::{
+                x_data_device::XIpcLocation, x_data_offer::XDataOffer, DataOfferId, DataSourceId,
+            },
             wl_seat::SeatId,
             wl_surface::x_surface::xwindow::{Xwindow, XwindowData},
         },
@@ -80,6 +83,8 @@ enum XWaylandError {
     XconError(#[from] XconError),
     #[error("Could not create a window to manage a selection")]
     CreateSelectionWindow(#[source] XconError),
+    #[error("Could not create the drag-and-drop proxy window")]
+    CreateDndProxy(#[source] XconError),
     #[error("Could not watch selection changes")]
     WatchSelection(#[source] XconError),
     #[error("Could not enable the xfixes extension")]
@@ -278,31 +283,59 @@ pub enum XWaylandEvent {
     SeatChanged,
 
     IpcCancelSource {
-        location: IpcLocation,
+        location: XIpcLocation,
         seat: SeatId,
         source: DataSourceId,
     },
     IpcSendSource {
-        location: IpcLocation,
+        location: XIpcLocation,
         seat: SeatId,
         source: DataSourceId,
         mime_type: String,
         fd: Rc<OwnedFd>,
     },
     IpcSetOffer {
-        location: IpcLocation,
+        location: XIpcLocation,
         seat: SeatId,
         offer: Rc<XDataOffer>,
     },
     IpcSetSelection {
-        location: IpcLocation,
+        location: XIpcLocation,
         seat: SeatId,
         offer: Option<Rc<XDataOffer>>,
     },
     IpcAddOfferMimeType {
-        location: IpcLocation,
+        location: XIpcLocation,
         seat: SeatId,
         offer: DataOfferId,
         mime_type: String,
     },
+
+    DndEnter {
+        seat: SeatId,
+        offer: DataOfferId,
+        surface: WlSurfaceId,
+        x: Fixed,
+        y: Fixed,
+    },
+    DndMotion {
+        seat: SeatId,
+        surface: WlSurfaceId,
+        x: Fixed,
+        y: Fixed,
+    },
+    DndLeave {
+        seat: SeatId,
+    },
+    DndDrop {
+        seat: SeatId,
+    },
+    DndSourceStatus {
+        seat: SeatId,
+        source: DataSourceId,
+    },
+    DndSourceFinished {
+        seat: SeatId,
+        source: DataSourceId,
+    },
 }
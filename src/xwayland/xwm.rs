@@ -4,14 +4,18 @@ use {
     crate::{
         async_engine::SpawnedFuture,
         client::Client,
+        fixed::Fixed,
         ifs::{
             ipc::{
-                add_data_source_mime_type, destroy_data_device, destroy_data_offer,
+                add_data_source_mime_type, attach_seat, destroy_data_device, destroy_data_offer,
                 destroy_data_source, receive_data_offer,
-                x_data_device::{XClipboardIpc, XIpc, XIpcDevice, XPrimarySelectionIpc},
+                wl_data_device_manager::{DND_ASK, DND_COPY, DND_MOVE, DND_NONE},
+                x_data_device::{
+                    XClipboardIpc, XDndIpc, XIpc, XIpcDevice, XIpcLocation, XPrimarySelectionIpc,
+                },
                 x_data_offer::XDataOffer,
                 x_data_source::XDataSource,
-                DataOfferId, DataSourceId, DynDataOffer, DynDataSource, IpcLocation, IpcVtable,
+                DataOfferId, DataSourceId, DynDataOffer, DynDataSource, IpcVtable, Role,
                 SourceData,
             },
             wl_seat::{SeatId, WlSeatGlobal},
@@ -23,7 +27,7 @@ use {
         io_uring::{IoUring, IoUringError},
         rect::Rect,
         state::State,
-        tree::{Node, ToplevelNode},
+        tree::{FindTreeUsecase, FoundNode, Node, ToplevelNode},
         utils::{
             bitflags::BitflagsExt, buf::Buf, cell_ext::CellExt, clonecell::CloneCell,
             copyhashmap::CopyHashMap, errorfmt::ErrorFmt, hash_map_ext::HashMapExt,
@@ -41,7 +45,8 @@ use {
         },
         xcon::{
             consts::{
-                ATOM_ATOM, ATOM_NONE, ATOM_STRING, ATOM_WINDOW, ATOM_WM_CLASS, ATOM_WM_NAME,
+                _NET_WM_STATE_ADD, _NET_WM_STATE_REMOVE, _NET_WM_STATE_TOGGLE, ATOM_ATOM,
+                ATOM_NONE, ATOM_STRING, ATOM_WINDOW, ATOM_WM_CLASS, ATOM_WM_NAME,
                 ATOM_WM_SIZE_HINTS, ATOM_WM_TRANSIENT_FOR, COMPOSITE_REDIRECT_MANUAL,
                 CONFIG_WINDOW_HEIGHT, CONFIG_WINDOW_WIDTH, CONFIG_WINDOW_X, CONFIG_WINDOW_Y,
                 EVENT_MASK_FOCUS_CHANGE, EVENT_MASK_PROPERTY_CHANGE,
@@ -52,8 +57,7 @@ use {
                 PROP_MODE_APPEND, PROP_MODE_REPLACE, RES_CLIENT_ID_MASK_LOCAL_CLIENT_PID,
                 SELECTION_CLIENT_CLOSE_MASK, SELECTION_WINDOW_DESTROY_MASK,
                 SET_SELECTION_OWNER_MASK, STACK_MODE_ABOVE, STACK_MODE_BELOW,
-                WINDOW_CLASS_INPUT_OUTPUT, _NET_WM_STATE_ADD, _NET_WM_STATE_REMOVE,
-                _NET_WM_STATE_TOGGLE,
+                WINDOW_CLASS_INPUT_ONLY, WINDOW_CLASS_INPUT_OUTPUT,
             },
             Event, XEvent, Xcon, XconError,
         },
@@ -152,6 +156,8 @@ atoms! {
     XdndTypeList,
 }
 
+const XDND_VERSION: u32 = 5;
+
 struct EnhancedOffer {
     offer: Rc<XDataOffer>,
     mime_types: RefCell<Vec<u32>>,
@@ -200,6 +206,7 @@ pub struct XwmShared {
     devices: CopyHashMap<SeatId, Rc<XIpcDevice>>,
     data: SelectionData<XClipboardIpc>,
     primary_selection: SelectionData<XPrimarySelectionIpc>,
+    dnd: SelectionData<XDndIpc>,
     transfers: CopyHashMap<u64, SpawnedFuture<()>>,
 }
 
@@ -207,9 +214,11 @@ impl Drop for XwmShared {
     fn drop(&mut self) {
         self.data.destroy();
         self.primary_selection.destroy();
+        self.dnd.destroy();
         for device in self.devices.lock().drain_values() {
             destroy_data_device::<XClipboardIpc>(&device);
             destroy_data_device::<XPrimarySelectionIpc>(&device);
+            destroy_data_device::<XDndIpc>(&device);
             device.seat.unset_x_data_device(device.id);
         }
         self.transfers.clear();
@@ -242,6 +251,35 @@ pub struct Wm {
 
     map_list: LinkedList<Rc<XwindowData>>,
     num_mapped: usize,
+
+    dnd_target: Option<DndTarget>,
+
+    dnd_proxy: u32,
+    dnd_source: Option<DndSource>,
+}
+
+struct DndTarget {
+    seat: SeatId,
+    offer: Rc<EnhancedOffer>,
+    window: Rc<XwindowData>,
+    destination: u32,
+    version: u32,
+    awaiting_status: bool,
+    pending_position: Option<(i32, i32)>,
+    dropped: bool,
+}
+
+/// A drag started by an X client that is offered to wayland clients.
+///
+/// X clients send their XDND messages to the proxy window whenever the pointer is not over
+/// an X window. Wayland windows that are stacked above X windows are therefore not
+/// detected as drop targets.
+struct DndSource {
+    seat: Rc<WlSeatGlobal>,
+    source: Rc<XDataSource>,
+    window: u32,
+    target: Option<Rc<WlSurface>>,
+    dropped: bool,
 }
 
 struct PendingTransfer {
@@ -273,6 +311,9 @@ impl Drop for Wm {
         self.windows_by_surface_serial.clear();
         self.focus_window.take();
         self.known_seats.clear();
+        if let Some(ds) = self.dnd_source.take() {
+            ds.source.cancel_dnd();
+        }
     }
 }
 
@@ -504,6 +545,73 @@ impl Wm {
         shared.data.selection.set(atoms.CLIPBOARD);
         shared.primary_selection.win.set(clipboard_wins[1]);
         shared.primary_selection.selection.set(atoms.PRIMARY);
+        {
+            let win = c.generate_id()?;
+            let cw = CreateWindow {
+                depth: 0,
+                wid: win,
+                parent: root,
+                x: 0,
+                y: 0,
+                width: 10,
+                height: 10,
+                border_width: 0,
+                class: WINDOW_CLASS_INPUT_OUTPUT,
+                visual: 0,
+                values: Default::default(),
+            };
+            if let Err(e) = c.call(&cw).await {
+                return Err(XWaylandError::CreateSelectionWindow(e));
+            }
+            shared.dnd.win.set(win);
+            shared.dnd.selection.set(atoms.XdndSelection);
+        }
+        let dnd_proxy = c.generate_id()?;
+        {
+            let cw = CreateWindow {
+                depth: 0,
+                wid: dnd_proxy,
+                parent: root,
+                x: 0,
+                y: 0,
+                width: i16::MAX as u16,
+                height: i16::MAX as u16,
+                border_width: 0,
+                class: WINDOW_CLASS_INPUT_ONLY,
+                visual: 0,
+                values: CreateWindowValues {
+                    override_redirect: Some(1),
+                    ..Default::default()
+                },
+            };
+            if let Err(e) = c.call(&cw).await {
+                return Err(XWaylandError::CreateDndProxy(e));
+            }
+            let cp = ChangeProperty {
+                mode: PROP_MODE_REPLACE,
+                window: dnd_proxy,
+                property: atoms.XdndAware,
+                ty: ATOM_ATOM,
+                format: 32,
+                data: uapi::as_bytes(&[XDND_VERSION][..]),
+            };
+            if let Err(e) = c.call(&cp).await {
+                return Err(XWaylandError::CreateDndProxy(e));
+            }
+            let cw = ConfigureWindow {
+                window: dnd_proxy,
+                values: ConfigureWindowValues {
+                    stack_mode: Some(STACK_MODE_BELOW),
+                    ..Default::default()
+                },
+            };
+            if let Err(e) = c.call(&cw).await {
+                return Err(XWaylandError::CreateDndProxy(e));
+            }
+            if let Err(e) = c.call(&MapWindow { window: dnd_proxy }).await {
+                return Err(XWaylandError::CreateDndProxy(e));
+            }
+        }
         Ok(Self {
             state: state.clone(),
             c,
@@ -527,6 +635,9 @@ impl Wm {
             num_stacked: 0,
             map_list: Default::default(),
             num_mapped: 0,
+            dnd_target: None,
+            dnd_proxy,
+            dnd_source: None,
         })
     }
 
@@ -554,6 +665,15 @@ impl Wm {
         for seat in removed_seats {
             self.shared.data.seat_removed(seat);
             self.shared.primary_selection.seat_removed(seat);
+            self.shared.dnd.seat_removed(seat);
+            if self.dnd_target.as_ref().map(|t| t.seat) == Some(seat) {
+                self.dnd_target = None;
+            }
+            if self.dnd_source.as_ref().map(|s| s.seat.id()) == Some(seat) {
+                if let Some(ds) = self.dnd_source.take() {
+                    ds.source.cancel_dnd();
+                }
+            }
             self.shared.devices.remove(&seat);
         }
         for seat in new_seats {
@@ -561,6 +681,7 @@ impl Wm {
                 id: self.state.xwayland.ipc_device_ids.next(),
                 clipboard: Default::default(),
                 primary_selection: Default::default(),
+                dnd: Default::default(),
                 seat: seat.clone(),
                 state: self.state.clone(),
                 client: self.client.clone(),
@@ -605,14 +726,17 @@ impl Wm {
                 seat,
                 source,
             } => match location {
-                IpcLocation::Clipboard => {
+                XIpcLocation::Clipboard => {
                     self.dd_cancel_source::<XClipboardIpc>(&self.shared.clone().data, seat, source)
                 }
-                IpcLocation::PrimarySelection => self.dd_cancel_source::<XPrimarySelectionIpc>(
+                XIpcLocation::PrimarySelection => self.dd_cancel_source::<XPrimarySelectionIpc>(
                     &self.shared.clone().primary_selection,
                     seat,
                     source,
                 ),
+                XIpcLocation::Dnd => {
+                    self.dd_cancel_source::<XDndIpc>(&self.shared.clone().dnd, seat, source)
+                }
             },
             XWaylandEvent::IpcSendSource {
                 location,
@@ -621,7 +745,7 @@ impl Wm {
                 mime_type,
                 fd,
             } => match location {
-                IpcLocation::Clipboard => {
+                XIpcLocation::Clipboard => {
                     self.dd_send_source::<XClipboardIpc>(
                         &self.shared.clone().data,
                         seat,
@@ -631,7 +755,7 @@ impl Wm {
                     )
                     .await
                 }
-                IpcLocation::PrimarySelection => {
+                XIpcLocation::PrimarySelection => {
                     self.dd_send_source::<XPrimarySelectionIpc>(
                         &self.shared.clone().primary_selection,
                         seat,
@@ -641,17 +765,27 @@ impl Wm {
                     )
                     .await
                 }
+                XIpcLocation::Dnd => {
+                    self.dd_send_source::<XDndIpc>(
+                        &self.shared.clone().dnd,
+                        seat,
+                        source,
+                        mime_type,
+                        fd,
+                    )
+                    .await
+                }
             },
             XWaylandEvent::IpcSetOffer {
                 location,
                 seat,
                 offer,
             } => match location {
-                IpcLocation::Clipboard => {
+                XIpcLocation::Clipboard => {
                     self.dd_set_offer::<XClipboardIpc>(&self.shared.clone().data, seat, offer)
                         .await
                 }
-                IpcLocation::PrimarySelection => {
+                XIpcLocation::PrimarySelection => {
                     self.dd_set_offer::<XPrimarySelectionIpc>(
                         &self.shared.clone().primary_selection,
                         seat,
@@ -659,17 +793,21 @@ impl Wm {
                     )
                     .await
                 }
+                XIpcLocation::Dnd => {
+                    self.dd_set_offer::<XDndIpc>(&self.shared.clone().dnd, seat, offer)
+                        .await
+                }
             },
             XWaylandEvent::IpcSetSelection {
                 seat,
                 location,
                 offer,
             } => match location {
-                IpcLocation::Clipboard => {
+                XIpcLocation::Clipboard => {
                     self.dd_set_selection::<XClipboardIpc>(&self.shared.clone().data, seat, offer)
                         .await
                 }
-                IpcLocation::PrimarySelection => {
+                XIpcLocation::PrimarySelection => {
                     self.dd_set_selection::<XPrimarySelectionIpc>(
                         &self.shared.clone().primary_selection,
                         seat,
@@ -677,6 +815,10 @@ impl Wm {
                     )
                     .await
                 }
+                XIpcLocation::Dnd => {
+                    self.dd_set_selection::<XDndIpc>(&self.shared.clone().dnd, seat, offer)
+                        .await
+                }
             },
             XWaylandEvent::IpcAddOfferMimeType {
                 location,
@@ -684,7 +826,7 @@ impl Wm {
                 offer,
                 mime_type,
             } => match location {
-                IpcLocation::Clipboard => {
+                XIpcLocation::Clipboard => {
                     self.dd_add_offer_mime_type::<XClipboardIpc>(
                         &self.shared.clone().data,
                         seat,
@@ -693,7 +835,7 @@ impl Wm {
                     )
                     .await
                 }
-                IpcLocation::PrimarySelection => {
+                XIpcLocation::PrimarySelection => {
                     self.dd_add_offer_mime_type::<XPrimarySelectionIpc>(
                         &self.shared.clone().primary_selection,
                         seat,
@@ -702,10 +844,560 @@ impl Wm {
                     )
                     .await
                 }
+                XIpcLocation::Dnd => {
+                    self.dd_add_offer_mime_type::<XDndIpc>(
+                        &self.shared.clone().dnd,
+                        seat,
+                        offer,
+                        mime_type,
+                    )
+                    .await
+                }
             },
+            XWaylandEvent::DndEnter {
+                seat,
+                offer,
+                surface,
+                x,
+                y,
+            } => self.handle_dnd_enter(seat, offer, surface, x, y).await,
+            XWaylandEvent::DndMotion {
+                seat,
+                surface,
+                x,
+                y,
+            } => self.handle_dnd_motion(seat, surface, x, y).await,
+            XWaylandEvent::DndLeave { seat } => self.handle_dnd_leave(seat).await,
+            XWaylandEvent::DndDrop { seat } => self.handle_dnd_drop(seat).await,
+            XWaylandEvent::DndSourceStatus { seat, source } => {
+                self.handle_dnd_source_status(seat, source).await
+            }
+            XWaylandEvent::DndSourceFinished { seat, source } => {
+                self.handle_dnd_source_finished(seat, source).await
+            }
+        }
+    }
+
+    fn window_for_surface(&self, surface: WlSurfaceId) -> Option<Rc<XwindowData>> {
+        self.windows
+            .values()
+            .find(|w| w.window.get().map(|w| w.x.surface.id) == Some(surface))
+            .cloned()
+    }
+
+    async fn get_xdnd_destination(&self, window: u32) -> Option<(u32, u32)> {
+        let mut destination = window;
+        let mut buf = vec![];
+        let res = self
+            .c
+            .get_property::<u32>(window, self.atoms.XdndProxy, ATOM_WINDOW, &mut buf)
+            .await;
+        if res.is_ok() {
+            if let Some(&proxy) = buf.first() {
+                destination = proxy;
+            }
+        }
+        buf.clear();
+        let res = self
+            .c
+            .get_property::<u32>(destination, self.atoms.XdndAware, ATOM_ATOM, &mut buf)
+            .await;
+        match res {
+            Ok(_) => {}
+            Err(XconError::PropertyUnavailable) => return None,
+            Err(e) => {
+                log::error!("Could not retrieve XdndAware property: {}", ErrorFmt(e));
+                return None;
+            }
+        }
+        let version = *buf.first()?;
+        Some((destination, version.min(XDND_VERSION)))
+    }
+
+    async fn send_xdnd_message(&self, target: &DndTarget, ty: u32, data: &[u32; 5]) {
+        let event = ClientMessage {
+            format: 32,
+            window: target.window.window_id,
+            ty,
+            data,
+        };
+        if let Err(e) = self
+            .c
+            .send_event(false, target.destination, 0, &event)
+            .await
+        {
+            log::error!("Could not send XDND message: {}", ErrorFmt(e));
+        }
+    }
+
+    async fn handle_dnd_enter(
+        &mut self,
+        seat: SeatId,
+        offer: DataOfferId,
+        surface: WlSurfaceId,
+        x: Fixed,
+        y: Fixed,
+    ) {
+        self.dnd_leave_target().await;
+        let sd = &self.shared.clone().dnd;
+        let offer = match sd.offers.get(&seat) {
+            Some(o) if o.offer.offer_id == offer => o,
+            _ => return,
+        };
+        let Some(window) = self.window_for_surface(surface) else {
+            return;
+        };
+        let Some((destination, version)) = self.get_xdnd_destination(window.window_id).await else {
+            return;
+        };
+        if !offer.active.replace(true) {
+            if let Some(old) = sd.active_offer.set(Some(offer.clone())) {
+                old.active.set(false);
+            }
+        }
+        let so = SetSelectionOwner {
+            owner: sd.win.get(),
+            selection: sd.selection.get(),
+            time: 0,
+        };
+        if let Err(e) = self.c.call(&so).await {
+            log::error!("Could not set XdndSelection owner: {}", ErrorFmt(e));
+            return;
+        }
+        let mime_types = offer.mime_types.borrow().clone();
+        if mime_types.len() > 3 {
+            let cp = ChangeProperty {
+                mode: PROP_MODE_REPLACE,
+                window: sd.win.get(),
+                property: self.atoms.XdndTypeList,
+                ty: ATOM_ATOM,
+                format: 32,
+                data: uapi::as_bytes(&mime_types[..]),
+            };
+            if let Err(e) = self.c.call(&cp).await {
+                log::error!("Could not set XdndTypeList property: {}", ErrorFmt(e));
+            }
+        }
+        let target = DndTarget {
+            seat,
+            offer,
+            window,
+            destination,
+            version,
+            awaiting_status: false,
+            pending_position: None,
+            dropped: false,
+        };
+        let mut data = [
+            sd.win.get(),
+            (version << 24) | (mime_types.len() > 3) as u32,
+            0,
+            0,
+            0,
+        ];
+        for (dst, mt) in data[2..].iter_mut().zip(mime_types.iter()) {
+            *dst = *mt;
+        }
+        self.send_xdnd_message(&target, self.atoms.XdndEnter, &data)
+            .await;
+        self.dnd_target = Some(target);
+        self.handle_dnd_motion(seat, surface, x, y).await;
+    }
+
+    async fn handle_dnd_motion(&mut self, seat: SeatId, surface: WlSurfaceId, x: Fixed, y: Fixed) {
+        let Some(target) = &mut self.dnd_target else {
+            return;
+        };
+        if target.seat != seat || target.dropped {
+            return;
+        }
+        if target.window.window.get().map(|w| w.x.surface.id) != Some(surface) {
+            return;
+        }
+        let extents = target.window.info.extents.get();
+        let mut x = extents.x1() + x.round_down();
+        let mut y = extents.y1() + y.round_down();
+        logical_to_client_wire_scale!(self.client, x, y);
+        if target.awaiting_status {
+            target.pending_position = Some((x, y));
+            return;
+        }
+        self.send_xdnd_position(x, y).await;
+    }
+
+    async fn send_xdnd_position(&mut self, x: i32, y: i32) {
+        let Some(target) = &mut self.dnd_target else {
+            return;
+        };
+        target.awaiting_status = true;
+        let actions = target.offer.offer.source_actions();
+        let action = if actions.contains(DND_COPY) {
+            self.atoms.XdndActionCopy
+        } else if actions.contains(DND_MOVE) {
+            self.atoms.XdndActionMove
+        } else if actions.contains(DND_ASK) {
+            self.atoms.XdndActionAsk
+        } else {
+            ATOM_NONE
+        };
+        let data = [
+            self.shared.dnd.win.get(),
+            0,
+            ((x as u32) << 16) | (y as u32 & 0xffff),
+            0,
+            action,
+        ];
+        if let Some(target) = &self.dnd_target {
+            self.send_xdnd_message(target, self.atoms.XdndPosition, &data)
+                .await;
+        }
+    }
+
+    async fn handle_dnd_leave(&mut self, seat: SeatId) {
+        match &self.dnd_target {
+            Some(target) if target.seat == seat && !target.dropped => {}
+            _ => return,
+        }
+        self.dnd_leave_target().await;
+    }
+
+    async fn dnd_leave_target(&mut self) {
+        let Some(target) = self.dnd_target.take() else {
+            return;
+        };
+        if !target.dropped {
+            let data = [self.shared.dnd.win.get(), 0, 0, 0, 0];
+            self.send_xdnd_message(&target, self.atoms.XdndLeave, &data)
+                .await;
+        }
+        let sd = &self.shared.dnd;
+        if let Some(offer) = sd.offers.remove(&target.seat) {
+            destroy_data_offer::<XDndIpc>(&offer.offer);
+            if offer.active.get() {
+                sd.active_offer.take();
+            }
+        }
+    }
+
+    async fn handle_dnd_drop(&mut self, seat: SeatId) {
+        let Some(target) = &mut self.dnd_target else {
+            return;
+        };
+        if target.seat != seat || target.dropped {
+            return;
+        }
+        if !target.offer.offer.is_droppable() {
+            self.dnd_leave_target().await;
+            return;
+        }
+        target.dropped = true;
+        let data = [self.shared.dnd.win.get(), 0, 0, 0, 0];
+        if let Some(target) = &self.dnd_target {
+            self.send_xdnd_message(target, self.atoms.XdndDrop, &data)
+                .await;
+        }
+    }
+
+    async fn handle_xdnd_status(&mut self, event: &ClientMessage<'_>) -> Result<(), XWaylandError> {
+        let Some(target) = &mut self.dnd_target else {
+            return Ok(());
+        };
+        if event.data[0] != target.window.window_id || target.dropped {
+            return Ok(());
+        }
+        target.awaiting_status = false;
+        let pending_position = target.pending_position.take();
+        let offer = target.offer.clone();
+        let mut mime_type = None;
+        let mut action = DND_NONE;
+        if event.data[1] & 1 != 0 {
+            let first = offer.mime_types.borrow().first().copied();
+            if let Some(first) = first {
+                match self.atom_to_mime_type(first).await {
+                    Ok(mt) => mime_type = Some(mt),
+                    Err(e) => log::error!("Could not get mime type name: {}", ErrorFmt(e)),
+                }
+            }
+            action = if event.data[4] == self.atoms.XdndActionMove {
+                DND_MOVE
+            } else if event.data[4] == self.atoms.XdndActionAsk {
+                DND_ASK
+            } else {
+                DND_COPY
+            };
+        }
+        offer.offer.dnd_status(mime_type.as_deref(), action);
+        if let Some((x, y)) = pending_position {
+            self.send_xdnd_position(x, y).await;
+        }
+        Ok(())
+    }
+
+    async fn handle_xdnd_finished(
+        &mut self,
+        event: &ClientMessage<'_>,
+    ) -> Result<(), XWaylandError> {
+        let target = match self.dnd_target.take() {
+            Some(t) if t.dropped && event.data[0] == t.window.window_id => t,
+            t => {
+                self.dnd_target = t;
+                return Ok(());
+            }
+        };
+        if target.version < 5 || event.data[1] & 1 != 0 {
+            target.offer.offer.dnd_finished();
+        }
+        let sd = &self.shared.dnd;
+        if let Some(offer) = sd.offers.remove(&target.seat) {
+            destroy_data_offer::<XDndIpc>(&offer.offer);
+            if offer.active.get() {
+                sd.active_offer.take();
+            }
+        }
+        Ok(())
+    }
+
+    async fn send_dnd_source_message(&self, window: u32, ty: u32, data: &[u32; 5]) {
+        let event = ClientMessage {
+            format: 32,
+            window,
+            ty,
+            data,
+        };
+        if let Err(e) = self.c.send_event(false, window, 0, &event).await {
+            log::error!("Could not send XDND message: {}", ErrorFmt(e));
+        }
+    }
+
+    fn dnd_action_to_atom(&self, action: u32) -> u32 {
+        match action {
+            DND_COPY => self.atoms.XdndActionCopy,
+            DND_MOVE => self.atoms.XdndActionMove,
+            DND_ASK => self.atoms.XdndActionAsk,
+            _ => ATOM_NONE,
         }
     }
 
+    fn is_dnd_source_message(&self, event: &ClientMessage<'_>) -> bool {
+        match &self.dnd_source {
+            Some(ds) => event.window == self.dnd_proxy && event.data[0] == ds.window && !ds.dropped,
+            None => false,
+        }
+    }
+
+    fn wayland_surface_at(&self, x: i32, y: i32) -> Option<(Rc<WlSurface>, i32, i32)> {
+        let mut found_tree = vec![FoundNode {
+            node: self.state.root.clone(),
+            x,
+            y,
+        }];
+        self.state
+            .root
+            .node_find_tree_at(x, y, &mut found_tree, FindTreeUsecase::None);
+        let FoundNode { node, x, y } = found_tree.pop()?;
+        let surface = node.node_into_surface()?;
+        if surface.client.is_xwayland {
+            return None;
+        }
+        Some((surface, x, y))
+    }
+
+    async fn handle_xdnd_enter(&mut self, event: &ClientMessage<'_>) -> Result<(), XWaylandError> {
+        if event.window != self.dnd_proxy {
+            return Ok(());
+        }
+        self.dnd_source_leave();
+        let Some(seat) = self.state.seat_queue.last().as_deref().cloned() else {
+            return Ok(());
+        };
+        let Some(device) = self.shared.devices.get(&seat.id()) else {
+            return Ok(());
+        };
+        let window = event.data[0];
+        let mut types = vec![];
+        if event.data[1] & 1 != 0 {
+            let res = self
+                .c
+                .get_property::<u32>(window, self.atoms.XdndTypeList, ATOM_ATOM, &mut types)
+                .await;
+            if let Err(e) = res {
+                log::error!("Could not retrieve XdndTypeList property: {}", ErrorFmt(e));
+            }
+        } else {
+            types.extend(event.data[2..].iter().copied().filter(|&t| t != ATOM_NONE));
+        }
+        let source = Rc::new(XDataSource {
+            state: self.state.clone(),
+            device,
+            data: SourceData::new(&self.client),
+            location: XIpcLocation::Dnd,
+        });
+        for ty in types {
+            match self.atom_to_mime_type(ty).await {
+                Ok(mt) => add_data_source_mime_type::<XDndIpc>(&source, &mt),
+                Err(e) => log::error!("Could not get mime type name: {}", ErrorFmt(e)),
+            }
+        }
+        source.set_dnd_actions(DND_COPY);
+        if let Err(e) = attach_seat(&*source, &seat, Role::Dnd) {
+            log::error!("Could not attach the drag source: {}", ErrorFmt(e));
+            return Ok(());
+        }
+        self.shared.dnd.sources.set(seat.id(), source.clone());
+        self.dnd_source = Some(DndSource {
+            seat,
+            source,
+            window,
+            target: None,
+            dropped: false,
+        });
+        Ok(())
+    }
+
+    async fn handle_xdnd_position(
+        &mut self,
+        event: &ClientMessage<'_>,
+    ) -> Result<(), XWaylandError> {
+        if !self.is_dnd_source_message(event) {
+            return Ok(());
+        }
+        let mut x = (event.data[2] >> 16) as i16 as i32;
+        let mut y = event.data[2] as i16 as i32;
+        client_wire_scale_to_logical!(self.client, x, y);
+        let action = if event.data[4] == self.atoms.XdndActionMove {
+            DND_MOVE
+        } else if event.data[4] == self.atoms.XdndActionAsk {
+            DND_ASK
+        } else {
+            DND_COPY
+        };
+        let target = self.wayland_surface_at(x, y);
+        let Some(ds) = &mut self.dnd_source else {
+            return Ok(());
+        };
+        ds.source.set_dnd_actions(action);
+        match target {
+            Some((surface, x, y)) if ds.target.as_ref().is_some_and(|t| rc_eq(t, &surface)) => {
+                ds.seat.x_dnd_surface_motion(
+                    &surface,
+                    self.state.now_usec(),
+                    Fixed::from_int(x),
+                    Fixed::from_int(y),
+                );
+            }
+            target => {
+                if let Some(old) = ds.target.take() {
+                    ds.seat.x_dnd_surface_leave(&old, &ds.source);
+                }
+                if let Some((surface, x, y)) = target {
+                    let serial = self.state.next_serial(Some(&surface.client));
+                    ds.seat.x_dnd_surface_enter(
+                        &surface,
+                        &ds.source,
+                        Fixed::from_int(x),
+                        Fixed::from_int(y),
+                        serial,
+                    );
+                    ds.target = Some(surface);
+                }
+            }
+        }
+        self.send_xdnd_status_to_source().await;
+        Ok(())
+    }
+
+    async fn send_xdnd_status_to_source(&self) {
+        let Some(ds) = &self.dnd_source else {
+            return;
+        };
+        if ds.dropped {
+            return;
+        }
+        let accepted = ds.target.is_some() && ds.source.can_drop();
+        let action = match accepted {
+            true => self.dnd_action_to_atom(ds.source.selected_action()),
+            false => ATOM_NONE,
+        };
+        // Bit 1 requests a position message for every pointer motion.
+        let data = [self.dnd_proxy, accepted as u32 | 2, 0, 0, action];
+        self.send_dnd_source_message(ds.window, self.atoms.XdndStatus, &data)
+            .await;
+    }
+
+    fn handle_xdnd_leave(&mut self, event: &ClientMessage<'_>) {
+        if self.is_dnd_source_message(event) {
+            self.dnd_source_leave();
+        }
+    }
+
+    fn dnd_source_leave(&mut self) {
+        let Some(ds) = self.dnd_source.take() else {
+            return;
+        };
+        if !ds.dropped {
+            if let Some(target) = &ds.target {
+                ds.seat.x_dnd_surface_leave(target, &ds.source);
+            }
+            ds.source.cancel_dnd();
+        }
+        self.remove_dnd_source(&ds);
+    }
+
+    fn remove_dnd_source(&self, ds: &DndSource) {
+        let sources = &self.shared.dnd.sources;
+        if let Some(source) = sources.get(&ds.seat.id()) {
+            if rc_eq(&source, &ds.source) {
+                sources.remove(&ds.seat.id());
+            }
+        }
+    }
+
+    async fn handle_xdnd_drop(&mut self, event: &ClientMessage<'_>) {
+        if !self.is_dnd_source_message(event) {
+            return;
+        }
+        let Some(ds) = &mut self.dnd_source else {
+            return;
+        };
+        if let Some(target) = &ds.target {
+            if ds.source.can_drop() {
+                ds.dropped = true;
+                ds.seat.x_dnd_surface_drop(target);
+                ds.source.on_drop();
+                ds.seat.x_dnd_surface_leave(target, &ds.source);
+                return;
+            }
+        }
+        let window = ds.window;
+        self.dnd_source_leave();
+        let data = [self.dnd_proxy, 0, 0, 0, 0];
+        self.send_dnd_source_message(window, self.atoms.XdndFinished, &data)
+            .await;
+    }
+
+    async fn handle_dnd_source_status(&mut self, seat: SeatId, source: DataSourceId) {
+        match &self.dnd_source {
+            Some(ds) if ds.seat.id() == seat && ds.source.data.id == source => {}
+            _ => return,
+        }
+        self.send_xdnd_status_to_source().await;
+    }
+
+    async fn handle_dnd_source_finished(&mut self, seat: SeatId, source: DataSourceId) {
+        let ds = match self.dnd_source.take() {
+            Some(ds) if ds.dropped && ds.seat.id() == seat && ds.source.data.id == source => ds,
+            ds => {
+                self.dnd_source = ds;
+                return;
+            }
+        };
+        let action = self.dnd_action_to_atom(ds.source.selected_action());
+        let data = [self.dnd_proxy, 1, action, 0, 0];
+        self.send_dnd_source_message(ds.window, self.atoms.XdndFinished, &data)
+            .await;
+        self.remove_dnd_source(&ds);
+    }
+
     async fn dd_add_offer_mime_type<T: XIpc>(
         &mut self,
         sd: &SelectionData<T>,
@@ -1615,6 +2307,8 @@ impl Wm {
                 .await
         } else if event.selection == self.atoms.CLIPBOARD {
             self.handle_selection_request_(&shared.data, &event).await
+        } else if event.selection == self.atoms.XdndSelection {
+            self.handle_selection_request_(&shared.dnd, &event).await
         } else {
             log::warn!("Unknown selection request");
             Ok(())
@@ -1717,6 +2411,8 @@ impl Wm {
                 .await
         } else if event.selection == self.atoms.CLIPBOARD {
             self.handle_selection_notify_(&shared.data, &event).await
+        } else if event.selection == self.atoms.XdndSelection {
+            self.handle_selection_notify_(&shared.dnd, &event).await
         } else {
             Ok(())
         }
@@ -1750,10 +2446,11 @@ impl Wm {
                     add_data_source_mime_type::<T>(&source, target);
                 }
                 let res = match source.location {
-                    IpcLocation::Clipboard => seat.set_selection(Some(source.clone())),
-                    IpcLocation::PrimarySelection => {
+                    XIpcLocation::Clipboard => seat.set_selection(Some(source.clone())),
+                    XIpcLocation::PrimarySelection => {
                         seat.set_primary_selection(Some(source.clone()))
                     }
+                    XIpcLocation::Dnd => continue,
                 };
                 if let Err(e) = res {
                     log::error!("Could not set selection: {}", ErrorFmt(e));
@@ -2011,7 +2708,7 @@ impl Wm {
 
     async fn handle_create_notify(&mut self, event: &Event) -> Result<(), XWaylandError> {
         let event: CreateNotify = event.parse()?;
-        if event.window == self.xwin {
+        if event.window == self.xwin || event.window == self.dnd_proxy {
             return Ok(());
         }
         let data = Rc::new(XwindowData::new(&self.state, &event, &self.client));
@@ -2059,6 +2756,18 @@ impl Wm {
             self.handle_net_wm_moveresize(&event).await?;
         } else if event.ty == self.atoms.WL_SURFACE_SERIAL {
             self.handle_wl_surface_serial(&event).await?;
+        } else if event.ty == self.atoms.XdndStatus {
+            self.handle_xdnd_status(&event).await?;
+        } else if event.ty == self.atoms.XdndFinished {
+            self.handle_xdnd_finished(&event).await?;
+        } else if event.ty == self.atoms.XdndEnter {
+            self.handle_xdnd_enter(&event).await?;
+        } else if event.ty == self.atoms.XdndPosition {
+            self.handle_xdnd_position(&event).await?;
+        } else if event.ty == self.atoms.XdndLeave {
+            self.handle_xdnd_leave(&event);
+        } else if event.ty == self.atoms.XdndDrop {
+            self.handle_xdnd_drop(&event).await;
         }
         Ok(())
     }
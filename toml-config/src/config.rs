@@ -17,12 +17,16 @@ use {
     },
     ahash::AHashMap,
     jay_config::{
-        input::{acceleration::AccelProfile, SwitchEvent},
+        input::{
+            acceleration::AccelProfile, HotCorner, OutputEdge, PointerEdgeBehavior, PointerTrigger,
+            SwitchEvent,
+        },
         keyboard::{mods::Modifiers, Keymap, ModifiedKeySym},
         logging::LogLevel,
         status::MessageFormat,
         theme::Color,
-        video::{Format, GfxApi, TearingMode, Transform, VrrMode},
+        video::{ColorMatrix, Format, GfxApi, TearingMode, TitleBarPosition, Transform, VrrMode},
+        window::DecorationMode,
         xwayland::XScalingMode,
         Axis, Direction, Workspace,
     },
@@ -40,6 +44,11 @@ pub enum SimpleCommand {
     Close,
     DisablePointerConstraint,
     Focus(Direction),
+    LocatePointer,
+    ZoomIn,
+    ZoomOut,
+    ResetZoom,
+    ToggleInvertColors,
     FocusParent,
     Move(Direction),
     None,
@@ -48,11 +57,13 @@ pub enum SimpleCommand {
     ReloadConfigToml,
     Split(Axis),
     ToggleFloating,
+    ToggleSticky,
     ToggleFullscreen,
     ToggleMono,
     ToggleSplit,
     Forward(bool),
     EnableWindowManagement(bool),
+    CycleLayout,
 }
 
 #[derive(Debug, Clone)]
@@ -67,7 +78,7 @@ pub enum Action {
         dev: ConfigDrmDevice,
     },
     ConfigureIdle {
-        idle: Duration,
+        idle: Idle,
     },
     ConfigureInput {
         input: Box<Input>,
@@ -143,8 +154,15 @@ pub struct Theme {
     pub unfocused_title_bg_color: Option<Color>,
     pub unfocused_title_text_color: Option<Color>,
     pub highlight_color: Option<Color>,
+    pub pointer_locator_color: Option<Color>,
+    pub focused_window_border_color: Option<Color>,
+    pub unfocused_window_border_color: Option<Color>,
+    pub attention_requested_window_border_color: Option<Color>,
+    pub focused_output_indicator_color: Option<Color>,
     pub border_width: Option<i32>,
     pub title_height: Option<i32>,
+    pub pointer_locator_size: Option<i32>,
+    pub window_border_width: Option<i32>,
     pub font: Option<String>,
 }
 
@@ -161,6 +179,50 @@ pub struct UiDrag {
     pub threshold: Option<i32>,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct OutputThrottle {
+    pub max_refresh_rate: Option<f64>,
+    pub delay: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceScroll {
+    pub inverted: Option<bool>,
+    pub threshold: Option<i32>,
+    pub wrap: Option<bool>,
+    pub skip_empty: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceSwipe {
+    pub fingers: Option<u32>,
+    pub inverted: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PointerLocator {
+    pub duration: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HotCorners {
+    pub size: Option<i32>,
+    pub dwell: Option<Duration>,
+    pub actions: AHashMap<HotCorner, Action>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WindowRule {
+    pub app_id: String,
+    pub scale: Option<f64>,
+    pub decorations: Option<DecorationMode>,
+    pub border_width: Option<i32>,
+    pub focused_border_color: Option<Color>,
+    pub unfocused_border_color: Option<Color>,
+    pub attention_requested_border_color: Option<Color>,
+    pub block_capture: Option<bool>,
+}
+
 #[derive(Debug, Clone)]
 pub enum OutputMatch {
     Any(Vec<OutputMatch>),
@@ -216,6 +278,17 @@ pub struct Output {
     pub vrr: Option<Vrr>,
     pub tearing: Option<Tearing>,
     pub format: Option<Format>,
+    pub vblank_cursor_updates: Option<bool>,
+    pub use_hardware_cursor: Option<bool>,
+    pub color_filter: Option<Option<ColorMatrix>>,
+    pub invert_colors: Option<bool>,
+    pub workspace_capture: Option<bool>,
+    pub title_bar_position: Option<TitleBarPosition>,
+    pub render_scale: Option<f64>,
+    pub workspace_scroll_wrap: Option<bool>,
+    pub workspace_scroll_skip_empty: Option<bool>,
+    pub game_mode: Option<bool>,
+    pub max_scale: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -259,6 +332,7 @@ pub struct Input {
     pub switch_actions: AHashMap<SwitchEvent, Action>,
     pub output: Option<Option<OutputMatch>>,
     pub calibration_matrix: Option<[[f32; 3]; 2]>,
+    pub repeat_rate: Option<RepeatRate>,
 }
 
 #[derive(Debug, Clone)]
@@ -297,10 +371,17 @@ pub struct RepeatRate {
     pub delay: i32,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct Idle {
+    pub timeout: Option<Duration>,
+    pub grace_period: Option<Duration>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Vrr {
     pub mode: Option<VrrMode>,
     pub cursor_hz: Option<f64>,
+    pub min_refresh_hz: Option<u32>,
 }
 
 #[derive(Debug, Clone)]
@@ -326,6 +407,19 @@ pub struct Shortcut {
     pub latch: Option<Action>,
 }
 
+#[derive(Debug, Clone)]
+pub struct KeySequence {
+    pub keys: Vec<ModifiedKeySym>,
+    pub action: Action,
+}
+
+#[derive(Debug, Clone)]
+pub struct PointerShortcut {
+    pub mods: Modifiers,
+    pub trigger: PointerTrigger,
+    pub action: Action,
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub keymap: Option<ConfigKeymap>,
@@ -347,15 +441,40 @@ pub struct Config {
     pub drm_devices: Vec<ConfigDrmDevice>,
     pub render_device: Option<DrmDeviceMatch>,
     pub inputs: Vec<Input>,
-    pub idle: Option<Duration>,
+    pub idle: Option<Idle>,
     pub explicit_sync_enabled: Option<bool>,
     pub focus_follows_mouse: bool,
+    pub focus_follows_mouse_dwell: Option<Duration>,
+    pub warp_pointer_on_focus: bool,
     pub window_management_key: Option<ModifiedKeySym>,
+    pub shortcuts_inhibit_escape: Option<ModifiedKeySym>,
+    pub zoom_scroll_modifiers: Option<Modifiers>,
     pub vrr: Option<Vrr>,
     pub tearing: Option<Tearing>,
     pub libei: Libei,
     pub ui_drag: UiDrag,
     pub xwayland: Option<Xwayland>,
+    pub pointer_locator: PointerLocator,
+    pub max_zoom: Option<f64>,
+    pub color_filter: Option<Option<ColorMatrix>>,
+    pub window_rules: Vec<WindowRule>,
+    pub show_focused_output: Option<bool>,
+    pub desktop_click_actions: AHashMap<u32, Action>,
+    pub hot_corners: HotCorners,
+    pub pointer_edge_behaviors: Vec<(OutputEdge, PointerEdgeBehavior)>,
+    pub workspace_scroll: WorkspaceScroll,
+    pub workspace_swipe: WorkspaceSwipe,
+    pub dnd_threshold: Option<u64>,
+    pub middle_click_paste: Option<bool>,
+    pub output_throttle: OutputThrottle,
+    pub shared_clipboard: Option<bool>,
+    pub layouts: Vec<ConfigKeymap>,
+    pub key_sequences: Vec<KeySequence>,
+    pub pointer_shortcuts: Vec<PointerShortcut>,
+    pub cursor_theme: Option<String>,
+    pub cursor_size: Option<i32>,
+    pub hide_cursor_while_typing: bool,
+    pub hide_cursor_timeout: Option<Duration>,
 }
 
 #[derive(Debug, Error)]
@@ -417,3 +536,30 @@ fn default_config_parses() {
     let input = include_bytes!("default-config.toml");
     parse_config(input, |_| ()).unwrap();
 }
+
+#[test]
+fn key_sequences_parse() {
+    use jay_config::keyboard::{
+        mods::ALT,
+        syms::{SYM_a, SYM_b},
+    };
+    let input = br#"
+        [shortcuts]
+        alt-a = "quit"
+        "alt-a b" = "quit"
+        "alt-a  alt-b" = "quit"
+        "alt-a b " = "reload-config-toml"
+    "#;
+    let config = parse_config(input, |_| ()).unwrap();
+    assert_eq!(config.shortcuts.len(), 1);
+    let sequences = &config.key_sequences;
+    assert_eq!(sequences.len(), 2);
+    assert_eq!(sequences[0].keys, [ALT | SYM_a, ALT | SYM_b]);
+    assert_eq!(sequences[1].keys, [ALT | SYM_a, SYM_b.into()]);
+    assert!(matches!(
+        sequences[1].action,
+        Action::SimpleCommand {
+            cmd: SimpleCommand::ReloadConfigToml
+        }
+    ));
+}
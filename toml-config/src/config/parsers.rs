@@ -8,15 +8,18 @@ use {
 
 pub mod action;
 mod color;
+mod color_filter;
 pub mod config;
 mod connector;
 mod connector_match;
+mod desktop_click;
 mod drm_device;
 mod drm_device_match;
 mod env;
 pub mod exec;
 mod format;
 mod gfx_api;
+mod hot_corners;
 mod idle;
 mod input;
 mod input_match;
@@ -27,6 +30,10 @@ mod mode;
 pub mod modified_keysym;
 mod output;
 mod output_match;
+mod output_throttle;
+mod pointer_edges;
+mod pointer_locator;
+mod pointer_shortcuts;
 mod repeat_rate;
 pub mod shortcuts;
 mod status;
@@ -34,6 +41,9 @@ mod tearing;
 mod theme;
 mod ui_drag;
 mod vrr;
+mod window_rule;
+mod workspace_scroll;
+mod workspace_swipe;
 mod xwayland;
 
 #[derive(Debug, Error)]
@@ -104,7 +104,13 @@ impl ActionParser<'_> {
             "focus-parent" => FocusParent,
             "close" => Close,
             "disable-pointer-constraint" => DisablePointerConstraint,
+            "locate-pointer" => LocatePointer,
+            "zoom-in" => ZoomIn,
+            "zoom-out" => ZoomOut,
+            "reset-zoom" => ResetZoom,
+            "toggle-invert-colors" => ToggleInvertColors,
             "toggle-floating" => ToggleFloating,
+            "toggle-sticky" => ToggleSticky,
             "quit" => Quit,
             "reload-config-toml" => ReloadConfigToml,
             "reload-config-so" => ReloadConfigSo,
@@ -113,6 +119,7 @@ impl ActionParser<'_> {
             "consume" => Forward(false),
             "enable-window-management" => EnableWindowManagement(true),
             "disable-window-management" => EnableWindowManagement(false),
+            "cycle-layout" => CycleLayout,
             _ => {
                 return Err(ActionParserError::UnknownSimpleAction(string.to_string()).spanned(span))
             }
@@ -0,0 +1,83 @@
+use {
+    crate::{
+        config::parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        toml::{
+            toml_span::{Span, Spanned, SpannedExt},
+            toml_value::Value,
+        },
+    },
+    jay_config::video::ColorMatrix,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum ColorFilterParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error("Unknown color filter {0}")]
+    UnknownFilter(String),
+    #[error("Color matrix must have exactly three rows")]
+    ThreeRows,
+    #[error("Color matrix must have exactly four columns")]
+    FourColumns,
+    #[error("Color matrix entries must be floats")]
+    Float,
+}
+
+pub struct ColorFilterParser;
+
+impl Parser for ColorFilterParser {
+    type Value = Option<ColorMatrix>;
+    type Error = ColorFilterParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::String, DataType::Array];
+
+    fn parse_string(&mut self, span: Span, string: &str) -> ParseResult<Self> {
+        let filter = match string {
+            "none" => return Ok(None),
+            "grayscale" => ColorMatrix::GRAYSCALE,
+            "protanopia" => ColorMatrix::PROTANOPIA,
+            "deuteranopia" => ColorMatrix::DEUTERANOPIA,
+            "tritanopia" => ColorMatrix::TRITANOPIA,
+            _ => {
+                return Err(ColorFilterParserError::UnknownFilter(string.to_string()).spanned(span))
+            }
+        };
+        Ok(Some(filter))
+    }
+
+    fn parse_array(&mut self, span: Span, array: &[Spanned<Value>]) -> ParseResult<Self> {
+        if array.len() != 3 {
+            return Err(ColorFilterParserError::ThreeRows.spanned(span));
+        }
+        Ok(Some(ColorMatrix([
+            array[0].parse(&mut ColorMatrixRowParser)?,
+            array[1].parse(&mut ColorMatrixRowParser)?,
+            array[2].parse(&mut ColorMatrixRowParser)?,
+        ])))
+    }
+}
+
+struct ColorMatrixRowParser;
+
+impl Parser for ColorMatrixRowParser {
+    type Value = [f32; 4];
+    type Error = ColorFilterParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Array];
+
+    fn parse_array(&mut self, span: Span, array: &[Spanned<Value>]) -> ParseResult<Self> {
+        if array.len() != 4 {
+            return Err(ColorFilterParserError::FourColumns.spanned(span));
+        }
+        let extract = |v: &Spanned<Value>| match v.value {
+            Value::Float(f) => Ok(f as f32),
+            Value::Integer(f) => Ok(f as _),
+            _ => Err(ColorFilterParserError::Float.spanned(v.span)),
+        };
+        Ok([
+            extract(&array[0])?,
+            extract(&array[1])?,
+            extract(&array[2])?,
+            extract(&array[3])?,
+        ])
+    }
+}
@@ -2,21 +2,31 @@ use {
     crate::{
         config::{
             context::Context,
-            extractor::{arr, bol, opt, recover, str, val, Extractor, ExtractorError},
+            extractor::{
+                arr, bol, fltorint, n64, opt, recover, s32, str, val, Extractor, ExtractorError,
+            },
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
             parsers::{
                 action::ActionParser,
+                color_filter::ColorFilterParser,
                 connector::ConnectorsParser,
+                desktop_click::DesktopClickParser,
                 drm_device::DrmDevicesParser,
                 drm_device_match::DrmDeviceMatchParser,
                 env::EnvParser,
                 gfx_api::GfxApiParser,
+                hot_corners::HotCornersParser,
                 idle::IdleParser,
                 input::InputsParser,
                 keymap::KeymapParser,
                 libei::LibeiParser,
                 log_level::LogLevelParser,
+                modified_keysym::ModifiersParser,
                 output::OutputsParser,
+                output_throttle::OutputThrottleParser,
+                pointer_edges::PointerEdgesParser,
+                pointer_locator::PointerLocatorParser,
+                pointer_shortcuts::PointerShortcutsParser,
                 repeat_rate::RepeatRateParser,
                 shortcuts::{
                     parse_modified_keysym_str, ComplexShortcutsParser, ShortcutsParser,
@@ -27,10 +37,14 @@ use {
                 theme::ThemeParser,
                 ui_drag::UiDragParser,
                 vrr::VrrParser,
+                window_rule::WindowRulesParser,
+                workspace_scroll::WorkspaceScrollParser,
+                workspace_swipe::WorkspaceSwipeParser,
                 xwayland::XwaylandParser,
             },
             spanned::SpannedErrorExt,
-            Action, Config, Libei, Theme, UiDrag,
+            Action, Config, HotCorners, Libei, OutputThrottle, PointerLocator, Theme, UiDrag,
+            WorkspaceScroll, WorkspaceSwipe,
         },
         toml::{
             toml_span::{DespanExt, Span, Spanned},
@@ -38,7 +52,7 @@ use {
         },
     },
     indexmap::IndexMap,
-    std::collections::HashSet,
+    std::{collections::HashSet, time::Duration},
     thiserror::Error,
 };
 
@@ -117,6 +131,31 @@ impl Parser for ConfigParser<'_> {
                 ui_drag_val,
                 xwayland_val,
             ),
+            (
+                pointer_locator_val,
+                max_zoom,
+                color_filter_val,
+                window_rules_val,
+                show_focused_output,
+                desktop_click_val,
+                shared_clipboard,
+                hot_corners_val,
+                pointer_edges_val,
+                workspace_scroll_val,
+            ),
+            (
+                output_throttle_val,
+                layouts_val,
+                focus_follows_mouse_dwell_ms,
+                warp_pointer_on_focus,
+                shortcuts_inhibit_escape_val,
+                zoom_scroll_modifiers_val,
+                workspace_swipe_val,
+                dnd_threshold,
+                middle_click_paste,
+                pointer_shortcuts_val,
+            ),
+            (cursor_theme, cursor_size, hide_cursor_while_typing, hide_cursor_timeout_ms),
         ) = ext.extract((
             (
                 opt(val("keymap")),
@@ -154,6 +193,36 @@ impl Parser for ConfigParser<'_> {
                 opt(val("ui-drag")),
                 opt(val("xwayland")),
             ),
+            (
+                opt(val("pointer-locator")),
+                recover(opt(fltorint("max-zoom"))),
+                opt(val("color-filter")),
+                opt(val("window-rules")),
+                recover(opt(bol("show-focused-output"))),
+                opt(val("on-desktop-click")),
+                recover(opt(bol("shared-clipboard"))),
+                opt(val("hot-corners")),
+                opt(val("pointer-edges")),
+                opt(val("workspace-scroll")),
+            ),
+            (
+                opt(val("output-throttle")),
+                recover(opt(arr("layouts"))),
+                recover(opt(n64("focus-follows-mouse-dwell-ms"))),
+                recover(opt(bol("warp-pointer-on-focus"))),
+                recover(opt(str("shortcuts-inhibit-escape"))),
+                recover(opt(str("zoom-scroll-modifiers"))),
+                opt(val("workspace-swipe")),
+                recover(opt(n64("dnd-threshold"))),
+                recover(opt(bol("middle-click-paste"))),
+                opt(val("pointer-shortcuts")),
+            ),
+            (
+                recover(opt(str("cursor-theme"))),
+                recover(opt(s32("cursor-size"))),
+                recover(opt(bol("hide-cursor-while-typing"))),
+                recover(opt(n64("hide-cursor-timeout-ms"))),
+            ),
         ))?;
         let mut keymap = None;
         if let Some(value) = keymap_val {
@@ -169,12 +238,14 @@ impl Parser for ConfigParser<'_> {
         }
         let mut used_keys = HashSet::new();
         let mut shortcuts = vec![];
+        let mut key_sequences = vec![];
         if let Some(value) = shortcuts_val {
             value
                 .parse(&mut ShortcutsParser {
                     cx: self.0,
                     used_keys: &mut used_keys,
                     shortcuts: &mut shortcuts,
+                    sequences: &mut key_sequences,
                 })
                 .map_spanned_err(ConfigParserError::ParseShortcuts)?;
         }
@@ -187,7 +258,7 @@ impl Parser for ConfigParser<'_> {
                 })
                 .map_spanned_err(ConfigParserError::ParseShortcuts)?;
         }
-        if shortcuts.is_empty() {
+        if shortcuts.is_empty() && key_sequences.is_empty() {
             log::warn!("Config defines no shortcuts");
         }
         let on_graphics_initialized =
@@ -239,6 +310,20 @@ impl Parser for ConfigParser<'_> {
                 }
             }
         }
+        let mut layouts = vec![];
+        if let Some(value) = layouts_val {
+            for value in value.value {
+                match value.parse(&mut KeymapParser {
+                    cx: self.0,
+                    definition: false,
+                }) {
+                    Ok(m) => layouts.push(m),
+                    Err(e) => {
+                        log::warn!("Could not parse a layout: {}", self.0.error(e));
+                    }
+                }
+            }
+        }
         let mut log_level = None;
         if let Some(value) = log_level_val {
             match value.parse(&mut LogLevelParser) {
@@ -317,6 +402,24 @@ impl Parser for ConfigParser<'_> {
                 window_management_key = Some(key);
             }
         }
+        let mut shortcuts_inhibit_escape = None;
+        if let Some(value) = shortcuts_inhibit_escape_val {
+            if let Some(key) = parse_modified_keysym_str(self.0, value.span, value.value) {
+                shortcuts_inhibit_escape = Some(key);
+            }
+        }
+        let mut zoom_scroll_modifiers = None;
+        if let Some(value) = zoom_scroll_modifiers_val {
+            match ModifiersParser.parse_string(value.span, value.value) {
+                Ok(v) => zoom_scroll_modifiers = Some(v),
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse the zoom scroll modifiers: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
         let mut vrr = None;
         if let Some(value) = vrr_val {
             match value.parse(&mut VrrParser(self.0)) {
@@ -353,6 +456,42 @@ impl Parser for ConfigParser<'_> {
                 }
             }
         }
+        let mut workspace_scroll = WorkspaceScroll::default();
+        if let Some(value) = workspace_scroll_val {
+            match value.parse(&mut WorkspaceScrollParser(self.0)) {
+                Ok(v) => workspace_scroll = v,
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse workspace-scroll setting: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
+        let mut workspace_swipe = WorkspaceSwipe::default();
+        if let Some(value) = workspace_swipe_val {
+            match value.parse(&mut WorkspaceSwipeParser(self.0)) {
+                Ok(v) => workspace_swipe = v,
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse workspace-swipe setting: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
+        let mut output_throttle = OutputThrottle::default();
+        if let Some(value) = output_throttle_val {
+            match value.parse(&mut OutputThrottleParser(self.0)) {
+                Ok(v) => output_throttle = v,
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse output-throttle setting: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
         let mut xwayland = None;
         if let Some(value) = xwayland_val {
             match value.parse(&mut XwaylandParser(self.0)) {
@@ -362,6 +501,65 @@ impl Parser for ConfigParser<'_> {
                 }
             }
         }
+        let mut pointer_locator = PointerLocator::default();
+        if let Some(value) = pointer_locator_val {
+            match value.parse(&mut PointerLocatorParser(self.0)) {
+                Ok(v) => pointer_locator = v,
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse pointer-locator setting: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
+        let mut color_filter = None;
+        if let Some(value) = color_filter_val {
+            match value.parse(&mut ColorFilterParser) {
+                Ok(v) => color_filter = Some(v),
+                Err(e) => {
+                    log::warn!("Could not parse color filter: {}", self.0.error(e));
+                }
+            }
+        }
+        let mut window_rules = vec![];
+        if let Some(value) = window_rules_val {
+            match value.parse(&mut WindowRulesParser(self.0)) {
+                Ok(v) => window_rules = v,
+                Err(e) => log::warn!("Could not parse the window rules: {}", self.0.error(e)),
+            }
+        }
+        let mut desktop_click_actions = Default::default();
+        if let Some(value) = desktop_click_val {
+            match value.parse(&mut DesktopClickParser(self.0)) {
+                Ok(v) => desktop_click_actions = v,
+                Err(e) => log::warn!(
+                    "Could not parse the desktop click actions: {}",
+                    self.0.error(e)
+                ),
+            }
+        }
+        let mut hot_corners = HotCorners::default();
+        if let Some(value) = hot_corners_val {
+            match value.parse(&mut HotCornersParser(self.0)) {
+                Ok(v) => hot_corners = v,
+                Err(e) => log::warn!("Could not parse the hot corners: {}", self.0.error(e)),
+            }
+        }
+        let mut pointer_shortcuts = vec![];
+        if let Some(value) = pointer_shortcuts_val {
+            match value.parse(&mut PointerShortcutsParser(self.0)) {
+                Ok(v) => pointer_shortcuts = v,
+                Err(e) => log::warn!("Could not parse the pointer shortcuts: {}", self.0.error(e)),
+            }
+        }
+        let mut pointer_edge_behaviors = vec![];
+        if let Some(value) = pointer_edges_val {
+            match value.parse(&mut PointerEdgesParser(self.0)) {
+                Ok(v) => pointer_edge_behaviors = v,
+                Err(e) => log::warn!("Could not parse the pointer edges: {}", self.0.error(e)),
+            }
+        }
         Ok(Config {
             keymap,
             repeat_rate,
@@ -385,12 +583,39 @@ impl Parser for ConfigParser<'_> {
             inputs,
             idle,
             focus_follows_mouse: focus_follows_mouse.despan().unwrap_or(true),
+            focus_follows_mouse_dwell: focus_follows_mouse_dwell_ms
+                .despan()
+                .map(Duration::from_millis),
+            warp_pointer_on_focus: warp_pointer_on_focus.despan().unwrap_or(false),
             window_management_key,
+            shortcuts_inhibit_escape,
+            zoom_scroll_modifiers,
             vrr,
             tearing,
             libei,
             ui_drag,
             xwayland,
+            pointer_locator,
+            max_zoom: max_zoom.despan(),
+            color_filter,
+            window_rules,
+            show_focused_output: show_focused_output.despan(),
+            desktop_click_actions,
+            shared_clipboard: shared_clipboard.despan(),
+            hot_corners,
+            pointer_edge_behaviors,
+            workspace_scroll,
+            workspace_swipe,
+            dnd_threshold: dnd_threshold.despan(),
+            middle_click_paste: middle_click_paste.despan(),
+            output_throttle,
+            layouts,
+            key_sequences,
+            pointer_shortcuts,
+            cursor_theme: cursor_theme.despan_into(),
+            cursor_size: cursor_size.despan(),
+            hide_cursor_while_typing: hide_cursor_while_typing.despan().unwrap_or(false),
+            hide_cursor_timeout: hide_cursor_timeout_ms.despan().map(Duration::from_millis),
         })
     }
 }
@@ -0,0 +1,62 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{opt, val, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            parsers::action::ActionParser,
+            Action,
+        },
+        toml::{
+            toml_span::{Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    ahash::AHashMap,
+    indexmap::IndexMap,
+    jay_config::input::button::{BTN_LEFT, BTN_MIDDLE, BTN_RIGHT},
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum DesktopClickParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct DesktopClickParser<'a>(pub &'a Context<'a>);
+
+impl Parser for DesktopClickParser<'_> {
+    type Value = AHashMap<u32, Action>;
+    type Error = DesktopClickParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (left_val, right_val, middle_val) =
+            ext.extract((opt(val("left")), opt(val("right")), opt(val("middle"))))?;
+        let mut actions = AHashMap::new();
+        let mut parse_action = |val: Option<Spanned<&Value>>, name, button| {
+            if let Some(val) = val {
+                match val.parse(&mut ActionParser(self.0)) {
+                    Ok(a) => {
+                        actions.insert(button, a);
+                    }
+                    Err(e) => {
+                        log::warn!("Could not parse {name} action: {}", self.0.error(e));
+                    }
+                }
+            }
+        };
+        parse_action(left_val, "left", BTN_LEFT);
+        parse_action(right_val, "right", BTN_RIGHT);
+        parse_action(middle_val, "middle", BTN_MIDDLE);
+        Ok(actions)
+    }
+}
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{n64, opt, recover, s32, val, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            parsers::action::ActionParser,
+            HotCorners,
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    ahash::AHashMap,
+    indexmap::IndexMap,
+    jay_config::input::HotCorner,
+    std::time::Duration,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum HotCornersParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct HotCornersParser<'a>(pub &'a Context<'a>);
+
+impl Parser for HotCornersParser<'_> {
+    type Value = HotCorners;
+    type Error = HotCornersParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (size, dwell_ms, top_left_val, top_right_val, bottom_left_val, bottom_right_val) = ext
+            .extract((
+                recover(opt(s32("size"))),
+                recover(opt(n64("dwell-ms"))),
+                opt(val("top-left")),
+                opt(val("top-right")),
+                opt(val("bottom-left")),
+                opt(val("bottom-right")),
+            ))?;
+        let mut actions = AHashMap::new();
+        let mut parse_action = |val: Option<Spanned<&Value>>, name, corner| {
+            if let Some(val) = val {
+                match val.parse(&mut ActionParser(self.0)) {
+                    Ok(a) => {
+                        actions.insert(corner, a);
+                    }
+                    Err(e) => {
+                        log::warn!("Could not parse {name} action: {}", self.0.error(e));
+                    }
+                }
+            }
+        };
+        parse_action(top_left_val, "top-left", HotCorner::TopLeft);
+        parse_action(top_right_val, "top-right", HotCorner::TopRight);
+        parse_action(bottom_left_val, "bottom-left", HotCorner::BottomLeft);
+        parse_action(bottom_right_val, "bottom-right", HotCorner::BottomRight);
+        Ok(HotCorners {
+            size: size.despan(),
+            dwell: dwell_ms.despan().map(Duration::from_millis),
+            actions,
+        })
+    }
+}
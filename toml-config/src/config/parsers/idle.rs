@@ -2,8 +2,9 @@ use {
     crate::{
         config::{
             context::Context,
-            extractor::{n64, opt, Extractor, ExtractorError},
+            extractor::{n64, opt, val, Extractor, ExtractorError},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            Idle,
         },
         toml::{
             toml_span::{DespanExt, Span, Spanned},
@@ -26,7 +27,7 @@ pub enum IdleParserError {
 pub struct IdleParser<'a>(pub &'a Context<'a>);
 
 impl Parser for IdleParser<'_> {
-    type Value = Duration;
+    type Value = Idle;
     type Error = IdleParserError;
     const EXPECTED: &'static [DataType] = &[DataType::Table];
 
@@ -36,10 +37,29 @@ impl Parser for IdleParser<'_> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         let mut ext = Extractor::new(self.0, span, table);
-        let (minutes, seconds) = ext.extract((opt(n64("minutes")), opt(n64("seconds"))))?;
-        let idle = Duration::from_secs(
-            minutes.despan().unwrap_or_default() * 60 + seconds.despan().unwrap_or_default(),
-        );
-        Ok(idle)
+        let (minutes, seconds, grace_period_val) = ext.extract((
+            opt(n64("minutes")),
+            opt(n64("seconds")),
+            opt(val("grace-period")),
+        ))?;
+        let mut timeout = None;
+        if minutes.is_some() || seconds.is_some() {
+            timeout = Some(Duration::from_secs(
+                minutes.despan().unwrap_or_default() * 60 + seconds.despan().unwrap_or_default(),
+            ));
+        }
+        let mut grace_period = None;
+        if let Some(value) = grace_period_val {
+            match value.parse(&mut IdleParser(self.0)) {
+                Ok(v) => grace_period = Some(v.timeout.unwrap_or_default()),
+                Err(e) => {
+                    log::warn!("Could not parse the grace period: {}", self.0.error(e));
+                }
+            }
+        }
+        Ok(Idle {
+            timeout,
+            grace_period,
+        })
     }
 }
@@ -9,6 +9,7 @@ use {
                 input_match::{InputMatchParser, InputMatchParserError},
                 keymap::KeymapParser,
                 output_match::OutputMatchParser,
+                repeat_rate::RepeatRateParser,
             },
             Input,
         },
@@ -87,6 +88,7 @@ impl<'a> Parser for InputParser<'a> {
                 output_val,
                 remove_mapping,
                 calibration_matrix,
+                repeat_rate_val,
             ),
         ) = ext.extract((
             (
@@ -111,6 +113,7 @@ impl<'a> Parser for InputParser<'a> {
                 opt(val("output")),
                 recover(opt(bol("remove-mapping"))),
                 recover(opt(val("calibration-matrix"))),
+                opt(val("repeat-rate")),
             ),
         ))?;
         let accel_profile = match accel_profile {
@@ -232,6 +235,15 @@ impl<'a> Parser for InputParser<'a> {
                 }
             },
         };
+        let mut repeat_rate = None;
+        if let Some(value) = repeat_rate_val {
+            match value.parse(&mut RepeatRateParser(self.cx)) {
+                Ok(v) => repeat_rate = Some(v),
+                Err(e) => {
+                    log::warn!("Could not parse repeat rate: {}", self.cx.error(e));
+                }
+            }
+        }
         Ok(Input {
             tag: tag.despan_into(),
             match_: match_val.parse_map(&mut InputMatchParser(self.cx))?,
@@ -248,6 +260,7 @@ impl<'a> Parser for InputParser<'a> {
             switch_actions,
             output,
             calibration_matrix,
+            repeat_rate,
         })
     }
 }
@@ -2,9 +2,10 @@ use {
     crate::{
         config::{
             context::Context,
-            extractor::{fltorint, opt, recover, s32, str, val, Extractor, ExtractorError},
+            extractor::{bol, fltorint, opt, recover, s32, str, val, Extractor, ExtractorError},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
             parsers::{
+                color_filter::ColorFilterParser,
                 format::FormatParser,
                 mode::ModeParser,
                 output_match::{OutputMatchParser, OutputMatchParserError},
@@ -19,7 +20,7 @@ use {
         },
     },
     indexmap::IndexMap,
-    jay_config::video::Transform,
+    jay_config::video::{TitleBarPosition, Transform},
     thiserror::Error,
 };
 
@@ -49,8 +50,23 @@ impl<'a> Parser for OutputParser<'a> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         let mut ext = Extractor::new(self.cx, span, table);
-        let (name, match_val, x, y, scale, transform, mode, vrr_val, tearing_val, format_val) = ext
-            .extract((
+        let (
+            (name, match_val, x, y, scale, transform, mode, vrr_val, tearing_val, format_val),
+            (
+                vblank_cursor_updates,
+                use_hardware_cursor,
+                color_filter_val,
+                invert_colors,
+                workspace_capture,
+                title_bar_position,
+                render_scale,
+                workspace_scroll_wrap,
+                workspace_scroll_skip_empty,
+                game_mode,
+            ),
+            (max_scale,),
+        ) = ext.extract((
+            (
                 opt(str("name")),
                 val("match"),
                 recover(opt(s32("x"))),
@@ -61,7 +77,21 @@ impl<'a> Parser for OutputParser<'a> {
                 opt(val("vrr")),
                 opt(val("tearing")),
                 opt(val("format")),
-            ))?;
+            ),
+            (
+                recover(opt(bol("vblank-cursor-updates"))),
+                recover(opt(bol("use-hardware-cursor"))),
+                opt(val("color-filter")),
+                recover(opt(bol("invert-colors"))),
+                recover(opt(bol("workspace-capture"))),
+                recover(opt(str("title-bar-position"))),
+                recover(opt(fltorint("render-scale"))),
+                recover(opt(bol("workspace-scroll-wrap"))),
+                recover(opt(bol("workspace-scroll-skip-empty"))),
+                recover(opt(bol("game-mode"))),
+            ),
+            (recover(opt(fltorint("max-scale"))),),
+        ))?;
         let transform = match transform {
             None => None,
             Some(t) => match t.value {
@@ -79,6 +109,21 @@ impl<'a> Parser for OutputParser<'a> {
                 }
             },
         };
+        let title_bar_position = match title_bar_position {
+            None => None,
+            Some(p) => match p.value {
+                "top" => Some(TitleBarPosition::Top),
+                "bottom" => Some(TitleBarPosition::Bottom),
+                _ => {
+                    log::warn!(
+                        "Unknown title bar position {}: {}",
+                        p.value,
+                        self.cx.error3(p.span)
+                    );
+                    None
+                }
+            },
+        };
         let mode = match mode {
             Some(mode) => match mode.parse(&mut ModeParser(self.cx)) {
                 Ok(m) => Some(m),
@@ -133,6 +178,15 @@ impl<'a> Parser for OutputParser<'a> {
                 }
             }
         }
+        let mut color_filter = None;
+        if let Some(value) = color_filter_val {
+            match value.parse(&mut ColorFilterParser) {
+                Ok(v) => color_filter = Some(v),
+                Err(e) => {
+                    log::warn!("Could not parse color filter: {}", self.cx.error(e));
+                }
+            }
+        }
         Ok(Output {
             name: name.despan().map(|v| v.to_string()),
             match_: match_val.parse_map(&mut OutputMatchParser(self.cx))?,
@@ -144,6 +198,17 @@ impl<'a> Parser for OutputParser<'a> {
             vrr,
             tearing,
             format,
+            vblank_cursor_updates: vblank_cursor_updates.despan(),
+            use_hardware_cursor: use_hardware_cursor.despan(),
+            color_filter,
+            invert_colors: invert_colors.despan(),
+            workspace_capture: workspace_capture.despan(),
+            title_bar_position,
+            render_scale: render_scale.despan(),
+            workspace_scroll_wrap: workspace_scroll_wrap.despan(),
+            workspace_scroll_skip_empty: workspace_scroll_skip_empty.despan(),
+            game_mode: game_mode.despan(),
+            max_scale: max_scale.despan(),
         })
     }
 }
@@ -0,0 +1,49 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{fltorint, n64, opt, recover, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            OutputThrottle,
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    std::time::Duration,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum OutputThrottleParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct OutputThrottleParser<'a>(pub &'a Context<'a>);
+
+impl Parser for OutputThrottleParser<'_> {
+    type Value = OutputThrottle;
+    type Error = OutputThrottleParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (max_refresh_rate, delay_ms) = ext.extract((
+            recover(opt(fltorint("max-refresh-rate"))),
+            recover(opt(n64("delay-ms"))),
+        ))?;
+        Ok(OutputThrottle {
+            max_refresh_rate: max_refresh_rate.despan(),
+            delay: delay_ms.despan().map(Duration::from_millis),
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{opt, val, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        },
+        toml::{
+            toml_span::{Span, Spanned, SpannedExt},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    jay_config::input::{OutputEdge, PointerEdgeBehavior},
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum PointerEdgesParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct PointerEdgesParser<'a>(pub &'a Context<'a>);
+
+impl Parser for PointerEdgesParser<'_> {
+    type Value = Vec<(OutputEdge, PointerEdgeBehavior)>;
+    type Error = PointerEdgesParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (left, right, top, bottom) = ext.extract((
+            opt(val("left")),
+            opt(val("right")),
+            opt(val("top")),
+            opt(val("bottom")),
+        ))?;
+        let mut behaviors = vec![];
+        for (val, name, edge) in [
+            (left, "left", OutputEdge::Left),
+            (right, "right", OutputEdge::Right),
+            (top, "top", OutputEdge::Top),
+            (bottom, "bottom", OutputEdge::Bottom),
+        ] {
+            if let Some(val) = val {
+                match val.parse(&mut PointerEdgeBehaviorParser) {
+                    Ok(b) => behaviors.push((edge, b)),
+                    Err(e) => {
+                        log::warn!(
+                            "Could not parse the {name} edge behavior: {}",
+                            self.0.error(e)
+                        );
+                    }
+                }
+            }
+        }
+        Ok(behaviors)
+    }
+}
+
+struct PointerEdgeBehaviorParser;
+
+#[derive(Debug, Error)]
+pub enum PointerEdgeBehaviorParserError {
+    #[error(transparent)]
+    DataType(#[from] UnexpectedDataType),
+    #[error("Unknown edge behavior {0}")]
+    Unknown(String),
+}
+
+impl Parser for PointerEdgeBehaviorParser {
+    type Value = PointerEdgeBehavior;
+    type Error = PointerEdgeBehaviorParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::String];
+
+    fn parse_string(&mut self, span: Span, string: &str) -> ParseResult<Self> {
+        let behavior = match string {
+            "clamp" => PointerEdgeBehavior::Clamp,
+            "wrap" => PointerEdgeBehavior::Wrap,
+            "push-through" => PointerEdgeBehavior::PushThrough,
+            _ => {
+                return Err(
+                    PointerEdgeBehaviorParserError::Unknown(string.to_string()).spanned(span)
+                )
+            }
+        };
+        Ok(behavior)
+    }
+}
@@ -0,0 +1,45 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{n64, opt, recover, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            PointerLocator,
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    std::time::Duration,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum PointerLocatorParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct PointerLocatorParser<'a>(pub &'a Context<'a>);
+
+impl Parser for PointerLocatorParser<'_> {
+    type Value = PointerLocator;
+    type Error = PointerLocatorParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let duration_ms = ext.extract(recover(opt(n64("duration-ms"))))?;
+        Ok(PointerLocator {
+            duration: duration_ms.despan().map(Duration::from_millis),
+        })
+    }
+}
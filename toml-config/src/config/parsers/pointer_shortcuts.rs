@@ -0,0 +1,113 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            parsers::{action::ActionParser, modified_keysym::parse_mod},
+            PointerShortcut,
+        },
+        toml::{
+            toml_span::{Span, Spanned, SpannedExt},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    jay_config::{
+        input::{
+            button::{BTN_EXTRA, BTN_LEFT, BTN_MIDDLE, BTN_RIGHT, BTN_SIDE},
+            PointerTrigger,
+        },
+        keyboard::mods::Modifiers,
+    },
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum PointerShortcutsParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error("Unknown pointer trigger {0}")]
+    UnknownTrigger(String),
+}
+
+pub struct PointerShortcutsParser<'a>(pub &'a Context<'a>);
+
+impl Parser for PointerShortcutsParser<'_> {
+    type Value = Vec<PointerShortcut>;
+    type Error = PointerShortcutsParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        _span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut res = vec![];
+        for (key, value) in table.iter() {
+            let (mods, trigger) = match parse_pointer_trigger(key.span, &key.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    log::warn!("Could not parse pointer shortcut: {}", self.0.error(e));
+                    continue;
+                }
+            };
+            let action = match value.parse(&mut ActionParser(self.0)) {
+                Ok(a) => a,
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse action for pointer shortcut {}: {}",
+                        key.value,
+                        self.0.error(e)
+                    );
+                    continue;
+                }
+            };
+            if let Some(prev) = res
+                .iter()
+                .position(|s: &PointerShortcut| s.mods == mods && s.trigger == trigger)
+            {
+                log::warn!(
+                    "Duplicate pointer shortcut overrides previous definition: {}",
+                    self.0.error3(key.span)
+                );
+                res.remove(prev);
+            }
+            res.push(PointerShortcut {
+                mods,
+                trigger,
+                action,
+            });
+        }
+        Ok(res)
+    }
+}
+
+fn parse_pointer_trigger(
+    span: Span,
+    string: &str,
+) -> Result<(Modifiers, PointerTrigger), Spanned<PointerShortcutsParserError>> {
+    let mut mods = Modifiers(0);
+    let mut rest = string;
+    while let Some((part, tail)) = rest.split_once("-") {
+        let Some(modifier) = parse_mod(part) else {
+            break;
+        };
+        mods |= modifier;
+        rest = tail;
+    }
+    let trigger = match rest {
+        "left" => PointerTrigger::Button(BTN_LEFT),
+        "right" => PointerTrigger::Button(BTN_RIGHT),
+        "middle" => PointerTrigger::Button(BTN_MIDDLE),
+        "side" => PointerTrigger::Button(BTN_SIDE),
+        "extra" => PointerTrigger::Button(BTN_EXTRA),
+        "scroll-up" => PointerTrigger::ScrollUp,
+        "scroll-down" => PointerTrigger::ScrollDown,
+        "scroll-left" => PointerTrigger::ScrollLeft,
+        "scroll-right" => PointerTrigger::ScrollRight,
+        _ => {
+            return Err(PointerShortcutsParserError::UnknownTrigger(rest.to_string()).spanned(span))
+        }
+    };
+    Ok((mods, trigger))
+}
@@ -11,7 +11,7 @@ use {
                 },
             },
             spanned::SpannedErrorExt,
-            Action, Shortcut, SimpleCommand,
+            Action, KeySequence, Shortcut, SimpleCommand,
         },
         toml::{
             toml_span::{Span, Spanned, SpannedExt},
@@ -42,6 +42,7 @@ pub struct ShortcutsParser<'a, 'b> {
     pub cx: &'a Context<'a>,
     pub used_keys: &'b mut HashSet<Spanned<ModifiedKeySym>>,
     pub shortcuts: &'b mut Vec<Shortcut>,
+    pub sequences: &'b mut Vec<KeySequence>,
 }
 
 impl Parser for ShortcutsParser<'_, '_> {
@@ -55,6 +56,10 @@ impl Parser for ShortcutsParser<'_, '_> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         for (key, value) in table.iter() {
+            if key.value.trim().contains(char::is_whitespace) {
+                self.parse_sequence(key, value);
+                continue;
+            }
             let Some(keysym) = parse_modified_keysym(self.cx, key) else {
                 continue;
             };
@@ -74,6 +79,29 @@ impl Parser for ShortcutsParser<'_, '_> {
     }
 }
 
+impl ShortcutsParser<'_, '_> {
+    fn parse_sequence(&mut self, key: &Spanned<String>, value: &Spanned<Value>) {
+        let mut keys = vec![];
+        for part in key.value.split_whitespace() {
+            let Some(keysym) = parse_modified_keysym_str(self.cx, key.span, part) else {
+                return;
+            };
+            keys.push(keysym);
+        }
+        let Some(action) = parse_action(self.cx, &key.value, value) else {
+            return;
+        };
+        if let Some(prev) = self.sequences.iter().position(|s| s.keys == keys) {
+            log::warn!(
+                "Duplicate key sequence overrides previous definition: {}",
+                self.cx.error3(key.span)
+            );
+            self.sequences.remove(prev);
+        }
+        self.sequences.push(KeySequence { keys, action });
+    }
+}
+
 pub struct ComplexShortcutsParser<'a, 'b> {
     pub cx: &'a Context<'a>,
     pub used_keys: &'b mut HashSet<Spanned<ModifiedKeySym>>,
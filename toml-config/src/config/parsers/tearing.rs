@@ -71,6 +71,8 @@ impl Parser for TearingModeParser {
             "variant1" => TearingMode::VARIANT_1,
             "variant2" => TearingMode::VARIANT_2,
             "variant3" => TearingMode::VARIANT_3,
+            "variant4" => TearingMode::VARIANT_4,
+            "variant5" => TearingMode::VARIANT_5,
             _ => return Err(TearingModeParserError::UnknownMode(string.to_string()).spanned(span)),
         };
         Ok(mode)
@@ -59,6 +59,15 @@ impl Parser for ThemeParser<'_> {
                 border_width,
                 title_height,
                 font,
+                pointer_locator_color,
+                pointer_locator_size,
+            ),
+            (
+                focused_window_border_color,
+                unfocused_window_border_color,
+                attention_requested_window_border_color,
+                window_border_width,
+                focused_output_indicator_color,
             ),
         ) = ext.extract((
             (
@@ -82,6 +91,15 @@ impl Parser for ThemeParser<'_> {
                 recover(opt(s32("border-width"))),
                 recover(opt(s32("title-height"))),
                 recover(opt(str("font"))),
+                opt(val("pointer-locator-color")),
+                recover(opt(s32("pointer-locator-size"))),
+            ),
+            (
+                opt(val("focused-window-border-color")),
+                opt(val("unfocused-window-border-color")),
+                opt(val("attention-requested-window-border-color")),
+                recover(opt(s32("window-border-width"))),
+                opt(val("focused-output-indicator-color")),
             ),
         ))?;
         macro_rules! color {
@@ -114,8 +132,17 @@ impl Parser for ThemeParser<'_> {
             unfocused_title_bg_color: color!(unfocused_title_bg_color),
             unfocused_title_text_color: color!(unfocused_title_text_color),
             highlight_color: color!(highlight_color),
+            pointer_locator_color: color!(pointer_locator_color),
+            focused_window_border_color: color!(focused_window_border_color),
+            unfocused_window_border_color: color!(unfocused_window_border_color),
+            attention_requested_window_border_color: color!(
+                attention_requested_window_border_color
+            ),
+            focused_output_indicator_color: color!(focused_output_indicator_color),
             border_width: border_width.despan(),
             title_height: title_height.despan(),
+            pointer_locator_size: pointer_locator_size.despan(),
+            window_border_width: window_border_width.despan(),
             font: font.map(|f| f.value.to_string()),
         })
     }
@@ -2,12 +2,12 @@ use {
     crate::{
         config::{
             context::Context,
-            extractor::{opt, val, Extractor, ExtractorError},
+            extractor::{n32, opt, recover, val, Extractor, ExtractorError},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
             Vrr,
         },
         toml::{
-            toml_span::{Span, Spanned, SpannedExt},
+            toml_span::{DespanExt, Span, Spanned, SpannedExt},
             toml_value::Value,
         },
     },
@@ -37,7 +37,11 @@ impl Parser for VrrParser<'_> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         let mut ext = Extractor::new(self.0, span, table);
-        let (mode, cursor_hz) = ext.extract((opt(val("mode")), opt(val("cursor-hz"))))?;
+        let (mode, cursor_hz, min_refresh_hz) = ext.extract((
+            opt(val("mode")),
+            opt(val("cursor-hz")),
+            recover(opt(n32("min-refresh-hz"))),
+        ))?;
         let mode = mode.and_then(|m| match m.parse(&mut VrrModeParser) {
             Ok(m) => Some(m),
             Err(e) => {
@@ -52,7 +56,11 @@ impl Parser for VrrParser<'_> {
                 None
             }
         });
-        Ok(Vrr { mode, cursor_hz })
+        Ok(Vrr {
+            mode,
+            cursor_hz,
+            min_refresh_hz: min_refresh_hz.despan(),
+        })
     }
 }
 
@@ -0,0 +1,136 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{bol, fltorint, opt, recover, s32, str, val, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            parsers::color::ColorParser,
+            WindowRule,
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    jay_config::window::DecorationMode,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum WindowRuleParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct WindowRuleParser<'a>(pub &'a Context<'a>);
+
+impl<'a> Parser for WindowRuleParser<'a> {
+    type Value = WindowRule;
+    type Error = WindowRuleParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (
+            app_id,
+            scale,
+            decorations,
+            border_width,
+            focused_border_color,
+            unfocused_border_color,
+            attention_requested_border_color,
+            block_capture,
+        ) = ext.extract((
+            str("app-id"),
+            recover(opt(fltorint("scale"))),
+            recover(opt(str("decorations"))),
+            recover(opt(s32("border-width"))),
+            opt(val("focused-border-color")),
+            opt(val("unfocused-border-color")),
+            opt(val("attention-requested-border-color")),
+            recover(opt(bol("block-capture"))),
+        ))?;
+        macro_rules! color {
+            ($e:expr) => {
+                match $e {
+                    None => None,
+                    Some(v) => match v.parse(&mut ColorParser) {
+                        Ok(v) => Some(v),
+                        Err(e) => {
+                            log::warn!("Could not parse a color: {}", self.0.error(e));
+                            None
+                        }
+                    },
+                }
+            };
+        }
+        let decorations = match decorations {
+            None => None,
+            Some(d) => match d.value {
+                "server" => Some(DecorationMode::SERVER),
+                "client" => Some(DecorationMode::CLIENT),
+                "none" => Some(DecorationMode::NONE),
+                _ => {
+                    log::warn!(
+                        "Unknown decoration mode {}: {}",
+                        d.value,
+                        self.0.error3(d.span)
+                    );
+                    None
+                }
+            },
+        };
+        Ok(WindowRule {
+            app_id: app_id.value.to_string(),
+            scale: scale.despan(),
+            decorations,
+            border_width: border_width.despan(),
+            focused_border_color: color!(focused_border_color),
+            unfocused_border_color: color!(unfocused_border_color),
+            attention_requested_border_color: color!(attention_requested_border_color),
+            block_capture: block_capture.despan(),
+        })
+    }
+}
+
+pub struct WindowRulesParser<'a>(pub &'a Context<'a>);
+
+impl<'a> Parser for WindowRulesParser<'a> {
+    type Value = Vec<WindowRule>;
+    type Error = WindowRuleParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table, DataType::Array];
+
+    fn parse_array(&mut self, _span: Span, array: &[Spanned<Value>]) -> ParseResult<Self> {
+        let mut res = vec![];
+        for el in array {
+            match el.parse(&mut WindowRuleParser(self.0)) {
+                Ok(o) => res.push(o),
+                Err(e) => {
+                    log::warn!("Could not parse window rule: {}", self.0.error(e));
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        log::warn!(
+            "`window-rules` value should be an array: {}",
+            self.0.error3(span)
+        );
+        WindowRuleParser(self.0)
+            .parse_table(span, table)
+            .map(|v| vec![v])
+    }
+}
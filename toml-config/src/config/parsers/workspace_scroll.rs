@@ -0,0 +1,62 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{bol, opt, recover, s32, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            WorkspaceScroll,
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum WorkspaceScrollParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct WorkspaceScrollParser<'a>(pub &'a Context<'a>);
+
+impl Parser for WorkspaceScrollParser<'_> {
+    type Value = WorkspaceScroll;
+    type Error = WorkspaceScrollParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (inverted, threshold, wrap, skip_empty) = ext.extract((
+            recover(opt(bol("inverted"))),
+            recover(opt(s32("threshold"))),
+            recover(opt(bol("wrap"))),
+            recover(opt(bol("skip-empty"))),
+        ))?;
+        let threshold = match threshold {
+            Some(t) if t.value < 1 => {
+                log::warn!(
+                    "The workspace scroll threshold must be at least 1: {}",
+                    self.0.error3(t.span)
+                );
+                None
+            }
+            t => t.despan(),
+        };
+        Ok(WorkspaceScroll {
+            inverted: inverted.despan(),
+            threshold,
+            wrap: wrap.despan(),
+            skip_empty: skip_empty.despan(),
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{bol, int, opt, recover, Extractor, ExtractorError},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            WorkspaceSwipe,
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum WorkspaceSwipeParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct WorkspaceSwipeParser<'a>(pub &'a Context<'a>);
+
+impl Parser for WorkspaceSwipeParser<'_> {
+    type Value = WorkspaceSwipe;
+    type Error = WorkspaceSwipeParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (fingers, inverted) =
+            ext.extract((recover(opt(int("fingers"))), recover(opt(bol("inverted")))))?;
+        Ok(WorkspaceSwipe {
+            fingers: fingers.despan().map(|v| v.max(0) as u32),
+            inverted: inverted.despan(),
+        })
+    }
+}
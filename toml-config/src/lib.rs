@@ -6,8 +6,8 @@ mod toml;
 use {
     crate::config::{
         parse_config, Action, Config, ConfigConnector, ConfigDrmDevice, ConfigKeymap,
-        ConnectorMatch, DrmDeviceMatch, Exec, Input, InputMatch, Output, OutputMatch, Shortcut,
-        SimpleCommand, Status, Theme,
+        ConnectorMatch, DrmDeviceMatch, Exec, Idle, Input, InputMatch, KeySequence, Output,
+        OutputMatch, PointerShortcut, Shortcut, SimpleCommand, Status, Theme,
     },
     ahash::{AHashMap, AHashSet},
     error_reporter::Report,
@@ -17,23 +17,37 @@ use {
         get_workspace,
         input::{
             capability::CAP_SWITCH, get_seat, input_devices, on_input_device_removed,
-            on_new_input_device, set_libei_socket_enabled, FocusFollowsMouseMode, InputDevice,
-            Seat, SwitchEvent,
+            on_new_input_device, set_libei_socket_enabled, set_max_zoom, set_pointer_edge_behavior,
+            set_pointer_locator_duration, set_shared_clipboard, FocusFollowsMouseMode, InputDevice,
+            OutputEdge, PointerEdgeBehavior, PointerTrigger, Seat, SwitchEvent,
         },
         is_reload,
-        keyboard::{Keymap, ModifiedKeySym},
+        keyboard::{mods::Modifiers, Keymap, ModifiedKeySym},
         logging::set_log_level,
         on_devices_enumerated, on_idle, quit, reload, set_default_workspace_capture,
-        set_explicit_sync_enabled, set_idle, set_ui_drag_enabled, set_ui_drag_threshold,
+        set_explicit_sync_enabled, set_idle, set_idle_grace_period, set_output_throttle,
+        set_output_throttle_delay, set_show_focused_output, set_ui_drag_enabled,
+        set_ui_drag_threshold, set_workspace_scroll_inverted, set_workspace_scroll_skip_empty,
+        set_workspace_scroll_threshold, set_workspace_scroll_wrap,
         status::{set_i3bar_separator, set_status, set_status_command, unset_status_command},
         switch_to_vt,
-        theme::{reset_colors, reset_font, reset_sizes, set_font},
+        theme::{
+            colors::{
+                ATTENTION_REQUESTED_WINDOW_BORDER_COLOR, FOCUSED_WINDOW_BORDER_COLOR,
+                UNFOCUSED_WINDOW_BORDER_COLOR,
+            },
+            reset_colors, reset_font, reset_sizes, set_font,
+        },
         video::{
             connectors, drm_devices, on_connector_connected, on_connector_disconnected,
-            on_graphics_initialized, on_new_connector, on_new_drm_device,
+            on_graphics_initialized, on_new_connector, on_new_drm_device, set_color_filter,
             set_direct_scanout_enabled, set_gfx_api, set_tearing_mode, set_vrr_cursor_hz,
             set_vrr_mode, Connector, DrmDevice,
         },
+        window::{
+            reset_window_rules, set_block_capture, set_border_color, set_border_width,
+            set_decoration_mode, set_scale_override,
+        },
         xwayland::set_x_scaling_mode,
     },
     std::{cell::RefCell, io::ErrorKind, path::PathBuf, rc::Rc, time::Duration},
@@ -83,7 +97,13 @@ impl Action {
                 SimpleCommand::DisablePointerConstraint => {
                     B::new(move || s.disable_pointer_constraint())
                 }
+                SimpleCommand::LocatePointer => B::new(move || s.locate_pointer()),
+                SimpleCommand::ZoomIn => B::new(move || s.zoom_in()),
+                SimpleCommand::ZoomOut => B::new(move || s.zoom_out()),
+                SimpleCommand::ResetZoom => B::new(move || s.reset_zoom()),
+                SimpleCommand::ToggleInvertColors => B::new(move || s.toggle_invert_colors()),
                 SimpleCommand::ToggleFloating => B::new(move || s.toggle_floating()),
+                SimpleCommand::ToggleSticky => B::new(move || s.toggle_sticky()),
                 SimpleCommand::Quit => B::new(quit),
                 SimpleCommand::ReloadConfigToml => {
                     let persistent = state.persistent.clone();
@@ -95,6 +115,7 @@ impl Action {
                 SimpleCommand::EnableWindowManagement(bool) => {
                     B::new(move || s.set_window_management_enabled(bool))
                 }
+                SimpleCommand::CycleLayout => B::new(move || s.cycle_layout()),
             },
             Action::Multi { actions } => {
                 let actions: Vec<_> = actions.into_iter().map(|a| a.into_fn(state)).collect();
@@ -188,7 +209,7 @@ impl Action {
                     }
                 })
             }
-            Action::ConfigureIdle { idle } => B::new(move || set_idle(Some(idle))),
+            Action::ConfigureIdle { idle } => B::new(move || idle.apply()),
             Action::MoveToOutput { output, workspace } => {
                 let state = state.clone();
                 B::new(move || {
@@ -445,6 +466,9 @@ impl Input {
         if let Some(v) = self.calibration_matrix {
             c.set_calibration_matrix(v);
         }
+        if let Some(v) = &self.repeat_rate {
+            c.set_repeat_rate(v.rate, v.delay);
+        }
     }
 }
 
@@ -540,6 +564,9 @@ impl Output {
             let (old_x, old_y) = c.position();
             c.set_position(self.x.unwrap_or(old_x), self.y.unwrap_or(old_y));
         }
+        if let Some(scale) = self.max_scale {
+            c.set_max_scale(Some(scale));
+        }
         if let Some(scale) = self.scale {
             c.set_scale(scale);
         }
@@ -571,6 +598,9 @@ impl Output {
             if let Some(hz) = vrr.cursor_hz {
                 c.set_vrr_cursor_hz(hz);
             }
+            if let Some(hz) = vrr.min_refresh_hz {
+                c.set_vrr_min_refresh(Some(hz));
+            }
         }
         if let Some(tearing) = &self.tearing {
             if let Some(mode) = tearing.mode {
@@ -580,6 +610,36 @@ impl Output {
         if let Some(format) = self.format {
             c.set_format(format);
         }
+        if let Some(scale) = self.render_scale {
+            c.set_render_scale(scale);
+        }
+        if self.workspace_scroll_wrap.is_some() {
+            c.set_workspace_scroll_wrap(self.workspace_scroll_wrap);
+        }
+        if self.workspace_scroll_skip_empty.is_some() {
+            c.set_workspace_scroll_skip_empty(self.workspace_scroll_skip_empty);
+        }
+        if let Some(enabled) = self.game_mode {
+            c.set_game_mode(enabled);
+        }
+        if let Some(enabled) = self.vblank_cursor_updates {
+            c.set_vblank_cursor_updates(enabled);
+        }
+        if let Some(enabled) = self.use_hardware_cursor {
+            c.use_hardware_cursor(enabled);
+        }
+        if let Some(filter) = self.color_filter {
+            c.set_color_filter(filter);
+        }
+        if let Some(invert) = self.invert_colors {
+            c.set_invert_colors(invert);
+        }
+        if let Some(capture) = self.workspace_capture {
+            c.set_default_workspace_capture(capture);
+        }
+        if let Some(position) = self.title_bar_position {
+            c.set_title_bar_position(position);
+        }
     }
 }
 
@@ -595,6 +655,17 @@ struct State {
     io_outputs: RefCell<AHashMap<Connector, Vec<bool>>>,
 }
 
+impl Idle {
+    fn apply(self) {
+        if let Some(timeout) = self.timeout {
+            set_idle(Some(timeout));
+        }
+        if let Some(grace_period) = self.grace_period {
+            set_idle_grace_period(grace_period);
+        }
+    }
+}
+
 impl Drop for State {
     fn drop(&mut self) {
         for keymap in self.keymaps.values() {
@@ -611,6 +682,46 @@ impl State {
         for bind in binds.drain() {
             self.persistent.seat.unbind(bind);
         }
+        let mut sequences = self.persistent.sequences.borrow_mut();
+        for keys in sequences.drain(..) {
+            self.persistent.seat.unbind_sequence(&keys);
+        }
+        let mut pointer_binds = self.persistent.pointer_binds.borrow_mut();
+        for (mods, trigger) in pointer_binds.drain() {
+            self.persistent.seat.unbind_pointer(mods, trigger);
+        }
+    }
+
+    fn apply_pointer_shortcuts(self: &Rc<Self>, shortcuts: Vec<PointerShortcut>) {
+        let mut pointer_binds = self.persistent.pointer_binds.borrow_mut();
+        for shortcut in shortcuts {
+            if let Action::SimpleCommand {
+                cmd: SimpleCommand::None,
+            } = shortcut.action
+            {
+                continue;
+            }
+            let f = shortcut.action.into_fn(self);
+            self.persistent
+                .seat
+                .bind_pointer(shortcut.mods, shortcut.trigger, f);
+            pointer_binds.insert((shortcut.mods, shortcut.trigger));
+        }
+    }
+
+    fn apply_key_sequences(self: &Rc<Self>, key_sequences: Vec<KeySequence>) {
+        let mut sequences = self.persistent.sequences.borrow_mut();
+        for sequence in key_sequences {
+            if let Action::SimpleCommand {
+                cmd: SimpleCommand::None,
+            } = sequence.action
+            {
+                continue;
+            }
+            let f = sequence.action.into_fn(self);
+            self.persistent.seat.bind_sequence(&sequence.keys, f);
+            sequences.push(sequence.keys);
+        }
     }
 
     fn apply_shortcuts(self: &Rc<Self>, shortcuts: impl IntoIterator<Item = Shortcut>) {
@@ -714,6 +825,17 @@ impl State {
         color!(UNFOCUSED_TITLE_BACKGROUND_COLOR, unfocused_title_bg_color);
         color!(UNFOCUSED_TITLE_TEXT_COLOR, unfocused_title_text_color);
         color!(HIGHLIGHT_COLOR, highlight_color);
+        color!(POINTER_LOCATOR_COLOR, pointer_locator_color);
+        color!(FOCUSED_WINDOW_BORDER_COLOR, focused_window_border_color);
+        color!(UNFOCUSED_WINDOW_BORDER_COLOR, unfocused_window_border_color);
+        color!(
+            ATTENTION_REQUESTED_WINDOW_BORDER_COLOR,
+            attention_requested_window_border_color
+        );
+        color!(
+            FOCUSED_OUTPUT_INDICATOR_COLOR,
+            focused_output_indicator_color
+        );
         macro_rules! size {
             ($sized:ident, $field:ident) => {
                 if let Some(size) = theme.$field {
@@ -723,6 +845,8 @@ impl State {
         }
         size!(BORDER_WIDTH, border_width);
         size!(TITLE_HEIGHT, title_height);
+        size!(POINTER_LOCATOR_SIZE, pointer_locator_size);
+        size!(WINDOW_BORDER_WIDTH, window_border_width);
         if let Some(font) = &theme.font {
             set_font(font);
         }
@@ -812,6 +936,8 @@ struct PersistentState {
     default: Config,
     seat: Seat,
     binds: RefCell<AHashSet<ModifiedKeySym>>,
+    sequences: RefCell<Vec<Vec<ModifiedKeySym>>>,
+    pointer_binds: RefCell<AHashSet<(Modifiers, PointerTrigger)>>,
 }
 
 fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
@@ -903,6 +1029,34 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
         }
     }
     let switch_actions = Rc::new(switch_actions);
+    let mut desktop_click_actions = AHashMap::new();
+    for (button, action) in config.desktop_click_actions.drain() {
+        desktop_click_actions.insert(button, action.into_fn(&state));
+    }
+    if desktop_click_actions.len() > 0 {
+        persistent.seat.on_desktop_click(move |click| {
+            if let Some(action) = desktop_click_actions.get(&click.button) {
+                action();
+            }
+        });
+    }
+    if let Some(size) = config.hot_corners.size {
+        persistent.seat.set_hot_corner_size(size);
+    }
+    if let Some(dwell) = config.hot_corners.dwell {
+        persistent.seat.set_hot_corner_dwell(dwell);
+    }
+    let mut hot_corner_actions = AHashMap::new();
+    for (corner, action) in config.hot_corners.actions.drain() {
+        hot_corner_actions.insert(corner, action.into_fn(&state));
+    }
+    if hot_corner_actions.len() > 0 {
+        persistent.seat.on_hot_corner(move |event| {
+            if let Some(action) = hot_corner_actions.get(&event.corner) {
+                action();
+            }
+        });
+    }
     match config.on_graphics_initialized {
         None => on_graphics_initialized(|| ()),
         Some(a) => on_graphics_initialized(a.into_fn(&state)),
@@ -913,14 +1067,42 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
     }
     state.unbind_all();
     state.apply_shortcuts(config.shortcuts);
+    state.apply_key_sequences(config.key_sequences);
+    state.apply_pointer_shortcuts(config.pointer_shortcuts);
     if let Some(keymap) = config.keymap {
         state.set_keymap(&keymap);
     }
+    if !config.layouts.is_empty() {
+        let layouts: Vec<_> = config
+            .layouts
+            .iter()
+            .filter_map(|l| state.get_keymap(l))
+            .collect();
+        persistent.seat.set_layouts(&layouts);
+    }
     if let Some(repeat_rate) = config.repeat_rate {
         persistent
             .seat
             .set_repeat_rate(repeat_rate.rate, repeat_rate.delay);
     }
+    // Outputs are only configured when they are first seen. Reset the per-output
+    // workspace scroll settings so that overrides removed from the config do not
+    // survive a reload.
+    for c in connectors() {
+        if !c.connected() {
+            continue;
+        }
+        let mut wrap = None;
+        let mut skip_empty = None;
+        for output in &config.outputs {
+            if output.match_.matches(c, &state) {
+                wrap = output.workspace_scroll_wrap.or(wrap);
+                skip_empty = output.workspace_scroll_skip_empty.or(skip_empty);
+            }
+        }
+        c.set_workspace_scroll_wrap(wrap);
+        c.set_workspace_scroll_skip_empty(skip_empty);
+    }
     on_new_connector(move |c| {
         for connector in &config.connectors {
             if connector.match_.matches(c) {
@@ -965,7 +1147,7 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
             set_log_level(level);
         }
         if let Some(idle) = config.idle {
-            set_idle(Some(idle));
+            idle.apply();
         }
     }
     on_devices_enumerated({
@@ -1037,11 +1219,58 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
             true => FocusFollowsMouseMode::True,
             false => FocusFollowsMouseMode::False,
         });
+    persistent
+        .seat
+        .set_focus_follows_mouse_dwell(config.focus_follows_mouse_dwell.unwrap_or_default());
+    persistent
+        .seat
+        .set_warp_pointer_on_focus(config.warp_pointer_on_focus);
+    persistent
+        .seat
+        .set_cursor_theme(config.cursor_theme.as_deref());
+    if let Some(size) = config.cursor_size {
+        persistent.seat.set_cursor_size(size);
+    }
+    persistent
+        .seat
+        .set_hide_cursor_while_typing(config.hide_cursor_while_typing);
+    persistent
+        .seat
+        .set_hide_cursor_timeout(config.hide_cursor_timeout.unwrap_or_default());
     if let Some(window_management_key) = config.window_management_key {
         persistent
             .seat
             .set_window_management_key(window_management_key);
     }
+    match config.shortcuts_inhibit_escape {
+        Some(key) => persistent.seat.set_shortcuts_inhibit_escape(key),
+        None => persistent.seat.unset_shortcuts_inhibit_escape(),
+    }
+    persistent
+        .seat
+        .set_zoom_scroll_modifiers(config.zoom_scroll_modifiers.unwrap_or(Modifiers::NONE));
+    persistent.seat.set_workspace_swipe(
+        config.workspace_swipe.fingers.unwrap_or(0),
+        config.workspace_swipe.inverted.unwrap_or(false),
+    );
+    persistent
+        .seat
+        .set_dnd_threshold(config.dnd_threshold.unwrap_or(0).min(i32::MAX as u64) as i32);
+    persistent
+        .seat
+        .set_middle_click_paste_enabled(config.middle_click_paste.unwrap_or(true));
+    let mut edge_behaviors = [
+        (OutputEdge::Left, PointerEdgeBehavior::Clamp),
+        (OutputEdge::Right, PointerEdgeBehavior::Clamp),
+        (OutputEdge::Top, PointerEdgeBehavior::Clamp),
+        (OutputEdge::Bottom, PointerEdgeBehavior::Clamp),
+    ];
+    for (edge, behavior) in config.pointer_edge_behaviors {
+        edge_behaviors[edge as usize].1 = behavior;
+    }
+    for (edge, behavior) in edge_behaviors {
+        set_pointer_edge_behavior(edge, behavior);
+    }
     if let Some(vrr) = config.vrr {
         if let Some(mode) = vrr.mode {
             set_vrr_mode(mode);
@@ -1049,6 +1278,9 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
         if let Some(hz) = vrr.cursor_hz {
             set_vrr_cursor_hz(hz);
         }
+        if vrr.min_refresh_hz.is_some() {
+            log::warn!("`vrr.min-refresh-hz` can only be set per output");
+        }
     }
     if let Some(tearing) = config.tearing {
         if let Some(mode) = tearing.mode {
@@ -1062,6 +1294,60 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
     if let Some(threshold) = config.ui_drag.threshold {
         set_ui_drag_threshold(threshold);
     }
+    set_workspace_scroll_inverted(config.workspace_scroll.inverted.unwrap_or(false));
+    set_workspace_scroll_threshold(config.workspace_scroll.threshold.unwrap_or(1));
+    set_workspace_scroll_wrap(config.workspace_scroll.wrap.unwrap_or(false));
+    set_workspace_scroll_skip_empty(config.workspace_scroll.skip_empty.unwrap_or(false));
+    set_output_throttle(config.output_throttle.max_refresh_rate);
+    set_output_throttle_delay(
+        config
+            .output_throttle
+            .delay
+            .unwrap_or(Duration::from_secs(5)),
+    );
+    if let Some(duration) = config.pointer_locator.duration {
+        set_pointer_locator_duration(duration);
+    }
+    if let Some(factor) = config.max_zoom {
+        set_max_zoom(factor);
+    }
+    if let Some(show) = config.show_focused_output {
+        set_show_focused_output(show);
+    }
+    if let Some(shared) = config.shared_clipboard {
+        set_shared_clipboard(shared);
+    }
+    if let Some(filter) = config.color_filter {
+        set_color_filter(filter);
+    }
+    reset_window_rules();
+    for rule in &config.window_rules {
+        if let Some(scale) = rule.scale {
+            set_scale_override(&rule.app_id, Some(scale));
+        }
+        if let Some(mode) = rule.decorations {
+            set_decoration_mode(&rule.app_id, Some(mode));
+        }
+        if let Some(width) = rule.border_width {
+            set_border_width(&rule.app_id, Some(width));
+        }
+        let colors = [
+            (FOCUSED_WINDOW_BORDER_COLOR, rule.focused_border_color),
+            (UNFOCUSED_WINDOW_BORDER_COLOR, rule.unfocused_border_color),
+            (
+                ATTENTION_REQUESTED_WINDOW_BORDER_COLOR,
+                rule.attention_requested_border_color,
+            ),
+        ];
+        for (colorable, color) in colors {
+            if let Some(color) = color {
+                set_border_color(&rule.app_id, colorable, Some(color));
+            }
+        }
+        if let Some(block) = rule.block_capture {
+            set_block_capture(&rule.app_id, block);
+        }
+    }
     if let Some(xwayland) = config.xwayland {
         if let Some(mode) = xwayland.scaling_mode {
             set_x_scaling_mode(mode);
@@ -1094,6 +1380,8 @@ pub fn configure() {
         default: default.unwrap(),
         seat: default_seat(),
         binds: Default::default(),
+        sequences: Default::default(),
+        pointer_binds: Default::default(),
     });
     load_config(true, &persistent);
 }